@@ -5,12 +5,15 @@ use forc_util::validate_name;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     path::{Path, PathBuf},
     sync::Arc,
 };
 use sway_core::{
-    fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type, BuildTarget, OptLevel,
+    fuel_prelude::fuel_tx,
+    language::parsed::TreeType,
+    lint::{LintConfig, LintLevel, WARNINGS_LINT_GROUP},
+    parse_tree_type, BuildTarget, ExperimentalFeature, OptLevel,
 };
 use sway_error::handler::Handler;
 use sway_utils::{
@@ -150,6 +153,10 @@ pub struct PackageManifest {
     pub build_target: Option<BTreeMap<String, BuildTarget>>,
     build_profile: Option<BTreeMap<String, BuildProfile>>,
     pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
+    /// The configuration of `forc lint`, also used by the language server when present.
+    pub lint: Option<LintConfig>,
+    /// Limits on the size and gas usage of the compiled program, checked by `forc build`.
+    pub budget: Option<Budget>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -159,10 +166,56 @@ pub struct Project {
     pub name: String,
     pub organization: Option<String>,
     pub license: String,
+    /// The version of the package, required to publish it to a registry.
+    pub version: Option<semver::Version>,
+    /// A short description of the package, required to publish it to a registry.
+    pub description: Option<String>,
     #[serde(default = "default_entry")]
     pub entry: String,
     pub implicit_std: Option<bool>,
-    pub forc_version: Option<semver::Version>,
+    /// The versions of forc the package can be built with, e.g. `>=0.50`.
+    #[serde(default, deserialize_with = "deserialize_forc_version")]
+    pub forc_version: Option<semver::VersionReq>,
+    /// The experimental features the package opts into, e.g. `["new_encoding"]`.
+    #[serde(default)]
+    pub experimental: BTreeSet<ExperimentalFeature>,
+    /// Whether the package is an interface package, i.e. a library containing only `abi`
+    /// declarations and the types they use, shared by a contract and its callers.
+    #[serde(default)]
+    pub interface: bool,
+    /// The interface packages implemented by the contract, keyed by dependency name, along with
+    /// the versions of the interfaces they must match, e.g. `{ token = "^1.2" }`.
+    #[serde(default)]
+    pub implements: BTreeMap<String, semver::VersionReq>,
+}
+
+/// Deserializes the `forc-version` requirement of a package. A plain version, e.g. `0.49.0`, is
+/// the minimum version required, as it was before version requirements were supported.
+fn deserialize_forc_version<'de, D>(deserializer: D) -> Result<Option<semver::VersionReq>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(req) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let req = match semver::Version::parse(&req) {
+        Ok(_) => format!(">={req}"),
+        Err(_) => req,
+    };
+    semver::VersionReq::parse(&req)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+/// The `[budget]` section of a package manifest.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Budget {
+    /// The maximum size in bytes of the bytecode, including its data section.
+    pub max_bytecode_size: Option<usize>,
+    /// The maximum estimated gas of each entry point, i.e. of each contract method or of the
+    /// `main` function of a script or predicate.
+    pub max_entry_gas: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -223,6 +276,10 @@ pub struct BuildProfile {
     #[serde(default)]
     pub print_intermediate_asm: bool,
     #[serde(default)]
+    pub print_asm_stats: bool,
+    #[serde(default)]
+    pub verbose_types: bool,
+    #[serde(default)]
     pub terse: bool,
     #[serde(default)]
     pub time_phases: bool,
@@ -230,20 +287,55 @@ pub struct BuildProfile {
     pub metrics_outfile: Option<String>,
     #[serde(default)]
     pub include_tests: bool,
+    /// Whether the gas estimates of the entry points are printed and added to the ABI.
+    #[serde(default)]
+    pub gas_report: bool,
     #[serde(default)]
     pub json_abi_with_callpaths: bool,
     #[serde(default)]
     pub error_on_warnings: bool,
+    /// The levels of the lints of the compiler warnings, overriding `error-on-warnings`. The
+    /// `warnings` lint group stands for all of the lints.
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
     pub reverse_results: bool,
     pub optimization_level: OptLevel,
+    /// Whether overflowing arithmetic reverts, rather than wrapping around.
+    #[serde(default = "default_overflow_checks")]
+    pub overflow_checks: bool,
+    /// Whether submodules which don't depend on each other are type-checked in parallel.
+    #[serde(default)]
+    pub parallel_type_check: bool,
     #[serde(default)]
     pub experimental: ExperimentalFlags,
+    /// The JSON file holding the execution profile of the program, towards whose hot paths the
+    /// optimizations are biased.
+    #[serde(default)]
+    pub pgo_profile: Option<PathBuf>,
+    /// The number of instructions the bytecode may grow by when inlining functions, unlimited if
+    /// not set.
+    #[serde(default)]
+    pub inline_budget: Option<u64>,
+    /// Whether storage writes overwritten before being read are removed.
+    #[serde(default = "default_dse_storage")]
+    pub dse_storage: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct ExperimentalFlags {
     pub new_encoding: bool,
+    #[serde(default)]
+    pub test_only: bool,
+}
+
+impl ExperimentalFlags {
+    pub fn enable(&mut self, feature: ExperimentalFeature) {
+        match feature {
+            ExperimentalFeature::NewEncoding => self.new_encoding = true,
+            ExperimentalFeature::TestOnly => self.test_only = true,
+        }
+    }
 }
 
 impl DependencyDetails {
@@ -555,6 +647,14 @@ impl PackageManifest {
         for (_, dependency_details) in self.deps_detailed() {
             dependency_details.validate()?;
         }
+        for name in self.project.implements.keys() {
+            if self.dep(name).is_none() {
+                bail!(
+                    "the implemented interface {name:?} must be declared in the `dependencies` \
+                    table"
+                );
+            }
+        }
         Ok(())
     }
 
@@ -722,17 +822,27 @@ impl BuildProfile {
             print_ir: false,
             print_finalized_asm: false,
             print_intermediate_asm: false,
+            print_asm_stats: false,
+            verbose_types: false,
             terse: false,
             time_phases: false,
             metrics_outfile: None,
             include_tests: false,
+            gas_report: false,
             json_abi_with_callpaths: false,
             error_on_warnings: false,
+            lints: BTreeMap::new(),
             reverse_results: false,
             optimization_level: OptLevel::Opt0,
+            overflow_checks: true,
+            parallel_type_check: false,
             experimental: ExperimentalFlags {
                 new_encoding: false,
+                test_only: false,
             },
+            pgo_profile: None,
+            inline_budget: None,
+            dse_storage: true,
         }
     }
 
@@ -744,19 +854,41 @@ impl BuildProfile {
             print_ir: false,
             print_finalized_asm: false,
             print_intermediate_asm: false,
+            print_asm_stats: false,
+            verbose_types: false,
             terse: false,
             time_phases: false,
             metrics_outfile: None,
             include_tests: false,
+            gas_report: false,
             json_abi_with_callpaths: false,
             error_on_warnings: false,
+            lints: BTreeMap::new(),
             reverse_results: false,
             optimization_level: OptLevel::Opt1,
+            overflow_checks: true,
+            parallel_type_check: false,
             experimental: ExperimentalFlags {
                 new_encoding: false,
+                test_only: false,
             },
+            pgo_profile: None,
+            inline_budget: None,
+            dse_storage: true,
         }
     }
+    /// The level of the given lint of the compiler warnings.
+    pub fn lint_level(&self, lint: &str) -> LintLevel {
+        self.lints
+            .get(lint)
+            .or_else(|| self.lints.get(WARNINGS_LINT_GROUP))
+            .copied()
+            .unwrap_or(if self.error_on_warnings {
+                LintLevel::Deny
+            } else {
+                LintLevel::Warn
+            })
+    }
 }
 
 impl std::ops::Deref for PackageManifestFile {
@@ -833,6 +965,14 @@ fn default_url() -> String {
     constants::DEFAULT_NODE_URL.into()
 }
 
+fn default_overflow_checks() -> bool {
+    true
+}
+
+fn default_dse_storage() -> bool {
+    true
+}
+
 /// A [WorkspaceManifest] that was deserialized from a file at a particular path.
 #[derive(Clone, Debug)]
 pub struct WorkspaceManifestFile {
@@ -1073,6 +1213,166 @@ pub fn find_dir_within(dir: &Path, pkg_name: &str) -> Option<PathBuf> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_forc_version_requirement() {
+        let project = |forc_version: &str| {
+            let toml = format!(
+                r#"
+                [project]
+                name = "test"
+                license = "Apache-2.0"
+                forc-version = "{forc_version}"
+                "#
+            );
+            toml::from_str::<PackageManifest>(&toml).map(|manifest| manifest.project)
+        };
+        let req = project(">=0.50").unwrap().forc_version.unwrap();
+        assert!(req.matches(&semver::Version::new(0, 50, 0)));
+        assert!(!req.matches(&semver::Version::new(0, 49, 1)));
+
+        // A plain version is the minimum version required.
+        let req = project("0.49.0").unwrap().forc_version.unwrap();
+        assert!(req.matches(&semver::Version::new(0, 50, 0)));
+        assert!(!req.matches(&semver::Version::new(0, 48, 0)));
+
+        assert!(project("not a version").is_err());
+    }
+
+    #[test]
+    fn test_lint_config() {
+        let toml = r#"
+            [project]
+            name = "test"
+            license = "Apache-2.0"
+
+            [lint]
+            deny = ["non_snake_case_function"]
+            max-function-length = 50
+        "#;
+        let lint = toml::from_str::<PackageManifest>(toml)
+            .unwrap()
+            .lint
+            .unwrap();
+        assert_eq!(
+            lint.level(sway_core::lint::Lint::NonSnakeCaseFunction),
+            sway_core::lint::LintLevel::Deny
+        );
+        assert_eq!(lint.max_function_length, 50);
+        assert_eq!(
+            lint.max_cyclomatic_complexity,
+            LintConfig::default().max_cyclomatic_complexity
+        );
+    }
+
+    #[test]
+    fn test_budget() {
+        let toml = r#"
+            [project]
+            name = "test"
+            license = "Apache-2.0"
+
+            [budget]
+            max-bytecode-size = 102400
+        "#;
+        let budget = toml::from_str::<PackageManifest>(toml)
+            .unwrap()
+            .budget
+            .unwrap();
+        assert_eq!(budget.max_bytecode_size, Some(102400));
+        assert_eq!(budget.max_entry_gas, None);
+    }
+
+    #[test]
+    fn test_overflow_checks() {
+        let toml = r#"
+            [project]
+            name = "test"
+            license = "Apache-2.0"
+
+            [build-profile.wrapping]
+            reverse-results = false
+            optimization-level = "Opt1"
+            overflow-checks = false
+        "#;
+        let mut manifest = toml::from_str::<PackageManifest>(toml).unwrap();
+        manifest.implicitly_include_default_build_profiles_if_missing();
+        assert!(!manifest.build_profile("wrapping").unwrap().overflow_checks);
+        for profile in [BuildProfile::DEBUG, BuildProfile::RELEASE] {
+            assert!(manifest.build_profile(profile).unwrap().overflow_checks);
+        }
+    }
+
+    #[test]
+    fn test_experimental_features() {
+        let project = |experimental: &str| {
+            let toml = format!(
+                r#"
+                [project]
+                name = "test"
+                license = "Apache-2.0"
+                {experimental}
+                "#
+            );
+            toml::from_str::<PackageManifest>(&toml).map(|manifest| manifest.project)
+        };
+        assert!(project("").unwrap().experimental.is_empty());
+        let experimental = project(r#"experimental = ["new_encoding"]"#)
+            .unwrap()
+            .experimental;
+        assert!(experimental.contains(&ExperimentalFeature::NewEncoding));
+        let experimental = project(r#"experimental = ["test_only"]"#)
+            .unwrap()
+            .experimental;
+        assert!(experimental.contains(&ExperimentalFeature::TestOnly));
+        assert!(!experimental.contains(&ExperimentalFeature::NewEncoding));
+        assert!(project(r#"experimental = ["not_a_feature"]"#).is_err());
+    }
+
+    #[test]
+    fn test_interface_packages() {
+        let manifest = |project: &str, dependencies: &str| {
+            let toml = format!(
+                r#"
+                [project]
+                name = "test"
+                license = "Apache-2.0"
+                {project}
+
+                [dependencies]
+                {dependencies}
+                "#
+            );
+            toml::from_str::<PackageManifest>(&toml).unwrap()
+        };
+
+        let interface = manifest("interface = true", "");
+        assert!(interface.project.interface);
+        assert!(interface.project.implements.is_empty());
+        assert!(interface.validate().is_ok());
+
+        let contract = manifest(
+            r#"implements = { token = "^1.2" }"#,
+            r#"token = { path = "../token" }"#,
+        );
+        assert!(!contract.project.interface);
+        let req = &contract.project.implements["token"];
+        assert!(req.matches(&semver::Version::new(1, 3, 0)));
+        assert!(!req.matches(&semver::Version::new(2, 0, 0)));
+        assert!(contract.validate().is_ok());
+
+        // The implemented interfaces must be dependencies of the package.
+        let contract = manifest(r#"implements = { token = "^1.2" }"#, "");
+        assert!(contract.validate().is_err());
+
+        let toml = r#"
+            [project]
+            name = "test"
+            license = "Apache-2.0"
+            implements = { token = "not a version" }
+        "#;
+        assert!(toml::from_str::<PackageManifest>(toml).is_err());
+    }
+
     #[test]
     fn test_invalid_dependency_details_mixed_together() {
         let dependency_details_path_branch = DependencyDetails {
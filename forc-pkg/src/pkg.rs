@@ -1,7 +1,7 @@
 use crate::{
     lock::Lock,
     manifest::{
-        BuildProfile, Dependency, ExperimentalFlags, ManifestFile, MemberManifestFiles,
+        Budget, BuildProfile, Dependency, ExperimentalFlags, ManifestFile, MemberManifestFiles,
         PackageManifestFile,
     },
     source::{self, IPFSNode, Source},
@@ -9,7 +9,7 @@ use crate::{
 use anyhow::{anyhow, bail, Context, Error, Result};
 use forc_util::{
     default_output_directory, find_file_name, kebab_to_snake_case, print_compiling,
-    print_on_failure, print_warnings,
+    print_on_failure, print_suppressed_warnings, print_warnings,
 };
 use fuel_abi_types::abi::program as program_abi;
 use petgraph::{
@@ -19,7 +19,7 @@ use petgraph::{
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{hash_map, BTreeSet, HashMap, HashSet},
+    collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
     fs::{self, File},
     hash::{Hash, Hasher},
@@ -34,21 +34,24 @@ use sway_core::{
         evm_abi,
         fuel_abi::{self, AbiContext},
     },
-    asm_generation::ProgramABI,
-    decl_engine::DeclRefFunction,
+    asm_generation::{CompiledBytecode, ProgramABI},
+    decl_engine::{id::DeclId, DeclRefFunction, InterfaceDeclId},
     fuel_prelude::{
         fuel_crypto,
         fuel_tx::{self, Contract, ContractId, StorageSlot},
     },
-    language::{parsed::TreeType, Visibility},
+    gas::GasEstimator,
+    ir_generation::storage::{FieldStorageSlot, VersionedStorageSlots},
+    language::{parsed::TreeType, ty, Purity, Visibility},
+    lint::LintLevel,
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
-    BuildTarget, Engines, FinalizedEntry,
+    BuildTarget, EliminatedFunctions, Engines, ExecutionProfile, FinalizedEntry, TypeInfo,
 };
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
-use sway_types::constants::{CORE, PRELUDE, STD};
-use sway_types::{Ident, Span, Spanned};
+use sway_types::constants::{CORE, PRELUDE, STD, TEST_SHARED_STATE_NAME, TEST_SHOULD_REVERT_NAME};
+use sway_types::{Ident, SourceEngine, Span, Spanned};
 use sway_utils::{constants, time_expr, PerformanceData, PerformanceMetric};
 use tracing::{info, warn};
 
@@ -103,16 +106,281 @@ pub struct BuiltPackage {
     pub descriptor: PackageDescriptor,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    /// The storage slots along with the storage fields they belong to.
+    pub field_storage_slots: Vec<FieldStorageSlot>,
+    /// The explicit discriminants of the enums in the ABI, keyed by their type declaration.
+    pub enum_discriminants: BTreeMap<String, Vec<u64>>,
     pub warnings: Vec<CompileWarning>,
     source_map: SourceMap,
     pub tree_type: TreeType,
     pub bytecode: BuiltPackageBytecode,
+    /// The bytes of the bytecode attributed to the functions they were compiled from.
+    pub size_profile: SizeProfile,
     /// `Some` for contract member builds where tests were included. This is
     /// required so that we can deploy once instance of the contract (without
     /// tests) with a valid contract ID before executing the tests as scripts.
     ///
     /// For non-contract members, this is always `None`.
     pub bytecode_without_tests: Option<BuiltPackageBytecode>,
+    /// The functions of the package and of its dependencies dropped before codegen for not being
+    /// reachable from its entry points.
+    pub eliminated_functions: EliminatedFunctions,
+    /// The contract methods, or the `main` function, of the package.
+    pub entry_functions: Vec<EntryFunction>,
+}
+
+/// An entry function of a built package along with what is needed to call it: the methods of a
+/// contract in the order of its dispatcher, or the `main` function of a script or a predicate.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EntryFunction {
+    pub name: String,
+    /// The selector of a contract method, as a hex string. The `main` functions of scripts and
+    /// predicates are called without a selector.
+    pub selector: Option<String>,
+    pub parameters: Vec<EntryFunctionParameter>,
+    pub return_type: String,
+    pub storage: StorageAccess,
+    /// Whether the contract method accepts coins, i.e. is decorated with `#[payable]`.
+    pub payable: bool,
+}
+
+/// A parameter of an [EntryFunction].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EntryFunctionParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+}
+
+/// The storage accesses an [EntryFunction] is allowed, per its `#[storage(...)]` attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageAccess {
+    None,
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl EntryFunction {
+    pub fn new(entry_fn: &ty::TyEntryFn, engines: &Engines) -> Self {
+        let type_name = |type_id: sway_core::TypeId| engines.help_out(type_id).to_string();
+        Self {
+            name: entry_fn.name.to_string(),
+            selector: entry_fn
+                .selector
+                .map(|selector| format!("0x{}", hex::encode(selector))),
+            parameters: entry_fn
+                .parameters
+                .iter()
+                .map(|(name, type_id)| EntryFunctionParameter {
+                    name: name.to_string(),
+                    type_name: type_name(*type_id),
+                })
+                .collect(),
+            return_type: type_name(entry_fn.return_type),
+            storage: match entry_fn.purity {
+                Purity::Pure => StorageAccess::None,
+                Purity::Reads => StorageAccess::Read,
+                Purity::Writes => StorageAccess::Write,
+                Purity::ReadsWrites => StorageAccess::ReadWrite,
+            },
+            payable: entry_fn.payable,
+        }
+    }
+}
+
+/// A machine-readable listing of all of the artifacts produced by a build, written with
+/// `BuildOpts::json_artifact_outfile`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BuildArtifacts {
+    /// The name of the build profile used.
+    pub profile: String,
+    /// Whether overflowing arithmetic reverts, rather than wrapping around, in the built
+    /// packages.
+    pub overflow_checks: bool,
+    /// The artifacts of every built member package.
+    pub packages: Vec<PackageArtifacts>,
+}
+
+/// The artifacts written for a single built package.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PackageArtifacts {
+    pub name: String,
+    /// The type of the program, i.e. `contract`, `script`, `predicate` or `library`.
+    pub program_type: String,
+    pub manifest_path: PathBuf,
+    pub output_directory: PathBuf,
+    pub bytecode: BytecodeArtifact,
+    /// The path to the JSON ABI. `None` if the program has no ABI.
+    pub abi: Option<PathBuf>,
+    /// The path to the JSON initial storage slots. Only produced for contracts.
+    pub storage_slots: Option<PathBuf>,
+    /// The path to the versioned JSON initial storage slots along with their storage fields.
+    /// Only produced for contracts.
+    pub storage_fields: Option<PathBuf>,
+    /// The path to the source map. Only produced if a debug outfile is requested.
+    pub source_map: Option<PathBuf>,
+    /// The root of the bytecode of predicates.
+    pub predicate_root: Option<String>,
+    /// The path to the size profile. Only produced if a size profile is requested.
+    pub size_profile: Option<PathBuf>,
+    /// The bytes of the bytecode attributed to the functions they were compiled from.
+    pub size_report: SizeProfile,
+    /// The contract methods, or the `main` function, of the package.
+    pub entry_functions: Vec<EntryFunction>,
+}
+
+/// The bytecode written for a built package.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BytecodeArtifact {
+    pub path: PathBuf,
+    /// The SHA-256 hash of the bytecode.
+    pub hash: String,
+    pub size_in_bytes: usize,
+}
+
+/// The bytes of the bytecode of a package attributed to the Sway functions they were compiled
+/// from, written with `BuildOpts::size_profile`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SizeProfile {
+    pub bytecode_size_in_bytes: usize,
+    /// The bytes of the preamble and, for contracts, of the dispatch on the function selector.
+    pub prologue_size_in_bytes: usize,
+    pub data_section_size_in_bytes: usize,
+    /// The Sway functions compiled into the bytecode, largest first.
+    pub functions: Vec<FunctionSizeProfile>,
+}
+
+/// The bytes of the bytecode compiled from a single Sway function.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FunctionSizeProfile {
+    pub name: String,
+    /// The location of the function declaration, as `path:line:column`.
+    pub location: Option<String>,
+    pub size_in_bytes: usize,
+    /// The monomorphized instances of the function, largest first.
+    pub instances: Vec<FunctionInstanceSize>,
+}
+
+/// The bytes of the bytecode compiled from a monomorphized instance of a Sway function.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FunctionInstanceSize {
+    /// The name of the instance in the IR.
+    pub name: String,
+    pub size_in_bytes: usize,
+}
+
+impl SizeProfile {
+    /// Attributes the bytes of the compiled bytecode to the Sway functions they were compiled
+    /// from, grouping the monomorphized instances of each function by its declaration.
+    fn new(compiled: &CompiledBytecode, source_engine: &SourceEngine) -> Self {
+        let mut functions: Vec<FunctionSizeProfile> = vec![];
+        for function_size in &compiled.function_sizes {
+            let location = function_size.span.as_ref().and_then(|span| {
+                let source_id = span.source_id()?;
+                let (line, col) = span.start_pos().line_col();
+                let path = source_engine.get_path(source_id);
+                Some(format!("{}:{line}:{col}", path.display()))
+            });
+            let instance = FunctionInstanceSize {
+                name: function_size.name.clone(),
+                size_in_bytes: function_size.size_in_bytes,
+            };
+            let existing = functions.iter_mut().find(|function| match &location {
+                Some(_) => function.location == location,
+                None => function.name == function_size.name,
+            });
+            match existing {
+                Some(function) => {
+                    function.size_in_bytes += instance.size_in_bytes;
+                    function.instances.push(instance);
+                }
+                None => functions.push(FunctionSizeProfile {
+                    name: function_size
+                        .span
+                        .as_ref()
+                        .and_then(declared_fn_name)
+                        .unwrap_or_else(|| function_size.name.clone()),
+                    location,
+                    size_in_bytes: instance.size_in_bytes,
+                    instances: vec![instance],
+                }),
+            }
+        }
+        for function in functions.iter_mut() {
+            function
+                .instances
+                .sort_by(|a, b| b.size_in_bytes.cmp(&a.size_in_bytes));
+        }
+        functions.sort_by(|a, b| b.size_in_bytes.cmp(&a.size_in_bytes));
+
+        let functions_size: usize = functions
+            .iter()
+            .map(|function| function.size_in_bytes)
+            .sum();
+        let bytecode_size = compiled.bytecode.len();
+        SizeProfile {
+            bytecode_size_in_bytes: bytecode_size,
+            prologue_size_in_bytes: (bytecode_size - compiled.data_section_size)
+                .saturating_sub(functions_size),
+            data_section_size_in_bytes: compiled.data_section_size,
+            functions,
+        }
+    }
+
+    /// Prints the size profile as a table, the largest functions first.
+    fn print(&self) {
+        let percent = |size: usize| size as f64 * 100.0 / self.bytecode_size_in_bytes as f64;
+        info!("      Size profile:");
+        info!("      {:>8}  {:>6}  function", "bytes", "%");
+        for function in &self.functions {
+            info!(
+                "      {:>8}  {:>5.1}%  {}{}",
+                function.size_in_bytes,
+                percent(function.size_in_bytes),
+                function.name,
+                function
+                    .location
+                    .as_ref()
+                    .map(|location| format!(" ({location})"))
+                    .unwrap_or_default(),
+            );
+            if function.instances.len() > 1 {
+                for instance in &function.instances {
+                    info!(
+                        "      {:>8}  {:>5.1}%    {}",
+                        instance.size_in_bytes,
+                        percent(instance.size_in_bytes),
+                        instance.name,
+                    );
+                }
+            }
+        }
+        for (name, size) in [
+            ("<prologue>", self.prologue_size_in_bytes),
+            ("<data section>", self.data_section_size_in_bytes),
+        ] {
+            info!("      {size:>8}  {:>5.1}%  {name}", percent(size));
+        }
+    }
+}
+
+/// The name of the function declared within `span`, i.e. the identifier following the `fn`
+/// keyword, skipping the doc comments and attributes of the declaration.
+fn declared_fn_name(span: &Span) -> Option<String> {
+    let signature = span
+        .as_str()
+        .lines()
+        .map(str::trim_start)
+        .find(|line| !line.is_empty() && !line.starts_with("//") && !line.starts_with("#["))?;
+    let (_, after_fn) = signature.split_once("fn ")?;
+    let name: String = after_fn
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
 }
 
 /// The package descriptors that a `BuiltPackage` holds so that the source used for building the
@@ -157,6 +425,9 @@ pub enum TestPassCondition {
 #[derive(Debug, Clone)]
 pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
+    /// Whether the test shares its storage with the other `#[test(shared_state)]` tests of the
+    /// package, instead of starting from freshly deployed contracts.
+    pub shared_state: bool,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
 }
@@ -178,9 +449,16 @@ pub struct CompiledPackage {
     pub tree_type: TreeType,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    pub field_storage_slots: Vec<FieldStorageSlot>,
+    pub enum_discriminants: BTreeMap<String, Vec<u64>>,
     pub bytecode: BuiltPackageBytecode,
     pub namespace: namespace::Root,
     pub warnings: Vec<CompileWarning>,
+    /// The number of warnings suppressed by allowing their lints, per lint.
+    pub suppressed_warnings: BTreeMap<String, usize>,
+    pub eliminated_functions: EliminatedFunctions,
+    pub size_profile: SizeProfile,
+    pub entry_functions: Vec<EntryFunction>,
     pub metrics: PerformanceData,
 }
 
@@ -263,10 +541,16 @@ pub struct PrintOpts {
     /// This is the state of the ASM prior to performing register allocation and other ASM
     /// optimisations.
     pub intermediate_asm: bool,
+    /// Print the number of instructions before and after the peephole optimizations of the
+    /// finalized ASM.
+    pub asm_stats: bool,
     /// Print the generated Sway IR (Intermediate Representation).
     pub ir: bool,
     /// Output build errors and warnings in reverse order.
     pub reverse_order: bool,
+    /// Display the fully-qualified names of types in errors and warnings, rather than their
+    /// abbreviated forms.
+    pub verbose_types: bool,
 }
 
 #[derive(Default, Clone)]
@@ -292,6 +576,14 @@ pub struct BuildOpts {
     pub binary_outfile: Option<String>,
     /// If set, outputs source file mapping in JSON format
     pub debug_outfile: Option<String>,
+    /// If set, outputs a JSON manifest listing the artifacts of every built package.
+    pub json_artifact_outfile: Option<String>,
+    /// Attribute the bytes of the bytecode of each built package to the functions they were
+    /// compiled from, printing the largest functions and writing the report in JSON format.
+    pub size_profile: bool,
+    /// Print a static estimate of the gas used by each entry point of each built package, and
+    /// add the estimates to their ABI.
+    pub gas_report: bool,
     /// Build target to use.
     pub build_target: BuildTarget,
     /// Name of the build profile to use.
@@ -307,6 +599,16 @@ pub struct BuildOpts {
     pub metrics_outfile: Option<String>,
     /// Warnings must be treated as compiler errors.
     pub error_on_warnings: bool,
+    /// The levels of the lints of the compiler warnings, overriding those of the build profile.
+    pub lints: BTreeMap<String, LintLevel>,
+    /// The JSON file holding the execution profile of the program, overriding that of the build
+    /// profile.
+    pub pgo_profile: Option<PathBuf>,
+    /// The number of instructions the bytecode may grow by when inlining functions, overriding
+    /// that of the build profile.
+    pub inline_budget: Option<u64>,
+    /// Keep the storage writes overwritten before being read, regardless of the build profile.
+    pub no_dse_storage: bool,
     /// Include all test functions within the build.
     pub tests: bool,
     /// The set of options to filter by member project kind.
@@ -433,14 +735,29 @@ impl BuiltPackage {
         Ok(())
     }
 
+    /// The mapping from the instructions of the bytecode to the source locations they were
+    /// generated from.
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    /// Writes the size profile of the BuiltPackage in JSON format to the given `path`.
+    pub fn write_size_profile(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.size_profile)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
     pub fn json_abi_string(&self, minify_json_abi: bool) -> Result<Option<String>> {
         match &self.program_abi {
             ProgramABI::Fuel(program_abi) => {
                 if !program_abi.functions.is_empty() {
+                    let mut json_abi = serde_json::to_value(program_abi)?;
+                    add_enum_discriminants(&mut json_abi, &self.enum_discriminants);
                     let json_string = if minify_json_abi {
-                        serde_json::to_string(&program_abi)
+                        serde_json::to_string(&json_abi)
                     } else {
-                        serde_json::to_string_pretty(&program_abi)
+                        serde_json::to_string_pretty(&json_abi)
                     }?;
                     Ok(Some(json_string))
                 } else {
@@ -473,13 +790,13 @@ impl BuiltPackage {
         Ok(())
     }
 
-    /// Writes BuiltPackage to `output_dir`.
+    /// Writes BuiltPackage to `output_dir`, returning the artifacts that were written.
     pub fn write_output(
         &self,
         minify: MinifyOpts,
         pkg_name: &str,
         output_dir: &Path,
-    ) -> Result<()> {
+    ) -> Result<PackageArtifacts> {
         if !output_dir.exists() {
             fs::create_dir_all(output_dir)?;
         }
@@ -490,16 +807,55 @@ impl BuiltPackage {
 
         let program_abi_stem = format!("{pkg_name}-abi");
         let json_abi_path = output_dir.join(program_abi_stem).with_extension("json");
-        self.write_json_abi(&json_abi_path, minify.clone())?;
+        let abi = match self.json_abi_string(minify.json_abi)? {
+            Some(json_abi_string) => {
+                fs::write(&json_abi_path, json_abi_string)?;
+                Some(json_abi_path)
+            }
+            None => None,
+        };
+
+        let bytecode_hash = format!("0x{}", fuel_crypto::Hasher::hash(&self.bytecode.bytes));
+        let mut artifacts = PackageArtifacts {
+            name: pkg_name.to_string(),
+            program_type: forc_util::program_type_str(&self.tree_type).to_string(),
+            manifest_path: self.descriptor.manifest_file.path().to_path_buf(),
+            output_directory: output_dir.to_path_buf(),
+            bytecode: BytecodeArtifact {
+                path: bin_path,
+                hash: bytecode_hash.clone(),
+                size_in_bytes: self.bytecode.bytes.len(),
+            },
+            abi,
+            storage_slots: None,
+            storage_fields: None,
+            source_map: None,
+            predicate_root: None,
+            size_profile: None,
+            size_report: self.size_profile.clone(),
+            entry_functions: self.entry_functions.clone(),
+        };
 
         info!("      Bytecode size: {} bytes", self.bytecode.bytes.len());
+        if self.eliminated_functions.functions > 0 {
+            info!(
+                "      Eliminated {} unreachable {} ({} IR instructions)",
+                self.eliminated_functions.functions,
+                if self.eliminated_functions.functions > 1 {
+                    "functions"
+                } else {
+                    "function"
+                },
+                self.eliminated_functions.instructions,
+            );
+        }
         // Additional ops required depending on the program type
         match self.tree_type {
             TreeType::Contract => {
                 // For contracts, emit a JSON file with all the initialized storage slots.
                 let storage_slots_stem = format!("{pkg_name}-storage_slots");
                 let storage_slots_path = output_dir.join(storage_slots_stem).with_extension("json");
-                let storage_slots_file = File::create(storage_slots_path)?;
+                let storage_slots_file = File::create(&storage_slots_path)?;
                 let res = if minify.json_storage_slots {
                     serde_json::to_writer(&storage_slots_file, &self.storage_slots)
                 } else {
@@ -507,6 +863,22 @@ impl BuiltPackage {
                 };
 
                 res?;
+                artifacts.storage_slots = Some(storage_slots_path);
+
+                // Also emit the slots along with the fields they belong to, in a versioned format.
+                let storage_fields_stem = format!("{pkg_name}-storage_fields");
+                let storage_fields_path =
+                    output_dir.join(storage_fields_stem).with_extension("json");
+                let storage_fields_file = File::create(&storage_fields_path)?;
+                let storage_fields = VersionedStorageSlots::new(self.field_storage_slots.clone());
+                let res = if minify.json_storage_slots {
+                    serde_json::to_writer(&storage_fields_file, &storage_fields)
+                } else {
+                    serde_json::to_writer_pretty(&storage_fields_file, &storage_fields)
+                };
+
+                res?;
+                artifacts.storage_fields = Some(storage_fields_path);
             }
             TreeType::Predicate => {
                 // Get the root hash of the bytecode for predicates and store the result in a file in the output directory
@@ -518,11 +890,10 @@ impl BuiltPackage {
                 let root_path = output_dir.join(root_file_name);
                 fs::write(root_path, &root)?;
                 info!("      Predicate root: {}", root);
+                artifacts.predicate_root = Some(root);
             }
             TreeType::Script => {
                 // hash the bytecode for scripts and store the result in a file in the output directory
-                let bytecode_hash =
-                    format!("0x{}", fuel_crypto::Hasher::hash(&self.bytecode.bytes));
                 let hash_file_name = format!("{}{}", &pkg_name, SWAY_BIN_HASH_SUFFIX);
                 let hash_path = output_dir.join(hash_file_name);
                 fs::write(hash_path, &bytecode_hash)?;
@@ -531,7 +902,7 @@ impl BuiltPackage {
             _ => (),
         }
 
-        Ok(())
+        Ok(artifacts)
     }
 }
 
@@ -887,28 +1258,31 @@ fn validate_version(member_manifests: &MemberManifestFiles) -> Result<()> {
     Ok(())
 }
 
-/// Check minimum forc version given in the package manifest file
+/// Check that the current forc version satisfies the `forc-version` requirement of the package.
 ///
-/// If required minimum forc version is higher than current forc version return an error with
-/// upgrade instructions
+/// If it does not, return an error naming the package, with upgrade instructions.
 fn validate_pkg_version(pkg_manifest: &PackageManifestFile) -> Result<()> {
-    match &pkg_manifest.project.forc_version {
-        Some(min_forc_version) => {
-            // Get the current version of the toolchain
-            let crate_version = env!("CARGO_PKG_VERSION");
-            let toolchain_version = semver::Version::parse(crate_version)?;
-            if toolchain_version < *min_forc_version {
-                bail!(
-                    "{:?} requires forc version {} but current forc version is {}\nUpdate the toolchain by following: https://fuellabs.github.io/sway/v{}/introduction/installation.html",
-                    pkg_manifest.project.name,
-                    min_forc_version,
-                    crate_version,
-                    crate_version
-                );
-            }
+    if let Some(forc_version_req) = &pkg_manifest.project.forc_version {
+        // Get the current version of the toolchain
+        let crate_version = env!("CARGO_PKG_VERSION");
+        let toolchain_version = semver::Version::parse(crate_version)?;
+        // Pre-release toolchains are considered to be their release version.
+        let release_version = semver::Version::new(
+            toolchain_version.major,
+            toolchain_version.minor,
+            toolchain_version.patch,
+        );
+        if !forc_version_req.matches(&release_version) {
+            bail!(
+                "package {:?} ({}) requires forc version {} but current forc version is {}\nUpdate the toolchain by following: https://fuellabs.github.io/sway/v{}/introduction/installation.html",
+                pkg_manifest.project.name,
+                pkg_manifest.path().display(),
+                forc_version_req,
+                crate_version,
+                crate_version
+            );
         }
-        None => {}
-    };
+    }
     Ok(())
 }
 
@@ -1009,7 +1383,7 @@ fn validate_dep(
         bail!("dependency node's source does not match manifest entry");
     }
 
-    validate_dep_manifest(&graph[dep_node], &dep_manifest, dep_edge)?;
+    validate_dep_manifest(&graph[dep_node], &dep_manifest, node_manifest, dep_edge)?;
 
     Ok(dep_manifest)
 }
@@ -1017,6 +1391,7 @@ fn validate_dep(
 fn validate_dep_manifest(
     dep: &Pinned,
     dep_manifest: &PackageManifestFile,
+    parent_manifest: &PackageManifestFile,
     dep_edge: &Edge,
 ) -> Result<()> {
     let dep_program_type = dep_manifest.program_type()?;
@@ -1041,6 +1416,23 @@ fn validate_dep_manifest(
             dep_manifest.project.name,
         );
     }
+    // Ensure the dependency doesn't rely on experimental features that its dependent hasn't
+    // opted into.
+    let missing_features: Vec<_> = dep_manifest
+        .project
+        .experimental
+        .difference(&parent_manifest.project.experimental)
+        .map(|feature| format!("`{feature}`"))
+        .collect();
+    if !missing_features.is_empty() {
+        bail!(
+            "\"{}\" requires the experimental feature(s) {}, which must also be enabled in the \
+            `experimental` field of the manifest of \"{}\"",
+            dep.name,
+            missing_features.join(", "),
+            parent_manifest.project.name,
+        );
+    }
     validate_pkg_version(dep_manifest)?;
     Ok(())
 }
@@ -1506,8 +1898,14 @@ fn fetch_deps(
 
         let dep_pinned = &graph[dep_node];
         let dep_pkg_id = dep_pinned.id();
-        validate_dep_manifest(dep_pinned, &manifest_map[&dep_pkg_id], &dep_edge).map_err(|e| {
-            let parent = &graph[node];
+        let parent = &graph[node];
+        validate_dep_manifest(
+            dep_pinned,
+            &manifest_map[&dep_pkg_id],
+            package_manifest,
+            &dep_edge,
+        )
+        .map_err(|e| {
             anyhow!(
                 "dependency of {:?} named {:?} is invalid: {}",
                 parent.name,
@@ -1561,16 +1959,66 @@ pub fn sway_build_config(
     .with_print_finalized_asm(build_profile.print_finalized_asm)
     .with_print_intermediate_asm(build_profile.print_intermediate_asm)
     .with_print_ir(build_profile.print_ir)
+    .with_print_asm_stats(build_profile.print_asm_stats)
     .with_include_tests(build_profile.include_tests)
     .with_time_phases(build_profile.time_phases)
     .with_metrics(build_profile.metrics_outfile.clone())
     .with_optimization_level(build_profile.optimization_level)
+    .with_overflow_checks(build_profile.overflow_checks)
+    .with_parallel_type_check(build_profile.parallel_type_check)
+    .with_execution_profile(
+        build_profile
+            .pgo_profile
+            .as_ref()
+            .map(|path| read_execution_profile(&manifest_dir.join(path)))
+            .transpose()?
+            .map(Arc::new),
+    )
+    .with_inline_budget(build_profile.inline_budget)
+    .with_dse_storage(build_profile.dse_storage)
     .with_experimental(sway_core::ExperimentalFlags {
         new_encoding: build_profile.experimental.new_encoding,
+        test_only: build_profile.experimental.test_only,
     });
     Ok(build_config)
 }
 
+/// The format of the execution profile files given with `--pgo`, holding the gas spent in each
+/// function of the program, keyed by the name of the function.
+#[derive(Deserialize)]
+struct ExecutionProfileFile {
+    functions: BTreeMap<String, u64>,
+}
+
+fn read_execution_profile(path: &Path) -> Result<ExecutionProfile> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("failed to read the execution profile {}", path.display()))?;
+    let file: ExecutionProfileFile = serde_json::from_str(&json)
+        .with_context(|| format!("failed to parse the execution profile {}", path.display()))?;
+    Ok(ExecutionProfile::new(file.functions))
+}
+
+/// Returns the experimental features to compile the package at `node` with.
+///
+/// These are the features enabled by the profile, along with those declared in the manifests of
+/// the package and of every package depending on it, so that a dependency is compiled the same
+/// way as the packages using it.
+pub fn experimental_flags(
+    plan: &BuildPlan,
+    node: NodeIx,
+    profile: &BuildProfile,
+) -> ExperimentalFlags {
+    let mut experimental = profile.experimental.clone();
+    let rev_pkg_graph = petgraph::visit::Reversed(plan.graph());
+    for dependent in Dfs::new(rev_pkg_graph, node).iter(rev_pkg_graph) {
+        let manifest = &plan.manifest_map()[&plan.graph()[dependent].id()];
+        for feature in &manifest.project.experimental {
+            experimental.enable(*feature);
+        }
+    }
+    experimental
+}
+
 /// The name of the constant holding the contract's id.
 pub const CONTRACT_ID_CONSTANT_NAME: &str = "CONTRACT_ID";
 
@@ -1760,6 +2208,7 @@ pub fn compile(
     let terse_mode = profile.terse;
     let reverse_results = profile.reverse_results;
     let fail = |handler: Handler| {
+        let handler = apply_lint_levels(handler, profile, &mut BTreeMap::new());
         let (errors, warnings) = handler.consume();
         print_on_failure(
             engines.se(),
@@ -1804,7 +2253,11 @@ pub fn compile(
         tracing::info!("{:#?}", typed_program);
     }
 
-    let storage_slots = typed_program.storage_slots.clone();
+    let field_storage_slots = typed_program.storage_slots.clone();
+    let storage_slots = field_storage_slots
+        .iter()
+        .map(|slot| slot.slot.clone())
+        .collect();
     let tree_type = typed_program.kind.tree_type();
 
     let namespace = typed_program.root.namespace.clone().into();
@@ -1813,6 +2266,14 @@ pub fn compile(
         return fail(handler);
     }
 
+    let entry_functions = match typed_program.entry_fns_metadata(&handler, engines) {
+        Ok(entry_fns) => entry_fns
+            .iter()
+            .map(|entry_fn| EntryFunction::new(entry_fn, engines))
+            .collect(),
+        Err(_) => return fail(handler),
+    };
+
     let asm_res = time_expr!(
         "compile ast to asm",
         "compile_ast_to_asm",
@@ -1823,6 +2284,11 @@ pub fn compile(
 
     const NEW_ENCODING_VERSION: &str = "1";
 
+    let mut abi_ctx = AbiContext {
+        program: typed_program,
+        abi_with_callpaths: profile.json_abi_with_callpaths,
+        enum_discriminants: BTreeMap::new(),
+    };
     let mut program_abi = match pkg.target {
         BuildTarget::Fuel => {
             let mut types = vec![];
@@ -1830,10 +2296,7 @@ pub fn compile(
                 "generate JSON ABI program",
                 "generate_json_abi",
                 fuel_abi::generate_program_abi(
-                    &mut AbiContext {
-                        program: typed_program,
-                        abi_with_callpaths: profile.json_abi_with_callpaths,
-                    },
+                    &mut abi_ctx,
                     engines.te(),
                     engines.de(),
                     &mut types,
@@ -1886,6 +2349,7 @@ pub fn compile(
         Err(_) => return fail(handler),
         Ok(asm) => asm,
     };
+    let eliminated_functions = asm.0.eliminated_functions;
 
     let bc_res = time_expr!(
         "compile asm to bytecode",
@@ -1895,7 +2359,9 @@ pub fn compile(
         metrics
     );
 
-    let errored = handler.has_errors() || (handler.has_warnings() && profile.error_on_warnings);
+    let mut suppressed_warnings = BTreeMap::new();
+    let handler = apply_lint_levels(handler, profile, &mut suppressed_warnings);
+    let errored = handler.has_errors();
 
     let compiled = match bc_res {
         Ok(compiled) if !errored => compiled,
@@ -1906,6 +2372,23 @@ pub fn compile(
 
     print_warnings(engines.se(), terse_mode, &pkg.name, &warnings, &tree_type);
 
+    // Test builds include the tests in the bytecode, so they are not checked against the budget.
+    if let Some(budget) = pkg.manifest_file.budget.as_ref() {
+        if !profile.include_tests {
+            check_budget(&pkg.name, budget, typed_program, &compiled, engines)?;
+        }
+    }
+    let size_profile = SizeProfile::new(&compiled, engines.se());
+    if pkg.manifest_file.project.interface {
+        check_interface(&pkg.name, typed_program, engines)?;
+    }
+    check_implemented_abis(
+        &pkg.name,
+        &pkg.manifest_file.project.implements,
+        typed_program,
+        engines,
+    )?;
+
     // TODO: This should probably be in `fuel_abi_json::generate_json_abi_program`?
     // If ABI requires knowing config offsets, they should be inputs to ABI gen.
     if let ProgramABI::Fuel(ref mut program_abi) = program_abi {
@@ -1921,6 +2404,12 @@ pub fn compile(
         }
     }
 
+    if profile.gas_report {
+        if let ProgramABI::Fuel(ref mut program_abi) = program_abi {
+            add_gas_estimates_to_abi(program_abi, &entries);
+        }
+    }
+
     metrics.bytecode_size = compiled.bytecode.len();
     let bytecode = BuiltPackageBytecode {
         bytes: compiled.bytecode,
@@ -1930,15 +2419,327 @@ pub fn compile(
         source_map: source_map.clone(),
         program_abi,
         storage_slots,
+        field_storage_slots,
+        enum_discriminants: abi_ctx.enum_discriminants,
         tree_type,
         bytecode,
         namespace,
         warnings,
+        suppressed_warnings,
+        eliminated_functions,
         metrics,
+        size_profile,
+        entry_functions,
     };
     Ok(compiled_package)
 }
 
+/// Applies the lint levels of the build profile to the warnings of the handler. The warnings of
+/// denied lints are turned into errors, while those of allowed lints are removed and counted per
+/// lint in `suppressed`.
+fn apply_lint_levels(
+    handler: Handler,
+    profile: &BuildProfile,
+    suppressed: &mut BTreeMap<String, usize>,
+) -> Handler {
+    let (errors, warnings) = handler.consume();
+    let handler = Handler::from_parts(errors, vec![]);
+    for warning in warnings {
+        let lint = warning.warning_content.lint_name();
+        match profile.lint_level(lint) {
+            LintLevel::Allow => *suppressed.entry(lint.to_string()).or_default() += 1,
+            LintLevel::Warn => handler.emit_warn(warning),
+            LintLevel::Deny => {
+                handler.emit_err(CompileError::Lint {
+                    name: lint.to_string(),
+                    message: warning.to_friendly_warning_string(),
+                    span: warning.span,
+                });
+            }
+        }
+    }
+    handler
+}
+
+/// Checks the compiled program against the budget declared in its package manifest, failing
+/// with a breakdown of the bytecode size and of the gas estimated for each entry point when the
+/// budget is exceeded.
+fn check_budget(
+    pkg_name: &str,
+    budget: &Budget,
+    typed_program: &ty::TyProgram,
+    compiled: &CompiledBytecode,
+    engines: &Engines,
+) -> Result<()> {
+    let bytecode_size = compiled.bytecode.len();
+    let size_exceeded = budget
+        .max_bytecode_size
+        .is_some_and(|max| bytecode_size > max);
+
+    let entry_fns = typed_program.entry_fns();
+    let mut estimator = GasEstimator::new(engines);
+    let entry_gas: Vec<_> = entry_fns
+        .iter()
+        .map(|fn_id| {
+            let name = engines.de().get_function(fn_id).name.to_string();
+            (name, estimator.function(fn_id))
+        })
+        .collect();
+    let gas_exceeded = budget
+        .max_entry_gas
+        .is_some_and(|max| entry_gas.iter().any(|(_, gas)| *gas > max));
+
+    if !size_exceeded && !gas_exceeded {
+        return Ok(());
+    }
+
+    let over = |exceeded: bool| if exceeded { "  <- over budget" } else { "" };
+    let mut breakdown = format!("Package {pkg_name} exceeds its budget:\n");
+    breakdown.push_str(&format!(
+        "  bytecode size: {bytecode_size} bytes{}{}\n",
+        budget
+            .max_bytecode_size
+            .map(|max| format!(" (max {max})"))
+            .unwrap_or_default(),
+        over(size_exceeded),
+    ));
+    breakdown.push_str(&format!(
+        "    instructions: {} bytes\n    data section: {} bytes\n",
+        bytecode_size - compiled.data_section_size,
+        compiled.data_section_size,
+    ));
+    if !entry_gas.is_empty() {
+        breakdown.push_str(&format!(
+            "  estimated gas per entry point{}:\n",
+            budget
+                .max_entry_gas
+                .map(|max| format!(" (max {max})"))
+                .unwrap_or_default(),
+        ));
+        for (name, gas) in &entry_gas {
+            let exceeded = budget.max_entry_gas.is_some_and(|max| *gas > max);
+            breakdown.push_str(&format!("    {name}: {gas}{}\n", over(exceeded)));
+        }
+    }
+    bail!("{}", breakdown.trim_end())
+}
+
+/// Adds the gas estimate of each entry point to the attributes of its ABI function, as a
+/// `gas_estimate` attribute whose arguments are the estimated gas and either `upper_bound` or
+/// `approximate`.
+fn add_gas_estimates_to_abi(program_abi: &mut program_abi::ProgramABI, entries: &[PkgEntry]) {
+    for entry in entries.iter().filter(|entry| entry.kind.test().is_none()) {
+        let Some(function) = program_abi
+            .functions
+            .iter_mut()
+            .find(|function| function.name == entry.finalized.fn_name)
+        else {
+            continue;
+        };
+        let estimate = entry.finalized.gas_estimate;
+        let kind = if estimate.is_upper_bound {
+            "upper_bound"
+        } else {
+            "approximate"
+        };
+        function
+            .attributes
+            .get_or_insert_with(Vec::new)
+            .push(program_abi::Attribute {
+                name: "gas_estimate".to_string(),
+                arguments: vec![estimate.gas.to_string(), kind.to_string()],
+            });
+    }
+}
+
+/// Prints the gas estimates of the entry points of a package, the most expensive first. The
+/// estimates which are only approximations are prefixed with `~`.
+fn print_gas_report(entries: &[PkgEntry]) {
+    let mut entries: Vec<_> = entries
+        .iter()
+        .filter(|entry| entry.kind.test().is_none())
+        .map(|entry| &entry.finalized)
+        .collect();
+    if entries.is_empty() {
+        return;
+    }
+    entries.sort_by(|a, b| b.gas_estimate.gas.cmp(&a.gas_estimate.gas));
+    info!("      Gas report:");
+    info!("      {:>10}  entry", "gas");
+    for entry in entries {
+        let approximate = if entry.gas_estimate.is_upper_bound {
+            ""
+        } else {
+            "~"
+        };
+        info!(
+            "      {:>10}  {}",
+            format!("{approximate}{}", entry.gas_estimate.gas),
+            entry.fn_name
+        );
+    }
+}
+
+/// Checks that an interface package only declares ABIs and the types they use, failing with the
+/// list of the declarations an interface package may not contain.
+fn check_interface(pkg_name: &str, typed_program: &ty::TyProgram, engines: &Engines) -> Result<()> {
+    if !matches!(typed_program.kind, ty::TyProgramKind::Library { .. }) {
+        bail!("Interface package {pkg_name} must be a library");
+    }
+    let modules = std::iter::once(&typed_program.root).chain(
+        typed_program
+            .root
+            .submodules_recursive()
+            .map(|(_, submod)| &submod.module),
+    );
+    let mut forbidden = vec![];
+    for module in modules {
+        for node in module.all_nodes.iter() {
+            let ty::TyAstNodeContent::Declaration(decl) = &node.content else {
+                continue;
+            };
+            match decl {
+                ty::TyDecl::FunctionDecl(ty::FunctionDecl { name, .. }) => {
+                    forbidden.push(format!("function `{name}`"));
+                }
+                ty::TyDecl::StorageDecl(_) => forbidden.push("storage".to_string()),
+                ty::TyDecl::ConstantDecl(ty::ConstantDecl { name, decl_id, .. })
+                    if engines.de().get_constant(decl_id).is_configurable =>
+                {
+                    forbidden.push(format!("configurable `{name}`"));
+                }
+                _ => {}
+            }
+        }
+    }
+    if forbidden.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "Interface package {pkg_name} may only declare ABIs and the types they use, but declares:\n{}",
+        forbidden
+            .iter()
+            .map(|decl| format!("  {decl}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+/// Checks that the contract implements every ABI declared by the interface packages listed
+/// under `implements` in its manifest.
+fn check_implemented_abis(
+    pkg_name: &str,
+    implements: &BTreeMap<String, semver::VersionReq>,
+    typed_program: &ty::TyProgram,
+    engines: &Engines,
+) -> Result<()> {
+    if implements.is_empty() {
+        return Ok(());
+    }
+    let implemented: HashSet<_> = typed_program
+        .root
+        .all_nodes
+        .iter()
+        .filter_map(|node| match &node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::ImplTrait(ty::ImplTrait {
+                decl_id,
+                ..
+            })) => {
+                let impl_trait = engines.de().get_impl_trait(decl_id);
+                let is_contract = matches!(
+                    &*engines.te().get(impl_trait.implementing_for.type_id),
+                    TypeInfo::Contract
+                );
+                match impl_trait
+                    .trait_decl_ref
+                    .as_ref()
+                    .map(|decl_ref| *decl_ref.id())
+                {
+                    Some(InterfaceDeclId::Abi(abi_id)) if is_contract => Some(abi_id),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut missing = vec![];
+    for name in implements.keys() {
+        let dep_name = Ident::new_no_span(kebab_to_snake_case(name));
+        let Some(interface) = typed_program.root.namespace.module().submodule(&[dep_name]) else {
+            continue;
+        };
+        let mut abis = vec![];
+        collect_abi_decls(interface, &mut abis);
+        for (abi_name, abi_id) in abis {
+            if !implemented.contains(&abi_id) {
+                missing.push(format!("  {name}::{abi_name}"));
+            }
+        }
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "Contract {pkg_name} does not implement the following ABIs of the interfaces it implements:\n{}",
+        missing.join("\n")
+    )
+}
+
+/// Collects the ABIs declared in the given module and its submodules, skipping the external
+/// modules of its dependencies.
+fn collect_abi_decls(module: &namespace::Module, abis: &mut Vec<(String, DeclId<ty::TyAbiDecl>)>) {
+    for decl in module.symbols().values() {
+        if let ty::TyDecl::AbiDecl(ty::AbiDecl { name, decl_id, .. }) = decl {
+            abis.push((name.to_string(), *decl_id));
+        }
+    }
+    for submodule in module.submodules().values() {
+        if !submodule.is_external {
+            collect_abi_decls(submodule, abis);
+        }
+    }
+}
+
+/// Checks that the interface packages listed under `implements` in the manifest of the given
+/// package are interface packages whose versions match the required ones.
+fn validate_implemented_interfaces(
+    plan: &BuildPlan,
+    node: NodeIx,
+    manifest: &PackageManifestFile,
+) -> Result<()> {
+    for (name, req) in manifest.project.implements.iter() {
+        let Some(dep_node) = plan
+            .graph()
+            .edges_directed(node, Direction::Outgoing)
+            .find(|edge| edge.weight().name == *name)
+            .map(|edge| edge.target())
+        else {
+            bail!("dependency {name:?} listed under `implements` was not found");
+        };
+        let dep_manifest = &plan.manifest_map()[&plan.graph()[dep_node].id()];
+        if !dep_manifest.project.interface {
+            bail!(
+                "{} implements {name:?}, but {:?} is not an interface package",
+                manifest.project.name,
+                dep_manifest.project.name,
+            );
+        }
+        match &dep_manifest.project.version {
+            Some(version) if req.matches(version) => {}
+            Some(version) => bail!(
+                "{} implements version {req} of interface {name:?}, but the interface package is version {version}",
+                manifest.project.name,
+            ),
+            None => bail!(
+                "{} implements version {req} of interface {name:?}, but the interface package has no version",
+                manifest.project.name,
+            ),
+        }
+    }
+    Ok(())
+}
+
 impl PkgEntry {
     /// Returns whether this `PkgEntry` corresponds to a test.
     pub fn is_test(&self) -> bool {
@@ -1976,8 +2777,6 @@ impl PkgTestEntry {
         let span = decl_ref.span();
         let test_function_decl = engines.de().get_function(&decl_ref);
 
-        const FAILING_TEST_KEYWORD: &str = "should_revert";
-
         let test_args: HashMap<String, Option<String>> = test_function_decl
             .attributes
             .get(&AttributeKind::Test)
@@ -1994,21 +2793,28 @@ impl PkgTestEntry {
             })
             .collect();
 
-        let pass_condition = if test_args.is_empty() {
-            anyhow::Ok(TestPassCondition::ShouldNotRevert)
-        } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
-            let expected_revert_code = args
-                .as_ref()
-                .map(|arg| {
-                    let arg_str = arg.replace('"', "");
-                    arg_str.parse::<u64>()
-                })
-                .transpose()?;
-            anyhow::Ok(TestPassCondition::ShouldRevert(expected_revert_code))
-        } else {
+        if test_args
+            .keys()
+            .any(|arg| arg != TEST_SHOULD_REVERT_NAME && arg != TEST_SHARED_STATE_NAME)
+        {
             let test_name = &test_function_decl.name;
             bail!("Invalid test argument(s) for test: {test_name}.")
-        }?;
+        }
+
+        let pass_condition = match test_args.get(TEST_SHOULD_REVERT_NAME) {
+            Some(args) => {
+                let expected_revert_code = args
+                    .as_ref()
+                    .map(|arg| {
+                        let arg_str = arg.replace('"', "");
+                        arg_str.parse::<u64>()
+                    })
+                    .transpose()?;
+                TestPassCondition::ShouldRevert(expected_revert_code)
+            }
+            None => TestPassCondition::ShouldNotRevert,
+        };
+        let shared_state = test_args.contains_key(TEST_SHARED_STATE_NAME);
 
         let file_path = Arc::new(
             engines.se().get_path(
@@ -2018,6 +2824,7 @@ impl PkgTestEntry {
         );
         Ok(Self {
             pass_condition,
+            shared_state,
             span,
             file_path,
         })
@@ -2045,7 +2852,12 @@ fn build_profile_from_opts(
         time_phases,
         metrics_outfile,
         tests,
+        gas_report,
         error_on_warnings,
+        lints,
+        pgo_profile,
+        inline_budget,
+        no_dse_storage,
         experimental,
         ..
     } = build_options;
@@ -2092,14 +2904,25 @@ fn build_profile_from_opts(
     profile.print_ir |= print.ir;
     profile.print_finalized_asm |= print.finalized_asm;
     profile.print_intermediate_asm |= print.intermediate_asm;
+    profile.print_asm_stats |= print.asm_stats;
+    profile.verbose_types |= print.verbose_types;
     profile.terse |= pkg.terse;
     profile.time_phases |= time_phases;
     if profile.metrics_outfile.is_none() {
         profile.metrics_outfile = metrics_outfile.clone();
     }
     profile.include_tests |= tests;
+    profile.gas_report |= gas_report;
     profile.json_abi_with_callpaths |= pkg.json_abi_with_callpaths;
     profile.error_on_warnings |= error_on_warnings;
+    profile.lints.extend(lints.clone());
+    if let Some(pgo_profile) = pgo_profile {
+        profile.pgo_profile = Some(std::env::current_dir()?.join(pgo_profile));
+    }
+    if inline_budget.is_some() {
+        profile.inline_budget = *inline_budget;
+    }
+    profile.dse_storage &= !no_dse_storage;
     profile.experimental = experimental.clone();
 
     Ok((selected_build_profile.to_string(), profile))
@@ -2118,6 +2941,9 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         minify,
         binary_outfile,
         debug_outfile,
+        json_artifact_outfile,
+        size_profile,
+        gas_report,
         pkg,
         build_target,
         member_filter,
@@ -2159,6 +2985,7 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
 
     // Build it!
     let mut built_workspace = Vec::new();
+    let mut artifacts = Vec::new();
     let build_start = std::time::Instant::now();
     let built_packages = build(&build_plan, *build_target, &build_profile, &outputs)?;
     let output_dir = pkg.output_directory.as_ref().map(PathBuf::from);
@@ -2181,10 +3008,33 @@ pub fn build_with_options(build_options: BuildOpts) -> Result<Built> {
         if let Some(outfile) = &debug_outfile {
             built_package.write_debug_info(outfile.as_ref())?;
         }
-        built_package.write_output(minify.clone(), &pkg_manifest.project.name, &output_dir)?;
+        let mut pkg_artifacts =
+            built_package.write_output(minify.clone(), &pkg_manifest.project.name, &output_dir)?;
+        pkg_artifacts.source_map = debug_outfile.as_ref().map(PathBuf::from);
+        if *size_profile {
+            built_package.size_profile.print();
+            let size_profile_stem = format!("{}-size-profile", pkg_manifest.project.name);
+            let size_profile_path = output_dir.join(size_profile_stem).with_extension("json");
+            built_package.write_size_profile(&size_profile_path)?;
+            pkg_artifacts.size_profile = Some(size_profile_path);
+        }
+        if *gas_report {
+            print_gas_report(&built_package.bytecode.entries);
+        }
+        artifacts.push(pkg_artifacts);
         built_workspace.push(Arc::new(built_package));
     }
 
+    if let Some(outfile) = &json_artifact_outfile {
+        let build_artifacts = BuildArtifacts {
+            profile: profile_name,
+            overflow_checks: build_profile.overflow_checks,
+            packages: artifacts,
+        };
+        let json = serde_json::to_string_pretty(&build_artifacts)?;
+        fs::write(outfile, json)?;
+    }
+
     match curr_manifest {
         Some(pkg_manifest) => {
             let built_pkg = built_workspace
@@ -2267,8 +3117,10 @@ pub fn build(
         .flat_map(|output_node| plan.node_deps(*output_node))
         .collect();
 
-    let engines = Engines::default();
+    let mut engines = Engines::default();
+    engines.set_verbose_types(profile.verbose_types);
     let include_tests = profile.include_tests;
+    let mut suppressed_warnings: BTreeMap<String, usize> = BTreeMap::new();
 
     // This is the Contract ID of the current contract being compiled.
     // We will need this for `forc test`.
@@ -2284,7 +3136,12 @@ pub fn build(
         let mut source_map = SourceMap::new();
         let pkg = &plan.graph()[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
+        validate_implemented_interfaces(plan, node, manifest)?;
         let program_ty = manifest.program_type().ok();
+        let profile = &BuildProfile {
+            experimental: experimental_flags(plan, node, profile),
+            ..profile.clone()
+        };
 
         print_compiling(
             program_ty.as_ref(),
@@ -2428,6 +3285,9 @@ pub fn build(
                 serde_json::to_string(&compiled.metrics).expect("JSON serialization failed");
             fs::write(path, metrics_json)?;
         }
+        for (lint, count) in compiled.suppressed_warnings {
+            *suppressed_warnings.entry(lint).or_default() += count;
+        }
 
         if let TreeType::Library = compiled.tree_type {
             let mut namespace = namespace::Module::from(compiled.namespace);
@@ -2445,11 +3305,16 @@ pub fn build(
             descriptor,
             program_abi: compiled.program_abi,
             storage_slots: compiled.storage_slots,
+            field_storage_slots: compiled.field_storage_slots,
+            enum_discriminants: compiled.enum_discriminants,
             source_map: compiled.source_map,
             tree_type: compiled.tree_type,
             bytecode: compiled.bytecode,
             warnings: compiled.warnings,
+            size_profile: compiled.size_profile,
             bytecode_without_tests,
+            eliminated_functions: compiled.eliminated_functions,
+            entry_functions: compiled.entry_functions,
         };
 
         if outputs.contains(&node) {
@@ -2457,9 +3322,44 @@ pub fn build(
         }
     }
 
+    print_suppressed_warnings(&suppressed_warnings);
+
     Ok(built_packages)
 }
 
+/// Adds a `discriminant` to each of the components of the enum type declarations that have
+/// explicit discriminants. The components are in the same order as the variants.
+fn add_enum_discriminants(
+    json_abi: &mut serde_json::Value,
+    enum_discriminants: &BTreeMap<String, Vec<u64>>,
+) {
+    let Some(types) = json_abi
+        .get_mut("types")
+        .and_then(serde_json::Value::as_array_mut)
+    else {
+        return;
+    };
+    for type_decl in types {
+        let discriminants = type_decl
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|type_field| enum_discriminants.get(type_field));
+        let (Some(discriminants), Some(components)) = (
+            discriminants,
+            type_decl
+                .get_mut("components")
+                .and_then(serde_json::Value::as_array_mut),
+        ) else {
+            continue;
+        };
+        for (component, discriminant) in components.iter_mut().zip(discriminants) {
+            if let Some(component) = component.as_object_mut() {
+                component.insert("discriminant".to_string(), (*discriminant).into());
+            }
+        }
+    }
+}
+
 /// Standardize the JSON ABI data structure by eliminating duplicate types. This is an iterative
 /// process because every time two types are merged, new opportunities for more merging arise.
 fn standardize_json_abi_types(json_abi_program: &mut program_abi::ProgramABI) {
@@ -2600,6 +3500,31 @@ pub fn check(
     include_tests: bool,
     engines: &Engines,
     retrigger_compilation: Option<Arc<AtomicBool>>,
+) -> anyhow::Result<Vec<(Option<Programs>, Handler)>> {
+    check_with_dependencies(
+        plan,
+        build_target,
+        terse_mode,
+        include_tests,
+        engines,
+        retrigger_compilation,
+        &[],
+    )
+}
+
+/// Like [check], but reuses the given programs for the first packages in the compilation order
+/// rather than compiling them again.
+///
+/// The programs must have been type checked with the given `engines`, or with the engines they
+/// were cloned from, for the same build target and tests inclusion.
+pub fn check_with_dependencies(
+    plan: &BuildPlan,
+    build_target: BuildTarget,
+    terse_mode: bool,
+    include_tests: bool,
+    engines: &Engines,
+    retrigger_compilation: Option<Arc<AtomicBool>>,
+    dependencies: &[Programs],
 ) -> anyhow::Result<Vec<(Option<Programs>, Handler)>> {
     let mut lib_namespace_map = Default::default();
     let mut source_map = SourceMap::new();
@@ -2623,48 +3548,57 @@ pub fn check(
         let contract_id_value =
             (idx == plan.compilation_order.len() - 1).then(|| DUMMY_CONTRACT_ID.to_string());
 
-        let dep_namespace = dependency_namespace(
-            &lib_namespace_map,
-            &compiled_contract_deps,
-            &plan.graph,
-            node,
-            engines,
-            contract_id_value,
-        )
-        .expect("failed to create dependency namespace");
-
-        let profile = BuildProfile {
-            terse: terse_mode,
-            ..BuildProfile::debug()
-        };
+        let handler = Handler::default();
+        let programs_res = match dependencies.get(idx) {
+            Some(programs) => Ok(programs.clone()),
+            None => {
+                let dep_namespace = dependency_namespace(
+                    &lib_namespace_map,
+                    &compiled_contract_deps,
+                    &plan.graph,
+                    node,
+                    engines,
+                    contract_id_value,
+                )
+                .expect("failed to create dependency namespace");
 
-        let build_config = sway_build_config(
-            manifest.dir(),
-            &manifest.entry_path(),
-            build_target,
-            &profile,
-        )?
-        .with_include_tests(include_tests);
+                let mut profile = BuildProfile {
+                    terse: terse_mode,
+                    ..BuildProfile::debug()
+                };
+                profile.experimental = experimental_flags(plan, node, &profile);
+
+                let build_config = sway_build_config(
+                    manifest.dir(),
+                    &manifest.entry_path(),
+                    build_target,
+                    &profile,
+                )?
+                .with_include_tests(include_tests)
+                .with_incremental(true);
+
+                let input = manifest.entry_string()?;
+                let programs_res = sway_core::compile_to_ast(
+                    &handler,
+                    engines,
+                    input,
+                    dep_namespace,
+                    Some(&build_config),
+                    &pkg.name,
+                    retrigger_compilation.clone(),
+                );
 
-        let input = manifest.entry_string()?;
-        let handler = Handler::default();
-        let programs_res = sway_core::compile_to_ast(
-            &handler,
-            engines,
-            input,
-            dep_namespace,
-            Some(&build_config),
-            &pkg.name,
-            retrigger_compilation.clone(),
-        );
+                if retrigger_compilation
+                    .as_ref()
+                    .map(|b| b.load(std::sync::atomic::Ordering::SeqCst))
+                    .unwrap_or(false)
+                {
+                    bail!("compilation was retriggered")
+                }
 
-        if retrigger_compilation
-            .as_ref()
-            .map(|b| b.load(std::sync::atomic::Ordering::SeqCst))
-            .unwrap_or(false)
-        {
-            bail!("compilation was retriggered")
-        }
+                programs_res
+            }
+        };
 
         let programs = match programs_res.as_ref() {
             Ok(programs) => programs,
@@ -2750,6 +3684,7 @@ pub fn fuel_core_not_running(node_url: &str) -> anyhow::Error {
 mod test {
     use super::*;
     use regex::Regex;
+    use sway_core::ir_generation::storage::STORAGE_SLOTS_SCHEMA_VERSION;
 
     fn setup_build_plan() -> BuildPlan {
         let current_dir = env!("CARGO_MANIFEST_DIR");
@@ -2812,4 +3747,128 @@ mod test {
 "#;
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_declared_fn_name() {
+        let span = |src: &str| Span::from_string(src.to_string());
+        assert_eq!(
+            declared_fn_name(&span("fn foo(x: u64) -> u64 {\n    x\n}")),
+            Some("foo".to_string())
+        );
+        assert_eq!(
+            declared_fn_name(&span(
+                "/// Calls fn bar.\n#[storage(read)]\npub fn baz_2() {}"
+            )),
+            Some("baz_2".to_string())
+        );
+        assert_eq!(declared_fn_name(&span("struct S {}")), None);
+    }
+
+    #[test]
+    fn test_json_artifacts() {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("test/src/e2e_vm_tests/test_programs/should_pass/forc/interface_packages/token_contract");
+        let output_dir = std::env::temp_dir().join("forc-pkg-test-json-artifacts");
+        let json_artifact_path = output_dir.join("artifacts.json");
+        let build_options = BuildOpts {
+            pkg: PkgOpts {
+                path: Some(manifest_dir.display().to_string()),
+                output_directory: Some(output_dir.display().to_string()),
+                ..Default::default()
+            },
+            json_artifact_outfile: Some(json_artifact_path.display().to_string()),
+            ..Default::default()
+        };
+        let built = build_with_options(build_options)
+            .unwrap()
+            .expect_pkg()
+            .unwrap();
+
+        let json = fs::read_to_string(&json_artifact_path).unwrap();
+        let artifacts: BuildArtifacts = serde_json::from_str(&json).unwrap();
+        assert_eq!(artifacts.profile, BuildProfile::DEBUG);
+        let [pkg] = &artifacts.packages[..] else {
+            panic!("expected a single package, found {:?}", artifacts.packages);
+        };
+        assert_eq!(pkg.name, "token_contract");
+        assert_eq!(pkg.program_type, "contract");
+        assert_eq!(pkg.output_directory, output_dir);
+
+        let bytecode = &built.bytecode.bytes;
+        assert_eq!(pkg.bytecode.path, output_dir.join("token_contract.bin"));
+        assert_eq!(&fs::read(&pkg.bytecode.path).unwrap(), bytecode);
+        assert_eq!(
+            pkg.bytecode.hash,
+            format!("0x{}", fuel_crypto::Hasher::hash(bytecode))
+        );
+        assert_eq!(pkg.bytecode.size_in_bytes, bytecode.len());
+
+        assert_eq!(pkg.abi, Some(output_dir.join("token_contract-abi.json")));
+        assert_eq!(
+            pkg.storage_slots,
+            Some(output_dir.join("token_contract-storage_slots.json"))
+        );
+        for path in pkg.abi.iter().chain(&pkg.storage_slots) {
+            assert!(path.exists(), "{} was not written", path.display());
+        }
+        // The storage slots are written as a plain list of slots, as expected by the SDKs, while
+        // the versioned schema along with the fields is written to a separate artifact.
+        let storage_slots = fs::read_to_string(pkg.storage_slots.as_ref().unwrap()).unwrap();
+        let storage_slots: Vec<StorageSlot> = serde_json::from_str(&storage_slots).unwrap();
+        assert_eq!(storage_slots, built.storage_slots);
+        assert_eq!(
+            pkg.storage_fields,
+            Some(output_dir.join("token_contract-storage_fields.json"))
+        );
+        let storage_fields = fs::read_to_string(pkg.storage_fields.as_ref().unwrap()).unwrap();
+        let storage_fields: VersionedStorageSlots = serde_json::from_str(&storage_fields).unwrap();
+        assert_eq!(storage_fields.version, STORAGE_SLOTS_SCHEMA_VERSION);
+        assert_eq!(storage_fields.storage_slots(), built.storage_slots);
+        assert_eq!(pkg.source_map, None);
+        assert_eq!(pkg.size_profile, None);
+        assert_eq!(pkg.size_report, built.size_profile);
+        assert!(!pkg.size_report.functions.is_empty());
+        let mut entry_functions: Vec<_> = pkg
+            .entry_functions
+            .iter()
+            .map(|entry_fn| entry_fn.name.as_str())
+            .collect();
+        entry_functions.sort();
+        assert_eq!(entry_functions, ["mint", "owner", "supply"]);
+
+        fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn test_entry_function_json() {
+        let entry_fn = EntryFunction {
+            name: "set".to_string(),
+            selector: Some("0x1b2d3a4f".to_string()),
+            parameters: vec![EntryFunctionParameter {
+                name: "value".to_string(),
+                type_name: "u64".to_string(),
+            }],
+            return_type: "()".to_string(),
+            storage: StorageAccess::ReadWrite,
+            payable: true,
+        };
+        let json = serde_json::to_value(&entry_fn).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "set",
+                "selector": "0x1b2d3a4f",
+                "parameters": [{ "name": "value", "type": "u64" }],
+                "return_type": "()",
+                "storage": "read_write",
+                "payable": true,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<EntryFunction>(json).unwrap(),
+            entry_fn
+        );
+    }
 }
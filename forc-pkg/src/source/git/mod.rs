@@ -550,6 +550,49 @@ pub fn fetch(fetch_id: u64, name: &str, pinned: &Pinned) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Lists the tags of the remote repository and returns the greatest tag that is a valid semantic
+/// version, allowing for a leading `v` (e.g. `v0.49.1`).
+///
+/// Returns `None` if the repository has no tags that are semantic versions.
+pub fn latest_semver_tag(repo: &Url) -> Result<Option<String>> {
+    let latest_tag = semver_tags(repo)?
+        .into_iter()
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag);
+    Ok(latest_tag)
+}
+
+/// Like [latest_semver_tag], but only considers the tags whose versions match `req`.
+pub fn latest_matching_semver_tag(repo: &Url, req: &semver::VersionReq) -> Result<Option<String>> {
+    let latest_tag = semver_tags(repo)?
+        .into_iter()
+        .filter(|(version, _)| req.matches(version))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag);
+    Ok(latest_tag)
+}
+
+/// Lists the tags of the remote repository that are semantic versions, along with their versions.
+fn semver_tags(repo: &Url) -> Result<Vec<(semver::Version, String)>> {
+    let mut remote = git2::Remote::create_detached(repo.to_string())?;
+    remote
+        .connect(git2::Direction::Fetch)
+        .with_context(|| format!("failed to connect to {repo}"))?;
+    let tags = remote
+        .list()?
+        .iter()
+        .filter_map(|head| head.name().strip_prefix("refs/tags/"))
+        // Skip the peeled entries of annotated tags.
+        .filter(|tag| !tag.ends_with("^{}"))
+        .filter_map(|tag| {
+            semver::Version::parse(tag.trim_start_matches('v'))
+                .ok()
+                .map(|version| (version, tag.to_string()))
+        })
+        .collect();
+    Ok(tags)
+}
+
 /// Search local checkout dir for git sources, for non-branch git references tries to find the
 /// exact match. For branch references, tries to find the most recent repo present locally with the given repo
 pub(crate) fn search_source_locally(
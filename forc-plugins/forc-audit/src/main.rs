@@ -0,0 +1,124 @@
+//! A `forc` plugin for auditing Sway code for common security issues.
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use forc_pkg::{self as pkg, manifest::ManifestFile};
+use forc_tracing::{init_tracing_subscriber, println_error, println_green};
+use forc_util::{print_on_failure, print_warnings};
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::PathBuf,
+};
+use sway_core::{
+    audit::{self, AuditCheck},
+    BuildTarget, Engines,
+};
+use tracing::info;
+
+forc_util::cli_examples! {
+    [ Audit the package in the current directory => audit "" => ".*could not find `Forc.toml`.*" ]
+    [ Audit a package without checking for unbounded loops => audit "--path ../contract -A unbounded_loop" => ".*could not find `Forc.toml`.*" ]
+    [ List the available checks => audit "--list" ]
+}
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "forc-audit",
+    about = "Forc plugin for auditing Sway code for common security issues.",
+    after_help = help(),
+    version
+)]
+pub struct App {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Skip the given check.
+    #[clap(short = 'A', long = "allow", value_name = "CHECK")]
+    pub allow: Vec<AuditCheck>,
+    /// List the available checks.
+    #[clap(long)]
+    pub list: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error.
+    #[clap(long)]
+    pub locked: bool,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+fn main() {
+    init_tracing_subscriber(Default::default());
+    let app = App::parse();
+    if let Err(err) = run(app) {
+        println_error(&format!("{}", err));
+        std::process::exit(1);
+    }
+}
+
+fn run(app: App) -> Result<()> {
+    if app.list {
+        for check in AuditCheck::ALL {
+            info!("{check}");
+        }
+        return Ok(());
+    }
+
+    let checks: BTreeSet<_> = AuditCheck::ALL
+        .iter()
+        .copied()
+        .filter(|check| !app.allow.contains(check))
+        .collect();
+
+    let dir = match app.path.as_ref() {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&dir)?;
+    let member_manifests = manifest.member_manifests()?;
+    let lock_path = manifest.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        app.locked,
+        app.offline,
+        Default::default(),
+    )?;
+
+    let engines = Engines::default();
+    let results = pkg::check(&plan, BuildTarget::default(), false, false, &engines, None)?;
+
+    // Only the members of the workspace are audited, not their dependencies.
+    let members: HashSet<_> = plan.member_nodes().collect();
+    let mut findings = 0;
+    for (node, (programs, handler)) in plan.compilation_order().iter().zip(results) {
+        let pkg = &plan.graph()[*node];
+        let programs = match programs {
+            Some(programs) if !handler.has_errors() => programs,
+            _ => {
+                let (errors, warnings) = handler.consume();
+                print_on_failure(engines.se(), false, &warnings, &errors, false);
+                bail!("Failed to compile {}", pkg.name);
+            }
+        };
+        if !members.contains(node) {
+            continue;
+        }
+        let warnings = audit::audit(&programs.lexed, &checks);
+        findings += warnings.len();
+        print_warnings(
+            engines.se(),
+            false,
+            &pkg.name,
+            &warnings,
+            &programs.lexed.kind,
+        );
+    }
+
+    if findings > 0 {
+        bail!("Audit found {findings} potential issue(s)");
+    }
+    println_green("Audit finished, no issues found.");
+    Ok(())
+}
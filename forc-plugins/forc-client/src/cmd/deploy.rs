@@ -78,6 +78,14 @@ pub struct Command {
     /// ]
     #[clap(long, verbatim_doc_comment, name = "JSON_FILE_PATH")]
     pub override_storage_slots: Option<String>,
+    /// Arguments of the constructor of the contract, i.e. of its method marked `#[constructor]`.
+    ///
+    /// The constructor is called in a transaction submitted right after the contract is
+    /// deployed, as the transaction deploying a contract cannot execute code.
+    ///
+    /// Example: `forc deploy --constructor-args 42 --constructor-args true`
+    #[clap(long)]
+    pub constructor_args: Option<Vec<String>>,
 
     #[clap(long)]
     pub experimental_new_encoding: bool,
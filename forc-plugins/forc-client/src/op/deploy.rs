@@ -1,7 +1,8 @@
 use crate::{
     cmd,
     util::{
-        gas::get_gas_price,
+        constructor::ConstructorCall,
+        gas::{get_gas_price, get_gas_used},
         node_url::get_node_url,
         pkg::built_pkgs,
         tx::{TransactionBuilderExt, WalletSelectionMode, TX_SUBMIT_TIMEOUT_MS},
@@ -231,6 +232,13 @@ pub async fn deploy_pkg(
     let state_root = Contract::initial_state_root(storage_slots.iter());
     let contract_id = contract.id(&salt, &root, &state_root);
 
+    // Check the constructor arguments before deploying, so that invalid ones are rejected before
+    // any transaction is submitted.
+    let constructor = ConstructorCall::from_built_pkg(
+        compiled,
+        command.constructor_args.as_deref().unwrap_or_default(),
+    )?;
+
     let wallet_mode = if command.manual_signing {
         WalletSelectionMode::Manual
     } else {
@@ -311,9 +319,82 @@ pub async fn deploy_pkg(
             &contract_id
         )
     })??;
+
+    if let Some(constructor) = constructor {
+        call_constructor(command, &node_url, contract_id, &constructor).await?;
+    }
+
     Ok(DeployedContract { id: contract_id })
 }
 
+/// Calls the constructor of a freshly deployed contract.
+async fn call_constructor(
+    command: &cmd::Deploy,
+    node_url: &str,
+    contract_id: ContractId,
+    constructor: &ConstructorCall,
+) -> Result<()> {
+    let client = FuelClient::new(node_url)?;
+    let provider = Provider::connect(node_url).await?;
+
+    let wallet_mode = if command.manual_signing {
+        WalletSelectionMode::Manual
+    } else {
+        WalletSelectionMode::ForcWallet
+    };
+
+    let mut tb =
+        TransactionBuilder::script(constructor.script(), constructor.script_data(contract_id));
+    tb.gas_price(get_gas_price(&command.gas, client.node_info().await?))
+        .maturity(command.maturity.maturity.into())
+        .add_contracts(vec![contract_id]);
+    let script_gas_limit = match command.gas.script_gas_limit {
+        Some(script_gas_limit) => script_gas_limit,
+        None => get_gas_used(tb.clone().finalize_without_signature_inner(), &provider).await?,
+    };
+    tb.script_gas_limit(script_gas_limit);
+
+    let tx = tb
+        .finalize_signed(
+            provider,
+            command.default_signer || command.unsigned,
+            command.signing_key,
+            wallet_mode,
+        )
+        .await?;
+    let tx = Transaction::from(tx);
+
+    let constructor_request = client.submit_and_await_commit(&tx).map(|res| match res {
+        Ok(TransactionStatus::Success { .. }) => {
+            info!("Constructor {} called.", constructor.name);
+            Ok(())
+        }
+        Ok(TransactionStatus::Submitted { .. }) => {
+            bail!(
+                "call to the constructor of contract {} timed out",
+                &contract_id
+            )
+        }
+        Ok(e) => bail!(
+            "call to the constructor of contract {} failed due to an error: {:?}",
+            &contract_id,
+            e
+        ),
+        Err(e) => bail!("{e}"),
+    });
+    tokio::time::timeout(
+        Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
+        constructor_request,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "Timed out waiting for the constructor of contract {} to be called. The transaction may have been dropped.",
+            &contract_id
+        )
+    })?
+}
+
 fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
     pkg::BuildOpts {
         pkg: pkg::PkgOpts {
@@ -331,8 +412,10 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
             dca_graph_url_format: cmd.print.dca_graph_url_format.clone(),
             finalized_asm: cmd.print.finalized_asm,
             intermediate_asm: cmd.print.intermediate_asm,
+            asm_stats: cmd.print.asm_stats,
             ir: cmd.print.ir,
             reverse_order: cmd.print.reverse_order,
+            verbose_types: cmd.print.verbose_types,
         },
         time_phases: cmd.print.time_phases,
         metrics_outfile: cmd.print.metrics_outfile.clone(),
@@ -343,13 +426,21 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
         build_profile: cmd.build_profile.build_profile.clone(),
         release: cmd.build_profile.release,
         error_on_warnings: cmd.build_profile.error_on_warnings,
+        lints: cmd.build_profile.lints(),
+        pgo_profile: cmd.build_profile.pgo_profile.clone(),
+        inline_budget: cmd.build_profile.inline_budget,
+        no_dse_storage: cmd.build_profile.no_dse_storage,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file.clone(),
+        json_artifact_outfile: cmd.build_output.json_artifact.clone(),
+        size_profile: cmd.build_output.size_profile,
+        gas_report: cmd.build_output.gas_report,
         build_target: BuildTarget::default(),
         tests: false,
         member_filter: pkg::MemberFilter::only_contracts(),
         experimental: ExperimentalFlags {
             new_encoding: cmd.experimental_new_encoding,
+            ..Default::default()
         },
     }
 }
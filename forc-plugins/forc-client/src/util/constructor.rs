@@ -0,0 +1,119 @@
+use crate::util::encode::{Token, Type};
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::BuiltPackage;
+use fuel_abi_types::abi::full_program::FullProgramABI;
+use fuel_tx::ContractId;
+use fuel_vm::fuel_asm::{op, GTFArgs, RegId};
+use fuels_core::codec::ABIEncoder;
+
+/// The name of the attribute marking the constructor of a contract.
+const CONSTRUCTOR_ATTRIBUTE: &str = "constructor";
+
+/// The size of the call frame parameters read by the `CALL` instruction: the contract ID
+/// followed by the function selector and the argument word.
+const CALL_PARAMS_SIZE: u16 = 32 + 8 + 8;
+
+/// A call to the constructor of a contract, submitted right after the contract is deployed.
+#[derive(Debug)]
+pub(crate) struct ConstructorCall {
+    pub(crate) name: String,
+    selector: [u8; 4],
+    encoded_args: Vec<u8>,
+    single_value_type_arg: bool,
+}
+
+impl ConstructorCall {
+    /// Prepares the call to the constructor of the given contract with the given arguments.
+    ///
+    /// Returns `None` if the contract has no constructor, and an error if arguments are provided
+    /// for a contract without a constructor, or if they do not match its parameters.
+    pub(crate) fn from_built_pkg(compiled: &BuiltPackage, args: &[String]) -> Result<Option<Self>> {
+        let minify_json_abi = true;
+        let json_abi = compiled
+            .json_abi_string(minify_json_abi)?
+            .ok_or_else(|| anyhow!("Missing json abi string"))?;
+        let full_abi = FullProgramABI::from_json_abi(&json_abi)?;
+        let Some(constructor) = full_abi.functions.iter().find(|function| {
+            function
+                .attributes()
+                .iter()
+                .any(|attribute| attribute.name == CONSTRUCTOR_ATTRIBUTE)
+        }) else {
+            if !args.is_empty() {
+                bail!("constructor arguments provided, but the contract has no constructor");
+            }
+            return Ok(None);
+        };
+
+        let name = constructor.name().to_string();
+        let arg_types = constructor
+            .inputs()
+            .iter()
+            .map(Type::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let expected_arg_count = arg_types.len();
+        let provided_arg_count = args.len();
+        if expected_arg_count != provided_arg_count {
+            bail!(
+                "constructor {name} takes {expected_arg_count} arguments, {provided_arg_count} provided"
+            );
+        }
+        let tokens = arg_types
+            .iter()
+            .zip(args.iter())
+            .map(|(ty, val)| Token::from_type_and_value(ty, val).map(|token| token.0))
+            .collect::<Result<Vec<_>>>()?;
+        let encoded_args = ABIEncoder::encode(tokens.as_slice())?.resolve(0);
+
+        let selector = compiled
+            .bytecode
+            .entries
+            .iter()
+            .find(|entry| entry.finalized.fn_name == name)
+            .and_then(|entry| entry.finalized.selector)
+            .ok_or_else(|| anyhow!("unable to find the function selector of constructor {name}"))?;
+        let single_value_type_arg = matches!(
+            arg_types.as_slice(),
+            [Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::Bool]
+        );
+
+        Ok(Some(Self {
+            name,
+            selector,
+            encoded_args,
+            single_value_type_arg,
+        }))
+    }
+
+    /// The script calling the constructor of the contract whose call parameters are at the start
+    /// of the script data.
+    pub(crate) fn script(&self) -> Vec<u8> {
+        let params = RegId::new(0x10);
+        let args = RegId::new(0x11);
+        let mut instructions = vec![op::gtf_args(params, RegId::ZERO, GTFArgs::ScriptData)];
+        if !self.single_value_type_arg {
+            // The argument word is a pointer to the encoded arguments following the parameters.
+            instructions.push(op::addi(args, params, CALL_PARAMS_SIZE));
+            instructions.push(op::sw(params, args, 5));
+        }
+        instructions.push(op::call(params, RegId::ZERO, RegId::ZERO, RegId::CGAS));
+        instructions.push(op::ret(RegId::ONE));
+        instructions.into_iter().collect()
+    }
+
+    /// The script data of the call to the constructor of the given contract.
+    pub(crate) fn script_data(&self, contract_id: ContractId) -> Vec<u8> {
+        let mut script_data = contract_id.to_vec();
+        script_data.extend([0u8; 4]);
+        script_data.extend(self.selector);
+        if self.single_value_type_arg {
+            // A single value-type argument is passed in the argument word itself.
+            script_data.extend(&self.encoded_args);
+        } else {
+            // Reserve the argument word, written by the script.
+            script_data.extend([0u8; 8]);
+            script_data.extend(&self.encoded_args);
+        }
+        script_data
+    }
+}
@@ -1,5 +1,5 @@
 //! The command line interface for `forc doc`.
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use forc_pkg::source::IPFSNode;
 
 const ABOUT: &str = "Forc plugin for building a Sway package's documentation";
@@ -10,6 +10,7 @@ forc_util::cli_examples! {
     [ Build the docs for a project located in another path => doc "--manifest-path ../tests_project2" ]
     [ Build the docs for the current project exporting private types => doc "--document-private-items" ]
     [ Build the docs offline without downloading any dependency from the network => doc "--offline" ]
+    [ Build the documentation model as JSON rather than HTML => doc "--output json" ]
 }
 
 #[derive(Debug, Parser, Default)]
@@ -49,7 +50,21 @@ pub struct Command {
     /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
     #[clap(long)]
     pub ipfs_node: Option<IPFSNode>,
+    /// The format of the documentation.
+    ///
+    /// The JSON format writes the documentation model, i.e. the documented items along with
+    /// their signatures, docs, modules and trait implementations, to `doc.json` in the output
+    /// directory, rather than rendering it to HTML.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Html)]
+    pub output: OutputFormat,
 
     #[cfg(test)]
     pub(crate) doc_path: Option<String>,
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Html,
+    Json,
+}
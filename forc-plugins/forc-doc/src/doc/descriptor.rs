@@ -71,6 +71,7 @@ impl Descriptor {
                             item_context: ItemContext {
                                 context_opt: context,
                                 impl_traits: None,
+                                reexported_by: vec![],
                             },
                         },
                         raw_attributes: attrs_opt,
@@ -108,6 +109,7 @@ impl Descriptor {
                             item_context: ItemContext {
                                 context_opt: context,
                                 impl_traits: None,
+                                reexported_by: vec![],
                             },
                         },
                         raw_attributes: attrs_opt,
@@ -156,6 +158,7 @@ impl Descriptor {
                             item_context: ItemContext {
                                 context_opt: context,
                                 impl_traits: None,
+                                reexported_by: vec![],
                             },
                         },
                         raw_attributes: attrs_opt,
@@ -198,6 +201,7 @@ impl Descriptor {
                         item_context: ItemContext {
                             context_opt: context,
                             impl_traits: None,
+                            reexported_by: vec![],
                         },
                     },
                     raw_attributes: attrs_opt,
@@ -233,6 +237,7 @@ impl Descriptor {
                         item_context: ItemContext {
                             context_opt: context,
                             impl_traits: None,
+                            reexported_by: vec![],
                         },
                     },
                     raw_attributes: attrs_opt,
@@ -265,6 +270,7 @@ impl Descriptor {
                             item_context: ItemContext {
                                 context_opt: None,
                                 impl_traits: None,
+                                reexported_by: vec![],
                             },
                         },
                         raw_attributes: attrs_opt,
@@ -298,6 +304,7 @@ impl Descriptor {
                             item_context: ItemContext {
                                 context_opt: None,
                                 impl_traits: None,
+                                reexported_by: vec![],
                             },
                         },
                         raw_attributes: attrs_opt,
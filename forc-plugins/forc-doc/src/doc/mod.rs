@@ -13,7 +13,7 @@ use sway_core::{
     decl_engine::DeclEngine,
     language::ty::{TyAstNodeContent, TyDecl, TyImplTrait, TyModule, TyProgram, TySubmodule},
 };
-use sway_types::{BaseIdent, Spanned};
+use sway_types::{constants::PRELUDE, BaseIdent, Spanned};
 
 mod descriptor;
 pub mod module;
@@ -107,6 +107,32 @@ impl Documentation {
             }
         }
 
+        // Only the imports of preludes are re-exported, as dependent packages glob import them
+        // along with their imports.
+        let exported_items = namespace.root().exported_items(decl_engine);
+        for doc in &mut docs.0 {
+            let module_path = &doc.module_info.module_prefixes[1..];
+            doc.item_body.item_context.reexported_by = exported_items
+                .iter()
+                .filter(|item| {
+                    item.is_reexport()
+                        && item.name.as_str() == doc.item_header.item_name.as_str()
+                        && item.path().last().map(|name| name.as_str()) == Some(PRELUDE)
+                        && item
+                            .canonical_path()
+                            .iter()
+                            .map(|name| name.as_str())
+                            .eq(module_path.iter().map(String::as_str))
+                })
+                .map(|item| {
+                    std::iter::once(project_name)
+                        .chain(item.path().iter().map(|name| name.as_str()))
+                        .collect::<Vec<_>>()
+                        .join("::")
+                })
+                .collect();
+        }
+
         Ok(docs)
     }
     fn from_ty_module(
@@ -0,0 +1,216 @@
+//! Handles conversion of the [Documentation] into a JSON model that can be rendered by other tools.
+use crate::{
+    doc::{Document, Documentation},
+    render::{
+        item::context::{ContextType, DocImplTrait},
+        util::format::docstring::DocStrings,
+    },
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+use sway_core::language::ty::TyImplItem;
+use sway_types::Spanned;
+
+pub(crate) const JSON_FILE_NAME: &str = "doc.json";
+
+/// The version of the JSON model, bumped on breaking changes to its structure.
+const FORMAT_VERSION: u32 = 1;
+
+/// Writes the JSON model of the documentation to the doc folder.
+pub(crate) fn write_json(doc_path: &Path, docs: &Documentation) -> Result<()> {
+    let json = serde_json::to_string_pretty(&JsonDocumentation::from(docs))?;
+    Ok(fs::write(doc_path.join(JSON_FILE_NAME), json)?)
+}
+
+/// The documentation of all of the packages, keyed by package name.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct JsonDocumentation {
+    pub(crate) format_version: u32,
+    pub(crate) packages: BTreeMap<String, JsonPackage>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct JsonPackage {
+    /// The modules of the package containing documented items, in order of their paths.
+    pub(crate) modules: Vec<JsonModule>,
+    pub(crate) items: Vec<JsonItem>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct JsonModule {
+    /// The path of the module, starting with the package name.
+    pub(crate) path: Vec<String>,
+    /// The module level docs, rendered to HTML.
+    pub(crate) docs: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct JsonItem {
+    pub(crate) name: String,
+    /// The kind of the item, e.g. `struct` or `function`.
+    pub(crate) kind: String,
+    /// The path of the module declaring the item, starting with the package name.
+    pub(crate) module: Vec<String>,
+    /// The formatted declaration of the item, without the bodies of its functions.
+    pub(crate) signature: String,
+    /// The docs of the item, rendered to HTML.
+    pub(crate) docs: Option<String>,
+    /// The fields of a struct or storage declaration.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) fields: Vec<JsonMember>,
+    /// The variants of an enum.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) variants: Vec<JsonMember>,
+    /// The methods required by a trait or declared by an ABI.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) methods: Vec<JsonMember>,
+    /// The traits implemented for the item.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) impls: Vec<JsonImpl>,
+    /// The full paths of the preludes re-exporting the item, e.g. `std::prelude`.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) reexported_by: Vec<String>,
+}
+
+/// A field, variant or method of an item.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct JsonMember {
+    pub(crate) name: String,
+    /// The type of a field or variant, or the signature of a method.
+    pub(crate) signature: String,
+    pub(crate) docs: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct JsonImpl {
+    /// The path of the implemented trait.
+    pub(crate) r#trait: String,
+    /// The module of the `impl` block, starting with the package name.
+    pub(crate) module: Vec<String>,
+    pub(crate) methods: Vec<String>,
+}
+
+impl From<&Documentation> for JsonDocumentation {
+    fn from(docs: &Documentation) -> Self {
+        let mut packages: BTreeMap<String, JsonPackage> = BTreeMap::new();
+        for doc in docs.0.iter() {
+            let package = packages
+                .entry(doc.module_info.project_name().to_string())
+                .or_default();
+            if !package
+                .modules
+                .iter()
+                .any(|module| module.path == doc.module_info.module_prefixes)
+            {
+                package.modules.push(JsonModule {
+                    path: doc.module_info.module_prefixes.clone(),
+                    docs: doc.module_info.attributes.clone(),
+                });
+            }
+            package.items.push(JsonItem::from(doc));
+        }
+        for package in packages.values_mut() {
+            package.modules.sort_by(|a, b| a.path.cmp(&b.path));
+        }
+        Self {
+            format_version: FORMAT_VERSION,
+            packages,
+        }
+    }
+}
+
+impl From<&Document> for JsonItem {
+    fn from(doc: &Document) -> Self {
+        let mut item = Self {
+            name: doc.item_header.item_name.as_str().to_string(),
+            kind: doc.item_header.friendly_name.to_string(),
+            module: doc.module_info.module_prefixes.clone(),
+            signature: doc.item_body.code_str.clone(),
+            docs: doc.item_body.attrs_opt.clone(),
+            fields: vec![],
+            variants: vec![],
+            methods: vec![],
+            impls: vec![],
+            reexported_by: doc.item_body.item_context.reexported_by.clone(),
+        };
+        let docs = |attributes: &sway_core::transform::AttributesMap| {
+            (!attributes.is_empty()).then(|| attributes.to_html_string())
+        };
+        if let Some(context) = &doc.item_body.item_context.context_opt {
+            match &context.context_type {
+                ContextType::StructFields(fields) => {
+                    item.fields = fields
+                        .iter()
+                        .map(|field| JsonMember {
+                            name: field.name.as_str().to_string(),
+                            signature: field.type_argument.span.as_str().to_string(),
+                            docs: docs(&field.attributes),
+                        })
+                        .collect();
+                }
+                ContextType::StorageFields(fields) => {
+                    item.fields = fields
+                        .iter()
+                        .map(|field| JsonMember {
+                            name: field.name.as_str().to_string(),
+                            signature: field.type_argument.span.as_str().to_string(),
+                            docs: docs(&field.attributes),
+                        })
+                        .collect();
+                }
+                ContextType::EnumVariants(variants) => {
+                    item.variants = variants
+                        .iter()
+                        .map(|variant| JsonMember {
+                            name: variant.name.as_str().to_string(),
+                            signature: variant.type_argument.span.as_str().to_string(),
+                            docs: docs(&variant.attributes),
+                        })
+                        .collect();
+                }
+                ContextType::RequiredMethods(methods) => {
+                    item.methods = methods
+                        .iter()
+                        .map(|method| JsonMember {
+                            name: method.name.as_str().to_string(),
+                            signature: method.span().as_str().trim().to_string(),
+                            docs: docs(&method.attributes),
+                        })
+                        .collect();
+                }
+            }
+        }
+        if let Some(impl_traits) = &doc.item_body.item_context.impl_traits {
+            item.impls = impl_traits.iter().map(JsonImpl::from).collect();
+        }
+        item
+    }
+}
+
+impl From<&DocImplTrait> for JsonImpl {
+    fn from(doc_impl_trait: &DocImplTrait) -> Self {
+        let impl_trait = &doc_impl_trait.impl_trait;
+        let r#trait = match &doc_impl_trait.module_info_override {
+            Some(module_prefixes) => module_prefixes
+                .iter()
+                .map(String::as_str)
+                .chain(std::iter::once(impl_trait.trait_name.suffix.as_str()))
+                .collect::<Vec<_>>()
+                .join("::"),
+            None => impl_trait.trait_name.to_string(),
+        };
+        Self {
+            r#trait,
+            module: doc_impl_trait.impl_for_module.module_prefixes.clone(),
+            methods: impl_trait
+                .items
+                .iter()
+                .filter_map(|item| match item {
+                    TyImplItem::Fn(decl_ref) => Some(decl_ref.name().as_str().to_string()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
     doc::Documentation,
+    json::write_json,
     render::{constant::INDEX_FILENAME, RenderedDocumentation},
     search::write_search_index,
 };
 use anyhow::{bail, Result};
 use clap::Parser;
-use cli::Command;
+use cli::{Command, OutputFormat};
 use colored::*;
 use forc_pkg as pkg;
 use forc_util::default_output_directory;
@@ -20,6 +21,7 @@ use sway_core::{language::ty::TyProgram, BuildTarget, Engines};
 
 mod cli;
 mod doc;
+mod json;
 mod render;
 mod search;
 mod tests;
@@ -53,6 +55,9 @@ pub fn main() -> Result<()> {
     let build_instructions = Command::parse();
 
     let (doc_path, pkg_manifest) = compile_html(&build_instructions, &get_doc_dir)?;
+    if build_instructions.output == OutputFormat::Json {
+        return Ok(());
+    }
 
     // CSS, icons and logos
     static ASSETS_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/static.files");
@@ -105,6 +110,7 @@ fn build_docs(
     let Command {
         document_private_items,
         no_deps,
+        output,
         ..
     } = *build_instructions;
     let ProgramInfo {
@@ -127,13 +133,17 @@ fn build_docs(
         &ty_program,
         document_private_items,
     )?;
+    if output == OutputFormat::Json {
+        println!("    {}", "Finished".bold().yellow());
+        return Ok(raw_docs);
+    }
     let root_attributes =
         (!ty_program.root.attributes.is_empty()).then_some(ty_program.root.attributes);
     let forc_version = pkg_manifest
         .project
         .forc_version
         .as_ref()
-        .map(|ver| format!("Forc v{}.{}.{}", ver.major, ver.minor, ver.patch));
+        .map(|req| format!("Forc {req}"));
     // render docs to HTML
     let rendered_docs = RenderedDocumentation::from_raw_docs(
         raw_docs.clone(),
@@ -272,7 +282,10 @@ pub fn compile_html(
         };
         build_docs(program_info, &doc_path, build_instructions)?
     };
-    write_search_index(&doc_path, raw_docs)?;
+    match build_instructions.output {
+        OutputFormat::Html => write_search_index(&doc_path, raw_docs)?,
+        OutputFormat::Json => write_json(&doc_path, &raw_docs)?,
+    }
 
     Ok((doc_path, pkg_manifest.to_owned()))
 }
@@ -109,6 +109,7 @@ impl Renderable for ItemBody {
         let decl_ty = ty_decl.doc_name();
         let block_title = ty_decl.as_block_title();
         let sidebar = sidebar.render(render_plan.clone())?;
+        let reexported_by = item_context.reexported_by.clone();
         let item_context = (item_context.context_opt.is_some()
             || item_context.impl_traits.is_some())
         .then(|| -> Result<Box<dyn RenderBox>> { item_context.render(render_plan.clone()) });
@@ -154,6 +155,19 @@ impl Renderable for ItemBody {
                                     }
                                 }
                             }
+                            @ if !reexported_by.is_empty() {
+                                div(class="docblock") {
+                                    p {
+                                        : "Re-exported by ";
+                                        @ for (idx, path) in reexported_by.iter().enumerate() {
+                                            @ if idx > 0 {
+                                                : ", ";
+                                            }
+                                            code { : path; }
+                                        }
+                                    }
+                                }
+                            }
                             @ if item_context.is_some() {
                                 : item_context.unwrap();
                             }
@@ -34,7 +34,7 @@ use sway_core::language::ty::{
 #[derive(Clone, Debug)]
 pub(crate) struct Context {
     module_info: ModuleInfo,
-    context_type: ContextType,
+    pub(crate) context_type: ContextType,
 }
 impl Context {
     pub(crate) fn new(module_info: ModuleInfo, context_type: ContextType) -> Self {
@@ -272,6 +272,8 @@ pub(crate) struct ItemContext {
     pub(crate) context_opt: Option<Context>,
     /// The traits implemented for this type.
     pub(crate) impl_traits: Option<Vec<DocImplTrait>>,
+    /// The full paths of the preludes re-exporting this item, e.g. `std::prelude`.
+    pub(crate) reexported_by: Vec<String>,
     // TODO: All other Implementation types, eg
     // implementations on foreign types, method implementations, etc.
 }
@@ -108,6 +108,9 @@ pub(crate) fn render_type_anchor(
         TypeInfo::Boolean => Ok(box_html! {
             : "bool";
         }),
+        TypeInfo::Never => Ok(box_html! {
+            : "!";
+        }),
         TypeInfo::ContractCaller { abi_name, .. } => {
             // TODO: determine whether we should give a link to this
             if let AbiName::Known(name) = abi_name {
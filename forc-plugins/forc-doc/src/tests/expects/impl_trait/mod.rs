@@ -1,7 +1,8 @@
 #![cfg(test)]
 use crate::{
-    cli::Command,
+    cli::{Command, OutputFormat},
     compile_html,
+    json::{JsonDocumentation, JSON_FILE_NAME},
     tests::expects::{check_file, get_doc_dir},
 };
 use dir_indexer::get_relative_file_paths_set;
@@ -125,6 +126,47 @@ fn test_impl_traits_no_deps() {
     );
 }
 
+#[test]
+fn test_impl_traits_json() {
+    let doc_dir_name: &str = "impl_traits_json";
+    let project_name: &str = "impl_traits_clone";
+    let command = Command {
+        manifest_path: Some(format!("{}/{}", DATA_DIR, project_name)),
+        doc_path: Some(doc_dir_name.into()),
+        no_deps: true,
+        output: OutputFormat::Json,
+        ..Default::default()
+    };
+    let (doc_path, _) = compile_html(&command, &get_doc_dir).unwrap();
+    assert_file_tree(doc_dir_name, project_name, vec![JSON_FILE_NAME]);
+
+    let json = std::fs::read_to_string(doc_path.join(JSON_FILE_NAME)).unwrap();
+    let docs: JsonDocumentation = serde_json::from_str(&json).unwrap();
+    let package = &docs.packages[project_name];
+    let module_paths: Vec<_> = package
+        .modules
+        .iter()
+        .map(|module| module.path.join("::"))
+        .collect();
+    assert_eq!(
+        module_paths,
+        vec!["impl_traits_clone::bar", "impl_traits_clone::foo"]
+    );
+    let bar = package
+        .items
+        .iter()
+        .find(|item| item.name == "Bar")
+        .unwrap();
+    assert_eq!(bar.kind, "struct");
+    assert_eq!(bar.signature, "pub struct Bar {}");
+    let foo_impl = bar
+        .impls
+        .iter()
+        .find(|json_impl| json_impl.r#trait == "impl_traits_clone::foo::Foo")
+        .unwrap();
+    assert_eq!(foo_impl.methods, vec!["foo"]);
+}
+
 fn assert_index_html(doc_path: &Path, project_name: &str, expect: &Expect) {
     let path_to_file = PathBuf::from(format!("{}/{}", project_name, IMPL_FOR));
     check_file(doc_path, &path_to_file, expect);
@@ -2,21 +2,27 @@
 
 use anyhow::{bail, Result};
 use clap::Parser;
-use forc_pkg::{manifest::ManifestFile, WorkspaceManifestFile};
+use forc_pkg::{
+    manifest::{ManifestFile, PackageManifestFile},
+    WorkspaceManifestFile,
+};
 use prettydiff::{basic::DiffOp, diff_lines};
 use std::{
     default::Default,
     fs,
+    io::Read,
+    ops::Range,
     path::{Path, PathBuf},
+    str::FromStr,
     sync::Arc,
 };
 use taplo::formatter as taplo_fmt;
 use tracing::{debug, error, info};
 
 use forc_tracing::{init_tracing_subscriber, println_error, println_green, println_red};
-use sway_core::{BuildConfig, BuildTarget};
+use sway_core::{BuildConfig, BuildTarget, ExperimentalFlags};
 use sway_utils::{constants, find_parent_manifest_dir, get_sway_files, is_sway_file};
-use swayfmt::Formatter;
+use swayfmt::{Formatter, FormatterError};
 
 forc_util::cli_examples! {
     [ Run the formatter in check mode on the current directory => fmt "--check"]
@@ -25,6 +31,8 @@ forc_util::cli_examples! {
     [ Run formatter against a given file with short format => fmt "-f src/main.sw"]
     [ Run formatter against a given dir => fmt "--path ../tests/"]
     [ Run formatter against a given dir with short format => fmt "-p ../tests"]
+    [ Format the code read from stdin and write it to stdout => fmt "--stdin"]
+    [ Run formatter only on the items within lines 10 to 20 of a given file => fmt "--file src/main.sw --lines 10:20"]
 }
 
 #[derive(Debug, Parser)]
@@ -48,6 +56,72 @@ pub struct App {
     /// Formats a single .sw file with the default settings.
     /// If not specified, current working directory will be formatted using a Forc.toml configuration.
     pub file: Option<String>,
+    /// Formats the code read from stdin and writes it to stdout, instead of formatting files.
+    ///
+    /// In 'check' mode, nothing is written and the exit code tells if the code is formatted.
+    #[clap(long, conflicts_with = "file")]
+    pub stdin: bool,
+    /// Formats only the items within the given inclusive range of lines, e.g. `10:20`.
+    ///
+    /// Requires either `--file` or `--stdin`. Line numbers start from 1.
+    #[clap(long)]
+    pub lines: Option<LineRange>,
+    /// Accept the syntax of the experimental "new encoding" feature.
+    #[clap(long)]
+    pub experimental_new_encoding: bool,
+}
+
+/// An inclusive range of lines, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Returns the byte range of the lines within the source.
+    fn byte_range(&self, src: &str) -> Range<usize> {
+        let line_start = |line: usize| -> usize {
+            src.split_inclusive('\n')
+                .take(line)
+                .map(|line| line.len())
+                .sum()
+        };
+        line_start(self.start - 1)..line_start(self.end)
+    }
+}
+
+impl FromStr for LineRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid range of lines `{s}`, expected `<START>:<END>`");
+        let (start, end) = s.split_once(':').ok_or_else(err)?;
+        let start = start.trim().parse::<usize>().map_err(|_| err())?;
+        let end = end.trim().parse::<usize>().map_err(|_| err())?;
+        if start == 0 || start > end {
+            return Err(err());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+impl App {
+    /// Creates a [Formatter] using the `swayfmt.toml` in the directory or its parents, that
+    /// accepts the syntax of the enabled experimental features, and of those the package in the
+    /// directory opts into.
+    fn formatter(&self, dir: &Path) -> Result<Formatter> {
+        let mut experimental = ExperimentalFlags {
+            new_encoding: self.experimental_new_encoding,
+            ..Default::default()
+        };
+        if let Ok(manifest) = PackageManifestFile::from_dir(dir) {
+            for feature in &manifest.project.experimental {
+                experimental.enable(*feature);
+            }
+        }
+        Ok(Formatter::from_dir(dir)?.with_experimental(experimental))
+    }
 }
 
 fn main() {
@@ -67,7 +141,10 @@ fn run() -> Result<()> {
         None => std::env::current_dir()?,
     };
 
-    let mut formatter = Formatter::from_dir(&dir)?;
+    let mut formatter = app.formatter(&dir)?;
+    if app.stdin {
+        return format_stdin(&app, &mut formatter);
+    }
     if let Some(f) = app.file.as_ref() {
         let file_path = &PathBuf::from(f);
 
@@ -87,6 +164,10 @@ fn run() -> Result<()> {
         );
     };
 
+    if app.lines.is_some() {
+        bail!("A range of lines can only be formatted along with `--file` or `--stdin`");
+    }
+
     let manifest_file = forc_pkg::manifest::ManifestFile::from_dir(&dir)?;
     match manifest_file {
         ManifestFile::Workspace(ws) => {
@@ -162,7 +243,7 @@ fn format_file(
                 BuildTarget::default(),
             )
         });
-        match Formatter::format(formatter, file_content.clone(), build_config.as_ref()) {
+        match format_content(app, formatter, file_content.clone(), build_config.as_ref()) {
             Ok(formatted_content) => {
                 if app.check {
                     if *file_content != formatted_content {
@@ -192,10 +273,46 @@ fn format_file(
     bail!("Could not read file: {:?}", file)
 }
 
+/// Formats the code read from stdin and writes it to stdout.
+fn format_stdin(app: &App, formatter: &mut Formatter) -> Result<()> {
+    let mut content = String::new();
+    std::io::stdin().read_to_string(&mut content)?;
+    let formatted_content = format_content(app, formatter, Arc::from(content.as_str()), None)?;
+    if app.check {
+        if content != formatted_content {
+            display_file_diff(&content, &formatted_content)?;
+            bail!("Input contains formatting violations.");
+        }
+        return Ok(());
+    }
+    print!("{formatted_content}");
+    Ok(())
+}
+
+/// Formats the whole content, or only the items within the range of lines given with `--lines`.
+fn format_content(
+    app: &App,
+    formatter: &mut Formatter,
+    content: Arc<str>,
+    build_config: Option<&BuildConfig>,
+) -> Result<String, FormatterError> {
+    let Some(lines) = app.lines else {
+        return formatter.format(content, build_config);
+    };
+    let range = lines.byte_range(&content);
+    let formatted_ranges = formatter.format_range(content.clone(), range, build_config)?;
+    let mut formatted_content = content.to_string();
+    // Replace the last ranges first, so that the offsets of the preceding ones stay valid.
+    for formatted_range in formatted_ranges.into_iter().rev() {
+        formatted_content.replace_range(formatted_range.range, &formatted_range.formatted_code);
+    }
+    Ok(formatted_content)
+}
+
 /// Format the workspace at the given directory.
 fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &Path) -> Result<()> {
     let mut contains_edits = false;
-    let mut formatter = Formatter::from_dir(dir)?;
+    let mut formatter = app.formatter(dir)?;
     let mut members = vec![];
 
     for member_path in workspace.member_paths()? {
@@ -227,7 +344,7 @@ fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &P
             // if there is no swayfmt.toml in the sub directory because we still want
             // to use the swayfmt.toml at the workspace root (if any).
             // In order of priority: member > workspace > default.
-            formatter = Formatter::from_dir(&sub_dir)?;
+            formatter = app.formatter(&sub_dir)?;
         }
         format_pkg_at_dir(app, &sub_dir, &mut formatter)?;
     }
@@ -354,8 +471,21 @@ fn write_file_formatted(file: &Path, formatted_content: &str) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::taplo_fmt;
-    use std::default::Default;
+    use super::{taplo_fmt, LineRange};
+    use std::{default::Default, str::FromStr};
+
+    #[test]
+    fn test_line_range() {
+        let src = "library;\n\nfn foo() {}\nfn bar() {}\n";
+        let lines = LineRange::from_str("3:3").unwrap();
+        assert_eq!(lines, LineRange { start: 3, end: 3 });
+        assert_eq!(&src[lines.byte_range(src)], "fn foo() {}\n");
+        let lines = LineRange::from_str("2:10").unwrap();
+        assert_eq!(&src[lines.byte_range(src)], "\nfn foo() {}\nfn bar() {}\n");
+        assert!(LineRange::from_str("0:1").is_err());
+        assert!(LineRange::from_str("3:2").is_err());
+        assert!(LineRange::from_str("3").is_err());
+    }
 
     #[test]
     fn test_forc_indentation() {
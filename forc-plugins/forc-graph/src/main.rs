@@ -0,0 +1,487 @@
+//! A `forc` plugin for drawing the call graph and the storage accesses of Sway programs.
+
+use anyhow::{bail, Result};
+use clap::{Parser, ValueEnum};
+use forc_pkg::{self as pkg, manifest::ManifestFile};
+use forc_tracing::{init_tracing_subscriber, println_error};
+use forc_util::print_on_failure;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt::Write,
+    path::PathBuf,
+};
+use sway_core::{
+    call_graph::{CallGraph, StorageAccessKind},
+    BuildTarget, Engines,
+};
+
+forc_util::cli_examples! {
+    [ Draw the call graph and the storage accesses of the package in the current directory => graph "" => ".*could not find `Forc.toml`.*" ]
+    [ Draw the storage accesses of the entry points as a Mermaid diagram => graph "--storage --format mermaid --path ../contract" => ".*could not find `Forc.toml`.*" ]
+    [ Write the call graph to a file => graph "--calls --output calls.dot" => ".*could not find `Forc.toml`.*" ]
+}
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "forc-graph",
+    about = "Forc plugin for drawing the call graph and the storage accesses of Sway programs.",
+    after_help = help(),
+    version
+)]
+pub struct App {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Draw the functions, the calls between them and the calls to other contracts.
+    ///
+    /// When neither `--calls` nor `--storage` is specified, both are drawn.
+    #[clap(long)]
+    pub calls: bool,
+    /// Draw the storage fields read and written by each entry point, including through the
+    /// functions it calls.
+    ///
+    /// When neither `--calls` nor `--storage` is specified, both are drawn.
+    #[clap(long)]
+    pub storage: bool,
+    /// The format of the diagram.
+    #[clap(long, value_enum, default_value_t = Format::Dot)]
+    pub format: Format,
+    /// Write the diagram to the given file rather than to the standard output.
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error.
+    #[clap(long)]
+    pub locked: bool,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A Graphviz DOT graph.
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+fn main() {
+    init_tracing_subscriber(Default::default());
+    let app = App::parse();
+    if let Err(err) = run(app) {
+        println_error(&format!("{}", err));
+        std::process::exit(1);
+    }
+}
+
+fn run(app: App) -> Result<()> {
+    let (calls, storage) = if app.calls || app.storage {
+        (app.calls, app.storage)
+    } else {
+        (true, true)
+    };
+
+    let dir = match app.path.as_ref() {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&dir)?;
+    let member_manifests = manifest.member_manifests()?;
+    let lock_path = manifest.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        app.locked,
+        app.offline,
+        Default::default(),
+    )?;
+
+    let engines = Engines::default();
+    let results = pkg::check(&plan, BuildTarget::default(), false, false, &engines, None)?;
+
+    // Only the members of the workspace are drawn, not their dependencies.
+    let members: HashSet<_> = plan.member_nodes().collect();
+    let mut diagram = Diagram::default();
+    for (node, (programs, handler)) in plan.compilation_order().iter().zip(results) {
+        let pkg = &plan.graph()[*node];
+        let typed = match programs.as_ref().map(|programs| programs.typed.as_ref()) {
+            Some(Ok(typed)) if !handler.has_errors() => typed,
+            _ => {
+                let (errors, warnings) = handler.consume();
+                print_on_failure(engines.se(), false, &warnings, &errors, false);
+                bail!("Failed to compile {}", pkg.name);
+            }
+        };
+        if !members.contains(node) {
+            continue;
+        }
+        let call_graph = CallGraph::new(&engines, typed);
+        diagram.add_package(&pkg.name, &call_graph, calls, storage);
+    }
+
+    let rendered = match app.format {
+        Format::Dot => diagram.to_dot(),
+        Format::Mermaid => diagram.to_mermaid(),
+    };
+    match app.output {
+        Some(path) => std::fs::write(path, rendered)?,
+        None => print!("{rendered}"),
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NodeKind {
+    Entry,
+    Function,
+    Contract,
+    Storage,
+}
+
+struct Node {
+    id: String,
+    label: String,
+    kind: NodeKind,
+}
+
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+    dashed: bool,
+}
+
+struct Cluster {
+    id: String,
+    label: String,
+    nodes: Vec<Node>,
+}
+
+/// A diagram holding one cluster of nodes per package.
+#[derive(Default)]
+struct Diagram {
+    clusters: Vec<Cluster>,
+    edges: Vec<Edge>,
+}
+
+impl Diagram {
+    fn add_package(&mut self, name: &str, call_graph: &CallGraph, calls: bool, storage: bool) {
+        let prefix = sanitize(name);
+        let fn_id = |index: usize| format!("{prefix}_fn_{index}");
+        let mut nodes = vec![];
+
+        for (index, function) in call_graph.functions.iter().enumerate() {
+            if calls || function.is_entry {
+                nodes.push(Node {
+                    id: fn_id(index),
+                    label: function.name.clone(),
+                    kind: if function.is_entry {
+                        NodeKind::Entry
+                    } else {
+                        NodeKind::Function
+                    },
+                });
+            }
+        }
+
+        if calls {
+            let contract_calls: BTreeSet<_> = call_graph
+                .functions
+                .iter()
+                .flat_map(|function| function.contract_calls.iter())
+                .collect();
+            let contract_ids: BTreeMap<_, _> = contract_calls
+                .into_iter()
+                .enumerate()
+                .map(|(index, name)| (name, format!("{prefix}_contract_{index}")))
+                .collect();
+            for (name, id) in contract_ids.iter() {
+                nodes.push(Node {
+                    id: id.clone(),
+                    label: name.to_string(),
+                    kind: NodeKind::Contract,
+                });
+            }
+            for (index, function) in call_graph.functions.iter().enumerate() {
+                for callee in function.calls.iter() {
+                    self.edges.push(Edge {
+                        from: fn_id(index),
+                        to: fn_id(*callee),
+                        label: None,
+                        dashed: false,
+                    });
+                }
+                for contract_call in function.contract_calls.iter() {
+                    self.edges.push(Edge {
+                        from: fn_id(index),
+                        to: contract_ids[contract_call].clone(),
+                        label: None,
+                        dashed: true,
+                    });
+                }
+            }
+        }
+
+        if storage {
+            let mut field_ids = BTreeMap::new();
+            for (index, function) in call_graph.functions.iter().enumerate() {
+                if !function.is_entry {
+                    continue;
+                }
+                let mut accesses: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+                for access in call_graph.storage_accesses(index) {
+                    accesses
+                        .entry(access.field.clone())
+                        .or_default()
+                        .insert(access.kind);
+                }
+                for (field, kinds) in accesses {
+                    let next_id = format!("{prefix}_storage_{}", field_ids.len());
+                    let field_id = field_ids.entry(field).or_insert(next_id).clone();
+                    let label = kinds
+                        .iter()
+                        .map(|kind| match kind {
+                            StorageAccessKind::Read => "read",
+                            StorageAccessKind::Write => "write",
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    self.edges.push(Edge {
+                        from: fn_id(index),
+                        to: field_id,
+                        label: Some(label),
+                        dashed: false,
+                    });
+                }
+            }
+            for (field, id) in field_ids {
+                nodes.push(Node {
+                    id,
+                    label: format!("storage.{field}"),
+                    kind: NodeKind::Storage,
+                });
+            }
+        }
+
+        self.clusters.push(Cluster {
+            id: prefix.clone(),
+            label: name.to_string(),
+            nodes,
+        });
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n    rankdir=LR;\n");
+        for cluster in self.clusters.iter() {
+            let _ = writeln!(dot, "    subgraph cluster_{} {{", cluster.id);
+            let _ = writeln!(dot, "        label=\"{}\";", escape_dot(&cluster.label));
+            for node in cluster.nodes.iter() {
+                let shape = match node.kind {
+                    NodeKind::Entry => "box, style=bold",
+                    NodeKind::Function => "box",
+                    NodeKind::Contract => "hexagon",
+                    NodeKind::Storage => "cylinder",
+                };
+                let _ = writeln!(
+                    dot,
+                    "        {} [label=\"{}\", shape={shape}];",
+                    node.id,
+                    escape_dot(&node.label)
+                );
+            }
+            dot.push_str("    }\n");
+        }
+        for edge in self.edges.iter() {
+            let mut attributes = vec![];
+            if let Some(label) = &edge.label {
+                attributes.push(format!("label=\"{label}\""));
+            }
+            if edge.dashed {
+                attributes.push("style=dashed".to_string());
+            }
+            let attributes = if attributes.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attributes.join(", "))
+            };
+            let _ = writeln!(dot, "    {} -> {}{attributes};", edge.from, edge.to);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        for cluster in self.clusters.iter() {
+            let _ = writeln!(
+                mermaid,
+                "    subgraph {}[\"{}\"]",
+                cluster.id,
+                escape_mermaid(&cluster.label)
+            );
+            for node in cluster.nodes.iter() {
+                let label = escape_mermaid(&node.label);
+                let _ = match node.kind {
+                    NodeKind::Entry => writeln!(mermaid, "        {}[[\"{label}\"]]", node.id),
+                    NodeKind::Function => writeln!(mermaid, "        {}[\"{label}\"]", node.id),
+                    NodeKind::Contract => {
+                        writeln!(mermaid, "        {}{{{{\"{label}\"}}}}", node.id)
+                    }
+                    NodeKind::Storage => writeln!(mermaid, "        {}[(\"{label}\")]", node.id),
+                };
+            }
+            mermaid.push_str("    end\n");
+        }
+        for edge in self.edges.iter() {
+            let arrow = if edge.dashed { "-.->" } else { "-->" };
+            let label = edge
+                .label
+                .as_ref()
+                .map(|label| format!("|{label}|"))
+                .unwrap_or_default();
+            let _ = writeln!(mermaid, "    {} {arrow}{label} {}", edge.from, edge.to);
+        }
+        mermaid
+    }
+}
+
+/// Turns a name into an identifier usable in both DOT and Mermaid diagrams.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(label: &str) -> String {
+    label
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_core::call_graph::{FunctionNode, StorageAccess};
+    use sway_types::Span;
+
+    fn function(
+        name: &str,
+        is_entry: bool,
+        calls: &[usize],
+        contract_calls: &[&str],
+        accesses: &[(&str, StorageAccessKind)],
+    ) -> FunctionNode {
+        FunctionNode {
+            name: name.to_string(),
+            span: Span::dummy(),
+            is_entry,
+            calls: calls.iter().copied().collect(),
+            contract_calls: contract_calls.iter().map(|name| name.to_string()).collect(),
+            storage_accesses: accesses
+                .iter()
+                .map(|(field, kind)| StorageAccess {
+                    field: field.to_string(),
+                    kind: *kind,
+                    span: Span::dummy(),
+                })
+                .collect(),
+        }
+    }
+
+    /// An entry point calling a recursive helper that writes to storage, and another one reading
+    /// the same field and calling another contract.
+    fn call_graph() -> CallGraph {
+        use StorageAccessKind::*;
+        CallGraph {
+            functions: vec![
+                function("deposit", true, &[1], &[], &[("balance", Read)]),
+                function("add", false, &[1], &[], &[("balance", Write)]),
+                function(
+                    "forward",
+                    true,
+                    &[],
+                    &["Token::transfer"],
+                    &[("balance", Read)],
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn draws_calls_and_storage_as_dot() {
+        let mut diagram = Diagram::default();
+        diagram.add_package("my-contract", &call_graph(), true, true);
+        assert_eq!(
+            diagram.to_dot(),
+            r#"digraph {
+    rankdir=LR;
+    subgraph cluster_my_contract {
+        label="my-contract";
+        my_contract_fn_0 [label="deposit", shape=box, style=bold];
+        my_contract_fn_1 [label="add", shape=box];
+        my_contract_fn_2 [label="forward", shape=box, style=bold];
+        my_contract_contract_0 [label="Token::transfer", shape=hexagon];
+        my_contract_storage_0 [label="storage.balance", shape=cylinder];
+    }
+    my_contract_fn_0 -> my_contract_fn_1;
+    my_contract_fn_1 -> my_contract_fn_1;
+    my_contract_fn_2 -> my_contract_contract_0 [style=dashed];
+    my_contract_fn_0 -> my_contract_storage_0 [label="read, write"];
+    my_contract_fn_2 -> my_contract_storage_0 [label="read"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn draws_only_the_storage_of_entries_as_mermaid() {
+        let mut diagram = Diagram::default();
+        diagram.add_package("my-contract", &call_graph(), false, true);
+        assert_eq!(
+            diagram.to_mermaid(),
+            r#"flowchart LR
+    subgraph my_contract["my-contract"]
+        my_contract_fn_0[["deposit"]]
+        my_contract_fn_2[["forward"]]
+        my_contract_storage_0[("storage.balance")]
+    end
+    my_contract_fn_0 -->|read, write| my_contract_storage_0
+    my_contract_fn_2 -->|read| my_contract_storage_0
+"#
+        );
+    }
+
+    #[test]
+    fn draws_only_the_calls_of_several_packages() {
+        let mut diagram = Diagram::default();
+        diagram.add_package("a", &call_graph(), true, false);
+        diagram.add_package("b", &call_graph(), true, false);
+        let dot = diagram.to_dot();
+        assert!(dot.contains("subgraph cluster_a {"));
+        assert!(dot.contains("subgraph cluster_b {"));
+        assert!(dot.contains("    b_fn_1 -> b_fn_1;\n"));
+        assert!(!dot.contains("storage"));
+    }
+
+    #[test]
+    fn escapes_labels() {
+        let call_graph = CallGraph {
+            functions: vec![function("Wrapper<\"T\">::get", true, &[], &[], &[])],
+        };
+        let mut diagram = Diagram::default();
+        diagram.add_package("p", &call_graph, true, false);
+        assert!(diagram
+            .to_dot()
+            .contains(r#"p_fn_0 [label="Wrapper<\"T\">::get", shape=box, style=bold];"#));
+        assert!(diagram
+            .to_mermaid()
+            .contains(r#"p_fn_0[["Wrapper#lt;#quot;T#quot;#gt;::get"]]"#));
+    }
+}
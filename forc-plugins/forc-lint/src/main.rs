@@ -0,0 +1,145 @@
+//! A `forc` plugin for checking the naming conventions and the style of Sway code.
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use forc_pkg::{self as pkg, manifest::ManifestFile};
+use forc_tracing::{init_tracing_subscriber, println_error, println_green};
+use forc_util::{print_on_failure, print_warnings};
+use std::{collections::HashSet, path::PathBuf};
+use sway_core::{
+    lint::{self, Lint, LintConfig},
+    BuildTarget, Engines,
+};
+use tracing::info;
+
+forc_util::cli_examples! {
+    [ Lint the package in the current directory => lint "" => ".*could not find `Forc.toml`.*" ]
+    [ Lint a package and its tests => lint "--path ../tests --tests" => ".*could not find `Forc.toml`.*" ]
+    [ Deny functions longer than the configured maximum => lint "-D function_too_long" => ".*could not find `Forc.toml`.*" ]
+    [ List the available lints => lint "--list" ]
+}
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "forc-lint",
+    about = "Forc plugin for checking the naming conventions and the style of Sway code.",
+    after_help = help(),
+    version
+)]
+pub struct App {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Lint the tests of the packages too.
+    #[clap(long)]
+    pub tests: bool,
+    /// Allow the given lint, in addition to those allowed in the `[lint]` section of the
+    /// manifests.
+    #[clap(short = 'A', long = "allow", value_name = "LINT")]
+    pub allow: Vec<Lint>,
+    /// Deny the given lint, in addition to those denied in the `[lint]` section of the manifests.
+    #[clap(short = 'D', long = "deny", value_name = "LINT")]
+    pub deny: Vec<Lint>,
+    /// List the available lints.
+    #[clap(long)]
+    pub list: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error.
+    #[clap(long)]
+    pub locked: bool,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long)]
+    pub offline: bool,
+}
+
+fn main() {
+    init_tracing_subscriber(Default::default());
+    let app = App::parse();
+    if let Err(err) = run(app) {
+        println_error(&format!("{}", err));
+        std::process::exit(1);
+    }
+}
+
+fn run(app: App) -> Result<()> {
+    if app.list {
+        for lint in Lint::ALL {
+            info!("{lint}");
+        }
+        return Ok(());
+    }
+
+    let dir = match app.path.as_ref() {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let manifest = ManifestFile::from_dir(&dir)?;
+    let member_manifests = manifest.member_manifests()?;
+    let lock_path = manifest.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        app.locked,
+        app.offline,
+        Default::default(),
+    )?;
+
+    let engines = Engines::default();
+    let results = pkg::check(
+        &plan,
+        BuildTarget::default(),
+        false,
+        app.tests,
+        &engines,
+        None,
+    )?;
+
+    // Only the members of the workspace are linted, not their dependencies.
+    let members: HashSet<_> = plan.member_nodes().collect();
+    let mut failed = false;
+    for (node, (programs, handler)) in plan.compilation_order().iter().zip(results) {
+        let pkg = &plan.graph()[*node];
+        if !members.contains(node) {
+            if programs.is_none() || handler.has_errors() {
+                let (errors, warnings) = handler.consume();
+                print_on_failure(engines.se(), false, &warnings, &errors, false);
+                bail!("Failed to compile {}", pkg.name);
+            }
+            continue;
+        }
+        let manifest = &plan.manifest_map()[&pkg.id()];
+        let config = lint_config(manifest.lint.clone().unwrap_or_default(), &app);
+        let handler = match &programs {
+            Some(programs) => lint::lint(handler, &programs.lexed, &config),
+            None => handler,
+        };
+        let (errors, warnings) = handler.consume();
+        if errors.is_empty() {
+            if let Some(programs) = &programs {
+                let tree_type = &programs.lexed.kind;
+                print_warnings(engines.se(), false, &pkg.name, &warnings, tree_type);
+            }
+        } else {
+            print_on_failure(engines.se(), false, &warnings, &errors, false);
+            failed = true;
+        }
+    }
+
+    if failed {
+        bail!("Linting failed");
+    }
+    println_green("Linting finished.");
+    Ok(())
+}
+
+/// Applies the lints allowed and denied on the command line on top of the configuration of a
+/// package, which they take precedence over.
+fn lint_config(mut config: LintConfig, app: &App) -> LintConfig {
+    for lint in &app.allow {
+        config.deny.remove(lint);
+        config.allow.insert(*lint);
+    }
+    config.deny.extend(app.deny.iter().copied());
+    config
+}
@@ -0,0 +1,313 @@
+//! Decoding of the logs emitted by tests.
+//!
+//! Logged values are decoded from the memory layout of the types they were logged with, as
+//! described by the ABI of the tested package, and are formatted as Sway expressions. The
+//! instructions that emitted them are located in the source code through the source map of the
+//! tested package.
+//!
+//! Only the logs and reverts of the test itself can be located, as the source map does not cover
+//! the contracts called by a test. Values that cannot be decoded, like string slices whose
+//! content is not part of the log, are left to be printed as raw receipts.
+
+use fuel_abi_types::abi::program::{ProgramABI, TypeApplication, TypeDeclaration};
+use fuel_tx::{ContractId, Receipt};
+use std::{collections::HashMap, fmt, fs, path::PathBuf};
+use sway_core::{asm_generation::ProgramABI as BuiltProgramABI, source_map::SourceMap};
+
+const WORD_SIZE: usize = 8;
+
+/// A location in the source code of a tested package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file_path: PathBuf,
+    /// The line number, starting from `1`.
+    pub line_number: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file_path.display(), self.line_number)
+    }
+}
+
+/// A `Log` or `LogData` receipt emitted by a test, along with its decoded value.
+#[derive(Debug, Clone)]
+pub struct DecodedLog {
+    /// The logged value formatted as a Sway expression, if it could be decoded.
+    pub value: Option<String>,
+    /// The location of the `log` call, if it was made by the test itself.
+    pub location: Option<SourceLocation>,
+    pub receipt: Receipt,
+}
+
+/// Decodes the logs of the tests of a package using its ABI and source map.
+pub struct LogDecoder<'a> {
+    abi: Option<&'a ProgramABI>,
+    types: HashMap<usize, &'a TypeDeclaration>,
+    source_map: &'a SourceMap,
+}
+
+impl<'a> LogDecoder<'a> {
+    pub fn new(abi: &'a BuiltProgramABI, source_map: &'a SourceMap) -> Self {
+        let abi = match abi {
+            BuiltProgramABI::Fuel(abi) => Some(abi),
+            BuiltProgramABI::Evm(_) | BuiltProgramABI::MidenVM(_) => None,
+        };
+        let types = abi
+            .iter()
+            .flat_map(|abi| abi.types.iter())
+            .map(|decl| (decl.type_id, decl))
+            .collect();
+        Self {
+            abi,
+            types,
+            source_map,
+        }
+    }
+
+    /// Decodes a `Log` or `LogData` receipt.
+    pub fn decode(&self, receipt: &Receipt) -> DecodedLog {
+        let (id, pc, is) = match receipt {
+            Receipt::Log { id, pc, is, .. } | Receipt::LogData { id, pc, is, .. } => {
+                (*id, *pc, *is)
+            }
+            _ => (ContractId::zeroed(), 0, 0),
+        };
+        DecodedLog {
+            value: self.decode_value(receipt),
+            location: self.locate(id, pc, is),
+            receipt: receipt.clone(),
+        }
+    }
+
+    /// Returns the source location of the instruction at `pc`, if it was executed by the test
+    /// itself, i.e. outside of any contract.
+    pub fn locate(&self, id: ContractId, pc: u64, is: u64) -> Option<SourceLocation> {
+        if id != ContractId::zeroed() {
+            return None;
+        }
+        let instruction_index = pc.checked_sub(is)? as usize / fuel_vm::prelude::Instruction::SIZE;
+        let (file_path, range) = self.source_map.addr_to_span(instruction_index)?;
+        let file_str = fs::read_to_string(&file_path).ok()?;
+        let line_number = file_str.get(..range.start)?.matches('\n').count() + 1;
+        Some(SourceLocation {
+            file_path,
+            line_number,
+        })
+    }
+
+    fn decode_value(&self, receipt: &Receipt) -> Option<String> {
+        let log_id = match receipt {
+            Receipt::Log { rb, .. } | Receipt::LogData { rb, .. } => *rb,
+            _ => return None,
+        };
+        let logged_type = self
+            .abi?
+            .logged_types
+            .as_ref()?
+            .iter()
+            .find(|logged_type| logged_type.log_id == log_id)?;
+        let ty = self.resolve(&logged_type.application, &HashMap::new())?;
+        match receipt {
+            // Values that fit into a register are logged directly.
+            Receipt::Log { ra, .. } => self.format_word(&ty, *ra),
+            Receipt::LogData {
+                data: Some(data), ..
+            } => self.format(&ty, data),
+            _ => None,
+        }
+    }
+
+    /// Replaces the generic type parameters within `ty` with the types they are instantiated
+    /// with.
+    fn resolve(
+        &self,
+        ty: &TypeApplication,
+        generics: &HashMap<usize, TypeApplication>,
+    ) -> Option<TypeApplication> {
+        let decl = self.types.get(&ty.type_id)?;
+        if decl.type_field.starts_with("generic ") {
+            return generics.get(&ty.type_id).cloned();
+        }
+        let type_arguments = match &ty.type_arguments {
+            Some(args) => Some(
+                args.iter()
+                    .map(|arg| self.resolve(arg, generics))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+        Some(TypeApplication {
+            name: ty.name.clone(),
+            type_id: ty.type_id,
+            type_arguments,
+        })
+    }
+
+    /// Returns the fields of a struct, the variants of an enum or the elements of a tuple or an
+    /// array, with their generic type parameters resolved.
+    fn components(&self, ty: &TypeApplication) -> Option<Vec<TypeApplication>> {
+        let decl = self.types.get(&ty.type_id)?;
+        let generics = decl
+            .type_parameters
+            .iter()
+            .flatten()
+            .copied()
+            .zip(ty.type_arguments.iter().flatten().cloned())
+            .collect();
+        decl.components
+            .iter()
+            .flatten()
+            .map(|component| self.resolve(component, &generics))
+            .collect()
+    }
+
+    /// Returns the size in bytes of a value of `ty` in memory.
+    fn size(&self, ty: &TypeApplication) -> Option<usize> {
+        let type_field = self.types.get(&ty.type_id)?.type_field.as_str();
+        Some(match type_field {
+            "()" | "bool" | "u8" => 1,
+            "u16" | "u32" | "u64" | "raw untyped ptr" => WORD_SIZE,
+            "b256" | "u256" => 32,
+            "str" | "raw untyped slice" => 2 * WORD_SIZE,
+            _ if type_field.starts_with("str[") => aligned(str_array_len(type_field)?),
+            _ if type_field.starts_with('[') => {
+                let elem = self.components(ty)?.pop()?;
+                array_len(type_field)? * self.size(&elem)?
+            }
+            _ if type_field.starts_with('(') || type_field.starts_with("struct ") => self
+                .components(ty)?
+                .iter()
+                .map(|field| self.size(field).map(aligned))
+                .sum::<Option<usize>>()?,
+            _ if type_field.starts_with("enum ") => {
+                WORD_SIZE + self.union_size(&self.components(ty)?)?
+            }
+            _ => return None,
+        })
+    }
+
+    fn union_size(&self, variants: &[TypeApplication]) -> Option<usize> {
+        variants
+            .iter()
+            .map(|variant| self.size(variant).map(aligned))
+            .try_fold(0, |max, size| size.map(|size| max.max(size)))
+    }
+
+    fn format_word(&self, ty: &TypeApplication, word: u64) -> Option<String> {
+        match self.types.get(&ty.type_id)?.type_field.as_str() {
+            "()" => Some("()".to_string()),
+            "bool" => Some((word != 0).to_string()),
+            "u8" | "u16" | "u32" | "u64" => Some(word.to_string()),
+            "raw untyped ptr" => Some(format!("{word:#x}")),
+            _ => None,
+        }
+    }
+
+    /// Formats the value of `ty` stored at the start of `bytes`.
+    fn format(&self, ty: &TypeApplication, bytes: &[u8]) -> Option<String> {
+        let decl = self.types.get(&ty.type_id)?;
+        let type_field = decl.type_field.as_str();
+        let bytes = bytes.get(..self.size(ty)?)?;
+        match type_field {
+            "()" => Some("()".to_string()),
+            "bool" => Some((bytes[0] != 0).to_string()),
+            "u8" => Some(bytes[0].to_string()),
+            "u16" | "u32" | "u64" | "raw untyped ptr" => {
+                self.format_word(ty, u64::from_be_bytes(bytes.try_into().ok()?))
+            }
+            "b256" | "u256" => Some(format!("0x{}", hex_string(bytes))),
+            _ if type_field.starts_with("str[") => {
+                let len = str_array_len(type_field)?;
+                Some(format!("{:?}", std::str::from_utf8(&bytes[..len]).ok()?))
+            }
+            _ if type_field.starts_with('[') => {
+                let elem = self.components(ty)?.pop()?;
+                let elem_size = self.size(&elem)?;
+                let elems = (0..array_len(type_field)?)
+                    .map(|ix| self.format(&elem, &bytes[ix * elem_size..]))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("[{}]", elems.join(", ")))
+            }
+            _ if type_field.starts_with('(') => {
+                let elems = self.format_fields(&self.components(ty)?, bytes)?;
+                match elems.as_slice() {
+                    [elem] => Some(format!("({elem},)")),
+                    _ => Some(format!("({})", elems.join(", "))),
+                }
+            }
+            _ if type_field.starts_with("struct ") => {
+                let name = type_field.trim_start_matches("struct ");
+                let fields = decl.components.as_ref()?;
+                let values = self.format_fields(&self.components(ty)?, bytes)?;
+                if values.is_empty() {
+                    return Some(format!("{name} {{}}"));
+                }
+                let fields = fields
+                    .iter()
+                    .zip(values)
+                    .map(|(field, value)| format!("{}: {value}", field.name))
+                    .collect::<Vec<_>>();
+                Some(format!("{name} {{ {} }}", fields.join(", ")))
+            }
+            _ if type_field.starts_with("enum ") => {
+                let name = type_field.trim_start_matches("enum ");
+                let variants = self.components(ty)?;
+                let tag = u64::from_be_bytes(bytes[..WORD_SIZE].try_into().ok()?) as usize;
+                let variant = variants.get(tag)?;
+                let variant_name = &decl.components.as_ref()?.get(tag)?.name;
+                // Variants are left padded within the union.
+                let union_size = self.union_size(&variants)?;
+                let offset = WORD_SIZE + union_size - self.size(variant)?;
+                match self.types.get(&variant.type_id)?.type_field.as_str() {
+                    "()" => Some(format!("{name}::{variant_name}")),
+                    _ => {
+                        let value = self.format(variant, &bytes[offset..])?;
+                        Some(format!("{name}::{variant_name}({value})"))
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Formats the fields of a struct or a tuple, each of which is right padded to a word.
+    fn format_fields(&self, fields: &[TypeApplication], bytes: &[u8]) -> Option<Vec<String>> {
+        let mut offset = 0;
+        fields
+            .iter()
+            .map(|field| {
+                let value = self.format(field, bytes.get(offset..)?)?;
+                offset += aligned(self.size(field)?);
+                Some(value)
+            })
+            .collect()
+    }
+}
+
+/// Returns the length of a string array type, e.g. `3` for `str[3]`.
+fn str_array_len(type_field: &str) -> Option<usize> {
+    type_field
+        .strip_prefix("str[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Returns the length of an array type, e.g. `3` for `[_; 3]`.
+fn array_len(type_field: &str) -> Option<usize> {
+    type_field
+        .strip_prefix("[_; ")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+/// Rounds `size` up to a multiple of the word size.
+fn aligned(size: usize) -> usize {
+    (size + WORD_SIZE - 1) / WORD_SIZE * WORD_SIZE
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,368 @@
+//! The test harness that in-language tests interact with through the `ecal` instruction.
+//!
+//! Tests query and manipulate the chain they are executed on through `std::test_harness`, which
+//! issues an `ecal` with the number of the requested operation in its first register. The chain
+//! lives in the [TestStorage] of the VM, which the VM reads the block height, the timestamps and
+//! the block producer from. The wallets created by a test are funded with coin inputs of the test
+//! transaction, see `crate::execute::TestExecutor`. Tests can also set up the storage of
+//! contracts and the owner of the inputs of the test transaction.
+//!
+//! The harness state is reset before each test, and lives in a thread local as the `ecal`
+//! handlers of the VM are stateless. Each test executes on a single thread.
+
+use crate::storage::TestStorage;
+use fuel_tx::{Address, AssetId, Bytes32, ContractId, Input, Receipt};
+use fuel_vm::{
+    error::SimpleResult,
+    interpreter::{EcalHandler, Interpreter},
+    prelude::{PanicReason, RegId, SecretKey},
+    storage::InterpreterStorage,
+};
+use rand::SeedableRng;
+use std::{cell::RefCell, collections::HashMap};
+
+/// Creates a wallet, to be funded by a coin input of the test transaction. `$rB` points to the
+/// asset id, `$rC` holds the amount, and the address of the wallet is written to the 32 bytes
+/// `$rD` points to.
+pub const NEW_WALLET: u64 = 1;
+/// Moves `$rD` coins between two coin inputs of the test transaction, given by the pointers to
+/// their owners. `$rB` points to the owner of the input the coins are taken from, and `$rC` to the
+/// owner of the input they are given to.
+pub const WALLET_TRANSFER: u64 = 2;
+/// Advances the chain by `$rB` blocks, each produced `$rC` seconds after the previous one.
+pub const ADVANCE_BLOCKS: u64 = 3;
+/// Sets `$rA` to the height of the latest block of the chain.
+pub const BLOCK_HEIGHT: u64 = 4;
+/// Sets `$rA` to the TAI64 timestamp of the latest block of the chain.
+pub const BLOCK_TIMESTAMP: u64 = 5;
+/// Sets the block producer to the 32 bytes `$rB` points to.
+pub const SET_BLOCK_PRODUCER: u64 = 6;
+/// Writes the block producer to the 32 bytes `$rB` points to.
+pub const BLOCK_PRODUCER: u64 = 7;
+/// Sets `$rA` to the number of receipts emitted so far.
+pub const RECEIPTS_LEN: u64 = 8;
+/// Sets `$rA` to the kind of the receipt with the index `$rB`, as numbered by `std::test_harness`.
+pub const RECEIPT_KIND: u64 = 9;
+/// Stores a storage slot of a contract. `$rB` points to the contract id, the key and the value of
+/// the slot, in this order.
+pub const STORE_SLOT: u64 = 10;
+/// Loads a storage slot of a contract. `$rB` points to the contract id and the key of the slot, in
+/// this order, and the value is written to the 32 bytes `$rC` points to. Sets `$rA` to `1` if the
+/// slot is set and `0` otherwise.
+pub const LOAD_SLOT: u64 = 11;
+/// Overwrites the owner of the input `$rB` points to, within the test transaction, with the 32
+/// bytes `$rC` points to.
+pub const PRANK: u64 = 12;
+/// Restores the original owner of the input `$rB` points to.
+pub const STOP_PRANK: u64 = 13;
+/// Sets `$rA` to `1` if a receipt emitted before the `Log` or `LogData` receipt with the index
+/// `$rB` logs the same value, with the same log id, and `0` otherwise.
+pub const FIND_LOG: u64 = 14;
+
+/// The revert code of a failing call to `std::test_harness::assert_emitted`.
+pub const FAILED_ASSERT_EMITTED_SIGNAL: u64 = 0xffff_ffff_ffff_0007;
+
+/// Seed for the keys of the wallets, so that tests are reproducible.
+const WALLET_SEED: u64 = 0x3A11E7;
+
+/// The amount of a coin input is serialized right after the 32 bytes of its owner.
+const COIN_AMOUNT_OFFSET: u64 = 32;
+
+/// A wallet created by a test, funded by a coin input of the test transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wallet {
+    pub secret_key: SecretKey,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+impl Wallet {
+    /// Returns the address of the wallet, the owner of its coin input.
+    pub fn address(&self) -> Address {
+        Input::owner(&self.secret_key.public_key())
+    }
+}
+
+/// The state of the harness for a single test.
+#[derive(Debug, Clone)]
+pub struct TestHarness {
+    /// The wallets created by the test, in the order they were created in.
+    wallets: Vec<Wallet>,
+    /// The original owners of the inputs overwritten by `PRANK`, by the address of the owner.
+    pranked_owners: HashMap<u64, [u8; 32]>,
+    rng: rand::rngs::StdRng,
+}
+
+impl Default for TestHarness {
+    fn default() -> Self {
+        Self {
+            wallets: vec![],
+            pranked_owners: HashMap::new(),
+            rng: rand::rngs::StdRng::seed_from_u64(WALLET_SEED),
+        }
+    }
+}
+
+impl TestHarness {
+    /// Returns the wallets created by the test, in the order they were created in.
+    pub fn into_wallets(self) -> Vec<Wallet> {
+        self.wallets
+    }
+
+    fn new_wallet(&mut self, asset_id: AssetId, amount: u64) -> Address {
+        let wallet = Wallet {
+            secret_key: SecretKey::random(&mut self.rng),
+            asset_id,
+            amount,
+        };
+        let address = wallet.address();
+        self.wallets.push(wallet);
+        address
+    }
+}
+
+thread_local! {
+    static HARNESS: RefCell<Option<TestHarness>> = RefCell::new(None);
+}
+
+/// Resets the harness used by the tests executed on the current thread.
+pub fn reset_harness() {
+    HARNESS.with(|cell| *cell.borrow_mut() = Some(TestHarness::default()));
+}
+
+/// Takes the harness left by the last test executed on the current thread.
+pub fn take_harness() -> Option<TestHarness> {
+    HARNESS.with(|cell| cell.borrow_mut().take())
+}
+
+/// Handles the `ecal` instructions of tests by running the requested harness operation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestHarnessEcal;
+
+impl EcalHandler for TestHarnessEcal {
+    fn ecal<S, Tx>(
+        vm: &mut Interpreter<S, Tx, Self>,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        d: RegId,
+    ) -> SimpleResult<()> {
+        let reg = |vm: &Interpreter<S, Tx, Self>, reg: RegId| vm.registers()[reg.to_u8() as usize];
+        let (op, b, c, d) = (reg(vm, a), reg(vm, b), reg(vm, c), reg(vm, d));
+
+        let result = HARNESS.with(|cell| {
+            let mut harness = cell.borrow_mut();
+            let harness = harness.as_mut().ok_or(PanicReason::EcalError)?;
+            Ok::<_, PanicReason>(match op {
+                NEW_WALLET => {
+                    let asset_id = AssetId::new(read_bytes(vm, b)?);
+                    let address = harness.new_wallet(asset_id, c);
+                    write_bytes(vm, d, *address)?;
+                    None
+                }
+                WALLET_TRANSFER => {
+                    let from = b.saturating_add(COIN_AMOUNT_OFFSET);
+                    let to = c.saturating_add(COIN_AMOUNT_OFFSET);
+                    let from_amount = read_word(vm, from)?
+                        .checked_sub(d)
+                        .ok_or(PanicReason::EcalError)?;
+                    let to_amount = read_word(vm, to)?
+                        .checked_add(d)
+                        .ok_or(PanicReason::EcalError)?;
+                    write_word(vm, from, from_amount)?;
+                    write_word(vm, to, to_amount)?;
+                    None
+                }
+                ADVANCE_BLOCKS => {
+                    let blocks = u32::try_from(b).unwrap_or(u32::MAX);
+                    test_storage(vm)?.advance_blocks(blocks, c);
+                    None
+                }
+                BLOCK_HEIGHT => Some(*test_storage(vm)?.height() as u64),
+                BLOCK_TIMESTAMP => {
+                    let storage = test_storage(vm)?;
+                    let timestamp = storage
+                        .timestamp(storage.height())
+                        .map_err(|_| PanicReason::EcalError)?;
+                    Some(timestamp)
+                }
+                SET_BLOCK_PRODUCER => {
+                    let producer = ContractId::new(read_bytes(vm, b)?);
+                    test_storage(vm)?.set_coinbase(producer);
+                    None
+                }
+                BLOCK_PRODUCER => {
+                    let producer = test_storage(vm)?
+                        .coinbase()
+                        .map_err(|_| PanicReason::EcalError)?;
+                    write_bytes(vm, b, *producer)?;
+                    None
+                }
+                RECEIPTS_LEN => Some(vm.receipts().len() as u64),
+                RECEIPT_KIND => {
+                    let receipt = vm
+                        .receipts()
+                        .get(b as usize)
+                        .ok_or(PanicReason::EcalError)?;
+                    Some(receipt_kind(receipt))
+                }
+                STORE_SLOT => {
+                    let contract_id = ContractId::new(read_bytes(vm, b)?);
+                    let key = Bytes32::new(read_bytes(vm, b.saturating_add(32))?);
+                    let value = Bytes32::new(read_bytes(vm, b.saturating_add(64))?);
+                    test_storage(vm)?
+                        .merkle_contract_state_insert(&contract_id, &key, &value)
+                        .map_err(|_| PanicReason::EcalError)?;
+                    None
+                }
+                LOAD_SLOT => {
+                    let contract_id = ContractId::new(read_bytes(vm, b)?);
+                    let key = Bytes32::new(read_bytes(vm, b.saturating_add(32))?);
+                    let value = test_storage(vm)?
+                        .merkle_contract_state(&contract_id, &key)
+                        .map_err(|_| PanicReason::EcalError)?
+                        .map(|value| *value.into_owned());
+                    if let Some(value) = value {
+                        write_bytes(vm, c, value)?;
+                    }
+                    Some(value.is_some() as u64)
+                }
+                PRANK => {
+                    let owner = read_bytes(vm, b)?;
+                    let caller = read_bytes(vm, c)?;
+                    harness.pranked_owners.entry(b).or_insert(owner);
+                    write_bytes(vm, b, caller)?;
+                    None
+                }
+                STOP_PRANK => {
+                    if let Some(owner) = harness.pranked_owners.remove(&b) {
+                        write_bytes(vm, b, owner)?;
+                    }
+                    None
+                }
+                FIND_LOG => {
+                    let receipts = vm.receipts();
+                    let expected = receipts.get(b as usize).ok_or(PanicReason::EcalError)?;
+                    let found = receipts[..b as usize]
+                        .iter()
+                        .any(|receipt| same_log(receipt, expected));
+                    Some(found as u64)
+                }
+                _ => return Err(PanicReason::EcalError),
+            })
+        })?;
+
+        if let Some(result) = result {
+            vm.registers_mut()[a.to_u8() as usize] = result;
+        }
+        Ok(())
+    }
+}
+
+/// The number of a receipt kind, as defined by `std::test_harness::ReceiptKind`.
+fn receipt_kind(receipt: &Receipt) -> u64 {
+    match receipt {
+        Receipt::Call { .. } => 0,
+        Receipt::Return { .. } => 1,
+        Receipt::ReturnData { .. } => 2,
+        Receipt::Panic { .. } => 3,
+        Receipt::Revert { .. } => 4,
+        Receipt::Log { .. } => 5,
+        Receipt::LogData { .. } => 6,
+        Receipt::Transfer { .. } => 7,
+        Receipt::TransferOut { .. } => 8,
+        Receipt::ScriptResult { .. } => 9,
+        Receipt::MessageOut { .. } => 10,
+        Receipt::Mint { .. } => 11,
+        Receipt::Burn { .. } => 12,
+    }
+}
+
+/// Returns the storage of the VM executing the test.
+///
+/// The `ecal` handlers of the VM are generic over its storage, which can only be accessed as the
+/// [TestStorage] tests are executed with, see `crate::execute::TestExecutor`.
+fn test_storage<S, Tx>(
+    vm: &mut Interpreter<S, Tx, TestHarnessEcal>,
+) -> Result<&mut TestStorage, PanicReason> {
+    if std::any::type_name::<S>() != std::any::type_name::<TestStorage>()
+        || std::mem::size_of::<S>() != std::mem::size_of::<TestStorage>()
+    {
+        return Err(PanicReason::EcalError);
+    }
+    let storage: &mut S = vm.as_mut();
+    // SAFETY: `S` was just checked to be `TestStorage`.
+    Ok(unsafe { &mut *(storage as *mut S).cast::<TestStorage>() })
+}
+
+/// Returns whether both receipts log the same value with the same log id.
+fn same_log(receipt: &Receipt, other: &Receipt) -> bool {
+    match (receipt, other) {
+        (
+            Receipt::Log { ra, rb, .. },
+            Receipt::Log {
+                ra: other_ra,
+                rb: other_rb,
+                ..
+            },
+        ) => ra == other_ra && rb == other_rb,
+        (
+            Receipt::LogData { rb, data, .. },
+            Receipt::LogData {
+                rb: other_rb,
+                data: other_data,
+                ..
+            },
+        ) => rb == other_rb && data == other_data,
+        _ => false,
+    }
+}
+
+fn read_bytes<S, Tx>(
+    vm: &Interpreter<S, Tx, TestHarnessEcal>,
+    addr: u64,
+) -> Result<[u8; 32], PanicReason> {
+    let start = usize::try_from(addr).map_err(|_| PanicReason::MemoryOverflow)?;
+    vm.memory()
+        .get(start..start.saturating_add(32))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(PanicReason::MemoryOverflow)
+}
+
+fn write_bytes<S, Tx>(
+    vm: &mut Interpreter<S, Tx, TestHarnessEcal>,
+    addr: u64,
+    bytes: [u8; 32],
+) -> Result<(), PanicReason> {
+    let start = usize::try_from(addr).map_err(|_| PanicReason::MemoryOverflow)?;
+    vm.memory_mut()
+        .get_mut(start..start.saturating_add(32))
+        .ok_or(PanicReason::MemoryOverflow)?
+        .copy_from_slice(&bytes);
+    Ok(())
+}
+
+fn read_word<S, Tx>(
+    vm: &Interpreter<S, Tx, TestHarnessEcal>,
+    addr: u64,
+) -> Result<u64, PanicReason> {
+    let start = usize::try_from(addr).map_err(|_| PanicReason::MemoryOverflow)?;
+    vm.memory()
+        .get(start..start.saturating_add(8))
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_be_bytes)
+        .ok_or(PanicReason::MemoryOverflow)
+}
+
+fn write_word<S, Tx>(
+    vm: &mut Interpreter<S, Tx, TestHarnessEcal>,
+    addr: u64,
+    word: u64,
+) -> Result<(), PanicReason> {
+    let start = usize::try_from(addr).map_err(|_| PanicReason::MemoryOverflow)?;
+    vm.memory_mut()
+        .get_mut(start..start.saturating_add(8))
+        .ok_or(PanicReason::MemoryOverflow)?
+        .copy_from_slice(&word.to_be_bytes());
+    Ok(())
+}
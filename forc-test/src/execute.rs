@@ -1,4 +1,6 @@
+use crate::ecal::{self, TestHarnessEcal, Wallet};
 use crate::setup::TestSetup;
+use crate::storage::TestStorage;
 use crate::TestResult;
 use crate::TEST_METADATA_SEED;
 use forc_pkg::PkgTestEntry;
@@ -7,17 +9,20 @@ use fuel_vm::error::InterpreterError;
 use fuel_vm::{
     self as vm,
     checked_transaction::builder::TransactionBuilderExt,
-    interpreter::{Interpreter, NotSupportedEcal},
+    interpreter::Interpreter,
     prelude::{Instruction, SecretKey},
-    storage::MemoryStorage,
 };
 use rand::{Rng, SeedableRng};
 
 /// An interface for executing a test within a VM [Interpreter] instance.
 #[derive(Debug)]
 pub struct TestExecutor {
-    pub interpreter: Interpreter<MemoryStorage, tx::Script, NotSupportedEcal>,
+    pub interpreter: Interpreter<TestStorage, tx::Script, TestHarnessEcal>,
+    /// The storage the test starts from.
+    storage: TestStorage,
     tx_builder: tx::TransactionBuilder<tx::Script>,
+    /// The wallets funded by the coin inputs of `tx_builder`.
+    wallets: Vec<Wallet>,
     test_entry: PkgTestEntry,
     name: String,
 }
@@ -77,34 +82,46 @@ impl TestExecutor {
                 }));
             output_index += 1;
         }
-        let consensus_params = tx_builder.get_params().clone();
-
-        // Temporarily finalize to calculate `script_gas_limit`
-        let tmp_tx = tx_builder.clone().finalize();
-        // Get `max_gas` used by everything except the script execution. Add `1` because of rounding.
-        let max_gas =
-            tmp_tx.max_gas(consensus_params.gas_costs(), consensus_params.fee_params()) + 1;
-        // Increase `script_gas_limit` to the maximum allowed value.
-        tx_builder.script_gas_limit(consensus_params.tx_params().max_gas_per_tx - max_gas);
+        set_script_gas_limit(&mut tx_builder);
 
         TestExecutor {
-            interpreter: Interpreter::with_storage(storage, consensus_params.into()),
+            interpreter: Interpreter::with_storage(
+                storage.clone(),
+                tx_builder.get_params().clone().into(),
+            ),
+            storage,
             tx_builder,
+            wallets: vec![],
             test_entry: test_entry.clone(),
             name,
         }
     }
 
     pub fn execute(&mut self) -> anyhow::Result<TestResult> {
-        let block_height = (u32::MAX >> 1).into();
         let start = std::time::Instant::now();
-        let transition = self
-            .interpreter
-            .transact(self.tx_builder.finalize_checked(block_height))
-            .map_err(|err: InterpreterError<_>| anyhow::anyhow!(err))?;
+        let (state, receipts) = loop {
+            ecal::reset_harness();
+            let transition = self
+                .interpreter
+                .transact(self.tx_builder.finalize_checked(self.storage.height()))
+                .map_err(|err: InterpreterError<_>| anyhow::anyhow!(err))?;
+            let state = *transition.state();
+            let receipts = transition.receipts().to_vec();
+
+            // The wallets created by the test are funded with coin inputs of the test
+            // transaction, so the test is executed again once the wallets it creates are known.
+            let wallets = ecal::take_harness()
+                .map(|harness| harness.into_wallets())
+                .unwrap_or_default();
+            if !wallets.starts_with(&self.wallets) {
+                anyhow::bail!("the test created different wallets when executed again");
+            }
+            if wallets.len() == self.wallets.len() {
+                break (state, receipts);
+            }
+            self.fund_wallets(wallets);
+        };
         let duration = start.elapsed();
-        let state = *transition.state();
-        let receipts = transition.receipts().to_vec();
 
         let gas_used = *receipts
             .iter()
@@ -114,6 +131,11 @@ impl TestExecutor {
             })
             .ok_or_else(|| anyhow::anyhow!("missing used gas information from test execution"))?;
 
+        let revert = receipts
+            .iter()
+            .find(|receipt| matches!(receipt, tx::Receipt::Revert { .. }))
+            .cloned();
+
         // Only retain `Log` and `LogData` receipts.
         let logs = receipts
             .into_iter()
@@ -135,11 +157,48 @@ impl TestExecutor {
             state,
             condition,
             logs,
+            revert,
             gas_used,
         })
     }
 }
 
+impl TestExecutor {
+    /// Adds a coin input to the test transaction for each of the `wallets` it does not fund yet,
+    /// and resets the interpreter to execute the test again.
+    fn fund_wallets(&mut self, wallets: Vec<Wallet>) {
+        for wallet in &wallets[self.wallets.len()..] {
+            let utxo_id = tx::UtxoId::new(tx::Bytes32::new(*wallet.address()), 0);
+            self.tx_builder.add_unsigned_coin_input(
+                wallet.secret_key,
+                utxo_id,
+                wallet.amount,
+                wallet.asset_id,
+                tx::TxPointer::default(),
+                0u32.into(),
+            );
+        }
+        self.wallets = wallets;
+        set_script_gas_limit(&mut self.tx_builder);
+        self.interpreter = Interpreter::with_storage(
+            self.storage.clone(),
+            self.tx_builder.get_params().clone().into(),
+        );
+    }
+}
+
+/// Sets the `script_gas_limit` of the transaction to the maximum allowed value.
+fn set_script_gas_limit(tx_builder: &mut tx::TransactionBuilder<tx::Script>) {
+    let consensus_params = tx_builder.get_params().clone();
+
+    // Temporarily finalize to calculate `script_gas_limit`
+    let tmp_tx = tx_builder.clone().finalize();
+    // Get `max_gas` used by everything except the script execution. Add `1` because of rounding.
+    let max_gas = tmp_tx.max_gas(consensus_params.gas_costs(), consensus_params.fee_params()) + 1;
+    // Increase `script_gas_limit` to the maximum allowed value.
+    tx_builder.script_gas_limit(consensus_params.tx_params().max_gas_per_tx - max_gas);
+}
+
 /// Given some bytecode and an instruction offset for some test's desired entry point, patch the
 /// bytecode with a `JI` (jump) instruction to jump to the desired test.
 ///
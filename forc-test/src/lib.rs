@@ -1,10 +1,15 @@
+pub mod decode;
+pub mod ecal;
 pub mod execute;
 pub mod setup;
+pub mod storage;
 
+use crate::decode::{DecodedLog, LogDecoder, SourceLocation};
 use crate::execute::TestExecutor;
 use crate::setup::{
     ContractDeploymentSetup, ContractTestSetup, DeploymentSetup, ScriptTestSetup, TestSetup,
 };
+use crate::storage::{TestStorage, TEST_BLOCK_HEIGHT};
 use forc_pkg as pkg;
 use fuel_abi_types::error_codes::ErrorSignal;
 use fuel_tx as tx;
@@ -15,8 +20,13 @@ use pkg::TestPassCondition;
 use pkg::{Built, BuiltPackage};
 use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
-use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
-use sway_core::BuildTarget;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
+use sway_core::{lint::LintLevel, BuildTarget};
 use sway_types::Span;
 use vm::prelude::SecretKey;
 
@@ -43,6 +53,14 @@ pub struct TestDetails {
     pub line_number: usize,
 }
 
+/// An event that a test asserted was emitted, but was not.
+#[derive(Debug)]
+pub struct MissingEvent {
+    pub expected: DecodedLog,
+    /// The events emitted with the same type as the expected one.
+    pub emitted: Vec<DecodedLog>,
+}
+
 /// The filter to be used to only run matching tests.
 #[derive(Debug, Clone)]
 pub struct TestFilter<'a> {
@@ -53,6 +71,19 @@ pub struct TestFilter<'a> {
     pub exact_match: bool,
 }
 
+/// A test of a built package, listed without being executed.
+#[derive(Debug, Clone)]
+pub struct ListedTest {
+    /// The name of the package declaring the test.
+    pub package: String,
+    /// The name of the function.
+    pub name: String,
+    /// The span for the function declaring this test.
+    pub span: Span,
+    /// The file path for the function declaring this test.
+    pub file_path: Arc<PathBuf>,
+}
+
 /// The result of executing a single test within a single package.
 #[derive(Debug)]
 pub struct TestResult {
@@ -70,6 +101,8 @@ pub struct TestResult {
     pub condition: pkg::TestPassCondition,
     /// Emitted `Recipt`s during the execution of the test.
     pub logs: Vec<fuel_tx::Receipt>,
+    /// The `Revert` receipt emitted if the test reverted.
+    pub revert: Option<fuel_tx::Receipt>,
     /// Gas used while executing this test.
     pub gas_used: u64,
 }
@@ -133,6 +166,13 @@ pub struct Opts {
     pub binary_outfile: Option<String>,
     /// If set, outputs source file mapping in JSON format
     pub debug_outfile: Option<String>,
+    /// If set, outputs a JSON manifest listing the artifacts of every built package.
+    pub json_artifact_outfile: Option<String>,
+    /// Attribute the bytes of the bytecode of each built package to the functions they were
+    /// compiled from.
+    pub size_profile: bool,
+    /// Print a static estimate of the gas used by each entry point of each built package.
+    pub gas_report: bool,
     /// Build target to use.
     pub build_target: BuildTarget,
     /// Name of the build profile to use.
@@ -144,6 +184,14 @@ pub struct Opts {
     pub release: bool,
     /// Should warnings be treated as errors?
     pub error_on_warnings: bool,
+    /// The levels of the lints of the compiler warnings, overriding those of the build profile.
+    pub lints: BTreeMap<String, LintLevel>,
+    /// The JSON file holding the execution profile of the program.
+    pub pgo_profile: Option<PathBuf>,
+    /// The number of instructions the bytecode may grow by when inlining functions.
+    pub inline_budget: Option<u64>,
+    /// Keep the storage writes overwritten before being read.
+    pub no_dse_storage: bool,
     /// Output the time elapsed over each part of the compilation process.
     pub time_phases: bool,
     /// Output compilation metrics into file.
@@ -163,6 +211,51 @@ impl TestedPackage {
     pub fn tests_passed(&self) -> bool {
         self.tests.iter().all(|test| test.passed())
     }
+
+    /// Decodes the logs of `test` using the ABI and source map of the package.
+    pub fn decoded_logs(&self, test: &TestResult) -> Vec<DecodedLog> {
+        let decoder = self.log_decoder();
+        test.logs
+            .iter()
+            .map(|receipt| decoder.decode(receipt))
+            .collect()
+    }
+
+    /// Returns the location at which `test` reverted, if it reverted outside of any contract.
+    pub fn revert_location(&self, test: &TestResult) -> Option<SourceLocation> {
+        match test.revert {
+            Some(tx::Receipt::Revert { id, pc, is, .. }) => self.log_decoder().locate(id, pc, is),
+            _ => None,
+        }
+    }
+
+    /// Returns the event that `test` expected and the events of the same type that were emitted
+    /// instead, if `test` failed a call to `std::test_harness::assert_emitted`.
+    pub fn missing_event(&self, test: &TestResult) -> Option<MissingEvent> {
+        if test.revert_code() != Some(ecal::FAILED_ASSERT_EMITTED_SIGNAL) {
+            return None;
+        }
+        // The expected event is logged right before the assertion reverts.
+        let (expected, logs) = test.logs.split_last()?;
+        let log_id = |receipt: &tx::Receipt| match receipt {
+            tx::Receipt::Log { rb, .. } | tx::Receipt::LogData { rb, .. } => Some(*rb),
+            _ => None,
+        };
+        let decoder = self.log_decoder();
+        let emitted = logs
+            .iter()
+            .filter(|receipt| log_id(receipt) == log_id(expected))
+            .map(|receipt| decoder.decode(receipt))
+            .collect();
+        Some(MissingEvent {
+            expected: decoder.decode(expected),
+            emitted,
+        })
+    }
+
+    fn log_decoder(&self) -> LogDecoder {
+        LogDecoder::new(&self.built.program_abi, self.built.source_map())
+    }
 }
 
 impl PackageWithDeploymentToTest {
@@ -195,7 +288,7 @@ impl PackageWithDeploymentToTest {
     fn deploy(&self) -> anyhow::Result<TestSetup> {
         // Setup the interpreter for deployment.
         let params = tx::ConsensusParameters::default();
-        let storage = vm::storage::MemoryStorage::default();
+        let storage = TestStorage::default();
         let mut interpreter: vm::prelude::Interpreter<_, _, vm::interpreter::NotSupportedEcal> =
             vm::interpreter::Interpreter::with_storage(storage, params.clone().into());
 
@@ -321,49 +414,73 @@ impl<'a> PackageTests {
     }
 
     /// Run all tests after applying the provided filter and collect their results.
+    ///
+    /// Each test runs in its own VM, against freshly deployed contracts, so that tests can't see
+    /// each other's storage writes and can run in parallel.  The `#[test(shared_state)]` tests
+    /// are the exception: they run one after the other, in the order they're declared in, each
+    /// starting from the storage the previous one left.
     pub(crate) fn run_tests(
         &self,
         test_runners: &rayon::ThreadPool,
         test_filter: Option<&TestFilter>,
     ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
-        let tests = test_runners.install(|| {
-            pkg_with_tests
-                .bytecode
-                .entries
-                .par_iter()
-                .filter_map(|entry| {
-                    if let Some(test_entry) = entry.kind.test() {
-                        // If a test filter is specified, only the tests containing the filter phrase in
-                        // their name are going to be executed.
-                        let name = entry.finalized.fn_name.clone();
-                        if let Some(filter) = test_filter {
-                            if !filter.filter(&name) {
-                                return None;
-                            }
-                        }
-                        return Some((entry, test_entry));
+        let (shared_state_tests, isolated_tests): (Vec<_>, Vec<_>) = pkg_with_tests
+            .bytecode
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let test_entry = entry.kind.test()?;
+                // If a test filter is specified, only the tests containing the filter phrase in
+                // their name are going to be executed.
+                if let Some(filter) = test_filter {
+                    if !filter.filter(&entry.finalized.fn_name) {
+                        return None;
                     }
-                    None
-                })
-                .map(|(entry, test_entry)| {
-                    // Execute the test and return the result.
-                    let offset = u32::try_from(entry.finalized.imm)
-                        .expect("test instruction offset out of range");
-                    let name = entry.finalized.fn_name.clone();
-                    let test_setup = self.setup()?;
-                    TestExecutor::new(
-                        &pkg_with_tests.bytecode.bytes,
-                        offset,
-                        test_setup,
-                        test_entry,
-                        name,
-                    )
-                    .execute()
+                }
+                Some((entry, test_entry))
+            })
+            .enumerate()
+            .partition(|(_, (_, test_entry))| test_entry.shared_state);
+
+        let execute = |entry: &pkg::PkgEntry, test_entry: &pkg::PkgTestEntry, test_setup| {
+            let offset =
+                u32::try_from(entry.finalized.imm).expect("test instruction offset out of range");
+            let name = entry.finalized.fn_name.clone();
+            let mut executor = TestExecutor::new(
+                &pkg_with_tests.bytecode.bytes,
+                offset,
+                test_setup,
+                test_entry,
+                name,
+            );
+            let result = executor.execute()?;
+            anyhow::Ok((result, executor.interpreter.as_ref().clone()))
+        };
+
+        let mut tests = test_runners.install(|| {
+            isolated_tests
+                .into_par_iter()
+                .map(|(idx, (entry, test_entry))| {
+                    let (result, _) = execute(entry, test_entry, self.setup()?)?;
+                    Ok((idx, result))
                 })
-                .collect::<anyhow::Result<_>>()
+                .collect::<anyhow::Result<Vec<_>>>()
         })?;
 
+        if !shared_state_tests.is_empty() {
+            let mut test_setup = self.setup()?;
+            for (idx, (entry, test_entry)) in shared_state_tests {
+                let (result, storage) = execute(entry, test_entry, test_setup.clone())?;
+                *test_setup.storage_mut() = storage;
+                tests.push((idx, result));
+            }
+        }
+
+        // Report the tests in the order they're declared in.
+        tests.sort_by_key(|(idx, _)| *idx);
+        let tests = tests.into_iter().map(|(_, result)| result).collect();
+
         Ok(TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
             tests,
@@ -384,9 +501,9 @@ impl<'a> PackageTests {
                 let test_setup = script_to_test.deploy()?;
                 Ok(test_setup)
             }
-            PackageTests::Predicate(_) | PackageTests::Library(_) => Ok(
-                TestSetup::WithoutDeployment(vm::storage::MemoryStorage::default()),
-            ),
+            PackageTests::Predicate(_) | PackageTests::Library(_) => {
+                Ok(TestSetup::WithoutDeployment(TestStorage::default()))
+            }
         }
     }
 }
@@ -400,10 +517,17 @@ impl Opts {
             minify: self.minify,
             binary_outfile: self.binary_outfile,
             debug_outfile: self.debug_outfile,
+            json_artifact_outfile: self.json_artifact_outfile,
+            size_profile: self.size_profile,
+            gas_report: self.gas_report,
             build_target: self.build_target,
             build_profile: self.build_profile,
             release: self.release,
             error_on_warnings: self.error_on_warnings,
+            lints: self.lints,
+            pgo_profile: self.pgo_profile,
+            inline_budget: self.inline_budget,
+            no_dse_storage: self.no_dse_storage,
             time_phases: self.time_phases,
             metrics_outfile: self.metrics_outfile,
             tests: true,
@@ -445,19 +569,30 @@ impl TestResult {
 
     /// Return [TestDetails] from the span of the function declaring this test.
     pub fn details(&self) -> anyhow::Result<TestDetails> {
-        let span_start = self.span.start();
-        let file_str = fs::read_to_string(&*self.file_path)?;
-        let line_number = file_str[..span_start]
-            .chars()
-            .filter(|&c| c == '\n')
-            .count();
-        Ok(TestDetails {
-            file_path: self.file_path.clone(),
-            line_number,
-        })
+        test_details(&self.span, &self.file_path)
     }
 }
 
+impl ListedTest {
+    /// Return [TestDetails] from the span of the function declaring this test.
+    pub fn details(&self) -> anyhow::Result<TestDetails> {
+        test_details(&self.span, &self.file_path)
+    }
+}
+
+fn test_details(span: &Span, file_path: &Arc<PathBuf>) -> anyhow::Result<TestDetails> {
+    let span_start = span.start();
+    let file_str = fs::read_to_string(&**file_path)?;
+    let line_number = file_str[..span_start]
+        .chars()
+        .filter(|&c| c == '\n')
+        .count();
+    Ok(TestDetails {
+        file_path: file_path.clone(),
+        line_number,
+    })
+}
+
 /// Used to control test runner count for forc-test. Number of runners to use can be specified using
 /// `Manual` or can be left forc-test to decide by using `Auto`.
 pub enum TestRunnerCount {
@@ -514,6 +649,31 @@ impl BuiltTests {
             })
     }
 
+    /// The tests matching the filter, in the order they are declared within each package.
+    pub fn list(&self, test_filter: Option<&TestFilter>) -> Vec<ListedTest> {
+        let pkgs: Vec<&PackageTests> = match self {
+            BuiltTests::Package(pkg) => vec![pkg],
+            BuiltTests::Workspace(workspace) => workspace.iter().collect(),
+        };
+        pkgs.iter()
+            .flat_map(|pkg| {
+                let built = pkg.built_pkg_with_tests();
+                built.bytecode.entries.iter().filter_map(|entry| {
+                    let test = entry.kind.test()?;
+                    let name = &entry.finalized.fn_name;
+                    test_filter
+                        .map_or(true, |filter| filter.filter(name))
+                        .then(|| ListedTest {
+                            package: built.descriptor.name.clone(),
+                            name: name.clone(),
+                            span: test.span.clone(),
+                            file_path: test.file_path.clone(),
+                        })
+                })
+            })
+            .collect()
+    }
+
     /// Run all built tests, return the result.
     pub fn run(
         self,
@@ -584,7 +744,7 @@ fn deployment_transaction(
     let maturity = 1u32.into();
     let asset_id = rng.gen();
     let tx_pointer = rng.gen();
-    let block_height = (u32::MAX >> 1).into();
+    let block_height = TEST_BLOCK_HEIGHT.into();
 
     let tx = tx::TransactionBuilder::create(bytecode.as_slice().into(), salt, storage_slots)
         .with_params(params)
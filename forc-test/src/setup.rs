@@ -1,3 +1,4 @@
+use crate::storage::TestStorage;
 use fuel_tx as tx;
 use fuel_vm::{self as vm};
 
@@ -5,7 +6,7 @@ use fuel_vm::{self as vm};
 pub type ContractDeploymentSetup = (tx::ContractId, vm::checked_transaction::Checked<tx::Create>);
 
 /// Required test setup for package types that requires a deployment.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DeploymentSetup {
     Script(ScriptTestSetup),
     Contract(ContractTestSetup),
@@ -13,7 +14,7 @@ pub enum DeploymentSetup {
 
 impl DeploymentSetup {
     /// Returns the storage for this test setup
-    fn storage(&self) -> &vm::storage::MemoryStorage {
+    fn storage(&self) -> &TestStorage {
         match self {
             DeploymentSetup::Script(script_setup) => &script_setup.storage,
             DeploymentSetup::Contract(contract_setup) => &contract_setup.storage,
@@ -30,21 +31,34 @@ impl DeploymentSetup {
 }
 
 /// The storage and the contract id (if a contract is being tested) for a test.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum TestSetup {
     WithDeployment(DeploymentSetup),
-    WithoutDeployment(vm::storage::MemoryStorage),
+    WithoutDeployment(TestStorage),
 }
 
 impl TestSetup {
     /// Returns the storage for this test setup
-    pub fn storage(&self) -> &vm::storage::MemoryStorage {
+    pub fn storage(&self) -> &TestStorage {
         match self {
             TestSetup::WithDeployment(deployment_setup) => deployment_setup.storage(),
             TestSetup::WithoutDeployment(storage) => storage,
         }
     }
 
+    /// Returns the storage for this test setup, to be replaced with the storage left by a test.
+    pub fn storage_mut(&mut self) -> &mut TestStorage {
+        match self {
+            TestSetup::WithDeployment(DeploymentSetup::Script(script_setup)) => {
+                &mut script_setup.storage
+            }
+            TestSetup::WithDeployment(DeploymentSetup::Contract(contract_setup)) => {
+                &mut contract_setup.storage
+            }
+            TestSetup::WithoutDeployment(storage) => storage,
+        }
+    }
+
     /// Produces an iterator yielding contract ids of contract dependencies for this test setup.
     pub fn contract_dependency_ids(&self) -> impl Iterator<Item = &tx::ContractId> + '_ {
         match self {
@@ -78,16 +92,16 @@ impl TestSetup {
 }
 
 /// The data collected to test a contract.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ContractTestSetup {
-    pub storage: vm::storage::MemoryStorage,
+    pub storage: TestStorage,
     pub contract_dependency_ids: Vec<tx::ContractId>,
     pub root_contract_id: tx::ContractId,
 }
 
 /// The data collected to test a script.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ScriptTestSetup {
-    pub storage: vm::storage::MemoryStorage,
+    pub storage: TestStorage,
     pub contract_dependency_ids: Vec<tx::ContractId>,
 }
@@ -0,0 +1,246 @@
+//! The storage tests are executed with.
+//!
+//! Besides the storage of the deployed contracts, it holds the chain the tests are executed on:
+//! the height of its latest block, the timestamps of its blocks and its block producer. The VM
+//! reads those through [InterpreterStorage] as it would from a node, so the chain manipulated with
+//! `std::test_harness` is the chain seen by the tests and the contracts they call.
+
+use fuel_tx::{Bytes32, ContractId};
+use fuel_vm::{
+    fuel_storage::{
+        Mappable, MerkleRoot, MerkleRootStorage, StorageInspect, StorageMutate, StorageRead,
+        StorageSize,
+    },
+    fuel_types::{BlockHeight, Word},
+    storage::{ContractsAssetsStorage, InterpreterStorage, MemoryStorage},
+};
+use std::{borrow::Cow, convert::Infallible};
+
+/// The height of the block tests are executed in, until they advance the chain.
+pub const TEST_BLOCK_HEIGHT: u32 = u32::MAX >> 1;
+
+/// A run of consecutive blocks produced by the test harness.
+#[derive(Debug, Clone, Copy)]
+struct ProducedBlocks {
+    /// The height of the first block.
+    start: u32,
+    /// The TAI64 timestamp of the first block.
+    timestamp: Word,
+    /// The number of seconds between two blocks.
+    block_time: Word,
+}
+
+/// A [MemoryStorage] on top of a chain that tests can advance and whose block producer they can
+/// set.
+#[derive(Debug, Clone)]
+pub struct TestStorage {
+    storage: MemoryStorage,
+    block_height: BlockHeight,
+    /// The blocks produced by `advance_blocks`, in the order they were produced in. The timestamps
+    /// of the blocks before them are the ones of [MemoryStorage].
+    produced_blocks: Vec<ProducedBlocks>,
+    coinbase: ContractId,
+}
+
+impl Default for TestStorage {
+    fn default() -> Self {
+        Self {
+            storage: MemoryStorage::default(),
+            block_height: TEST_BLOCK_HEIGHT.into(),
+            produced_blocks: vec![],
+            coinbase: ContractId::zeroed(),
+        }
+    }
+}
+
+impl TestStorage {
+    /// Returns the height of the latest block of the chain.
+    pub fn height(&self) -> BlockHeight {
+        self.block_height
+    }
+
+    /// Produces `blocks` blocks, each `block_time` seconds after the previous one.
+    ///
+    /// A transaction executes within a single block, so the new blocks are only seen by the
+    /// transactions executed afterwards.
+    pub fn advance_blocks(&mut self, blocks: u32, block_time: Word) {
+        if blocks == 0 {
+            return;
+        }
+        let height = *self.block_height;
+        self.produced_blocks.push(ProducedBlocks {
+            start: height.saturating_add(1),
+            timestamp: self.timestamp_of(height).saturating_add(block_time),
+            block_time,
+        });
+        self.block_height = height.saturating_add(blocks).into();
+    }
+
+    /// Sets the producer of the current block, seen through the `cb` instruction.
+    pub fn set_coinbase(&mut self, coinbase: ContractId) {
+        self.coinbase = coinbase;
+    }
+
+    fn timestamp_of(&self, height: u32) -> Word {
+        match self
+            .produced_blocks
+            .iter()
+            .rev()
+            .find(|blocks| blocks.start <= height)
+        {
+            Some(blocks) => blocks.timestamp.saturating_add(
+                Word::from(height - blocks.start).saturating_mul(blocks.block_time),
+            ),
+            None => self
+                .storage
+                .timestamp(height.into())
+                .unwrap_or_else(|never| match never {}),
+        }
+    }
+}
+
+impl<Type: Mappable> StorageInspect<Type> for TestStorage
+where
+    MemoryStorage: StorageInspect<Type, Error = Infallible>,
+{
+    type Error = Infallible;
+
+    fn get(&self, key: &Type::Key) -> Result<Option<Cow<Type::OwnedValue>>, Infallible> {
+        StorageInspect::<Type>::get(&self.storage, key)
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, Infallible> {
+        StorageInspect::<Type>::contains_key(&self.storage, key)
+    }
+}
+
+impl<Type: Mappable> StorageMutate<Type> for TestStorage
+where
+    MemoryStorage: StorageMutate<Type, Error = Infallible>,
+{
+    fn insert(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Infallible> {
+        StorageMutate::<Type>::insert(&mut self.storage, key, value)
+    }
+
+    fn remove(&mut self, key: &Type::Key) -> Result<Option<Type::OwnedValue>, Infallible> {
+        StorageMutate::<Type>::remove(&mut self.storage, key)
+    }
+}
+
+impl<Type: Mappable> StorageSize<Type> for TestStorage
+where
+    MemoryStorage: StorageSize<Type, Error = Infallible>,
+{
+    fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, Infallible> {
+        StorageSize::<Type>::size_of_value(&self.storage, key)
+    }
+}
+
+impl<Type: Mappable> StorageRead<Type> for TestStorage
+where
+    MemoryStorage: StorageRead<Type, Error = Infallible>,
+{
+    fn read(&self, key: &Type::Key, buf: &mut [u8]) -> Result<Option<usize>, Infallible> {
+        StorageRead::<Type>::read(&self.storage, key, buf)
+    }
+
+    fn read_alloc(&self, key: &Type::Key) -> Result<Option<Vec<u8>>, Infallible> {
+        StorageRead::<Type>::read_alloc(&self.storage, key)
+    }
+}
+
+impl<Key, Type: Mappable> MerkleRootStorage<Key, Type> for TestStorage
+where
+    MemoryStorage: MerkleRootStorage<Key, Type, Error = Infallible>,
+{
+    fn root(&self, key: &Key) -> Result<MerkleRoot, Infallible> {
+        MerkleRootStorage::<Key, Type>::root(&self.storage, key)
+    }
+}
+
+impl ContractsAssetsStorage for TestStorage {}
+
+impl InterpreterStorage for TestStorage {
+    type DataError = Infallible;
+
+    fn block_height(&self) -> Result<BlockHeight, Infallible> {
+        Ok(self.block_height)
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Infallible> {
+        Ok(self.timestamp_of(*height))
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Infallible> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Infallible> {
+        Ok(self.coinbase)
+    }
+
+    fn merkle_contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Vec<Option<Cow<Bytes32>>>, Infallible> {
+        self.storage
+            .merkle_contract_state_range(id, start_key, range)
+    }
+
+    fn merkle_contract_state_insert_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: &[Bytes32],
+    ) -> Result<usize, Infallible> {
+        self.storage
+            .merkle_contract_state_insert_range(contract, start_key, values)
+    }
+
+    fn merkle_contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: Word,
+    ) -> Result<Option<()>, Infallible> {
+        self.storage
+            .merkle_contract_state_remove_range(contract, start_key, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advancing_blocks_sets_their_timestamps() {
+        let mut storage = TestStorage::default();
+        let genesis = storage.timestamp(TEST_BLOCK_HEIGHT.into()).unwrap();
+
+        storage.advance_blocks(10, 2);
+        storage.advance_blocks(5, 7);
+
+        assert_eq!(*storage.height(), TEST_BLOCK_HEIGHT + 15);
+        assert_eq!(storage.block_height().unwrap(), storage.height());
+        let timestamp = |blocks: u32| storage.timestamp((TEST_BLOCK_HEIGHT + blocks).into());
+        assert_eq!(timestamp(0).unwrap(), genesis);
+        assert_eq!(timestamp(1).unwrap(), genesis + 2);
+        assert_eq!(timestamp(10).unwrap(), genesis + 20);
+        assert_eq!(timestamp(11).unwrap(), genesis + 27);
+        assert_eq!(timestamp(15).unwrap(), genesis + 55);
+    }
+
+    #[test]
+    fn advancing_no_blocks_keeps_the_chain() {
+        let mut storage = TestStorage::default();
+        storage.advance_blocks(0, 100);
+        assert_eq!(*storage.height(), TEST_BLOCK_HEIGHT);
+        assert!(storage.produced_blocks.is_empty());
+    }
+}
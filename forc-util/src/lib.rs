@@ -8,13 +8,14 @@ use ansi_term::Colour;
 use anyhow::{bail, Context, Result};
 use forc_tracing::{println_red_err, println_yellow_err};
 use std::{
-    collections::{hash_map, HashSet},
+    collections::{hash_map, BTreeMap, HashSet},
     fmt::Display,
     fs::File,
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
     process::Termination,
     str,
+    sync::OnceLock,
 };
 use sway_core::language::parsed::TreeType;
 use sway_error::{
@@ -22,7 +23,7 @@ use sway_error::{
     error::CompileError,
     warning::CompileWarning,
 };
-use sway_types::{LineCol, SourceEngine, Span};
+use sway_types::{LineCol, LineTable, SourceEngine, Span};
 use sway_utils::constants;
 use tracing::error;
 
@@ -36,6 +37,7 @@ pub use regex::Regex;
 pub use serial_test;
 
 pub const DEFAULT_OUTPUT_DIRECTORY: &str = "out";
+pub const DEFAULT_DIAGNOSTIC_CONTEXT_LINES: usize = 2;
 pub const DEFAULT_ERROR_EXIT_CODE: u8 = 1;
 pub const DEFAULT_SUCCESS_EXIT_CODE: u8 = 0;
 
@@ -359,6 +361,56 @@ pub fn print_compiling(ty: Option<&TreeType>, name: &str, src: &dyn std::fmt::Di
     );
 }
 
+/// The format in which compiler diagnostics are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiagnosticFormat {
+    /// The message of the diagnostic along with the annotated source code.
+    #[default]
+    Full,
+    /// A single line per diagnostic, with its location and message.
+    Compact,
+}
+
+/// Options for printing compiler diagnostics, shared by all of the diagnostics printed by the
+/// process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticOptions {
+    pub format: DiagnosticFormat,
+    /// The number of lines of source code shown before and after the annotated code.
+    pub context_lines: usize,
+    /// Whether the diagnostics are colored. Disabled by default when the `NO_COLOR` environment
+    /// variable is set.
+    pub color: bool,
+    /// The width of the terminal, to which the messages of the diagnostics are wrapped. `None`
+    /// when not printing to a terminal.
+    pub terminal_width: Option<usize>,
+}
+
+impl Default for DiagnosticOptions {
+    fn default() -> Self {
+        Self {
+            format: DiagnosticFormat::default(),
+            context_lines: DEFAULT_DIAGNOSTIC_CONTEXT_LINES,
+            color: std::env::var_os("NO_COLOR").map_or(true, |no_color| no_color.is_empty()),
+            terminal_width: terminal_size::terminal_size()
+                .map(|(terminal_size::Width(width), _)| width as usize),
+        }
+    }
+}
+
+static DIAGNOSTIC_OPTIONS: OnceLock<DiagnosticOptions> = OnceLock::new();
+
+/// Sets the options for printing compiler diagnostics.
+///
+/// Only the first call has an effect, so it must precede printing any diagnostics.
+pub fn set_diagnostic_options(options: DiagnosticOptions) {
+    let _ = DIAGNOSTIC_OPTIONS.set(options);
+}
+
+fn diagnostic_options() -> &'static DiagnosticOptions {
+    DIAGNOSTIC_OPTIONS.get_or_init(DiagnosticOptions::default)
+}
+
 pub fn print_warnings(
     source_engine: &SourceEngine,
     terse_mode: bool,
@@ -374,7 +426,7 @@ pub fn print_warnings(
     if !terse_mode {
         warnings
             .iter()
-            .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
+            .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine), source_engine));
     }
 
     println_yellow_err(&format!(
@@ -390,6 +442,25 @@ pub fn print_warnings(
     ));
 }
 
+/// Prints the number of warnings suppressed by allowing their lints, per lint.
+pub fn print_suppressed_warnings(suppressed: &BTreeMap<String, usize>) {
+    if suppressed.is_empty() {
+        return;
+    }
+    let total: usize = suppressed.values().sum();
+    let counts = suppressed
+        .iter()
+        .map(|(lint, count)| format!("{lint}: {count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println_yellow_err(&format!(
+        "  Suppressed {} {} ({}).",
+        total,
+        if total > 1 { "warnings" } else { "warning" },
+        counts
+    ));
+}
+
 pub fn print_on_failure(
     source_engine: &SourceEngine,
     terse_mode: bool,
@@ -405,18 +476,18 @@ pub fn print_on_failure(
             warnings
                 .iter()
                 .rev()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
+                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine), source_engine));
             errors
                 .iter()
                 .rev()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine), source_engine));
         } else {
             warnings
                 .iter()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
+                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine), source_engine));
             errors
                 .iter()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine), source_engine));
         }
     }
 
@@ -434,18 +505,29 @@ pub fn print_on_failure(
     }
 }
 
-fn format_diagnostic(diagnostic: &Diagnostic) {
+fn format_diagnostic(diagnostic: &Diagnostic, source_engine: &SourceEngine) {
     /// Temporary switch for testing the feature.
     /// Keep it false until we decide to fully support the diagnostic codes.
     const SHOW_DIAGNOSTIC_CODE: bool = false;
 
+    let options = diagnostic_options();
+    if options.format == DiagnosticFormat::Compact {
+        format_compact_diagnostic(diagnostic, source_engine);
+        return;
+    }
+
     if diagnostic.is_old_style() {
-        format_old_style_diagnostic(diagnostic.issue());
+        format_old_style_diagnostic(diagnostic.issue(), source_engine);
         return;
     }
 
     let mut label = String::new();
     get_title_label(diagnostic, &mut label);
+    let label = wrap_message(&label);
+    let help = diagnostic
+        .help()
+        .map(|help| wrap_message(help))
+        .collect::<Vec<_>>();
 
     let snippet_title = Some(Annotation {
         label: Some(label.as_str()),
@@ -461,29 +543,38 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
 
     // We first display labels from the issue file...
     if diagnostic.issue().is_in_source() {
-        snippet_slices.push(construct_slice(diagnostic.labels_in_issue_source()))
+        snippet_slices.push(construct_slice(
+            diagnostic.labels_in_issue_source(),
+            source_engine,
+        ))
     }
 
     // ...and then all the remaining labels from the other files.
     for source_path in diagnostic.related_sources(false) {
-        snippet_slices.push(construct_slice(diagnostic.labels_in_source(source_path)))
+        snippet_slices.push(construct_slice(
+            diagnostic.labels_in_source(source_path),
+            source_engine,
+        ))
     }
 
     let mut snippet_footer = Vec::<Annotation<'_>>::new();
-    for help in diagnostic.help() {
+    for help in help.iter() {
         snippet_footer.push(Annotation {
             id: None,
-            label: Some(help),
+            label: Some(help.as_str()),
             annotation_type: AnnotationType::Help,
         });
     }
+    if diagnostic.issue().span().is_synthetic() {
+        snippet_footer.push(synthetic_span_note());
+    }
 
     let snippet = Snippet {
         title: snippet_title,
         slices: snippet_slices,
         footer: snippet_footer,
         opt: FormatOptions {
-            color: true,
+            color: options.color,
             ..Default::default()
         },
     };
@@ -493,14 +584,15 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
         Level::Error => tracing::error!("{}\n____\n", DisplayList::from(snippet)),
     }
 
-    fn format_old_style_diagnostic(issue: &Issue) {
+    fn format_old_style_diagnostic(issue: &Issue, source_engine: &SourceEngine) {
         let annotation_type = label_type_to_annotation_type(issue.label_type());
+        let friendly_text = wrap_message(issue.friendly_text());
 
         let snippet_title = Some(Annotation {
             label: if issue.is_in_source() {
                 None
             } else {
-                Some(issue.friendly_text())
+                Some(friendly_text.as_str())
             },
             id: None,
             annotation_type,
@@ -512,8 +604,17 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
             let input = span.input();
             let mut start_pos = span.start();
             let mut end_pos = span.end();
-            let (mut start, end) = span.line_col();
-            let input = construct_window(&mut start, end, &mut start_pos, &mut end_pos, input);
+            let line_table = source_engine.line_table(span);
+            let mut start = line_table.line_col(start_pos);
+            let end = line_table.line_col(end_pos);
+            let input = construct_window(
+                &line_table,
+                &mut start,
+                end,
+                &mut start_pos,
+                &mut end_pos,
+                input,
+            );
 
             let slice = Slice {
                 source: input,
@@ -522,7 +623,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
                 origin: Some(issue.source_path().unwrap().as_str()),
                 fold: false,
                 annotations: vec![SourceAnnotation {
-                    label: issue.friendly_text(),
+                    label: friendly_text.as_str(),
                     annotation_type,
                     range: (start_pos, end_pos),
                 }],
@@ -533,10 +634,14 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
 
         let snippet = Snippet {
             title: snippet_title,
-            footer: vec![],
+            footer: if issue.span().is_synthetic() {
+                vec![synthetic_span_note()]
+            } else {
+                vec![]
+            },
             slices: snippet_slices,
             opt: FormatOptions {
-                color: true,
+                color: diagnostic_options().color,
                 ..Default::default()
             },
         };
@@ -544,6 +649,31 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
         tracing::error!("{}\n____\n", DisplayList::from(snippet));
     }
 
+    /// Prints the diagnostic on a single line, like `src/main.sw:3:5: error: Unknown variable`.
+    fn format_compact_diagnostic(diagnostic: &Diagnostic, source_engine: &SourceEngine) {
+        let issue = diagnostic.issue();
+        let location = match issue.source_path() {
+            Some(source_path) if issue.is_in_source() => {
+                let span = issue.span();
+                let start = source_engine.line_table(span).line_col(span.start());
+                format!("{}:{}:{}: ", source_path.as_str(), start.line, start.col)
+            }
+            _ => String::new(),
+        };
+        let message = match diagnostic.reason() {
+            Some(reason) if !diagnostic.is_old_style() => {
+                format!("{}: {}", reason.description(), issue.friendly_text())
+            }
+            _ => issue.friendly_text().to_string(),
+        };
+        // The message may span multiple lines, which are joined into one.
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+        match diagnostic.level() {
+            Level::Warning => tracing::warn!("{location}warning: {message}"),
+            Level::Error => tracing::error!("{location}error: {message}"),
+        }
+    }
+
     fn get_title_label(diagnostics: &Diagnostic, label: &mut String) {
         label.clear();
         if let Some(reason) = diagnostics.reason() {
@@ -559,7 +689,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
     }
 }
 
-fn construct_slice(labels: Vec<&Label>) -> Slice {
+fn construct_slice<'a>(labels: Vec<&'a Label>, source_engine: &SourceEngine) -> Slice<'a> {
     debug_assert!(
         !labels.is_empty(),
         "To construct slices, at least one label must be provided."
@@ -581,7 +711,8 @@ fn construct_slice(labels: Vec<&Label>) -> Slice {
     // Joint span of the code snippet that covers all the labels.
     let span = Span::join_all(labels.iter().map(|label| label.span().clone()));
 
-    let (source, line_start, shift_in_bytes) = construct_code_snippet(&span, source_code);
+    let (source, line_start, shift_in_bytes) =
+        construct_code_snippet(&span, source_code, source_engine);
 
     let mut annotations = vec![];
 
@@ -624,6 +755,54 @@ fn construct_slice(labels: Vec<&Label>) -> Slice {
     }
 }
 
+/// Wraps the lines of the message of a diagnostic at whitespace to fit the width of the terminal,
+/// leaving room for the prefix of the message, like `error: ` or `= help: `.
+fn wrap_message(message: &str) -> String {
+    const PREFIX_WIDTH: usize = 12;
+    match diagnostic_options().terminal_width {
+        Some(width) => wrap_text(
+            message,
+            width.saturating_sub(PREFIX_WIDTH).max(PREFIX_WIDTH),
+        ),
+        None => message.to_string(),
+    }
+}
+
+fn wrap_text(text: &str, width: usize) -> String {
+    let mut wrapped = String::with_capacity(text.len());
+    for (index, line) in text.lines().enumerate() {
+        if index > 0 {
+            wrapped.push('\n');
+        }
+        let mut line_width = 0;
+        for (word_index, word) in line.split(' ').enumerate() {
+            let word_width = word.chars().count();
+            if word_index > 0 {
+                if line_width > 0 && line_width + 1 + word_width > width {
+                    wrapped.push('\n');
+                    line_width = 0;
+                } else {
+                    wrapped.push(' ');
+                    line_width += 1;
+                }
+            }
+            wrapped.push_str(word);
+            line_width += word_width;
+        }
+    }
+    wrapped
+}
+
+/// Notes that the highlighted code is not the location of the issue, but the item for which the
+/// compiler generated the code that contains it.
+fn synthetic_span_note() -> Annotation<'static> {
+    Annotation {
+        id: None,
+        label: Some("The issue is in code generated by the compiler for the highlighted item."),
+        annotation_type: AnnotationType::Note,
+    }
+}
+
 fn label_type_to_annotation_type(label_type: LabelType) -> AnnotationType {
     match label_type {
         LabelType::Info => AnnotationType::Info,
@@ -641,43 +820,18 @@ fn label_type_to_annotation_type(label_type: LabelType) -> AnnotationType {
 ///
 /// The library we use doesn't handle auto-windowing and line numbers, so we must manually
 /// calculate the line numbers and match them up with the input window. It is a bit fiddly.
-fn construct_code_snippet<'a>(span: &Span, input: &'a str) -> (&'a str, usize, usize) {
-    // how many lines to prepend or append to the highlighted region in the window
-    const NUM_LINES_BUFFER: usize = 2;
-
-    let (start, end) = span.line_col();
-
-    let total_lines_in_input = input.chars().filter(|x| *x == '\n').count();
+fn construct_code_snippet<'a>(
+    span: &Span,
+    input: &'a str,
+    source_engine: &SourceEngine,
+) -> (&'a str, usize, usize) {
+    let line_table = source_engine.line_table(span);
+    let start = line_table.line_col(span.start());
+    let end = line_table.line_col(span.end());
     debug_assert!(end.line >= start.line);
-    let total_lines_of_highlight = end.line - start.line;
-    debug_assert!(total_lines_in_input >= total_lines_of_highlight);
-
-    let mut current_line = 0;
-    let mut lines_to_start_of_snippet = 0;
-    let mut calculated_start_ix = None;
-    let mut calculated_end_ix = None;
-    let mut pos = 0;
-    for character in input.chars() {
-        if character == '\n' {
-            current_line += 1
-        }
 
-        if current_line + NUM_LINES_BUFFER >= start.line && calculated_start_ix.is_none() {
-            calculated_start_ix = Some(pos);
-            lines_to_start_of_snippet = current_line;
-        }
-
-        if current_line >= end.line + NUM_LINES_BUFFER && calculated_end_ix.is_none() {
-            calculated_end_ix = Some(pos);
-        }
-
-        if calculated_start_ix.is_some() && calculated_end_ix.is_some() {
-            break;
-        }
-        pos += character.len_utf8();
-    }
-    let calculated_start_ix = calculated_start_ix.unwrap_or(0);
-    let calculated_end_ix = calculated_end_ix.unwrap_or(input.len());
+    let (calculated_start_ix, calculated_end_ix, lines_to_start_of_snippet) =
+        snippet_window(&line_table, start, end, input);
 
     (
         &input[calculated_start_ix..calculated_end_ix],
@@ -686,6 +840,31 @@ fn construct_code_snippet<'a>(span: &Span, input: &'a str) -> (&'a str, usize, u
     )
 }
 
+/// Given the start and end of the highlighted region, determines the byte range of the input to
+/// show in a snippet, along with the number of lines preceding it.
+///
+/// The window starts and ends at the newlines that are a few lines away from the highlighted
+/// region, or at the start and end of the input.
+fn snippet_window(
+    line_table: &LineTable,
+    start: LineCol,
+    end: LineCol,
+    input: &str,
+) -> (usize, usize, usize) {
+    // how many lines to prepend or append to the highlighted region in the window
+    let num_lines_buffer = diagnostic_options().context_lines;
+
+    // Returns the byte position of the newline that ends the given line.
+    let newline_ending = |line: usize| line_table.line_start(line + 1).map(|ix| ix - 1);
+
+    let (start_ix, lines_to_start_of_snippet) = match start.line.saturating_sub(num_lines_buffer) {
+        0 => (0, 0),
+        line => newline_ending(line).map_or((0, 0), |ix| (ix, line)),
+    };
+    let end_ix = newline_ending(end.line + num_lines_buffer).unwrap_or(input.len());
+    (start_ix, end_ix, lines_to_start_of_snippet)
+}
+
 // TODO: Remove once "old-style" diagnostic is fully replaced with new one and the backward
 //       compatibility is no longer needed.
 /// Given a start and an end position and an input, determine how much of a window to show in the
@@ -695,46 +874,17 @@ fn construct_code_snippet<'a>(span: &Span, input: &'a str) -> (&'a str, usize, u
 /// The library we use doesn't handle auto-windowing and line numbers, so we must manually
 /// calculate the line numbers and match them up with the input window. It is a bit fiddly.
 fn construct_window<'a>(
+    line_table: &LineTable,
     start: &mut LineCol,
     end: LineCol,
     start_ix: &mut usize,
     end_ix: &mut usize,
     input: &'a str,
 ) -> &'a str {
-    // how many lines to prepend or append to the highlighted region in the window
-    const NUM_LINES_BUFFER: usize = 2;
-
-    let total_lines_in_input = input.chars().filter(|x| *x == '\n').count();
     debug_assert!(end.line >= start.line);
-    let total_lines_of_highlight = end.line - start.line;
-    debug_assert!(total_lines_in_input >= total_lines_of_highlight);
-
-    let mut current_line = 0;
-    let mut lines_to_start_of_snippet = 0;
-    let mut calculated_start_ix = None;
-    let mut calculated_end_ix = None;
-    let mut pos = 0;
-    for character in input.chars() {
-        if character == '\n' {
-            current_line += 1
-        }
-
-        if current_line + NUM_LINES_BUFFER >= start.line && calculated_start_ix.is_none() {
-            calculated_start_ix = Some(pos);
-            lines_to_start_of_snippet = current_line;
-        }
 
-        if current_line >= end.line + NUM_LINES_BUFFER && calculated_end_ix.is_none() {
-            calculated_end_ix = Some(pos);
-        }
-
-        if calculated_start_ix.is_some() && calculated_end_ix.is_some() {
-            break;
-        }
-        pos += character.len_utf8();
-    }
-    let calculated_start_ix = calculated_start_ix.unwrap_or(0);
-    let calculated_end_ix = calculated_end_ix.unwrap_or(input.len());
+    let (calculated_start_ix, calculated_end_ix, lines_to_start_of_snippet) =
+        snippet_window(line_table, *start, end, input);
 
     let start_ix_bytes = *start_ix - std::cmp::min(calculated_start_ix, *start_ix);
     let end_ix_bytes = *end_ix - std::cmp::min(calculated_start_ix, *end_ix);
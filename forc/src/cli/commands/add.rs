@@ -0,0 +1,64 @@
+use crate::ops::forc_add;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+/// Add a dependency to a Forc.toml manifest file.
+///
+/// The manifest is edited in place, preserving its formatting and comments, and the lock file is
+/// updated to include the new dependency. An existing dependency with the same name is replaced.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The name of the dependency, optionally followed by a version requirement for git
+    /// dependencies, e.g. `std@0.49`.
+    ///
+    /// The version requirement selects the latest tag of the git repository that matches it.
+    pub dependency: String,
+    /// Add a dependency on the package at this path.
+    #[clap(long)]
+    pub path: Option<String>,
+    /// Add a dependency on the package in this git repository.
+    ///
+    /// When none of `--branch`, `--tag` and `--rev` is given, the dependency is pinned to the
+    /// latest tag of the repository that is a semantic version, or to its default branch if it has
+    /// no such tags.
+    #[clap(long)]
+    pub git: Option<String>,
+    /// The branch of the git repository to depend on.
+    #[clap(long, requires = "git")]
+    pub branch: Option<String>,
+    /// The tag of the git repository to depend on.
+    #[clap(long, requires = "git")]
+    pub tag: Option<String>,
+    /// The revision of the git repository to depend on.
+    #[clap(long, requires = "git")]
+    pub rev: Option<String>,
+    /// Add a dependency on the package with this IPFS CID.
+    #[clap(long)]
+    pub ipfs: Option<String>,
+    /// The name of the package to depend on, if it differs from the name of the dependency.
+    #[clap(long)]
+    pub package: Option<String>,
+    /// Add the dependency to the `[contract-dependencies]` table.
+    #[clap(long)]
+    pub contract_dep: bool,
+    /// The salt of the contract dependency.
+    #[clap(long, requires = "contract_dep")]
+    pub salt: Option<String>,
+    /// Path to the package to add the dependency to. Defaults to the current directory.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+    /// Only edit the manifest, without updating the lock file or fetching the dependency.
+    #[clap(long)]
+    pub offline: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_add::add(command)?;
+    Ok(())
+}
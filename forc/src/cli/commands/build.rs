@@ -6,6 +6,7 @@ forc_util::cli_examples! {
     [ Compile the current project => forc "build" => r#".*could not find `Forc.toml`.*"# ]
     [ Compile the current project with a different path => forc "build --path ../tests/" => r#".*could not find `Forc.toml`.*"# ]
     [ Compile the current project without updating dependencies => forc "build --locked" => r#".*could not find `Forc.toml`.*"# ]
+    [ Compile the current project and list its artifacts in a JSON manifest => forc "build --json-artifact artifacts.json" => r#".*could not find `Forc.toml`.*"# ]
 }
 
 /// Compile the current or target project.
@@ -22,6 +23,13 @@ forc_util::cli_examples! {
 ///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 /// `<project-name>-abi.json`.
+///
+/// - `contract` projects will also produce their initial storage slots in JSON format
+/// `<project-name>-storage_slots.json`, and the same slots along with the storage fields they
+/// belong to in a versioned JSON format `<project-name>-storage_fields.json`.
+///
+/// With `--json-artifact <path>`, the paths and hashes of all of the produced artifacts of every
+/// built package are also listed in a single JSON manifest written to `<path>`.
 #[derive(Debug, Default, Parser)]
 #[clap(bin_name = "forc build", version, after_help = help())]
 pub struct Command {
@@ -0,0 +1,39 @@
+use crate::ops::forc_bytecode_diff;
+use clap::Parser;
+use forc_util::ForcResult;
+use std::path::PathBuf;
+
+forc_util::cli_examples! {
+    [ Compare the bytecode of two builds => forc "bytecode-diff old.bin new.bin --old-source-map old.json --new-source-map new.json" => r#".*No such file.*"# ]
+}
+
+/// Compare the bytecode of two builds function by function.
+///
+/// The instructions of each build are attributed to the functions they were compiled from
+/// using the source maps written with `forc build --output-debug`. Instructions without a
+/// source location, as well as the data section, are reported as `<unmapped>`.
+#[derive(Debug, Parser)]
+#[clap(bin_name = "forc bytecode-diff", version, after_help = help())]
+pub struct Command {
+    /// The bytecode of the old build.
+    pub old: PathBuf,
+    /// The bytecode of the new build.
+    pub new: PathBuf,
+    /// The source map of the old build, in JSON format.
+    #[clap(long)]
+    pub old_source_map: PathBuf,
+    /// The source map of the new build, in JSON format.
+    #[clap(long)]
+    pub new_source_map: PathBuf,
+    /// Where to search for the sources of relative source map paths.
+    #[clap(short = 'S', long, default_value = ".")]
+    pub search_dir: PathBuf,
+    /// Also list the functions whose size did not change.
+    #[clap(long)]
+    pub all: bool,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_bytecode_diff::bytecode_diff(command)?;
+    Ok(())
+}
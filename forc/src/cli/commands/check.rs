@@ -2,12 +2,14 @@ use crate::ops::forc_check;
 use clap::Parser;
 use forc_pkg::source::IPFSNode;
 use forc_util::{forc_result_bail, ForcResult};
-use sway_core::{BuildTarget, Engines};
+use sway_core::{ir_generation::storage::program_storage_slots, BuildTarget, Engines};
+use sway_error::handler::Handler;
 
 forc_util::cli_examples! {
     [ Check the current project => forc "check" => r#".*could not find `Forc.toml`.*"# ]
     [ Check the current project with a different path => forc "check --path ../tests/" => r#".*could not find `Forc.toml`.*"# ]
     [ Check the current project without updating dependencies => forc "check --locked" => r#".*could not find `Forc.toml`.*"# ]
+    [ Print the initial storage slots of the current contract => forc "check --storage-slots" => r#".*could not find `Forc.toml`.*"# ]
 }
 
 /// Check the current or target project and all of its dependencies for errors.
@@ -42,13 +44,34 @@ pub struct Command {
     /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
     #[clap(long)]
     pub ipfs_node: Option<IPFSNode>,
+    /// Display the fully-qualified names of types in errors and warnings, rather than their
+    /// abbreviated forms.
+    #[clap(long)]
+    pub verbose_types: bool,
+    /// Print the initial storage slots of the contract, along with the storage fields they belong
+    /// to, in the versioned JSON format of `<project-name>-storage_fields.json`. The slots are
+    /// regenerated from the sources without generating code.
+    #[clap(long)]
+    pub storage_slots: bool,
 }
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
-    let engines = Engines::default();
+    let mut engines = Engines::default();
+    engines.set_verbose_types(command.verbose_types);
+    let storage_slots = command.storage_slots;
     let res = forc_check::check(command, &engines)?;
-    if res.0.is_none() {
+    let Some(program) = res.0 else {
         forc_result_bail!("unable to type check");
+    };
+    if storage_slots {
+        let handler = Handler::default();
+        let Ok(storage_slots) = program_storage_slots(&handler, &engines, &program) else {
+            forc_result_bail!("unable to compute the initial storage slots");
+        };
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&storage_slots).map_err(anyhow::Error::from)?
+        );
     }
     Ok(())
 }
@@ -1,14 +1,20 @@
+pub mod add;
 pub mod addr2line;
 pub mod build;
+pub mod bytecode_diff;
 pub mod check;
 pub mod clean;
 pub mod completions;
 pub mod contract_id;
 pub mod init;
+pub mod multicall;
 pub mod new;
 pub mod parse_bytecode;
 pub mod plugins;
 pub mod predicate_root;
+pub mod publish;
+pub mod remove;
 pub mod template;
 pub mod test;
 pub mod update;
+pub mod yank;
@@ -0,0 +1,44 @@
+use crate::ops::forc_multicall;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    [ Generate the multicall helpers of the current script => forc "multicall" => r#".*could not find `Forc.toml`.*"# ]
+    [ Generate the multicall helpers into a different module => forc "multicall --output src/calls.sw" => r#".*could not find `Forc.toml`.*"# ]
+}
+
+/// Generate typed multicall helpers for the interfaces imported by a script.
+///
+/// For every method of the ABIs declared by the interface packages the script depends on, a
+/// function adding a call to the method to a `std::multicall::Multicall` is generated, along
+/// with a function decoding the value the method returns. The generated module can then be
+/// declared in the script with `mod multicall;`.
+#[derive(Debug, Default, Parser)]
+#[clap(bin_name = "forc multicall", version, after_help = help())]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Path of the generated module, relative to the project directory.
+    #[clap(short, long, default_value = "src/multicall.sw")]
+    pub output: String,
+    /// Offline mode, prevents Forc from using the network when managing dependencies.
+    /// Meaning it will only try to use previously downloaded dependencies.
+    #[clap(long = "offline")]
+    pub offline_mode: bool,
+    /// Requires that the Forc.lock file is up-to-date. If the lock file is missing, or it
+    /// needs to be updated, Forc will exit with an error
+    #[clap(long)]
+    pub locked: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_multicall::multicall(command)?;
+    Ok(())
+}
@@ -26,6 +26,14 @@ pub struct Command {
     /// Set the package name. Defaults to the directory name
     #[clap(long)]
     pub name: Option<String>,
+    /// Create a contract from a template, along with its tests and an example Rust SDK harness.
+    /// The built-in templates are `counter`, `token`, `amm` and `multisig`. When used with
+    /// `--template-url`, this is the name of the template within the git repository.
+    #[clap(long)]
+    pub template: Option<String>,
+    /// Create the package from a template in a git repository.
+    #[clap(long)]
+    pub template_url: Option<String>,
     /// The path at which the project directory will be created.
     pub path: String,
 }
@@ -41,6 +49,8 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
         library,
         workspace,
         name,
+        template,
+        template_url,
         path,
     } = command;
 
@@ -78,6 +88,8 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
         library,
         workspace,
         name,
+        template,
+        template_url,
     };
 
     init(init_cmd)?;
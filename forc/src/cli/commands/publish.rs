@@ -0,0 +1,86 @@
+use crate::{
+    cli::{
+        self,
+        commands::{check, test},
+        shared::Pkg,
+    },
+    ops::forc_publish::{self, Registry},
+};
+use clap::Parser;
+use forc_pkg::manifest::PackageManifestFile;
+use forc_util::ForcResult;
+use tracing::info;
+use url::Url;
+
+/// Publish a package to a registry.
+///
+/// Before the package is uploaded, its manifest is checked for the fields a registry requires,
+/// and the package is verified: it is type checked, its tests are run, and its documentation is
+/// built with the `forc doc` plugin.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// Path to the package, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// The URL of the registry to publish to.
+    #[clap(long, env = "FORC_REGISTRY")]
+    pub registry: Url,
+    /// The token used to authenticate with the registry.
+    #[clap(long, env = "FORC_REGISTRY_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+    /// Verify and package the package without uploading it. The package is written to
+    /// `out/package`.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// Skip type checking, testing, and building the documentation of the package.
+    #[clap(long)]
+    pub no_verify: bool,
+}
+
+pub(crate) async fn exec(command: Command) -> ForcResult<()> {
+    let dir = match &command.path {
+        Some(path) => path.into(),
+        None => std::env::current_dir()?,
+    };
+    let manifest = PackageManifestFile::from_dir(&dir)?;
+    forc_publish::verify_manifest(&manifest)?;
+
+    if !command.no_verify {
+        let path = Some(manifest.dir().display().to_string());
+        info!("   Verifying {}", manifest.project.name);
+        check::exec(cli::CheckCommand {
+            path: path.clone(),
+            ..Default::default()
+        })?;
+        test::exec(cli::TestCommand {
+            build: cli::shared::Build {
+                pkg: Pkg {
+                    path,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        })?;
+        forc_publish::build_docs(manifest.dir())?;
+    }
+
+    let tarball = forc_publish::package(&manifest)?;
+    if command.dry_run {
+        let path = forc_publish::write_package(&manifest, &tarball)?;
+        info!("   Packaged {} without uploading it", path.display());
+        return Ok(());
+    }
+
+    let registry = Registry {
+        url: command.registry,
+        token: command.token,
+    };
+    forc_publish::upload(&registry, &manifest, tarball).await?;
+    info!(
+        "  Published {} {}",
+        manifest.project.name,
+        manifest.project.version.as_ref().expect("verified")
+    );
+    Ok(())
+}
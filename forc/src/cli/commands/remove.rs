@@ -0,0 +1,34 @@
+use crate::ops::forc_remove;
+use clap::Parser;
+use forc_pkg::source::IPFSNode;
+use forc_util::ForcResult;
+
+/// Remove dependencies from a Forc.toml manifest file.
+///
+/// The manifest is edited in place, preserving its formatting and comments, and the lock file is
+/// updated accordingly.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The names of the dependencies to remove.
+    #[clap(required = true)]
+    pub dependencies: Vec<String>,
+    /// Remove the dependencies from the `[contract-dependencies]` table.
+    #[clap(long)]
+    pub contract_dep: bool,
+    /// Path to the package to remove the dependencies from. Defaults to the current directory.
+    #[clap(long)]
+    pub manifest_path: Option<String>,
+    /// Only edit the manifest, without updating the lock file.
+    #[clap(long)]
+    pub offline: bool,
+    /// The IPFS Node to use for fetching IPFS sources.
+    ///
+    /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
+    #[clap(long)]
+    pub ipfs_node: Option<IPFSNode>,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_remove::remove(command)?;
+    Ok(())
+}
@@ -2,9 +2,14 @@ use crate::cli;
 use ansi_term::Colour;
 use clap::Parser;
 use forc_pkg as pkg;
-use forc_test::{TestFilter, TestRunnerCount, TestedPackage};
+use forc_test::{
+    decode::DecodedLog, BuiltTests, MissingEvent, TestFilter, TestResult, TestRunnerCount,
+    TestedPackage,
+};
 use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
 use pkg::manifest::ExperimentalFlags;
+use serde::Serialize;
+use std::path::PathBuf;
 use tracing::info;
 
 forc_util::cli_examples! {
@@ -12,6 +17,8 @@ forc_util::cli_examples! {
     [ Run test with a filter => forc "test $filter" => ".*could not find `Forc.toml`.*" ]
     [ Run test without any output => forc "test --silent" => "^$" ]
     [ Run test without creating or update the lock file  => forc "test --locked" => ".*could not find `Forc.toml`.*" ]
+    [ List tests without running them => forc "test --list" => ".*could not find `Forc.toml`.*" ]
+    [ Run test and print a JUnit report => forc "test --format junit" => ".*could not find `Forc.toml`.*" ]
 }
 
 /// Run the Sway unit tests for the current project.
@@ -31,7 +38,7 @@ forc_util::cli_examples! {
 /// Upon successful compilation, test scripts are executed to their completion. A test is
 /// considered a failure in the case that a revert (`rvrt`) instruction is encountered during
 /// execution. Otherwise, it is considered a success.
-#[derive(Debug, Parser)]
+#[derive(Debug, Default, Parser)]
 pub struct Command {
     #[clap(flatten)]
     pub build: cli::shared::Build,
@@ -46,6 +53,9 @@ pub struct Command {
     /// Number of threads to utilize when running the tests. By default, this is the number of
     /// threads available in your system.
     pub test_threads: Option<usize>,
+    #[clap(long)]
+    /// List the tests matching the filter, without running them.
+    pub list: bool,
 
     #[clap(long)]
     /// Experimental flag for the "new encoding" feature
@@ -53,15 +63,30 @@ pub struct Command {
 }
 
 /// The set of options provided for controlling output of a test.
-#[derive(Parser, Debug, Clone)]
+#[derive(Parser, Debug, Clone, Default)]
 #[clap(after_help = help())]
 pub struct TestPrintOpts {
     #[clap(long = "pretty-print", short = 'r')]
-    /// Pretty-print the logs emiited from tests.
+    /// Pretty-print the receipts of logs emitted from tests that cannot be decoded.
     pub pretty_print: bool,
-    /// Print `Log` and `LogData` receipts for tests.
+    /// Print the values logged by tests, decoded using the ABI of the package.
     #[clap(long = "logs", short = 'l')]
     pub print_logs: bool,
+    /// The format in which the tests, or their results, are printed.
+    #[clap(long, value_enum, default_value_t = TestOutputFormat::Human)]
+    pub format: TestOutputFormat,
+}
+
+/// The format in which the tests, or their results, are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum TestOutputFormat {
+    /// A line per test, followed by the details of the failed tests.
+    #[default]
+    Human,
+    /// A JSON report, with the status, duration, gas used and logs of each test.
+    Json,
+    /// A JUnit XML report, as consumed by CI dashboards.
+    Junit,
 }
 
 pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
@@ -76,38 +101,56 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
         filter_phrase,
         exact_match: cmd.filter_exact,
     });
+    let list = cmd.list;
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
+    if list {
+        return print_test_list(&built_tests, test_filter.as_ref(), test_print_opts.format);
+    }
+
     let start = std::time::Instant::now();
     let test_count = built_tests.test_count(test_filter.as_ref());
     let num_tests_running = test_count.total - test_count.ignored;
     let num_tests_ignored = test_count.ignored;
-    info!(
-        "   Running {} {}, filtered {} {}",
-        num_tests_running,
-        formatted_test_count_string(&num_tests_running),
-        num_tests_ignored,
-        formatted_test_count_string(&num_tests_ignored)
-    );
+    if test_print_opts.format == TestOutputFormat::Human {
+        info!(
+            "   Running {} {}, filtered {} {}",
+            num_tests_running,
+            formatted_test_count_string(&num_tests_running),
+            num_tests_ignored,
+            formatted_test_count_string(&num_tests_ignored)
+        );
+    }
     let tested = built_tests.run(test_runner_count, test_filter)?;
     let duration = start.elapsed();
 
-    // Eventually we'll print this in a fancy manner, but this will do for testing.
-    let all_tests_passed = match tested {
-        forc_test::Tested::Workspace(pkgs) => {
-            for pkg in &pkgs {
-                let built = &pkg.built.descriptor.name;
-                info!("\n   tested -- {built}\n");
-                print_tested_pkg(pkg, &test_print_opts)?;
+    let tested_pkgs: Vec<&TestedPackage> = match &tested {
+        forc_test::Tested::Workspace(pkgs) => pkgs.iter().collect(),
+        forc_test::Tested::Package(pkg) => vec![pkg.as_ref()],
+    };
+    match test_print_opts.format {
+        // Eventually we'll print this in a fancy manner, but this will do for testing.
+        TestOutputFormat::Human => match &tested {
+            forc_test::Tested::Workspace(pkgs) => {
+                for pkg in pkgs {
+                    let built = &pkg.built.descriptor.name;
+                    info!("\n   tested -- {built}\n");
+                    print_tested_pkg(pkg, &test_print_opts)?;
+                }
+                info!("\n   Finished in {:?}", duration);
             }
-            info!("\n   Finished in {:?}", duration);
-            pkgs.iter().all(|pkg| pkg.tests_passed())
+            forc_test::Tested::Package(pkg) => print_tested_pkg(pkg, &test_print_opts)?,
+        },
+        TestOutputFormat::Json => {
+            let report = json_test_report(&tested_pkgs, test_print_opts.pretty_print)?;
+            println!("{}", to_json(&report)?);
         }
-        forc_test::Tested::Package(pkg) => {
-            print_tested_pkg(&pkg, &test_print_opts)?;
-            pkg.tests_passed()
+        TestOutputFormat::Junit => {
+            let report = junit_test_report(&tested_pkgs, test_print_opts.pretty_print)?;
+            println!("{report}");
         }
-    };
+    }
+    let all_tests_passed = tested_pkgs.iter().all(|pkg| pkg.tests_passed());
 
     if all_tests_passed {
         Ok(())
@@ -138,9 +181,10 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
 
         // If logs are enabled, print them.
         if test_print_opts.print_logs {
-            let logs = &test.logs;
-            let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
-            info!("{}", formatted_logs);
+            let formatted_logs = format_logs(pkg, test, test_print_opts.pretty_print)?;
+            if !formatted_logs.is_empty() {
+                info!("{}", formatted_logs);
+            }
         }
 
         // If the test is failing, save the test result for printing the details later on.
@@ -159,8 +203,7 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             let failed_test_details = failed_test.details()?;
             let path = &*failed_test_details.file_path;
             let line_number = failed_test_details.line_number;
-            let logs = &failed_test.logs;
-            let formatted_logs = format_log_receipts(logs, test_print_opts.pretty_print)?;
+            let formatted_logs = format_logs(pkg, failed_test, test_print_opts.pretty_print)?;
             info!(
                 "      - test {}, {:?}:{} ",
                 failed_test_name, path, line_number
@@ -175,7 +218,18 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 }
                 info!("{failed_info_str}");
             }
-            info!("        Logs: {}", formatted_logs);
+            if let Some(location) = pkg.revert_location(failed_test) {
+                info!("        reverted at {location}");
+            }
+            if let Some(missing_event) = pkg.missing_event(failed_test) {
+                info!(
+                    "{}",
+                    format_missing_event(&missing_event, test_print_opts.pretty_print)?
+                );
+            }
+            if !formatted_logs.is_empty() {
+                info!("        Logs:\n{formatted_logs}");
+            }
         }
         info!("\n");
     }
@@ -196,6 +250,290 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
     Ok(())
 }
 
+/// Formats the logs of a test, one per line, along with the location of the `log` call that
+/// emitted them. Values that the ABI of the package does not describe are printed as receipts.
+fn format_logs(pkg: &TestedPackage, test: &TestResult, pretty_print: bool) -> ForcResult<String> {
+    let mut lines = vec![];
+    for log in pkg.decoded_logs(test) {
+        let value = format_log_value(&log, pretty_print)?;
+        let line = match log.location {
+            Some(location) => format!("        {location}: {value}"),
+            None => format!("        {value}"),
+        };
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Formats an event that a test expected, followed by the events of the same type that were
+/// emitted instead.
+fn format_missing_event(missing_event: &MissingEvent, pretty_print: bool) -> ForcResult<String> {
+    let format_value = |log: &DecodedLog| format_log_value(log, pretty_print);
+    let mut lines = vec!["        event not emitted:".to_string()];
+    lines.push(format!(
+        "        - expected: {}",
+        format_value(&missing_event.expected)?
+    ));
+    if missing_event.emitted.is_empty() {
+        lines.push("        + emitted:  no event of the same type".to_string());
+    }
+    for log in &missing_event.emitted {
+        lines.push(format!("        + emitted:  {}", format_value(log)?));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Prints the tests matching the filter, without running them.
+fn print_test_list(
+    built_tests: &BuiltTests,
+    test_filter: Option<&TestFilter>,
+    format: TestOutputFormat,
+) -> ForcResult<()> {
+    let tests = built_tests.list(test_filter);
+    match format {
+        TestOutputFormat::Human => {
+            for test in &tests {
+                info!("{}::{}: test", test.package, test.name);
+            }
+            info!(
+                "\n{} {}",
+                tests.len(),
+                formatted_test_count_string(&tests.len())
+            );
+        }
+        TestOutputFormat::Json => {
+            let listed = tests
+                .iter()
+                .map(|test| {
+                    let details = test.details()?;
+                    Ok(ListedTestJson {
+                        package: &test.package,
+                        name: &test.name,
+                        file_path: details.file_path.to_path_buf(),
+                        line_number: details.line_number,
+                    })
+                })
+                .collect::<ForcResult<Vec<_>>>()?;
+            println!("{}", to_json(&listed)?);
+        }
+        TestOutputFormat::Junit => {
+            // JUnit reports describe the results of tests, so the tests are listed as skipped.
+            let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+            xml.push_str(&format!("<testsuites tests=\"{}\">\n", tests.len()));
+            for test in &tests {
+                xml.push_str(&format!(
+                    "  <testcase name=\"{}\" classname=\"{}\">\n    <skipped/>\n  </testcase>\n",
+                    xml_escape(&test.name),
+                    xml_escape(&test.package)
+                ));
+            }
+            xml.push_str("</testsuites>");
+            println!("{xml}");
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct ListedTestJson<'a> {
+    package: &'a str,
+    name: &'a str,
+    file_path: PathBuf,
+    line_number: usize,
+}
+
+#[derive(Serialize)]
+struct TestedPackageJson<'a> {
+    name: &'a str,
+    passed: usize,
+    failed: usize,
+    tests: Vec<TestResultJson<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum TestStatus {
+    Passed,
+    Failed,
+}
+
+#[derive(Serialize)]
+struct TestResultJson<'a> {
+    name: &'a str,
+    status: TestStatus,
+    file_path: PathBuf,
+    line_number: usize,
+    duration_secs: f64,
+    gas_used: u64,
+    revert_code: Option<u64>,
+    reverted_at: Option<String>,
+    logs: Vec<LogJson>,
+}
+
+#[derive(Serialize)]
+struct LogJson {
+    location: Option<String>,
+    value: String,
+}
+
+/// The results of the tests of each package, along with their logs.
+fn json_test_report<'a>(
+    pkgs: &[&'a TestedPackage],
+    pretty_print: bool,
+) -> ForcResult<Vec<TestedPackageJson<'a>>> {
+    pkgs.iter()
+        .map(|pkg| {
+            let tests = pkg
+                .tests
+                .iter()
+                .map(|test| {
+                    let details = test.details()?;
+                    let logs = pkg
+                        .decoded_logs(test)
+                        .into_iter()
+                        .map(|log| {
+                            Ok(LogJson {
+                                location: log.location.as_ref().map(ToString::to_string),
+                                value: format_log_value(&log, pretty_print)?,
+                            })
+                        })
+                        .collect::<ForcResult<Vec<_>>>()?;
+                    Ok(TestResultJson {
+                        name: &test.name,
+                        status: if test.passed() {
+                            TestStatus::Passed
+                        } else {
+                            TestStatus::Failed
+                        },
+                        file_path: details.file_path.to_path_buf(),
+                        line_number: details.line_number,
+                        duration_secs: test.duration.as_secs_f64(),
+                        gas_used: test.gas_used,
+                        revert_code: test.revert_code(),
+                        reverted_at: pkg
+                            .revert_location(test)
+                            .map(|location| location.to_string()),
+                        logs,
+                    })
+                })
+                .collect::<ForcResult<Vec<_>>>()?;
+            let passed = pkg.tests.iter().filter(|test| test.passed()).count();
+            Ok(TestedPackageJson {
+                name: &pkg.built.descriptor.name,
+                passed,
+                failed: pkg.tests.len() - passed,
+                tests,
+            })
+        })
+        .collect()
+}
+
+/// The results of the tests as a JUnit XML report, with a test suite per package. The gas used
+/// by each test is reported as a property, and its logs as its output.
+fn junit_test_report(pkgs: &[&TestedPackage], pretty_print: bool) -> ForcResult<String> {
+    let total_count: usize = pkgs.iter().map(|pkg| pkg.tests.len()).sum();
+    let total_failures: usize = pkgs
+        .iter()
+        .map(|pkg| pkg.tests.iter().filter(|test| !test.passed()).count())
+        .sum();
+    let total_time: f64 = pkgs
+        .iter()
+        .flat_map(|pkg| &pkg.tests)
+        .map(|test| test.duration.as_secs_f64())
+        .sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites tests=\"{total_count}\" failures=\"{total_failures}\" time=\"{total_time}\">\n"
+    ));
+    for pkg in pkgs {
+        let name = xml_escape(&pkg.built.descriptor.name);
+        let failures = pkg.tests.iter().filter(|test| !test.passed()).count();
+        let time: f64 = pkg
+            .tests
+            .iter()
+            .map(|test| test.duration.as_secs_f64())
+            .sum();
+        xml.push_str(&format!(
+            "  <testsuite name=\"{name}\" tests=\"{}\" failures=\"{failures}\" time=\"{time}\">\n",
+            pkg.tests.len()
+        ));
+        for test in &pkg.tests {
+            let details = test.details()?;
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{name}\" time=\"{}\" file=\"{}\" line=\"{}\">\n",
+                xml_escape(&test.name),
+                test.duration.as_secs_f64(),
+                xml_escape(&details.file_path.display().to_string()),
+                details.line_number
+            ));
+            xml.push_str(&format!(
+                "      <properties>\n        <property name=\"gas_used\" value=\"{}\"/>\n      </properties>\n",
+                test.gas_used
+            ));
+            if !test.passed() {
+                let message = match test.revert_code() {
+                    Some(revert_code) => format!("revert code: {revert_code:x}"),
+                    None => "test did not revert as expected".to_string(),
+                };
+                let location = pkg
+                    .revert_location(test)
+                    .map(|location| format!("reverted at {location}"))
+                    .unwrap_or_default();
+                xml.push_str(&format!(
+                    "      <failure message=\"{}\">{}</failure>\n",
+                    xml_escape(&message),
+                    xml_escape(&location)
+                ));
+            }
+            let logs = pkg
+                .decoded_logs(test)
+                .iter()
+                .map(|log| {
+                    let value = format_log_value(log, pretty_print)?;
+                    Ok(match &log.location {
+                        Some(location) => format!("{location}: {value}"),
+                        None => value,
+                    })
+                })
+                .collect::<ForcResult<Vec<_>>>()?;
+            if !logs.is_empty() {
+                xml.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    xml_escape(&logs.join("\n"))
+                ));
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>");
+    Ok(xml)
+}
+
+/// The decoded value of a log, or its receipt if the ABI of the package does not describe it.
+fn format_log_value(log: &DecodedLog, pretty_print: bool) -> ForcResult<String> {
+    match &log.value {
+        Some(value) => Ok(value.clone()),
+        None => Ok(format_log_receipts(
+            std::slice::from_ref(&log.receipt),
+            pretty_print,
+        )?),
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn to_json<T: Serialize>(value: &T) -> ForcResult<String> {
+    Ok(serde_json::to_string_pretty(value).map_err(anyhow::Error::from)?)
+}
+
 fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
     forc_test::Opts {
         pkg: pkg::PkgOpts {
@@ -213,8 +551,10 @@ fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
             dca_graph_url_format: cmd.build.print.dca_graph_url_format,
             finalized_asm: cmd.build.print.finalized_asm,
             intermediate_asm: cmd.build.print.intermediate_asm,
+            asm_stats: cmd.build.print.asm_stats,
             ir: cmd.build.print.ir,
             reverse_order: cmd.build.print.reverse_order,
+            verbose_types: cmd.build.print.verbose_types,
         },
         time_phases: cmd.build.print.time_phases,
         metrics_outfile: cmd.build.print.metrics_outfile,
@@ -225,11 +565,19 @@ fn opts_from_cmd(cmd: Command) -> forc_test::Opts {
         build_profile: cmd.build.profile.build_profile,
         release: cmd.build.profile.release,
         error_on_warnings: cmd.build.profile.error_on_warnings,
+        lints: cmd.build.profile.lints(),
+        pgo_profile: cmd.build.profile.pgo_profile,
+        inline_budget: cmd.build.profile.inline_budget,
+        no_dse_storage: cmd.build.profile.no_dse_storage,
         binary_outfile: cmd.build.output.bin_file,
         debug_outfile: cmd.build.output.debug_file,
+        json_artifact_outfile: cmd.build.output.json_artifact,
+        size_profile: cmd.build.output.size_profile,
+        gas_report: cmd.build.output.gas_report,
         build_target: cmd.build.build_target,
         experimental: ExperimentalFlags {
             new_encoding: cmd.experimental_new_encoding,
+            ..Default::default()
         },
     }
 }
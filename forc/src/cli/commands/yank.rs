@@ -0,0 +1,41 @@
+use crate::ops::forc_publish::{self, Registry};
+use clap::Parser;
+use forc_util::{forc_result_bail, ForcResult};
+use tracing::info;
+use url::Url;
+
+/// Yank a published version of a package from a registry.
+///
+/// A yanked version can no longer be added as a new dependency, but packages that already depend
+/// on it can still fetch it.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The package and version to yank, e.g. `my_lib@0.1.0`.
+    pub package: String,
+    /// Undo a yank, making the version available again.
+    #[clap(long)]
+    pub undo: bool,
+    /// The URL of the registry the package was published to.
+    #[clap(long, env = "FORC_REGISTRY")]
+    pub registry: Url,
+    /// The token used to authenticate with the registry.
+    #[clap(long, env = "FORC_REGISTRY_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+}
+
+pub(crate) async fn exec(command: Command) -> ForcResult<()> {
+    let Some((name, version)) = command.package.split_once('@') else {
+        forc_result_bail!("expected a package and a version, e.g. `my_lib@0.1.0`");
+    };
+    if let Err(e) = semver::Version::parse(version) {
+        forc_result_bail!("invalid version `{version}`: {e}");
+    }
+    let registry = Registry {
+        url: command.registry,
+        token: command.token,
+    };
+    forc_publish::yank(&registry, name, version, command.undo).await?;
+    let action = if command.undo { "Unyanked" } else { "Yanked" };
+    info!("     {action} {name}@{version}");
+    Ok(())
+}
@@ -1,31 +1,39 @@
 use std::str::FromStr;
 
 use self::commands::{
-    addr2line, build, check, clean, completions, contract_id, init, new, parse_bytecode, plugins,
-    predicate_root, template, test, update,
+    add, addr2line, build, bytecode_diff, check, clean, completions, contract_id, init, multicall,
+    new, parse_bytecode, plugins, predicate_root, publish, remove, template, test, update, yank,
 };
+pub use add::Command as AddCommand;
 use addr2line::Command as Addr2LineCommand;
 use anyhow::anyhow;
 pub use build::Command as BuildCommand;
+pub(crate) use bytecode_diff::Command as BytecodeDiffCommand;
 pub use check::Command as CheckCommand;
 use clap::{Parser, Subcommand};
 pub use clean::Command as CleanCommand;
 pub use completions::Command as CompletionsCommand;
 pub(crate) use contract_id::Command as ContractIdCommand;
 use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions};
-use forc_util::ForcResult;
+use forc_util::{
+    DiagnosticFormat, DiagnosticOptions, ForcResult, DEFAULT_DIAGNOSTIC_CONTEXT_LINES,
+};
 pub use init::Command as InitCommand;
+pub(crate) use multicall::Command as MulticallCommand;
 pub use new::Command as NewCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
 pub use plugins::Command as PluginsCommand;
 pub(crate) use predicate_root::Command as PredicateRootCommand;
+pub use publish::Command as PublishCommand;
+pub use remove::Command as RemoveCommand;
 pub use template::Command as TemplateCommand;
 pub use test::Command as TestCommand;
 use tracing::metadata::LevelFilter;
 pub use update::Command as UpdateCommand;
+pub use yank::Command as YankCommand;
 
 mod commands;
-mod plugin;
+pub(crate) mod plugin;
 pub mod shared;
 
 fn help() -> &'static str {
@@ -61,23 +69,37 @@ struct Opt {
     /// Set the log level
     #[clap(short='L', long, global = true, parse(try_from_str = LevelFilter::from_str))]
     log_level: Option<LevelFilter>,
+
+    /// The format of the printed compiler diagnostics
+    #[clap(long, value_enum, global = true, default_value_t = DiagnosticFormat::Full)]
+    diagnostic_format: DiagnosticFormat,
+
+    /// The number of lines of source code shown around the code annotated by compiler diagnostics
+    #[clap(long, global = true, default_value_t = DEFAULT_DIAGNOSTIC_CONTEXT_LINES)]
+    diagnostic_context_lines: usize,
 }
 
 #[derive(Subcommand, Debug)]
 enum Forc {
+    Add(AddCommand),
     #[clap(name = "addr2line")]
     Addr2Line(Addr2LineCommand),
     #[clap(visible_alias = "b")]
     Build(BuildCommand),
+    BytecodeDiff(BytecodeDiffCommand),
     Check(CheckCommand),
     Clean(CleanCommand),
     Completions(CompletionsCommand),
     New(NewCommand),
     Init(InitCommand),
+    Multicall(MulticallCommand),
     ParseBytecode(ParseBytecodeCommand),
     #[clap(visible_alias = "t")]
     Test(TestCommand),
     Update(UpdateCommand),
+    Remove(RemoveCommand),
+    Publish(PublishCommand),
+    Yank(YankCommand),
     Plugins(PluginsCommand),
     Template(TemplateCommand),
     ContractId(ContractIdCommand),
@@ -104,19 +126,30 @@ pub async fn run_cli() -> ForcResult<()> {
     };
 
     init_tracing_subscriber(tracing_options);
+    forc_util::set_diagnostic_options(DiagnosticOptions {
+        format: opt.diagnostic_format,
+        context_lines: opt.diagnostic_context_lines,
+        ..Default::default()
+    });
 
     match opt.command {
+        Forc::Add(command) => add::exec(command),
         Forc::Addr2Line(command) => addr2line::exec(command),
         Forc::Build(command) => build::exec(command),
+        Forc::BytecodeDiff(command) => bytecode_diff::exec(command),
         Forc::Check(command) => check::exec(command),
         Forc::Clean(command) => clean::exec(command),
         Forc::Completions(command) => completions::exec(command),
         Forc::Init(command) => init::exec(command),
+        Forc::Multicall(command) => multicall::exec(command),
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
         Forc::Plugins(command) => plugins::exec(command),
         Forc::Test(command) => test::exec(command),
         Forc::Update(command) => update::exec(command).await,
+        Forc::Remove(command) => remove::exec(command),
+        Forc::Publish(command) => publish::exec(command).await,
+        Forc::Yank(command) => yank::exec(command).await,
         Forc::Template(command) => template::exec(command),
         Forc::ContractId(command) => contract_id::exec(command),
         Forc::PredicateRoot(command) => predicate_root::exec(command),
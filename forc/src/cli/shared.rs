@@ -2,7 +2,8 @@
 
 use clap::{Args, Parser};
 use forc_pkg::source::IPFSNode;
-use sway_core::BuildTarget;
+use std::{collections::BTreeMap, path::PathBuf};
+use sway_core::{lint::LintLevel, BuildTarget};
 
 /// Args that can be shared between all commands that `build` a package. E.g. `build`, `test`,
 /// `deploy`.
@@ -32,6 +33,20 @@ pub struct BuildOutput {
     /// If set, outputs source file mapping in JSON format
     #[clap(long = "output-debug", short = 'g')]
     pub debug_file: Option<String>,
+    /// If set, outputs a JSON manifest listing the artifacts of every built package, including the
+    /// paths and hashes of their bytecode, ABI, storage slots and source map.
+    #[clap(long = "json-artifact")]
+    pub json_artifact: Option<String>,
+    /// Attributes the bytes of the bytecode to the functions they were compiled from, printing
+    /// the largest functions and writing the report in JSON format to the output directory.
+    #[clap(long)]
+    pub size_profile: bool,
+    /// Prints a static estimate of the gas used by each contract method, or by the `main`
+    /// function of a script or predicate, and adds the estimates to the ABI. Estimates prefixed
+    /// with `~` are approximations, as the function contains loops, recursion or operations whose
+    /// cost depends on the size of their operands.
+    #[clap(long)]
+    pub gas_report: bool,
 }
 
 /// Build profile options.
@@ -50,6 +65,52 @@ pub struct BuildProfile {
     /// Treat warnings as errors.
     #[clap(long)]
     pub error_on_warnings: bool,
+    /// Treat the warnings of the given lint as errors, e.g. `--deny dead_code`.
+    ///
+    /// `--deny warnings` treats all of the warnings as errors.
+    #[clap(long, value_name = "LINT")]
+    pub deny: Vec<String>,
+    /// Report the warnings of the given lint as warnings, overriding the other lint options.
+    #[clap(long, value_name = "LINT")]
+    pub warn: Vec<String>,
+    /// Suppress the warnings of the given lint, e.g. `--allow deprecated`.
+    ///
+    /// The number of suppressed warnings of each lint is reported at the end of the build.
+    #[clap(long, value_name = "LINT")]
+    pub allow: Vec<String>,
+    /// Bias the optimizations towards the hot paths of the program, as measured in the given
+    /// execution profile.
+    ///
+    /// The profile is a JSON file holding the gas spent in each function of the program, keyed by
+    /// the name of the function, e.g. `{ "functions": { "transfer": 120000, "owner": 300 } }`.
+    #[clap(long = "pgo", value_name = "PROFILE")]
+    pub pgo_profile: Option<PathBuf>,
+    /// The number of instructions the bytecode may grow by when inlining functions.
+    ///
+    /// Calls whose inlining doesn't grow the bytecode, as estimated by the compiler, are always
+    /// inlined. The budget is spent on the calls worth inlining even though it grows the bytecode,
+    /// e.g. calls to hot functions. Unlimited by default.
+    #[clap(long, value_name = "INSTRUCTIONS")]
+    pub inline_budget: Option<u64>,
+    /// Keep the storage writes which are overwritten before being read, which are otherwise
+    /// removed.
+    #[clap(long)]
+    pub no_dse_storage: bool,
+}
+
+impl BuildProfile {
+    /// The levels of the lints given with `--deny`, `--warn` and `--allow`. A lint given with more
+    /// than one of them is denied over being warned about, and warned about over being allowed.
+    pub fn lints(&self) -> BTreeMap<String, LintLevel> {
+        let allowed = self.allow.iter().map(|lint| (lint, LintLevel::Allow));
+        let warned = self.warn.iter().map(|lint| (lint, LintLevel::Warn));
+        let denied = self.deny.iter().map(|lint| (lint, LintLevel::Deny));
+        allowed
+            .chain(warned)
+            .chain(denied)
+            .map(|(lint, level)| (lint.clone(), level))
+            .collect()
+    }
 }
 
 /// Options related to printing stages of compiler output.
@@ -80,6 +141,11 @@ pub struct Print {
     /// optimisations.
     #[clap(long)]
     pub intermediate_asm: bool,
+    /// Print the number of instructions of the finalized ASM before and after the peephole
+    /// optimizations, and how many times each of them was applied.  The peephole optimizations
+    /// only run in optimized builds, e.g. `--release`.
+    #[clap(long)]
+    pub asm_stats: bool,
     /// Print the generated Sway IR (Intermediate Representation).
     #[clap(long)]
     pub ir: bool,
@@ -89,6 +155,10 @@ pub struct Print {
     /// Output build errors and warnings in reverse order.
     #[clap(long)]
     pub reverse_order: bool,
+    /// Display the fully-qualified names of types in errors and warnings, rather than their
+    /// abbreviated forms.
+    #[clap(long)]
+    pub verbose_types: bool,
     /// Output compilation metrics into file.
     #[clap(long)]
     pub metrics_outfile: Option<String>,
@@ -0,0 +1,322 @@
+use crate::cli::AddCommand;
+use anyhow::{anyhow, bail, Context, Result};
+use forc_pkg::{
+    self as pkg,
+    manifest::{ManifestFile, PackageManifestFile},
+    source::{git, IPFSNode},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tracing::info;
+
+/// The table of the manifest that regular dependencies are added to.
+const DEPENDENCIES_TABLE: &str = "dependencies";
+/// The table of the manifest that contract dependencies are added to.
+const CONTRACT_DEPENDENCIES_TABLE: &str = "contract-dependencies";
+
+/// Adds a dependency to the `Forc.toml` of a package, and updates the lock file.
+///
+/// The manifest is edited with `toml_edit` so that its formatting and comments are preserved. If
+/// the edited manifest turns out to be invalid, or the dependency cannot be fetched, the original
+/// manifest is restored.
+pub fn add(command: AddCommand) -> Result<()> {
+    let (name, dependency) = dependency(&command)?;
+    let table = dependencies_table(command.contract_dep);
+    let manifest_path = manifest_path(command.manifest_path.as_deref())?;
+    let original = fs::read_to_string(&manifest_path)?;
+    let (edited, replaced) =
+        insert_dependency(&original, &manifest_path, table, &name, dependency.clone())?;
+
+    write_manifest(
+        &manifest_path,
+        &original,
+        &edited,
+        command.offline,
+        command.ipfs_node,
+    )?;
+    let verb = if replaced { "Replaced" } else { "Added" };
+    info!("      {verb} `{name} = {dependency}` to [{table}]");
+    Ok(())
+}
+
+/// Returns the table of the manifest that dependencies are added to or removed from.
+pub(crate) fn dependencies_table(contract_dep: bool) -> &'static str {
+    match contract_dep {
+        true => CONTRACT_DEPENDENCIES_TABLE,
+        false => DEPENDENCIES_TABLE,
+    }
+}
+
+/// Returns the name of the dependency described by the command, along with its details as
+/// written in the manifest.
+///
+/// A git dependency without a reference is pinned to the latest tag of the repository matching
+/// the version requirement, which is looked up remotely.
+fn dependency(command: &AddCommand) -> Result<(String, toml_edit::InlineTable)> {
+    let (name, version_req) = match command.dependency.split_once('@') {
+        Some((name, req)) => {
+            let req = semver::VersionReq::parse(req)
+                .with_context(|| format!("invalid version requirement `{req}`"))?;
+            (name.to_string(), Some(req))
+        }
+        None => (command.dependency.clone(), None),
+    };
+    forc_util::validate_name(&name, "dependency name")?;
+    if version_req.is_some() && command.git.is_none() {
+        bail!("a version requirement can only be specified for git dependencies");
+    }
+
+    let mut dependency = toml_edit::InlineTable::new();
+    match (&command.path, &command.git, &command.ipfs) {
+        (Some(path), None, None) => {
+            dependency.insert("path", path.as_str().into());
+        }
+        (None, Some(repo), None) => {
+            dependency.insert("git", repo.as_str().into());
+            let reference = match (&command.branch, &command.tag, &command.rev) {
+                (Some(branch), None, None) => Some(("branch", branch.clone())),
+                (None, Some(tag), None) => Some(("tag", tag.clone())),
+                (None, None, Some(rev)) => Some(("rev", rev.clone())),
+                (None, None, None) => {
+                    latest_tag(repo, version_req.as_ref())?.map(|tag| ("tag", tag))
+                }
+                _ => bail!(
+                    "git dependencies support at most one reference: \
+                    either `--branch`, `--tag` or `--rev`"
+                ),
+            };
+            if let Some((key, value)) = reference {
+                dependency.insert(key, value.into());
+            }
+        }
+        (None, None, Some(cid)) => {
+            dependency.insert("ipfs", cid.as_str().into());
+        }
+        (None, None, None) => bail!(
+            "the source of `{name}` must be specified with one of `--path`, `--git` or `--ipfs`, \
+            as registry dependencies are not yet supported"
+        ),
+        _ => bail!("only one of `--path`, `--git` and `--ipfs` can be specified"),
+    }
+    if let Some(package) = &command.package {
+        dependency.insert("package", package.as_str().into());
+    }
+    if let Some(salt) = &command.salt {
+        dependency.insert("salt", salt.as_str().into());
+    }
+    Ok((name, dependency))
+}
+
+/// Returns the `manifest` read from `manifest_path` with `dependency` inserted into its `table`
+/// under `name`, along with whether it replaced a dependency of the same name.
+pub(crate) fn insert_dependency(
+    manifest: &str,
+    manifest_path: &Path,
+    table: &str,
+    name: &str,
+    dependency: toml_edit::InlineTable,
+) -> Result<(String, bool)> {
+    let mut doc = manifest.parse::<toml_edit::Document>()?;
+    let deps = doc
+        .entry(table)
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .ok_or_else(|| anyhow!("`{table}` is not a table in {}", manifest_path.display()))?;
+    let replaced = deps.insert(name, toml_edit::value(dependency)).is_some();
+    Ok((doc.to_string(), replaced))
+}
+
+/// Returns the latest tag of `repo` that is a semantic version matching `req`, if any.
+fn latest_tag(repo: &str, req: Option<&semver::VersionReq>) -> Result<Option<String>> {
+    let url = git::Url::from_str(repo)?;
+    info!("    Resolving the latest release of {url}");
+    match req {
+        Some(req) => git::latest_matching_semver_tag(&url, req)?
+            .map(Some)
+            .ok_or_else(|| anyhow!("no tag of {url} matches the version requirement `{req}`")),
+        None => git::latest_semver_tag(&url),
+    }
+}
+
+/// Returns the path of the `Forc.toml` of the package in `dir`, or in the current directory.
+pub(crate) fn manifest_path(dir: Option<&str>) -> Result<PathBuf> {
+    let dir = match dir {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::current_dir()?,
+    };
+    match ManifestFile::from_dir(&dir)? {
+        ManifestFile::Package(manifest) => Ok(manifest.path().to_path_buf()),
+        ManifestFile::Workspace(manifest) => bail!(
+            "{} is a workspace manifest, dependencies can only be edited for packages",
+            manifest.path().display()
+        ),
+    }
+}
+
+/// Writes the edited manifest and updates the lock file, restoring the `original` manifest if
+/// either fails.
+pub(crate) fn write_manifest(
+    manifest_path: &Path,
+    original: &str,
+    edited: &str,
+    offline: bool,
+    ipfs_node: Option<IPFSNode>,
+) -> Result<()> {
+    fs::write(manifest_path, edited)?;
+    let result = match offline {
+        true => PackageManifestFile::from_file(manifest_path).map(|_| ()),
+        false => update_lock(manifest_path, ipfs_node.unwrap_or_default()),
+    };
+    if let Err(e) = result {
+        fs::write(manifest_path, original)?;
+        return Err(e.context(format!(
+            "failed to update {}, the manifest was left unchanged",
+            manifest_path.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Updates the lock file of the package at `manifest_path` to match its manifest, fetching the
+/// dependencies that are not yet in it.
+fn update_lock(manifest_path: &Path, ipfs_node: IPFSNode) -> Result<()> {
+    let manifest = ManifestFile::from_file(manifest_path.to_path_buf())?;
+    let member_manifests = manifest.member_manifests()?;
+    let lock_path = manifest.lock_path()?;
+    let locked = false;
+    let offline = false;
+    pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        locked,
+        offline,
+        ipfs_node,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A manifest with a git, a path and a version dependency, and comments around them.
+    pub(crate) const MANIFEST: &str = r#"[project]
+authors = ["Fuel Labs <contact@fuel.sh>"]
+entry = "main.sw"
+license = "Apache-2.0"
+name = "app"
+
+# The dependencies of the app.
+[dependencies]
+std = { git = "https://github.com/fuellabs/sway", tag = "v0.49.1" } # Pinned to a release.
+token = { path = "../token" }
+# Resolved from the registry.
+utils = "1.0"
+"#;
+
+    /// Returns `manifest` edited as by `forc add` with the given arguments.
+    pub(crate) fn add(manifest: &str, args: &[&str]) -> Result<(String, bool)> {
+        let command = AddCommand::parse_from(std::iter::once("add").chain(args.iter().copied()));
+        let (name, dependency) = dependency(&command)?;
+        let table = dependencies_table(command.contract_dep);
+        insert_dependency(manifest, Path::new("Forc.toml"), table, &name, dependency)
+    }
+
+    #[test]
+    fn adds_path_dependency() {
+        let (manifest, replaced) = add(MANIFEST, &["vault", "--path", "../vault"]).unwrap();
+        assert!(!replaced);
+        assert_eq!(
+            manifest,
+            format!("{MANIFEST}vault = {{ path = \"../vault\" }}\n")
+        );
+    }
+
+    #[test]
+    fn adds_git_dependency() {
+        let repo = "https://github.com/fuellabs/sway-libs";
+        for (reference, value) in [
+            ("--branch", "master"),
+            ("--tag", "v0.1.0"),
+            ("--rev", "a1b2c3"),
+        ] {
+            let (manifest, replaced) =
+                add(MANIFEST, &["libs", "--git", repo, reference, value]).unwrap();
+            assert!(!replaced);
+            let key = reference.trim_start_matches("--");
+            assert_eq!(
+                manifest,
+                format!("{MANIFEST}libs = {{ git = \"{repo}\", {key} = \"{value}\" }}\n")
+            );
+        }
+    }
+
+    #[test]
+    fn replaces_dependency() {
+        let (manifest, replaced) = add(MANIFEST, &["token", "--path", "../new_token"]).unwrap();
+        assert!(replaced);
+        assert_eq!(
+            manifest,
+            MANIFEST.replace("../token", "../new_token"),
+            "the comments and the other dependencies are preserved"
+        );
+    }
+
+    #[test]
+    fn adds_contract_dependency() {
+        let salt = "0x1111111111111111111111111111111111111111111111111111111111111111";
+        let (manifest, replaced) = add(
+            MANIFEST,
+            &[
+                "vault",
+                "--path",
+                "../vault",
+                "--contract-dep",
+                "--salt",
+                salt,
+            ],
+        )
+        .unwrap();
+        assert!(!replaced);
+        assert_eq!(
+            manifest,
+            format!(
+                "{MANIFEST}\n[contract-dependencies]\nvault = {{ path = \"../vault\", salt = \"{salt}\" }}\n"
+            )
+        );
+        let manifest = toml::from_str::<forc_pkg::PackageManifest>(&manifest).unwrap();
+        assert!(manifest
+            .contract_dependencies
+            .unwrap()
+            .contains_key("vault"));
+    }
+
+    #[test]
+    fn rejects_invalid_dependencies() {
+        let repo = "https://github.com/fuellabs/sway-libs";
+        assert!(add(MANIFEST, &["vault@1.0", "--path", "../vault"]).is_err());
+        assert!(add(MANIFEST, &["vault", "--path", "../vault", "--git", repo]).is_err());
+        assert!(add(
+            MANIFEST,
+            &["vault", "--git", repo, "--tag", "v1", "--rev", "a1b2"]
+        )
+        .is_err());
+        assert!(add(MANIFEST, &["vault"]).is_err());
+        assert!(add(MANIFEST, &["not a name", "--path", "../vault"]).is_err());
+    }
+
+    #[test]
+    fn edited_manifest_is_valid() {
+        let (manifest, _) = add(MANIFEST, &["vault", "--path", "../vault"]).unwrap();
+        let manifest = toml::from_str::<forc_pkg::PackageManifest>(&manifest).unwrap();
+        let deps = manifest.dependencies.unwrap();
+        assert_eq!(
+            deps.keys().collect::<Vec<_>>(),
+            ["std", "token", "utils", "vault"]
+        );
+    }
+}
@@ -26,8 +26,10 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
             dca_graph_url_format: cmd.build.print.dca_graph_url_format,
             finalized_asm: cmd.build.print.finalized_asm,
             intermediate_asm: cmd.build.print.intermediate_asm,
+            asm_stats: cmd.build.print.asm_stats,
             ir: cmd.build.print.ir,
             reverse_order: cmd.build.print.reverse_order,
+            verbose_types: cmd.build.print.verbose_types,
         },
         time_phases: cmd.build.print.time_phases,
         metrics_outfile: cmd.build.print.metrics_outfile,
@@ -38,13 +40,21 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
         build_profile: cmd.build.profile.build_profile,
         release: cmd.build.profile.release,
         error_on_warnings: cmd.build.profile.error_on_warnings,
+        lints: cmd.build.profile.lints(),
+        pgo_profile: cmd.build.profile.pgo_profile,
+        inline_budget: cmd.build.profile.inline_budget,
+        no_dse_storage: cmd.build.profile.no_dse_storage,
         binary_outfile: cmd.build.output.bin_file,
         debug_outfile: cmd.build.output.debug_file,
+        json_artifact_outfile: cmd.build.output.json_artifact,
+        size_profile: cmd.build.output.size_profile,
+        gas_report: cmd.build.output.gas_report,
         build_target: cmd.build.build_target,
         tests: cmd.tests,
         member_filter: Default::default(),
         experimental: ExperimentalFlags {
             new_encoding: cmd.experimental_new_encoding,
+            ..Default::default()
         },
     }
 }
@@ -0,0 +1,404 @@
+use crate::cli::BytecodeDiffCommand;
+use anyhow::{anyhow, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use sway_ast::{ItemFn, ItemImplItem, ItemKind};
+use sway_core::source_map::SourceMap;
+use sway_error::handler::Handler;
+use sway_types::Spanned;
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
+use tracing::info;
+
+/// The name under which the instructions that cannot be attributed to a function are reported.
+const UNMAPPED: &str = "<unmapped>";
+
+pub fn bytecode_diff(command: BytecodeDiffCommand) -> Result<()> {
+    let mut functions = FunctionIndex::default();
+    let old = read_sizes(
+        &mut functions,
+        &command.old,
+        &command.old_source_map,
+        &command.search_dir,
+    )?;
+    let new = read_sizes(
+        &mut functions,
+        &command.new,
+        &command.new_source_map,
+        &command.search_dir,
+    )?;
+    info!("{}", render(&diff(&old, &new, command.all), &old, &new));
+    Ok(())
+}
+
+/// The size of a function in the old and the new build, `None` if it is missing from one of them.
+#[derive(Debug, PartialEq, Eq)]
+struct SizeDiff {
+    name: String,
+    old: Option<usize>,
+    new: Option<usize>,
+}
+
+impl SizeDiff {
+    /// Describes how the size of the function changed between the builds.
+    fn change(&self) -> String {
+        match (self.old, self.new) {
+            (None, Some(_)) => "added".to_string(),
+            (Some(_), None) => "removed".to_string(),
+            (Some(old), Some(new)) if new > old => format!("grew by {} bytes", new - old),
+            (Some(old), Some(new)) if new < old => format!("shrank by {} bytes", old - new),
+            _ => "".to_string(),
+        }
+    }
+
+    fn row(&self) -> Row<'static> {
+        let size_cell = |size: Option<usize>| {
+            TableCell::new_with_alignment(
+                size.map(|size| size.to_string()).unwrap_or_default(),
+                1,
+                Alignment::Right,
+            )
+        };
+        Row::new(vec![
+            TableCell::new(&self.name),
+            size_cell(self.old),
+            size_cell(self.new),
+            TableCell::new(self.change()),
+        ])
+    }
+}
+
+/// Compares the sizes of the functions of two builds, listing the functions whose size changed
+/// the most first. The functions whose size did not change are only listed if `all` is set.
+fn diff(old: &BTreeMap<String, usize>, new: &BTreeMap<String, usize>, all: bool) -> Vec<SizeDiff> {
+    let names: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+    let mut diffs: Vec<_> = names
+        .into_iter()
+        .map(|name| SizeDiff {
+            name: name.clone(),
+            old: old.get(name).copied(),
+            new: new.get(name).copied(),
+        })
+        .filter(|diff| all || diff.old != diff.new)
+        .collect();
+    diffs.sort_by_key(|diff| {
+        let delta = diff.new.unwrap_or(0) as i64 - diff.old.unwrap_or(0) as i64;
+        (std::cmp::Reverse(delta.abs()), diff.name.clone())
+    });
+    diffs
+}
+
+/// Renders the differences as a table, followed by the total sizes of the builds.
+fn render(
+    diffs: &[SizeDiff],
+    old: &BTreeMap<String, usize>,
+    new: &BTreeMap<String, usize>,
+) -> String {
+    let mut table = term_table::Table::new();
+    table.separate_rows = false;
+    table.style = term_table::TableStyle::empty();
+    table.add_row(Row::new(vec![
+        TableCell::new("function"),
+        TableCell::new("old"),
+        TableCell::new("new"),
+        TableCell::new("change"),
+    ]));
+    for diff in diffs {
+        table.add_row(diff.row());
+    }
+    let total = SizeDiff {
+        name: "total".to_string(),
+        old: Some(old.values().sum()),
+        new: Some(new.values().sum()),
+    };
+    table.add_row(total.row());
+    table.render()
+}
+
+/// Returns the number of bytes of the bytecode at `bytecode_path` attributed to each function,
+/// per the source map at `source_map_path`.
+fn read_sizes(
+    functions: &mut FunctionIndex,
+    bytecode_path: &Path,
+    source_map_path: &Path,
+    search_dir: &Path,
+) -> Result<BTreeMap<String, usize>> {
+    let bytecode = fs::read(bytecode_path)
+        .map_err(|err| anyhow!("{:?}: could not read: {}", bytecode_path, err))?;
+    let contents = fs::read(source_map_path)
+        .map_err(|err| anyhow!("{:?}: could not read: {}", source_map_path, err))?;
+    let source_map: SourceMap = serde_json::from_slice(&contents)
+        .map_err(|err| anyhow!("{:?}: invalid source map json: {}", source_map_path, err))?;
+    Ok(functions.sizes(&bytecode, &source_map, search_dir))
+}
+
+/// The spans of the functions declared in the source files, parsed on demand.
+#[derive(Default)]
+struct FunctionIndex {
+    files: HashMap<PathBuf, Vec<FunctionSpan>>,
+}
+
+struct FunctionSpan {
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+impl FunctionIndex {
+    /// Returns the number of bytes of the given bytecode attributed to each function.
+    fn sizes(
+        &mut self,
+        bytecode: &[u8],
+        source_map: &SourceMap,
+        search_dir: &Path,
+    ) -> BTreeMap<String, usize> {
+        let mut sizes = BTreeMap::new();
+        for (pc, chunk) in bytecode.chunks(fuel_asm::Instruction::SIZE).enumerate() {
+            let name = match source_map.addr_to_span(pc) {
+                Some((mut path, range)) => {
+                    if path.is_relative() {
+                        path = search_dir.join(path);
+                    }
+                    self.function_at(&path, range.start)
+                        .unwrap_or_else(|| UNMAPPED.to_string())
+                }
+                None => UNMAPPED.to_string(),
+            };
+            *sizes.entry(name).or_insert(0) += chunk.len();
+        }
+        sizes
+    }
+
+    /// Returns the name of the innermost function of the file containing the given offset.
+    fn function_at(&mut self, path: &Path, offset: usize) -> Option<String> {
+        let functions = self
+            .files
+            .entry(path.to_path_buf())
+            .or_insert_with(|| read_functions(path));
+        let function = functions
+            .iter()
+            .filter(|function| function.start <= offset && offset < function.end)
+            .min_by_key(|function| function.end - function.start)?;
+        Some(format!("{}: {}", path.display(), function.name))
+    }
+}
+
+/// Collects the functions declared in the file at the given path. Files that cannot be read
+/// have no functions.
+fn read_functions(path: &Path) -> Vec<FunctionSpan> {
+    match fs::read_to_string(path) {
+        Ok(src) => parse_functions(Arc::from(src)),
+        Err(_) => vec![],
+    }
+}
+
+/// Collects the functions declared in the given source. Sources that cannot be parsed have no
+/// functions.
+fn parse_functions(src: Arc<str>) -> Vec<FunctionSpan> {
+    let handler = Handler::default();
+    let Ok(module) = sway_parse::parse_file(&handler, src, None, Default::default()) else {
+        return vec![];
+    };
+
+    let mut functions = vec![];
+    let mut push = |prefix: Option<String>, item_fn: &ItemFn| {
+        let span = item_fn.span();
+        let name = item_fn.fn_signature.name.as_str();
+        functions.push(FunctionSpan {
+            name: match prefix {
+                Some(prefix) => format!("{prefix}::{name}"),
+                None => name.to_string(),
+            },
+            start: span.start(),
+            end: span.end(),
+        });
+    };
+    for item in module.value.items.iter() {
+        match &item.value {
+            ItemKind::Fn(item_fn) => push(None, item_fn),
+            ItemKind::Impl(item_impl) => {
+                let ty = item_impl.ty.span().as_str().to_string();
+                let prefix = match &item_impl.trait_opt {
+                    Some((trait_path, _)) => format!("<{ty} as {}>", trait_path.span().as_str()),
+                    None => ty,
+                };
+                for item in item_impl.contents.get().iter() {
+                    if let ItemImplItem::Fn(item_fn) = &item.value {
+                        push(Some(prefix.clone()), item_fn);
+                    }
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                for item in item_abi.abi_defs_opt.iter().flat_map(|defs| defs.get()) {
+                    push(Some(item_abi.name.as_str().to_string()), &item.value);
+                }
+            }
+            ItemKind::Trait(item_trait) => {
+                for item in item_trait.trait_defs_opt.iter().flat_map(|defs| defs.get()) {
+                    push(Some(item_trait.name.as_str().to_string()), &item.value);
+                }
+            }
+            _ => {}
+        }
+    }
+    functions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC: &str = r#"contract;
+
+abi Counter {
+    fn get() -> u64;
+} {
+    fn twice() -> u64 {
+        Self::get() * 2
+    }
+}
+
+impl Counter for Contract {
+    fn get() -> u64 {
+        helper()
+    }
+}
+
+struct S {}
+
+impl S {
+    fn new() -> Self {
+        S {}
+    }
+}
+
+fn helper() -> u64 {
+    1
+}
+"#;
+
+    fn sizes(entries: &[(&str, usize)]) -> BTreeMap<String, usize> {
+        entries
+            .iter()
+            .map(|(name, size)| (name.to_string(), *size))
+            .collect()
+    }
+
+    #[test]
+    fn collects_functions() {
+        let names: Vec<_> = parse_functions(Arc::from(SRC))
+            .into_iter()
+            .map(|function| function.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Counter::twice",
+                "<Contract as Counter>::get",
+                "S::new",
+                "helper"
+            ]
+        );
+        assert!(read_functions(Path::new("/does/not/exist.sw")).is_empty());
+    }
+
+    #[test]
+    fn attributes_instructions_to_functions() {
+        let get = SRC.find("helper()").unwrap();
+        let helper = SRC.rfind("1\n").unwrap();
+        let source_map: SourceMap = serde_json::from_value(serde_json::json!({
+            "dependency_paths": [],
+            "paths": ["src/main.sw"],
+            "map": {
+                "0": { "path": 0, "range": { "start": get, "end": get + 8 } },
+                "1": { "path": 0, "range": { "start": helper, "end": helper + 1 } },
+                "2": { "path": 0, "range": { "start": helper, "end": helper + 1 } },
+                // Outside of any function.
+                "3": { "path": 0, "range": { "start": 0, "end": 9 } },
+            },
+        }))
+        .unwrap();
+
+        let search_dir = Path::new("/project");
+        let mut functions = FunctionIndex::default();
+        functions.files.insert(
+            search_dir.join("src/main.sw"),
+            parse_functions(Arc::from(SRC)),
+        );
+        // Four instructions and a data section of two words.
+        let bytecode = vec![0; 4 * fuel_asm::Instruction::SIZE + 16];
+        assert_eq!(
+            functions.sizes(&bytecode, &source_map, search_dir),
+            sizes(&[
+                ("/project/src/main.sw: <Contract as Counter>::get", 4),
+                ("/project/src/main.sw: helper", 8),
+                (UNMAPPED, 20),
+            ])
+        );
+    }
+
+    #[test]
+    fn lists_largest_changes_first() {
+        let old = sizes(&[("a", 100), ("b", 40), ("c", 8), ("d", 16)]);
+        let new = sizes(&[("a", 96), ("b", 60), ("c", 8), ("e", 12)]);
+        let diff_of = |name: &str, old: Option<usize>, new: Option<usize>| SizeDiff {
+            name: name.to_string(),
+            old,
+            new,
+        };
+        assert_eq!(
+            diff(&old, &new, false),
+            [
+                diff_of("b", Some(40), Some(60)),
+                diff_of("d", Some(16), None),
+                diff_of("e", None, Some(12)),
+                diff_of("a", Some(100), Some(96)),
+            ]
+        );
+        assert_eq!(
+            diff(&old, &new, true).last(),
+            Some(&diff_of("c", Some(8), Some(8)))
+        );
+    }
+
+    #[test]
+    fn describes_changes() {
+        let change = |old, new| {
+            SizeDiff {
+                name: "f".to_string(),
+                old,
+                new,
+            }
+            .change()
+        };
+        assert_eq!(change(None, Some(4)), "added");
+        assert_eq!(change(Some(4), None), "removed");
+        assert_eq!(change(Some(4), Some(12)), "grew by 8 bytes");
+        assert_eq!(change(Some(12), Some(4)), "shrank by 8 bytes");
+        assert_eq!(change(Some(4), Some(4)), "");
+    }
+
+    #[test]
+    fn renders_totals() {
+        let old = sizes(&[("a", 100), ("b", 40)]);
+        let new = sizes(&[("a", 96), ("c", 12)]);
+        let table = render(&diff(&old, &new, false), &old, &new);
+        let rows: Vec<Vec<&str>> = table
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .filter(|row: &Vec<&str>| !row.is_empty())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                vec!["function", "old", "new", "change"],
+                vec!["b", "40", "removed"],
+                vec!["c", "12", "added"],
+                vec!["a", "100", "96", "shrank", "by", "4", "bytes"],
+                vec!["total", "140", "108", "shrank", "by", "32", "bytes"],
+            ]
+        );
+    }
+}
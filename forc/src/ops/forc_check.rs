@@ -15,6 +15,7 @@ pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyP
         locked,
         disable_tests,
         ipfs_node,
+        ..
     } = command;
 
     let this_dir = if let Some(ref path) = path {
@@ -62,8 +62,10 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
             dca_graph_url_format: cmd.print.dca_graph_url_format.clone(),
             finalized_asm: cmd.print.finalized_asm,
             intermediate_asm: cmd.print.intermediate_asm,
+            asm_stats: cmd.print.asm_stats,
             ir: cmd.print.ir,
             reverse_order: cmd.print.reverse_order,
+            verbose_types: cmd.print.verbose_types,
         },
         time_phases: cmd.print.time_phases,
         metrics_outfile: cmd.print.metrics_outfile.clone(),
@@ -74,13 +76,21 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
         build_profile: cmd.build_profile.build_profile.clone(),
         release: cmd.build_profile.release,
         error_on_warnings: cmd.build_profile.error_on_warnings,
+        lints: cmd.build_profile.lints(),
+        pgo_profile: cmd.build_profile.pgo_profile.clone(),
+        inline_budget: cmd.build_profile.inline_budget,
+        no_dse_storage: cmd.build_profile.no_dse_storage,
         binary_outfile: cmd.build_output.bin_file.clone(),
         debug_outfile: cmd.build_output.debug_file.clone(),
+        json_artifact_outfile: cmd.build_output.json_artifact.clone(),
+        size_profile: cmd.build_output.size_profile,
+        gas_report: cmd.build_output.gas_report,
         build_target: BuildTarget::default(),
         tests: false,
         member_filter: pkg::MemberFilter::only_contracts(),
         experimental: ExperimentalFlags {
             new_encoding: cmd.experimental_new_encoding,
+            ..Default::default()
         },
     }
 }
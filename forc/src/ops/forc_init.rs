@@ -1,7 +1,9 @@
 use crate::cli::InitCommand;
-use crate::utils::{defaults, program_type::ProgramType};
+use crate::ops::forc_template;
+use crate::utils::{defaults, program_type::ProgramType, template::Template};
 use anyhow::Context;
 use forc_util::{forc_result_bail, validate_name, ForcResult};
+use fs_extra::dir::CopyOptions;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -80,6 +82,39 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
 
     validate_name(&project_name, "project name")?;
 
+    if command.template.is_some() || command.template_url.is_some() {
+        if command.script || command.predicate || command.library || command.workspace {
+            forc_result_bail!("Templates can only be used to create contracts.");
+        }
+        if project_dir
+            .join(constants::TEST_MANIFEST_FILE_NAME)
+            .exists()
+        {
+            forc_result_bail!(
+                "'{}' already includes a Cargo.toml file.",
+                project_dir.display()
+            );
+        }
+    }
+
+    if let Some(url) = &command.template_url {
+        init_from_git_template(
+            &project_dir,
+            &project_name,
+            url,
+            command.template.as_deref(),
+        )?;
+        debug!("\nSuccessfully created a package from {url}: {project_name}");
+        print_welcome_message();
+        return Ok(());
+    }
+
+    let template = command
+        .template
+        .as_deref()
+        .map(str::parse::<Template>)
+        .transpose()?;
+
     let init_type = match (
         command.contract,
         command.script,
@@ -132,7 +167,10 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
             Path::new(&project_dir)
                 .join("src")
                 .join(constants::MAIN_ENTRY),
-            defaults::default_contract(),
+            match template {
+                Some(template) => template.contract().to_string(),
+                None => defaults::default_contract(),
+            },
         )?,
         InitType::Package(ProgramType::Script) => fs::write(
             Path::new(&project_dir)
@@ -156,6 +194,20 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
         _ => {}
     }
 
+    // Insert the Rust SDK harness of the template
+    if let Some(template) = template {
+        fs::write(
+            Path::new(&project_dir).join(constants::TEST_MANIFEST_FILE_NAME),
+            defaults::default_test_manifest(&project_name),
+        )?;
+        let tests_dir = project_dir.join("tests");
+        fs::create_dir_all(&tests_dir)?;
+        fs::write(
+            tests_dir.join("harness.rs"),
+            template.harness(&project_name),
+        )?;
+    }
+
     // Ignore default `out` and `target` directories created by forc and cargo.
     let gitignore_path = Path::new(&project_dir).join(".gitignore");
     // Append to existing gitignore if it exists otherwise create a new one.
@@ -177,3 +229,34 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
 
     Ok(())
 }
+
+/// Copies the contents of a template fetched from a git repository into `project_dir`, keeping
+/// the files that already exist in it.
+fn init_from_git_template(
+    project_dir: &Path,
+    project_name: &str,
+    url: &str,
+    template_name: Option<&str>,
+) -> anyhow::Result<()> {
+    // The name used for the temporary local repo directory used for fetching the template.
+    let local_repo_name = template_name
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{project_name}-template-source"));
+    let from_path = forc_template::fetch_template(url, template_name, &local_repo_name)?;
+
+    let mut copy_options = CopyOptions::new();
+    copy_options.content_only = true;
+    copy_options.skip_exist = true;
+    fs_extra::dir::copy(&from_path, project_dir, &copy_options)
+        .with_context(|| format!("Failed to copy the template from {}", from_path.display()))?;
+
+    let author = defaults::get_author();
+    forc_template::edit_forc_toml(project_dir, project_name, &author)?;
+    if project_dir
+        .join(constants::TEST_MANIFEST_FILE_NAME)
+        .exists()
+    {
+        forc_template::edit_cargo_toml(project_dir, project_name, &author)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,388 @@
+use crate::cli::MulticallCommand;
+use anyhow::{anyhow, bail, Result};
+use forc_pkg::{self as pkg, manifest::ManifestFile};
+use forc_tracing::println_green;
+use forc_util::kebab_to_snake_case;
+use std::{fmt::Write, fs, path::PathBuf, sync::Arc};
+use sway_core::{
+    language::{parsed::TreeType, ty},
+    namespace, BuildTarget, Engines,
+};
+use sway_error::handler::Handler;
+
+/// An ABI declared by an interface package, along with the path of the module declaring it.
+struct InterfaceAbi {
+    module_path: Vec<String>,
+    decl: Arc<ty::TyAbiDecl>,
+}
+
+pub fn multicall(command: MulticallCommand) -> Result<()> {
+    let MulticallCommand {
+        path,
+        output,
+        offline_mode: offline,
+        locked,
+        ipfs_node,
+    } = command;
+
+    let this_dir = if let Some(ref path) = path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+    let manifest_file = ManifestFile::from_dir(&this_dir)?;
+    let ManifestFile::Package(manifest) = &manifest_file else {
+        bail!("`forc multicall` must be run on a script package, not on a workspace");
+    };
+    if manifest.program_type()? != TreeType::Script {
+        bail!(
+            "`forc multicall` must be run on a script package, but {} is not a script",
+            manifest.project.name
+        );
+    }
+
+    let member_manifests = manifest_file.member_manifests()?;
+    let lock_path = manifest_file.lock_path()?;
+    let plan = pkg::BuildPlan::from_lock_and_manifests(
+        &lock_path,
+        &member_manifests,
+        locked,
+        offline,
+        ipfs_node.unwrap_or_default(),
+    )?;
+    let script_node = plan
+        .find_member_index(&manifest.project.name)
+        .ok_or_else(|| anyhow!("{} is not in the build plan", manifest.project.name))?;
+
+    // Only the interface packages the script directly depends on are of interest.
+    let interfaces: Vec<_> = manifest
+        .deps()
+        .filter_map(|(dep_name, dep)| {
+            let pkg_name = dep.package().unwrap_or(dep_name);
+            let node = plan
+                .node_deps(script_node)
+                .find(|node| plan.graph()[*node].name == pkg_name)?;
+            let dep_manifest = &plan.manifest_map()[&plan.graph()[node].id()];
+            dep_manifest
+                .project
+                .interface
+                .then(|| (kebab_to_snake_case(dep_name), node))
+        })
+        .collect();
+    if interfaces.is_empty() {
+        bail!(
+            "{} does not depend on any interface package",
+            manifest.project.name
+        );
+    }
+
+    // The script itself need not type check, e.g. because of a previously generated module
+    // being out of date, so only the interface packages are inspected.
+    let engines = Engines::default();
+    let results = pkg::check(&plan, BuildTarget::default(), true, false, &engines, None)?;
+    let mut abis = vec![];
+    for (dep_name, node) in interfaces.iter() {
+        let typed_program = plan
+            .compilation_order()
+            .iter()
+            .position(|n| n == node)
+            .and_then(|idx| results.get(idx))
+            .and_then(|(programs, _)| programs.as_ref())
+            .and_then(|programs| programs.typed.as_ref().ok())
+            .ok_or_else(|| anyhow!("unable to type check interface {dep_name:?}"))?;
+        collect_abis(
+            typed_program.root.namespace.module(),
+            vec![dep_name.clone()],
+            &engines,
+            &mut abis,
+        );
+    }
+
+    let mut module_paths: Vec<_> = abis.iter().map(|abi| abi.module_path.join("::")).collect();
+    module_paths.dedup();
+    let module = generate(&module_paths, &interface_methods(&abis, &engines)?)?;
+    let output_path = manifest.dir().join(output);
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output_path, module)?;
+    println_green(&format!(
+        "  Generated multicall helpers at {}",
+        output_path.display()
+    ));
+    Ok(())
+}
+
+/// Collects the ABIs declared in the given module and its submodules, skipping the external
+/// modules of its dependencies.
+fn collect_abis(
+    module: &namespace::Module,
+    module_path: Vec<String>,
+    engines: &Engines,
+    abis: &mut Vec<InterfaceAbi>,
+) {
+    for decl in module.symbols().values() {
+        if let ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) = decl {
+            abis.push(InterfaceAbi {
+                module_path: module_path.clone(),
+                decl: engines.de().get_abi(decl_id),
+            });
+        }
+    }
+    for (name, submodule) in module.submodules().iter() {
+        if !submodule.is_external {
+            let mut submodule_path = module_path.clone();
+            submodule_path.push(name.clone());
+            collect_abis(submodule, submodule_path, engines, abis);
+        }
+    }
+}
+
+/// A method of an interface ABI, along with what its multicall helpers need to know about it.
+struct InterfaceMethod {
+    abi_name: String,
+    method_name: String,
+    selector: [u8; 4],
+    /// The parameters of the method, e.g. `amount: u64`.
+    params: Vec<String>,
+    /// The names of the parameters of the method.
+    args: Vec<String>,
+    single_value_type_arg: bool,
+    /// The return type of the method, unless it returns `()`.
+    return_type: Option<String>,
+}
+
+/// Collects the methods of the given ABIs.
+fn interface_methods(abis: &[InterfaceAbi], engines: &Engines) -> Result<Vec<InterfaceMethod>> {
+    let handler = Handler::default();
+    let mut methods = vec![];
+    for abi in abis {
+        let abi_name = abi.decl.name.as_str();
+        for item in abi.decl.interface_surface.iter() {
+            let ty::TyTraitInterfaceItem::TraitFn(decl_ref) = item else {
+                continue;
+            };
+            let method = engines.de().get_trait_fn(decl_ref);
+            let method_name = method.name.as_str();
+            let selector = method
+                .to_fn_selector_value(&handler, engines)
+                .map_err(|_| {
+                    anyhow!("unable to compute the selector of {abi_name}::{method_name}")
+                })?;
+            methods.push(InterfaceMethod {
+                abi_name: abi_name.to_string(),
+                method_name: method_name.to_string(),
+                selector,
+                params: method
+                    .parameters
+                    .iter()
+                    .map(|param| {
+                        format!(
+                            "{}: {}",
+                            param.name.as_str(),
+                            param.type_argument.span.as_str()
+                        )
+                    })
+                    .collect(),
+                args: method
+                    .parameters
+                    .iter()
+                    .map(|param| param.name.to_string())
+                    .collect(),
+                single_value_type_arg: matches!(
+                    method.parameters.as_slice(),
+                    [param] if engines.te().get(param.type_argument.type_id).is_copy_type()
+                ),
+                return_type: (!engines.te().get(method.return_type.type_id).is_unit())
+                    .then(|| method.return_type.span.as_str().to_string()),
+            });
+        }
+    }
+    Ok(methods)
+}
+
+/// Generates the module declaring the multicall helpers of the given methods, which are declared
+/// in the modules at `module_paths`.
+fn generate(module_paths: &[String], methods: &[InterfaceMethod]) -> Result<String> {
+    let mut module = String::new();
+    writeln!(module, "library;")?;
+    writeln!(module)?;
+    writeln!(
+        module,
+        "// This module is generated by `forc multicall`. Do not edit it by hand."
+    )?;
+    writeln!(module)?;
+    writeln!(
+        module,
+        "use std::{{bytes::Bytes, low_level_call::CallParams, multicall::Multicall}};"
+    )?;
+    for module_path in module_paths {
+        writeln!(module, "use {module_path}::*;")?;
+    }
+
+    for method in methods {
+        let InterfaceMethod {
+            abi_name,
+            method_name,
+            ..
+        } = method;
+        let prefix = camel_to_snake_case(abi_name);
+        let calldata = match method.args.as_slice() {
+            [] => "Bytes::new()".to_string(),
+            [arg] => format!("Bytes::from(encode({arg}))"),
+            args => format!("Bytes::from(encode(({})))", args.join(", ")),
+        };
+
+        writeln!(module)?;
+        writeln!(
+            module,
+            "/// Adds a call to `{abi_name}::{method_name}` to the multicall."
+        )?;
+        writeln!(
+            module,
+            "pub fn {prefix}_{method_name}(ref mut multicall: Multicall, target: ContractId, {}call_params: CallParams) {{",
+            method
+                .params
+                .iter()
+                .map(|param| format!("{param}, "))
+                .collect::<String>(),
+        )?;
+        writeln!(
+            module,
+            "    multicall.add(target, 0x{}, {calldata}, {}, call_params);",
+            hex::encode(method.selector),
+            method.single_value_type_arg,
+        )?;
+        writeln!(module, "}}")?;
+
+        let Some(return_type) = &method.return_type else {
+            continue;
+        };
+        writeln!(module)?;
+        writeln!(
+            module,
+            "/// Decodes the value returned by a call to `{abi_name}::{method_name}`."
+        )?;
+        writeln!(
+            module,
+            "pub fn decode_{prefix}_{method_name}(data: Bytes) -> {return_type} {{"
+        )?;
+        writeln!(
+            module,
+            "    abi_decode::<{return_type}>(data.as_raw_slice())"
+        )?;
+        writeln!(module, "}}")?;
+    }
+    Ok(module)
+}
+
+/// Converts the name of an ABI, e.g. `TokenVault`, into the prefix of its helpers, e.g.
+/// `token_vault`.
+fn camel_to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method(
+        abi_name: &str,
+        method_name: &str,
+        params: &[(&str, &str)],
+        single_value_type_arg: bool,
+        return_type: Option<&str>,
+    ) -> InterfaceMethod {
+        InterfaceMethod {
+            abi_name: abi_name.to_string(),
+            method_name: method_name.to_string(),
+            selector: [0x98, 0x90, 0xae, 0xf4],
+            params: params
+                .iter()
+                .map(|(name, ty)| format!("{name}: {ty}"))
+                .collect(),
+            args: params.iter().map(|(name, _)| name.to_string()).collect(),
+            single_value_type_arg,
+            return_type: return_type.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn converts_abi_names_to_snake_case() {
+        assert_eq!(camel_to_snake_case("Token"), "token");
+        assert_eq!(camel_to_snake_case("TokenVault"), "token_vault");
+        assert_eq!(camel_to_snake_case("tokenVault"), "token_vault");
+    }
+
+    #[test]
+    fn generates_helpers_for_each_method() {
+        let methods = [
+            method("TokenVault", "total", &[], false, Some("u64")),
+            method("TokenVault", "deposit", &[("amount", "u64")], true, None),
+            method(
+                "TokenVault",
+                "transfer",
+                &[("to", "Identity"), ("amount", "u64")],
+                false,
+                Some("bool"),
+            ),
+        ];
+        let module = generate(&["vault_interface".to_string()], &methods).unwrap();
+        assert_eq!(
+            module,
+            r#"library;
+
+// This module is generated by `forc multicall`. Do not edit it by hand.
+
+use std::{bytes::Bytes, low_level_call::CallParams, multicall::Multicall};
+use vault_interface::*;
+
+/// Adds a call to `TokenVault::total` to the multicall.
+pub fn token_vault_total(ref mut multicall: Multicall, target: ContractId, call_params: CallParams) {
+    multicall.add(target, 0x9890aef4, Bytes::new(), false, call_params);
+}
+
+/// Decodes the value returned by a call to `TokenVault::total`.
+pub fn decode_token_vault_total(data: Bytes) -> u64 {
+    abi_decode::<u64>(data.as_raw_slice())
+}
+
+/// Adds a call to `TokenVault::deposit` to the multicall.
+pub fn token_vault_deposit(ref mut multicall: Multicall, target: ContractId, amount: u64, call_params: CallParams) {
+    multicall.add(target, 0x9890aef4, Bytes::from(encode(amount)), true, call_params);
+}
+
+/// Adds a call to `TokenVault::transfer` to the multicall.
+pub fn token_vault_transfer(ref mut multicall: Multicall, target: ContractId, to: Identity, amount: u64, call_params: CallParams) {
+    multicall.add(target, 0x9890aef4, Bytes::from(encode((to, amount))), false, call_params);
+}
+
+/// Decodes the value returned by a call to `TokenVault::transfer`.
+pub fn decode_token_vault_transfer(data: Bytes) -> bool {
+    abi_decode::<bool>(data.as_raw_slice())
+}
+"#
+        );
+    }
+
+    #[test]
+    fn imports_every_module_declaring_an_abi() {
+        let module = generate(
+            &["interface".to_string(), "interface::admin".to_string()],
+            &[],
+        )
+        .unwrap();
+        assert!(module.ends_with("use interface::*;\nuse interface::admin::*;\n"));
+    }
+}
@@ -0,0 +1,225 @@
+//! Packaging and uploading packages to a registry, and yanking published versions.
+//!
+//! The registry is expected to expose the same API as a cargo registry:
+//!
+//! - `PUT {registry}/api/v1/packages/new` publishes a package. The body is the length of the
+//!   JSON metadata of the package as a little endian `u32`, the metadata, the length of the
+//!   tarball of the package as a little endian `u32`, and the tarball.
+//! - `DELETE {registry}/api/v1/packages/{name}/{version}/yank` yanks a version.
+//! - `PUT {registry}/api/v1/packages/{name}/{version}/unyank` undoes a yank.
+//!
+//! Requests are authenticated with the token of the user in the `Authorization` header.
+
+use anyhow::{anyhow, bail, Context, Result};
+use forc_pkg::manifest::{Dependency, PackageManifestFile};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, fs, path::Path};
+use tracing::info;
+use url::Url;
+
+/// The directories of a package that are never published.
+const EXCLUDED_DIRS: &[&str] = &["out", "target"];
+
+/// A registry that packages are published to.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    pub url: Url,
+    pub token: Option<String>,
+}
+
+/// The metadata of a package sent to the registry along with its tarball.
+#[derive(Debug, Serialize)]
+struct PackageMetadata {
+    name: String,
+    version: String,
+    description: String,
+    license: String,
+    authors: Vec<String>,
+    organization: Option<String>,
+    dependencies: BTreeMap<String, Dependency>,
+    checksum: String,
+}
+
+/// Checks that a package has everything a registry requires of it: a license, a version and a
+/// description, and dependencies that do not refer to the local file system.
+pub fn verify_manifest(manifest: &PackageManifestFile) -> Result<()> {
+    let project = &manifest.project;
+    let mut missing = vec![];
+    if project.license.trim().is_empty() {
+        missing.push("license");
+    }
+    if project.version.is_none() {
+        missing.push("version");
+    }
+    if project
+        .description
+        .as_ref()
+        .map_or(true, |description| description.trim().is_empty())
+    {
+        missing.push("description");
+    }
+    if !missing.is_empty() {
+        bail!(
+            "{} is missing the following fields of its [project], which are required to publish it: {}",
+            manifest.path().display(),
+            missing.join(", ")
+        );
+    }
+
+    let path_deps: Vec<_> = manifest
+        .deps_detailed()
+        .chain(
+            manifest
+                .contract_deps()
+                .filter_map(|(name, dep)| match &dep.dependency {
+                    Dependency::Detailed(details) => Some((name, details)),
+                    Dependency::Simple(_) => None,
+                }),
+        )
+        .filter(|(_, details)| details.path.is_some())
+        .map(|(name, _)| format!("`{name}`"))
+        .collect();
+    if !path_deps.is_empty() {
+        bail!(
+            "path dependencies cannot be published, use a git or ipfs source for: {}",
+            path_deps.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Builds the documentation of the package with the `forc doc` plugin.
+pub fn build_docs(dir: &Path) -> Result<()> {
+    let args = vec![
+        "doc".to_string(),
+        "--manifest-path".to_string(),
+        dir.display().to_string(),
+        "--locked".to_string(),
+    ];
+    let silent = false;
+    let output = crate::cli::plugin::execute_external_subcommand(args, silent)
+        .context("the `forc doc` plugin is required to verify the package")?;
+    if !output.status.success() {
+        bail!("failed to build the documentation of the package");
+    }
+    Ok(())
+}
+
+/// Creates a tarball of the sources of the package, excluding its build artifacts and hidden
+/// files. The files are placed in a `{name}-{version}` directory.
+pub fn package(manifest: &PackageManifestFile) -> Result<Vec<u8>> {
+    let version = manifest
+        .project
+        .version
+        .as_ref()
+        .ok_or_else(|| anyhow!("the package has no version"))?;
+    let prefix = format!("{}-{version}", manifest.project.name);
+    let dir = manifest.dir();
+
+    let mut builder = tar::Builder::new(vec![]);
+    let entries = walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| !is_excluded(dir, entry.path()));
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative_path = entry.path().strip_prefix(dir)?;
+        builder
+            .append_path_with_name(entry.path(), Path::new(&prefix).join(relative_path))
+            .with_context(|| format!("failed to package {}", entry.path().display()))?;
+    }
+    Ok(builder.into_inner()?)
+}
+
+fn is_excluded(dir: &Path, path: &Path) -> bool {
+    let Ok(relative_path) = path.strip_prefix(dir) else {
+        return true;
+    };
+    let Some(first) = relative_path.components().next() else {
+        return false;
+    };
+    let first = first.as_os_str().to_string_lossy();
+    first.starts_with('.') || EXCLUDED_DIRS.contains(&first.as_ref())
+}
+
+/// Writes the tarball of a package to `out/package`, and returns its path.
+pub fn write_package(manifest: &PackageManifestFile, tarball: &[u8]) -> Result<std::path::PathBuf> {
+    let version = manifest.project.version.as_ref().expect("verified");
+    let package_dir = forc_util::default_output_directory(manifest.dir()).join("package");
+    fs::create_dir_all(&package_dir)?;
+    let path = package_dir.join(format!("{}-{version}.tar", manifest.project.name));
+    fs::write(&path, tarball)?;
+    Ok(path)
+}
+
+/// Uploads the tarball of a package to the registry.
+pub async fn upload(
+    registry: &Registry,
+    manifest: &PackageManifestFile,
+    tarball: Vec<u8>,
+) -> Result<()> {
+    let project = &manifest.project;
+    let metadata = PackageMetadata {
+        name: project.name.clone(),
+        version: project.version.as_ref().expect("verified").to_string(),
+        description: project.description.clone().unwrap_or_default(),
+        license: project.license.clone(),
+        authors: project.authors.clone().unwrap_or_default(),
+        organization: project.organization.clone(),
+        dependencies: manifest.dependencies.clone().unwrap_or_default(),
+        checksum: hex::encode(Sha256::digest(&tarball)),
+    };
+    let metadata = serde_json::to_vec(&metadata)?;
+
+    let mut body = Vec::with_capacity(8 + metadata.len() + tarball.len());
+    body.extend((metadata.len() as u32).to_le_bytes());
+    body.extend(metadata);
+    body.extend((tarball.len() as u32).to_le_bytes());
+    body.extend(tarball);
+
+    let url = registry.url.join("api/v1/packages/new")?;
+    let client = reqwest::Client::new();
+    let request = client.put(url).body(body);
+    send(registry, request).await
+}
+
+/// Yanks a published version of a package, or undoes the yank if `undo` is `true`.
+pub async fn yank(registry: &Registry, name: &str, version: &str, undo: bool) -> Result<()> {
+    let client = reqwest::Client::new();
+    let request = match undo {
+        true => client.put(
+            registry
+                .url
+                .join(&format!("api/v1/packages/{name}/{version}/unyank"))?,
+        ),
+        false => client.delete(
+            registry
+                .url
+                .join(&format!("api/v1/packages/{name}/{version}/yank"))?,
+        ),
+    };
+    send(registry, request).await
+}
+
+async fn send(registry: &Registry, request: reqwest::RequestBuilder) -> Result<()> {
+    let token = registry
+        .token
+        .as_ref()
+        .ok_or_else(|| anyhow!("no token for {}, pass one with `--token`", registry.url))?;
+    let response = request
+        .header(reqwest::header::AUTHORIZATION, token)
+        .send()
+        .await
+        .with_context(|| format!("failed to connect to {}", registry.url))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        bail!("{} responded with {status}: {text}", registry.url);
+    }
+    info!("    Registry {} responded with {status}", registry.url);
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::{cli::RemoveCommand, ops::forc_add};
+use anyhow::{anyhow, bail, Result};
+use std::{fs, path::Path};
+use tracing::info;
+
+/// Removes dependencies from the `Forc.toml` of a package, and updates the lock file.
+///
+/// Like `forc add`, the manifest is edited with `toml_edit` so that its formatting and comments
+/// are preserved, and is restored if the lock file cannot be updated.
+pub fn remove(command: RemoveCommand) -> Result<()> {
+    let table = forc_add::dependencies_table(command.contract_dep);
+    let manifest_path = forc_add::manifest_path(command.manifest_path.as_deref())?;
+    let original = fs::read_to_string(&manifest_path)?;
+    let edited = remove_dependencies(&original, &manifest_path, table, &command.dependencies)?;
+
+    forc_add::write_manifest(
+        &manifest_path,
+        &original,
+        &edited,
+        command.offline,
+        command.ipfs_node,
+    )?;
+    for name in &command.dependencies {
+        info!("      Removed `{name}` from [{table}]");
+    }
+    Ok(())
+}
+
+/// Returns the `manifest` read from `manifest_path` with the dependencies called `names` removed
+/// from its `table`.
+fn remove_dependencies(
+    manifest: &str,
+    manifest_path: &Path,
+    table: &str,
+    names: &[String],
+) -> Result<String> {
+    let mut doc = manifest.parse::<toml_edit::Document>()?;
+    let deps = doc
+        .get_mut(table)
+        .and_then(|deps| deps.as_table_like_mut())
+        .ok_or_else(|| anyhow!("{} has no [{table}]", manifest_path.display()))?;
+    for name in names {
+        if deps.remove(name).is_none() {
+            bail!(
+                "`{name}` is not in the [{table}] of {}",
+                manifest_path.display()
+            );
+        }
+    }
+    Ok(doc.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::forc_add::tests::{add, MANIFEST};
+
+    fn remove(manifest: &str, names: &[&str]) -> Result<String> {
+        let names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+        remove_dependencies(manifest, Path::new("Forc.toml"), "dependencies", &names)
+    }
+
+    #[test]
+    fn removes_dependencies() {
+        let git = r#"std = { git = "https://github.com/fuellabs/sway", tag = "v0.49.1" } # Pinned to a release.
+"#;
+        let path = "token = { path = \"../token\" }\n";
+        let version = "# Resolved from the registry.\nutils = \"1.0\"\n";
+        assert_eq!(
+            remove(MANIFEST, &["std"]).unwrap(),
+            MANIFEST.replace(git, "")
+        );
+        assert_eq!(
+            remove(MANIFEST, &["token"]).unwrap(),
+            MANIFEST.replace(path, "")
+        );
+        assert_eq!(
+            remove(MANIFEST, &["utils"]).unwrap(),
+            MANIFEST.replace(version, ""),
+            "the comment above a dependency is removed along with it"
+        );
+        assert_eq!(
+            remove(MANIFEST, &["std", "token", "utils"]).unwrap(),
+            MANIFEST
+                .replace(git, "")
+                .replace(path, "")
+                .replace(version, "")
+        );
+    }
+
+    #[test]
+    fn rejects_missing_dependencies() {
+        assert!(remove(MANIFEST, &["vault"]).is_err());
+        assert!(remove(MANIFEST, &["token", "vault"]).is_err());
+        let names = ["token".to_string()];
+        assert!(remove_dependencies(
+            MANIFEST,
+            Path::new("Forc.toml"),
+            "contract-dependencies",
+            &names
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn removing_added_dependency_restores_manifest() {
+        let repo = "https://github.com/fuellabs/sway-libs";
+        for args in [
+            &["vault", "--path", "../vault"][..],
+            &["vault", "--git", repo, "--tag", "v0.1.0"],
+        ] {
+            let (manifest, _) = add(MANIFEST, args).unwrap();
+            assert_eq!(remove(&manifest, &["vault"]).unwrap(), MANIFEST);
+        }
+    }
+}
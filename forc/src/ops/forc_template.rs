@@ -21,63 +21,72 @@ pub fn init(command: TemplateCommand) -> Result<()> {
         .clone()
         .unwrap_or_else(|| format!("{}-template-source", command.project_name));
 
+    let current_dir = &env::current_dir()?;
+    let from_path = fetch_template(
+        &command.url,
+        command.template_name.as_deref(),
+        &local_repo_name,
+    )?;
+
+    // Create the target dir
+    let target_dir = current_dir.join(&command.project_name);
+
+    info!("Creating {} from template", &command.project_name);
+    // Copy contents from template to target dir
+    copy_template_to_target(&from_path, &target_dir)?;
+
+    // Edit forc.toml
+    edit_forc_toml(&target_dir, &command.project_name, &whoami::realname())?;
+    if target_dir.join("test").exists() {
+        edit_cargo_toml(&target_dir, &command.project_name, &whoami::realname())?;
+    }
+    Ok(())
+}
+
+/// Fetches the git repository at `url`, and returns the path of the template within it: the
+/// directory named `template_name` if one is given, or the root of the repository otherwise.
+pub(crate) fn fetch_template(
+    url: &str,
+    template_name: Option<&str>,
+    local_repo_name: &str,
+) -> Result<PathBuf> {
     let source = source::git::Source {
-        repo: Url::from_str(&command.url)?,
+        repo: Url::from_str(url)?,
         reference: source::git::Reference::DefaultBranch,
     };
 
     let current_dir = &env::current_dir()?;
-
     let fetch_ts = std::time::Instant::now();
     let fetch_id = source::fetch_id(current_dir, fetch_ts);
 
     info!("Resolving the HEAD of {}", source.repo);
-    let git_source = source::git::pin(fetch_id, &local_repo_name, source)?;
+    let git_source = source::git::pin(fetch_id, local_repo_name, source)?;
 
     let repo_path = source::git::commit_path(
-        &local_repo_name,
+        local_repo_name,
         &git_source.source.repo,
         &git_source.commit_hash,
     );
     if !repo_path.exists() {
         info!("  Fetching {}", git_source.to_string());
-        source::git::fetch(fetch_id, &local_repo_name, &git_source)?;
+        source::git::fetch(fetch_id, local_repo_name, &git_source)?;
     }
 
-    let from_path = match command.template_name {
-        Some(ref template_name) => manifest::find_dir_within(&repo_path, template_name)
-            .ok_or_else(|| {
-                anyhow!(
-                    "failed to find a template `{}` in {}",
-                    template_name,
-                    command.url
-                )
-            })?,
+    let from_path = match template_name {
+        Some(template_name) => manifest::find_dir_within(&repo_path, template_name)
+            .ok_or_else(|| anyhow!("failed to find a template `{}` in {}", template_name, url))?,
         None => {
             let manifest_path = repo_path.join(constants::MANIFEST_FILE_NAME);
             if PackageManifest::from_file(manifest_path).is_err() {
-                anyhow::bail!("failed to find a template in {}", command.url);
+                anyhow::bail!("failed to find a template in {}", url);
             }
             repo_path
         }
     };
-
-    // Create the target dir
-    let target_dir = current_dir.join(&command.project_name);
-
-    info!("Creating {} from template", &command.project_name);
-    // Copy contents from template to target dir
-    copy_template_to_target(&from_path, &target_dir)?;
-
-    // Edit forc.toml
-    edit_forc_toml(&target_dir, &command.project_name, &whoami::realname())?;
-    if target_dir.join("test").exists() {
-        edit_cargo_toml(&target_dir, &command.project_name, &whoami::realname())?;
-    }
-    Ok(())
+    Ok(from_path)
 }
 
-fn edit_forc_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
+pub(crate) fn edit_forc_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
     let mut file = File::open(out_dir.join(constants::MANIFEST_FILE_NAME))?;
     let mut toml = String::new();
     file.read_to_string(&mut toml)?;
@@ -120,7 +129,7 @@ fn edit_forc_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result
     Ok(())
 }
 
-fn edit_cargo_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
+pub(crate) fn edit_cargo_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
     let mut file = File::open(out_dir.join(constants::TEST_MANIFEST_FILE_NAME))?;
     let mut toml = String::new();
     file.read_to_string(&mut toml)?;
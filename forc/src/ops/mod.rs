@@ -1,8 +1,12 @@
+pub mod forc_add;
 pub mod forc_build;
+pub mod forc_bytecode_diff;
 pub mod forc_check;
 pub mod forc_clean;
 pub mod forc_contract_id;
 pub mod forc_init;
+pub mod forc_multicall;
 pub mod forc_predicate_root;
+pub mod forc_remove;
 pub mod forc_template;
 pub mod forc_update;
@@ -15,6 +15,30 @@ name = "{project_name}"
     )
 }
 
+/// The manifest of the Rust SDK harness of a project created from a template.
+pub(crate) fn default_test_manifest(project_name: &str) -> String {
+    let author = get_author();
+
+    format!(
+        r#"[package]
+name = "{project_name}"
+version = "0.1.0"
+edition = "2021"
+authors = ["{author}"]
+license = "Apache-2.0"
+
+[dev-dependencies]
+fuels = {{ version = "0.54.0", features = ["fuel-core-lib"] }}
+tokio = {{ version = "1.12", features = ["rt", "macros"] }}
+
+[[test]]
+harness = true
+name = "integration_tests"
+path = "tests/harness.rs"
+"#
+    )
+}
+
 pub(crate) fn default_workspace_manifest() -> String {
     r#"[workspace]
 members = []"#
@@ -72,7 +96,7 @@ target
     .into()
 }
 
-fn get_author() -> String {
+pub(crate) fn get_author() -> String {
     std::env::var(sway_utils::FORC_INIT_MANIFEST_AUTHOR).unwrap_or_else(|_| whoami::realname())
 }
 
@@ -86,6 +110,13 @@ fn parse_default_pkg_manifest() {
     )
 }
 #[test]
+fn parse_default_test_manifest() {
+    tracing::info!(
+        "{:#?}",
+        toml::from_str::<toml::Value>(&default_test_manifest("test_proj")).unwrap()
+    )
+}
+#[test]
 fn parse_default_workspace_manifest() {
     tracing::info!(
         "{:#?}",
@@ -1,2 +1,3 @@
 pub mod defaults;
 pub mod program_type;
+pub mod template;
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+/// A project template that ships with forc, and that `forc init` and `forc new` can create a
+/// project from.
+///
+/// Each template is a contract with its storage layout and unit tests, along with an example
+/// Rust SDK harness. The sources of the templates live in the `templates` directory of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Counter,
+    Token,
+    Amm,
+    Multisig,
+}
+
+impl Template {
+    pub const ALL: [Template; 4] = [
+        Template::Counter,
+        Template::Token,
+        Template::Amm,
+        Template::Multisig,
+    ];
+
+    /// The source of the contract of the template.
+    pub(crate) fn contract(self) -> &'static str {
+        match self {
+            Template::Counter => include_str!("../../templates/counter/src/main.sw"),
+            Template::Token => include_str!("../../templates/token/src/main.sw"),
+            Template::Amm => include_str!("../../templates/amm/src/main.sw"),
+            Template::Multisig => include_str!("../../templates/multisig/src/main.sw"),
+        }
+    }
+
+    /// The source of the Rust SDK harness of the template, for the project `project_name`.
+    pub(crate) fn harness(self, project_name: &str) -> String {
+        let harness = match self {
+            Template::Counter => include_str!("../../templates/counter/tests/harness.rs"),
+            Template::Token => include_str!("../../templates/token/tests/harness.rs"),
+            Template::Amm => include_str!("../../templates/amm/tests/harness.rs"),
+            Template::Multisig => include_str!("../../templates/multisig/tests/harness.rs"),
+        };
+        harness.replace("{{project-name}}", project_name)
+    }
+}
+
+impl std::fmt::Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Template::Counter => "counter",
+            Template::Token => "token",
+            Template::Amm => "amm",
+            Template::Multisig => "multisig",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::ALL
+            .into_iter()
+            .find(|template| template.to_string() == s)
+            .ok_or_else(|| {
+                let templates = Template::ALL.map(|template| template.to_string());
+                anyhow::anyhow!(
+                    "unknown template `{s}`, the available templates are: {}",
+                    templates.join(", ")
+                )
+            })
+    }
+}
@@ -0,0 +1,122 @@
+contract;
+
+use std::{
+    asset::transfer,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    u128::U128,
+};
+
+configurable {
+    /// One of the two assets of the pool.
+    ASSET_A: AssetId = AssetId {
+        value: 0x0000000000000000000000000000000000000000000000000000000000000000,
+    },
+    /// The other asset of the pool.
+    ASSET_B: AssetId = AssetId {
+        value: 0x0000000000000000000000000000000000000000000000000000000000000001,
+    },
+}
+
+/// The fee taken from each swap, in thousandths.
+const FEE: u64 = 3;
+
+abi Amm {
+    /// Adds the forwarded coins of either asset to the reserves of the pool.
+    #[payable]
+    #[storage(read, write)]
+    fn deposit();
+
+    /// Swaps the forwarded coins for the other asset, and sends them to `recipient`.
+    #[payable]
+    #[storage(read, write)]
+    fn swap(recipient: Identity) -> u64;
+
+    /// Returns the amount of the other asset received when swapping `amount_in` of `asset_in`.
+    #[storage(read)]
+    fn quote(asset_in: AssetId, amount_in: u64) -> u64;
+
+    #[storage(read)]
+    fn reserves() -> (u64, u64);
+}
+
+storage {
+    reserve_a: u64 = 0,
+    reserve_b: u64 = 0,
+}
+
+/// Returns the output of a swap of `amount_in` against a constant product pool.
+fn amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    let amount_in = U128::from((0, amount_in * (1000 - FEE)));
+    let numerator = amount_in * U128::from((0, reserve_out));
+    let denominator = U128::from((0, reserve_in * 1000)) + amount_in;
+    (numerator / denominator).as_u64().unwrap()
+}
+
+impl Amm for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit() {
+        let asset_id = msg_asset_id();
+        if asset_id == ASSET_A {
+            storage.reserve_a.write(storage.reserve_a.read() + msg_amount());
+        } else {
+            require(asset_id == ASSET_B, "not an asset of the pool");
+            storage.reserve_b.write(storage.reserve_b.read() + msg_amount());
+        }
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn swap(recipient: Identity) -> u64 {
+        let amount_in = msg_amount();
+        let reserve_a = storage.reserve_a.read();
+        let reserve_b = storage.reserve_b.read();
+        let asset_in = msg_asset_id();
+        let (asset_out, amount_out) = if asset_in == ASSET_A {
+            let amount_out = amount_out(amount_in, reserve_a, reserve_b);
+            storage.reserve_a.write(reserve_a + amount_in);
+            storage.reserve_b.write(reserve_b - amount_out);
+            (ASSET_B, amount_out)
+        } else {
+            require(asset_in == ASSET_B, "not an asset of the pool");
+            let amount_out = amount_out(amount_in, reserve_b, reserve_a);
+            storage.reserve_b.write(reserve_b + amount_in);
+            storage.reserve_a.write(reserve_a - amount_out);
+            (ASSET_A, amount_out)
+        };
+        require(amount_out > 0, "insufficient liquidity");
+        transfer(recipient, asset_out, amount_out);
+        amount_out
+    }
+
+    #[storage(read)]
+    fn quote(asset_in: AssetId, amount_in: u64) -> u64 {
+        let reserve_a = storage.reserve_a.read();
+        let reserve_b = storage.reserve_b.read();
+        if asset_in == ASSET_A {
+            amount_out(amount_in, reserve_a, reserve_b)
+        } else {
+            amount_out(amount_in, reserve_b, reserve_a)
+        }
+    }
+
+    #[storage(read)]
+    fn reserves() -> (u64, u64) {
+        (storage.reserve_a.read(), storage.reserve_b.read())
+    }
+}
+
+#[test]
+fn test_amount_out() {
+    assert(amount_out(1000, 1_000_000, 1_000_000) == 996);
+    assert(amount_out(1000, 1_000_000, 0) == 0);
+}
+
+#[test]
+fn test_empty_pool() {
+    let amm = abi(Amm, CONTRACT_ID);
+    let (reserve_a, reserve_b) = amm.reserves();
+    assert(reserve_a == 0 && reserve_b == 0);
+    assert(amm.quote(ASSET_A, 1000) == 0);
+}
@@ -0,0 +1,51 @@
+use fuels::prelude::*;
+
+abigen!(Contract(
+    name = "Amm",
+    abi = "out/debug/{{project-name}}-abi.json"
+));
+
+async fn get_contract_instance() -> Amm<WalletUnlocked> {
+    let wallet = launch_provider_and_get_wallet().await.unwrap();
+    let id = Contract::load_from(
+        "./out/debug/{{project-name}}.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+    Amm::new(id, wallet)
+}
+
+#[tokio::test]
+async fn can_deposit() {
+    let instance = get_contract_instance().await;
+
+    // The base asset is the first asset of the pool by default.
+    instance
+        .methods()
+        .deposit()
+        .call_params(CallParameters::default().with_amount(1_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let reserves = instance.methods().reserves().call().await.unwrap().value;
+    assert_eq!(reserves, (1_000, 0));
+}
+
+#[tokio::test]
+async fn quotes_nothing_without_liquidity() {
+    let instance = get_contract_instance().await;
+
+    let quote = instance
+        .methods()
+        .quote(AssetId::default(), 1_000)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(quote, 0);
+}
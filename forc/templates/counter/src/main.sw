@@ -0,0 +1,52 @@
+contract;
+
+abi Counter {
+    #[storage(read)]
+    fn count() -> u64;
+
+    #[storage(read, write)]
+    fn increment() -> u64;
+
+    #[storage(write)]
+    fn reset();
+}
+
+storage {
+    counter: u64 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.counter.read()
+    }
+
+    #[storage(read, write)]
+    fn increment() -> u64 {
+        let incremented = storage.counter.read() + 1;
+        storage.counter.write(incremented);
+        incremented
+    }
+
+    #[storage(write)]
+    fn reset() {
+        storage.counter.write(0);
+    }
+}
+
+#[test]
+fn test_increment() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.count() == 0);
+    assert(counter.increment() == 1);
+    assert(counter.increment() == 2);
+    assert(counter.count() == 2);
+}
+
+#[test]
+fn test_reset() {
+    let counter = abi(Counter, CONTRACT_ID);
+    let _ = counter.increment();
+    counter.reset();
+    assert(counter.count() == 0);
+}
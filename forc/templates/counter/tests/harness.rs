@@ -0,0 +1,41 @@
+use fuels::prelude::*;
+
+abigen!(Contract(
+    name = "Counter",
+    abi = "out/debug/{{project-name}}-abi.json"
+));
+
+async fn get_contract_instance() -> Counter<WalletUnlocked> {
+    let wallet = launch_provider_and_get_wallet().await.unwrap();
+    let id = Contract::load_from(
+        "./out/debug/{{project-name}}.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+    Counter::new(id, wallet)
+}
+
+#[tokio::test]
+async fn can_increment() {
+    let instance = get_contract_instance().await;
+
+    let count = instance.methods().increment().call().await.unwrap().value;
+    assert_eq!(count, 1);
+
+    let count = instance.methods().count().call().await.unwrap().value;
+    assert_eq!(count, 1);
+}
+
+#[tokio::test]
+async fn can_reset() {
+    let instance = get_contract_instance().await;
+
+    instance.methods().increment().call().await.unwrap();
+    instance.methods().reset().call().await.unwrap();
+
+    let count = instance.methods().count().call().await.unwrap().value;
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,129 @@
+contract;
+
+use std::{asset::transfer, auth::msg_sender};
+
+configurable {
+    /// The addresses allowed to propose and approve transfers.
+    OWNERS: [b256; 3] = [
+        0x0000000000000000000000000000000000000000000000000000000000000001,
+        0x0000000000000000000000000000000000000000000000000000000000000002,
+        0x0000000000000000000000000000000000000000000000000000000000000003,
+    ],
+    /// The number of approvals required to execute a transfer.
+    THRESHOLD: u64 = 2,
+}
+
+/// A transfer of the coins held by the contract, awaiting approvals.
+struct Proposal {
+    recipient: Identity,
+    asset_id: AssetId,
+    amount: u64,
+    approvals: u64,
+    executed: bool,
+}
+
+abi Multisig {
+    /// Proposes a transfer, and returns its id.
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset_id: AssetId, amount: u64) -> u64;
+
+    #[storage(read, write)]
+    fn approve(proposal_id: u64);
+
+    /// Executes a transfer once it has been approved by enough owners.
+    #[storage(read, write)]
+    fn execute(proposal_id: u64);
+
+    #[storage(read)]
+    fn proposal_count() -> u64;
+}
+
+storage {
+    proposal_count: u64 = 0,
+    proposals: StorageMap<u64, Proposal> = StorageMap {},
+    approvals: StorageMap<(u64, b256), bool> = StorageMap {},
+}
+
+fn is_owner(address: b256) -> bool {
+    let mut i = 0;
+    while i < 3 {
+        if OWNERS[i] == address {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the address of the caller, reverting if it is not an owner.
+fn owner() -> b256 {
+    let sender = match msg_sender().unwrap() {
+        Identity::Address(address) => address.value,
+        Identity::ContractId(contract_id) => contract_id.value,
+    };
+    require(is_owner(sender), "not an owner");
+    sender
+}
+
+impl Multisig for Contract {
+    #[storage(read, write)]
+    fn propose(recipient: Identity, asset_id: AssetId, amount: u64) -> u64 {
+        let _ = owner();
+        let proposal_id = storage.proposal_count.read();
+        storage.proposals.insert(proposal_id, Proposal {
+            recipient,
+            asset_id,
+            amount,
+            approvals: 0,
+            executed: false,
+        });
+        storage.proposal_count.write(proposal_id + 1);
+        proposal_id
+    }
+
+    #[storage(read, write)]
+    fn approve(proposal_id: u64) {
+        let owner = owner();
+        let mut proposal = storage.proposals.get(proposal_id).read();
+        require(!proposal.executed, "already executed");
+        require(storage.approvals.get((proposal_id, owner)).try_read().is_none(), "already approved");
+        storage.approvals.insert((proposal_id, owner), true);
+        proposal.approvals += 1;
+        storage.proposals.insert(proposal_id, proposal);
+    }
+
+    #[storage(read, write)]
+    fn execute(proposal_id: u64) {
+        let _ = owner();
+        let mut proposal = storage.proposals.get(proposal_id).read();
+        require(!proposal.executed, "already executed");
+        require(proposal.approvals >= THRESHOLD, "not enough approvals");
+        proposal.executed = true;
+        storage.proposals.insert(proposal_id, proposal);
+        transfer(proposal.recipient, proposal.asset_id, proposal.amount);
+    }
+
+    #[storage(read)]
+    fn proposal_count() -> u64 {
+        storage.proposal_count.read()
+    }
+}
+
+#[test]
+fn test_is_owner() {
+    assert(is_owner(OWNERS[0]));
+    assert(!is_owner(std::constants::ZERO_B256));
+}
+
+#[test]
+fn test_no_proposals() {
+    let multisig = abi(Multisig, CONTRACT_ID);
+    assert(multisig.proposal_count() == 0);
+}
+
+#[test(should_revert)]
+fn test_propose_not_owner() {
+    let multisig = abi(Multisig, CONTRACT_ID);
+    let recipient = Identity::Address(Address::from(std::constants::ZERO_B256));
+    let _ = multisig.propose(recipient, AssetId { value: std::constants::ZERO_B256 }, 1);
+}
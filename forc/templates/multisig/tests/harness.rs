@@ -0,0 +1,65 @@
+use fuels::{prelude::*, types::Identity};
+
+abigen!(Contract(
+    name = "Multisig",
+    abi = "out/debug/{{project-name}}-abi.json"
+));
+
+/// Deploys the contract with three wallets as its owners.
+async fn get_contract_instances() -> Vec<Multisig<WalletUnlocked>> {
+    let wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(3), Some(1), Some(1_000_000_000)),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let owners = [0, 1, 2].map(|ix| Bits256(*wallets[ix].address().hash()));
+    let configurables = MultisigConfigurables::new().with_OWNERS(owners);
+
+    let id = Contract::load_from(
+        "./out/debug/{{project-name}}.bin",
+        LoadConfiguration::default().with_configurables(configurables),
+    )
+    .unwrap()
+    .deploy(&wallets[0], TxPolicies::default())
+    .await
+    .unwrap();
+
+    wallets
+        .into_iter()
+        .map(|wallet| Multisig::new(id.clone(), wallet))
+        .collect()
+}
+
+#[tokio::test]
+async fn can_propose_and_approve() {
+    let instances = get_contract_instances().await;
+    let recipient = Identity::Address(instances[0].account().address().into());
+
+    let proposal_id = instances[0]
+        .methods()
+        .propose(recipient, AssetId::default(), 100)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(proposal_id, 0);
+
+    instances[0]
+        .methods()
+        .approve(proposal_id)
+        .call()
+        .await
+        .unwrap();
+    instances[1]
+        .methods()
+        .approve(proposal_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Each owner can only approve a proposal once.
+    let result = instances[1].methods().approve(proposal_id).call().await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,71 @@
+contract;
+
+use std::{
+    asset::{
+        burn,
+        mint_to,
+    },
+    call_frames::msg_asset_id,
+    constants::DEFAULT_SUB_ID,
+    context::msg_amount,
+};
+
+configurable {
+    /// The maximum number of coins that can be in circulation.
+    MAX_SUPPLY: u64 = 1_000_000,
+}
+
+abi Token {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64);
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn();
+
+    #[storage(read)]
+    fn total_supply() -> u64;
+}
+
+storage {
+    total_supply: u64 = 0,
+}
+
+impl Token for Contract {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64) {
+        let total_supply = storage.total_supply.read() + amount;
+        require(total_supply <= MAX_SUPPLY, "exceeds the maximum supply");
+        storage.total_supply.write(total_supply);
+        mint_to(recipient, DEFAULT_SUB_ID, amount);
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn() {
+        require(msg_asset_id() == AssetId::default(), "not the asset of this token");
+        let amount = msg_amount();
+        storage.total_supply.write(storage.total_supply.read() - amount);
+        burn(DEFAULT_SUB_ID, amount);
+    }
+
+    #[storage(read)]
+    fn total_supply() -> u64 {
+        storage.total_supply.read()
+    }
+}
+
+#[test]
+fn test_mint() {
+    let token = abi(Token, CONTRACT_ID);
+    let recipient = Identity::Address(Address::from(std::constants::ZERO_B256));
+    token.mint(recipient, 100);
+    assert(token.total_supply() == 100);
+}
+
+#[test(should_revert)]
+fn test_mint_exceeds_max_supply() {
+    let token = abi(Token, CONTRACT_ID);
+    let recipient = Identity::Address(Address::from(std::constants::ZERO_B256));
+    token.mint(recipient, MAX_SUPPLY + 1);
+}
@@ -0,0 +1,56 @@
+use fuels::{prelude::*, types::Identity};
+
+abigen!(Contract(
+    name = "Token",
+    abi = "out/debug/{{project-name}}-abi.json"
+));
+
+async fn get_contract_instance() -> (Token<WalletUnlocked>, WalletUnlocked) {
+    let wallet = launch_provider_and_get_wallet().await.unwrap();
+    let id = Contract::load_from(
+        "./out/debug/{{project-name}}.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+    (Token::new(id, wallet.clone()), wallet)
+}
+
+#[tokio::test]
+async fn can_mint() {
+    let (instance, wallet) = get_contract_instance().await;
+    let recipient = Identity::Address(wallet.address().into());
+
+    instance
+        .methods()
+        .mint(recipient, 100)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    let total_supply = instance
+        .methods()
+        .total_supply()
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(total_supply, 100);
+}
+
+#[tokio::test]
+async fn cannot_exceed_max_supply() {
+    let (instance, wallet) = get_contract_instance().await;
+    let recipient = Identity::Address(wallet.address().into());
+
+    let result = instance
+        .methods()
+        .mint(recipient, 1_000_001)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+    assert!(result.is_err());
+}
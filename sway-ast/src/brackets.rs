@@ -30,6 +30,13 @@ macro_rules! define_brackets (
                 self.span.clone()
             }
         }
+
+        impl<T: Relocate> Relocate for $ty_name<T> {
+            fn relocate(&mut self, relocator: &Relocator) {
+                self.inner.relocate(relocator);
+                self.span.relocate(relocator);
+            }
+        }
     };
 );
 
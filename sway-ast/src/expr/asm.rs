@@ -4,15 +4,26 @@ use crate::priv_prelude::*;
 pub struct AsmBlock {
     pub asm_token: AsmToken,
     pub registers: Parens<Punctuated<AsmRegisterDeclaration, CommaToken>>,
+    pub clobbers_opt: Option<AsmClobbers>,
     pub contents: Braces<AsmBlockContents>,
 }
 
+/// A register declaration of an asm block, like `r1: x`, or a `const` operand, like
+/// `const offset: 8`, whose value is used by name as an immediate in the instructions.
 #[derive(Clone, Debug, Serialize)]
 pub struct AsmRegisterDeclaration {
+    pub const_token_opt: Option<ConstToken>,
     pub register: Ident,
     pub value_opt: Option<(ColonToken, Box<Expr>)>,
 }
 
+/// The `clobbers(r1, r2)` list of the initialized registers an asm block writes to.
+#[derive(Clone, Debug, Serialize)]
+pub struct AsmClobbers {
+    pub clobbers_token: Ident,
+    pub registers: Parens<Punctuated<Ident, CommaToken>>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct AsmBlockContents {
     pub instructions: Vec<(Instruction, SemicolonToken)>,
@@ -21,14 +32,32 @@ pub struct AsmBlockContents {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct AsmFinalExpr {
-    pub register: Ident,
+    pub registers: AsmFinalRegisters,
     pub ty_opt: Option<(ColonToken, Ty)>,
 }
 
+/// The registers returned by an asm block, either a single register, like `r1`, or a tuple of
+/// registers, like `(r1, r2)`.
+#[derive(Clone, Debug, Serialize)]
+pub enum AsmFinalRegisters {
+    Register(Ident),
+    Tuple(Parens<Punctuated<Ident, CommaToken>>),
+}
+
+impl AsmFinalRegisters {
+    pub fn registers(&self) -> Vec<&Ident> {
+        match self {
+            AsmFinalRegisters::Register(register) => vec![register],
+            AsmFinalRegisters::Tuple(registers) => registers.get().into_iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct AsmImmediate {
     pub span: Span,
-    pub parsed: BigUint,
+    /// The value of the immediate, or `None` if it names a `const` operand of the asm block.
+    pub parsed: Option<BigUint>,
 }
 
 impl Spanned for AsmImmediate {
@@ -42,3 +71,18 @@ impl Spanned for AsmBlock {
         Span::join(self.asm_token.span(), self.contents.span())
     }
 }
+
+impl Spanned for AsmClobbers {
+    fn span(&self) -> Span {
+        Span::join(self.clobbers_token.span(), self.registers.span())
+    }
+}
+
+impl Spanned for AsmFinalRegisters {
+    fn span(&self) -> Span {
+        match self {
+            AsmFinalRegisters::Register(register) => register.span(),
+            AsmFinalRegisters::Tuple(registers) => registers.span(),
+        }
+    }
+}
@@ -38,7 +38,7 @@ pub enum Expr {
     },
     While {
         while_token: WhileToken,
-        condition: Box<Expr>,
+        condition: IfCondition,
         block: Braces<CodeBlockContents>,
     },
     FuncApp {
@@ -49,6 +49,13 @@ pub enum Expr {
         target: Box<Expr>,
         arg: SquareBrackets<Box<Expr>>,
     },
+    /// A range of indices, i.e. `start..end`, which is only valid as the argument of an index
+    /// expression, e.g. `array[1..3]`.
+    Range {
+        start: Box<Expr>,
+        dot_dot_token: DoubleDotToken,
+        end: Box<Expr>,
+    },
     MethodCall {
         target: Box<Expr>,
         dot_token: DotToken,
@@ -222,6 +229,7 @@ impl Spanned for Expr {
             } => Span::join(while_token.span(), block.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
+            Expr::Range { start, end, .. } => Span::join(start.span(), end.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
             Expr::FieldProjection { target, name, .. } => Span::join(target.span(), name.span()),
             Expr::TupleFieldProjection {
@@ -290,6 +298,20 @@ impl ReassignmentOpVariant {
             ReassignmentOpVariant::ShrEquals => "rsh",
         }
     }
+
+    /// The name of the method of the assignment operator trait in `core::ops`, e.g.
+    /// `add_assign` for `+=`, if the operator has one.
+    pub fn assign_core_name(&self) -> Option<&'static str> {
+        match self {
+            ReassignmentOpVariant::AddEquals => Some("add_assign"),
+            ReassignmentOpVariant::SubEquals => Some("subtract_assign"),
+            ReassignmentOpVariant::MulEquals => Some("multiply_assign"),
+            ReassignmentOpVariant::DivEquals => Some("divide_assign"),
+            ReassignmentOpVariant::Equals
+            | ReassignmentOpVariant::ShlEquals
+            | ReassignmentOpVariant::ShrEquals => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -311,6 +333,7 @@ pub struct IfExpr {
     )>,
 }
 
+/// The condition of an `if` or `while` expression.
 #[derive(Clone, Debug, Serialize)]
 pub enum IfCondition {
     Expr(Box<Expr>),
@@ -320,6 +343,23 @@ pub enum IfCondition {
         eq_token: EqToken,
         rhs: Box<Expr>,
     },
+    /// Conditions chained with `&&` after a `let` condition, e.g. `let Some(x) = e && x > 0`.
+    /// The bindings of a `let` condition are in scope of the conditions following it.
+    And {
+        lhs: Box<IfCondition>,
+        double_ampersand_token: DoubleAmpersandToken,
+        rhs: Box<IfCondition>,
+    },
+}
+
+impl Spanned for IfCondition {
+    fn span(&self) -> Span {
+        match self {
+            IfCondition::Expr(expr) => expr.span(),
+            IfCondition::Let { let_token, rhs, .. } => Span::join(let_token.span(), rhs.span()),
+            IfCondition::And { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -365,6 +405,8 @@ pub enum ExprArrayDescriptor {
 #[derive(Clone, Debug, Serialize)]
 pub struct MatchBranch {
     pub pattern: Pattern,
+    /// The guard of the branch, e.g. `if x > 3` in `Some(x) if x > 3 => ...`.
+    pub guard_opt: Option<(IfToken, Box<Expr>)>,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
 }
@@ -12,6 +12,12 @@ macro_rules! define_op_code (
                 self.span.clone()
             }
         }
+
+        impl Relocate for $ty_name {
+            fn relocate(&mut self, relocator: &Relocator) {
+                self.span.relocate(relocator);
+            }
+        }
     );
 );
 
@@ -114,6 +120,17 @@ macro_rules! define_op_codes (
             },)*
         }
 
+        impl Relocate for Instruction {
+            fn relocate(&mut self, relocator: &Relocator) {
+                match self {
+                    $(Instruction::$op_name { token, $($arg_name,)* } => {
+                        token.relocate(relocator);
+                        $($arg_name.relocate(relocator);)*
+                    },)*
+                }
+            }
+        }
+
         impl Instruction {
             pub fn op_code_ident(&self) -> Ident {
                 match self {
@@ -329,6 +346,12 @@ define_op_codes!(
         "gtf",
         (ret: reg, index: reg, tx_field_id: imm)
     ),
+    (
+        Ecal,
+        EcalOpcode,
+        "ecal",
+        (reg_a: reg, reg_b: reg, reg_c: reg, reg_d: reg)
+    ),
     /* Non-VM Instructions */
     (Blob, BlobOpcode, "blob", (size: imm)),
 );
@@ -35,6 +35,8 @@ pub enum Intrinsic {
     PtrSub,
     Smo,
     Not,
+    TypeName,
+    TypeId,
 }
 
 impl fmt::Display for Intrinsic {
@@ -73,6 +75,8 @@ impl fmt::Display for Intrinsic {
             Intrinsic::PtrSub => "ptr_sub",
             Intrinsic::Smo => "smo",
             Intrinsic::Not => "not",
+            Intrinsic::TypeName => "type_name",
+            Intrinsic::TypeId => "type_id",
         };
         write!(f, "{s}")
     }
@@ -115,6 +119,8 @@ impl Intrinsic {
             "__ptr_sub" => PtrSub,
             "__smo" => Smo,
             "__not" => Not,
+            "__type_name" => TypeName,
+            "__type_id" => TypeId,
             _ => return None,
         })
     }
@@ -69,11 +69,17 @@ pub struct TypeField {
     pub name: Ident,
     pub colon_token: ColonToken,
     pub ty: Ty,
+    /// The explicit discriminant of an enum variant, as in `A: () = 1`.
+    pub discriminant_opt: Option<(EqToken, Literal)>,
 }
 
 impl Spanned for TypeField {
     fn span(&self) -> Span {
-        Span::join(self.name.span(), self.ty.span())
+        let end = match &self.discriminant_opt {
+            Some((_, discriminant)) => discriminant.span(),
+            None => self.ty.span(),
+        };
+        Span::join(self.name.span(), end)
     }
 }
 
@@ -25,6 +25,12 @@ macro_rules! define_keyword (
             }
         }
 
+        impl Relocate for $ty_name {
+            fn relocate(&mut self, relocator: &Relocator) {
+                self.span.relocate(relocator);
+            }
+        }
+
         impl Keyword for $ty_name {
             fn new(span: Span) -> Self {
                 $ty_name { span }
@@ -120,6 +126,12 @@ macro_rules! define_token (
             }
         }
 
+        impl Relocate for $ty_name {
+            fn relocate(&mut self, relocator: &Relocator) {
+                self.span.relocate(relocator);
+            }
+        }
+
         impl Token for $ty_name {
             fn new(span: Span) -> Self {
                 $ty_name { span }
@@ -186,7 +198,7 @@ define_token!(
     [Equals, GreaterThan],
     [GreaterThan, Equals]
 );
-define_token!(DotToken, "`.`", [Dot], []);
+define_token!(DotToken, "`.`", [Dot], [Dot]);
 define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
 define_token!(PercentToken, "`%`", [Percent], []);
@@ -12,6 +12,7 @@ pub mod path;
 pub mod pattern;
 mod priv_prelude;
 pub mod punctuated;
+pub mod relocate;
 pub mod statement;
 pub mod submodule;
 pub mod token;
@@ -28,6 +28,7 @@ pub use {
         path::{PathExpr, PathType},
         pattern::Pattern,
         punctuated::Punctuated,
+        relocate::{Relocate, Relocator},
         statement::Statement,
         submodule::Submodule,
         ty::Ty,
@@ -0,0 +1,874 @@
+//! Moving the syntax trees of a source file into an edited version of the file, so that the trees
+//! away from an edit can be reused rather than lexed and parsed again.
+
+use crate::{
+    attribute::{Attribute, AttributeArg, AttributeDecl, AttributeHashKind},
+    expr::{
+        asm::{
+            AsmBlockContents, AsmClobbers, AsmFinalExpr, AsmFinalRegisters, AsmRegisterDeclaration,
+        },
+        AbiCastArgs, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, IfCondition,
+        IfExpr, LoopControlFlow, MatchBranch, MatchBranchKind, ReassignmentOp,
+    },
+    item::{
+        item_configurable::ConfigurableField, item_impl::ItemImplItem, item_storage::StorageField,
+        item_trait::ItemTraitItem, item_use::UseTree, FnArg, FnArgs,
+    },
+    literal::{LitBool, LitChar, LitInt, LitString},
+    module::{Module, ModuleKind},
+    path::{PathExprSegment, PathTypeSegment, QualifiedPathRoot},
+    pattern::PatternStructField,
+    priv_prelude::*,
+    statement::StatementLet,
+    token::{
+        Comment, CommentedTokenStream, CommentedTokenTree, DocComment, GenericGroup,
+        GenericTokenTree, Punct,
+    },
+    ty::{TyArrayDescriptor, TyTupleDescriptor},
+    where_clause::WhereBound,
+};
+use std::sync::Arc;
+
+/// Moves spans from a source file into an edited version of it, in which the code they cover is
+/// `delta` bytes further.
+#[derive(Debug, Clone)]
+pub struct Relocator {
+    /// The source the spans point into.
+    pub from: Arc<str>,
+    /// The edited source.
+    pub to: Arc<str>,
+    pub delta: isize,
+}
+
+impl Relocator {
+    /// Returns the span moved into the edited source. Spans that do not point into the source
+    /// the trees were parsed from, like dummy spans, are kept as they are.
+    ///
+    /// Panics if the moved span is not within the edited source.
+    pub fn span(&self, span: &Span) -> Span {
+        if !Arc::ptr_eq(span.src(), &self.from) {
+            return span.clone();
+        }
+        let start = (span.start() as isize + self.delta) as usize;
+        let end = (span.end() as isize + self.delta) as usize;
+        Span::new(self.to.clone(), start, end, span.source_id().cloned())
+            .expect("the relocated span is within the edited source")
+    }
+}
+
+/// A syntax tree whose spans can be moved into an edited version of its source.
+pub trait Relocate {
+    fn relocate(&mut self, relocator: &Relocator);
+}
+
+impl Relocate for Span {
+    fn relocate(&mut self, relocator: &Relocator) {
+        *self = relocator.span(self);
+    }
+}
+
+impl Relocate for Ident {
+    fn relocate(&mut self, relocator: &Relocator) {
+        let span = relocator.span(&self.span());
+        *self = match self.name_override_opt() {
+            Some(name_override) => Ident::new_with_override(name_override.to_string(), span),
+            None if self.is_raw_ident() => Ident::new_with_raw(span, true),
+            None => Ident::new_no_trim(span),
+        };
+    }
+}
+
+impl<T: Relocate + ?Sized> Relocate for Box<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        (**self).relocate(relocator);
+    }
+}
+
+impl<T: Relocate> Relocate for [T] {
+    fn relocate(&mut self, relocator: &Relocator) {
+        for value in self.iter_mut() {
+            value.relocate(relocator);
+        }
+    }
+}
+
+impl<T: Relocate> Relocate for Vec<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.as_mut_slice().relocate(relocator);
+    }
+}
+
+impl<T: Relocate> Relocate for Option<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        if let Some(value) = self {
+            value.relocate(relocator);
+        }
+    }
+}
+
+impl<A: Relocate, B: Relocate> Relocate for (A, B) {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.0.relocate(relocator);
+        self.1.relocate(relocator);
+    }
+}
+
+impl<T: Relocate> Relocate for Annotated<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.attribute_list.relocate(relocator);
+        self.value.relocate(relocator);
+    }
+}
+
+impl<T: Relocate, P: Relocate> Relocate for Punctuated<T, P> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.value_separator_pairs.relocate(relocator);
+        self.final_value_opt.relocate(relocator);
+    }
+}
+
+impl<T: Relocate> Relocate for AngleBrackets<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.open_angle_bracket_token.relocate(relocator);
+        self.inner.relocate(relocator);
+        self.close_angle_bracket_token.relocate(relocator);
+    }
+}
+
+impl<B: Relocate, C: Relocate> Relocate for LoopControlFlow<B, C> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            LoopControlFlow::Continue(value) => value.relocate(relocator),
+            LoopControlFlow::Break(value) => value.relocate(relocator),
+        }
+    }
+}
+
+/// Implements [Relocate] for a struct by relocating the given fields, the others having no spans.
+macro_rules! relocate_fields (
+    ($($ty_name:ident { $($field:ident),* $(,)? })*) => {
+        $(
+            impl Relocate for $ty_name {
+                fn relocate(&mut self, relocator: &Relocator) {
+                    $(self.$field.relocate(relocator);)*
+                }
+            }
+        )*
+    };
+);
+
+relocate_fields! {
+    Module { kind, semicolon_token, items }
+    AttributeDecl { hash_kind, attribute }
+    Attribute { name, args }
+    AttributeArg { name, value }
+    GenericParams { parameters }
+    GenericArgs { parameters }
+    LitString { span }
+    LitChar { span }
+    LitBool { span }
+    PathExpr { root_opt, prefix, suffix }
+    PathExprSegment { name, generics_opt }
+    PathType { root_opt, prefix, suffix }
+    PathTypeSegment { name, generics_opt }
+    QualifiedPathRoot { ty, as_trait }
+    StatementLet { let_token, pattern, ty_opt, eq_token, expr, else_opt, semicolon_token }
+    Submodule { mod_token, name, semicolon_token, visibility }
+    WhereClause { where_token, bounds }
+    WhereBound { ty_name, colon_token, bounds }
+    TyArrayDescriptor { ty, semicolon_token, length }
+    ItemAbi { abi_token, name, super_traits, abi_items, abi_defs_opt }
+    ItemConfigurable { configurable_token, fields }
+    ConfigurableField { name, colon_token, ty, eq_token, initializer }
+    ItemConst { visibility, const_token, name, ty_opt, eq_token_opt, expr_opt, semicolon_token }
+    ItemEnum { visibility, enum_token, name, generics, where_clause_opt, fields }
+    ItemFn { fn_signature, body }
+    ItemImpl { impl_token, generic_params_opt, trait_opt, ty, where_clause_opt, contents }
+    ItemStorage { storage_token, fields }
+    StorageField { name, colon_token, ty, eq_token, initializer }
+    ItemStruct { visibility, struct_token, name, generics, where_clause_opt, fields }
+    ItemTrait {
+        visibility,
+        trait_token,
+        name,
+        generics,
+        where_clause_opt,
+        super_traits,
+        trait_items,
+        trait_defs_opt,
+    }
+    Traits { prefix, suffixes }
+    ItemTypeAlias { visibility, name, type_token, eq_token, ty, semicolon_token }
+    ItemUse { visibility, use_token, root_import, tree, semicolon_token }
+    TypeField { name, colon_token, ty, discriminant_opt }
+    FnArg { pattern, colon_token, ty }
+    FnSignature {
+        visibility,
+        fn_token,
+        name,
+        generics,
+        arguments,
+        return_type_opt,
+        where_clause_opt,
+    }
+    TraitType { name, type_token, eq_token_opt, ty_opt, semicolon_token }
+    AsmBlock { asm_token, registers, clobbers_opt, contents }
+    AsmRegisterDeclaration { const_token_opt, register, value_opt }
+    AsmClobbers { clobbers_token, registers }
+    AsmBlockContents { instructions, final_expr_opt }
+    AsmFinalExpr { registers, ty_opt }
+    AsmImmediate { span }
+    ReassignmentOp { span }
+    AbiCastArgs { name, comma_token, address }
+    IfExpr { if_token, condition, then_block, else_opt }
+    MatchBranch { pattern, guard_opt, fat_right_arrow_token, kind }
+    CodeBlockContents { statements, final_expr_opt, span }
+    ExprStructField { field_name, expr_opt }
+    Punct { span }
+    Comment { span }
+    DocComment { span, content_span }
+    CommentedTokenStream { token_trees, full_span }
+}
+
+impl Relocate for LitInt {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.span.relocate(relocator);
+        if let Some((_, span)) = &mut self.ty_opt {
+            span.relocate(relocator);
+        }
+    }
+}
+
+impl Relocate for Literal {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Literal::String(lit) => lit.relocate(relocator),
+            Literal::Char(lit) => lit.relocate(relocator),
+            Literal::Int(lit) => lit.relocate(relocator),
+            Literal::Bool(lit) => lit.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for AttributeHashKind {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            AttributeHashKind::Inner(hash_bang_token) => hash_bang_token.relocate(relocator),
+            AttributeHashKind::Outer(hash_token) => hash_token.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for ModuleKind {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ModuleKind::Script { script_token } => script_token.relocate(relocator),
+            ModuleKind::Contract { contract_token } => contract_token.relocate(relocator),
+            ModuleKind::Predicate { predicate_token } => predicate_token.relocate(relocator),
+            ModuleKind::Library { library_token } => library_token.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for ItemKind {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ItemKind::Submodule(item) => item.relocate(relocator),
+            ItemKind::Use(item) => item.relocate(relocator),
+            ItemKind::Struct(item) => item.relocate(relocator),
+            ItemKind::Enum(item) => item.relocate(relocator),
+            ItemKind::Fn(item) => item.relocate(relocator),
+            ItemKind::Trait(item) => item.relocate(relocator),
+            ItemKind::Impl(item) => item.relocate(relocator),
+            ItemKind::Abi(item) => item.relocate(relocator),
+            ItemKind::Const(item) => item.relocate(relocator),
+            ItemKind::Storage(item) => item.relocate(relocator),
+            ItemKind::Configurable(item) => item.relocate(relocator),
+            ItemKind::TypeAlias(item) => item.relocate(relocator),
+            ItemKind::Error(spans, _) => spans.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for ItemImplItem {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ItemImplItem::Fn(item) => item.relocate(relocator),
+            ItemImplItem::Const(item) => item.relocate(relocator),
+            ItemImplItem::Type(item) => item.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for ItemTraitItem {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ItemTraitItem::Fn(item, semicolon_token_opt) => {
+                item.relocate(relocator);
+                semicolon_token_opt.relocate(relocator);
+            }
+            ItemTraitItem::Const(item, semicolon_token_opt) => {
+                item.relocate(relocator);
+                semicolon_token_opt.relocate(relocator);
+            }
+            ItemTraitItem::Type(item, semicolon_token_opt) => {
+                item.relocate(relocator);
+                semicolon_token_opt.relocate(relocator);
+            }
+            ItemTraitItem::Error(spans, _) => spans.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for UseTree {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            UseTree::Group { imports } => imports.relocate(relocator),
+            UseTree::Name { name } => name.relocate(relocator),
+            UseTree::Rename {
+                name,
+                as_token,
+                alias,
+            } => {
+                name.relocate(relocator);
+                as_token.relocate(relocator);
+                alias.relocate(relocator);
+            }
+            UseTree::Glob { star_token } => star_token.relocate(relocator),
+            UseTree::Path {
+                prefix,
+                double_colon_token,
+                suffix,
+            } => {
+                prefix.relocate(relocator);
+                double_colon_token.relocate(relocator);
+                suffix.relocate(relocator);
+            }
+            UseTree::Error { spans } => spans.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for FnArgs {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            FnArgs::Static(args) => args.relocate(relocator),
+            FnArgs::NonStatic {
+                self_token,
+                ref_self,
+                mutable_self,
+                args_opt,
+            } => {
+                self_token.relocate(relocator);
+                ref_self.relocate(relocator);
+                mutable_self.relocate(relocator);
+                args_opt.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for Ty {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Ty::Path(path_type) => path_type.relocate(relocator),
+            Ty::Tuple(tuple) => tuple.relocate(relocator),
+            Ty::Array(array) => array.relocate(relocator),
+            Ty::StringSlice(str_token) => str_token.relocate(relocator),
+            Ty::StringArray { str_token, length } => {
+                str_token.relocate(relocator);
+                length.relocate(relocator);
+            }
+            Ty::Infer { underscore_token } => underscore_token.relocate(relocator),
+            Ty::Ptr { ptr_token, ty } => {
+                ptr_token.relocate(relocator);
+                ty.relocate(relocator);
+            }
+            Ty::Slice { slice_token, ty } => {
+                slice_token.relocate(relocator);
+                ty.relocate(relocator);
+            }
+            Ty::Ref {
+                ampersand_token,
+                ty,
+            } => {
+                ampersand_token.relocate(relocator);
+                ty.relocate(relocator);
+            }
+            Ty::SliceRef {
+                ampersand_token,
+                ty,
+            } => {
+                ampersand_token.relocate(relocator);
+                ty.relocate(relocator);
+            }
+            Ty::Never { bang_token } => bang_token.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for TyTupleDescriptor {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            TyTupleDescriptor::Nil => {}
+            TyTupleDescriptor::Cons {
+                head,
+                comma_token,
+                tail,
+            } => {
+                head.relocate(relocator);
+                comma_token.relocate(relocator);
+                tail.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for Pattern {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Pattern::Or {
+                lhs,
+                pipe_token,
+                rhs,
+            } => {
+                lhs.relocate(relocator);
+                pipe_token.relocate(relocator);
+                rhs.relocate(relocator);
+            }
+            Pattern::Wildcard { underscore_token } => underscore_token.relocate(relocator),
+            Pattern::AmbiguousSingleIdent(ident) => ident.relocate(relocator),
+            Pattern::Var {
+                reference,
+                mutable,
+                name,
+            } => {
+                reference.relocate(relocator);
+                mutable.relocate(relocator);
+                name.relocate(relocator);
+            }
+            Pattern::Literal(literal) => literal.relocate(relocator),
+            Pattern::Constant(path) => path.relocate(relocator),
+            Pattern::Constructor { path, args } => {
+                path.relocate(relocator);
+                args.relocate(relocator);
+            }
+            Pattern::Struct { path, fields } => {
+                path.relocate(relocator);
+                fields.relocate(relocator);
+            }
+            Pattern::Tuple(patterns) => patterns.relocate(relocator),
+            Pattern::Error(spans, _) => spans.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for PatternStructField {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            PatternStructField::Rest { token } => token.relocate(relocator),
+            PatternStructField::Field {
+                field_name,
+                pattern_opt,
+            } => {
+                field_name.relocate(relocator);
+                pattern_opt.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for Statement {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Statement::Let(statement_let) => statement_let.relocate(relocator),
+            Statement::Item(item) => item.relocate(relocator),
+            Statement::Expr {
+                expr,
+                semicolon_token_opt,
+            } => {
+                expr.relocate(relocator);
+                semicolon_token_opt.relocate(relocator);
+            }
+            Statement::Error(spans, _) => spans.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for AsmFinalRegisters {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            AsmFinalRegisters::Register(register) => register.relocate(relocator),
+            AsmFinalRegisters::Tuple(registers) => registers.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for Assignable {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Assignable::Var(name) => name.relocate(relocator),
+            Assignable::Index { target, arg } => {
+                target.relocate(relocator);
+                arg.relocate(relocator);
+            }
+            Assignable::FieldProjection {
+                target,
+                dot_token,
+                name,
+            } => {
+                target.relocate(relocator);
+                dot_token.relocate(relocator);
+                name.relocate(relocator);
+            }
+            Assignable::TupleFieldProjection {
+                target,
+                dot_token,
+                field: _,
+                field_span,
+            } => {
+                target.relocate(relocator);
+                dot_token.relocate(relocator);
+                field_span.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for IfCondition {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            IfCondition::Expr(expr) => expr.relocate(relocator),
+            IfCondition::Let {
+                let_token,
+                lhs,
+                eq_token,
+                rhs,
+            } => {
+                let_token.relocate(relocator);
+                lhs.relocate(relocator);
+                eq_token.relocate(relocator);
+                rhs.relocate(relocator);
+            }
+            IfCondition::And {
+                lhs,
+                double_ampersand_token,
+                rhs,
+            } => {
+                lhs.relocate(relocator);
+                double_ampersand_token.relocate(relocator);
+                rhs.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for ExprTupleDescriptor {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ExprTupleDescriptor::Nil => {}
+            ExprTupleDescriptor::Cons {
+                head,
+                comma_token,
+                tail,
+            } => {
+                head.relocate(relocator);
+                comma_token.relocate(relocator);
+                tail.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for ExprArrayDescriptor {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            ExprArrayDescriptor::Sequence(elements) => elements.relocate(relocator),
+            ExprArrayDescriptor::Repeat {
+                value,
+                semicolon_token,
+                length,
+            } => {
+                value.relocate(relocator);
+                semicolon_token.relocate(relocator);
+                length.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for MatchBranchKind {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            MatchBranchKind::Block {
+                block,
+                comma_token_opt,
+            } => {
+                block.relocate(relocator);
+                comma_token_opt.relocate(relocator);
+            }
+            MatchBranchKind::Expr { expr, comma_token } => {
+                expr.relocate(relocator);
+                comma_token.relocate(relocator);
+            }
+        }
+    }
+}
+
+impl Relocate for Expr {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            Expr::Error(spans, _) => spans.relocate(relocator),
+            Expr::Path(path) => path.relocate(relocator),
+            Expr::Literal(literal) => literal.relocate(relocator),
+            Expr::AbiCast { abi_token, args } => {
+                abi_token.relocate(relocator);
+                args.relocate(relocator);
+            }
+            Expr::Struct { path, fields } => {
+                path.relocate(relocator);
+                fields.relocate(relocator);
+            }
+            Expr::Tuple(tuple) => tuple.relocate(relocator),
+            Expr::Parens(expr) => expr.relocate(relocator),
+            Expr::Block(block) => block.relocate(relocator),
+            Expr::Array(array) => array.relocate(relocator),
+            Expr::Asm(asm_block) => asm_block.relocate(relocator),
+            Expr::Return {
+                return_token,
+                expr_opt,
+            } => {
+                return_token.relocate(relocator);
+                expr_opt.relocate(relocator);
+            }
+            Expr::If(if_expr) => if_expr.relocate(relocator),
+            Expr::Match {
+                match_token,
+                value,
+                branches,
+            } => {
+                match_token.relocate(relocator);
+                value.relocate(relocator);
+                branches.relocate(relocator);
+            }
+            Expr::While {
+                while_token,
+                condition,
+                block,
+            } => {
+                while_token.relocate(relocator);
+                condition.relocate(relocator);
+                block.relocate(relocator);
+            }
+            Expr::FuncApp { func, args } => {
+                func.relocate(relocator);
+                args.relocate(relocator);
+            }
+            Expr::Index { target, arg } => {
+                target.relocate(relocator);
+                arg.relocate(relocator);
+            }
+            Expr::Range {
+                start,
+                dot_dot_token,
+                end,
+            } => {
+                start.relocate(relocator);
+                dot_dot_token.relocate(relocator);
+                end.relocate(relocator);
+            }
+            Expr::MethodCall {
+                target,
+                dot_token,
+                path_seg,
+                contract_args_opt,
+                args,
+            } => {
+                target.relocate(relocator);
+                dot_token.relocate(relocator);
+                path_seg.relocate(relocator);
+                contract_args_opt.relocate(relocator);
+                args.relocate(relocator);
+            }
+            Expr::FieldProjection {
+                target,
+                dot_token,
+                name,
+            } => {
+                target.relocate(relocator);
+                dot_token.relocate(relocator);
+                name.relocate(relocator);
+            }
+            Expr::TupleFieldProjection {
+                target,
+                dot_token,
+                field: _,
+                field_span,
+            } => {
+                target.relocate(relocator);
+                dot_token.relocate(relocator);
+                field_span.relocate(relocator);
+            }
+            Expr::Ref {
+                ampersand_token,
+                expr,
+            } => {
+                ampersand_token.relocate(relocator);
+                expr.relocate(relocator);
+            }
+            Expr::Deref { star_token, expr } => {
+                star_token.relocate(relocator);
+                expr.relocate(relocator);
+            }
+            Expr::Not { bang_token, expr } => {
+                bang_token.relocate(relocator);
+                expr.relocate(relocator);
+            }
+            Expr::Mul {
+                lhs,
+                star_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, star_token, rhs),
+            Expr::Div {
+                lhs,
+                forward_slash_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, forward_slash_token, rhs),
+            Expr::Pow {
+                lhs,
+                double_star_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, double_star_token, rhs),
+            Expr::Modulo {
+                lhs,
+                percent_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, percent_token, rhs),
+            Expr::Add {
+                lhs,
+                add_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, add_token, rhs),
+            Expr::Sub {
+                lhs,
+                sub_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, sub_token, rhs),
+            Expr::Shl {
+                lhs,
+                shl_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, shl_token, rhs),
+            Expr::Shr {
+                lhs,
+                shr_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, shr_token, rhs),
+            Expr::BitAnd {
+                lhs,
+                ampersand_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, ampersand_token, rhs),
+            Expr::BitXor {
+                lhs,
+                caret_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, caret_token, rhs),
+            Expr::BitOr {
+                lhs,
+                pipe_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, pipe_token, rhs),
+            Expr::Equal {
+                lhs,
+                double_eq_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, double_eq_token, rhs),
+            Expr::NotEqual {
+                lhs,
+                bang_eq_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, bang_eq_token, rhs),
+            Expr::LessThan {
+                lhs,
+                less_than_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, less_than_token, rhs),
+            Expr::GreaterThan {
+                lhs,
+                greater_than_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, greater_than_token, rhs),
+            Expr::LessThanEq {
+                lhs,
+                less_than_eq_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, less_than_eq_token, rhs),
+            Expr::GreaterThanEq {
+                lhs,
+                greater_than_eq_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, greater_than_eq_token, rhs),
+            Expr::LogicalAnd {
+                lhs,
+                double_ampersand_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, double_ampersand_token, rhs),
+            Expr::LogicalOr {
+                lhs,
+                double_pipe_token,
+                rhs,
+            } => relocate_binary_op(relocator, lhs, double_pipe_token, rhs),
+            Expr::Reassignment {
+                assignable,
+                reassignment_op,
+                expr,
+            } => {
+                assignable.relocate(relocator);
+                reassignment_op.relocate(relocator);
+                expr.relocate(relocator);
+            }
+            Expr::Break { break_token } => break_token.relocate(relocator),
+            Expr::Continue { continue_token } => continue_token.relocate(relocator),
+        }
+    }
+}
+
+fn relocate_binary_op(
+    relocator: &Relocator,
+    lhs: &mut Expr,
+    op_token: &mut impl Relocate,
+    rhs: &mut Expr,
+) {
+    lhs.relocate(relocator);
+    op_token.relocate(relocator);
+    rhs.relocate(relocator);
+}
+
+impl<T: Relocate> Relocate for GenericGroup<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        self.token_stream.relocate(relocator);
+        self.span.relocate(relocator);
+    }
+}
+
+impl<T: Relocate> Relocate for GenericTokenTree<T> {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            GenericTokenTree::Punct(punct) => punct.relocate(relocator),
+            GenericTokenTree::Ident(ident) => ident.relocate(relocator),
+            GenericTokenTree::Group(group) => group.relocate(relocator),
+            GenericTokenTree::Literal(literal) => literal.relocate(relocator),
+            GenericTokenTree::DocComment(doc_comment) => doc_comment.relocate(relocator),
+        }
+    }
+}
+
+impl Relocate for CommentedTokenTree {
+    fn relocate(&mut self, relocator: &Relocator) {
+        match self {
+            CommentedTokenTree::Comment(comment) => comment.relocate(relocator),
+            CommentedTokenTree::Tree(tree) => tree.relocate(relocator),
+        }
+    }
+}
@@ -22,6 +22,8 @@ pub struct StatementLet {
     pub ty_opt: Option<(ColonToken, Ty)>,
     pub eq_token: EqToken,
     pub expr: Expr,
+    /// The block evaluated when the pattern is refutable and doesn't match, in `let .. else`.
+    pub else_opt: Option<(ElseToken, Braces<CodeBlockContents>)>,
     pub semicolon_token: SemicolonToken,
 }
 
@@ -334,3 +334,144 @@ impl Spanned for CommentedTokenStream {
         self.full_span.clone()
     }
 }
+
+/// Whitespace or a comment, which the parser ignores but which is needed to reproduce the
+/// source exactly.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum Trivia {
+    Whitespace(Span),
+    Comment(Comment),
+}
+
+impl Spanned for Trivia {
+    fn span(&self) -> Span {
+        match self {
+            Self::Whitespace(span) => span.clone(),
+            Self::Comment(comment) => comment.span(),
+        }
+    }
+}
+
+/// A token tree along with the trivia preceding it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct LosslessTokenTree {
+    pub leading_trivia: Vec<Trivia>,
+    pub tree: GenericTokenTree<LosslessTokenStream>,
+}
+
+/// A token stream retaining all of the whitespace and comments of the source, so that
+/// [LosslessTokenStream::to_source] reproduces it exactly.
+///
+/// Tools editing the source can replace the trees of the stream, and the whitespace and comments
+/// around the replaced trees are kept as they are.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct LosslessTokenStream {
+    pub token_trees: Vec<LosslessTokenTree>,
+    /// The trivia after the last tree of the stream, like the whitespace before the closing
+    /// delimiter of a group.
+    pub trailing_trivia: Vec<Trivia>,
+    pub full_span: Span,
+}
+
+pub type LosslessGroup = GenericGroup<LosslessTokenStream>;
+
+impl LosslessTokenStream {
+    /// Returns the source of the token stream, including all of its whitespace and comments.
+    ///
+    /// The source of a group is always closed with its delimiter, so the source of a stream
+    /// lexed with unclosed delimiters is not reproduced exactly.
+    pub fn to_source(&self) -> String {
+        let mut source = String::with_capacity(self.full_span.as_str().len());
+        self.write_source(&mut source);
+        source
+    }
+
+    fn write_source(&self, source: &mut String) {
+        for token_tree in self.token_trees.iter() {
+            write_trivia(source, &token_tree.leading_trivia);
+            match &token_tree.tree {
+                GenericTokenTree::Group(group) => {
+                    source.push(group.delimiter.as_open_char());
+                    group.token_stream.write_source(source);
+                    source.push(group.delimiter.as_close_char());
+                }
+                GenericTokenTree::Ident(ident) => {
+                    if ident.is_raw_ident() {
+                        source.push_str("r#");
+                    }
+                    source.push_str(ident.as_str());
+                }
+                tree => source.push_str(tree.span().as_str()),
+            }
+        }
+        write_trivia(source, &self.trailing_trivia);
+    }
+}
+
+fn write_trivia(source: &mut String, trivia: &[Trivia]) {
+    for trivia in trivia.iter() {
+        source.push_str(trivia.span().as_str());
+    }
+}
+
+impl Spanned for LosslessTokenStream {
+    fn span(&self) -> Span {
+        self.full_span.clone()
+    }
+}
+
+impl From<CommentedTokenStream> for LosslessTokenStream {
+    /// Attaches the whitespace between the trees and the comments of the stream to the trees
+    /// following them.
+    fn from(stream: CommentedTokenStream) -> Self {
+        let src = stream.full_span.src().clone();
+        let source_id = stream.full_span.source_id().cloned();
+        let whitespace = |start: usize, end: usize| {
+            (start < end).then(|| {
+                Trivia::Whitespace(
+                    Span::new(src.clone(), start, end, source_id)
+                        .expect("whitespace is within the source"),
+                )
+            })
+        };
+
+        let mut token_trees = vec![];
+        let mut trivia = vec![];
+        let mut position = stream.full_span.start();
+        for commented_tree in stream.token_trees {
+            let span = commented_tree.span();
+            trivia.extend(whitespace(position, span.start()));
+            position = span.end();
+            let tree = match commented_tree {
+                CommentedTokenTree::Comment(comment) => {
+                    trivia.push(Trivia::Comment(comment));
+                    continue;
+                }
+                CommentedTokenTree::Tree(tree) => tree,
+            };
+            let tree = match tree {
+                GenericTokenTree::Punct(punct) => punct.into(),
+                GenericTokenTree::Ident(ident) => ident.into(),
+                GenericTokenTree::Group(group) => LosslessGroup {
+                    delimiter: group.delimiter,
+                    token_stream: group.token_stream.into(),
+                    span: group.span,
+                }
+                .into(),
+                GenericTokenTree::Literal(literal) => literal.into(),
+                GenericTokenTree::DocComment(doc_comment) => doc_comment.into(),
+            };
+            token_trees.push(LosslessTokenTree {
+                leading_trivia: std::mem::take(&mut trivia),
+                tree,
+            });
+        }
+        trivia.extend(whitespace(position, stream.full_span.end()));
+
+        Self {
+            token_trees,
+            trailing_trivia: trivia,
+            full_span: stream.full_span,
+        }
+    }
+}
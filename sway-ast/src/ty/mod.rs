@@ -26,6 +26,13 @@ pub enum Ty {
         ampersand_token: AmpersandToken,
         ty: Box<Ty>,
     },
+    SliceRef {
+        ampersand_token: AmpersandToken,
+        ty: SquareBrackets<Box<Ty>>,
+    },
+    Never {
+        bang_token: BangToken,
+    },
 }
 
 impl Spanned for Ty {
@@ -43,6 +50,11 @@ impl Spanned for Ty {
                 ampersand_token,
                 ty,
             } => Span::join(ampersand_token.span(), ty.span()),
+            Ty::SliceRef {
+                ampersand_token,
+                ty,
+            } => Span::join(ampersand_token.span(), ty.span()),
+            Ty::Never { bang_token } => bang_token.span(),
         }
     }
 }
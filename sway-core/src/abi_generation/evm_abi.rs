@@ -97,6 +97,7 @@ pub fn abi_str(type_info: &TypeInfo, type_engine: &TypeEngine, decl_engine: &Dec
         }
         .into(),
         Boolean => "bool".into(),
+        Never => "!".into(),
         Custom {
             qualified_call_path: call_path,
             ..
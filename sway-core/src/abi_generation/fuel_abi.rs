@@ -1,4 +1,5 @@
 use fuel_abi_types::abi::program as program_abi;
+use std::collections::BTreeMap;
 use sway_types::integer_bits::IntegerBits;
 
 use crate::{
@@ -14,6 +15,10 @@ use crate::{
 pub struct AbiContext<'a> {
     pub program: &'a TyProgram,
     pub abi_with_callpaths: bool,
+    /// The discriminants of the variants of the enums in the ABI that declare explicit
+    /// discriminants, keyed by the name of their type declaration, e.g. `enum Status`.
+    /// Filled in while generating the ABI.
+    pub enum_discriminants: BTreeMap<String, Vec<u64>>,
 }
 
 pub fn generate_program_abi(
@@ -84,7 +89,7 @@ fn generate_logged_types(
         .logged_types
         .iter()
         .map(|(_, type_id)| program_abi::TypeDeclaration {
-            type_id: type_id.index(),
+            type_id: type_engine.dense_index(*type_id),
             type_field: type_id.get_abi_type_str(ctx, type_engine, decl_engine, *type_id),
             components: type_id.get_abi_type_components(
                 ctx,
@@ -114,7 +119,7 @@ fn generate_logged_types(
             log_id: **log_id as u64,
             application: program_abi::TypeApplication {
                 name: "".to_string(),
-                type_id: type_id.index(),
+                type_id: type_engine.dense_index(*type_id),
                 type_arguments: type_id.get_abi_type_arguments(
                     ctx,
                     type_engine,
@@ -139,7 +144,7 @@ fn generate_messages_types(
         .messages_types
         .iter()
         .map(|(_, type_id)| program_abi::TypeDeclaration {
-            type_id: type_id.index(),
+            type_id: type_engine.dense_index(*type_id),
             type_field: type_id.get_abi_type_str(ctx, type_engine, decl_engine, *type_id),
             components: type_id.get_abi_type_components(
                 ctx,
@@ -169,7 +174,7 @@ fn generate_messages_types(
             message_id: **message_id as u64,
             application: program_abi::TypeApplication {
                 name: "".to_string(),
-                type_id: type_id.index(),
+                type_id: type_engine.dense_index(*type_id),
                 type_arguments: type_id.get_abi_type_arguments(
                     ctx,
                     type_engine,
@@ -197,7 +202,7 @@ fn generate_configurables(
             |TyConstantDecl {
                  type_ascription, ..
              }| program_abi::TypeDeclaration {
-                type_id: type_ascription.type_id.index(),
+                type_id: type_engine.dense_index(type_ascription.type_id),
                 type_field: type_ascription.type_id.get_abi_type_str(
                     ctx,
                     type_engine,
@@ -238,7 +243,7 @@ fn generate_configurables(
                 name: call_path.suffix.to_string(),
                 application: program_abi::TypeApplication {
                     name: "".to_string(),
-                    type_id: type_ascription.type_id.index(),
+                    type_id: type_engine.dense_index(type_ascription.type_id),
                     type_arguments: type_ascription.type_id.get_abi_type_arguments(
                         ctx,
                         type_engine,
@@ -354,7 +359,7 @@ impl TypeId {
                     .variants
                     .iter()
                     .map(|x| program_abi::TypeDeclaration {
-                        type_id: x.type_argument.initial_type_id.index(),
+                        type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                         type_field: x.type_argument.initial_type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -379,6 +384,23 @@ impl TypeId {
                     .collect::<Vec<_>>();
                 types.extend(variants);
 
+                // The discriminants of enums that only use the default ones are left out, so that
+                // their ABI stays the same.
+                if decl
+                    .variants
+                    .iter()
+                    .enumerate()
+                    .any(|(index, variant)| variant.discriminant != index as u64)
+                {
+                    let type_field = format!("enum {}", call_path_display(ctx, &decl.call_path));
+                    let discriminants = decl
+                        .variants
+                        .iter()
+                        .map(|variant| variant.discriminant)
+                        .collect();
+                    ctx.enum_discriminants.insert(type_field, discriminants);
+                }
+
                 // Generate the JSON data for the enum. This is basically a list of
                 // `program_abi::TypeApplication`s
                 Some(
@@ -386,7 +408,7 @@ impl TypeId {
                         .iter()
                         .map(|x| program_abi::TypeApplication {
                             name: x.name.to_string(),
-                            type_id: x.type_argument.initial_type_id.index(),
+                            type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                             type_arguments: x.type_argument.initial_type_id.get_abi_type_arguments(
                                 ctx,
                                 type_engine,
@@ -406,7 +428,7 @@ impl TypeId {
                     .fields
                     .iter()
                     .map(|x| program_abi::TypeDeclaration {
-                        type_id: x.type_argument.initial_type_id.index(),
+                        type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                         type_field: x.type_argument.initial_type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -438,7 +460,7 @@ impl TypeId {
                         .iter()
                         .map(|x| program_abi::TypeApplication {
                             name: x.name.to_string(),
-                            type_id: x.type_argument.initial_type_id.index(),
+                            type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                             type_arguments: x.type_argument.initial_type_id.get_abi_type_arguments(
                                 ctx,
                                 type_engine,
@@ -454,7 +476,7 @@ impl TypeId {
                 if let TypeInfo::Array(elem_ty, _) = &*type_engine.get(resolved_type_id) {
                     // The `program_abi::TypeDeclaration`s needed for the array element type
                     let elem_abi_ty = program_abi::TypeDeclaration {
-                        type_id: elem_ty.initial_type_id.index(),
+                        type_id: type_engine.dense_index(elem_ty.initial_type_id),
                         type_field: elem_ty.initial_type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -482,7 +504,7 @@ impl TypeId {
                     // `program_abi::TypeApplication` for the array element type
                     Some(vec![program_abi::TypeApplication {
                         name: "__array_element".to_string(),
-                        type_id: elem_ty.initial_type_id.index(),
+                        type_id: type_engine.dense_index(elem_ty.initial_type_id),
                         type_arguments: elem_ty.initial_type_id.get_abi_type_arguments(
                             ctx,
                             type_engine,
@@ -501,7 +523,7 @@ impl TypeId {
                     let fields_types = fields
                         .iter()
                         .map(|x| program_abi::TypeDeclaration {
-                            type_id: x.initial_type_id.index(),
+                            type_id: type_engine.dense_index(x.initial_type_id),
                             type_field: x.initial_type_id.get_abi_type_str(
                                 ctx,
                                 type_engine,
@@ -533,7 +555,7 @@ impl TypeId {
                             .iter()
                             .map(|x| program_abi::TypeApplication {
                                 name: "__tuple_element".to_string(),
-                                type_id: x.initial_type_id.index(),
+                                type_id: type_engine.dense_index(x.initial_type_id),
                                 type_arguments: x.initial_type_id.get_abi_type_arguments(
                                     ctx,
                                     type_engine,
@@ -562,7 +584,7 @@ impl TypeId {
                                 .iter(),
                         )
                         .map(|(v, p)| program_abi::TypeDeclaration {
-                            type_id: v.initial_type_id.index(),
+                            type_id: type_engine.dense_index(v.initial_type_id),
                             type_field: v.initial_type_id.get_abi_type_str(
                                 ctx,
                                 type_engine,
@@ -639,7 +661,7 @@ impl TypeId {
                     .iter()
                     .zip(resolved_params.iter())
                     .map(|(v, p)| program_abi::TypeDeclaration {
-                        type_id: v.initial_type_id.index(),
+                        type_id: type_engine.dense_index(v.initial_type_id),
                         type_field: v.initial_type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -668,7 +690,7 @@ impl TypeId {
                     .iter()
                     .map(|arg| program_abi::TypeApplication {
                         name: "".to_string(),
-                        type_id: arg.initial_type_id.index(),
+                        type_id: type_engine.dense_index(arg.initial_type_id),
                         type_arguments: arg.initial_type_id.get_abi_type_arguments(
                             ctx,
                             type_engine,
@@ -686,7 +708,7 @@ impl TypeId {
                     .type_parameters
                     .iter()
                     .map(|v| program_abi::TypeDeclaration {
-                        type_id: v.type_id.index(),
+                        type_id: type_engine.dense_index(v.type_id),
                         type_field: v.type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -716,7 +738,7 @@ impl TypeId {
                         .iter()
                         .map(|arg| program_abi::TypeApplication {
                             name: "".to_string(),
-                            type_id: arg.type_id.index(),
+                            type_id: type_engine.dense_index(arg.type_id),
                             type_arguments: arg.type_id.get_abi_type_arguments(
                                 ctx,
                                 type_engine,
@@ -736,7 +758,7 @@ impl TypeId {
                     .type_parameters
                     .iter()
                     .map(|v| program_abi::TypeDeclaration {
-                        type_id: v.type_id.index(),
+                        type_id: type_engine.dense_index(v.type_id),
                         type_field: v.type_id.get_abi_type_str(
                             ctx,
                             type_engine,
@@ -766,7 +788,7 @@ impl TypeId {
                         .iter()
                         .map(|arg| program_abi::TypeApplication {
                             name: "".to_string(),
-                            type_id: arg.type_id.index(),
+                            type_id: type_engine.dense_index(arg.type_id),
                             type_arguments: arg.type_id.get_abi_type_arguments(
                                 ctx,
                                 type_engine,
@@ -807,6 +829,7 @@ impl TypeInfo {
             }
             .into(),
             Boolean => "bool".into(),
+            Never => "!".into(),
             Custom {
                 qualified_call_path: call_path,
                 ..
@@ -900,7 +923,7 @@ impl TyFunctionDecl {
             .parameters
             .iter()
             .map(|x| program_abi::TypeDeclaration {
-                type_id: x.type_argument.initial_type_id.index(),
+                type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                 type_field: x.type_argument.initial_type_id.get_abi_type_str(
                     ctx,
                     type_engine,
@@ -926,7 +949,7 @@ impl TyFunctionDecl {
 
         // The single `program_abi::TypeDeclaration` needed for the output
         let output_type = program_abi::TypeDeclaration {
-            type_id: self.return_type.initial_type_id.index(),
+            type_id: type_engine.dense_index(self.return_type.initial_type_id),
             type_field: self.return_type.initial_type_id.get_abi_type_str(
                 ctx,
                 type_engine,
@@ -961,7 +984,7 @@ impl TyFunctionDecl {
                 .iter()
                 .map(|x| program_abi::TypeApplication {
                     name: x.name.to_string(),
-                    type_id: x.type_argument.initial_type_id.index(),
+                    type_id: type_engine.dense_index(x.type_argument.initial_type_id),
                     type_arguments: x.type_argument.initial_type_id.get_abi_type_arguments(
                         ctx,
                         type_engine,
@@ -973,7 +996,7 @@ impl TyFunctionDecl {
                 .collect(),
             output: program_abi::TypeApplication {
                 name: "".to_string(),
-                type_id: self.return_type.initial_type_id.index(),
+                type_id: type_engine.dense_index(self.return_type.initial_type_id),
                 type_arguments: self.return_type.initial_type_id.get_abi_type_arguments(
                     ctx,
                     type_engine,
@@ -1016,7 +1039,7 @@ impl TypeParameter {
         types: &mut Vec<program_abi::TypeDeclaration>,
     ) -> usize {
         let type_parameter = program_abi::TypeDeclaration {
-            type_id: self.initial_type_id.index(),
+            type_id: type_engine.dense_index(self.initial_type_id),
             type_field: self.initial_type_id.get_abi_type_str(
                 ctx,
                 type_engine,
@@ -1033,7 +1056,7 @@ impl TypeParameter {
             type_parameters: None,
         };
         types.push(type_parameter);
-        self.initial_type_id.index()
+        type_engine.dense_index(self.initial_type_id)
     }
 }
 
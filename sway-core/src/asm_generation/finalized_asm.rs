@@ -11,6 +11,7 @@ use crate::source_map::SourceMap;
 use etk_asm::asm::Assembler;
 use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
+use sway_ir::EliminatedFunctions;
 use sway_types::span::Span;
 use sway_types::SourceEngine;
 
@@ -25,7 +26,11 @@ pub struct FinalizedAsm {
     pub program_section: InstructionSet,
     pub program_kind: ProgramKind,
     pub entries: Vec<FinalizedEntry>,
+    pub functions: Vec<FinalizedFunction>,
     pub abi: Option<ProgramABI>,
+    /// The functions dropped from the IR before codegen for not being reachable from the entry
+    /// points.
+    pub eliminated_functions: EliminatedFunctions,
 }
 
 #[derive(Clone, Debug)]
@@ -39,6 +44,39 @@ pub struct FinalizedEntry {
     /// If this entry is constructed from a test function contains the declaration id for that
     /// function, otherwise contains `None`.
     pub test_decl_ref: Option<DeclRefFunction>,
+    /// A static estimate of the gas used by a call to the entry function.
+    pub gas_estimate: GasEstimate,
+}
+
+/// A static estimate of the gas used by a function, see `forc build --gas-report`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasEstimate {
+    pub gas: u64,
+    /// Whether the function never uses more gas than estimated.  Otherwise the estimate is only
+    /// an approximation, as the function contains loops, recursion or operations whose cost
+    /// depends on the size of their operands.
+    pub is_upper_bound: bool,
+}
+
+/// A function of the program, whose bytecode follows that of the previous function.
+#[derive(Clone, Debug)]
+pub struct FinalizedFunction {
+    /// The name of the function in the IR, which is unique to each monomorphized instance.
+    pub name: String,
+    /// The immediate instruction offset at which the function begins.
+    pub imm: u64,
+    /// The span of the function declaration, if known.
+    pub span: Option<Span>,
+}
+
+/// The number of bytes of the bytecode compiled from a function.
+#[derive(Clone, Debug)]
+pub struct FunctionSize {
+    /// The name of the function in the IR, which is unique to each monomorphized instance.
+    pub name: String,
+    /// The span of the function declaration, if known.
+    pub span: Option<Span>,
+    pub size_in_bytes: usize,
 }
 
 /// The bytecode for a sway program as well as the byte offsets of configuration-time constants in
@@ -46,6 +84,12 @@ pub struct FinalizedEntry {
 pub struct CompiledBytecode {
     pub bytecode: Vec<u8>,
     pub config_const_offsets: BTreeMap<String, u64>,
+    /// The size in bytes of the data section at the end of the bytecode.
+    pub data_section_size: usize,
+    /// The size in bytes of the bytecode of each function, in the order of the bytecode. The
+    /// bytes before the first function are those of the prologue, i.e. the preamble and, for
+    /// contracts, the dispatch on the function selector.
+    pub function_sizes: Vec<FunctionSize>,
 }
 
 impl FinalizedAsm {
@@ -59,6 +103,7 @@ impl FinalizedAsm {
             InstructionSet::Fuel { ops } => to_bytecode_mut(
                 handler,
                 ops,
+                &self.functions,
                 &mut self.data_section,
                 source_map,
                 source_engine,
@@ -71,12 +116,16 @@ impl FinalizedAsm {
                     Ok(CompiledBytecode {
                         bytecode: assembler.take(),
                         config_const_offsets: BTreeMap::new(),
+                        data_section_size: 0,
+                        function_sizes: vec![],
                     })
                 }
             }
             InstructionSet::MidenVM { ops } => Ok(CompiledBytecode {
                 bytecode: ops.to_bytecode().into(),
                 config_const_offsets: Default::default(),
+                data_section_size: 0,
+                function_sizes: vec![],
             }),
         }
     }
@@ -100,6 +149,7 @@ impl fmt::Display for FinalizedAsm {
 fn to_bytecode_mut(
     handler: &Handler,
     ops: &Vec<AllocatedOp>,
+    functions: &[FinalizedFunction],
     data_section: &mut DataSection,
     source_map: &mut SourceMap,
     source_engine: &SourceEngine,
@@ -160,6 +210,25 @@ fn to_bytecode_mut(
         }
     }
 
+    // Each function spans the bytecode up to the start of the next one, or of the data section.
+    let program_size = half_word_ix * 4;
+    let mut functions = functions.iter().collect::<Vec<_>>();
+    functions.sort_by_key(|function| function.imm);
+    let function_ends = functions
+        .iter()
+        .skip(1)
+        .map(|function| function.imm as usize * 4)
+        .chain(std::iter::once(program_size));
+    let function_sizes = functions
+        .iter()
+        .zip(function_ends)
+        .map(|(function, end)| FunctionSize {
+            name: function.name.clone(),
+            span: function.span.clone(),
+            size_in_bytes: end - function.imm as usize * 4,
+        })
+        .collect();
+
     let config_offsets = data_section
         .config_map
         .iter()
@@ -172,12 +241,15 @@ fn to_bytecode_mut(
         .collect::<BTreeMap<String, u64>>();
 
     let mut data_section = data_section.serialize_to_bytes();
+    let data_section_size = data_section.len();
 
     buf.append(&mut data_section);
 
     Ok(CompiledBytecode {
         bytecode: buf,
         config_const_offsets: config_offsets,
+        data_section_size,
+        function_sizes,
     })
 }
 
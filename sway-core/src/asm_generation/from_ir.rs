@@ -11,7 +11,7 @@ use super::{
     MidenVMAsmBuilder,
 };
 
-use crate::{BuildConfig, BuildTarget};
+use crate::{metadata::MetadataManager, BuildConfig, BuildTarget, OptLevel};
 
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::*;
@@ -39,7 +39,10 @@ pub fn compile_ir_to_asm(
         println!("{final_program}");
     }
 
-    let final_asm = final_program.finalize();
+    let final_asm = FinalizedAsm {
+        eliminated_functions: ir.eliminated_functions,
+        ..final_program.finalize()
+    };
 
     check_invalid_opcodes(handler, &final_asm)?;
 
@@ -78,8 +81,12 @@ fn compile_module_to_asm(
 
     // Pre-create labels for all functions before we generate other code, so we can call them
     // before compiling them if needed.
+    let mut md_mgr = MetadataManager::default();
+    let mut function_labels = Vec::new();
     for func in module.function_iter(context) {
-        builder.func_to_labels(&func);
+        let (start_label, _) = builder.func_to_labels(&func);
+        let span = md_mgr.md_to_span(context, func.get_metadata(context));
+        function_labels.push((start_label, func.get_name(context).to_string(), span));
     }
 
     for function in module.function_iter(context) {
@@ -106,8 +113,14 @@ fn compile_module_to_asm(
                 })
                 .collect();
 
-            let abstract_program =
-                AbstractProgram::new(kind, data_section, entries, non_entries, reg_seqr);
+            let abstract_program = AbstractProgram::new(
+                kind,
+                data_section,
+                entries,
+                non_entries,
+                function_labels,
+                reg_seqr,
+            );
 
             if build_config
                 .map(|cfg| cfg.print_intermediate_asm)
@@ -117,10 +130,20 @@ fn compile_module_to_asm(
                 println!("{abstract_program}\n");
             }
 
-            let allocated_program = abstract_program
+            let mut allocated_program = abstract_program
                 .into_allocated_program()
                 .map_err(|e| handler.emit_err(e))?;
 
+            // The peephole optimizations only run when optimizing, so that debug builds keep the
+            // instructions as they were generated.
+            if build_config.is_some_and(|cfg| cfg.optimization_level != OptLevel::Opt0) {
+                let peephole_stats = allocated_program.peephole_optimize();
+                if build_config.map(|cfg| cfg.print_asm_stats).unwrap_or(false) {
+                    println!(";; --- ASM STATS ---\n");
+                    println!("{peephole_stats}\n");
+                }
+            }
+
             if build_config
                 .map(|cfg| cfg.print_intermediate_asm)
                 .unwrap_or(false)
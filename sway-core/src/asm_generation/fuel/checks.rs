@@ -102,6 +102,7 @@ pub(crate) fn check_predicate_opcodes(
                 CCP(..) => invalid_opcode("CCP"),
                 CROO(..) => invalid_opcode("CROO"),
                 CSIZ(..) => invalid_opcode("CSIZ"),
+                ECAL(..) => invalid_opcode("ECAL"),
                 GM(_, VirtualImmediate18 { value: 1..=2 }) => {
                     handler.emit_err(CompileError::GMFromExternalContext {
                         span: get_op_span(op),
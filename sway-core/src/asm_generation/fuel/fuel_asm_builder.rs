@@ -28,7 +28,7 @@ use sway_ir::*;
 use sway_types::{span::Span, Spanned};
 
 use either::Either;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct FuelAsmBuilder<'ir, 'eng> {
     pub(super) program_kind: ProgramKind,
@@ -348,6 +348,30 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         asm: &AsmBlock,
         asm_args: &[AsmArg],
     ) -> Result<(), ErrorEmitted> {
+        // Initialized registers can only be written to if the block declares them as clobbered,
+        // which the type checker makes sure of. Those registers get a copy of their initial
+        // values, so that the values themselves are left intact for the rest of the function.
+        let written_registers = asm
+            .body
+            .iter()
+            .filter_map(|op| {
+                let args = op
+                    .args
+                    .iter()
+                    .map(|arg| VirtualRegister::Virtual(arg.to_string()))
+                    .collect::<Vec<_>>();
+                Op::parse_opcode(
+                    &Handler::default(),
+                    &op.op_name,
+                    &args,
+                    &op.immediate,
+                    Span::dummy(),
+                )
+                .ok()
+            })
+            .flat_map(|op| op.def_registers().into_iter().cloned().collect::<Vec<_>>())
+            .collect::<HashSet<_>>();
+
         let mut inline_reg_map = HashMap::new();
         let mut inline_ops = Vec::new();
         for AsmArg { name, initializer } in asm_args {
@@ -368,7 +392,21 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                             return Err(handler.emit_err(e));
                         }
                     };
+                    let is_clobbered = written_registers
+                        .contains(&VirtualRegister::Virtual(name.as_str().to_string()));
                     match init_val_reg {
+                        VirtualRegister::Virtual(_) if is_clobbered => {
+                            let init_copy = self.reg_seqr.next();
+                            inline_ops.push(Op {
+                                opcode: Either::Left(VirtualOp::MOVE(
+                                    init_copy.clone(),
+                                    init_val_reg,
+                                )),
+                                comment: "copy clobbered asm init".into(),
+                                owning_span: self.md_mgr.val_to_span(self.context, *instr_val),
+                            });
+                            init_copy
+                        }
                         VirtualRegister::Virtual(_) => init_val_reg,
                         VirtualRegister::Constant(_) => {
                             let const_copy = self.reg_seqr.next();
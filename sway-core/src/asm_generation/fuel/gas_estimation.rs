@@ -0,0 +1,272 @@
+//! Static estimates of the gas used by the entry functions of a program.
+//!
+//! The estimate of a function is the cost, per the gas cost table of the VM, of the most expensive
+//! path through it, including the functions it calls.  Loops are counted once, recursive calls
+//! are not counted, and the operations whose cost depends on the size of their operands, like
+//! memory copies or calls to other contracts, are counted at their base cost.  The estimate is
+//! only an approximation when any of these are involved.
+
+use either::Either;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    asm_lang::{allocated_ops::AllocatedOpcode, AllocatedAbstractOp, ControlFlowOp, Label},
+    fuel_prelude::fuel_tx::{DependentCost, GasCosts},
+    GasEstimate,
+};
+
+use super::allocated_abstract_instruction_set::AllocatedAbstractInstructionSet;
+
+/// Estimates the gas used by the functions of a program, memoizing the estimates of the functions
+/// called by several others.
+pub(crate) struct GasEstimator<'a> {
+    costs: GasCosts,
+    functions: &'a [AllocatedAbstractInstructionSet],
+    function_indices: FxHashMap<Label, usize>,
+    estimates: FxHashMap<usize, GasEstimate>,
+    in_progress: FxHashSet<usize>,
+}
+
+impl<'a> GasEstimator<'a> {
+    pub(crate) fn new(functions: &'a [AllocatedAbstractInstructionSet]) -> Self {
+        // Every function starts with its label.
+        let function_indices = functions
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, function)| match function.ops.first()?.opcode {
+                Either::Right(ControlFlowOp::Label(label)) => Some((label, idx)),
+                _ => None,
+            })
+            .collect();
+        GasEstimator {
+            costs: GasCosts::default(),
+            functions,
+            function_indices,
+            estimates: FxHashMap::default(),
+            in_progress: FxHashSet::default(),
+        }
+    }
+
+    /// Estimates the gas used by the function starting at `label`, if there is one.
+    pub(crate) fn estimate(&mut self, label: &Label) -> Option<GasEstimate> {
+        let idx = *self.function_indices.get(label)?;
+        Some(self.estimate_function(idx))
+    }
+
+    fn estimate_function(&mut self, idx: usize) -> GasEstimate {
+        if let Some(estimate) = self.estimates.get(&idx) {
+            return *estimate;
+        }
+        if !self.in_progress.insert(idx) {
+            // A recursive call, whose depth is unknown.
+            return GasEstimate {
+                gas: 0,
+                is_upper_bound: false,
+            };
+        }
+
+        let functions = self.functions;
+        let ops = &functions[idx].ops;
+        let label_indices: FxHashMap<Label, usize> = ops
+            .iter()
+            .enumerate()
+            .filter_map(|(op_idx, op)| match op.opcode {
+                Either::Right(ControlFlowOp::Label(label)) => Some((label, op_idx)),
+                _ => None,
+            })
+            .collect();
+
+        // The most expensive path from each op to the end of the function.  Jumps only go forward
+        // but for loops, so the paths are computed backwards, ignoring the jumps back.
+        let mut is_upper_bound = true;
+        let mut path_costs = vec![0; ops.len() + 1];
+        for op_idx in (0..ops.len()).rev() {
+            let next = path_costs[op_idx + 1];
+            let mut target = |label: &Label| match label_indices.get(label) {
+                Some(&target_idx) if target_idx > op_idx => path_costs[target_idx],
+                _ => {
+                    is_upper_bound = false;
+                    0
+                }
+            };
+            let (cost, is_exact) = self.op_cost(&ops[op_idx]);
+            is_upper_bound &= is_exact;
+            let successors = match &ops[op_idx].opcode {
+                Either::Right(ControlFlowOp::Jump(label)) => target(label),
+                Either::Right(ControlFlowOp::JumpIfNotZero(_, label)) => target(label).max(next),
+                Either::Left(
+                    AllocatedOpcode::JMP(..)
+                    | AllocatedOpcode::RET(..)
+                    | AllocatedOpcode::RETD(..)
+                    | AllocatedOpcode::RVRT(..),
+                ) => 0,
+                Either::Left(
+                    AllocatedOpcode::JI(..)
+                    | AllocatedOpcode::JNE(..)
+                    | AllocatedOpcode::JNEI(..)
+                    | AllocatedOpcode::JNZI(..)
+                    | AllocatedOpcode::JMPB(..)
+                    | AllocatedOpcode::JMPF(..)
+                    | AllocatedOpcode::JNZB(..)
+                    | AllocatedOpcode::JNZF(..),
+                ) => {
+                    // Jumps in `asm` blocks, whose targets are not followed.
+                    is_upper_bound = false;
+                    next
+                }
+                _ => next,
+            };
+            path_costs[op_idx] = cost + successors;
+        }
+
+        // The costs of the functions called along the way are added separately, as the most
+        // expensive path through each function doesn't depend on its callers.
+        let callees: Vec<usize> = ops
+            .iter()
+            .filter_map(|op| match &op.opcode {
+                Either::Right(ControlFlowOp::Call(label)) => self.function_indices.get(label),
+                _ => None,
+            })
+            .copied()
+            .collect();
+        let mut gas = path_costs[0];
+        for callee in callees {
+            let callee_estimate = self.estimate_function(callee);
+            gas += callee_estimate.gas;
+            is_upper_bound &= callee_estimate.is_upper_bound;
+        }
+
+        self.in_progress.remove(&idx);
+        let estimate = GasEstimate {
+            gas,
+            is_upper_bound,
+        };
+        self.estimates.insert(idx, estimate);
+        estimate
+    }
+
+    /// The cost of an op, and whether it is exact rather than the base cost of an op whose cost
+    /// depends on the size of its operands.
+    fn op_cost(&self, op: &AllocatedAbstractOp) -> (u64, bool) {
+        let costs = &self.costs;
+        let dependent = |cost: &DependentCost| (cost.base(), false);
+        match &op.opcode {
+            Either::Left(opcode) => {
+                use AllocatedOpcode::*;
+                let cost = match opcode {
+                    ADD(..) => costs.add,
+                    ADDI(..) => costs.addi,
+                    AND(..) => costs.and,
+                    ANDI(..) => costs.andi,
+                    DIV(..) => costs.div,
+                    DIVI(..) => costs.divi,
+                    EQ(..) => costs.eq,
+                    EXP(..) => costs.exp,
+                    EXPI(..) => costs.expi,
+                    GT(..) => costs.gt,
+                    LT(..) => costs.lt,
+                    MLOG(..) => costs.mlog,
+                    MOD(..) => costs.mod_op,
+                    MODI(..) => costs.modi,
+                    MOVE(..) => costs.move_op,
+                    MOVI(..) => costs.movi,
+                    MROO(..) => costs.mroo,
+                    MUL(..) => costs.mul,
+                    MULI(..) => costs.muli,
+                    NOOP => costs.noop,
+                    NOT(..) => costs.not,
+                    OR(..) => costs.or,
+                    ORI(..) => costs.ori,
+                    SLL(..) => costs.sll,
+                    SLLI(..) => costs.slli,
+                    SRL(..) => costs.srl,
+                    SRLI(..) => costs.srli,
+                    SUB(..) => costs.sub,
+                    SUBI(..) => costs.subi,
+                    XOR(..) => costs.xor,
+                    XORI(..) => costs.xori,
+                    WQOP(..) => costs.wqop,
+                    WQML(..) => costs.wqml,
+                    WQDV(..) => costs.wqdv,
+                    WQCM(..) => costs.wqcm,
+                    WQAM(..) => costs.wqam,
+                    JMP(..) => costs.jmp,
+                    JI(..) => costs.ji,
+                    JNE(..) => costs.jne,
+                    JNEI(..) => costs.jnei,
+                    JNZI(..) => costs.jnzi,
+                    JMPB(..) => costs.jmpb,
+                    JMPF(..) => costs.jmpf,
+                    JNZB(..) => costs.jnzb,
+                    JNZF(..) => costs.jnzf,
+                    RET(..) => costs.ret,
+                    ALOC(..) => costs.aloc,
+                    CFEI(..) | CFE(..) => costs.cfei,
+                    CFSI(..) | CFS(..) => costs.cfsi,
+                    LB(..) => costs.lb,
+                    LW(..) => costs.lw,
+                    MCL(..) => return dependent(&costs.mcl),
+                    MCLI(..) => return dependent(&costs.mcli),
+                    MCP(..) => return dependent(&costs.mcp),
+                    MCPI(..) => return dependent(&costs.mcpi),
+                    MEQ(..) => return dependent(&costs.meq),
+                    SB(..) => costs.sb,
+                    SW(..) => costs.sw,
+                    BAL(..) => costs.bal,
+                    BHEI(..) => costs.bhei,
+                    BHSH(..) => costs.bhsh,
+                    BURN(..) => costs.burn,
+                    CALL(..) => return dependent(&costs.call),
+                    CB(..) => costs.cb,
+                    CCP(..) => return dependent(&costs.ccp),
+                    CROO(..) => costs.croo,
+                    CSIZ(..) => return dependent(&costs.csiz),
+                    LDC(..) => return dependent(&costs.ldc),
+                    LOG(..) => costs.log,
+                    LOGD(..) => return dependent(&costs.logd),
+                    MINT(..) => costs.mint,
+                    RETD(..) => return dependent(&costs.retd),
+                    RVRT(..) => costs.rvrt,
+                    SMO(..) => return dependent(&costs.smo),
+                    SCWQ(..) => return dependent(&costs.scwq),
+                    SRW(..) => costs.srw,
+                    SRWQ(..) => return dependent(&costs.srwq),
+                    SWW(..) => costs.sww,
+                    SWWQ(..) => return dependent(&costs.swwq),
+                    TIME(..) => costs.time,
+                    TR(..) => costs.tr,
+                    TRO(..) => costs.tro,
+                    ECK1(..) => costs.eck1,
+                    ECR1(..) => costs.ecr1,
+                    ED19(..) => costs.ed19,
+                    K256(..) => return dependent(&costs.k256),
+                    S256(..) => return dependent(&costs.s256),
+                    FLAG(..) => costs.flag,
+                    GM(..) => costs.gm,
+                    GTF(..) => costs.gtf,
+                    // Handled by the host running the VM, outside of the gas cost table.
+                    ECAL(..) => return (0, false),
+                    // Data section loads are a single word load, or the addition of an offset to
+                    // the data section address.
+                    LoadDataId(..) | DataSectionRegisterLoadPlaceholder => costs.lw,
+                    BLOB(..) | DataSectionOffsetPlaceholder | Undefined => 0,
+                };
+                (cost, true)
+            }
+            Either::Right(org_op) => {
+                let cost = match org_op {
+                    ControlFlowOp::Jump(_) | ControlFlowOp::Call(_) => costs.jmpf,
+                    ControlFlowOp::JumpIfNotZero(..) => costs.jnzf,
+                    ControlFlowOp::SaveRetAddr(..) => costs.sub + costs.srli + costs.addi,
+                    ControlFlowOp::LoadLabel(..) => costs.lw,
+                    ControlFlowOp::Label(_)
+                    | ControlFlowOp::Comment
+                    | ControlFlowOp::DataSectionOffsetPlaceholder
+                    | ControlFlowOp::PushAll(_)
+                    | ControlFlowOp::PopAll(_) => 0,
+                };
+                (cost, true)
+            }
+        }
+    }
+}
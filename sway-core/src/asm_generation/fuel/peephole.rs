@@ -0,0 +1,619 @@
+//! Peephole optimizations over the final, register allocated, assembly.
+//!
+//! Register allocation and the lowering of control flow leave behind patterns which are trivially
+//! redundant once the registers are known, like moves between coalesced registers or jumps to the
+//! very next instruction.  These are cleaned up here, before the labels are realized.
+
+use either::Either;
+
+use crate::asm_lang::{
+    allocated_ops::{AllocatedOpcode, AllocatedRegister},
+    AllocatedAbstractOp, ControlFlowOp, VirtualImmediate12,
+};
+
+use super::{
+    allocated_abstract_instruction_set::AllocatedAbstractInstructionSet, compiler_constants,
+};
+
+/// Counts of the instructions before and after the peephole optimizations, along with the number
+/// of times each of them was applied.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PeepholeStats {
+    pub(crate) ops_before: usize,
+    pub(crate) ops_after: usize,
+    pub(crate) moves_removed: usize,
+    pub(crate) immediates_folded: usize,
+    pub(crate) jumps_removed: usize,
+    pub(crate) memory_ops_merged: usize,
+}
+
+impl std::ops::AddAssign for PeepholeStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.ops_before += rhs.ops_before;
+        self.ops_after += rhs.ops_after;
+        self.moves_removed += rhs.moves_removed;
+        self.immediates_folded += rhs.immediates_folded;
+        self.jumps_removed += rhs.jumps_removed;
+        self.memory_ops_merged += rhs.memory_ops_merged;
+    }
+}
+
+impl std::fmt::Display for PeepholeStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "instructions before peephole: {}", self.ops_before)?;
+        writeln!(f, "instructions after peephole:  {}", self.ops_after)?;
+        writeln!(f, "  redundant moves removed:    {}", self.moves_removed)?;
+        writeln!(
+            f,
+            "  immediates folded:          {}",
+            self.immediates_folded
+        )?;
+        writeln!(f, "  jumps to next removed:      {}", self.jumps_removed)?;
+        write!(
+            f,
+            "  memory ops merged:          {}",
+            self.memory_ops_merged
+        )
+    }
+}
+
+impl AllocatedAbstractInstructionSet {
+    /// Apply the peephole optimizations until none of them applies anymore, returning how much
+    /// they changed.
+    pub(crate) fn peephole_optimize(&mut self) -> PeepholeStats {
+        let mut stats = PeepholeStats {
+            ops_before: self.instruction_count(),
+            ..Default::default()
+        };
+
+        // Jumps in user `asm` blocks are encoded as offsets, which would be invalidated by
+        // removing any instruction.
+        if !self.has_offset_jumps() {
+            while self.remove_redundant_moves(&mut stats)
+                | self.fold_immediates(&mut stats)
+                | self.remove_jumps_to_next(&mut stats)
+                | self.merge_memory_ops(&mut stats)
+            {}
+        }
+
+        stats.ops_after = self.instruction_count();
+        stats
+    }
+
+    fn instruction_count(&self) -> usize {
+        self.ops
+            .iter()
+            .filter(|op| {
+                !matches!(
+                    op.opcode,
+                    Either::Right(ControlFlowOp::Label(_) | ControlFlowOp::Comment)
+                )
+            })
+            .count()
+    }
+
+    fn has_offset_jumps(&self) -> bool {
+        self.ops.iter().any(|op| {
+            matches!(
+                op.opcode,
+                Either::Left(
+                    AllocatedOpcode::JI(..)
+                        | AllocatedOpcode::JNE(..)
+                        | AllocatedOpcode::JNEI(..)
+                        | AllocatedOpcode::JNZI(..)
+                        | AllocatedOpcode::JMPB(..)
+                        | AllocatedOpcode::JMPF(..)
+                        | AllocatedOpcode::JNZB(..)
+                        | AllocatedOpcode::JNZF(..)
+                )
+            )
+        })
+    }
+
+    // Remove moves of a register to itself, and moves undoing the move just before them, like the
+    // second one in `move $r1 $r0; move $r0 $r1`.
+    fn remove_redundant_moves(&mut self, stats: &mut PeepholeStats) -> bool {
+        let mut new_ops: Vec<AllocatedAbstractOp> = Vec::with_capacity(self.ops.len());
+        let mut modified = false;
+        for op in self.ops.drain(..) {
+            let redundant = match (&op.opcode, new_ops.last().map(|prev| &prev.opcode)) {
+                (Either::Left(AllocatedOpcode::MOVE(dst, src)), _) if dst == src => true,
+                (
+                    Either::Left(AllocatedOpcode::MOVE(dst, src)),
+                    Some(Either::Left(AllocatedOpcode::MOVE(prev_dst, prev_src))),
+                ) => {
+                    dst == prev_src
+                        && src == prev_dst
+                        && matches!(dst, AllocatedRegister::Allocated(_))
+                        && matches!(src, AllocatedRegister::Allocated(_))
+                }
+                _ => false,
+            };
+            if redundant {
+                stats.moves_removed += 1;
+                modified = true;
+            } else {
+                new_ops.push(op);
+            }
+        }
+        self.ops = new_ops;
+        modified
+    }
+
+    // Fold `addi $r1 $r0 i1; addi $r1 $r1 i2` into `addi $r1 $r0 i1+i2`, and likewise for `subi`,
+    // as long as the sum fits into the immediate.  Additions and subtractions of zero to a register
+    // itself are removed.
+    fn fold_immediates(&mut self, stats: &mut PeepholeStats) -> bool {
+        let mut new_ops: Vec<AllocatedAbstractOp> = Vec::with_capacity(self.ops.len());
+        let mut modified = false;
+        for op in self.ops.drain(..) {
+            match &op.opcode {
+                Either::Left(
+                    AllocatedOpcode::ADDI(dst, src, imm) | AllocatedOpcode::SUBI(dst, src, imm),
+                ) if dst == src
+                    && imm.value == 0
+                    && matches!(dst, AllocatedRegister::Allocated(_)) =>
+                {
+                    stats.immediates_folded += 1;
+                    modified = true;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let folded = match (new_ops.last().map(|prev| &prev.opcode), &op.opcode) {
+                (
+                    Some(Either::Left(AllocatedOpcode::ADDI(prev_dst, prev_src, prev_imm))),
+                    Either::Left(AllocatedOpcode::ADDI(dst, src, imm)),
+                ) if dst == prev_dst
+                    && src == dst
+                    && matches!(dst, AllocatedRegister::Allocated(_)) =>
+                {
+                    fold_imm(prev_imm, imm)
+                        .map(|imm| AllocatedOpcode::ADDI(prev_dst.clone(), prev_src.clone(), imm))
+                }
+                (
+                    Some(Either::Left(AllocatedOpcode::SUBI(prev_dst, prev_src, prev_imm))),
+                    Either::Left(AllocatedOpcode::SUBI(dst, src, imm)),
+                ) if dst == prev_dst
+                    && src == dst
+                    && matches!(dst, AllocatedRegister::Allocated(_)) =>
+                {
+                    fold_imm(prev_imm, imm)
+                        .map(|imm| AllocatedOpcode::SUBI(prev_dst.clone(), prev_src.clone(), imm))
+                }
+                _ => None,
+            };
+            match folded {
+                Some(opcode) => {
+                    new_ops.last_mut().unwrap().opcode = Either::Left(opcode);
+                    stats.immediates_folded += 1;
+                    modified = true;
+                }
+                None => new_ops.push(op),
+            }
+        }
+        self.ops = new_ops;
+        modified
+    }
+
+    // Remove jumps, conditional or not, to a label which immediately follows them.
+    fn remove_jumps_to_next(&mut self, stats: &mut PeepholeStats) -> bool {
+        let mut modified = false;
+        let mut idx = 0;
+        while idx < self.ops.len() {
+            let target = match &self.ops[idx].opcode {
+                Either::Right(
+                    ControlFlowOp::Jump(label) | ControlFlowOp::JumpIfNotZero(_, label),
+                ) => Some(*label),
+                _ => None,
+            };
+            let jumps_to_next = target.is_some_and(|target| {
+                self.ops[idx + 1..]
+                    .iter()
+                    .map_while(|op| match &op.opcode {
+                        Either::Right(ControlFlowOp::Label(label)) => Some(Some(*label)),
+                        Either::Right(ControlFlowOp::Comment) => Some(None),
+                        _ => None,
+                    })
+                    .any(|label| label == Some(target))
+            });
+            if jumps_to_next {
+                self.ops.remove(idx);
+                stats.jumps_removed += 1;
+                modified = true;
+            } else {
+                idx += 1;
+            }
+        }
+        modified
+    }
+
+    // Merge adjacent loads and stores of the same word:
+    // - `sw $r0 $r1 i; lw $r2 $r0 i` loads what was just stored, so the load becomes a move,
+    // - `sw $r0 $r1 i; sw $r0 $r2 i` overwrites the first store, which is removed,
+    // - `lw $r1 $r0 i; lw $r1 $r0 i` loads the same value twice, so the second load is removed,
+    // - `lw $r1 $r0 i; sw $r0 $r1 i` stores back what was just loaded, so the store is removed.
+    fn merge_memory_ops(&mut self, stats: &mut PeepholeStats) -> bool {
+        enum Merge {
+            None,
+            RemoveFirst,
+            RemoveSecond,
+            ReplaceSecond(AllocatedOpcode),
+        }
+
+        let mut new_ops: Vec<AllocatedAbstractOp> = Vec::with_capacity(self.ops.len());
+        let mut modified = false;
+        for mut op in self.ops.drain(..) {
+            let merge = match (new_ops.last().map(|prev| &prev.opcode), &op.opcode) {
+                (
+                    Some(Either::Left(AllocatedOpcode::SW(prev_addr, prev_val, prev_imm))),
+                    Either::Left(AllocatedOpcode::LW(dst, addr, imm)),
+                ) if addr == prev_addr && imm.value == prev_imm.value => {
+                    if dst == prev_val {
+                        Merge::RemoveSecond
+                    } else {
+                        Merge::ReplaceSecond(AllocatedOpcode::MOVE(dst.clone(), prev_val.clone()))
+                    }
+                }
+                (
+                    Some(Either::Left(AllocatedOpcode::SW(prev_addr, _, prev_imm))),
+                    Either::Left(AllocatedOpcode::SW(addr, _, imm)),
+                ) if addr == prev_addr && imm.value == prev_imm.value => Merge::RemoveFirst,
+                (
+                    Some(Either::Left(AllocatedOpcode::LW(prev_dst, prev_addr, prev_imm))),
+                    Either::Left(AllocatedOpcode::LW(dst, addr, imm)),
+                ) if dst == prev_dst
+                    && addr == prev_addr
+                    && imm.value == prev_imm.value
+                    && dst != addr =>
+                {
+                    Merge::RemoveSecond
+                }
+                (
+                    Some(Either::Left(AllocatedOpcode::LW(prev_dst, prev_addr, prev_imm))),
+                    Either::Left(AllocatedOpcode::SW(addr, val, imm)),
+                ) if val == prev_dst
+                    && addr == prev_addr
+                    && imm.value == prev_imm.value
+                    && val != addr =>
+                {
+                    Merge::RemoveSecond
+                }
+                _ => Merge::None,
+            };
+            if !matches!(merge, Merge::None) {
+                stats.memory_ops_merged += 1;
+                modified = true;
+            }
+            match merge {
+                Merge::None => new_ops.push(op),
+                Merge::RemoveFirst => {
+                    new_ops.pop();
+                    new_ops.push(op);
+                }
+                Merge::RemoveSecond => {}
+                Merge::ReplaceSecond(opcode) => {
+                    op.opcode = Either::Left(opcode);
+                    new_ops.push(op);
+                }
+            }
+        }
+        self.ops = new_ops;
+        modified
+    }
+}
+
+fn fold_imm(lhs: &VirtualImmediate12, rhs: &VirtualImmediate12) -> Option<VirtualImmediate12> {
+    let sum = lhs.value as u64 + rhs.value as u64;
+    (sum <= compiler_constants::TWELVE_BITS)
+        .then(|| VirtualImmediate12::new_unchecked(sum, "sum was checked to fit in 12 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm_lang::{ConstantRegister, Label, VirtualImmediate24};
+
+    fn reg(idx: u8) -> AllocatedRegister {
+        AllocatedRegister::Allocated(idx)
+    }
+
+    fn imm(value: u64) -> VirtualImmediate12 {
+        VirtualImmediate12::new_unchecked(value, "test immediates fit in 12 bits")
+    }
+
+    fn op(opcode: AllocatedOpcode) -> AllocatedAbstractOp {
+        AllocatedAbstractOp {
+            opcode: Either::Left(opcode),
+            comment: String::new(),
+            owning_span: None,
+        }
+    }
+
+    fn cf(opcode: ControlFlowOp<AllocatedRegister>) -> AllocatedAbstractOp {
+        AllocatedAbstractOp {
+            opcode: Either::Right(opcode),
+            comment: String::new(),
+            owning_span: None,
+        }
+    }
+
+    fn label(idx: usize) -> AllocatedAbstractOp {
+        cf(ControlFlowOp::Label(Label(idx)))
+    }
+
+    fn comment() -> AllocatedAbstractOp {
+        cf(ControlFlowOp::Comment)
+    }
+
+    /// Optimize `ops`, returning the resulting ops with their whitespace normalized, along with
+    /// the stats.
+    fn optimize(ops: Vec<AllocatedAbstractOp>) -> (Vec<String>, PeepholeStats) {
+        let mut set = AllocatedAbstractInstructionSet { ops };
+        let stats = set.peephole_optimize();
+        let ops = set
+            .ops
+            .iter()
+            .map(|op| {
+                op.opcode
+                    .to_string()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        (ops, stats)
+    }
+
+    #[test]
+    fn removes_moves_to_self() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), reg(1))),
+            op(AllocatedOpcode::MOVE(reg(1), reg(2))),
+        ]);
+        assert_eq!(ops, ["move $r1 $r2"]);
+        assert_eq!(stats.moves_removed, 1);
+        assert_eq!((stats.ops_before, stats.ops_after), (2, 1));
+    }
+
+    #[test]
+    fn removes_moves_undoing_the_previous_move() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), reg(0))),
+            op(AllocatedOpcode::MOVE(reg(0), reg(1))),
+        ]);
+        assert_eq!(ops, ["move $r1 $r0"]);
+        assert_eq!(stats.moves_removed, 1);
+    }
+
+    #[test]
+    fn keeps_moves_separated_by_a_label_or_a_comment() {
+        // The label may be jumped to with other values in the registers.
+        let (ops, _) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), reg(0))),
+            label(1),
+            op(AllocatedOpcode::MOVE(reg(0), reg(1))),
+        ]);
+        assert_eq!(ops, ["move $r1 $r0", ".1", "move $r0 $r1"]);
+
+        let (ops, _) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), reg(0))),
+            comment(),
+            op(AllocatedOpcode::MOVE(reg(0), reg(1))),
+        ]);
+        assert_eq!(ops, ["move $r1 $r0", "", "move $r0 $r1"]);
+    }
+
+    #[test]
+    fn keeps_moves_undoing_moves_of_constant_registers() {
+        let sp = AllocatedRegister::Constant(ConstantRegister::StackPointer);
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), sp.clone())),
+            op(AllocatedOpcode::MOVE(sp, reg(1))),
+        ]);
+        assert_eq!(ops, ["move $r1 $sp", "move $sp $r1"]);
+        assert_eq!(stats.moves_removed, 0);
+    }
+
+    #[test]
+    fn folds_consecutive_immediates() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::ADDI(reg(1), reg(0), imm(8))),
+            op(AllocatedOpcode::ADDI(reg(1), reg(1), imm(16))),
+            op(AllocatedOpcode::SUBI(reg(2), reg(2), imm(1))),
+            op(AllocatedOpcode::SUBI(reg(2), reg(2), imm(2))),
+        ]);
+        assert_eq!(ops, ["addi $r1 $r0 i24", "subi $r2 $r2 i3"]);
+        assert_eq!(stats.immediates_folded, 2);
+    }
+
+    #[test]
+    fn removes_additions_of_zero() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::ADDI(reg(1), reg(1), imm(0))),
+            op(AllocatedOpcode::SUBI(reg(2), reg(2), imm(0))),
+            op(AllocatedOpcode::ADDI(reg(3), reg(2), imm(0))),
+        ]);
+        assert_eq!(ops, ["addi $r3 $r2 i0"]);
+        assert_eq!(stats.immediates_folded, 2);
+    }
+
+    #[test]
+    fn keeps_immediates_which_cannot_be_folded() {
+        let (ops, stats) = optimize(vec![
+            // The sum doesn't fit in 12 bits.
+            op(AllocatedOpcode::ADDI(reg(1), reg(0), imm(4000))),
+            op(AllocatedOpcode::ADDI(reg(1), reg(1), imm(100))),
+            // The second addition reads the result of the first one into another register.
+            op(AllocatedOpcode::ADDI(reg(2), reg(1), imm(1))),
+            op(AllocatedOpcode::ADDI(reg(3), reg(2), imm(1))),
+            // The addition and the subtraction are not folded together.
+            op(AllocatedOpcode::ADDI(reg(4), reg(4), imm(1))),
+            op(AllocatedOpcode::SUBI(reg(4), reg(4), imm(1))),
+            // The label may be jumped to with another value in the register.
+            op(AllocatedOpcode::ADDI(reg(5), reg(5), imm(1))),
+            label(1),
+            op(AllocatedOpcode::ADDI(reg(5), reg(5), imm(1))),
+        ]);
+        assert_eq!(
+            ops,
+            [
+                "addi $r1 $r0 i4000",
+                "addi $r1 $r1 i100",
+                "addi $r2 $r1 i1",
+                "addi $r3 $r2 i1",
+                "addi $r4 $r4 i1",
+                "subi $r4 $r4 i1",
+                "addi $r5 $r5 i1",
+                ".1",
+                "addi $r5 $r5 i1",
+            ]
+        );
+        assert_eq!(stats.immediates_folded, 0);
+    }
+
+    #[test]
+    fn removes_jumps_to_the_next_label() {
+        let (ops, stats) = optimize(vec![
+            cf(ControlFlowOp::Jump(Label(1))),
+            label(1),
+            cf(ControlFlowOp::JumpIfNotZero(reg(1), Label(3))),
+            // Other labels and comments don't separate the jump from its target.
+            label(2),
+            comment(),
+            label(3),
+            op(AllocatedOpcode::NOOP),
+        ]);
+        assert_eq!(ops, [".1", ".2", "", ".3", "noop"]);
+        assert_eq!(stats.jumps_removed, 2);
+        assert_eq!((stats.ops_before, stats.ops_after), (3, 1));
+    }
+
+    #[test]
+    fn keeps_jumps_over_instructions_and_back() {
+        let (ops, stats) = optimize(vec![
+            label(1),
+            cf(ControlFlowOp::Jump(Label(2))),
+            op(AllocatedOpcode::NOOP),
+            label(2),
+            cf(ControlFlowOp::JumpIfNotZero(reg(1), Label(1))),
+            label(3),
+        ]);
+        assert_eq!(ops, [".1", "ji .2", "noop", ".2", "jnzi $r1 .1", ".3"]);
+        assert_eq!(stats.jumps_removed, 0);
+    }
+
+    #[test]
+    fn merges_store_and_load_of_the_same_word() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+            op(AllocatedOpcode::LW(reg(2), reg(0), imm(2))),
+        ]);
+        assert_eq!(ops, ["sw $r0 $r1 i2", "move $r2 $r1"]);
+        assert_eq!(stats.memory_ops_merged, 1);
+
+        // Loading into the stored register is a no-op.
+        let (ops, _) = optimize(vec![
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+            op(AllocatedOpcode::LW(reg(1), reg(0), imm(2))),
+        ]);
+        assert_eq!(ops, ["sw $r0 $r1 i2"]);
+
+        // Loading into the address register still loads the stored value.
+        let (ops, _) = optimize(vec![
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+            op(AllocatedOpcode::LW(reg(0), reg(0), imm(2))),
+        ]);
+        assert_eq!(ops, ["sw $r0 $r1 i2", "move $r0 $r1"]);
+    }
+
+    #[test]
+    fn removes_overwritten_stores() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+            op(AllocatedOpcode::SW(reg(0), reg(2), imm(2))),
+        ]);
+        assert_eq!(ops, ["sw $r0 $r2 i2"]);
+        assert_eq!(stats.memory_ops_merged, 1);
+    }
+
+    #[test]
+    fn removes_repeated_loads_and_stores_of_loaded_values() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::LW(reg(1), reg(0), imm(2))),
+            op(AllocatedOpcode::LW(reg(1), reg(0), imm(2))),
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+        ]);
+        assert_eq!(ops, ["lw $r1 $r0 i2"]);
+        assert_eq!(stats.memory_ops_merged, 2);
+    }
+
+    #[test]
+    fn keeps_memory_ops_which_cannot_be_merged() {
+        let (ops, stats) = optimize(vec![
+            // Different words.
+            op(AllocatedOpcode::SW(reg(0), reg(1), imm(2))),
+            op(AllocatedOpcode::LW(reg(2), reg(0), imm(3))),
+            // The first load overwrites its address register, so the second loads another word.
+            op(AllocatedOpcode::LW(reg(3), reg(3), imm(0))),
+            op(AllocatedOpcode::LW(reg(3), reg(3), imm(0))),
+            // The label may be jumped to with another value in memory.
+            op(AllocatedOpcode::SW(reg(4), reg(5), imm(0))),
+            label(1),
+            op(AllocatedOpcode::LW(reg(6), reg(4), imm(0))),
+            // So may the code after the comment be, as far as the pass knows.
+            op(AllocatedOpcode::SW(reg(4), reg(5), imm(0))),
+            comment(),
+            op(AllocatedOpcode::SW(reg(4), reg(6), imm(0))),
+        ]);
+        assert_eq!(
+            ops,
+            [
+                "sw $r0 $r1 i2",
+                "lw $r2 $r0 i3",
+                "lw $r3 $r3 i0",
+                "lw $r3 $r3 i0",
+                "sw $r4 $r5 i0",
+                ".1",
+                "lw $r6 $r4 i0",
+                "sw $r4 $r5 i0",
+                "",
+                "sw $r4 $r6 i0",
+            ]
+        );
+        assert_eq!(stats.memory_ops_merged, 0);
+    }
+
+    #[test]
+    fn applies_the_optimizations_until_none_applies() {
+        // Removing the move makes the additions adjacent, and folding them makes the jump go to
+        // the next label.
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::ADDI(reg(1), reg(0), imm(1))),
+            op(AllocatedOpcode::MOVE(reg(2), reg(2))),
+            op(AllocatedOpcode::ADDI(reg(1), reg(1), imm(2))),
+            cf(ControlFlowOp::Jump(Label(1))),
+            label(1),
+        ]);
+        assert_eq!(ops, ["addi $r1 $r0 i3", ".1"]);
+        assert_eq!(stats.moves_removed, 1);
+        assert_eq!(stats.immediates_folded, 1);
+        assert_eq!(stats.jumps_removed, 1);
+        assert_eq!((stats.ops_before, stats.ops_after), (4, 1));
+    }
+
+    #[test]
+    fn leaves_functions_with_offset_jumps_untouched() {
+        let (ops, stats) = optimize(vec![
+            op(AllocatedOpcode::MOVE(reg(1), reg(1))),
+            op(AllocatedOpcode::JI(VirtualImmediate24::new_unchecked(
+                2,
+                "test immediates fit in 24 bits",
+            ))),
+            op(AllocatedOpcode::NOOP),
+        ]);
+        assert_eq!(ops, ["move $r1 $r1", "ji i2", "noop"]);
+        assert_eq!((stats.ops_before, stats.ops_after), (3, 3));
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     asm_generation::fuel::{analyses::liveness_analysis, compiler_constants},
     asm_lang::{
-        allocated_ops::AllocatedRegister, virtual_register::*, AllocatedAbstractOp, Op,
-        VirtualImmediate12, VirtualImmediate18, VirtualImmediate24, VirtualOp,
+        allocated_ops::AllocatedRegister, virtual_register::*, AllocatedAbstractOp, ControlFlowOp,
+        Label, Op, VirtualImmediate12, VirtualImmediate18, VirtualImmediate24, VirtualOp,
     },
 };
 
@@ -186,6 +186,9 @@ pub(crate) fn create_interference_graph(
 /// unnecessary MOVE is detected and removed, the two virtual registers used by the MOVE are said
 /// to be "coalesced" and the two corresponding nodes in the graph are then merged.
 ///
+/// The MOVEs inside loops are considered first, as they're executed the most, and coalescing
+/// some registers may prevent coalescing others.
+///
 /// Two important aspects of this for our implementation:
 /// * When two registers are coalesced, a new node with a new virtual register (generated using the
 ///   register sequencer) is created in the interference graph.
@@ -208,109 +211,100 @@ pub(crate) fn coalesce_registers(
     let mut reduced_live_out: Vec<BTreeSet<VirtualRegister>> = Vec::with_capacity(live_out.len());
     assert!(ops.len() == live_out.len());
 
-    for (op_idx, op) in ops.iter().enumerate() {
-        match &op.opcode {
-            Either::Left(VirtualOp::MOVE(x, y)) => {
-                match (x, y) {
-                    (VirtualRegister::Virtual(_), VirtualRegister::Virtual(_)) => {
-                        // Use reg_to_reg_map to figure out what x and y have been replaced
-                        // with. We keep looking for mappings within reg_to_reg_map until we find a
-                        // register that doesn't map to any other.
-                        let mut r1 = x;
-                        while let Some(t) = reg_to_reg_map.get(r1) {
-                            r1 = t;
-                        }
-                        let mut r2 = y;
-                        while let Some(t) = reg_to_reg_map.get(r2) {
-                            r2 = t;
-                        }
+    // The MOVEs between virtual registers, innermost loops first, in the order of `ops` otherwise
+    // as the sort is stable.
+    let loop_depths = compute_loop_depths(ops);
+    let mut moves: Vec<_> = ops
+        .iter()
+        .enumerate()
+        .filter_map(|(op_idx, op)| match &op.opcode {
+            Either::Left(VirtualOp::MOVE(
+                x @ VirtualRegister::Virtual(_),
+                y @ VirtualRegister::Virtual(_),
+            )) => Some((op_idx, x, y)),
+            _ => None,
+        })
+        .collect();
+    moves.sort_by_key(|(op_idx, _, _)| std::cmp::Reverse(loop_depths[*op_idx]));
+
+    for (_, x, y) in moves {
+        // Use reg_to_reg_map to figure out what x and y have been replaced with. We keep looking
+        // for mappings within reg_to_reg_map until we find a register that doesn't map to any
+        // other.
+        let mut r1 = x;
+        while let Some(t) = reg_to_reg_map.get(r1) {
+            r1 = t;
+        }
+        let mut r2 = y;
+        while let Some(t) = reg_to_reg_map.get(r2) {
+            r2 = t;
+        }
 
-                        // Find the interference graph nodes that corresponding to r1 and r2
-                        let ix1 = reg_to_node_map.get(r1).unwrap();
-                        let ix2 = reg_to_node_map.get(r2).unwrap();
+        // If r1 and r2 are the same, the MOVE instruction can be safely removed.
+        if r1 == r2 {
+            continue;
+        }
 
-                        // If r1 and r2 are the same, the MOVE instruction can be safely removed,
-                        // i.e., not added to reduced_ops
-                        if r1 == r2 {
-                            continue;
-                        }
+        // Find the interference graph nodes that corresponding to r1 and r2
+        let ix1 = reg_to_node_map.get(r1).unwrap();
+        let ix2 = reg_to_node_map.get(r2).unwrap();
+
+        let r1_neighbours = interference_graph
+            .neighbors_undirected(*ix1)
+            .collect::<FxHashSet<_>>();
+        let r2_neighbours = interference_graph
+            .neighbors_undirected(*ix2)
+            .collect::<FxHashSet<_>>();
+
+        // Using either of the two safety conditions below, it's guaranteed that we aren't turning
+        // a k-colourable graph into one that's not, by doing the coalescing. Ref: "Coalescing"
+        // section in Appel's book.
+        let briggs_safety = r1_neighbours
+            .union(&r2_neighbours)
+            .filter(|&&neighbour| {
+                interference_graph.neighbors_undirected(neighbour).count()
+                    >= compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize
+            })
+            .count()
+            < compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize;
 
-                        let r1_neighbours = interference_graph
-                            .neighbors_undirected(*ix1)
-                            .collect::<FxHashSet<_>>();
-                        let r2_neighbours = interference_graph
-                            .neighbors_undirected(*ix2)
-                            .collect::<FxHashSet<_>>();
-
-                        // Using either of the two safety conditions below, it's guaranteed
-                        // that we aren't turning a k-colourable graph into one that's not,
-                        // by doing the coalescing. Ref: "Coalescing" section in Appel's book.
-                        let briggs_safety = r1_neighbours
-                            .union(&r2_neighbours)
-                            .filter(|&&neighbour| {
-                                interference_graph.neighbors_undirected(neighbour).count()
-                                    >= compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize
-                            })
-                            .count()
-                            < compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize;
-
-                        let george_safety = r2_neighbours.iter().all(|&r2_neighbor| {
-                            r1_neighbours.contains(&r2_neighbor)
-                                || interference_graph.neighbors_undirected(r2_neighbor).count()
-                                    < compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize
-                        });
-
-                        let safe = briggs_safety || george_safety;
-
-                        // If r1 and r2 are connected in the interference graph (i.e. their
-                        // respective liveness ranges overalp), preserve the MOVE instruction by
-                        // adding it to reduced_ops
-                        if interference_graph.contains_edge(*ix1, *ix2)
-                            || interference_graph.contains_edge(*ix2, *ix1)
-                            || !safe
-                        {
-                            reduced_ops.push(op.clone());
-                            reduced_live_out.push(live_out[op_idx].clone());
-                            continue;
-                        }
+        let george_safety = r2_neighbours.iter().all(|&r2_neighbor| {
+            r1_neighbours.contains(&r2_neighbor)
+                || interference_graph.neighbors_undirected(r2_neighbor).count()
+                    < compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize
+        });
 
-                        // The MOVE instruction can now be safely removed. That is, we simply don't
-                        // add it to the reduced_ops vector. Also, we combine the two nodes ix1 and
-                        // ix2 into ix2 and then we remove ix1 from the graph. We also have
-                        // to do some bookkeeping.
-                        //
-                        // Note that because the interference graph is of type StableGraph, the
-                        // node index corresponding to each virtual register does not change when
-                        // some graph nodes are added or removed.
-
-                        // Add all of ix1(r1)'s edges to `ix2(r2)` as incoming edges.
-                        for neighbor in r1_neighbours {
-                            if !interference_graph.contains_edge(*ix2, neighbor) {
-                                interference_graph.update_edge(neighbor, *ix2, true);
-                            }
-                        }
+        let safe = briggs_safety || george_safety;
 
-                        // Remove ix1.
-                        interference_graph.remove_node(*ix1);
+        // If r1 and r2 are connected in the interference graph (i.e. their respective liveness
+        // ranges overalp), preserve the MOVE instruction.
+        if interference_graph.contains_edge(*ix1, *ix2)
+            || interference_graph.contains_edge(*ix2, *ix1)
+            || !safe
+        {
+            continue;
+        }
 
-                        // Update the register maps
-                        reg_to_node_map.insert(r1.clone(), *ix2);
-                        reg_to_reg_map.insert(r1, r2);
-                    }
-                    _ => {
-                        // Preserve the MOVE instruction if either registers used in the MOVE is
-                        // special registers (i.e. *not* a VirtualRegister::Virtual(_))
-                        reduced_ops.push(op.clone());
-                        reduced_live_out.push(live_out[op_idx].clone());
-                    }
-                }
-            }
-            _ => {
-                // Preserve all other instructions
-                reduced_ops.push(op.clone());
-                reduced_live_out.push(live_out[op_idx].clone());
+        // The MOVE instruction can now be safely removed. We combine the two nodes ix1 and ix2
+        // into ix2 and then we remove ix1 from the graph. We also have to do some bookkeeping.
+        //
+        // Note that because the interference graph is of type StableGraph, the node index
+        // corresponding to each virtual register does not change when some graph nodes are added
+        // or removed.
+
+        // Add all of ix1(r1)'s edges to `ix2(r2)` as incoming edges.
+        for neighbor in r1_neighbours {
+            if !interference_graph.contains_edge(*ix2, neighbor) {
+                interference_graph.update_edge(neighbor, *ix2, true);
             }
         }
+
+        // Remove ix1.
+        interference_graph.remove_node(*ix1);
+
+        // Update the register maps
+        reg_to_node_map.insert(r1.clone(), *ix2);
+        reg_to_reg_map.insert(r1, r2);
     }
 
     // Create a *final* reg-to-reg map that We keep looking for mappings within reg_to_reg_map
@@ -324,6 +318,24 @@ pub(crate) fn coalesce_registers(
         final_reg_to_reg_map.insert(reg, temp);
     }
 
+    // Remove the MOVE instructions between coalesced registers, and preserve all other
+    // instructions.
+    for (op_idx, op) in ops.iter().enumerate() {
+        if let Either::Left(VirtualOp::MOVE(
+            x @ VirtualRegister::Virtual(_),
+            y @ VirtualRegister::Virtual(_),
+        )) = &op.opcode
+        {
+            if final_reg_to_reg_map.get(x).unwrap_or(&x)
+                == final_reg_to_reg_map.get(y).unwrap_or(&y)
+            {
+                continue;
+            }
+        }
+        reduced_ops.push(op.clone());
+        reduced_live_out.push(live_out[op_idx].clone());
+    }
+
     // Update the registers for all instructions using final_reg_to_reg_map
     for new_op in &mut reduced_ops {
         *new_op = new_op.update_register(&final_reg_to_reg_map);
@@ -372,6 +384,68 @@ fn compute_def_use_points(ops: &[Op]) -> FxHashMap<VirtualRegister, (Vec<usize>,
     res
 }
 
+/// How much more a def or use of a virtual register weighs, for each loop it's nested in, when
+/// estimating the cost of spilling it.
+const LOOP_SPILL_WEIGHT: u64 = 10;
+
+// For every instruction, compute the number of loops it's nested in. The loops are found as the
+// instructions between a label and the last jump back to it.
+fn compute_loop_depths(ops: &[Op]) -> Vec<u32> {
+    let mut label_to_index: HashMap<Label, usize> = HashMap::new();
+    for (idx, op) in ops.iter().enumerate() {
+        if let Either::Right(ControlFlowOp::Label(op_label)) = op.opcode {
+            label_to_index.insert(op_label, idx);
+        }
+    }
+
+    let mut loop_ends: FxHashMap<usize, usize> = FxHashMap::default();
+    for (idx, op) in ops.iter().enumerate() {
+        if let Either::Right(ControlFlowOp::Jump(label) | ControlFlowOp::JumpIfNotZero(_, label)) =
+            &op.opcode
+        {
+            match label_to_index.get(label) {
+                Some(&start) if start <= idx => {
+                    let end = loop_ends.entry(start).or_insert(idx);
+                    *end = (*end).max(idx);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let mut loop_depths = vec![0; ops.len()];
+    for (start, end) in loop_ends {
+        for depth in &mut loop_depths[start..=end] {
+            *depth += 1;
+        }
+    }
+    loop_depths
+}
+
+// For every virtual register, estimate the cost of spilling it, i.e. the number of loads and
+// stores it would take, each weighted by the loops it's in. The registers spilled already have
+// an infinite cost, as spilling them again wouldn't shorten their live ranges.
+fn compute_spill_costs(
+    def_use_points: &FxHashMap<VirtualRegister, (Vec<usize>, Vec<usize>)>,
+    loop_depths: &[u32],
+    spilled: &FxHashSet<VirtualRegister>,
+) -> FxHashMap<VirtualRegister, u64> {
+    def_use_points
+        .iter()
+        .map(|(reg, (defs, uses))| {
+            let cost = if spilled.contains(reg) {
+                u64::MAX
+            } else {
+                defs.iter()
+                    .chain(uses.iter())
+                    .map(|&idx| LOOP_SPILL_WEIGHT.saturating_pow(loop_depths[idx]))
+                    .fold(0, u64::saturating_add)
+            };
+            (reg.clone(), cost)
+        })
+        .collect()
+}
+
 /// Given an interference graph and a integer k, figure out if the graph k-colorable. Graph
 /// coloring is an NP-complete problem, but the algorithm below is a simple stack based
 /// approximation that relies on the fact that any node n in the graph that has fewer than k
@@ -384,18 +458,22 @@ fn compute_def_use_points(ops: &[Op]) -> FxHashMap<VirtualRegister, (Vec<usize>,
 ///    - This may make some new nodes have fewer than k neighbours which is nice.
 /// 3. If some vertex n still has k or more neighbors, then the graph may not be k colorable.
 ///     We still add it to the stack as is, as a potential spill. When popping, if we still
-///     can't colour it, then it becomes an actual spill.
+///     can't colour it, then it becomes an actual spill (Briggs' optimistic colouring).
+///     The potential spill is the node with the lowest spill cost per neighbour, i.e. the
+///     cheapest to spill for the most interferences removed.
 /// ===============================================================================================
 ///
 pub(crate) fn color_interference_graph(
     interference_graph: &mut InterferenceGraph,
     ops: &[Op],
     live_out: &[BTreeSet<VirtualRegister>],
-) -> Result<Vec<NodeIndex>, FxHashSet<VirtualRegister>> {
+    spilled: &FxHashSet<VirtualRegister>,
+) -> Vec<NodeIndex> {
     let mut stack = Vec::with_capacity(interference_graph.node_count());
     let mut on_stack = FxHashSet::default();
     let mut spills = FxHashSet::default();
     let def_use_points = compute_def_use_points(ops);
+    let spill_costs = compute_spill_costs(&def_use_points, &compute_loop_depths(ops), spilled);
 
     // Nodes with < k-degree before adding to the stack,
     // to have their neighbours processed.
@@ -496,18 +574,34 @@ pub(crate) fn color_interference_graph(
             }
         }
 
-        if let Some(&spill_reg_index) = pending.iter().max_by(|&&node1, &&node2| {
-            // At the moment, our spill priority function is just this,
-            // i.e., spill the register with more incoming interferences.
-            // (roughly indicating how long the interval is).
-            get_connected_incoming_neighbors(interference_graph, node1)
-                .count()
-                .cmp(&get_connected_incoming_neighbors(interference_graph, node2).count())
+        if let Some(&spill_reg_index) = pending.iter().min_by(|&&node1, &&node2| {
+            // Compare the spill costs per neighbour, cost1 / degree1 against cost2 / degree2,
+            // without dividing. Ties go to the register with more incoming interferences,
+            // roughly indicating how long the interval is.
+            let cost = |node: NodeIndex| {
+                u128::from(
+                    spill_costs
+                        .get(&interference_graph[node])
+                        .copied()
+                        .unwrap_or(u64::MAX),
+                )
+            };
+            let degree = |node: NodeIndex| {
+                get_connected_neighbours(interference_graph, node).count() as u128
+            };
+            (cost(node1) * degree(node2))
+                .cmp(&(cost(node2) * degree(node1)))
+                .then_with(|| {
+                    get_connected_incoming_neighbors(interference_graph, node2)
+                        .count()
+                        .cmp(&get_connected_incoming_neighbors(interference_graph, node1).count())
+                })
         }) {
             let spill_reg = interference_graph[spill_reg_index].clone();
             spills.insert(spill_reg.clone());
 
-            // Update the interference graph that this is spilled.
+            // Update the interference graph as if this is spilled, assuming the optimistic
+            // colouring fails.
             // A spill implies a store right after a definition and
             // a load right before a use, forming new tiny live ranges.
             // So we retain only those interferences that correspond to
@@ -542,26 +636,41 @@ pub(crate) fn color_interference_graph(
         }
     }
 
-    if spills.is_empty() {
-        Ok(stack)
-    } else {
-        Err(spills)
-    }
+    stack
 }
 
 /// Assigns an allocatable register to each virtual register used by some instruction in the
 /// list `self.ops`. The algorithm used is Chaitin's graph-coloring register allocation
-/// algorithm (https://en.wikipedia.org/wiki/Chaitin%27s_algorithm). The individual steps of
+/// algorithm (https://en.wikipedia.org/wiki/Chaitin%27s_algorithm), with Briggs' optimistic
+/// colouring and a spill cost weighted by loop depth. The individual steps of
 /// the algorithm are thoroughly explained in register_allocator.rs.
 
 pub(crate) fn allocate_registers(
     ops: &[Op],
 ) -> Result<AllocatedAbstractInstructionSet, CompileError> {
+    let (updated_ops, pool) = colour_registers(ops)?;
+
+    // Step 6: Update all instructions to use the resulting register pool.
+    let mut buf = vec![];
+    for op in &updated_ops {
+        buf.push(AllocatedAbstractOp {
+            opcode: op.allocate_registers(&pool),
+            comment: op.comment.clone(),
+            owning_span: op.owning_span.clone(),
+        })
+    }
+
+    Ok(AllocatedAbstractInstructionSet { ops: buf })
+}
+
+/// Colours the virtual registers of `ops`, spilling some of them if needed, and returns the ops
+/// with the spills inserted and the moves between coalesced registers removed, along with the
+/// register assigned to each of their virtual registers.
+fn colour_registers(ops: &[Op]) -> Result<(Vec<Op>, RegisterPool), CompileError> {
     enum ColouringResult {
         Success {
             updated_ops: Vec<Op>,
-            interference_graph: InterferenceGraph,
-            colouring_stack: Vec<NodeIndex>,
+            pool: RegisterPool,
         },
         SpillsNeeded {
             updated_ops: Vec<Op>,
@@ -569,7 +678,7 @@ pub(crate) fn allocate_registers(
         },
     }
 
-    fn try_color(ops: &[Op]) -> ColouringResult {
+    fn try_color(ops: &[Op], spilled: &FxHashSet<VirtualRegister>) -> ColouringResult {
         // Step 1: Liveness Analysis.
         let live_out = liveness_analysis(ops, true);
 
@@ -582,13 +691,17 @@ pub(crate) fn allocate_registers(
             coalesce_registers(ops, live_out, &mut interference_graph, &mut reg_to_node_ix);
 
         // Step 4: Simplify - i.e. color the interference graph and return a stack that contains
-        // each colorable node and its neighbors.
-        match color_interference_graph(&mut interference_graph, &updated_ops, &live_out) {
-            Ok(colouring_stack) => ColouringResult::Success {
-                updated_ops,
-                interference_graph,
-                colouring_stack,
-            },
+        // each colorable node and its neighbors. Simplifying deletes the edges of the potential
+        // spills, which may well be live at the same time as their neighbours if they end up
+        // coloured, so it works on a copy of the graph.
+        let mut simplified_graph = interference_graph.clone();
+        let mut stack =
+            color_interference_graph(&mut simplified_graph, &updated_ops, &live_out, spilled);
+
+        // Step 5: Use the stack to assign a register for each virtual register, against all of
+        // the interferences of the unmodified graph.
+        match assign_registers(&interference_graph, &mut stack) {
+            Ok(pool) => ColouringResult::Success { updated_ops, pool },
             Err(spills) => ColouringResult::SpillsNeeded {
                 updated_ops,
                 spills,
@@ -600,17 +713,15 @@ pub(crate) fn allocate_registers(
     let mut updated_ops_ref = ops;
     // A placeholder for updated ops.
     let mut updated_ops;
+    // The registers spilled so far.
+    let mut spilled = FxHashSet::default();
     // How many times to try spilling before we give up.
     let mut try_count = 0;
     // Try and assign registers. If we fail, spill. Repeat few times.
-    let (updated_ops, interference_graph, mut stack) = loop {
-        match try_color(updated_ops_ref) {
-            ColouringResult::Success {
-                updated_ops,
-                interference_graph,
-                colouring_stack,
-            } => {
-                break (updated_ops, interference_graph, colouring_stack);
+    let (updated_ops, pool) = loop {
+        match try_color(updated_ops_ref, &spilled) {
+            ColouringResult::Success { updated_ops, pool } => {
+                break (updated_ops, pool);
             }
             ColouringResult::SpillsNeeded {
                 updated_ops: updated_ops_before_spill,
@@ -641,24 +752,13 @@ pub(crate) fn allocate_registers(
                 }
                 try_count += 1;
                 updated_ops = spill(&updated_ops_before_spill, &spills);
+                spilled.extend(spills);
                 updated_ops_ref = &updated_ops;
             }
         }
     };
 
-    // Step 5: Use the stack to assign a register for each virtual register.
-    let pool = assign_registers(&interference_graph, &mut stack)?;
-    // Step 6: Update all instructions to use the resulting register pool.
-    let mut buf = vec![];
-    for op in &updated_ops {
-        buf.push(AllocatedAbstractOp {
-            opcode: op.allocate_registers(&pool),
-            comment: op.comment.clone(),
-            owning_span: op.owning_span.clone(),
-        })
-    }
-
-    Ok(AllocatedAbstractInstructionSet { ops: buf })
+    Ok((updated_ops, pool))
 }
 
 /// Use the stack generated by the coloring algorithm to figure out a register assignment for each
@@ -667,11 +767,17 @@ pub(crate) fn allocate_registers(
 /// the neighbors of v (available from the stack) and the list of virtual registers already used by
 /// r (available in the used_by field) is empty.
 ///
+/// The neighbours of a virtual register are all of the registers it interferes with, including
+/// those whose edges were deleted when simplifying, so `interference_graph` must be the graph as
+/// it was before simplifying. The virtual registers which can't be assigned a register are
+/// returned, to be spilled.
+///
 fn assign_registers(
     interference_graph: &InterferenceGraph,
     stack: &mut Vec<NodeIndex>,
-) -> Result<RegisterPool, CompileError> {
+) -> Result<RegisterPool, FxHashSet<VirtualRegister>> {
     let mut pool = RegisterPool::init();
+    let mut spills = FxHashSet::default();
 
     while let Some(node) = stack.pop() {
         let reg = interference_graph[node].clone();
@@ -690,16 +796,16 @@ fn assign_registers(
             if let Some(RegisterAllocationStatus { reg: _, used_by }) = available {
                 used_by.insert(reg.clone());
             } else {
-                return Err(CompileError::Internal(
-                    "The allocator cannot resolve a register mapping for this program. \
-                             Using #[inline(never)] on some functions may help.",
-                    Span::dummy(),
-                ));
+                spills.insert(reg);
             }
         }
     }
 
-    Ok(pool)
+    if spills.is_empty() {
+        Ok(pool)
+    } else {
+        Err(spills)
+    }
 }
 
 /// Given a function, its locals info (stack frame usage details)
@@ -937,3 +1043,128 @@ fn spill(ops: &[Op], spills: &FxHashSet<VirtualRegister>) -> Vec<Op> {
 
     spilled
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(opcode: VirtualOp) -> Op {
+        Op {
+            opcode: Either::Left(opcode),
+            comment: String::new(),
+            owning_span: None,
+        }
+    }
+
+    fn org_op(opcode: ControlFlowOp<VirtualRegister>) -> Op {
+        Op {
+            opcode: Either::Right(opcode),
+            comment: String::new(),
+            owning_span: None,
+        }
+    }
+
+    fn virtual_reg(idx: usize) -> VirtualRegister {
+        VirtualRegister::Virtual(format!("{idx}"))
+    }
+
+    // A function defining `num_regs` registers up front, all of them live until they are summed
+    // up at the end, optionally within a loop.
+    fn high_pressure_ops(num_regs: usize, in_loop: bool) -> Vec<Op> {
+        let acc = virtual_reg(num_regs);
+        let mut ops = vec![op(VirtualOp::CFEI(VirtualImmediate24 { value: 0 }))];
+        for idx in 0..num_regs {
+            ops.push(op(VirtualOp::MOVI(
+                virtual_reg(idx),
+                VirtualImmediate18 { value: idx as u32 },
+            )));
+        }
+        ops.push(op(VirtualOp::MOVI(
+            acc.clone(),
+            VirtualImmediate18 { value: 0 },
+        )));
+        if in_loop {
+            ops.push(org_op(ControlFlowOp::Label(Label(0))));
+        }
+        for idx in 0..num_regs {
+            ops.push(op(VirtualOp::ADD(
+                acc.clone(),
+                acc.clone(),
+                virtual_reg(idx),
+            )));
+        }
+        if in_loop {
+            ops.push(org_op(ControlFlowOp::JumpIfNotZero(acc.clone(), Label(0))));
+        }
+        ops.push(op(VirtualOp::CFSI(VirtualImmediate24 { value: 0 })));
+        ops.push(op(VirtualOp::RET(acc)));
+        ops
+    }
+
+    // Checks that every virtual register of the allocated ops is assigned a register, and that no
+    // two interfering virtual registers are assigned the same one.
+    fn assert_valid_allocation(ops: &[Op]) {
+        let (updated_ops, pool) = colour_registers(ops).unwrap();
+        let live_out = liveness_analysis(&updated_ops, true);
+        let (interference_graph, reg_to_node_map) =
+            create_interference_graph(&updated_ops, &live_out);
+
+        for reg in reg_to_node_map.keys() {
+            assert!(
+                pool.get_register(reg).is_some(),
+                "{reg} was not assigned a register"
+            );
+        }
+        for edge in interference_graph.edge_indices() {
+            let (source, target) = interference_graph.edge_endpoints(edge).unwrap();
+            let (source, target) = (&interference_graph[source], &interference_graph[target]);
+            assert_ne!(
+                pool.get_register(source),
+                pool.get_register(target),
+                "{source} and {target} interfere but share a register"
+            );
+        }
+    }
+
+    #[test]
+    fn allocation_without_spills_is_valid() {
+        let num_regs = compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize - 2;
+        let ops = high_pressure_ops(num_regs, false);
+        let (updated_ops, _) = colour_registers(&ops).unwrap();
+        assert_eq!(
+            updated_ops.len(),
+            ops.len(),
+            "no register should be spilled"
+        );
+        assert_valid_allocation(&ops);
+    }
+
+    #[test]
+    fn allocation_under_high_register_pressure_is_valid() {
+        let num_regs = compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize * 2;
+        assert_valid_allocation(&high_pressure_ops(num_regs, false));
+    }
+
+    #[test]
+    fn allocation_under_high_register_pressure_in_loop_is_valid() {
+        let num_regs = compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize + 4;
+        assert_valid_allocation(&high_pressure_ops(num_regs, true));
+    }
+
+    #[test]
+    fn allocation_of_registers_live_across_loop_is_valid() {
+        let num_regs = compiler_constants::NUM_ALLOCATABLE_REGISTERS as usize + 2;
+        let mut ops = high_pressure_ops(num_regs, true);
+        // Use the first register again after the loop, so it's live across the whole of it.
+        let cfsi_idx = ops.len() - 2;
+        ops.insert(
+            cfsi_idx,
+            op(VirtualOp::ADD(
+                virtual_reg(0),
+                virtual_reg(0),
+                virtual_reg(0),
+            )),
+        );
+        assert_valid_allocation(&ops);
+    }
+}
@@ -11,6 +11,8 @@ mod instruction_set;
 mod programs;
 
 mod finalized_asm;
-pub use finalized_asm::{CompiledBytecode, FinalizedAsm, FinalizedEntry};
+pub use finalized_asm::{
+    CompiledBytecode, FinalizedAsm, FinalizedEntry, FinalizedFunction, FunctionSize, GasEstimate,
+};
 
 pub(crate) use programs::ProgramKind;
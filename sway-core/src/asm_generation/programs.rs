@@ -11,11 +11,15 @@ use super::fuel::{
 use crate::{
     asm_lang::{allocated_ops::AllocatedOp, Label},
     decl_engine::DeclRefFunction,
+    GasEstimate,
 };
 
+use sway_types::Span;
+
 type SelectorOpt = Option<[u8; 4]>;
 type FnName = String;
 type ImmOffset = u64;
+type FnSpan = Option<Span>;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ProgramKind {
@@ -35,6 +39,8 @@ pub(super) struct AbstractProgram {
     data_section: DataSection,
     entries: Vec<AbstractEntry>,
     non_entries: Vec<AbstractInstructionSet>,
+    /// The start labels of all the functions, along with their names and spans.
+    function_labels: Vec<(Label, FnName, FnSpan)>,
     reg_seqr: RegisterSequencer,
 }
 
@@ -55,6 +61,7 @@ pub(super) struct AllocatedProgram {
     prologue: AllocatedAbstractInstructionSet,
     functions: Vec<AllocatedAbstractInstructionSet>,
     entries: Vec<(SelectorOpt, Label, FnName, Option<DeclRefFunction>)>,
+    function_labels: Vec<(Label, FnName, FnSpan)>,
 }
 
 /// A FinalProgram represents code which may be serialized to VM bytecode.
@@ -63,7 +70,16 @@ pub(super) enum FinalProgram {
         kind: ProgramKind,
         data_section: DataSection,
         ops: Vec<AllocatedOp>,
-        entries: Vec<(SelectorOpt, ImmOffset, FnName, Option<DeclRefFunction>)>,
+        /// The entry points, along with an estimate of the gas they use.
+        entries: Vec<(
+            SelectorOpt,
+            ImmOffset,
+            FnName,
+            Option<DeclRefFunction>,
+            GasEstimate,
+        )>,
+        /// The offsets at which all the functions begin, along with their names and spans.
+        functions: Vec<(ImmOffset, FnName, FnSpan)>,
     },
     Evm {
         ops: Vec<etk_asm::ops::AbstractOp>,
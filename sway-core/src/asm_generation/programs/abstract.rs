@@ -1,4 +1,4 @@
-use super::{AbstractEntry, AbstractProgram, AllocatedProgram, ProgramKind};
+use super::{AbstractEntry, AbstractProgram, AllocatedProgram, FnName, FnSpan, ProgramKind};
 
 use crate::{
     asm_generation::fuel::{
@@ -10,7 +10,7 @@ use crate::{
     },
     asm_lang::{
         allocated_ops::{AllocatedOpcode, AllocatedRegister},
-        AllocatedAbstractOp, ConstantRegister, ControlFlowOp, VirtualImmediate12,
+        AllocatedAbstractOp, ConstantRegister, ControlFlowOp, Label, VirtualImmediate12,
         VirtualImmediate18,
     },
 };
@@ -18,6 +18,7 @@ use crate::{
 use sway_error::error::CompileError;
 
 use either::Either;
+use rayon::prelude::*;
 
 impl AbstractProgram {
     pub(crate) fn new(
@@ -25,6 +26,7 @@ impl AbstractProgram {
         data_section: DataSection,
         entries: Vec<AbstractEntry>,
         non_entries: Vec<AbstractInstructionSet>,
+        function_labels: Vec<(Label, FnName, FnSpan)>,
         reg_seqr: RegisterSequencer,
     ) -> Self {
         AbstractProgram {
@@ -32,6 +34,7 @@ impl AbstractProgram {
             data_section,
             entries,
             non_entries,
+            function_labels,
             reg_seqr,
         }
     }
@@ -60,23 +63,33 @@ impl AbstractProgram {
             .collect();
 
         // Gather all the functions together, optimise and then verify the instructions.
+        //
+        // The functions are independent of each other from here on, so they are optimised and
+        // have their registers allocated in parallel.  The results are gathered in the original
+        // order of the functions, and the first error in that order is reported, so that the
+        // output doesn't depend on the number of threads.
         let abstract_functions = self
             .entries
             .into_iter()
             .map(|entry| entry.ops)
             .chain(self.non_entries)
-            .map(|ais| ais.optimize(&self.data_section))
-            .map(AbstractInstructionSet::verify)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|ais| ais.optimize(&self.data_section).verify())
+            .collect::<Vec<_>>()
+            .into_iter()
             .collect::<Result<Vec<_>, _>>()?;
 
         // Allocate the registers for each function.
         let functions = abstract_functions
-            .into_iter()
+            .into_par_iter()
             .map(|fn_ops| {
                 fn_ops
                     .allocate_registers()
                     .map(AllocatedAbstractInstructionSet::emit_pusha_popa)
             })
+            .collect::<Vec<_>>()
+            .into_iter()
             .collect::<Result<Vec<_>, _>>()?;
 
         // XXX need to verify that the stack use for each function is balanced.
@@ -87,6 +100,7 @@ impl AbstractProgram {
             prologue,
             functions,
             entries,
+            function_labels: self.function_labels,
         })
     }
 
@@ -255,3 +269,60 @@ impl std::fmt::Display for AbstractProgram {
         write!(f, "{}", self.data_section)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{namespace, test_utils, Engines, OptLevel};
+
+    /// Compiles a script of several functions, which are not inlined, using a pool of
+    /// `num_threads` threads.
+    fn compile(num_threads: usize) -> Vec<u8> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            let src = r#"script;
+
+#[inline(never)]
+fn pick(cond: bool, a: u64, b: u64) -> u64 {
+    if cond { a } else { b }
+}
+
+#[inline(never)]
+fn first(x: (u64, u64, u64)) -> u64 {
+    x.0
+}
+
+#[inline(never)]
+fn chain(a: u64, b: u64) -> u64 {
+    let x = pick(true, a, b);
+    let y = pick(false, x, b);
+    first((y, x, a))
+}
+
+fn main() -> u64 {
+    let a = chain(1, 2);
+    let b = chain(a, 3);
+    pick(true, first((b, a, 4)), chain(b, a))
+}
+"#;
+            let build_config = test_utils::source_build_config("parallel-codegen")
+                .with_optimization_level(OptLevel::Opt1);
+            let (bytecode, _warnings) = test_utils::compile_to_bytecode(
+                &Engines::default(),
+                src,
+                namespace::Module::default(),
+                build_config,
+            );
+            bytecode
+        })
+    }
+
+    #[test]
+    fn parallel_codegen_matches_serial_codegen() {
+        let serial = compile(1);
+        assert!(!serial.is_empty());
+        assert_eq!(serial, compile(4));
+    }
+}
@@ -1,9 +1,29 @@
 use super::{AllocatedProgram, FinalProgram};
 
-use crate::asm_generation::fuel::allocated_abstract_instruction_set::AllocatedAbstractInstructionSet;
+use crate::asm_generation::fuel::{
+    allocated_abstract_instruction_set::AllocatedAbstractInstructionSet,
+    gas_estimation::GasEstimator, peephole::PeepholeStats,
+};
 
 impl AllocatedProgram {
+    /// Apply the peephole optimizations to every function.  The prologue is left untouched.
+    pub(crate) fn peephole_optimize(&mut self) -> PeepholeStats {
+        let mut stats = PeepholeStats::default();
+        for function in &mut self.functions {
+            stats += function.peephole_optimize();
+        }
+        stats
+    }
+
     pub(crate) fn into_final_program(mut self) -> Result<FinalProgram, crate::CompileError> {
+        // Estimate the gas used by the entries while the functions are still apart.
+        let mut estimator = GasEstimator::new(&self.functions);
+        let gas_estimates: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(_, label, ..)| estimator.estimate(label).unwrap_or_default())
+            .collect();
+
         // Concat the prologue and all the functions together.
         let abstract_ops = AllocatedAbstractInstructionSet {
             ops: std::iter::once(self.prologue.ops)
@@ -20,12 +40,24 @@ impl AllocatedProgram {
         let entries = self
             .entries
             .into_iter()
-            .map(|(selector, label, name, test_decl_ref)| {
+            .zip(gas_estimates)
+            .map(|((selector, label, name, test_decl_ref), gas_estimate)| {
                 let offset = label_offsets
                     .remove(&label)
                     .expect("no offset for entry")
                     .offs;
-                (selector, offset, name, test_decl_ref)
+                (selector, offset, name, test_decl_ref, gas_estimate)
+            })
+            .collect();
+
+        // Collect the function offsets, for attributing the bytecode to them.
+        let functions = self
+            .function_labels
+            .into_iter()
+            .filter_map(|(label, name, span)| {
+                label_offsets
+                    .get(&label)
+                    .map(|block| (block.offs, name, span))
             })
             .collect();
 
@@ -34,6 +66,7 @@ impl AllocatedProgram {
             data_section: self.data_section,
             ops,
             entries,
+            functions,
         })
     }
 }
@@ -4,7 +4,7 @@ use crate::{
     asm_generation::{
         fuel::data_section::DataSection, instruction_set::InstructionSet, ProgramABI,
     },
-    FinalizedAsm, FinalizedEntry,
+    FinalizedAsm, FinalizedEntry, FinalizedFunction,
 };
 
 impl FinalProgram {
@@ -15,20 +15,29 @@ impl FinalProgram {
                 data_section,
                 ops,
                 entries,
+                functions,
             } => FinalizedAsm {
                 data_section,
                 program_section: InstructionSet::Fuel { ops },
                 program_kind: kind,
                 entries: entries
                     .into_iter()
-                    .map(|(selector, imm, fn_name, test_decl_ref)| FinalizedEntry {
-                        imm,
-                        fn_name,
-                        selector,
-                        test_decl_ref,
-                    })
+                    .map(
+                        |(selector, imm, fn_name, test_decl_ref, gas_estimate)| FinalizedEntry {
+                            imm,
+                            fn_name,
+                            selector,
+                            test_decl_ref,
+                            gas_estimate,
+                        },
+                    )
+                    .collect(),
+                functions: functions
+                    .into_iter()
+                    .map(|(imm, name, span)| FinalizedFunction { name, imm, span })
                     .collect(),
                 abi: None,
+                eliminated_functions: Default::default(),
             },
             FinalProgram::Evm { ops, abi } => FinalizedAsm {
                 data_section: DataSection {
@@ -37,7 +46,9 @@ impl FinalProgram {
                 program_section: InstructionSet::Evm { ops },
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
+                functions: vec![],
                 abi: Some(ProgramABI::Evm(abi)),
+                eliminated_functions: Default::default(),
             },
             FinalProgram::MidenVM { ops } => FinalizedAsm {
                 data_section: DataSection {
@@ -47,7 +58,9 @@ impl FinalProgram {
                 // should this be a script? :think:
                 program_kind: super::ProgramKind::Script,
                 entries: vec![],
+                functions: vec![],
                 abi: None, /* TODO? */
+                eliminated_functions: Default::default(),
             },
         }
     }
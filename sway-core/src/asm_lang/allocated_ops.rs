@@ -232,6 +232,12 @@ pub(crate) enum AllocatedOpcode {
     FLAG(AllocatedRegister),
     GM(AllocatedRegister, VirtualImmediate18),
     GTF(AllocatedRegister, AllocatedRegister, VirtualImmediate12),
+    ECAL(
+        AllocatedRegister,
+        AllocatedRegister,
+        AllocatedRegister,
+        AllocatedRegister,
+    ),
 
     /* Non-VM Instructions */
     BLOB(VirtualImmediate24),
@@ -349,6 +355,7 @@ impl AllocatedOpcode {
             FLAG(_r1) => vec![],
             GM(r1, _imm) => vec![r1],
             GTF(r1, _r2, _i) => vec![r1],
+            ECAL(r1, _r2, _r3, _r4) => vec![r1],
 
             /* Non-VM Instructions */
             BLOB(_imm) => vec![],
@@ -471,6 +478,7 @@ impl fmt::Display for AllocatedOpcode {
             FLAG(a) => write!(fmtr, "flag {a}"),
             GM(a, b) => write!(fmtr, "gm   {a} {b}"),
             GTF(a, b, c) => write!(fmtr, "gtf  {a} {b} {c}"),
+            ECAL(a, b, c, d) => write!(fmtr, "ecal {a} {b} {c} {d}"),
 
             /* Non-VM Instructions */
             BLOB(a) => write!(fmtr, "blob {a}"),
@@ -652,6 +660,9 @@ impl AllocatedOp {
             FLAG(a) => op::FLAG::new(a.to_reg_id()).into(),
             GM(a, b) => op::GM::new(a.to_reg_id(), b.value.into()).into(),
             GTF(a, b, c) => op::GTF::new(a.to_reg_id(), b.to_reg_id(), c.value.into()).into(),
+            ECAL(a, b, c, d) => {
+                op::ECAL::new(a.to_reg_id(), b.to_reg_id(), c.to_reg_id(), d.to_reg_id()).into()
+            }
 
             /* Non-VM Instructions */
             BLOB(a) => {
@@ -581,6 +581,10 @@ impl Op {
                 let (r1, r2, imm) = two_regs_imm_12(handler, args, immediate, whole_op_span)?;
                 VirtualOp::GTF(r1, r2, imm)
             }
+            "ecal" => {
+                let (r1, r2, r3, r4) = four_regs(handler, args, immediate, whole_op_span)?;
+                VirtualOp::ECAL(r1, r2, r3, r4)
+            }
 
             /* Non-VM Instructions */
             "blob" => {
@@ -1099,6 +1103,7 @@ impl fmt::Display for VirtualOp {
             FLAG(a) => write!(fmtr, "flag {a}"),
             GM(a, b) => write!(fmtr, "gm {a} {b}"),
             GTF(a, b, c) => write!(fmtr, "gtf {a} {b} {c}"),
+            ECAL(a, b, c, d) => write!(fmtr, "ecal {a} {b} {c} {d}"),
 
             /* Non-VM Instructions */
             BLOB(a) => write!(fmtr, "blob {a}"),
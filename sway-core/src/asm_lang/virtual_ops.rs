@@ -192,6 +192,12 @@ pub(crate) enum VirtualOp {
     FLAG(VirtualRegister),
     GM(VirtualRegister, VirtualImmediate18),
     GTF(VirtualRegister, VirtualRegister, VirtualImmediate12),
+    ECAL(
+        VirtualRegister,
+        VirtualRegister,
+        VirtualRegister,
+        VirtualRegister,
+    ),
 
     /* Non-VM Instructions */
     BLOB(VirtualImmediate24),
@@ -308,6 +314,7 @@ impl VirtualOp {
             FLAG(r1) => vec![r1],
             GM(r1, _imm) => vec![r1],
             GTF(r1, r2, _i) => vec![r1, r2],
+            ECAL(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
 
             /* Non-VM Instructions */
             BLOB(_imm) => vec![],
@@ -424,6 +431,7 @@ impl VirtualOp {
             | K256(_, _, _)
             | S256(_, _, _)
             | FLAG(_)
+            | ECAL(_, _, _, _)
             // Virtual OPs
             | BLOB(_)
             | DataSectionOffsetPlaceholder
@@ -528,6 +536,7 @@ impl VirtualOp {
             | S256(_, _, _)
             | GM(_, _)
             | GTF(_, _, _)
+            | ECAL(_, _, _, _)
             | BLOB(_)
             | DataSectionOffsetPlaceholder
             | DataSectionRegisterLoadPlaceholder
@@ -640,6 +649,7 @@ impl VirtualOp {
             FLAG(r1) => vec![r1],
             GM(_r1, _imm) => vec![],
             GTF(_r1, r2, _i) => vec![r2],
+            ECAL(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
 
             /* Non-VM Instructions */
             BLOB(_imm) => vec![],
@@ -758,6 +768,7 @@ impl VirtualOp {
             FLAG(_r1) => vec![],
             GM(r1, _imm) => vec![r1],
             GTF(r1, _r2, _i) => vec![r1],
+            ECAL(r1, _r2, _r3, _r4) => vec![r1],
 
             /* Non-VM Instructions */
             BLOB(_imm) => vec![],
@@ -1186,6 +1197,12 @@ impl VirtualOp {
                 update_reg(reg_to_reg_map, r2),
                 i.clone(),
             ),
+            ECAL(r1, r2, r3, r4) => Self::ECAL(
+                update_reg(reg_to_reg_map, r1),
+                update_reg(reg_to_reg_map, r2),
+                update_reg(reg_to_reg_map, r3),
+                update_reg(reg_to_reg_map, r4),
+            ),
 
             /* Non-VM Instructions */
             BLOB(i) => Self::BLOB(i.clone()),
@@ -1643,6 +1660,12 @@ impl VirtualOp {
                 map_reg(&mapping, reg2),
                 imm.clone(),
             ),
+            ECAL(reg1, reg2, reg3, reg4) => AllocatedOpcode::ECAL(
+                map_reg(&mapping, reg1),
+                map_reg(&mapping, reg2),
+                map_reg(&mapping, reg3),
+                map_reg(&mapping, reg4),
+            ),
 
             /* Non-VM Instructions */
             BLOB(imm) => AllocatedOpcode::BLOB(imm.clone()),
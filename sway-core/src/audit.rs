@@ -0,0 +1,597 @@
+//! Security-oriented checks of programs, run by `forc audit`.
+//!
+//! The checks are heuristics run on the lexed program. Their findings point at code that is worth
+//! reviewing, rather than code that is known to be vulnerable.
+
+use crate::{
+    language::{
+        lexed::{LexedModule, LexedProgram},
+        HasModule, HasSubmodules,
+    },
+    lint::{visit_block, visit_if_condition},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use strum::EnumString;
+use sway_ast::{
+    expr::{LoopControlFlow, ReassignmentOpVariant},
+    AttributeDecl, CodeBlockContents, Expr, FnArgs, IfCondition, IfExpr, ItemFn, ItemImpl,
+    ItemImplItem, ItemKind, MatchBranchKind, Pattern, Statement, Ty,
+};
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::{
+    constants::{
+        ALLOW_ATTRIBUTE_NAME, ALLOW_UNBOUNDED_LOOP_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
+        STORAGE_PURITY_WRITE_NAME,
+    },
+    Ident, Span, Spanned,
+};
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditCheck {
+    /// Subtractions and divisions in functions handling assets, whose operands are not compared
+    /// beforehand.
+    UncheckedAssetArithmetic,
+    /// Contract methods writing to storage without checking their caller.
+    MissingAccessControl,
+    /// Authorization based on the owner of a transaction input, like `tx.origin` in Solidity.
+    InputOwnerAuthorization,
+    /// Loops in contract methods bounded by their arguments or by the length of a storage
+    /// collection, unless the methods are annotated with `#[allow(unbounded_loop)]`.
+    UnboundedLoop,
+    /// Calls to other contracts whose results are discarded.
+    UncheckedExternalCall,
+}
+
+impl AuditCheck {
+    pub const ALL: &'static [Self] = &[
+        Self::UncheckedAssetArithmetic,
+        Self::MissingAccessControl,
+        Self::InputOwnerAuthorization,
+        Self::UnboundedLoop,
+        Self::UncheckedExternalCall,
+    ];
+}
+
+/// The functions of the standard library that move, mint, burn or read the balances of assets.
+const ASSET_FNS: &[&str] = &[
+    "msg_amount",
+    "this_balance",
+    "balance_of",
+    "transfer",
+    "force_transfer_to_contract",
+    "transfer_to_address",
+    "mint",
+    "mint_to",
+    "mint_to_address",
+    "mint_to_contract",
+    "burn",
+];
+
+/// The functions of the standard library returning the owner of a transaction input.
+const INPUT_OWNER_FNS: &[&str] = &["input_coin_owner", "input_message_sender"];
+
+/// The functions of the standard library identifying the caller of a contract.
+const CALLER_FNS: &[&str] = &["msg_sender", "caller_address", "caller_contract_id"];
+
+/// Parts of the names of the functions that are assumed to restrict who can call a method.
+const ACCESS_CONTROL_NAME_PARTS: &[&str] = &["owner", "only", "auth", "admin", "role"];
+
+/// Runs the given checks on a program, returning their findings as warnings.
+pub fn audit(lexed: &LexedProgram, checks: &BTreeSet<AuditCheck>) -> Vec<CompileWarning> {
+    let mut auditor = Auditor {
+        checks,
+        findings: vec![],
+    };
+    let modules = std::iter::once(&lexed.root).chain(
+        lexed
+            .root
+            .submodules_recursive()
+            .map(|(_, submodule)| submodule.module()),
+    );
+    for module in modules {
+        auditor.audit_module(module);
+    }
+    auditor.findings
+}
+
+struct Auditor<'a> {
+    checks: &'a BTreeSet<AuditCheck>,
+    findings: Vec<CompileWarning>,
+}
+
+impl Auditor<'_> {
+    fn report(&mut self, check: AuditCheck, span: Span, message: String) {
+        if self.checks.contains(&check) {
+            self.findings.push(CompileWarning {
+                span,
+                warning_content: Warning::AuditFinding {
+                    name: check.to_string(),
+                    message,
+                },
+            });
+        }
+    }
+
+    fn audit_module(&mut self, module: &LexedModule) {
+        for item in &module.tree.items {
+            match &item.value {
+                ItemKind::Fn(item_fn) => self.audit_fn(item_fn, None),
+                ItemKind::Impl(item_impl) => {
+                    let is_contract = is_contract_impl(item_impl);
+                    for item in &item_impl.contents.inner {
+                        if let ItemImplItem::Fn(item_fn) = &item.value {
+                            let attributes = is_contract.then_some(&item.attribute_list[..]);
+                            self.audit_fn(item_fn, attributes);
+                        }
+                    }
+                }
+                ItemKind::Trait(item_trait) => {
+                    for item_fn in item_trait.trait_defs_opt.iter().flat_map(|d| &d.inner) {
+                        self.audit_fn(&item_fn.value, None);
+                    }
+                }
+                ItemKind::Abi(item_abi) => {
+                    for item_fn in item_abi.abi_defs_opt.iter().flat_map(|d| &d.inner) {
+                        self.audit_fn(&item_fn.value, None);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Audits a function, given the attributes of the method if it is a contract method.
+    fn audit_fn(&mut self, item_fn: &ItemFn, contract_method: Option<&[AttributeDecl]>) {
+        let body = &item_fn.body.inner;
+        let mut exprs = vec![];
+        visit_block(body, &mut |expr| exprs.push(expr));
+        let calls: Vec<(&Ident, &Expr)> = exprs
+            .iter()
+            .filter_map(|expr| call_name(expr).map(|name| (name, *expr)))
+            .collect();
+
+        for (name, call) in &calls {
+            if INPUT_OWNER_FNS.contains(&name.as_str()) {
+                self.report(
+                    AuditCheck::InputOwnerAuthorization,
+                    call.span(),
+                    format!(
+                        "\"{name}\" returns the owner of a transaction input, which is not \
+                         necessarily the caller of this code, much like `tx.origin` in Solidity. \
+                         Consider authorizing the caller with \"msg_sender\" instead."
+                    ),
+                );
+            }
+        }
+
+        if calls
+            .iter()
+            .any(|(name, _)| ASSET_FNS.contains(&name.as_str()))
+        {
+            self.audit_asset_arithmetic(&exprs);
+        }
+
+        self.audit_external_calls(body);
+
+        let Some(attributes) = contract_method else {
+            return;
+        };
+        let name = &item_fn.fn_signature.name;
+        let checks_caller = calls.iter().any(|(name, _)| {
+            let name = name.as_str();
+            CALLER_FNS.contains(&name)
+                || ACCESS_CONTROL_NAME_PARTS
+                    .iter()
+                    .any(|part| name.contains(part))
+        });
+        let writes_storage = has_attribute_arg(
+            attributes,
+            STORAGE_PURITY_ATTRIBUTE_NAME,
+            STORAGE_PURITY_WRITE_NAME,
+        );
+        if writes_storage && !checks_caller {
+            self.report(
+                AuditCheck::MissingAccessControl,
+                name.span(),
+                format!(
+                    "Contract method \"{name}\" writes to storage without checking its caller. \
+                     Consider restricting who can call it with \"msg_sender\", unless it is \
+                     meant to be called by anyone."
+                ),
+            );
+        }
+
+        if has_attribute_arg(attributes, ALLOW_ATTRIBUTE_NAME, ALLOW_UNBOUNDED_LOOP_NAME) {
+            return;
+        }
+        let args = fn_arg_names(&item_fn.fn_signature.arguments.inner);
+        for expr in &exprs {
+            let Expr::While {
+                while_token,
+                condition,
+                ..
+            } = expr
+            else {
+                continue;
+            };
+            if is_unbounded(condition, &args) {
+                self.report(
+                    AuditCheck::UnboundedLoop,
+                    Span::join(while_token.span(), condition.span()),
+                    format!(
+                        "The number of iterations of this loop in contract method \"{name}\" is \
+                         controlled by its caller or by the size of a storage collection, which \
+                         can make the method run out of gas. Consider bounding it."
+                    ),
+                );
+            }
+        }
+    }
+
+    fn audit_asset_arithmetic(&mut self, exprs: &[&Expr]) {
+        // The pairs of operands compared within the function.
+        let comparisons: Vec<(String, String)> = exprs
+            .iter()
+            .filter_map(|expr| match expr {
+                Expr::LessThan { lhs, rhs, .. }
+                | Expr::GreaterThan { lhs, rhs, .. }
+                | Expr::LessThanEq { lhs, rhs, .. }
+                | Expr::GreaterThanEq { lhs, rhs, .. }
+                | Expr::Equal { lhs, rhs, .. }
+                | Expr::NotEqual { lhs, rhs, .. } => Some((normalized(lhs), normalized(rhs))),
+                _ => None,
+            })
+            .collect();
+        let compared = |lhs: &str, rhs: &str| {
+            comparisons
+                .iter()
+                .any(|(a, b)| (a == lhs && b == rhs) || (a == rhs && b == lhs))
+        };
+        let compared_to_anything = |operand: &str| {
+            comparisons
+                .iter()
+                .any(|(a, b)| a == operand || b == operand)
+        };
+
+        for expr in exprs {
+            match expr {
+                Expr::Sub { lhs, rhs, .. } => {
+                    if !compared(&normalized(lhs), &normalized(rhs)) {
+                        self.report_underflow(expr.span(), lhs.span(), rhs.span());
+                    }
+                }
+                Expr::Reassignment {
+                    assignable,
+                    reassignment_op,
+                    expr: rhs,
+                } if matches!(reassignment_op.variant, ReassignmentOpVariant::SubEquals) => {
+                    if !compared(&normalized(assignable), &normalized(rhs)) {
+                        self.report_underflow(expr.span(), assignable.span(), rhs.span());
+                    }
+                }
+                Expr::Div { rhs, .. } => {
+                    if !matches!(**rhs, Expr::Literal(_)) && !compared_to_anything(&normalized(rhs))
+                    {
+                        self.report(
+                            AuditCheck::UncheckedAssetArithmetic,
+                            expr.span(),
+                            format!(
+                                "This division in asset math reverts without a meaningful error \
+                                 if \"{}\" is zero. Consider checking it with \"require\" first.",
+                                rhs.span().as_str()
+                            ),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn report_underflow(&mut self, span: Span, lhs: Span, rhs: Span) {
+        self.report(
+            AuditCheck::UncheckedAssetArithmetic,
+            span,
+            format!(
+                "This subtraction in asset math reverts without a meaningful error if \"{}\" is \
+                 less than \"{}\". Consider comparing them with \"require\" first.",
+                lhs.as_str(),
+                rhs.as_str()
+            ),
+        );
+    }
+
+    fn audit_external_calls(&mut self, body: &CodeBlockContents) {
+        let mut blocks = vec![body];
+        visit_block(body, &mut |expr| blocks.extend(nested_blocks(expr)));
+
+        // The variables holding contracts cast with `abi`.
+        let contracts: HashSet<&str> = blocks
+            .iter()
+            .flat_map(|block| &block.statements)
+            .filter_map(|statement| match statement {
+                Statement::Let(statement_let)
+                    if matches!(statement_let.expr, Expr::AbiCast { .. }) =>
+                {
+                    pattern_name(&statement_let.pattern).map(|name| name.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+
+        for statement in blocks.iter().flat_map(|block| &block.statements) {
+            let discarded = match statement {
+                Statement::Expr {
+                    expr,
+                    semicolon_token_opt: Some(_),
+                } => expr,
+                Statement::Let(statement_let)
+                    if matches!(statement_let.pattern, Pattern::Wildcard { .. }) =>
+                {
+                    &statement_let.expr
+                }
+                _ => continue,
+            };
+            let Expr::MethodCall {
+                target,
+                path_seg,
+                contract_args_opt,
+                ..
+            } = discarded
+            else {
+                continue;
+            };
+            let is_contract_call = contract_args_opt.is_some()
+                || match &**target {
+                    Expr::AbiCast { .. } => true,
+                    Expr::Path(path) => {
+                        path.suffix.is_empty() && contracts.contains(path.prefix.name.as_str())
+                    }
+                    _ => false,
+                };
+            if is_contract_call {
+                self.report(
+                    AuditCheck::UncheckedExternalCall,
+                    discarded.span(),
+                    format!(
+                        "The result of the call to the external contract method \"{}\" is \
+                         discarded. Consider checking that the call succeeded.",
+                        path_seg.name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the function or method called by the expression, if it is a call.
+fn call_name(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::FuncApp { func, .. } => match &**func {
+            Expr::Path(path) => Some(
+                path.suffix
+                    .last()
+                    .map(|(_, segment)| &segment.name)
+                    .unwrap_or(&path.prefix.name),
+            ),
+            _ => None,
+        },
+        Expr::MethodCall { path_seg, .. } => Some(&path_seg.name),
+        _ => None,
+    }
+}
+
+/// Returns the source code of the expression without whitespace, to compare expressions.
+fn normalized(spanned: &impl Spanned) -> String {
+    spanned
+        .span()
+        .as_str()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Returns `true` if the implementation is an implementation of an ABI for a contract.
+fn is_contract_impl(item_impl: &ItemImpl) -> bool {
+    item_impl.trait_opt.is_some()
+        && matches!(&item_impl.ty, Ty::Path(path) if path.last_segment().name.as_str() == "Contract")
+}
+
+/// Returns `true` if one of the attributes is named `name` and has the argument `arg`, like
+/// `#[storage(read, write)]` for `storage` and `write`.
+fn has_attribute_arg(attributes: &[AttributeDecl], name: &str, arg: &str) -> bool {
+    attributes
+        .iter()
+        .flat_map(|decl| &decl.attribute.inner)
+        .filter(|attribute| attribute.name.as_str() == name)
+        .flat_map(|attribute| attribute.args.iter().flat_map(|args| &args.inner))
+        .any(|attribute_arg| attribute_arg.name.as_str() == arg)
+}
+
+fn fn_arg_names(args: &FnArgs) -> HashSet<&str> {
+    let args = match args {
+        FnArgs::Static(args) => Some(args),
+        FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_, args)| args),
+    };
+    args.into_iter()
+        .flatten()
+        .filter_map(|arg| pattern_name(&arg.pattern))
+        .map(|name| name.as_str())
+        .collect()
+}
+
+fn pattern_name(pattern: &Pattern) -> Option<&Ident> {
+    match pattern {
+        Pattern::AmbiguousSingleIdent(name) | Pattern::Var { name, .. } => Some(name),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the condition of a loop refers to one of the arguments of the function, or
+/// to the length of a storage collection.
+fn is_unbounded(condition: &IfCondition, args: &HashSet<&str>) -> bool {
+    let mut unbounded = false;
+    visit_if_condition(condition, &mut |expr| match expr {
+        Expr::Path(path) if path.suffix.is_empty() => {
+            unbounded |= args.contains(path.prefix.name.as_str());
+        }
+        Expr::MethodCall {
+            target, path_seg, ..
+        } if path_seg.name.as_str() == "len" => {
+            unbounded |= is_storage_access(target);
+        }
+        _ => {}
+    });
+    unbounded
+}
+
+/// Returns `true` if the expression accesses a field of the storage, e.g. `storage.values`.
+fn is_storage_access(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(path) => path.suffix.is_empty() && path.prefix.name.as_str() == "storage",
+        Expr::FieldProjection { target, .. } | Expr::MethodCall { target, .. } => {
+            is_storage_access(target)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the blocks directly nested in the expression.
+fn nested_blocks(expr: &Expr) -> Vec<&CodeBlockContents> {
+    match expr {
+        Expr::Block(block) => vec![&block.inner],
+        Expr::While { block, .. } => vec![&block.inner],
+        Expr::If(if_expr) => if_blocks(if_expr),
+        Expr::Match { branches, .. } => branches
+            .inner
+            .iter()
+            .filter_map(|branch| match &branch.kind {
+                MatchBranchKind::Block { block, .. } => Some(&block.inner),
+                MatchBranchKind::Expr { .. } => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn if_blocks(if_expr: &IfExpr) -> Vec<&CodeBlockContents> {
+    let mut blocks = vec![&if_expr.then_block.inner];
+    match &if_expr.else_opt {
+        Some((_, LoopControlFlow::Break(block))) => blocks.push(&block.inner),
+        Some((_, LoopControlFlow::Continue(else_if))) => blocks.extend(if_blocks(else_if)),
+        None => {}
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::parsed::TreeType;
+    use std::sync::Arc;
+    use sway_error::handler::Handler;
+
+    fn findings(src: &str) -> Vec<String> {
+        let handler = Handler::default();
+        let module =
+            sway_parse::parse_file(&handler, Arc::from(src), None, Default::default()).unwrap();
+        let lexed = LexedProgram::new(
+            TreeType::Contract,
+            LexedModule {
+                tree: module.value,
+                submodules: vec![],
+            },
+        );
+        let checks = AuditCheck::ALL.iter().copied().collect();
+        audit(&lexed, &checks)
+            .into_iter()
+            .map(|warning| match warning.warning_content {
+                Warning::AuditFinding { name, .. } => name,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_audit() {
+        let findings = findings(
+            r#"
+            contract;
+
+            abi Vault {
+                #[storage(read, write)]
+                fn withdraw(amount: u64, recipients: Vec<Identity>);
+            }
+
+            impl Vault for Contract {
+                #[storage(read, write)]
+                fn withdraw(amount: u64, recipients: Vec<Identity>) {
+                    let balance = this_balance(AssetId::base());
+                    storage.balance.write(balance - amount);
+                    let mut i = 0;
+                    while i < recipients.len() {
+                        i += 1;
+                    }
+                    let other = abi(Vault, CONTRACT_ID);
+                    other.withdraw(amount, recipients);
+                }
+            }
+
+            fn sender() -> Option<Address> {
+                input_coin_owner(0)
+            }
+            "#,
+        );
+        assert_eq!(
+            findings,
+            [
+                "unchecked_asset_arithmetic",
+                "unchecked_external_call",
+                "missing_access_control",
+                "unbounded_loop",
+                "input_owner_authorization",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_checked() {
+        let findings = findings(
+            r#"
+            contract;
+
+            abi Vault {
+                #[storage(read, write)]
+                fn withdraw(amount: u64);
+            }
+
+            impl Vault for Contract {
+                #[storage(read, write)]
+                fn withdraw(amount: u64) {
+                    require(msg_sender().unwrap() == storage.owner.read(), "not the owner");
+                    let balance = this_balance(AssetId::base());
+                    require(balance >= amount, "insufficient balance");
+                    storage.balance.write(balance - amount);
+                }
+            }
+            "#,
+        );
+        assert!(findings.is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
+use sway_ir::ExecutionProfile;
 
 #[derive(
     Clone,
@@ -51,8 +52,15 @@ pub struct BuildConfig {
     pub(crate) print_intermediate_asm: bool,
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
+    pub(crate) print_asm_stats: bool,
     pub(crate) include_tests: bool,
+    pub(crate) incremental: bool,
+    pub(crate) parallel_type_check: bool,
     pub(crate) optimization_level: OptLevel,
+    pub(crate) overflow_checks: bool,
+    pub(crate) execution_profile: Option<Arc<ExecutionProfile>>,
+    pub(crate) inline_budget: Option<u64>,
+    pub(crate) dse_storage: bool,
     pub time_phases: bool,
     pub metrics_outfile: Option<String>,
     pub experimental: ExperimentalFlags,
@@ -97,10 +105,17 @@ impl BuildConfig {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            print_asm_stats: false,
             include_tests: false,
+            incremental: false,
+            parallel_type_check: false,
             time_phases: false,
             metrics_outfile: None,
             optimization_level: OptLevel::Opt0,
+            overflow_checks: true,
+            execution_profile: None,
+            inline_budget: None,
+            dse_storage: true,
             experimental: ExperimentalFlags::default(),
         }
     }
@@ -140,6 +155,13 @@ impl BuildConfig {
         }
     }
 
+    pub fn with_print_asm_stats(self, a: bool) -> Self {
+        Self {
+            print_asm_stats: a,
+            ..self
+        }
+    }
+
     pub fn with_time_phases(self, a: bool) -> Self {
         Self {
             time_phases: a,
@@ -161,6 +183,51 @@ impl BuildConfig {
         }
     }
 
+    /// Whether or not overflowing arithmetic reverts, rather than wrapping around.
+    ///
+    /// Default: `true`
+    pub fn with_overflow_checks(self, overflow_checks: bool) -> Self {
+        Self {
+            overflow_checks,
+            ..self
+        }
+    }
+
+    /// The execution profile of the program, measured while running real workloads, towards
+    /// whose hot paths the optimizations are biased.
+    ///
+    /// Default: `None`
+    pub fn with_execution_profile(self, execution_profile: Option<Arc<ExecutionProfile>>) -> Self {
+        Self {
+            execution_profile,
+            ..self
+        }
+    }
+
+    /// The number of instructions the bytecode may grow by when inlining functions, if limited.
+    ///
+    /// Calls are always inlined if it doesn't grow the bytecode, as estimated by the compiler,
+    /// so this only limits inlining the calls worth it anyway, e.g. calls to hot functions.
+    ///
+    /// Default: `None`
+    pub fn with_inline_budget(self, inline_budget: Option<u64>) -> Self {
+        Self {
+            inline_budget,
+            ..self
+        }
+    }
+
+    /// Whether to remove the storage writes which are overwritten before being read, when
+    /// optimizing.
+    ///
+    /// Default: `true`
+    pub fn with_dse_storage(self, dse_storage: bool) -> Self {
+        Self {
+            dse_storage,
+            ..self
+        }
+    }
+
     /// Whether or not to include test functions in parsing, type-checking and codegen.
     ///
     /// This should be set to `true` by invocations like `forc test` or `forc check --tests`.
@@ -173,6 +240,30 @@ impl BuildConfig {
         }
     }
 
+    /// Whether to reuse the typed submodules of previous compilations with the same engines, when
+    /// neither they nor the modules they may depend on have changed.
+    ///
+    /// This should be set to `true` by invocations compiling the same packages repeatedly, like
+    /// the language server.
+    ///
+    /// Default: `false`
+    pub fn with_incremental(self, incremental: bool) -> Self {
+        Self {
+            incremental,
+            ..self
+        }
+    }
+
+    /// Whether to type-check the submodules which don't depend on each other in parallel.
+    ///
+    /// Default: `false`
+    pub fn with_parallel_type_check(self, parallel_type_check: bool) -> Self {
+        Self {
+            parallel_type_check,
+            ..self
+        }
+    }
+
     pub fn with_experimental(self, experimental: ExperimentalFlags) -> Self {
         Self {
             experimental,
@@ -185,9 +276,72 @@ impl BuildConfig {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ExperimentalFlags {
     pub new_encoding: bool,
+    pub test_only: bool,
+}
+
+impl ExperimentalFlags {
+    pub fn is_enabled(&self, feature: ExperimentalFeature) -> bool {
+        match feature {
+            ExperimentalFeature::NewEncoding => self.new_encoding,
+            ExperimentalFeature::TestOnly => self.test_only,
+        }
+    }
+
+    pub fn enable(&mut self, feature: ExperimentalFeature) {
+        match feature {
+            ExperimentalFeature::NewEncoding => self.new_encoding = true,
+            ExperimentalFeature::TestOnly => self.test_only = true,
+        }
+    }
+}
+
+/// A named experimental feature.
+///
+/// Packages opt into a feature through the `experimental` field of their manifest, and items
+/// can be gated on it with `#[cfg(experimental_<feature> = true)]`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExperimentalFeature {
+    NewEncoding,
+    /// Changes nothing, and only exists to test how experimental features are enabled and
+    /// checked without depending on a real feature, which is eventually stabilized.
+    TestOnly,
+}
+
+impl ExperimentalFeature {
+    pub const ALL: &'static [Self] = &[Self::NewEncoding, Self::TestOnly];
+
+    /// The name of the `cfg` attribute argument gating items on this feature.
+    pub fn cfg_name(&self) -> &'static str {
+        match self {
+            Self::NewEncoding => sway_types::constants::CFG_EXPERIMENTAL_NEW_ENCODING,
+            Self::TestOnly => sway_types::constants::CFG_EXPERIMENTAL_TEST_ONLY,
+        }
+    }
+
+    pub fn from_cfg_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|feature| feature.cfg_name() == name)
+    }
 }
 
 #[cfg(test)]
@@ -214,4 +368,22 @@ mod test {
             BuildTarget::default(),
         );
     }
+
+    #[test]
+    fn test_experimental_features() {
+        let feature = ExperimentalFeature::TestOnly;
+        assert_eq!(feature.to_string(), "test_only");
+        assert_eq!("test_only".parse::<ExperimentalFeature>(), Ok(feature));
+        assert_eq!(
+            ExperimentalFeature::from_cfg_name("experimental_test_only"),
+            Some(feature)
+        );
+        assert_eq!(ExperimentalFeature::from_cfg_name("test_only"), None);
+
+        let mut experimental = ExperimentalFlags::default();
+        assert!(!experimental.is_enabled(feature));
+        experimental.enable(feature);
+        assert!(experimental.is_enabled(feature));
+        assert!(!experimental.is_enabled(ExperimentalFeature::NewEncoding));
+    }
 }
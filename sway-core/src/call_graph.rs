@@ -0,0 +1,427 @@
+//! The call graph of a program, along with the contracts called and the storage fields accessed
+//! by each of its functions.
+//!
+//! Storage accesses are found at the calls that take a storage field as an argument, like
+//! `storage.balances.insert(key, value)`, and are classified according to the purity of the
+//! called function: calls to functions that may write to storage are writes, and calls to
+//! functions that only read from it are reads. Only the functions declared in the program
+//! itself are part of the graph, the functions of its dependencies are not.
+
+use crate::{
+    language::{
+        ty::{self, TyDecl, TyImplItem, TyVisitor},
+        Purity,
+    },
+    transform::AttributeKind,
+    Engines, TypeInfo,
+};
+use std::collections::{BTreeSet, HashMap};
+use sway_types::{Span, Spanned};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageAccess {
+    /// The path of the storage field, like `balances` or `config.owner`.
+    pub field: String,
+    pub kind: StorageAccessKind,
+    /// The span of the call accessing the field.
+    pub span: Span,
+}
+
+#[derive(Clone, Debug)]
+pub struct FunctionNode {
+    /// The name of the function. Methods are qualified with their implementing type, except for
+    /// contract methods.
+    pub name: String,
+    pub span: Span,
+    /// Whether the function is a contract method, or the `main` function of a script or
+    /// predicate.
+    pub is_entry: bool,
+    /// The indices in [CallGraph::functions] of the functions called by this function.
+    pub calls: BTreeSet<usize>,
+    /// The methods of other contracts called by this function, like `Token::transfer`.
+    pub contract_calls: BTreeSet<String>,
+    /// The storage accesses within the body of this function, in order.
+    pub storage_accesses: Vec<StorageAccess>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CallGraph {
+    pub functions: Vec<FunctionNode>,
+}
+
+impl CallGraph {
+    pub fn new(engines: &Engines, program: &ty::TyProgram) -> Self {
+        let decl_engine = engines.de();
+        let main_span = match &program.kind {
+            ty::TyProgramKind::Script { main_function }
+            | ty::TyProgramKind::Predicate { main_function } => {
+                Some(decl_engine.get_function(main_function).span.clone())
+            }
+            _ => None,
+        };
+
+        let modules = std::iter::once(&program.root).chain(
+            program
+                .root
+                .submodules_recursive()
+                .map(|(_, submodule)| &submodule.module),
+        );
+        let mut fn_decls = vec![];
+        for node in modules.flat_map(|module| module.all_nodes.iter()) {
+            match &node.content {
+                ty::TyAstNodeContent::Declaration(TyDecl::FunctionDecl(ty::FunctionDecl {
+                    decl_id,
+                    ..
+                })) => {
+                    let fn_decl = decl_engine.get_function(decl_id);
+                    let is_entry = main_span.as_ref() == Some(&fn_decl.span);
+                    fn_decls.push((fn_decl.name.to_string(), is_entry, fn_decl));
+                }
+                ty::TyAstNodeContent::Declaration(TyDecl::ImplTrait(ty::ImplTrait {
+                    decl_id,
+                    ..
+                })) => {
+                    let impl_trait = decl_engine.get_impl_trait(decl_id);
+                    let is_contract = matches!(
+                        &*engines.te().get(impl_trait.implementing_for.type_id),
+                        TypeInfo::Contract
+                    );
+                    for item in impl_trait.items.iter() {
+                        let TyImplItem::Fn(decl_ref) = item else {
+                            continue;
+                        };
+                        let fn_decl = decl_engine.get_function(decl_ref);
+                        let name = if is_contract {
+                            fn_decl.name.to_string()
+                        } else {
+                            format!(
+                                "{}::{}",
+                                engines.help_out(impl_trait.implementing_for.type_id),
+                                fn_decl.name
+                            )
+                        };
+                        fn_decls.push((name, is_contract, fn_decl));
+                    }
+                }
+                _ => {}
+            }
+        }
+        fn_decls.retain(|(_, _, fn_decl)| !fn_decl.attributes.contains_key(&AttributeKind::Test));
+
+        // Calls refer to the monomorphized copies of generic functions, which share the span of
+        // their declaration.
+        let indices: HashMap<Span, usize> = fn_decls
+            .iter()
+            .enumerate()
+            .map(|(index, (_, _, fn_decl))| (fn_decl.span.clone(), index))
+            .collect();
+        let functions = fn_decls
+            .iter()
+            .map(|(name, is_entry, fn_decl)| {
+                let mut visitor = FunctionVisitor {
+                    indices: &indices,
+                    node: FunctionNode {
+                        name: name.clone(),
+                        span: fn_decl.name.span(),
+                        is_entry: *is_entry,
+                        calls: BTreeSet::new(),
+                        contract_calls: BTreeSet::new(),
+                        storage_accesses: vec![],
+                    },
+                };
+                ty::walk_function_decl(&mut visitor, engines, fn_decl);
+                visitor.node
+            })
+            .collect();
+        Self { functions }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &FunctionNode> {
+        self.functions.iter().filter(|function| function.is_entry)
+    }
+
+    /// Returns the storage accesses of the function at `index` and of all of the functions it
+    /// calls, directly or not.
+    pub fn storage_accesses(&self, index: usize) -> Vec<&StorageAccess> {
+        let mut visited = BTreeSet::from([index]);
+        let mut stack = vec![index];
+        let mut accesses = vec![];
+        while let Some(index) = stack.pop() {
+            let function = &self.functions[index];
+            accesses.extend(function.storage_accesses.iter());
+            for callee in function.calls.iter() {
+                if visited.insert(*callee) {
+                    stack.push(*callee);
+                }
+            }
+        }
+        accesses
+    }
+}
+
+/// Collects the calls and the storage accesses within the body of a function.
+struct FunctionVisitor<'a> {
+    indices: &'a HashMap<Span, usize>,
+    node: FunctionNode,
+}
+
+impl TyVisitor for FunctionVisitor<'_> {
+    fn visit_expression(&mut self, engines: &Engines, expr: &ty::TyExpression) {
+        if let ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            fn_ref,
+            selector,
+            ..
+        } = &expr.expression
+        {
+            let callee = engines.de().get_function(fn_ref);
+            if callee.is_contract_call || selector.is_some() {
+                let name = match &callee.implementing_type {
+                    Some(TyDecl::AbiDecl(abi_decl)) => {
+                        format!("{}::{}", abi_decl.name, callee.name)
+                    }
+                    _ => callee.name.to_string(),
+                };
+                self.node.contract_calls.insert(name);
+            } else if let Some(index) = self.indices.get(&callee.span) {
+                self.node.calls.insert(*index);
+            }
+
+            let kind = match callee.purity {
+                Purity::Pure => None,
+                Purity::Reads => Some(StorageAccessKind::Read),
+                Purity::Writes | Purity::ReadsWrites => Some(StorageAccessKind::Write),
+            };
+            if let Some(kind) = kind {
+                for (_, arg) in arguments.iter() {
+                    if let Some(field) = storage_field(engines, arg) {
+                        self.node.storage_accesses.push(StorageAccess {
+                            field,
+                            kind,
+                            span: expr.span.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        ty::walk_expression(self, engines, expr);
+    }
+}
+
+/// Returns the path of the storage field the expression refers to, like `balances` for
+/// `storage.balances`, or for a storage key derived from it without accessing storage, like
+/// `storage.balances.get(key)`.
+fn storage_field(engines: &Engines, expr: &ty::TyExpression) -> Option<String> {
+    match &expr.expression {
+        ty::TyExpressionVariant::StorageAccess(access) => Some(
+            access
+                .fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>()
+                .join("."),
+        ),
+        ty::TyExpressionVariant::FunctionApplication {
+            arguments, fn_ref, ..
+        } if engines.de().get_function(fn_ref).purity == Purity::Pure => arguments
+            .first()
+            .and_then(|(_, arg)| storage_field(engines, arg)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{namespace, test_utils};
+
+    /// Type checks the program and returns its call graph.
+    fn call_graph(src: &str) -> CallGraph {
+        let engines = Engines::default();
+        let program = test_utils::compile_to_ast(
+            &engines,
+            src,
+            namespace::Module::default(),
+            Some(&test_utils::source_build_config("call-graph")),
+        );
+        CallGraph::new(&engines, &program)
+    }
+
+    fn index_of(call_graph: &CallGraph, name: &str) -> usize {
+        call_graph
+            .functions
+            .iter()
+            .position(|function| function.name == name)
+            .unwrap_or_else(|| panic!("no function named `{name}`"))
+    }
+
+    fn callees<'a>(call_graph: &'a CallGraph, name: &str) -> Vec<&'a str> {
+        call_graph.functions[index_of(call_graph, name)]
+            .calls
+            .iter()
+            .map(|callee| call_graph.functions[*callee].name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn calls_to_functions_and_methods() {
+        let call_graph = call_graph(
+            r#"script;
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn new() -> Counter {
+        Counter { value: zero() }
+    }
+}
+
+trait Get {
+    fn get(self) -> u64;
+}
+
+impl Get for Counter {
+    fn get(self) -> u64 {
+        self.value
+    }
+}
+
+fn zero() -> u64 {
+    0
+}
+
+fn unused() {}
+
+fn main() -> u64 {
+    let counter = Counter::new();
+    counter.get()
+}
+"#,
+        );
+
+        assert_eq!(
+            callees(&call_graph, "main"),
+            ["Counter::new", "Counter::get"]
+        );
+        assert_eq!(callees(&call_graph, "Counter::new"), ["zero"]);
+        assert!(callees(&call_graph, "Counter::get").is_empty());
+        assert!(callees(&call_graph, "unused").is_empty());
+        let entries: Vec<_> = call_graph.entries().map(|f| f.name.as_str()).collect();
+        assert_eq!(entries, ["main"]);
+    }
+
+    #[test]
+    fn recursive_calls() {
+        let call_graph = call_graph(
+            r#"script;
+
+#[tail_call]
+fn spin(stop: bool) -> u64 {
+    if stop {
+        0
+    } else {
+        spin(true)
+    }
+}
+
+fn main() -> u64 {
+    spin(false)
+}
+"#,
+        );
+
+        assert_eq!(callees(&call_graph, "spin"), ["spin"]);
+        assert_eq!(callees(&call_graph, "main"), ["spin"]);
+    }
+
+    #[test]
+    fn calls_to_other_contracts() {
+        let call_graph = call_graph(
+            r#"script;
+
+abi Token {
+    fn transfer(amount: u64);
+}
+
+fn main() {
+    let token = abi(Token, 0x0000000000000000000000000000000000000000000000000000000000000000);
+    token.transfer(1);
+}
+"#,
+        );
+
+        let main = &call_graph.functions[index_of(&call_graph, "main")];
+        assert!(main.calls.is_empty());
+        assert_eq!(
+            main.contract_calls.iter().collect::<Vec<_>>(),
+            ["Token::transfer"]
+        );
+    }
+
+    fn function(
+        name: &str,
+        calls: &[usize],
+        accesses: &[(&str, StorageAccessKind)],
+    ) -> FunctionNode {
+        FunctionNode {
+            name: name.to_string(),
+            span: Span::dummy(),
+            is_entry: false,
+            calls: calls.iter().copied().collect(),
+            contract_calls: BTreeSet::new(),
+            storage_accesses: accesses
+                .iter()
+                .map(|(field, kind)| StorageAccess {
+                    field: field.to_string(),
+                    kind: *kind,
+                    span: Span::dummy(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn storage_accesses_of_callees_are_included_once() {
+        use StorageAccessKind::*;
+        // `a` calls `b` and `c`, which call each other, and `c` calls itself.
+        let call_graph = CallGraph {
+            functions: vec![
+                function("a", &[1, 2], &[("owner", Read)]),
+                function("b", &[2], &[("balances", Write)]),
+                function("c", &[1, 2], &[("config.paused", Read)]),
+                function("d", &[], &[("unreachable", Write)]),
+            ],
+        };
+
+        let mut accesses: Vec<_> = call_graph
+            .storage_accesses(0)
+            .into_iter()
+            .map(|access| (access.field.as_str(), access.kind))
+            .collect();
+        accesses.sort();
+        assert_eq!(
+            accesses,
+            [
+                ("balances", Write),
+                ("config.paused", Read),
+                ("owner", Read)
+            ]
+        );
+
+        let accesses: Vec<_> = call_graph
+            .storage_accesses(2)
+            .into_iter()
+            .map(|access| access.field.as_str())
+            .collect();
+        assert_eq!(accesses.len(), 2);
+        assert!(accesses.contains(&"balances") && accesses.contains(&"config.paused"));
+    }
+}
@@ -15,6 +15,10 @@ pub fn is_generated_tuple_var_name(name: &str) -> bool {
     name.starts_with(TUPLE_VAR_NAME_PREFIX)
 }
 
+/// The name of the compiler generated variable that stores the result of a test, so that it can
+/// be returned after the `#[after_each]` functions of its module run.
+pub(crate) const TEST_RESULT_VAR_NAME: &str = "__test_result";
+
 /// The prefix for the compiler generated names of structs used in destructuring
 /// structs in `let` statements.
 const DESTRUCTURED_STRUCT_VAR_NAME_PREFIX: &str = "__destructured_struct_";
@@ -1,6 +1,8 @@
 use std::{
+    cell::RefCell,
     collections::HashMap,
     fmt,
+    ops::Range,
     sync::{Arc, RwLock},
 };
 
@@ -9,7 +11,30 @@ use itertools::Itertools;
 #[derive(Debug)]
 pub(crate) struct ConcurrentSlab<T> {
     inner: RwLock<HashMap<usize, Arc<T>>>,
-    last_id: Arc<RwLock<usize>>,
+    ids: Arc<RwLock<Ids>>,
+}
+
+/// The ids handed out by a slab, shared with its clones so that an id is never handed out twice.
+#[derive(Debug, Default)]
+struct Ids {
+    /// The last id handed out.
+    last: usize,
+    /// The ranges of ids below `last` which were never handed out, in order: the ids reserved for
+    /// [InsertionScope]s but left unused by them.
+    skipped: Vec<Range<usize>>,
+}
+
+impl Ids {
+    /// The position of `id` among the ids handed out, skipping the ids which never were.
+    fn dense_index(&self, id: usize) -> usize {
+        let skipped: usize = self
+            .skipped
+            .iter()
+            .take_while(|range| range.end <= id)
+            .map(|range| range.len())
+            .sum();
+        id - skipped
+    }
 }
 
 impl<T> Clone for ConcurrentSlab<T>
@@ -20,7 +45,7 @@ where
         let inner = self.inner.read().unwrap();
         Self {
             inner: RwLock::new(inner.clone()),
-            last_id: self.last_id.clone(),
+            ids: self.ids.clone(),
         }
     }
 }
@@ -29,7 +54,7 @@ impl<T> Default for ConcurrentSlab<T> {
     fn default() -> Self {
         Self {
             inner: Default::default(),
-            last_id: Default::default(),
+            ids: Default::default(),
         }
     }
 }
@@ -64,19 +89,31 @@ where
     }
 
     pub fn insert(&self, value: T) -> usize {
-        let mut inner = self.inner.write().unwrap();
-        let mut last_id = self.last_id.write().unwrap();
-        *last_id += 1;
-        inner.insert(*last_id, Arc::new(value));
-        *last_id
+        self.insert_arc(Arc::new(value))
     }
 
     pub fn insert_arc(&self, value: Arc<T>) -> usize {
+        let id = self.next_id();
         let mut inner = self.inner.write().unwrap();
-        let mut last_id = self.last_id.write().unwrap();
-        *last_id += 1;
-        inner.insert(*last_id, value);
-        *last_id
+        inner.insert(id, value);
+        id
+    }
+
+    fn next_id(&self) -> usize {
+        InsertionScope::next_id(&self.ids).unwrap_or_else(|| {
+            let mut ids = self.ids.write().unwrap();
+            ids.last += 1;
+            ids.last
+        })
+    }
+
+    /// Returns the position of `index` among the ids handed out by the slab.
+    ///
+    /// Unlike the ids themselves, the positions are dense: they don't have the gaps left between
+    /// the ids reserved for the [InsertionScope]s of a batch, so they are what is shown outside
+    /// of the compiler, e.g. as the type ids of the ABI.
+    pub fn dense_index(&self, index: usize) -> usize {
+        self.ids.read().unwrap().dense_index(index)
     }
 
     pub fn replace(&self, index: usize, new_value: T) -> Option<T> {
@@ -95,3 +132,171 @@ where
         inner.retain(predicate);
     }
 }
+
+/// The number of ids reserved in each slab for the values inserted within an [InsertionScope].
+const INSERTION_SCOPE_IDS: usize = 1 << (usize::BITS / 2);
+
+thread_local! {
+    static INSERTION_SCOPE: RefCell<Option<InsertionScope>> = const { RefCell::new(None) };
+}
+
+/// A range of ids reserved in each slab for the values inserted by the current thread while it
+/// runs one of the tasks of a batch, e.g. the type-checking of one of several submodules which
+/// don't depend on each other.
+///
+/// The ids of the values inserted by a task only depend on its position within the batch, rather
+/// than on how its inserts interleave with those of the other tasks, so the ids are the same
+/// whether the tasks run in parallel or one after another.
+pub(crate) struct InsertionScope {
+    index: usize,
+    /// The ids used within the scope in each slab values were inserted into, by the address of
+    /// the ids of the slab.
+    scoped_ids: HashMap<usize, ScopedIds>,
+}
+
+/// The ids used within an [InsertionScope] in one slab.
+struct ScopedIds {
+    ids: Arc<RwLock<Ids>>,
+    /// The id the range reserved for the scope starts after.
+    start: usize,
+    /// The last id used within the scope.
+    used: usize,
+}
+
+impl InsertionScope {
+    /// Runs `f` within the insertion scope of the task at `index` within its batch.
+    ///
+    /// Returns the scope along with the result of `f`. It must be committed once all the tasks of
+    /// the batch are done, before values are inserted outside of insertion scopes again.
+    pub(crate) fn run<R>(index: usize, f: impl FnOnce() -> R) -> (R, InsertionScope) {
+        INSERTION_SCOPE.with(|scope| {
+            let scope = scope.replace(Some(InsertionScope {
+                index,
+                scoped_ids: HashMap::new(),
+            }));
+            assert!(scope.is_none(), "insertion scopes cannot be nested");
+        });
+        let result = f();
+        let scope = INSERTION_SCOPE.with(|scope| scope.take());
+        (result, scope.expect("insertion scope was removed"))
+    }
+
+    /// The position within its batch of the task the current thread is running, if it's running
+    /// one within an insertion scope.
+    pub(crate) fn current_index() -> Option<usize> {
+        INSERTION_SCOPE.with(|scope| scope.borrow().as_ref().map(|scope| scope.index))
+    }
+
+    /// Advances the counters of the slabs past the ids used within this scope.
+    ///
+    /// The ids reserved for the scopes of the batch before this one and left unused are recorded
+    /// as skipped, so that the ids are compacted when shown outside of the compiler, see
+    /// [ConcurrentSlab::dense_index]. The scopes of a batch must be committed in order.
+    pub(crate) fn commit(self) {
+        for ScopedIds { ids, start, used } in self.scoped_ids.into_values() {
+            let mut ids = ids.write().unwrap();
+            assert!(
+                ids.last <= start,
+                "Internal error. Insertion scopes must be committed in order."
+            );
+            if ids.last < start {
+                let skipped = ids.last + 1..start + 1;
+                ids.skipped.push(skipped);
+            }
+            ids.last = used;
+        }
+    }
+
+    /// The next id of the slab with the given ids, if the current thread is running within an
+    /// insertion scope.
+    ///
+    /// The counters are only advanced when the scopes are committed, so the range of a scope
+    /// starts after the ids used before its batch.
+    ///
+    /// # Panics
+    ///
+    /// If the scope uses up the [INSERTION_SCOPE_IDS] ids reserved for it, which would collide
+    /// with those of the next scope.
+    fn next_id(ids: &Arc<RwLock<Ids>>) -> Option<usize> {
+        INSERTION_SCOPE.with(|scope| {
+            let mut scope = scope.borrow_mut();
+            let scope = scope.as_mut()?;
+            let index = scope.index;
+            let scoped_ids = scope
+                .scoped_ids
+                .entry(Arc::as_ptr(ids) as usize)
+                .or_insert_with(|| {
+                    let start = index
+                        .checked_mul(INSERTION_SCOPE_IDS)
+                        .and_then(|offset| offset.checked_add(ids.read().unwrap().last))
+                        .expect("Internal error. Too many insertion scopes in a batch.");
+                    ScopedIds {
+                        ids: ids.clone(),
+                        start,
+                        used: start,
+                    }
+                });
+            assert!(
+                scoped_ids.used - scoped_ids.start < INSERTION_SCOPE_IDS,
+                "Internal error. More than {INSERTION_SCOPE_IDS} values were inserted into a slab \
+                 within an insertion scope."
+            );
+            scoped_ids.used += 1;
+            Some(scoped_ids.used)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_of_batches_are_compacted() {
+        let slab = ConcurrentSlab::default();
+        let before = slab.insert("before");
+
+        let (first, first_scope) =
+            InsertionScope::run(0, || [slab.insert("first"), slab.insert("second")]);
+        let ((), empty_scope) = InsertionScope::run(1, || ());
+        let (third, third_scope) = InsertionScope::run(2, || slab.insert("third"));
+        first_scope.commit();
+        empty_scope.commit();
+        third_scope.commit();
+        let after = slab.insert("after");
+
+        // The ids reserved for the scopes of the batch leave gaps, which the dense indices don't
+        // have.
+        assert_eq!(first, [before + 1, before + 2]);
+        assert_eq!(third, before + 2 * INSERTION_SCOPE_IDS + 1);
+        assert_eq!(after, third + 1);
+        let ids = [before, first[0], first[1], third, after];
+        assert_eq!(ids.map(|id| slab.dense_index(id)), [1, 2, 3, 4, 5]);
+        assert_eq!(
+            ids.map(|id| *slab.get(id)),
+            ["before", "first", "second", "third", "after"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "within an insertion scope")]
+    fn overflowing_an_insertion_scope_panics() {
+        let slab = ConcurrentSlab::default();
+        InsertionScope::run(0, || {
+            slab.insert(0);
+            INSERTION_SCOPE.with(|scope| {
+                for scoped_ids in scope.borrow_mut().as_mut().unwrap().scoped_ids.values_mut() {
+                    scoped_ids.used = scoped_ids.start + INSERTION_SCOPE_IDS;
+                }
+            });
+            slab.insert(1);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many insertion scopes")]
+    fn too_many_insertion_scopes_panic() {
+        let slab = ConcurrentSlab::default();
+        InsertionScope::run(usize::MAX, || slab.insert(0));
+    }
+}
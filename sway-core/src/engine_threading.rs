@@ -12,6 +12,7 @@ pub struct Engines {
     decl_engine: DeclEngine,
     query_engine: QueryEngine,
     source_engine: SourceEngine,
+    verbose_types: bool,
 }
 
 impl Engines {
@@ -26,6 +27,7 @@ impl Engines {
             decl_engine,
             query_engine,
             source_engine,
+            verbose_types: false,
         }
     }
 
@@ -45,11 +47,51 @@ impl Engines {
         &self.source_engine
     }
 
+    /// Whether types are displayed with their fully-qualified names and all of their generic
+    /// arguments, rather than abbreviated.
+    pub fn verbose_types(&self) -> bool {
+        self.verbose_types
+    }
+
+    pub fn set_verbose_types(&mut self, verbose_types: bool) {
+        self.verbose_types = verbose_types;
+    }
+
     /// Removes all data associated with `module_id` from the declaration and type engines.
     /// It is intended to be used during garbage collection to remove any data that is no longer needed.
+    ///
+    /// The typed modules cached for incremental type-checking are cleared too, as they may refer
+    /// to the removed data.
     pub fn clear_module(&mut self, module_id: &sway_types::ModuleId) {
         self.type_engine.clear_module(module_id);
         self.decl_engine.clear_module(module_id);
+        self.query_engine.typed_module_cache().clear();
+    }
+
+    /// Takes a snapshot of the type and declaration engines, which [Engines::rollback] restores
+    /// them to. This allows speculatively type-checking an edit, e.g. a changed function signature,
+    /// and then discarding everything it inserted into the engines.
+    ///
+    /// Taking a snapshot only clones the maps from ids to the shared types and declarations, not
+    /// the types and declarations themselves.
+    pub fn snapshot(&self) -> EnginesSnapshot {
+        EnginesSnapshot {
+            type_engine: self.type_engine.clone(),
+            decl_engine: self.decl_engine.clone(),
+        }
+    }
+
+    /// Restores the type and declaration engines to the given snapshot, discarding the types and
+    /// declarations inserted, and the changes made to them, since it was taken.
+    ///
+    /// The ids handed out since the snapshot are never handed out again, so any of them that is
+    /// left over can't be confused with a type or declaration inserted after the rollback. The
+    /// typed modules cached for incremental type-checking are cleared, as they may refer to the
+    /// discarded data.
+    pub fn rollback(&mut self, snapshot: EnginesSnapshot) {
+        self.type_engine = snapshot.type_engine;
+        self.decl_engine = snapshot.decl_engine;
+        self.query_engine.typed_module_cache().clear();
     }
 
     /// Helps out some `thing: T` by adding `self` as context.
@@ -61,6 +103,13 @@ impl Engines {
     }
 }
 
+/// The state of the type and declaration engines at some point, see [Engines::snapshot].
+#[derive(Clone, Debug)]
+pub struct EnginesSnapshot {
+    type_engine: TypeEngine,
+    decl_engine: DeclEngine,
+}
+
 #[derive(Clone, Copy)]
 pub struct WithEngines<'a, T> {
     pub thing: T,
@@ -312,3 +361,84 @@ where
         state.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        decl_engine::{DeclEngineGet, DeclEngineInsert, DeclEngineReplace},
+        language::{ty::TyTraitDecl, Visibility},
+        type_system::{TypeInfo, TypeParameter},
+    };
+    use sway_types::{Ident, Span};
+
+    #[test]
+    fn rollback_discards_speculative_types() {
+        let mut engines = Engines::default();
+        let unit = engines.te().insert(&engines, TypeInfo::Tuple(vec![]), None);
+
+        let snapshot = engines.snapshot();
+        let speculative = engines.te().insert(&engines, TypeInfo::Boolean, None);
+        assert_eq!(
+            engines.te().insert(&engines, TypeInfo::Boolean, None),
+            speculative
+        );
+        engines.rollback(snapshot);
+
+        // The types inserted before the snapshot are kept, while those inserted after it are
+        // inserted anew, with fresh ids.
+        assert_eq!(
+            engines.te().insert(&engines, TypeInfo::Tuple(vec![]), None),
+            unit
+        );
+        let boolean = engines.te().insert(&engines, TypeInfo::Boolean, None);
+        assert_ne!(boolean, speculative);
+        assert!(matches!(*engines.te().get(boolean), TypeInfo::Boolean));
+    }
+
+    fn trait_decl(engines: &Engines, name: &str) -> TyTraitDecl {
+        let name = Ident::new_no_span(name.into());
+        TyTraitDecl {
+            name: name.clone(),
+            type_parameters: vec![],
+            self_type: TypeParameter::new_self_type(engines, Span::dummy()),
+            interface_surface: vec![],
+            items: vec![],
+            supertraits: vec![],
+            visibility: Visibility::Public,
+            attributes: Default::default(),
+            call_path: name.into(),
+            span: Span::dummy(),
+        }
+    }
+
+    #[test]
+    fn rollback_discards_speculative_declarations() {
+        let mut engines = Engines::default();
+        let name = |name: &str| Ident::new_no_span(name.into());
+        let kept = engines.de().insert(trait_decl(&engines, "Kept"));
+
+        let snapshot = engines.snapshot();
+        let speculative = engines.de().insert(trait_decl(&engines, "Speculative"));
+        engines
+            .de()
+            .replace(*kept.id(), trait_decl(&engines, "Replaced"));
+        assert_eq!(engines.de().get(kept.id()).name, name("Replaced"));
+        engines.rollback(snapshot);
+
+        // The declarations inserted before the snapshot are restored, while those inserted after
+        // it are gone and their ids are not handed out again.
+        assert_eq!(engines.de().get(kept.id()).name, name("Kept"));
+        assert!(engines
+            .de()
+            .get_traits_by_name(&name("Replaced"))
+            .is_empty());
+        assert!(engines
+            .de()
+            .get_traits_by_name(&name("Speculative"))
+            .is_empty());
+        let inserted = engines.de().insert(trait_decl(&engines, "Inserted"));
+        assert_ne!(inserted.id(), speculative.id());
+        assert_eq!(engines.de().get(inserted.id()).name, name("Inserted"));
+    }
+}
@@ -0,0 +1,204 @@
+//! A static gas model used to estimate the cost of executing statements and functions.
+//!
+//! The estimates are derived from the typed AST rather than the generated bytecode, so they are
+//! approximations meant to point out hot spots, such as storage accesses and contract calls,
+//! rather than exact costs. Loops are counted as a single iteration.
+
+use crate::{
+    decl_engine::id::DeclId,
+    language::ty::{self, TyAstNodeContent, TyDecl, TyExpression, TyExpressionVariant},
+    Engines,
+};
+use std::collections::HashMap;
+use sway_ast::Intrinsic;
+
+/// Cost of simple ALU, memory and control flow instructions.
+const BASE_COST: u64 = 1;
+/// Cost of jumping into and returning from a function, including saving registers.
+const FN_CALL_COST: u64 = 10;
+/// Cost of a `CALL` to another contract.
+const CONTRACT_CALL_COST: u64 = 200;
+/// Cost of reading a word or a quad word from storage.
+const STATE_READ_COST: u64 = 30;
+/// Cost of writing a word or a quad word to storage.
+const STATE_WRITE_COST: u64 = 60;
+/// Cost of clearing a range of storage slots.
+const STATE_CLEAR_COST: u64 = 50;
+/// Cost of emitting a log receipt.
+const LOG_COST: u64 = 20;
+/// Cost of sending a message to the output.
+const SMO_COST: u64 = 100;
+
+/// Estimates the gas used by typed AST nodes. The costs of called functions are cached, so the
+/// same estimator should be reused for all of the functions of a file.
+pub struct GasEstimator<'a> {
+    engines: &'a Engines,
+    fn_costs: HashMap<DeclId<ty::TyFunctionDecl>, u64>,
+}
+
+impl<'a> GasEstimator<'a> {
+    pub fn new(engines: &'a Engines) -> Self {
+        Self {
+            engines,
+            fn_costs: HashMap::new(),
+        }
+    }
+
+    /// Estimates the cost of a single execution of the function body.
+    pub fn function(&mut self, fn_id: &DeclId<ty::TyFunctionDecl>) -> u64 {
+        if let Some(cost) = self.fn_costs.get(fn_id) {
+            return *cost;
+        }
+        // Recursive calls are counted as a plain call.
+        self.fn_costs.insert(*fn_id, FN_CALL_COST);
+        let func = self.engines.de().get_function(fn_id);
+        let cost = self.code_block(&func.body);
+        self.fn_costs.insert(*fn_id, cost);
+        cost
+    }
+
+    pub fn code_block(&mut self, code_block: &ty::TyCodeBlock) -> u64 {
+        code_block
+            .contents
+            .iter()
+            .map(|node| self.ast_node(node))
+            .sum()
+    }
+
+    pub fn ast_node(&mut self, node: &ty::TyAstNode) -> u64 {
+        match &node.content {
+            TyAstNodeContent::Declaration(TyDecl::VariableDecl(var_decl)) => {
+                BASE_COST + self.expr(&var_decl.body)
+            }
+            TyAstNodeContent::Expression(expr)
+            | TyAstNodeContent::ImplicitReturnExpression(expr) => self.expr(expr),
+            TyAstNodeContent::Declaration(_)
+            | TyAstNodeContent::SideEffect(_)
+            | TyAstNodeContent::Error(_, _) => 0,
+        }
+    }
+
+    pub fn expr(&mut self, expr: &TyExpression) -> u64 {
+        match &expr.expression {
+            TyExpressionVariant::Literal(_)
+            | TyExpressionVariant::ConstantExpression { .. }
+            | TyExpressionVariant::VariableExpression { .. }
+            | TyExpressionVariant::FunctionParameter
+            | TyExpressionVariant::AbiName(_)
+            | TyExpressionVariant::Break
+            | TyExpressionVariant::Continue => BASE_COST,
+            TyExpressionVariant::FunctionApplication {
+                arguments,
+                contract_call_params,
+                fn_ref,
+                selector,
+                ..
+            } => {
+                let args_cost = arguments
+                    .iter()
+                    .map(|(_, arg)| arg)
+                    .chain(contract_call_params.values())
+                    .map(|arg| self.expr(arg))
+                    .sum::<u64>();
+                match selector {
+                    // The body of the called contract method is not known statically.
+                    Some(_) => CONTRACT_CALL_COST + args_cost,
+                    None => FN_CALL_COST + args_cost + self.function(fn_ref.id()),
+                }
+            }
+            TyExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+                BASE_COST + self.expr(lhs) + self.expr(rhs)
+            }
+            TyExpressionVariant::Tuple { fields } => self.exprs(fields),
+            TyExpressionVariant::Array { contents, .. } => self.exprs(contents),
+            TyExpressionVariant::ArrayIndex { prefix, index } => {
+                BASE_COST + self.expr(prefix) + self.expr(index)
+            }
+            TyExpressionVariant::StructExpression { fields, .. } => fields
+                .iter()
+                .map(|field| BASE_COST + self.expr(&field.value))
+                .sum(),
+            TyExpressionVariant::CodeBlock(code_block) => self.code_block(code_block),
+            TyExpressionVariant::MatchExp { desugared, .. } => self.expr(desugared),
+            // Only the more expensive branch is counted.
+            TyExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                let then_cost = self.expr(then);
+                let else_cost = r#else.as_ref().map_or(0, |r#else| self.expr(r#else));
+                BASE_COST + self.expr(condition) + then_cost.max(else_cost)
+            }
+            TyExpressionVariant::AsmExpression {
+                registers, body, ..
+            } => {
+                registers
+                    .iter()
+                    .filter_map(|register| register.initializer.as_ref())
+                    .map(|initializer| self.expr(initializer))
+                    .sum::<u64>()
+                    + body.len() as u64 * BASE_COST
+            }
+            TyExpressionVariant::StructFieldAccess { prefix, .. }
+            | TyExpressionVariant::TupleElemAccess { prefix, .. } => BASE_COST + self.expr(prefix),
+            TyExpressionVariant::EnumInstantiation { contents, .. } => {
+                BASE_COST + contents.as_ref().map_or(0, |contents| self.expr(contents))
+            }
+            TyExpressionVariant::AbiCast { address, .. } => self.expr(address),
+            TyExpressionVariant::StorageAccess(access) => {
+                BASE_COST * (access.fields.len() as u64 + 1)
+            }
+            TyExpressionVariant::IntrinsicFunction(intrinsic) => {
+                self.exprs(&intrinsic.arguments) + intrinsic_cost(&intrinsic.kind)
+            }
+            TyExpressionVariant::EnumTag { exp }
+            | TyExpressionVariant::UnsafeDowncast { exp, .. } => BASE_COST + self.expr(exp),
+            TyExpressionVariant::WhileLoop { condition, body } => {
+                BASE_COST + self.expr(condition) + self.code_block(body)
+            }
+            TyExpressionVariant::Reassignment(reassignment) => {
+                let indices_cost = reassignment
+                    .lhs_indices
+                    .iter()
+                    .map(|index| match index {
+                        ty::ProjectionKind::ArrayIndex { index, .. } => {
+                            BASE_COST + self.expr(index)
+                        }
+                        _ => BASE_COST,
+                    })
+                    .sum::<u64>();
+                BASE_COST + indices_cost + self.expr(&reassignment.rhs)
+            }
+            TyExpressionVariant::Return(expr) => BASE_COST + self.expr(expr),
+            TyExpressionVariant::Ref(expr) | TyExpressionVariant::Deref(expr) => {
+                BASE_COST + self.expr(expr)
+            }
+        }
+    }
+
+    fn exprs(&mut self, exprs: &[TyExpression]) -> u64 {
+        exprs.iter().map(|expr| self.expr(expr)).sum()
+    }
+}
+
+fn intrinsic_cost(intrinsic: &Intrinsic) -> u64 {
+    match intrinsic {
+        Intrinsic::StateLoadWord | Intrinsic::StateLoadQuad => STATE_READ_COST,
+        Intrinsic::StateStoreWord | Intrinsic::StateStoreQuad => STATE_WRITE_COST,
+        Intrinsic::StateClear => STATE_CLEAR_COST,
+        Intrinsic::Log => LOG_COST,
+        Intrinsic::Smo => SMO_COST,
+        // These are evaluated at compile time.
+        Intrinsic::IsReferenceType
+        | Intrinsic::IsStrArray
+        | Intrinsic::SizeOfType
+        | Intrinsic::SizeOfVal
+        | Intrinsic::SizeOfStr
+        | Intrinsic::AssertIsStrArray
+        | Intrinsic::ToStrArray
+        | Intrinsic::TypeName
+        | Intrinsic::TypeId => 0,
+        _ => BASE_COST,
+    }
+}
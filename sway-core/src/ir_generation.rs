@@ -2,18 +2,22 @@ pub(crate) mod compile;
 pub mod const_eval;
 mod convert;
 mod function;
+pub mod layout;
 mod lexical_map;
 mod purity;
 pub mod storage;
 mod types;
 
 use sway_error::error::CompileError;
-use sway_ir::{Context, Kind};
-use sway_types::span::Span;
+use sway_ir::{
+    AsmArg, AsmInstruction, Constant, Context, InsertionPosition, InstOp, Instruction,
+    InstructionInserter, Kind, Type,
+};
+use sway_types::{span::Span, Ident};
 
 pub(crate) use purity::{check_function_purity, PurityEnv};
 
-use crate::{language::ty, Engines, ExperimentalFlags};
+use crate::{language::ty, metadata::MetadataManager, Engines, ExperimentalFlags};
 
 pub fn compile_program<'eng>(
     program: &ty::TyProgram,
@@ -113,3 +117,70 @@ pub fn compile_program<'eng>(
         )]
     })
 }
+
+/// The `F_WRAPPING` flag of the `$flag` register, which makes overflowing arithmetic wrap around
+/// instead of reverting.
+const F_WRAPPING: u64 = 0b10;
+
+/// Makes overflowing arithmetic wrap around instead of reverting, for a program built with
+/// `overflow-checks = false`.
+///
+/// The VM checks the overflows of 64-bit arithmetic, and the core library those of the narrower
+/// integer types, unless the `F_WRAPPING` flag is set. So each entry point of the program sets
+/// it before running anything else.
+pub(crate) fn disable_overflow_checks(context: &mut Context) {
+    let entry_points: Vec<_> = context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+        .filter(|func| func.is_entry(context))
+        .collect();
+    for func in entry_points {
+        let flags = Constant::get_uint(context, 64, F_WRAPPING);
+        let unit = Type::get_unit(context);
+        let flags_reg = Ident::new_no_span("flags".to_string());
+        let entry_block = func.get_entry_block(context);
+        InstructionInserter::new(context, entry_block, InsertionPosition::Start).asm_block(
+            vec![AsmArg {
+                name: flags_reg.clone(),
+                initializer: Some(flags),
+            }],
+            vec![AsmInstruction {
+                op_name: Ident::new_no_span("flag".to_string()),
+                args: vec![flags_reg],
+                immediate: None,
+                metadata: None,
+            }],
+            unit,
+            None,
+        );
+    }
+}
+
+/// Checks that no function calls itself once the tail calls have been turned into loops.
+///
+/// Only the functions marked `#[tail_call]` may call themselves, and the calls which are not in
+/// tail position, and so could not be turned into loops, are reported.
+pub(crate) fn check_tail_calls(context: &Context) -> Vec<CompileError> {
+    let mut md_mgr = MetadataManager::default();
+    let mut errors = Vec::new();
+    for func in context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+    {
+        for (_block, instr_val) in func.instruction_iter(context) {
+            if let Some(Instruction {
+                op: InstOp::Call(callee, _),
+                ..
+            }) = instr_val.get_instruction(context)
+            {
+                if *callee == func {
+                    let span = md_mgr
+                        .md_to_span(context, instr_val.get_metadata(context))
+                        .unwrap_or_else(Span::dummy);
+                    errors.push(CompileError::RecursiveCallNotInTailPosition { span });
+                }
+            }
+        }
+    }
+    errors
+}
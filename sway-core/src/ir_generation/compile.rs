@@ -499,6 +499,20 @@ fn compile_fn(
         logged_types_map,
         messages_types_map,
     );
+    if ast_fn_decl.is_tail_call() {
+        // The recursive calls are then turned into loops by the tail call pass.
+        compiler.compile_self_calls(ast_fn_decl);
+    }
+    if ast_fn_decl.is_constructor() {
+        compiler
+            .compile_constructor_guard(context, md_mgr, span)
+            .map_err(|err| vec![err])?;
+    }
+    if ast_fn_decl.is_when_not_paused() {
+        compiler
+            .compile_when_not_paused_guard(context, md_mgr, span)
+            .map_err(|err| vec![err])?;
+    }
     let mut ret_val = compiler.compile_code_block(context, md_mgr, body)?;
 
     // Special case: sometimes the returned value at the end of the function block is hacked
@@ -4,7 +4,7 @@ use crate::{
     engine_threading::*,
     language::{
         ty::{self, TyConstantDecl, TyIntrinsicFunctionKind},
-        CallPath, Literal,
+        CallPath, LazyOp, Literal,
     },
     metadata::MetadataManager,
     semantic_analysis::*,
@@ -492,7 +492,8 @@ fn const_eval_typed_expr(
             );
 
             if let Ok(enum_ty) = aggregate {
-                let tag_value = Constant::new_uint(lookup.context, 64, *tag as u64);
+                let tag_value =
+                    Constant::new_uint(lookup.context, 64, enum_decl.discriminant_of_tag(*tag));
                 let mut fields: Vec<Constant> = vec![tag_value];
 
                 match contents {
@@ -645,6 +646,31 @@ fn const_eval_typed_expr(
                 }
             }
         }
+        ty::TyExpressionVariant::LazyOperator { op, lhs, rhs } => {
+            match const_eval_typed_expr(lookup, known_consts, lhs, allow_configurables)? {
+                Some(Constant {
+                    value: ConstantValue::Bool(lhs_value),
+                    ..
+                }) => {
+                    // Just like at runtime, the right operand is only evaluated if the left one
+                    // does not already decide the result.
+                    let is_short_circuit = match op {
+                        LazyOp::And => !lhs_value,
+                        LazyOp::Or => lhs_value,
+                    };
+                    if is_short_circuit {
+                        Some(Constant::new_bool(lookup.context, lhs_value))
+                    } else {
+                        const_eval_typed_expr(lookup, known_consts, rhs, allow_configurables)?
+                    }
+                }
+                _ => {
+                    return Err(ConstEvalError::CannotBeEvaluatedToConst {
+                        span: expr.span.clone(),
+                    })
+                }
+            }
+        }
         ty::TyExpressionVariant::Ref(_) | ty::TyExpressionVariant::Deref(_) => {
             return Err(ConstEvalError::CompileError(CompileError::Unimplemented(
                 "Constant references are currently not supported.",
@@ -654,7 +680,6 @@ fn const_eval_typed_expr(
         ty::TyExpressionVariant::Reassignment(_)
         | ty::TyExpressionVariant::FunctionParameter
         | ty::TyExpressionVariant::AsmExpression { .. }
-        | ty::TyExpressionVariant::LazyOperator { .. }
         | ty::TyExpressionVariant::AbiCast { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
         | ty::TyExpressionVariant::AbiName(_)
@@ -984,6 +1009,28 @@ fn const_eval_intrinsic(
                 value: ConstantValue::Uint(ir_type.size(lookup.context).in_bytes()),
             }))
         }
+        Intrinsic::TypeName | Intrinsic::TypeId => {
+            let targ = &intrinsic.type_arguments[0];
+            // Make sure the type is fully resolved, i.e. not generic.
+            convert_resolved_typeid(
+                lookup.engines.te(),
+                lookup.engines.de(),
+                lookup.context,
+                &targ.type_id,
+                &targ.span,
+            )
+            .map_err(ConstEvalError::CompileError)?;
+            Ok(Some(match intrinsic.kind {
+                Intrinsic::TypeName => Constant::new_string(
+                    lookup.context,
+                    targ.type_id.get_type_name(lookup.engines).into_bytes(),
+                ),
+                _ => Constant {
+                    ty: Type::get_uint64(lookup.context),
+                    value: ConstantValue::Uint(targ.type_id.get_stable_type_id(lookup.engines)),
+                },
+            }))
+        }
         Intrinsic::SizeOfVal => {
             let val = &intrinsic.arguments[0];
             let type_id = val.return_type;
@@ -1244,6 +1291,18 @@ mod tests {
         assert_is_constant(true, "", "if true { 1 } else { 0 }");
         assert_is_constant(true, "", "(0,1).0");
         assert_is_constant(true, "", "[0,1][0]");
+        assert_is_constant(true, "", "true && false");
+        assert_is_constant(true, "", "false || __eq(1, 1)");
+        assert_is_constant(
+            true,
+            "fn in_range(x: u64) -> bool { __gt(x, 1) && __lt(x, 10) }",
+            "in_range(5)",
+        );
+        assert_is_constant(
+            true,
+            "fn max(a: u64, b: u64) -> u64 { if __gt(a, b) { a } else { b } } fn double(x: u64) -> u64 { __mul(x, 2) }",
+            "max(double(3), double(2))",
+        );
 
         // u256
         assert_is_constant(
@@ -1303,6 +1362,9 @@ mod tests {
         // At the moment this is not constant because of the "return"
         assert_is_constant(false, "fn id(x: u64) -> u64 { return x; }", "id(1)");
         assert_is_constant(false, "", "[0,1][2]");
+        // The right operand is only evaluated when it decides the result.
+        assert_is_constant(true, "", "false && __eq([0,1][2], 0)");
+        assert_is_constant(false, "", "true && __eq([0,1][2], 0)");
         assert_is_constant(
             false,
             "enum Color { Blue: u64 }",
@@ -159,6 +159,8 @@ fn convert_resolved_type(
             convert_resolved_typeid(type_engine, decl_engine, context, &ty.type_id, span)?
         }
         TypeInfo::Ref(_) => Type::get_uint64(context),
+        // A value of the never type is never produced, so it needs no representation.
+        TypeInfo::Never => Type::get_unit(context),
 
         // Unsupported types which shouldn't exist in the AST after type checking and
         // monomorphisation.
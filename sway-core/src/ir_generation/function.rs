@@ -2,11 +2,15 @@ use super::{
     compile::compile_function,
     convert::*,
     lexical_map::LexicalMap,
-    storage::{add_to_b256, get_storage_key},
+    storage::{
+        add_to_b256, get_constructor_key, get_paused_key, get_storage_key,
+        CONSTRUCTOR_ALREADY_RUN_SIGNAL, PAUSED_SIGNAL,
+    },
     types::*,
 };
 use crate::{
     engine_threading::*,
+    fuel_prelude::fuel_types::Bytes32,
     ir_generation::const_eval::{
         compile_constant_expression, compile_constant_expression_to_constant,
     },
@@ -103,6 +107,115 @@ impl<'eng> FnCompiler<'eng> {
         }
     }
 
+    /// Compiles the calls the function being compiled makes to itself as calls to `function`,
+    /// rather than recreating it.
+    pub(super) fn compile_self_calls(&mut self, fn_decl: &ty::TyFunctionDecl) {
+        self.recreated_fns
+            .insert(recreated_fn_key(fn_decl), self.function);
+    }
+
+    /// Compiles the prologue of a contract constructor, which reverts if the constructor has
+    /// already run and otherwise records in storage that it did.
+    pub(super) fn compile_constructor_guard(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        span: &Span,
+    ) -> Result<(), CompileError> {
+        let key_val = self.compile_storage_flag_guard(
+            context,
+            md_mgr,
+            span,
+            "constructor_key",
+            get_constructor_key(),
+            CONSTRUCTOR_ALREADY_RUN_SIGNAL,
+        )?;
+        let span_md_idx = md_mgr.span_to_md(context, span);
+        let one = Constant::get_uint(context, 64, 1);
+        self.current_block
+            .append(context)
+            .state_store_word(one, key_val)
+            .add_metadatum(context, span_md_idx);
+        Ok(())
+    }
+
+    /// Compiles the prologue of a contract method marked `#[when_not_paused]`, which reverts if
+    /// the contract is paused with `std::pausable::pause`.
+    pub(super) fn compile_when_not_paused_guard(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        span: &Span,
+    ) -> Result<(), CompileError> {
+        self.compile_storage_flag_guard(
+            context,
+            md_mgr,
+            span,
+            "paused_key",
+            get_paused_key(),
+            PAUSED_SIGNAL,
+        )?;
+        Ok(())
+    }
+
+    /// Compiles a check reverting with `signal` if the storage slot `key` holds a non-zero word,
+    /// and continues in a new block otherwise. Returns the pointer to the key.
+    fn compile_storage_flag_guard(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        span: &Span,
+        key_name: &str,
+        key: Bytes32,
+        signal: u64,
+    ) -> Result<Value, CompileError> {
+        let span_md_idx = md_mgr.span_to_md(context, span);
+
+        let key_name = self.lexical_map.insert(key_name.to_owned());
+        let key_var = self
+            .function
+            .new_local_var(context, key_name, Type::get_b256(context), None, false)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), span.clone()))?;
+        let key_val = self
+            .current_block
+            .append(context)
+            .get_local(key_var)
+            .add_metadatum(context, span_md_idx);
+        let key = Constant::get_b256(context, key.into());
+        self.current_block
+            .append(context)
+            .store(key_val, key)
+            .add_metadatum(context, span_md_idx);
+
+        let flag = self
+            .current_block
+            .append(context)
+            .state_load_word(key_val)
+            .add_metadatum(context, span_md_idx);
+        let zero = Constant::get_uint(context, 64, 0);
+        let is_unset = self
+            .current_block
+            .append(context)
+            .cmp(Predicate::Equal, flag, zero)
+            .add_metadatum(context, span_md_idx);
+
+        let revert_block = self.function.create_block(context, None);
+        let body_block = self.function.create_block(context, None);
+        self.current_block
+            .append(context)
+            .conditional_branch(is_unset, body_block, revert_block, vec![], vec![])
+            .add_metadatum(context, span_md_idx);
+
+        let signal = Constant::get_uint(context, 64, signal);
+        revert_block
+            .append(context)
+            .revert(signal)
+            .add_metadatum(context, span_md_idx);
+
+        self.current_block = body_block;
+        Ok(key_val)
+    }
+
     fn compile_with_new_scope<F, T, R>(&mut self, inner: F) -> Result<T, R>
     where
         F: FnOnce(&mut FnCompiler) -> Result<T, R>,
@@ -465,7 +578,7 @@ impl<'eng> FnCompiler<'eng> {
                     registers,
                     body,
                     ast_expr.return_type,
-                    returns.as_ref(),
+                    returns,
                     span_md_idx,
                 )
             }
@@ -673,6 +786,36 @@ impl<'eng> FnCompiler<'eng> {
                     ir_type.get_string_len(context).unwrap_or_default(),
                 ))
             }
+            Intrinsic::TypeName => {
+                let targ = type_arguments[0].clone();
+                // Make sure the type is fully resolved, i.e. not generic.
+                convert_resolved_typeid(
+                    engines.te(),
+                    engines.de(),
+                    context,
+                    &targ.type_id,
+                    &targ.span,
+                )?;
+                let span_md_idx = md_mgr.span_to_md(context, &span);
+                let name = targ.type_id.get_type_name(engines);
+                let string_data = Constant::get_string(context, name.as_bytes().to_vec());
+                self.compile_string_slice(context, span_md_idx, string_data, name.len() as u64)
+            }
+            Intrinsic::TypeId => {
+                let targ = type_arguments[0].clone();
+                convert_resolved_typeid(
+                    engines.te(),
+                    engines.de(),
+                    context,
+                    &targ.type_id,
+                    &targ.span,
+                )?;
+                Ok(Constant::get_uint(
+                    context,
+                    64,
+                    targ.type_id.get_stable_type_id(engines),
+                ))
+            }
             Intrinsic::IsReferenceType => {
                 let targ = type_arguments[0].clone();
                 let val = !engines.te().get_unaliased(targ.type_id).is_copy_type();
@@ -1600,15 +1743,7 @@ impl<'eng> FnCompiler<'eng> {
         // cache, to uniquely identify a function instance, is the span and the type IDs of any
         // args and type parameters.  It's using the Sway types rather than IR types, which would
         // be more accurate but also more fiddly.
-        let fn_key = (
-            callee.span(),
-            callee
-                .parameters
-                .iter()
-                .map(|p| p.type_argument.type_id)
-                .collect(),
-            callee.type_parameters.iter().map(|tp| tp.type_id).collect(),
-        );
+        let fn_key = recreated_fn_key(callee);
         let new_callee = match self.recreated_fns.get(&fn_key).copied() {
             Some(func) => func,
             None => {
@@ -2616,8 +2751,8 @@ impl<'eng> FnCompiler<'eng> {
             context,
             &enum_decl.variants,
         )?;
-        let tag_value =
-            Constant::get_uint(context, 64, tag as u64).add_metadatum(context, span_md_idx);
+        let tag_value = Constant::get_uint(context, 64, enum_decl.discriminant_of_tag(tag))
+            .add_metadatum(context, span_md_idx);
 
         // Start with a temporary local struct and insert the tag.
         let temp_name = self.lexical_map.insert_anon();
@@ -2659,6 +2794,10 @@ impl<'eng> FnCompiler<'eng> {
                     enum_decl.span.clone(),
                 )
             })?;
+            // Values that take no space, like those of empty structs, have nothing to store.
+            if contents_type.size(context).in_bytes() == 0 {
+                return Ok(enum_ptr);
+            }
             let gep_val = self
                 .current_block
                 .append(context)
@@ -2810,11 +2949,44 @@ impl<'eng> FnCompiler<'eng> {
         registers: &[ty::TyAsmRegisterDeclaration],
         body: &[AsmOp],
         return_type: TypeId,
-        returns: Option<&(AsmRegister, Span)>,
+        returns: &[(AsmRegister, Span)],
         whole_block_span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
-        let registers = registers
+        // `const` operands are not put into registers. Their values replace the immediates that
+        // name them, which makes the range of the values checked like any other immediate.
+        let mut const_operands = HashMap::new();
+        for ty::TyAsmRegisterDeclaration {
+            initializer, name, ..
+        } in registers.iter().filter(|reg| reg.is_const)
+        {
+            let Some(init_expr) = initializer else {
+                continue;
+            };
+            let value = compile_constant_expression_to_constant(
+                self.engines,
+                context,
+                md_mgr,
+                self.module,
+                None,
+                Some(self),
+                init_expr,
+                false,
+            )
+            .ok()
+            .and_then(|constant| match constant.value {
+                ConstantValue::Uint(value) => Some(value),
+                _ => None,
+            })
+            .ok_or_else(|| CompileError::NonConstantAsmOperand {
+                name: name.to_string(),
+                span: init_expr.span.clone(),
+            })?;
+            const_operands.insert(name.as_str(), value);
+        }
+
+        let mut registers = registers
             .iter()
+            .filter(|reg| !reg.is_const)
             .map(
                 |ty::TyAsmRegisterDeclaration {
                      initializer, name, ..
@@ -2861,7 +3033,7 @@ impl<'eng> FnCompiler<'eng> {
                 },
             )
             .collect::<Result<Vec<AsmArg>, CompileError>>()?;
-        let body = body
+        let mut body = body
             .iter()
             .map(
                 |AsmOp {
@@ -2872,20 +3044,79 @@ impl<'eng> FnCompiler<'eng> {
                  }| AsmInstruction {
                     op_name: op_name.clone(),
                     args: op_args.clone(),
-                    immediate: immediate.clone(),
+                    immediate: immediate.as_ref().map(|imm| {
+                        match const_operands.get(imm.as_str()) {
+                            Some(value) => {
+                                Ident::new_with_override(format!("i{value}"), imm.span())
+                            }
+                            None => imm.clone(),
+                        }
+                    }),
                     metadata: md_mgr.span_to_md(context, span),
                 },
             )
-            .collect();
-        let returns = returns
-            .as_ref()
-            .map(|(_, asm_reg_span)| Ident::new(asm_reg_span.clone()));
+            .collect::<Vec<_>>();
         let return_type = convert_resolved_typeid_no_span(
             self.engines.te(),
             self.engines.de(),
             context,
             &return_type,
         )?;
+        let returns = match returns {
+            [] => None,
+            [(_, asm_reg_span)] => Some(Ident::new(asm_reg_span.clone())),
+            _ => {
+                // Several registers are returned by storing them into a local tuple and
+                // returning its address, the same way aggregates are returned from asm blocks.
+                let outputs_name = Ident::new_no_span("__asm_outputs".to_string());
+                let temp_name = self.lexical_map.insert_anon();
+                let outputs_var = self
+                    .function
+                    .new_local_var(context, temp_name, return_type, None, false)
+                    .map_err(|ir_error| {
+                        CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
+                    })?;
+                let outputs_ptr = self
+                    .current_block
+                    .append(context)
+                    .get_local(outputs_var)
+                    .add_metadatum(context, whole_block_span_md_idx);
+                registers.push(AsmArg {
+                    name: outputs_name.clone(),
+                    initializer: Some(outputs_ptr),
+                });
+                for (idx, (_, asm_reg_span)) in returns.iter().enumerate() {
+                    let (offset, field_type) = return_type
+                        .get_struct_field_offset_and_type(context, idx as u64)
+                        .ok_or_else(|| {
+                            CompileError::Internal(
+                                "Registers returned by an asm block are not a tuple.",
+                                asm_reg_span.clone(),
+                            )
+                        })?;
+                    // Bytes are stored with `sb`, whose offset is in bytes, and words with
+                    // `sw`, whose offset is in words.
+                    let (op_name, offset) = if field_type.size(context).in_bytes() == 1 {
+                        ("sb", offset)
+                    } else {
+                        ("sw", offset / 8)
+                    };
+                    body.push(AsmInstruction {
+                        op_name: Ident::new_with_override(
+                            op_name.to_string(),
+                            asm_reg_span.clone(),
+                        ),
+                        args: vec![outputs_name.clone(), Ident::new(asm_reg_span.clone())],
+                        immediate: Some(Ident::new_with_override(
+                            format!("i{offset}"),
+                            asm_reg_span.clone(),
+                        )),
+                        metadata: md_mgr.span_to_md(context, asm_reg_span),
+                    });
+                }
+                Some(outputs_name)
+            }
+        };
         Ok(self
             .current_block
             .append(context)
@@ -3003,3 +3234,21 @@ impl<'eng> FnCompiler<'eng> {
         Ok(storage_key)
     }
 }
+
+/// The key of the cache of recreated functions, uniquely identifying a function instance by its
+/// span and the type IDs of its args and type parameters.
+fn recreated_fn_key(fn_decl: &ty::TyFunctionDecl) -> (Span, Vec<TypeId>, Vec<TypeId>) {
+    (
+        fn_decl.span(),
+        fn_decl
+            .parameters
+            .iter()
+            .map(|p| p.type_argument.type_id)
+            .collect(),
+        fn_decl
+            .type_parameters
+            .iter()
+            .map(|tp| tp.type_id)
+            .collect(),
+    )
+}
@@ -0,0 +1,251 @@
+//! Computes the memory and storage layout of types, exactly as IR generation lays them out.
+
+use crate::{language::ty, Engines, TypeId};
+
+use super::{
+    convert::convert_resolved_typeid,
+    types::{create_tagged_union_type, get_struct_for_types},
+};
+
+use sway_error::error::CompileError;
+use sway_ir::{Context, ExperimentalFlags, Type};
+use sway_types::span::Span;
+
+/// The layout of a type in memory and in contract storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    /// The size of the type in bytes, as returned by `__size_of`.
+    pub size_in_bytes: u64,
+    /// The number of storage slots a value of the type occupies when it is a storage field.
+    pub storage_slots: u64,
+    /// The offsets in bytes of the fields of a struct, or of the values of the variants of an
+    /// enum. Empty for all other types.
+    pub field_offsets: Vec<u64>,
+}
+
+impl TypeLayout {
+    fn new(context: &Context, ty: Type) -> Self {
+        let size_in_bytes = ty.size(context).in_bytes();
+        // Aggregates are spread over successive slots, while every other value is stored at
+        // the beginning of a single slot. See `storage::serialize_to_storage_slots`.
+        let storage_slots =
+            if ty.is_struct(context) || ty.is_union(context) || ty.is_string_array(context) {
+                (size_in_bytes + 31) / 32
+            } else {
+                1
+            };
+        let field_offsets = if ty.is_struct(context) {
+            (0..ty.get_field_types(context).len() as u64)
+                .filter_map(|idx| ty.get_struct_field_offset_and_type(context, idx))
+                .map(|(offset, _)| offset)
+                .collect()
+        } else {
+            vec![]
+        };
+        Self {
+            size_in_bytes,
+            storage_slots,
+            field_offsets,
+        }
+    }
+}
+
+/// Returns the [TypeLayout] of the type. Fails if the type cannot be represented in the IR,
+/// for example if it is still generic.
+pub fn type_layout(
+    engines: &Engines,
+    type_id: TypeId,
+    span: &Span,
+) -> Result<TypeLayout, CompileError> {
+    let mut context = Context::new(engines.se(), ExperimentalFlags::default());
+    let ty = convert_resolved_typeid(engines.te(), engines.de(), &mut context, &type_id, span)?;
+    Ok(TypeLayout::new(&context, ty))
+}
+
+/// Returns the [TypeLayout] of the struct declaration.
+pub fn struct_layout(
+    engines: &Engines,
+    struct_decl: &ty::TyStructDecl,
+) -> Result<TypeLayout, CompileError> {
+    let mut context = Context::new(engines.se(), ExperimentalFlags::default());
+    let field_types = struct_decl
+        .fields
+        .iter()
+        .map(|field| field.type_argument.type_id)
+        .collect::<Vec<_>>();
+    let ty = get_struct_for_types(engines.te(), engines.de(), &mut context, &field_types)?;
+    Ok(TypeLayout::new(&context, ty))
+}
+
+/// Returns the [TypeLayout] of the enum declaration. The field offsets are the offsets of the
+/// values of its variants, which follow the one word tag.
+///
+/// Enums are never niche-filled: the tag is stored separately even when it would fit in bit
+/// patterns no value of a variant uses, as in `Option<bool>`. The memory layout of a type is
+/// its ABI encoding, and the layout its storage fields are written in, so storing the tag in a
+/// niche would change both for existing contracts. A value with no such bit patterns, such as
+/// the `b256` of `Option<ContractId>`, has no niche to store the tag in anyway.
+pub fn enum_layout(
+    engines: &Engines,
+    enum_decl: &ty::TyEnumDecl,
+) -> Result<TypeLayout, CompileError> {
+    let mut context = Context::new(engines.se(), ExperimentalFlags::default());
+    let ty = create_tagged_union_type(
+        engines.te(),
+        engines.de(),
+        &mut context,
+        &enum_decl.variants,
+    )?;
+    let mut layout = TypeLayout::new(&context, ty);
+    layout.field_offsets = match ty.get_field_types(&context).as_slice() {
+        [_, union_ty] => {
+            // The values of the variants are left padded within the union that follows the tag.
+            let union_offset = ty.size(&context).in_bytes() - union_ty.size(&context).in_bytes();
+            (0..union_ty.get_field_types(&context).len() as u64)
+                .filter_map(|idx| union_ty.get_union_field_offset_and_type(&context, idx))
+                .map(|(offset, _)| union_offset + offset)
+                .collect()
+        }
+        // Enums whose variants are all units only store the tag.
+        _ => vec![],
+    };
+    Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abi_generation::fuel_abi::{generate_program_abi, AbiContext},
+        fuel_prelude::{fuel_tx::StorageSlot, fuel_types::Bytes32},
+        ir_generation::storage::{
+            add_to_b256, get_storage_key, program_storage_slots, FieldStorageSlot,
+            VersionedStorageSlots,
+        },
+        language::ty::TyProgramKind,
+        namespace, test_utils,
+    };
+    use sway_error::handler::Handler;
+    use sway_types::state::StateIndex;
+
+    /// A contract returning and storing an `Option<ContractId>`, with the same declarations as
+    /// the standard library.
+    const SRC: &str = r#"contract;
+
+enum Option<T> {
+    None: (),
+    Some: T,
+}
+
+struct ContractId {
+    value: b256,
+}
+
+storage {
+    some: Option<ContractId> = Option::Some(ContractId {
+        value: 0x0101010101010101010101010101010101010101010101010101010101010101,
+    }),
+    none: Option<ContractId> = Option::None,
+}
+
+abi Registry {
+    fn owner() -> Option<ContractId>;
+}
+
+impl Registry for Contract {
+    fn owner() -> Option<ContractId> {
+        Option::None
+    }
+}
+"#;
+
+    fn compile() -> (Engines, ty::TyProgram) {
+        let engines = Engines::default();
+        let program = test_utils::compile_to_ast(&engines, SRC, namespace::Module::default(), None);
+        (engines, program)
+    }
+
+    #[test]
+    fn option_of_contract_id_stores_its_tag_before_its_value() {
+        let (engines, program) = compile();
+        let TyProgramKind::Contract { abi_entries } = &program.kind else {
+            panic!("expected a contract");
+        };
+        let owner = engines.de().get_function(&abi_entries[0]);
+        let layout =
+            type_layout(&engines, owner.return_type.type_id, &owner.return_type.span).unwrap();
+        assert_eq!(
+            layout,
+            TypeLayout {
+                size_in_bytes: 8 + 32,
+                storage_slots: 2,
+                field_offsets: vec![0, 8],
+            }
+        );
+    }
+
+    #[test]
+    fn option_of_contract_id_is_stored_as_its_tag_and_value() {
+        let (engines, program) = compile();
+        let slots = program_storage_slots(&Handler::default(), &engines, &program).unwrap();
+
+        // The words are stored from the beginning of the slot.
+        let words = |words: &[u64]| {
+            let mut value = [0; 32];
+            for (word, bytes) in words.iter().zip(value.chunks_mut(8)) {
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            Bytes32::new(value)
+        };
+        let key = |ix: usize| get_storage_key::<usize>(&StateIndex::new(ix), &[]);
+        let field_slot = |key, value, field: &str, offset| FieldStorageSlot {
+            slot: StorageSlot::new(key, value),
+            field: field.to_string(),
+            offset,
+        };
+        let id = 0x0101_0101_0101_0101;
+        // The tag and the four words of the value are spread over two successive slots.
+        let expected = VersionedStorageSlots::new(vec![
+            field_slot(key(0), words(&[1, id, id, id]), "storage.some", 0),
+            field_slot(add_to_b256(key(0), 1), words(&[id]), "storage.some", 1),
+            field_slot(key(1), words(&[0]), "storage.none", 0),
+            field_slot(add_to_b256(key(1), 1), words(&[0]), "storage.none", 1),
+        ]);
+        assert_eq!(slots, expected);
+    }
+
+    #[test]
+    fn option_of_contract_id_is_an_enum_in_the_abi() {
+        let (engines, program) = compile();
+        let mut ctx = AbiContext {
+            program: &program,
+            abi_with_callpaths: false,
+            enum_discriminants: Default::default(),
+        };
+        let abi = generate_program_abi(&mut ctx, engines.te(), engines.de(), &mut vec![], None);
+        let type_decl = |type_id: usize| {
+            abi.types
+                .iter()
+                .find(|decl| decl.type_id == type_id)
+                .unwrap()
+        };
+        let component_names = |type_id: usize| {
+            type_decl(type_id)
+                .components
+                .iter()
+                .flatten()
+                .map(|component| component.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        let output = &abi.functions[0].output;
+        assert_eq!(type_decl(output.type_id).type_field, "enum Option");
+        assert_eq!(component_names(output.type_id), ["None", "Some"]);
+        let contract_id = &output.type_arguments.as_ref().unwrap()[0];
+        assert_eq!(
+            type_decl(contract_id.type_id).type_field,
+            "struct ContractId"
+        );
+        assert_eq!(component_names(contract_id.type_id), ["value"]);
+    }
+}
@@ -1,8 +1,5 @@
 use crate::{
-    language::{
-        promote_purity,
-        Purity::{self, *},
-    },
+    language::Purity::{self, *},
     metadata::{MetadataManager, StorageOperation},
 };
 
@@ -53,10 +50,10 @@ pub(crate) fn check_function_purity(
                         // Iterate for and check each instruction in the ASM block.
                         InstOp::AsmBlock(asm_block, _args) => asm_block.body.iter().fold(
                             (reads, writes),
-                            |(reads, writes), asm_op| match asm_op.op_name.as_str() {
-                                "scwq" | "srw" | "srwq" => (true, writes),
-                                "sww" | "swwq" => (reads, true),
-                                _ => (reads, writes),
+                            |(reads, writes), asm_op| {
+                                let (op_reads, op_writes) =
+                                    asm_op_storage_access(asm_op.op_name.as_str());
+                                (reads || op_reads, writes || op_writes)
                             },
                         ),
 
@@ -86,11 +83,20 @@ pub(crate) fn check_function_purity(
         .md_to_span(context, function.get_metadata(context))
         .unwrap_or_else(Span::dummy);
 
+    // The attributes the function needs, which are suggested whenever the declared ones are
+    // wrong.
+    let required_purity = match (reads, writes) {
+        (false, false) => Pure,
+        (true, false) => Reads,
+        (false, true) => Writes,
+        (true, true) => ReadsWrites,
+    };
+
     // Simple closures for each of the error types.
-    let error = |span, storage_op, existing, needed| {
+    let error = |span, storage_op| {
         handler.emit_err(CompileError::ImpureInPureContext {
             storage_op,
-            attrs: promote_purity(existing, needed).to_attribute_syntax(),
+            attrs: required_purity.to_attribute_syntax(),
             span,
         });
     };
@@ -98,6 +104,7 @@ pub(crate) fn check_function_purity(
         handler.emit_warn(CompileWarning {
             warning_content: Warning::DeadStorageDeclarationForFunction {
                 unneeded_attrib: purity.to_attribute_syntax(),
+                required_attrib: required_purity.to_attribute_syntax(),
             },
             span,
         });
@@ -105,12 +112,12 @@ pub(crate) fn check_function_purity(
 
     match (attributed_purity, reads, writes) {
         // Has no attributes but needs some.
-        (None, true, false) => error(span, "read", Pure, Reads),
-        (None, false, true) => error(span, "write", Pure, Writes),
-        (None, true, true) => error(span, "read & write", Pure, ReadsWrites),
+        (None, true, false) => error(span, "read"),
+        (None, false, true) => error(span, "write"),
+        (None, true, true) => error(span, "read & write"),
 
         // Or the attribute must match the behaviour.
-        (Some(StorageOperation::Reads), _, true) => error(span, "write", Reads, Writes),
+        (Some(StorageOperation::Reads), _, true) => error(span, "write"),
 
         // Or we have unneeded attributes.
         (Some(StorageOperation::ReadsWrites), false, true) => warn(span, Reads),
@@ -128,3 +135,13 @@ pub(crate) fn check_function_purity(
 
     (reads, writes)
 }
+
+/// Returns whether the VM instruction reads and whether it writes contract storage.
+fn asm_op_storage_access(op_name: &str) -> (bool, bool) {
+    match op_name {
+        "srw" | "srwq" => (true, false),
+        // Clearing storage slots is a write.
+        "sww" | "swwq" | "scwq" => (false, true),
+        _ => (false, false),
+    }
+}
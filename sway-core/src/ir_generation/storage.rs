@@ -1,15 +1,131 @@
-use crate::fuel_prelude::{
-    fuel_crypto::Hasher,
-    fuel_tx::StorageSlot,
-    fuel_types::{Bytes32, Bytes8},
+use crate::{
+    fuel_prelude::{
+        fuel_crypto::Hasher,
+        fuel_tx::StorageSlot,
+        fuel_types::{Bytes32, Bytes8},
+    },
+    language::ty,
+    metadata::MetadataManager,
+    Engines,
 };
+use serde::{Deserialize, Serialize};
+use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{
     constant::{Constant, ConstantValue},
     context::Context,
     irtype::Type,
+    ExperimentalFlags, Kind, Module,
 };
 use sway_types::state::StateIndex;
 
+use super::compile::compile_constants;
+
+/// The version of the schema of [VersionedStorageSlots]. It is increased on every change that
+/// is not backwards compatible.
+pub const STORAGE_SLOTS_SCHEMA_VERSION: u32 = 1;
+
+/// An initialized storage slot along with the storage field it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldStorageSlot {
+    #[serde(flatten)]
+    pub slot: StorageSlot,
+    /// The path of the storage field, e.g. `storage.owner`.
+    pub field: String,
+    /// The index of the slot among the successive slots that the field is spread over.
+    pub offset: u64,
+}
+
+/// The initialized storage slots of a contract, as written to its storage slots artifact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionedStorageSlots {
+    /// The version of the schema, see [STORAGE_SLOTS_SCHEMA_VERSION].
+    pub version: u32,
+    /// The slots, sorted by key.
+    pub slots: Vec<FieldStorageSlot>,
+}
+
+impl VersionedStorageSlots {
+    pub fn new(mut slots: Vec<FieldStorageSlot>) -> Self {
+        // Sort the slots to standardize the output.
+        slots.sort_by(|a, b| a.slot.cmp(&b.slot));
+        Self {
+            version: STORAGE_SLOTS_SCHEMA_VERSION,
+            slots,
+        }
+    }
+
+    /// Returns the plain storage slots, as used when deploying the contract.
+    pub fn storage_slots(&self) -> Vec<StorageSlot> {
+        self.slots.iter().map(|slot| slot.slot.clone()).collect()
+    }
+
+    /// Returns the slots whose keys are missing from `slots` or whose values differ from the
+    /// values in `slots`.
+    pub fn mismatched_slots(&self, slots: &[StorageSlot]) -> Vec<&FieldStorageSlot> {
+        self.slots
+            .iter()
+            .filter(|expected| !slots.contains(&expected.slot))
+            .collect()
+    }
+}
+
+/// Regenerates the initialized storage slots of a contract and the storage fields they belong
+/// to from its typed program, using the same layout of the storage fields as IR generation.
+/// Returns no slots for programs that are not contracts.
+pub fn program_storage_slots(
+    handler: &Handler,
+    engines: &Engines,
+    program: &ty::TyProgram,
+) -> Result<VersionedStorageSlots, ErrorEmitted> {
+    let mut context = Context::new(engines.se(), ExperimentalFlags::default());
+    let mut md_mgr = MetadataManager::default();
+    let module = Module::new(&mut context, Kind::Contract);
+    compile_constants(
+        engines,
+        &mut context,
+        &mut md_mgr,
+        module,
+        &program.root.namespace,
+    )
+    .map_err(|err| handler.emit_err(err))?;
+    let program = program
+        .clone()
+        .get_typed_program_with_initialized_storage_slots(
+            handler,
+            engines,
+            &mut context,
+            &mut md_mgr,
+            module,
+        )?;
+    Ok(VersionedStorageSlots::new(program.storage_slots))
+}
+
+/// The value a contract constructor reverts with when it is called after it has already run.
+/// It matches `std::error_signals::CONSTRUCTOR_ALREADY_RUN_SIGNAL`.
+pub(super) const CONSTRUCTOR_ALREADY_RUN_SIGNAL: u64 = 0xffff_ffff_ffff_0006;
+
+/// Returns the key of the storage slot recording whether the constructor of the contract has
+/// run, i.e. sha256("storage_constructor"), which cannot collide with the keys of storage fields.
+pub(super) fn get_constructor_key() -> Bytes32 {
+    Hasher::hash(format!(
+        "{}constructor",
+        sway_utils::constants::STORAGE_DOMAIN_SEPARATOR
+    ))
+}
+
+/// The value a contract method marked `#[when_not_paused]` reverts with when the contract is
+/// paused. It matches `std::error_signals::PAUSED_SIGNAL`.
+pub(super) const PAUSED_SIGNAL: u64 = 0xffff_ffff_ffff_0008;
+
+/// Returns the key of the storage slot recording whether the contract is paused, i.e.
+/// sha256("storage_paused"). It matches the key used by `std::pausable`.
+pub(super) fn get_paused_key() -> Bytes32 {
+    Hasher::hash(format!(
+        "{}paused",
+        sway_utils::constants::STORAGE_DOMAIN_SEPARATOR
+    ))
+}
+
 /// Determines how values that are less then a word in length
 /// has to be padded to word boundary when in structs or enums.
 #[derive(Default)]
@@ -286,3 +402,99 @@ fn serialize_to_words(
         _ => vec![],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_storage_slots_are_sorted_and_validated() {
+        let slot = |key: u8, value: u8| {
+            StorageSlot::new(Bytes32::new([key; 32]), Bytes32::new([value; 32]))
+        };
+        let field_slot = |key: u8, field: &str| FieldStorageSlot {
+            slot: slot(key, 0),
+            field: field.to_string(),
+            offset: 0,
+        };
+        let slots = VersionedStorageSlots::new(vec![
+            field_slot(2, "storage.b"),
+            field_slot(1, "storage.a"),
+        ]);
+        assert_eq!(slots.version, STORAGE_SLOTS_SCHEMA_VERSION);
+        assert_eq!(slots.storage_slots(), vec![slot(1, 0), slot(2, 0)]);
+
+        let mismatched = slots.mismatched_slots(&[slot(1, 0), slot(2, 1)]);
+        assert_eq!(mismatched.len(), 1);
+        assert_eq!(mismatched[0].field, "storage.b");
+    }
+
+    #[test]
+    fn versioned_storage_slots_round_trip() {
+        let slots = VersionedStorageSlots::new(vec![FieldStorageSlot {
+            slot: StorageSlot::new(Bytes32::new([1; 32]), Bytes32::new([2; 32])),
+            field: "storage.owner".to_string(),
+            offset: 0,
+        }]);
+        let json = serde_json::to_value(&slots).unwrap();
+        assert_eq!(json["version"], STORAGE_SLOTS_SCHEMA_VERSION);
+        assert_eq!(json["slots"][0]["field"], "storage.owner");
+        assert!(json["slots"][0]["key"].is_string());
+        assert_eq!(
+            serde_json::from_value::<VersionedStorageSlots>(json).unwrap(),
+            slots
+        );
+    }
+
+    fn compile(src: &str) -> (Engines, ty::TyProgram) {
+        let engines = Engines::default();
+        let program = crate::test_utils::compile_to_ast(
+            &engines,
+            src,
+            crate::namespace::Module::default(),
+            None,
+        );
+        (engines, program)
+    }
+
+    #[test]
+    fn storage_slots_are_regenerated_from_programs() {
+        let (engines, program) = compile(
+            "contract;\n\nstorage {\n    a: u64 = 1,\n    b: bool = true,\n    c: (u64, u64, u64, u64, u64) = (1, 2, 3, 4, 5),\n}\n",
+        );
+        let slots = program_storage_slots(&Handler::default(), &engines, &program).unwrap();
+        assert_eq!(slots.version, STORAGE_SLOTS_SCHEMA_VERSION);
+
+        // The words are stored from the beginning of the slot.
+        let words = |words: &[u64]| {
+            let mut value = [0; 32];
+            for (word, bytes) in words.iter().zip(value.chunks_mut(8)) {
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            Bytes32::new(value)
+        };
+        let key = |ix: usize| get_storage_key::<usize>(&StateIndex::new(ix), &[]);
+        let field_slot = |key, value, field: &str, offset| FieldStorageSlot {
+            slot: StorageSlot::new(key, value),
+            field: field.to_string(),
+            offset,
+        };
+        let mut bool_value = [0; 32];
+        bool_value[0] = 1;
+        // The tuple is spread over two successive slots.
+        let expected = VersionedStorageSlots::new(vec![
+            field_slot(key(0), words(&[1]), "storage.a", 0),
+            field_slot(key(1), Bytes32::new(bool_value), "storage.b", 0),
+            field_slot(key(2), words(&[1, 2, 3, 4]), "storage.c", 0),
+            field_slot(add_to_b256(key(2), 1), words(&[5]), "storage.c", 1),
+        ]);
+        assert_eq!(slots, expected);
+    }
+
+    #[test]
+    fn storage_slots_are_not_regenerated_for_scripts() {
+        let (engines, program) = compile("script;\n\nfn main() {}\n");
+        let slots = program_storage_slots(&Handler::default(), &engines, &program).unwrap();
+        assert!(slots.slots.is_empty());
+    }
+}
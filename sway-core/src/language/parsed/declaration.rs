@@ -45,4 +45,13 @@ impl Declaration {
             false
         }
     }
+
+    /// Checks if this `Declaration` is a function that runs before or after each test.
+    pub(crate) fn is_test_fixture(&self) -> bool {
+        if let Declaration::FunctionDeclaration(fn_decl) = self {
+            fn_decl.is_test_fixture()
+        } else {
+            false
+        }
+    }
 }
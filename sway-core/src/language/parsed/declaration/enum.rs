@@ -17,5 +17,8 @@ pub struct EnumVariant {
     pub attributes: transform::AttributesMap,
     pub type_argument: TypeArgument,
     pub(crate) tag: usize,
+    /// The value of the tag of the variant at runtime. Unless given explicitly, it is the
+    /// discriminant of the previous variant plus one, starting at zero.
+    pub(crate) discriminant: u64,
     pub(crate) span: Span,
 }
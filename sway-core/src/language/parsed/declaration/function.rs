@@ -47,4 +47,20 @@ impl FunctionDeclaration {
             .keys()
             .any(|k| matches!(k, AttributeKind::Test))
     }
+
+    /// Checks if this `FunctionDeclaration` runs before or after each test, i.e. is decorated
+    /// with `#[before_each]` or `#[after_each]`.
+    pub(crate) fn is_test_fixture(&self) -> bool {
+        self.attributes
+            .keys()
+            .any(|k| matches!(k, AttributeKind::BeforeEach | AttributeKind::AfterEach))
+    }
+
+    /// Checks if this `FunctionDeclaration` may call itself, i.e. is decorated with
+    /// `#[tail_call]`.
+    pub(crate) fn is_tail_call(&self) -> bool {
+        self.attributes
+            .keys()
+            .any(|k| matches!(k, AttributeKind::TailCall))
+    }
 }
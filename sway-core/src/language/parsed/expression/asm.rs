@@ -9,7 +9,11 @@ use sway_types::{ident::Ident, span::Span};
 pub struct AsmExpression {
     pub registers: Vec<AsmRegisterDeclaration>,
     pub(crate) body: Vec<AsmOp>,
-    pub(crate) returns: Option<(AsmRegister, Span)>,
+    /// The registers returned by the block. A block that returns more than one register returns
+    /// them as a tuple.
+    pub(crate) returns: Vec<(AsmRegister, Span)>,
+    /// The initialized registers the block declares to write to.
+    pub(crate) clobbers: Vec<Ident>,
     pub(crate) return_type: TypeInfo,
     pub(crate) whole_block_span: Span,
 }
@@ -18,4 +22,7 @@ pub struct AsmExpression {
 pub struct AsmRegisterDeclaration {
     pub(crate) name: Ident,
     pub initializer: Option<Expression>,
+    /// True for a `const` operand, whose value is evaluated at compile time and used by name as
+    /// an immediate, rather than being put into a register.
+    pub(crate) is_const: bool,
 }
@@ -5,6 +5,9 @@ use super::{Expression, Scrutinee};
 #[derive(Debug, Clone)]
 pub struct MatchBranch {
     pub scrutinee: Scrutinee,
+    /// The boolean expression that must hold for the branch to match, in addition to the
+    /// scrutinee. The variables bound by the scrutinee are in scope in the guard.
+    pub guard: Option<Expression>,
     pub result: Expression,
     pub(crate) span: span::Span,
 }
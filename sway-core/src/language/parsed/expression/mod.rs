@@ -72,6 +72,19 @@ pub struct IfExpression {
 pub struct MatchExpression {
     pub value: Box<Expression>,
     pub branches: Vec<MatchBranch>,
+    pub source: MatchExpressionSource,
+}
+
+/// The construct a [MatchExpression] was written as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchExpressionSource {
+    Match,
+    /// The `let` condition of an `if let` expression or of a `while let` loop, desugared into a
+    /// match expression with a catch-all arm for when the pattern does not match.
+    IfLet,
+    /// A `let .. else` statement, desugared into a match expression whose catch-all arm is the
+    /// diverging `else` block.
+    LetElse,
 }
 
 #[derive(Debug, Clone)]
@@ -239,6 +252,10 @@ pub struct WhileLoopExpression {
 pub struct ReassignmentExpression {
     pub lhs: ReassignmentTarget,
     pub rhs: Box<Expression>,
+    /// The call to the assignment operator trait method a compound assignment is desugared to,
+    /// e.g. `core::ops::add_assign(lhs, operand)` for `lhs += operand`, if the type of `lhs`
+    /// implements it. Otherwise, `rhs` is e.g. `core::ops::add(lhs, operand)`.
+    pub assign_op_call: Option<Box<Expression>>,
 }
 
 #[derive(Debug, Clone)]
@@ -69,9 +69,11 @@ pub enum AstNodeContent {
 }
 
 impl ParseTree {
-    /// Excludes all test functions from the parse tree.
+    /// Excludes all test functions, and the functions that run before and after them, from the
+    /// parse tree.
     pub(crate) fn exclude_tests(&mut self) {
-        self.root_nodes.retain(|node| !node.is_test());
+        self.root_nodes
+            .retain(|node| !node.is_test() && !node.is_test_fixture());
     }
 }
 
@@ -84,4 +86,13 @@ impl AstNode {
             false
         }
     }
+
+    /// Checks if this `AstNode` is a function that runs before or after each test.
+    pub(crate) fn is_test_fixture(&self) -> bool {
+        if let AstNodeContent::Declaration(decl) = &self.content {
+            decl.is_test_fixture()
+        } else {
+            false
+        }
+    }
 }
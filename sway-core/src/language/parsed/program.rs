@@ -42,7 +42,8 @@ impl std::fmt::Display for TreeType {
 }
 
 impl ParseProgram {
-    /// Excludes all test functions from the parse tree.
+    /// Excludes all test functions, and the functions that run before and after them, from the
+    /// parse tree.
     pub(crate) fn exclude_tests(&mut self) {
         self.root.tree.exclude_tests()
     }
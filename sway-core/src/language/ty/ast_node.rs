@@ -145,6 +145,29 @@ impl CollectTypesMetadata for TyAstNode {
     }
 }
 
+impl UnconstrainedTypeParameters for TyAstNode {
+    fn type_parameter_is_unconstrained(
+        &self,
+        engines: &Engines,
+        type_parameter: &TypeParameter,
+    ) -> bool {
+        match &self.content {
+            TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => {
+                !type_uses_type_parameter(engines, decl.type_ascription.type_id, type_parameter)
+                    && decl
+                        .body
+                        .type_parameter_is_unconstrained(engines, type_parameter)
+            }
+            TyAstNodeContent::Expression(exp) | TyAstNodeContent::ImplicitReturnExpression(exp) => {
+                exp.type_parameter_is_unconstrained(engines, type_parameter)
+            }
+            TyAstNodeContent::Declaration(_)
+            | TyAstNodeContent::SideEffect(_)
+            | TyAstNodeContent::Error(_, _) => true,
+        }
+    }
+}
+
 impl DeterministicallyAborts for TyAstNode {
     fn deterministically_aborts(&self, decl_engine: &DeclEngine, check_call_body: bool) -> bool {
         use TyAstNodeContent::*;
@@ -166,6 +189,16 @@ impl GetDeclIdent for TyAstNode {
 }
 
 impl TyAstNode {
+    /// Returns `true` if the node is an expression that diverges, see [TyExpression::diverges].
+    pub(crate) fn diverges(&self, decl_engine: &DeclEngine) -> bool {
+        match &self.content {
+            TyAstNodeContent::Expression(exp) | TyAstNodeContent::ImplicitReturnExpression(exp) => {
+                exp.diverges(decl_engine)
+            }
+            _ => false,
+        }
+    }
+
     /// recurse into `self` and get any return statements -- used to validate that all returns
     /// do indeed return the correct type
     /// This does _not_ extract implicit return statements as those are not control flow! This is
@@ -237,7 +270,8 @@ impl TyAstNode {
     pub(crate) fn is_entry_point(&self, decl_engine: &DeclEngine, tree_type: &TreeType) -> bool {
         match tree_type {
             TreeType::Predicate | TreeType::Script => {
-                // Predicates and scripts have main and test functions as entry points.
+                // Predicates and scripts have main and test functions as entry points, along
+                // with the functions that run before and after the tests.
                 match self {
                     TyAstNode {
                         span: _,
@@ -249,7 +283,7 @@ impl TyAstNode {
                         ..
                     } => {
                         let decl = decl_engine.get_function(decl_id);
-                        decl.is_entry()
+                        decl.is_entry() || decl.is_test_fixture()
                     }
                     _ => false,
                 }
@@ -265,7 +299,9 @@ impl TyAstNode {
                     ..
                 } => {
                     let decl = decl_engine.get_function(decl_id);
-                    decl.visibility == Visibility::Public || decl.is_test()
+                    decl.visibility == Visibility::Public
+                        || decl.is_test()
+                        || decl.is_test_fixture()
                 }
                 TyAstNode {
                     content:
@@ -5,7 +5,7 @@ use sway_types::Span;
 
 use crate::{
     decl_engine::*, engine_threading::*, language::ty::*, semantic_analysis::TypeCheckContext,
-    type_system::*, types::DeterministicallyAborts,
+    type_system::*, types::*,
 };
 
 #[derive(Clone, Debug)]
@@ -75,6 +75,18 @@ impl UpdateConstantExpression for TyCodeBlock {
     }
 }
 
+impl UnconstrainedTypeParameters for TyCodeBlock {
+    fn type_parameter_is_unconstrained(
+        &self,
+        engines: &Engines,
+        type_parameter: &TypeParameter,
+    ) -> bool {
+        self.contents
+            .iter()
+            .all(|node| node.type_parameter_is_unconstrained(engines, type_parameter))
+    }
+}
+
 impl DeterministicallyAborts for TyCodeBlock {
     fn deterministically_aborts(&self, decl_engine: &DeclEngine, check_call_body: bool) -> bool {
         self.contents
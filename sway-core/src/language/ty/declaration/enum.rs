@@ -15,6 +15,7 @@ use crate::{
     semantic_analysis::type_check_context::MonomorphizeHelper,
     transform,
     type_system::*,
+    types::*,
 };
 
 #[derive(Clone, Debug)]
@@ -79,6 +80,18 @@ impl Spanned for TyEnumDecl {
     }
 }
 
+impl UnconstrainedTypeParameters for TyEnumDecl {
+    fn type_parameter_is_unconstrained(
+        &self,
+        engines: &Engines,
+        type_parameter: &TypeParameter,
+    ) -> bool {
+        !self.variants.iter().any(|variant| {
+            type_uses_type_parameter(engines, variant.type_argument.type_id, type_parameter)
+        })
+    }
+}
+
 impl MonomorphizeHelper for TyEnumDecl {
     fn type_parameters(&self) -> &[TypeParameter] {
         &self.type_parameters
@@ -112,6 +125,14 @@ impl TyEnumDecl {
             })),
         }
     }
+
+    /// Returns the discriminant of the variant with the given `tag`.
+    pub(crate) fn discriminant_of_tag(&self, tag: usize) -> u64 {
+        self.variants
+            .iter()
+            .find(|variant| variant.tag == tag)
+            .map_or(tag as u64, |variant| variant.discriminant)
+    }
 }
 
 impl Spanned for TyEnumVariant {
@@ -125,6 +146,8 @@ pub struct TyEnumVariant {
     pub name: Ident,
     pub type_argument: TypeArgument,
     pub(crate) tag: usize,
+    /// The value of the tag of the variant at runtime and in the ABI encoding.
+    pub discriminant: u64,
     pub span: Span,
     pub attributes: transform::AttributesMap,
 }
@@ -134,6 +157,7 @@ impl HashWithEngines for TyEnumVariant {
         self.name.hash(state);
         self.type_argument.hash(state, engines);
         self.tag.hash(state);
+        self.discriminant.hash(state);
     }
 }
 
@@ -143,6 +167,7 @@ impl PartialEqWithEngines for TyEnumVariant {
         self.name == other.name
             && self.type_argument.eq(&other.type_argument, engines)
             && self.tag == other.tag
+            && self.discriminant == other.discriminant
     }
 }
 
@@ -152,6 +177,7 @@ impl OrdWithEngines for TyEnumVariant {
             name: ln,
             type_argument: lta,
             tag: lt,
+            discriminant: ld,
             // these fields are not compared because they aren't relevant/a
             // reliable source of obj v. obj distinction
             span: _,
@@ -161,6 +187,7 @@ impl OrdWithEngines for TyEnumVariant {
             name: rn,
             type_argument: rta,
             tag: rt,
+            discriminant: rd,
             // these fields are not compared because they aren't relevant/a
             // reliable source of obj v. obj distinction
             span: _,
@@ -169,6 +196,7 @@ impl OrdWithEngines for TyEnumVariant {
         ln.cmp(rn)
             .then_with(|| lta.cmp(rta, engines))
             .then_with(|| lt.cmp(rt))
+            .then_with(|| ld.cmp(rd))
     }
 }
 
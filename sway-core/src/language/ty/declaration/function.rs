@@ -1,5 +1,4 @@
 use std::{
-    collections::HashSet,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -191,23 +190,24 @@ impl UnconstrainedTypeParameters for TyFunctionDecl {
         engines: &Engines,
         type_parameter: &TypeParameter,
     ) -> bool {
-        let type_engine = engines.te();
-        let mut all_types: HashSet<TypeId> = self
-            .type_parameters
-            .iter()
-            .map(|type_param| type_param.type_id)
-            .collect();
-        all_types.extend(self.parameters.iter().flat_map(|param| {
-            let mut inner = param.type_argument.type_id.extract_inner_types(engines);
-            inner.insert(param.type_argument.type_id);
-            inner
-        }));
-        all_types.extend(self.return_type.type_id.extract_inner_types(engines));
-        all_types.insert(self.return_type.type_id);
-        let type_parameter_info = type_engine.get(type_parameter.type_id);
-        all_types
+        let uses = |type_id: TypeId| type_uses_type_parameter(engines, type_id, type_parameter);
+        let used_in_signature = self
+            .parameters
             .iter()
-            .any(|type_id| type_engine.get(*type_id).eq(&type_parameter_info, engines))
+            .map(|param| param.type_argument.type_id)
+            .chain(std::iter::once(self.return_type.type_id))
+            .chain(
+                self.type_parameters
+                    .iter()
+                    .flat_map(|type_param| type_param.trait_constraints.iter())
+                    .flat_map(|constraint| constraint.type_arguments.iter())
+                    .map(|type_arg| type_arg.type_id),
+            )
+            .any(uses);
+        !used_in_signature
+            && self
+                .body
+                .type_parameter_is_unconstrained(engines, type_parameter)
     }
 }
 
@@ -277,6 +277,23 @@ impl TyFunctionDecl {
         }
     }
 
+    /// Returns the name of a generic function followed by its current type arguments, as in
+    /// `foo<u64>`, or `None` if the function is not generic.
+    pub(crate) fn instantiation_name(&self, engines: &Engines) -> Option<String> {
+        if self.type_parameters.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{}<{}>",
+            self.name,
+            self.type_parameters
+                .iter()
+                .map(|p| engines.help_out(p.type_id).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    }
+
     /// If there are parameters, join their spans. Otherwise, use the fn name span.
     pub(crate) fn parameters_span(&self) -> Span {
         if !self.parameters.is_empty() {
@@ -357,6 +374,37 @@ impl TyFunctionDecl {
             .contains_key(&transform::AttributeKind::Test)
     }
 
+    /// Whether or not this function is the constructor of a contract, i.e. decorated with
+    /// `#[constructor]`.
+    pub fn is_constructor(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::Constructor)
+    }
+
+    /// Whether or not this function reverts while the contract is paused, i.e. decorated with
+    /// `#[when_not_paused]`.
+    pub fn is_when_not_paused(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::WhenNotPaused)
+    }
+
+    /// Whether or not this function's self-recursive calls must be turned into loops, i.e.
+    /// decorated with `#[tail_call]`.
+    pub fn is_tail_call(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::TailCall)
+    }
+
+    /// Whether or not this function runs before or after each unit test of its module, i.e.
+    /// decorated with `#[before_each]` or `#[after_each]`.
+    pub fn is_test_fixture(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::BeforeEach)
+            || self
+                .attributes
+                .contains_key(&transform::AttributeKind::AfterEach)
+    }
+
     pub fn inline(&self) -> Option<Inline> {
         match self
             .attributes
@@ -446,3 +494,27 @@ impl TyFunctionSig {
         }
     }
 }
+
+/// Runs `f`, which instantiates the generic function named `instantiation_name`, and records
+/// the instantiation in the errors of unsatisfied trait constraints `f` emits, so that they can
+/// point back to the call at `call_span`.
+pub(crate) fn record_instantiation<T>(
+    handler: &Handler,
+    instantiation_name: Option<String>,
+    call_span: &Span,
+    f: impl FnOnce(&Handler) -> Result<T, ErrorEmitted>,
+) -> Result<T, ErrorEmitted> {
+    let Some(name) = instantiation_name else {
+        return f(handler);
+    };
+    let instantiation_handler = Handler::default();
+    let res = f(&instantiation_handler);
+    let (errors, warnings) = instantiation_handler.consume();
+    for warning in warnings {
+        handler.emit_warn(warning);
+    }
+    for error in errors {
+        handler.emit_err(error.in_instantiation_of(name.clone(), call_span.clone()));
+    }
+    res
+}
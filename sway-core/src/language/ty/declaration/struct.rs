@@ -15,6 +15,7 @@ use crate::{
     semantic_analysis::type_check_context::MonomorphizeHelper,
     transform,
     type_system::*,
+    types::*,
 };
 
 #[derive(Clone, Debug)]
@@ -79,6 +80,18 @@ impl Spanned for TyStructDecl {
     }
 }
 
+impl UnconstrainedTypeParameters for TyStructDecl {
+    fn type_parameter_is_unconstrained(
+        &self,
+        engines: &Engines,
+        type_parameter: &TypeParameter,
+    ) -> bool {
+        !self.fields.iter().any(|field| {
+            type_uses_type_parameter(engines, field.type_argument.type_id, type_parameter)
+        })
+    }
+}
+
 impl MonomorphizeHelper for TyStructDecl {
     fn type_parameters(&self) -> &[TypeParameter] {
         &self.type_parameters
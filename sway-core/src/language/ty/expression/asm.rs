@@ -8,11 +8,14 @@ use crate::{engine_threading::*, language::ty::*, type_system::*};
 pub struct TyAsmRegisterDeclaration {
     pub initializer: Option<TyExpression>,
     pub(crate) name: Ident,
+    /// True for a `const` operand, whose value is used as an immediate.
+    pub(crate) is_const: bool,
 }
 
 impl PartialEqWithEngines for TyAsmRegisterDeclaration {
     fn eq(&self, other: &Self, engines: &Engines) -> bool {
         self.name == other.name
+            && self.is_const == other.is_const
             && if let (Some(l), Some(r)) = (&self.initializer, &other.initializer) {
                 l.eq(r, engines)
             } else {
@@ -23,8 +26,13 @@ impl PartialEqWithEngines for TyAsmRegisterDeclaration {
 
 impl HashWithEngines for TyAsmRegisterDeclaration {
     fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
-        let TyAsmRegisterDeclaration { initializer, name } = self;
+        let TyAsmRegisterDeclaration {
+            initializer,
+            name,
+            is_const,
+        } = self;
         name.hash(state);
+        is_const.hash(state);
         if let Some(x) = initializer.as_ref() {
             x.hash(state, engines)
         }
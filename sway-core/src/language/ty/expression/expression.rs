@@ -312,6 +312,96 @@ impl CollectTypesMetadata for TyExpression {
     }
 }
 
+impl UnconstrainedTypeParameters for TyExpression {
+    fn type_parameter_is_unconstrained(
+        &self,
+        engines: &Engines,
+        type_parameter: &TypeParameter,
+    ) -> bool {
+        use TyExpressionVariant::*;
+        let uses = |type_id: TypeId| type_uses_type_parameter(engines, type_id, type_parameter);
+        let unconstrained =
+            |exp: &TyExpression| exp.type_parameter_is_unconstrained(engines, type_parameter);
+        if uses(self.return_type) {
+            return false;
+        }
+        match &self.expression {
+            FunctionApplication {
+                arguments,
+                contract_call_params,
+                type_binding,
+                ..
+            } => {
+                // Type arguments of calls such as `size_of::<T>()` use `T` without it being
+                // part of the type of the call.
+                type_binding.as_ref().map_or(true, |binding| {
+                    !binding
+                        .type_arguments
+                        .to_vec()
+                        .iter()
+                        .any(|type_arg| uses(type_arg.type_id))
+                }) && arguments.iter().all(|(_, arg)| unconstrained(arg))
+                    && contract_call_params.values().all(unconstrained)
+            }
+            IntrinsicFunction(kind) => {
+                !kind
+                    .type_arguments
+                    .iter()
+                    .any(|type_arg| uses(type_arg.type_id))
+                    && kind.arguments.iter().all(unconstrained)
+            }
+            LazyOperator { lhs, rhs, .. }
+            | ArrayIndex {
+                prefix: lhs,
+                index: rhs,
+            } => unconstrained(lhs) && unconstrained(rhs),
+            Tuple { fields } => fields.iter().all(unconstrained),
+            Array { contents, .. } => contents.iter().all(unconstrained),
+            StructExpression { fields, .. } => {
+                fields.iter().all(|field| unconstrained(&field.value))
+            }
+            CodeBlock(code_block) => {
+                code_block.type_parameter_is_unconstrained(engines, type_parameter)
+            }
+            MatchExp { desugared, .. } => unconstrained(desugared),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                unconstrained(condition)
+                    && unconstrained(then)
+                    && r#else.as_deref().map_or(true, unconstrained)
+            }
+            AsmExpression { registers, .. } => registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref())
+                .all(unconstrained),
+            StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => {
+                unconstrained(prefix)
+            }
+            EnumInstantiation { contents, .. } => contents.as_deref().map_or(true, unconstrained),
+            AbiCast { address, .. } => unconstrained(address),
+            EnumTag { exp } | UnsafeDowncast { exp, .. } | Return(exp) | Ref(exp) | Deref(exp) => {
+                unconstrained(exp)
+            }
+            WhileLoop { condition, body } => {
+                unconstrained(condition)
+                    && body.type_parameter_is_unconstrained(engines, type_parameter)
+            }
+            Reassignment(reassignment) => unconstrained(&reassignment.rhs),
+            Literal(_)
+            | ConstantExpression { .. }
+            | VariableExpression { .. }
+            | FunctionParameter
+            | StorageAccess(_)
+            | AbiName(_)
+            | Break
+            | Continue => true,
+        }
+    }
+}
+
 impl DeterministicallyAborts for TyExpression {
     fn deterministically_aborts(&self, decl_engine: &DeclEngine, check_call_body: bool) -> bool {
         use TyExpressionVariant::*;
@@ -429,6 +519,31 @@ impl TyExpression {
         self.expression.gather_return_statements()
     }
 
+    /// Returns `true` if the evaluation of `self` never completes normally, either because it
+    /// deterministically aborts or because it breaks out of or continues the enclosing loop.
+    pub(crate) fn diverges(&self, decl_engine: &DeclEngine) -> bool {
+        match &self.expression {
+            TyExpressionVariant::Break | TyExpressionVariant::Continue => true,
+            TyExpressionVariant::CodeBlock(block) => {
+                block.contents.iter().any(|node| node.diverges(decl_engine))
+            }
+            TyExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                condition.diverges(decl_engine)
+                    || (then.diverges(decl_engine)
+                        && r#else
+                            .as_ref()
+                            .map(|x| x.diverges(decl_engine))
+                            .unwrap_or(false))
+            }
+            TyExpressionVariant::MatchExp { desugared, .. } => desugared.diverges(decl_engine),
+            _ => self.deterministically_aborts(decl_engine, true),
+        }
+    }
+
     /// gathers the mutability of the expressions within
     pub(crate) fn gather_mutability(&self) -> VariableMutability {
         match &self.expression {
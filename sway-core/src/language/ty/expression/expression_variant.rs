@@ -82,7 +82,7 @@ pub enum TyExpressionVariant {
     AsmExpression {
         registers: Vec<TyAsmRegisterDeclaration>,
         body: Vec<AsmOp>,
-        returns: Option<(AsmRegister, Span)>,
+        returns: Vec<(AsmRegister, Span)>,
         whole_block_span: Span,
     },
     // like a variable expression but it has multiple parts,
@@ -767,6 +767,7 @@ impl ReplaceDecls for TyExpressionVariant {
             match self {
                 Literal(..) => (),
                 FunctionApplication {
+                    ref call_path,
                     ref mut fn_ref,
                     ref mut arguments,
                     ..
@@ -801,15 +802,23 @@ impl ReplaceDecls for TyExpressionVariant {
 
                     // Handle the trait constraints. This includes checking to see if the trait
                     // constraints are satisfied and replacing old decl ids based on the
-                    let inner_decl_mapping =
-                        TypeParameter::gather_decl_mapping_from_trait_constraints(
-                            handler,
-                            ctx.by_ref(),
-                            &method.type_parameters,
-                            method.name.as_str(),
-                            &method.name.span(),
-                        )?;
-                    method.replace_decls(&inner_decl_mapping, handler, ctx)?;
+                    let instantiation_name = method.instantiation_name(ctx.engines());
+                    record_instantiation(
+                        handler,
+                        instantiation_name,
+                        &call_path.span(),
+                        |handler| {
+                            let inner_decl_mapping =
+                                TypeParameter::gather_decl_mapping_from_trait_constraints(
+                                    handler,
+                                    ctx.by_ref(),
+                                    &method.type_parameters,
+                                    method.name.as_str(),
+                                    &method.name.span(),
+                                )?;
+                            method.replace_decls(&inner_decl_mapping, handler, ctx)
+                        },
+                    )?;
                     decl_engine.replace(*new_decl_ref.id(), method);
                 }
                 LazyOperator { lhs, rhs, .. } => {
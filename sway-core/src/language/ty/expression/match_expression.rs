@@ -33,6 +33,8 @@ pub(crate) struct TyMatchBranch {
     /// A boolean expression that represents the total match arm requirement,
     /// or `None` if the match arm is a catch-all arm.
     pub(crate) condition: MatchBranchCondition,
+    /// True if the match arm has a guard, which is then a part of the `condition`.
+    pub(crate) is_guarded: bool,
     /// The resulting [crate::ty::TyCodeBlock] that includes the match arm variable declarations
     /// and the typed result from the original untyped branch result.
     pub(crate) result: TyExpression,
@@ -6,6 +6,7 @@ mod module;
 mod program;
 mod side_effect;
 mod variable_mutability;
+mod visitor;
 
 pub use ast_node::*;
 pub use code_block::*;
@@ -15,3 +16,4 @@ pub use module::*;
 pub use program::*;
 pub use side_effect::*;
 pub use variable_mutability::*;
+pub use visitor::*;
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::{
     decl_engine::*,
-    fuel_prelude::fuel_tx::StorageSlot,
+    ir_generation::storage::FieldStorageSlot,
     language::{parsed, ty::*, Purity},
     transform::AllowDeprecatedState,
     type_system::*,
@@ -22,11 +22,28 @@ pub struct TyProgram {
     pub root: TyModule,
     pub declarations: Vec<TyDecl>,
     pub configurables: Vec<TyConstantDecl>,
-    pub storage_slots: Vec<StorageSlot>,
+    /// The initialized storage slots of contracts, along with the fields they belong to.
+    pub storage_slots: Vec<FieldStorageSlot>,
     pub logged_types: Vec<(LogId, TypeId)>,
     pub messages_types: Vec<(MessageId, TypeId)>,
 }
 
+/// An entry function of a program along with the metadata needed to call it, as returned by
+/// [TyProgram::entry_fns_metadata].
+#[derive(Debug, Clone)]
+pub struct TyEntryFn {
+    pub name: Ident,
+    pub decl_id: DeclId<TyFunctionDecl>,
+    /// The selector of a contract method. The `main` functions of scripts and predicates are
+    /// called without a selector.
+    pub selector: Option<[u8; 4]>,
+    pub parameters: Vec<(Ident, TypeId)>,
+    pub return_type: TypeId,
+    pub purity: Purity,
+    /// Whether the contract method accepts coins, i.e. is decorated with `#[payable]`.
+    pub payable: bool,
+}
+
 fn get_type_not_allowed_error(
     engines: &Engines,
     type_id: TypeId,
@@ -174,6 +191,24 @@ impl TyProgram {
             };
         }
 
+        // Constructors are run by the dispatcher of the contract, so free functions cannot be
+        // constructors.
+        for decl in declarations.iter() {
+            if let TyDecl::FunctionDecl(FunctionDecl { decl_id, .. }) = decl {
+                let func = decl_engine.get_function(decl_id);
+                if func.is_constructor() {
+                    handler.emit_err(CompileError::ConstructorNotAbiMethod {
+                        span: func.name.span(),
+                    });
+                }
+                if func.is_when_not_paused() {
+                    handler.emit_err(CompileError::WhenNotPausedNotAbiMethod {
+                        span: func.name.span(),
+                    });
+                }
+            }
+        }
+
         // Some checks that are specific to non-contracts
         if kind != parsed::TreeType::Contract {
             // impure functions are disallowed in non-contracts
@@ -230,6 +265,50 @@ impl TyProgram {
                     }
                 }
 
+                // A contract has at most one constructor, which records in storage that it has
+                // run so that it cannot be called again after the deployment.
+                let mut has_constructor = false;
+                for entry in abi_entries.iter() {
+                    let func = decl_engine.get_function(entry);
+                    if !func.is_constructor() {
+                        continue;
+                    }
+                    if has_constructor {
+                        handler.emit_err(CompileError::MultipleConstructors {
+                            name: func.name.clone(),
+                            span: func.name.span(),
+                        });
+                    }
+                    if func.purity != Purity::ReadsWrites {
+                        handler.emit_err(CompileError::ConstructorWithoutStorageAccess {
+                            name: func.name.clone(),
+                            span: func.name.span(),
+                        });
+                    }
+                    has_constructor = true;
+                }
+
+                // Methods marked `#[when_not_paused]` read in storage whether the contract is
+                // paused.
+                for entry in abi_entries.iter() {
+                    let func = decl_engine.get_function(entry);
+                    if func.is_when_not_paused()
+                        && !matches!(func.purity, Purity::Reads | Purity::ReadsWrites)
+                    {
+                        handler.emit_err(CompileError::WhenNotPausedWithoutStorageRead {
+                            name: func.name.clone(),
+                            span: func.name.span(),
+                        });
+                    }
+                }
+
+                // Order the entries by name, so that reordering the ABI implementations or the
+                // methods within them does not change the dispatcher, the ABI JSON or the log ids,
+                // and with them the bytecode of the contract.
+                abi_entries.sort_by_cached_key(|entry| {
+                    decl_engine.get_function(entry).name.as_str().to_string()
+                });
+
                 TyProgramKind::Contract { abi_entries }
             }
             parsed::TreeType::Library => {
@@ -389,6 +468,56 @@ impl TyProgram {
         Ok((typed_program_kind, declarations, configurables))
     }
 
+    /// The declarations of the entry functions of the program: the ABI methods of a contract in
+    /// the order of its dispatcher, or the `main` function of a script or a predicate. Libraries
+    /// have no entry functions.
+    pub fn entry_fns(&self) -> Vec<DeclId<TyFunctionDecl>> {
+        match &self.kind {
+            TyProgramKind::Contract { abi_entries } => abi_entries.clone(),
+            TyProgramKind::Script { main_function }
+            | TyProgramKind::Predicate { main_function } => {
+                vec![*main_function]
+            }
+            TyProgramKind::Library { .. } => vec![],
+        }
+    }
+
+    /// The entry functions of the program along with their selectors, signatures, purity and
+    /// payability, in the order of [TyProgram::entry_fns].
+    pub fn entry_fns_metadata(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+    ) -> Result<Vec<TyEntryFn>, ErrorEmitted> {
+        let is_contract = matches!(self.kind, TyProgramKind::Contract { .. });
+        self.entry_fns()
+            .into_iter()
+            .map(|decl_id| {
+                let func = engines.de().get_function(&decl_id);
+                let selector = if is_contract {
+                    Some(func.to_fn_selector_value(handler, engines)?)
+                } else {
+                    None
+                };
+                Ok(TyEntryFn {
+                    name: func.name.clone(),
+                    decl_id,
+                    selector,
+                    parameters: func
+                        .parameters
+                        .iter()
+                        .map(|param| (param.name.clone(), param.type_argument.type_id))
+                        .collect(),
+                    return_type: func.return_type.type_id,
+                    purity: func.purity,
+                    payable: func
+                        .attributes
+                        .contains_key(&crate::transform::AttributeKind::Payable),
+                })
+            })
+            .collect()
+    }
+
     /// All test function declarations within the program.
     pub fn test_fns<'a: 'b, 'b>(
         &'b self,
@@ -555,3 +684,119 @@ fn disallow_impure_functions(
     errs.append(&mut err_purity);
     errs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use sha2::{Digest, Sha256};
+
+    fn compile(name: &str, src: &str) -> (Engines, TyProgram) {
+        let engines = Engines::default();
+        let typed = test_utils::compile_to_ast(
+            &engines,
+            src,
+            crate::namespace::Module::default(),
+            Some(&test_utils::source_build_config(name)),
+        );
+        (engines, typed)
+    }
+
+    fn selector(signature: &str) -> [u8; 4] {
+        Sha256::digest(signature.as_bytes())[..4]
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn entry_fns_metadata_of_contract() {
+        let src = r#"
+contract;
+
+abi MyContract {
+    #[storage(read)]
+    fn get() -> u64;
+    #[payable]
+    fn pay();
+    #[storage(read, write)]
+    fn set(value: u64, flag: bool);
+}
+
+impl MyContract for Contract {
+    #[storage(read)]
+    fn get() -> u64 {
+        0
+    }
+    #[payable]
+    fn pay() {}
+    #[storage(read, write)]
+    fn set(value: u64, flag: bool) {}
+}
+"#;
+        let (engines, program) = compile("entry-fns-contract", src);
+        let handler = Handler::default();
+        let entry_fns = program.entry_fns_metadata(&handler, &engines).unwrap();
+
+        let summary: Vec<_> = entry_fns
+            .iter()
+            .map(|entry_fn| {
+                (
+                    entry_fn.name.as_str(),
+                    entry_fn.selector,
+                    entry_fn.purity,
+                    entry_fn.payable,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("get", Some(selector("get()")), Purity::Reads, false),
+                ("pay", Some(selector("pay()")), Purity::Pure, true),
+                (
+                    "set",
+                    Some(selector("set(u64,bool)")),
+                    Purity::ReadsWrites,
+                    false
+                ),
+            ]
+        );
+
+        let set = &entry_fns[2];
+        let parameters: Vec<_> = set
+            .parameters
+            .iter()
+            .map(|(name, type_id)| (name.as_str(), engines.help_out(*type_id).to_string()))
+            .collect();
+        assert_eq!(
+            parameters,
+            vec![("value", "u64".into()), ("flag", "bool".into())]
+        );
+        assert_eq!(
+            engines.help_out(entry_fns[0].return_type).to_string(),
+            "u64"
+        );
+    }
+
+    #[test]
+    fn entry_fns_metadata_of_script() {
+        let src = r#"
+script;
+
+fn main(x: u64) -> u64 {
+    x
+}
+"#;
+        let (engines, program) = compile("entry-fns-script", src);
+        let handler = Handler::default();
+        let entry_fns = program.entry_fns_metadata(&handler, &engines).unwrap();
+
+        assert_eq!(entry_fns.len(), 1);
+        let main = &entry_fns[0];
+        assert_eq!(main.name.as_str(), "main");
+        assert_eq!(main.selector, None);
+        assert_eq!(main.purity, Purity::Pure);
+        assert!(!main.payable);
+        assert_eq!(main.parameters.len(), 1);
+    }
+}
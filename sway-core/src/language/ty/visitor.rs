@@ -0,0 +1,351 @@
+//! A visitor over the typed AST, for analyses that inspect the declarations and expressions of a
+//! program along with their resolved types.
+//!
+//! Implementors of [TyVisitor] override the `visit_*` methods for the nodes they are interested
+//! in, and call the matching `walk_*` function to continue into the children of the node. Every
+//! `visit_*` method defaults to its `walk_*` function, so a visitor overriding nothing traverses
+//! the whole program. New kinds of nodes are only ever added to the visitor as new `visit_*`
+//! methods with default implementations, so that existing visitors keep compiling.
+//!
+//! The declarations referred to by the nodes are looked up in the
+//! [DeclEngine](crate::decl_engine::DeclEngine) of the [Engines], and the types of the
+//! expressions can be resolved with its [TypeEngine](crate::type_system::TypeEngine). Only the
+//! declarations written in the program are visited, the monomorphized copies of generic
+//! declarations and the declarations of dependencies are not.
+
+use crate::{
+    language::ty::{self, TyDecl, TyTraitItem},
+    Engines,
+};
+
+/// A visitor over the nodes of a [ty::TyProgram]. See the [module docs](self) for details.
+pub trait TyVisitor {
+    fn visit_program(&mut self, engines: &Engines, program: &ty::TyProgram) {
+        walk_program(self, engines, program);
+    }
+
+    fn visit_module(&mut self, engines: &Engines, module: &ty::TyModule) {
+        walk_module(self, engines, module);
+    }
+
+    fn visit_ast_node(&mut self, engines: &Engines, node: &ty::TyAstNode) {
+        walk_ast_node(self, engines, node);
+    }
+
+    fn visit_decl(&mut self, engines: &Engines, decl: &TyDecl) {
+        walk_decl(self, engines, decl);
+    }
+
+    fn visit_variable_decl(&mut self, engines: &Engines, decl: &ty::TyVariableDecl) {
+        walk_variable_decl(self, engines, decl);
+    }
+
+    fn visit_constant_decl(&mut self, engines: &Engines, decl: &ty::TyConstantDecl) {
+        walk_constant_decl(self, engines, decl);
+    }
+
+    fn visit_function_decl(&mut self, engines: &Engines, decl: &ty::TyFunctionDecl) {
+        walk_function_decl(self, engines, decl);
+    }
+
+    fn visit_trait_decl(&mut self, engines: &Engines, decl: &ty::TyTraitDecl) {
+        walk_trait_decl(self, engines, decl);
+    }
+
+    fn visit_abi_decl(&mut self, engines: &Engines, decl: &ty::TyAbiDecl) {
+        walk_abi_decl(self, engines, decl);
+    }
+
+    fn visit_impl_trait(&mut self, engines: &Engines, decl: &ty::TyImplTrait) {
+        walk_impl_trait(self, engines, decl);
+    }
+
+    fn visit_storage_decl(&mut self, engines: &Engines, decl: &ty::TyStorageDecl) {
+        walk_storage_decl(self, engines, decl);
+    }
+
+    fn visit_struct_decl(&mut self, _engines: &Engines, _decl: &ty::TyStructDecl) {}
+
+    fn visit_enum_decl(&mut self, _engines: &Engines, _decl: &ty::TyEnumDecl) {}
+
+    fn visit_code_block(&mut self, engines: &Engines, code_block: &ty::TyCodeBlock) {
+        walk_code_block(self, engines, code_block);
+    }
+
+    fn visit_expression(&mut self, engines: &Engines, expr: &ty::TyExpression) {
+        walk_expression(self, engines, expr);
+    }
+}
+
+/// Visits the root module of the program, and through it all of its submodules.
+pub fn walk_program<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    program: &ty::TyProgram,
+) {
+    visitor.visit_module(engines, &program.root);
+}
+
+/// Visits the submodules of the module, and then its nodes.
+pub fn walk_module<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    module: &ty::TyModule,
+) {
+    for (_, submodule) in module.submodules.iter() {
+        visitor.visit_module(engines, &submodule.module);
+    }
+    for node in module.all_nodes.iter() {
+        visitor.visit_ast_node(engines, node);
+    }
+}
+
+pub fn walk_ast_node<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    node: &ty::TyAstNode,
+) {
+    match &node.content {
+        ty::TyAstNodeContent::Declaration(decl) => visitor.visit_decl(engines, decl),
+        ty::TyAstNodeContent::Expression(expr)
+        | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => {
+            visitor.visit_expression(engines, expr)
+        }
+        ty::TyAstNodeContent::SideEffect(_) | ty::TyAstNodeContent::Error(..) => {}
+    }
+}
+
+/// Looks up the declaration in the declaration engine and visits it.
+pub fn walk_decl<V: TyVisitor + ?Sized>(visitor: &mut V, engines: &Engines, decl: &TyDecl) {
+    let decl_engine = engines.de();
+    match decl {
+        TyDecl::VariableDecl(decl) => visitor.visit_variable_decl(engines, decl),
+        TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => {
+            visitor.visit_constant_decl(engines, &decl_engine.get_constant(decl_id))
+        }
+        TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
+            visitor.visit_function_decl(engines, &decl_engine.get_function(decl_id))
+        }
+        TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. }) => {
+            visitor.visit_trait_decl(engines, &decl_engine.get_trait(decl_id))
+        }
+        TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
+            visitor.visit_struct_decl(engines, &decl_engine.get_struct(decl_id))
+        }
+        TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
+            visitor.visit_enum_decl(engines, &decl_engine.get_enum(decl_id))
+        }
+        TyDecl::ImplTrait(ty::ImplTrait { decl_id, .. }) => {
+            visitor.visit_impl_trait(engines, &decl_engine.get_impl_trait(decl_id))
+        }
+        TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
+            visitor.visit_abi_decl(engines, &decl_engine.get_abi(decl_id))
+        }
+        TyDecl::StorageDecl(ty::StorageDecl { decl_id, .. }) => {
+            visitor.visit_storage_decl(engines, &decl_engine.get_storage(decl_id))
+        }
+        TyDecl::TraitTypeDecl(_)
+        | TyDecl::EnumVariantDecl(_)
+        | TyDecl::GenericTypeForFunctionScope(_)
+        | TyDecl::ErrorRecovery(..)
+        | TyDecl::TypeAliasDecl(_) => {}
+    }
+}
+
+pub fn walk_variable_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyVariableDecl,
+) {
+    visitor.visit_expression(engines, &decl.body);
+}
+
+pub fn walk_constant_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyConstantDecl,
+) {
+    if let Some(value) = &decl.value {
+        visitor.visit_expression(engines, value);
+    }
+}
+
+pub fn walk_function_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyFunctionDecl,
+) {
+    visitor.visit_code_block(engines, &decl.body);
+}
+
+/// Visits the methods and constants of the trait that have a default implementation.
+pub fn walk_trait_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyTraitDecl,
+) {
+    walk_trait_items(visitor, engines, &decl.items);
+}
+
+/// Visits the methods and constants of the ABI that have a default implementation.
+pub fn walk_abi_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyAbiDecl,
+) {
+    walk_trait_items(visitor, engines, &decl.items);
+}
+
+pub fn walk_impl_trait<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyImplTrait,
+) {
+    walk_trait_items(visitor, engines, &decl.items);
+}
+
+fn walk_trait_items<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    items: &[TyTraitItem],
+) {
+    let decl_engine = engines.de();
+    for item in items.iter() {
+        match item {
+            TyTraitItem::Fn(decl_ref) => {
+                visitor.visit_function_decl(engines, &decl_engine.get_function(decl_ref))
+            }
+            TyTraitItem::Constant(decl_ref) => {
+                visitor.visit_constant_decl(engines, &decl_engine.get_constant(decl_ref))
+            }
+            TyTraitItem::Type(_) => {}
+        }
+    }
+}
+
+/// Visits the initializers of the storage fields.
+pub fn walk_storage_decl<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    decl: &ty::TyStorageDecl,
+) {
+    for field in decl.fields.iter() {
+        visitor.visit_expression(engines, &field.initializer);
+    }
+}
+
+pub fn walk_code_block<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    code_block: &ty::TyCodeBlock,
+) {
+    for node in code_block.contents.iter() {
+        visitor.visit_ast_node(engines, node);
+    }
+}
+
+/// Visits the expressions directly nested in the expression.
+///
+/// Match expressions are visited through the `if` expressions they are desugared into.
+pub fn walk_expression<V: TyVisitor + ?Sized>(
+    visitor: &mut V,
+    engines: &Engines,
+    expr: &ty::TyExpression,
+) {
+    match &expr.expression {
+        ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            contract_call_params,
+            ..
+        } => {
+            for (_, arg) in arguments.iter() {
+                visitor.visit_expression(engines, arg);
+            }
+            for param in contract_call_params.values() {
+                visitor.visit_expression(engines, param);
+            }
+        }
+        ty::TyExpressionVariant::LazyOperator { lhs, rhs, .. }
+        | ty::TyExpressionVariant::ArrayIndex {
+            prefix: lhs,
+            index: rhs,
+        } => {
+            visitor.visit_expression(engines, lhs);
+            visitor.visit_expression(engines, rhs);
+        }
+        ty::TyExpressionVariant::Tuple { fields: contents }
+        | ty::TyExpressionVariant::Array { contents, .. } => {
+            for expr in contents.iter() {
+                visitor.visit_expression(engines, expr);
+            }
+        }
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => {
+            for arg in kind.arguments.iter() {
+                visitor.visit_expression(engines, arg);
+            }
+        }
+        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+            for field in fields.iter() {
+                visitor.visit_expression(engines, &field.value);
+            }
+        }
+        ty::TyExpressionVariant::CodeBlock(code_block) => {
+            visitor.visit_code_block(engines, code_block)
+        }
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => {
+            visitor.visit_expression(engines, desugared)
+        }
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            visitor.visit_expression(engines, condition);
+            visitor.visit_expression(engines, then);
+            if let Some(r#else) = r#else {
+                visitor.visit_expression(engines, r#else);
+            }
+        }
+        ty::TyExpressionVariant::AsmExpression { registers, .. } => {
+            for initializer in registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref())
+            {
+                visitor.visit_expression(engines, initializer);
+            }
+        }
+        ty::TyExpressionVariant::StructFieldAccess { prefix: exp, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
+        | ty::TyExpressionVariant::AbiCast { address: exp, .. }
+        | ty::TyExpressionVariant::EnumTag { exp }
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Return(exp)
+        | ty::TyExpressionVariant::Ref(exp)
+        | ty::TyExpressionVariant::Deref(exp) => visitor.visit_expression(engines, exp),
+        ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
+            if let Some(contents) = contents {
+                visitor.visit_expression(engines, contents);
+            }
+        }
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            visitor.visit_expression(engines, condition);
+            visitor.visit_code_block(engines, body);
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            for projection in reassignment.lhs_indices.iter() {
+                if let ty::ProjectionKind::ArrayIndex { index, .. } = projection {
+                    visitor.visit_expression(engines, index);
+                }
+            }
+            visitor.visit_expression(engines, &reassignment.rhs);
+        }
+        ty::TyExpressionVariant::Literal(_)
+        | ty::TyExpressionVariant::ConstantExpression { .. }
+        | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::FunctionParameter
+        | ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AbiName(_)
+        | ty::TyExpressionVariant::Break
+        | ty::TyExpressionVariant::Continue => {}
+    }
+}
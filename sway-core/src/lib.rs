@@ -7,17 +7,23 @@ pub mod engine_threading;
 pub mod abi_generation;
 pub mod asm_generation;
 mod asm_lang;
+pub mod audit;
 mod build_config;
+pub mod call_graph;
 pub mod compiler_generated;
 mod concurrent_slab;
 mod control_flow_analysis;
 pub mod decl_engine;
+pub mod gas;
 pub mod ir_generation;
 pub mod language;
+pub mod lint;
 mod metadata;
 pub mod query_engine;
 pub mod semantic_analysis;
 pub mod source_map;
+#[cfg(test)]
+mod test_utils;
 pub mod transform;
 pub mod type_system;
 
@@ -26,8 +32,9 @@ use crate::query_engine::ModuleCacheEntry;
 use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
-pub use asm_generation::{CompiledBytecode, FinalizedEntry};
+pub use asm_generation::{CompiledBytecode, FinalizedEntry, FunctionSize, GasEstimate};
 pub use build_config::{BuildConfig, BuildTarget, OptLevel};
+pub use sway_ir::{EliminatedFunctions, ExecutionProfile};
 use control_flow_analysis::ControlFlowGraph;
 use metadata::MetadataManager;
 use query_engine::{ModuleCacheKey, ModulePath, ProgramsCacheEntry};
@@ -41,9 +48,9 @@ use sway_ast::AttributeDecl;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_ir::{
     create_o1_pass_group, register_known_passes, Context, Kind, Module, PassGroup, PassManager,
-    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, DCE_NAME, INLINE_MODULE_NAME, MEM2REG_NAME,
-    MEMCPYOPT_NAME, MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME, SIMPLIFYCFG_NAME,
-    SROA_NAME,
+    ARGDEMOTION_NAME, CONSTDEMOTION_NAME, CSE_NAME, DCE_NAME, FUNC_DCE_NAME, INLINE_MODULE_NAME,
+    MEM2REG_NAME, MEMCPYOPT_NAME, MISCDEMOTION_NAME, MODULEPRINTER_NAME, RETDEMOTION_NAME,
+    SIMPLIFYCFG_NAME, SROA_NAME, STORAGE_DSE_NAME, TAIL_CALL_NAME,
 };
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
 use sway_types::SourceEngine;
@@ -66,8 +73,8 @@ pub mod fuel_prelude {
     pub use fuel_vm::{self, fuel_asm, fuel_crypto, fuel_tx, fuel_types};
 }
 
-pub use build_config::ExperimentalFlags;
-pub use engine_threading::Engines;
+pub use build_config::{ExperimentalFeature, ExperimentalFlags};
+pub use engine_threading::{Engines, EnginesSnapshot};
 
 /// Given an input `Arc<str>` and an optional [BuildConfig], parse the input into a [lexed::LexedProgram] and [parsed::ParseProgram].
 ///
@@ -192,7 +199,7 @@ fn parse_in_memory(
     let mut hasher = DefaultHasher::new();
     src.hash(&mut hasher);
     let hash = hasher.finish();
-    let module = sway_parse::parse_file(handler, src, None)?;
+    let module = sway_parse::parse_file(handler, src, None, Default::default())?;
 
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
         &mut to_parsed_lang::Context::default(),
@@ -335,7 +342,15 @@ fn parse_module_tree(
     // Parse this module first.
     let module_dir = path.parent().expect("module file has no parent directory");
     let source_id = engines.se().get_source_id(&path.clone());
-    let module = sway_parse::parse_file(handler, src.clone(), Some(source_id))?;
+    let module = sway_parse::parse_file(
+        handler,
+        src.clone(),
+        Some(source_id),
+        sway_parse::ExperimentalFlags {
+            new_encoding: experimental.new_encoding,
+            test_only: experimental.test_only,
+        },
+    )?;
 
     // Parse all submodules before converting to the `ParseTree`.
     // This always recovers on parse errors for the file itself by skipping that file.
@@ -580,6 +595,12 @@ pub fn parsed_to_ast(
         handler.emit_warn(warn);
     }
 
+    // Check the types inferred for untyped numeric literals.
+    semantic_analysis::numeric_literal_analysis::analyze_program(handler, engines, &typed_program);
+
+    // Check for loops in contract methods whose number of iterations the caller controls.
+    semantic_analysis::unbounded_loop_analysis::analyze_program(handler, engines, &typed_program);
+
     // Check that all storage initializers can be evaluated at compile time.
     let typed_wiss_res = typed_program.get_typed_program_with_initialized_storage_slots(
         handler,
@@ -786,6 +807,38 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         }
     };
 
+    // Make overflowing arithmetic wrap around if overflow checks are disabled.
+    if !build_config.overflow_checks && build_config.build_target == BuildTarget::Fuel {
+        ir_generation::disable_overflow_checks(&mut ir);
+    }
+
+    // Bias the optimizations towards the hot paths of the program, if they were measured.
+    ir.profile = build_config.execution_profile.clone();
+    ir.inline_budget = build_config.inline_budget;
+
+    // Initialize the pass manager and register known passes.
+    let mut pass_mgr = PassManager::default();
+    register_known_passes(&mut pass_mgr);
+
+    // Turn the self-recursive tail calls of the functions marked `#[tail_call]` into loops,
+    // regardless of the optimization level.  Any self-recursive call left is an error.
+    let mut tail_call_group = PassGroup::default();
+    tail_call_group.append_pass(TAIL_CALL_NAME);
+    if let Err(ir_error) = pass_mgr.run(&mut ir, &tail_call_group) {
+        return Err(handler.emit_err(CompileError::InternalOwned(
+            ir_error.to_string(),
+            span::Span::dummy(),
+        )));
+    }
+    let errors = ir_generation::check_tail_calls(&ir);
+    if !errors.is_empty() {
+        let mut last = None;
+        for e in errors {
+            last = Some(handler.emit_err(e))
+        }
+        return Err(last.unwrap());
+    }
+
     // Find all the entry points for purity checking and DCE.
     let entry_point_functions: Vec<::sway_ir::Function> = ir
         .module_iter()
@@ -802,14 +855,14 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         }
     }
 
-    // Initialize the pass manager and register known passes.
-    let mut pass_mgr = PassManager::default();
-    register_known_passes(&mut pass_mgr);
     let mut pass_group = PassGroup::default();
 
     match build_config.optimization_level {
         OptLevel::Opt1 => {
             pass_group.append_group(create_o1_pass_group());
+            if build_config.dse_storage {
+                pass_group.append_pass(STORAGE_DSE_NAME);
+            }
         }
         OptLevel::Opt0 => {
             // Inlining is necessary until #4899 is resolved.
@@ -839,12 +892,18 @@ pub(crate) fn compile_ast_to_ir_to_asm(
             OptLevel::Opt1 => {
                 pass_group.append_pass(SROA_NAME);
                 pass_group.append_pass(MEM2REG_NAME);
+                pass_group.append_pass(CSE_NAME);
                 pass_group.append_pass(DCE_NAME);
             }
             OptLevel::Opt0 => {}
         }
     }
 
+    // Drop the functions which aren't reachable from the entry points before codegen, whatever
+    // the optimization level. These are mostly the library functions of the dependencies which
+    // were inlined into all their callers.
+    pass_group.append_pass(FUNC_DCE_NAME);
+
     if build_config.print_ir {
         pass_group.append_pass(MODULEPRINTER_NAME);
     }
@@ -0,0 +1,525 @@
+//! Configurable lints checking the naming conventions and the style of a program.
+//!
+//! The naming convention lints classify the warnings the compiler already emits for names that
+//! are not idiomatic, while the style lints are checked on the lexed program. Each lint can be
+//! allowed, or denied to turn its violations into errors, in the `[lint]` section of the
+//! package manifest.
+
+use crate::language::{
+    lexed::{LexedModule, LexedProgram},
+    HasModule, HasSubmodules,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use strum::EnumString;
+use sway_ast::{
+    expr::LoopControlFlow, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
+    ExprTupleDescriptor, IfCondition, IfExpr, ItemFn, ItemImplItem, ItemKind, MatchBranchKind,
+    Punctuated, Statement,
+};
+use sway_error::{
+    error::CompileError,
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{Span, Spanned};
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Lint {
+    /// Functions and methods should have snake_case names.
+    NonSnakeCaseFunction,
+    /// Structs, enums, enum variants, traits and type parameters should have UpperCamelCase
+    /// names.
+    NonCamelCaseType,
+    /// Constants should have SCREAMING_SNAKE_CASE names.
+    NonScreamingSnakeCaseConst,
+    /// Functions should not be longer than the `max-function-length`.
+    FunctionTooLong,
+    /// Functions should not be more complex than the `max-cyclomatic-complexity`.
+    CyclomaticComplexity,
+}
+
+impl Lint {
+    pub const ALL: &'static [Self] = &[
+        Self::NonSnakeCaseFunction,
+        Self::NonCamelCaseType,
+        Self::NonScreamingSnakeCaseConst,
+        Self::FunctionTooLong,
+        Self::CyclomaticComplexity,
+    ];
+
+    /// Returns the naming convention lint the compiler warning is about, if any.
+    fn from_warning(warning: &Warning) -> Option<Self> {
+        match warning {
+            Warning::NonSnakeCaseFunctionName { .. } => Some(Self::NonSnakeCaseFunction),
+            Warning::NonClassCaseStructName { .. }
+            | Warning::NonClassCaseTypeParameter { .. }
+            | Warning::NonClassCaseTraitName { .. }
+            | Warning::NonClassCaseEnumName { .. }
+            | Warning::NonClassCaseEnumVariantName { .. } => Some(Self::NonCamelCaseType),
+            Warning::NonScreamingSnakeCaseConstName { .. } => {
+                Some(Self::NonScreamingSnakeCaseConst)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The name of the lint group containing all of the warnings, e.g. in `--deny warnings`.
+pub const WARNINGS_LINT_GROUP: &str = "warnings";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+/// The configuration of the lints, i.e. the `[lint]` section of a package manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LintConfig {
+    /// The lints that are not checked.
+    #[serde(default)]
+    pub allow: BTreeSet<Lint>,
+    /// The lints whose violations are errors rather than warnings.
+    #[serde(default)]
+    pub deny: BTreeSet<Lint>,
+    /// The maximum number of lines of a function, including its signature.
+    #[serde(default = "default_max_function_length")]
+    pub max_function_length: usize,
+    /// The maximum cyclomatic complexity of a function.
+    #[serde(default = "default_max_cyclomatic_complexity")]
+    pub max_cyclomatic_complexity: usize,
+}
+
+fn default_max_function_length() -> usize {
+    100
+}
+
+fn default_max_cyclomatic_complexity() -> usize {
+    20
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            allow: BTreeSet::default(),
+            deny: BTreeSet::default(),
+            max_function_length: default_max_function_length(),
+            max_cyclomatic_complexity: default_max_cyclomatic_complexity(),
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        if self.deny.contains(&lint) {
+            LintLevel::Deny
+        } else if self.allow.contains(&lint) {
+            LintLevel::Allow
+        } else {
+            LintLevel::Warn
+        }
+    }
+}
+
+/// Lints a program, given the handler holding the diagnostics of its compilation.
+///
+/// Returns a handler holding the diagnostics of the compilation along with the violations of the
+/// lints. The compiler warnings about names that are not idiomatic are removed, or turned into
+/// errors, according to the level of their lints.
+pub fn lint(handler: Handler, lexed: &LexedProgram, config: &LintConfig) -> Handler {
+    let (errors, warnings) = handler.consume();
+    let linted = Handler::from_parts(errors, vec![]);
+    for warning in warnings {
+        let lint = Lint::from_warning(&warning.warning_content);
+        match lint.map(|lint| (lint, config.level(lint))) {
+            Some((_, LintLevel::Allow)) => {}
+            Some((lint, LintLevel::Deny)) => {
+                linted.emit_err(CompileError::Lint {
+                    name: lint.to_string(),
+                    message: warning.to_friendly_warning_string(),
+                    span: warning.span,
+                });
+            }
+            _ => linted.emit_warn(warning),
+        }
+    }
+
+    let modules = std::iter::once(&lexed.root).chain(
+        lexed
+            .root
+            .submodules_recursive()
+            .map(|(_, submodule)| submodule.module()),
+    );
+    for item_fn in modules.flat_map(module_fns) {
+        let name = &item_fn.fn_signature.name;
+        let length = item_fn.span().as_str().lines().count();
+        if length > config.max_function_length {
+            emit(
+                &linted,
+                config,
+                Lint::FunctionTooLong,
+                name.span(),
+                format!(
+                    "Function \"{name}\" is {length} lines long, which is more than the maximum \
+                     of {}.",
+                    config.max_function_length
+                ),
+            );
+        }
+        let complexity = cyclomatic_complexity(&item_fn.body.inner);
+        if complexity > config.max_cyclomatic_complexity {
+            emit(
+                &linted,
+                config,
+                Lint::CyclomaticComplexity,
+                name.span(),
+                format!(
+                    "Function \"{name}\" has a cyclomatic complexity of {complexity}, which is \
+                     more than the maximum of {}. Consider splitting it into smaller functions.",
+                    config.max_cyclomatic_complexity
+                ),
+            );
+        }
+    }
+    linted
+}
+
+/// Emits the violation of a lint according to its level.
+fn emit(handler: &Handler, config: &LintConfig, lint: Lint, span: Span, message: String) {
+    let name = lint.to_string();
+    match config.level(lint) {
+        LintLevel::Allow => {}
+        LintLevel::Warn => handler.emit_warn(CompileWarning {
+            span,
+            warning_content: Warning::Lint { name, message },
+        }),
+        LintLevel::Deny => {
+            handler.emit_err(CompileError::Lint {
+                name,
+                message,
+                span,
+            });
+        }
+    }
+}
+
+/// Returns the functions and methods declared within a module, excluding its submodules.
+fn module_fns(module: &LexedModule) -> Vec<&ItemFn> {
+    let mut fns = vec![];
+    for item in &module.tree.items {
+        match &item.value {
+            ItemKind::Fn(item_fn) => fns.push(item_fn),
+            ItemKind::Impl(item_impl) => fns.extend(item_impl.contents.inner.iter().filter_map(
+                |item| match &item.value {
+                    ItemImplItem::Fn(item_fn) => Some(item_fn),
+                    _ => None,
+                },
+            )),
+            ItemKind::Trait(item_trait) => fns.extend(
+                item_trait
+                    .trait_defs_opt
+                    .iter()
+                    .flat_map(|defs| defs.inner.iter().map(|item_fn| &item_fn.value)),
+            ),
+            ItemKind::Abi(item_abi) => fns.extend(
+                item_abi
+                    .abi_defs_opt
+                    .iter()
+                    .flat_map(|defs| defs.inner.iter().map(|item_fn| &item_fn.value)),
+            ),
+            _ => {}
+        }
+    }
+    fns
+}
+
+/// Returns the cyclomatic complexity of a function body, i.e. one plus the number of its
+/// branches, loops and short-circuiting operators.
+fn cyclomatic_complexity(body: &CodeBlockContents) -> usize {
+    let mut complexity = 1;
+    visit_block(body, &mut |expr| {
+        complexity += match expr {
+            Expr::If(if_expr) => if_chain_len(if_expr),
+            Expr::Match { branches, .. } => branches.inner.len().saturating_sub(1),
+            Expr::While { .. } | Expr::LogicalAnd { .. } | Expr::LogicalOr { .. } => 1,
+            _ => 0,
+        }
+    });
+    complexity
+}
+
+/// Returns the number of conditions of an `if` expression and of its `else if` branches.
+fn if_chain_len(if_expr: &IfExpr) -> usize {
+    match &if_expr.else_opt {
+        Some((_, LoopControlFlow::Continue(else_if))) => 1 + if_chain_len(else_if),
+        _ => 1,
+    }
+}
+
+/// Calls `f` on each expression within the block, except within the items declared in it.
+pub(crate) fn visit_block<'a, F: FnMut(&'a Expr)>(block: &'a CodeBlockContents, f: &mut F) {
+    for statement in &block.statements {
+        match statement {
+            Statement::Let(statement_let) => {
+                visit_expr(&statement_let.expr, f);
+                if let Some((_, block)) = &statement_let.else_opt {
+                    visit_block(block.get(), f);
+                }
+            }
+            Statement::Expr { expr, .. } => visit_expr(expr, f),
+            Statement::Item(_) | Statement::Error(..) => {}
+        }
+    }
+    if let Some(expr) = &block.final_expr_opt {
+        visit_expr(expr, f);
+    }
+}
+
+/// Calls `f` on the expression and on each expression nested within it, except within the items
+/// declared in it.
+pub(crate) fn visit_expr<'a, F: FnMut(&'a Expr)>(expr: &'a Expr, f: &mut F) {
+    f(expr);
+    match expr {
+        Expr::Error(..)
+        | Expr::Path(_)
+        | Expr::Literal(_)
+        | Expr::Asm(_)
+        | Expr::Break { .. }
+        | Expr::Continue { .. } => {}
+        Expr::AbiCast { args, .. } => visit_expr(&args.inner.address, f),
+        Expr::Struct { fields, .. } => visit_fields(&fields.inner, f),
+        Expr::Tuple(tuple) => {
+            if let ExprTupleDescriptor::Cons { head, tail, .. } = &tuple.inner {
+                visit_expr(head, f);
+                tail.into_iter().for_each(|elem| visit_expr(elem, f));
+            }
+        }
+        Expr::Parens(inner) => visit_expr(&inner.inner, f),
+        Expr::Block(block) => visit_block(&block.inner, f),
+        Expr::Array(array) => match &array.inner {
+            ExprArrayDescriptor::Sequence(elems) => {
+                elems.into_iter().for_each(|elem| visit_expr(elem, f))
+            }
+            ExprArrayDescriptor::Repeat { value, length, .. } => {
+                visit_expr(value, f);
+                visit_expr(length, f);
+            }
+        },
+        Expr::Return { expr_opt, .. } => {
+            if let Some(expr) = expr_opt {
+                visit_expr(expr, f);
+            }
+        }
+        Expr::If(if_expr) => visit_if(if_expr, f),
+        Expr::Match {
+            value, branches, ..
+        } => {
+            visit_expr(value, f);
+            for branch in &branches.inner {
+                if let Some((_, guard)) = &branch.guard_opt {
+                    visit_expr(guard, f);
+                }
+                match &branch.kind {
+                    MatchBranchKind::Block { block, .. } => visit_block(&block.inner, f),
+                    MatchBranchKind::Expr { expr, .. } => visit_expr(expr, f),
+                }
+            }
+        }
+        Expr::While {
+            condition, block, ..
+        } => {
+            visit_if_condition(condition, f);
+            visit_block(&block.inner, f);
+        }
+        Expr::FuncApp { func, args } => {
+            visit_expr(func, f);
+            for arg in &args.inner {
+                visit_expr(arg, f);
+            }
+        }
+        Expr::Index { target, arg } => {
+            visit_expr(target, f);
+            visit_expr(&arg.inner, f);
+        }
+        Expr::Range { start, end, .. } => {
+            visit_expr(start, f);
+            visit_expr(end, f);
+        }
+        Expr::MethodCall {
+            target,
+            contract_args_opt,
+            args,
+            ..
+        } => {
+            visit_expr(target, f);
+            if let Some(contract_args) = contract_args_opt {
+                visit_fields(&contract_args.inner, f);
+            }
+            for arg in &args.inner {
+                visit_expr(arg, f);
+            }
+        }
+        Expr::FieldProjection { target, .. } | Expr::TupleFieldProjection { target, .. } => {
+            visit_expr(target, f)
+        }
+        Expr::Ref { expr, .. } | Expr::Deref { expr, .. } | Expr::Not { expr, .. } => {
+            visit_expr(expr, f)
+        }
+        Expr::Mul { lhs, rhs, .. }
+        | Expr::Div { lhs, rhs, .. }
+        | Expr::Pow { lhs, rhs, .. }
+        | Expr::Modulo { lhs, rhs, .. }
+        | Expr::Add { lhs, rhs, .. }
+        | Expr::Sub { lhs, rhs, .. }
+        | Expr::Shl { lhs, rhs, .. }
+        | Expr::Shr { lhs, rhs, .. }
+        | Expr::BitAnd { lhs, rhs, .. }
+        | Expr::BitXor { lhs, rhs, .. }
+        | Expr::BitOr { lhs, rhs, .. }
+        | Expr::Equal { lhs, rhs, .. }
+        | Expr::NotEqual { lhs, rhs, .. }
+        | Expr::LessThan { lhs, rhs, .. }
+        | Expr::GreaterThan { lhs, rhs, .. }
+        | Expr::LessThanEq { lhs, rhs, .. }
+        | Expr::GreaterThanEq { lhs, rhs, .. }
+        | Expr::LogicalAnd { lhs, rhs, .. }
+        | Expr::LogicalOr { lhs, rhs, .. } => {
+            visit_expr(lhs, f);
+            visit_expr(rhs, f);
+        }
+        Expr::Reassignment { expr, .. } => visit_expr(expr, f),
+    }
+}
+
+pub(crate) fn visit_if_condition<'a, F: FnMut(&'a Expr)>(condition: &'a IfCondition, f: &mut F) {
+    match condition {
+        IfCondition::Expr(condition) => visit_expr(condition, f),
+        IfCondition::Let { rhs, .. } => visit_expr(rhs, f),
+        IfCondition::And { lhs, rhs, .. } => {
+            visit_if_condition(lhs, f);
+            visit_if_condition(rhs, f);
+        }
+    }
+}
+
+fn visit_if<'a, F: FnMut(&'a Expr)>(if_expr: &'a IfExpr, f: &mut F) {
+    visit_if_condition(&if_expr.condition, f);
+    visit_block(&if_expr.then_block.inner, f);
+    match &if_expr.else_opt {
+        Some((_, LoopControlFlow::Break(block))) => visit_block(&block.inner, f),
+        Some((_, LoopControlFlow::Continue(else_if))) => visit_if(else_if, f),
+        None => {}
+    }
+}
+
+fn visit_fields<'a, F: FnMut(&'a Expr), P>(fields: &'a Punctuated<ExprStructField, P>, f: &mut F) {
+    for field in fields {
+        if let Some((_, expr)) = &field.expr_opt {
+            visit_expr(expr, f);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::parsed::TreeType;
+    use std::sync::Arc;
+
+    fn lexed(src: &str) -> LexedProgram {
+        let handler = Handler::default();
+        let module =
+            sway_parse::parse_file(&handler, Arc::from(src), None, Default::default()).unwrap();
+        LexedProgram::new(
+            TreeType::Library,
+            LexedModule {
+                tree: module.value,
+                submodules: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn test_cyclomatic_complexity() {
+        let program = lexed(
+            r#"
+            library;
+
+            fn f(a: u64, b: bool) -> u64 {
+                if a == 0 && b {
+                    return 1;
+                } else if a == 1 {
+                    return 2;
+                }
+                while a > 0 {
+                    a = a - 1;
+                }
+                match a {
+                    0 => 1,
+                    1 => 2,
+                    _ => 3,
+                }
+            }
+            "#,
+        );
+        let fns = module_fns(&program.root);
+        // `if`, `else if`, `&&`, `while` and the two additional match arms.
+        assert_eq!(cyclomatic_complexity(&fns[0].body.inner), 7);
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let program = lexed(
+            r#"
+            library;
+
+            fn f() {
+                let a = 1;
+                let b = 2;
+            }
+            "#,
+        );
+        let config = LintConfig {
+            max_function_length: 3,
+            ..Default::default()
+        };
+        let (errors, warnings) = lint(Handler::default(), &program, &config).consume();
+        assert!(errors.is_empty());
+        assert_eq!(warnings.len(), 1);
+
+        let config = LintConfig {
+            deny: BTreeSet::from([Lint::FunctionTooLong]),
+            ..config
+        };
+        let (errors, warnings) = lint(Handler::default(), &program, &config).consume();
+        assert_eq!(errors.len(), 1);
+        assert!(warnings.is_empty());
+
+        let config = LintConfig {
+            allow: BTreeSet::from([Lint::FunctionTooLong]),
+            deny: BTreeSet::default(),
+            ..config
+        };
+        let (errors, warnings) = lint(Handler::default(), &program, &config).consume();
+        assert!(errors.is_empty() && warnings.is_empty());
+    }
+}
@@ -6,7 +6,7 @@ use std::{collections::HashMap, sync::Arc};
 use sway_error::error::CompileError;
 use sway_error::warning::CompileWarning;
 
-use crate::Programs;
+use crate::{semantic_analysis::cache::TypedModuleCache, Programs};
 
 pub type ModulePath = Arc<PathBuf>;
 
@@ -49,6 +49,7 @@ pub type ProgramsCacheMap = HashMap<ModulePath, ProgramsCacheEntry>;
 pub struct QueryEngine {
     parse_module_cache: Arc<RwLock<ModuleCacheMap>>,
     programs_cache: Arc<RwLock<ProgramsCacheMap>>,
+    typed_module_cache: TypedModuleCache,
 }
 
 impl Clone for QueryEngine {
@@ -56,6 +57,7 @@ impl Clone for QueryEngine {
         Self {
             parse_module_cache: self.parse_module_cache.clone(),
             programs_cache: self.programs_cache.clone(),
+            typed_module_cache: self.typed_module_cache.clone(),
         }
     }
 }
@@ -86,4 +88,9 @@ impl QueryEngine {
         let mut cache = self.programs_cache.write().unwrap();
         cache.insert(entry.path.clone(), entry);
     }
+
+    /// The typed submodules reused by incremental type-checking.
+    pub fn typed_module_cache(&self) -> &TypedModuleCache {
+        &self.typed_module_cache
+    }
 }
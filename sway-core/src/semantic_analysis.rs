@@ -1,15 +1,18 @@
 //! Type checking for Sway.
 pub mod ast_node;
+pub mod cache;
 pub(crate) mod cei_pattern_analysis;
 pub(crate) mod coins_analysis;
 mod module;
 pub mod namespace;
 mod node_dependencies;
+pub(crate) mod numeric_literal_analysis;
 mod program;
 mod type_check_analysis;
 pub(crate) mod type_check_context;
 mod type_check_finalization;
 mod type_check_unification;
+pub(crate) mod unbounded_loop_analysis;
 pub use ast_node::*;
 pub use namespace::Namespace;
 pub(crate) use type_check_analysis::*;
@@ -1,10 +1,7 @@
 use super::*;
-use crate::{
-    decl_engine::DeclRef,
-    language::{
-        parsed::CodeBlock,
-        ty::{self, TyAstNodeContent, TyCodeBlock},
-    },
+use crate::language::{
+    parsed::CodeBlock,
+    ty::{self, TyAstNodeContent, TyCodeBlock},
 };
 
 impl ty::TyCodeBlock {
@@ -49,14 +46,14 @@ impl ty::TyCodeBlock {
 
         // find the implicit return, if any, and use it as the code block's return type.
         // The fact that there is at most one implicit return is an invariant held by the parser.
-        // If any node diverges then the entire block has unknown type.
-        let mut node_deterministically_aborts = false;
+        // If any node diverges then the entire block has the never type.
+        let mut node_diverges = false;
         let block_type = code_block
             .contents
             .iter()
             .find_map(|node| {
-                if node.deterministically_aborts(decl_engine, true) {
-                    node_deterministically_aborts = true;
+                if node.diverges(decl_engine) {
+                    node_diverges = true;
                 };
                 match node {
                     ty::TyAstNode {
@@ -71,42 +68,12 @@ impl ty::TyCodeBlock {
                 }
             })
             .unwrap_or_else(|| {
-                if node_deterministically_aborts {
-                    let never_mod_path = vec![
-                        Ident::new_with_override("core".into(), span.clone()),
-                        Ident::new_with_override("never".into(), span.clone()),
-                    ];
-                    let never_ident = Ident::new_with_override("Never".into(), span.clone());
-
-                    let never_decl_opt = ctx
-                        .namespace
-                        .root()
-                        .resolve_symbol(
-                            &Handler::default(),
-                            engines,
-                            &never_mod_path,
-                            &never_ident,
-                            None,
-                        )
-                        .ok();
-
-                    if let Some(ty::TyDecl::EnumDecl(ty::EnumDecl {
-                        name,
-                        decl_id,
-                        subst_list: _,
-                        decl_span,
-                    })) = never_decl_opt
-                    {
-                        return ctx.engines().te().insert(
-                            engines,
-                            TypeInfo::Enum(DeclRef::new(name.clone(), decl_id, decl_span.clone())),
-                            name.span().source_id(),
-                        );
-                    }
-
-                    ctx.engines.te().insert(engines, TypeInfo::Unknown, None)
+                if node_diverges {
+                    engines
+                        .te()
+                        .insert(engines, TypeInfo::Never, span.source_id())
                 } else {
-                    ctx.engines
+                    engines
                         .te()
                         .insert(engines, TypeInfo::Tuple(Vec::new()), span.source_id())
                 }
@@ -4,8 +4,8 @@ use crate::{
         parsed::{
             AstNode, AstNodeContent, CodeBlock, Declaration, Expression, ExpressionKind,
             FunctionDeclaration, FunctionParameter, ImplItem, ImplTrait, MatchBranch,
-            MatchExpression, MethodApplicationExpression, MethodName, Scrutinee,
-            SubfieldExpression,
+            MatchExpression, MatchExpressionSource, MethodApplicationExpression, MethodName,
+            Scrutinee, SubfieldExpression,
         },
         ty::{self, TyAstNode, TyDecl},
         CallPath, QualifiedCallPath,
@@ -15,7 +15,7 @@ use crate::{
     TraitConstraint, TypeArgs, TypeArgument, TypeBinding, TypeId, TypeInfo, TypeParameter,
 };
 use sway_error::handler::Handler;
-use sway_types::{Ident, Span};
+use sway_types::{Ident, Span, Spanned};
 
 /// Contains all information needed to implement AbiEncode
 pub struct AutoImplAbiEncodeContext<'a, 'b> {
@@ -128,6 +128,8 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                 .insert(self.ctx.engines, TypeInfo::Tuple(vec![]), None);
 
         let enum_decl = self.ctx.engines().de().get(implementing_for_decl_ref.id());
+        // The generated code points at the enum it is generated for.
+        let span = Span::synthetic(&enum_decl.call_path.suffix.span());
 
         if !self.import_core_codec() {
             return None;
@@ -201,7 +203,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                 TypeArgument {
                                     type_id,
                                     initial_type_id: type_id,
-                                    span: Span::dummy(),
+                                    span: span.clone(),
                                     call_path_tree: None,
                                 }
                             })
@@ -223,7 +225,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                     initial_type_id: type_id,
                     name_ident: Ident::new_no_span(type_parameter.name_ident.as_str().into()),
                     trait_constraints,
-                    trait_constraints_span: Span::dummy(),
+                    trait_constraints_span: span.clone(),
                     is_from_parent: false,
                 }
             })
@@ -267,7 +269,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                 TypeArgument {
                                     type_id,
                                     initial_type_id: type_id,
-                                    span: Span::dummy(),
+                                    span: span.clone(),
                                     call_path_tree: None,
                                 }
                             })
@@ -282,7 +284,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
         let implementing_for = TypeArgument {
             type_id: implementing_for_type_id,
             initial_type_id: implementing_for_type_id,
-            span: Span::dummy(),
+            span: span.clone(),
             call_path_tree: None,
         };
 
@@ -294,7 +296,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
             items: vec![ImplItem::Fn(FunctionDeclaration {
                 purity: crate::language::Purity::Pure,
                 attributes: AttributesMap::default(),
-                name: Ident::new_no_span("abi_encode".into()),
+                name: Ident::new_with_override("abi_encode".into(), span.clone()),
                 visibility: crate::language::Visibility::Public,
                 body: CodeBlock {
                     contents: vec![
@@ -305,19 +307,18 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                         MatchExpression {
                                             value: Box::new(Expression {
                                                 kind: ExpressionKind::AmbiguousVariableExpression (
-                                                    Ident::new_no_span("self".into())
+                                                    Ident::new_with_override("self".into(), span.clone())
                                                 ),
-                                                span: Span::dummy()
+                                                span: span.clone()
                                             }),
                                             branches: enum_decl.variants.iter()
-                                                .enumerate()
-                                                .map(|(i, x)| {
+                                                .map(|x| {
                                                     let variant_type = self.ctx.engines().te().get(x.type_argument.type_id);
                                                     MatchBranch {
                                                         scrutinee: Scrutinee::EnumScrutinee {
                                                             call_path: CallPath {
                                                                 prefixes: vec![
-                                                                    Ident::new_no_span("Self".into())
+                                                                    Ident::new_with_override("Self".into(), span.clone())
                                                                 ],
                                                                 suffix: Ident::new_no_span(
                                                                     x.name.as_str().into()
@@ -326,16 +327,17 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                                             },
                                                             value: Box::new(if variant_type.is_unit() {
                                                                 Scrutinee::CatchAll {
-                                                                    span: Span::dummy()
+                                                                    span: span.clone()
                                                                 }
                                                             } else {
                                                                 Scrutinee::Variable {
-                                                                    name: Ident::new_no_span("x".into()),
-                                                                    span: Span::dummy()
+                                                                    name: Ident::new_with_override("x".into(), span.clone()),
+                                                                    span: span.clone()
                                                                 }
                                                             }),
-                                                            span: Span::dummy(),
+                                                            span: span.clone(),
                                                         },
+                                                        guard: None,
                                                         result: Expression {
                                                             kind: ExpressionKind::CodeBlock(
                                                                 CodeBlock {
@@ -351,34 +353,34 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                                                                             Box::new(MethodApplicationExpression {
                                                                                                 method_name_binding: TypeBinding {
                                                                                                     inner: MethodName::FromModule {
-                                                                                                        method_name: Ident::new_no_span("abi_encode".into())
+                                                                                                        method_name: Ident::new_with_override("abi_encode".into(), span.clone())
                                                                                                     },
                                                                                                     type_arguments: TypeArgs::Regular(vec![]),
-                                                                                                    span: Span::dummy()
+                                                                                                    span: span.clone()
                                                                                                 },
                                                                                                 arguments: vec![
                                                                                                     Expression {
                                                                                                         kind: ExpressionKind::Literal(
                                                                                                             crate::language::Literal::U64(
-                                                                                                                i as u64
+                                                                                                                x.discriminant
                                                                                                             )
                                                                                                         ),
-                                                                                                        span: Span::dummy()
+                                                                                                        span: span.clone()
                                                                                                     },
                                                                                                     Expression {
                                                                                                         kind: ExpressionKind::AmbiguousVariableExpression(
-                                                                                                            Ident::new_no_span("buffer".into())
+                                                                                                            Ident::new_with_override("buffer".into(), span.clone())
                                                                                                         ),
-                                                                                                        span: Span::dummy()
+                                                                                                        span: span.clone()
                                                                                                     }
                                                                                                 ],
                                                                                                 contract_call_params: vec![],
                                                                                             })
                                                                                         ),
-                                                                                        span: Span::dummy()
+                                                                                        span: span.clone()
                                                                                     }
                                                                                 ),
-                                                                                span: Span::dummy()
+                                                                                span: span.clone()
                                                                         });
 
                                                                         // variant data
@@ -391,93 +393,94 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                                                                                 Box::new(MethodApplicationExpression {
                                                                                                     method_name_binding: TypeBinding {
                                                                                                         inner: MethodName::FromModule {
-                                                                                                            method_name: Ident::new_no_span("abi_encode".into())
+                                                                                                            method_name: Ident::new_with_override("abi_encode".into(), span.clone())
                                                                                                         },
                                                                                                         type_arguments: TypeArgs::Regular(vec![]),
-                                                                                                        span: Span::dummy()
+                                                                                                        span: span.clone()
                                                                                                     },
                                                                                                     arguments: vec![
                                                                                                         Expression {
                                                                                                             kind: ExpressionKind::AmbiguousVariableExpression (
-                                                                                                                Ident::new_no_span("x".into())
+                                                                                                                Ident::new_with_override("x".into(), span.clone())
                                                                                                             ),
-                                                                                                            span: Span::dummy()
+                                                                                                            span: span.clone()
                                                                                                         },
                                                                                                         Expression {
                                                                                                             kind: ExpressionKind::AmbiguousVariableExpression(
-                                                                                                                Ident::new_no_span("buffer".into())
+                                                                                                                Ident::new_with_override("buffer".into(), span.clone())
                                                                                                             ),
-                                                                                                            span: Span::dummy()
+                                                                                                            span: span.clone()
                                                                                                         }
                                                                                                     ],
                                                                                                     contract_call_params: vec![],
                                                                                                 })
                                                                                             ),
-                                                                                            span: Span::dummy()
+                                                                                            span: span.clone()
                                                                                         }
                                                                                     ),
-                                                                                    span: Span::dummy()
+                                                                                    span: span.clone()
                                                                                 }
                                                                             );
                                                                         }
 
                                                                         contents
                                                                     },
-                                                                    whole_block_span: Span::dummy()
+                                                                    whole_block_span: span.clone()
                                                                 }
                                                             ),
-                                                            span: Span::dummy()
+                                                            span: span.clone()
                                                         },
-                                                        span: Span::dummy()
+                                                        span: span.clone()
                                                     }
-                                                }).collect()
+                                                }).collect(),
+                                            source: MatchExpressionSource::Match,
                                         }
                                     ),
-                                    span: Span::dummy()
+                                    span: span.clone()
                                 }
                             ),
-                            span: Span::dummy()
+                            span: span.clone()
                         }
                     ],
-                    whole_block_span: Span::dummy(),
+                    whole_block_span: span.clone(),
                 },
                 parameters: vec![
                     FunctionParameter {
-                        name: Ident::new_no_span("self".into()),
+                        name: Ident::new_with_override("self".into(), span.clone()),
                         is_reference: false,
                         is_mutable: false,
-                        mutability_span: Span::dummy(),
+                        mutability_span: span.clone(),
                         type_argument: TypeArgument {
                             type_id: implementing_for_type_id,
                             initial_type_id: implementing_for_type_id,
-                            span: Span::dummy(),
+                            span: span.clone(),
                             call_path_tree: None,
                         },
                     },
                     FunctionParameter {
-                        name: Ident::new_no_span("buffer".into()),
+                        name: Ident::new_with_override("buffer".into(), span.clone()),
                         is_reference: true,
                         is_mutable: true,
-                        mutability_span: Span::dummy(),
+                        mutability_span: span.clone(),
                         type_argument: TypeArgument {
                             type_id: self.buffer_type_id,
                             initial_type_id: self.buffer_type_id,
-                            span: Span::dummy(),
+                            span: span.clone(),
                             call_path_tree: None,
                         },
                     },
                 ],
-                span: Span::dummy(),
+                span: span.clone(),
                 return_type: TypeArgument {
                     type_id: unit_type_id,
                     initial_type_id: unit_type_id,
-                    span: Span::dummy(),
+                    span: span.clone(),
                     call_path_tree: None,
                 },
                 type_parameters: vec![],
                 where_clause: vec![],
             })],
-            block_span: Span::dummy(),
+            block_span: span.clone(),
         });
 
         let handler = Handler::default();
@@ -486,7 +489,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
             self.ctx.by_ref(),
             AstNode {
                 content: AstNodeContent::Declaration(impl_trait),
-                span: Span::dummy(),
+                span: span.clone(),
             },
         )
         .ok()
@@ -516,6 +519,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
             return false;
         }
 
+        let marker_traits = self.ctx.namespace.visible_marker_traits(self.ctx.engines);
         let all_fields_are_abi_encode = struct_ref.fields.iter().all(|field| {
             if let TypeInfo::UnknownGeneric { .. } =
                 &*self.ctx.engines().te().get(field.type_argument.type_id)
@@ -541,6 +545,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                         },
                         type_arguments: vec![],
                     }],
+                    marker_traits,
                     &Span::dummy(),
                     self.ctx.engines,
                     crate::namespace::TryInsertingTraitImplOnFailure::Yes,
@@ -559,6 +564,8 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
 
         let implementing_for_decl_ref = decl.get_struct_decl_ref().unwrap();
         let struct_decl = self.ctx.engines().de().get(implementing_for_decl_ref.id());
+        // The generated code points at the struct it is generated for.
+        let span = Span::synthetic(&struct_decl.call_path.suffix.span());
 
         let unit_type_id =
             self.ctx
@@ -653,7 +660,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                 TypeArgument {
                                     type_id,
                                     initial_type_id: type_id,
-                                    span: Span::dummy(),
+                                    span: span.clone(),
                                     call_path_tree: None,
                                 }
                             })
@@ -674,7 +681,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                     initial_type_id: type_id,
                     name_ident: Ident::new_no_span(x.name_ident.as_str().into()),
                     trait_constraints,
-                    trait_constraints_span: Span::dummy(),
+                    trait_constraints_span: span.clone(),
                     is_from_parent: false,
                 }
             })
@@ -718,7 +725,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                 TypeArgument {
                                     type_id,
                                     initial_type_id: type_id,
-                                    span: Span::dummy(),
+                                    span: span.clone(),
                                     call_path_tree: None,
                                 }
                             })
@@ -733,7 +740,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
         let implementing_for = TypeArgument {
             type_id: implementing_for_type_id,
             initial_type_id: implementing_for_type_id,
-            span: Span::dummy(),
+            span: span.clone(),
             call_path_tree: None,
         };
 
@@ -748,7 +755,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                         FunctionDeclaration {
                             purity: crate::language::Purity::Pure,
                             attributes: AttributesMap::default(),
-                            name: Ident::new_no_span("abi_encode".into()),
+                            name: Ident::new_with_override("abi_encode".into(), span.clone()),
                             visibility: crate::language::Visibility::Public,
                             body: CodeBlock {
                                 contents: struct_decl.fields
@@ -761,10 +768,10 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                                         Box::new(MethodApplicationExpression {
                                                             method_name_binding: TypeBinding {
                                                                 inner: MethodName::FromModule {
-                                                                    method_name: Ident::new_no_span("abi_encode".into())
+                                                                    method_name: Ident::new_with_override("abi_encode".into(), span.clone())
                                                                 },
                                                                 type_arguments: TypeArgs::Regular(vec![]),
-                                                                span: Span::dummy()
+                                                                span: span.clone()
                                                             },
                                                             arguments: vec![
                                                                 Expression {
@@ -773,66 +780,66 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                                                                             prefix: Box::new(
                                                                                 Expression {
                                                                                     kind: ExpressionKind::AmbiguousVariableExpression (
-                                                                                        Ident::new_no_span("self".into())
+                                                                                        Ident::new_with_override("self".into(), span.clone())
                                                                                     ),
-                                                                                    span: Span::dummy()
+                                                                                    span: span.clone()
                                                                                 }
                                                                             ),
                                                                             field_to_access: x.name.clone()
                                                                         }
                                                                     ),
-                                                                    span: Span::dummy()
+                                                                    span: span.clone()
                                                                 },
                                                                 Expression {
                                                                     kind: ExpressionKind::AmbiguousVariableExpression(
-                                                                        Ident::new_no_span("buffer".into())
+                                                                        Ident::new_with_override("buffer".into(), span.clone())
                                                                     ),
-                                                                    span: Span::dummy()
+                                                                    span: span.clone()
                                                                 }
                                                             ],
                                                             contract_call_params: vec![],
                                                         })
                                                     ),
-                                                    span: Span::dummy()
+                                                    span: span.clone()
                                                 }
                                             ),
-                                            span: Span::dummy()
+                                            span: span.clone()
                                         }
                                     })
                                     .collect(),
-                                whole_block_span: Span::dummy()
+                                whole_block_span: span.clone()
                             },
                             parameters: vec![
                                 FunctionParameter {
-                                    name: Ident::new_no_span("self".into()),
+                                    name: Ident::new_with_override("self".into(), span.clone()),
                                     is_reference: false,
                                     is_mutable: false,
-                                    mutability_span: Span::dummy(),
+                                    mutability_span: span.clone(),
                                     type_argument: TypeArgument {
                                         type_id: implementing_for_type_id,
                                         initial_type_id: implementing_for_type_id,
-                                        span: Span::dummy(),
+                                        span: span.clone(),
                                         call_path_tree: None
                                     }
                                 },
                                 FunctionParameter {
-                                    name: Ident::new_no_span("buffer".into()),
+                                    name: Ident::new_with_override("buffer".into(), span.clone()),
                                     is_reference: true,
                                     is_mutable: true,
-                                    mutability_span: Span::dummy(),
+                                    mutability_span: span.clone(),
                                     type_argument: TypeArgument {
                                         type_id: self.buffer_type_id,
                                         initial_type_id: self.buffer_type_id,
-                                        span: Span::dummy(),
+                                        span: span.clone(),
                                         call_path_tree: None
                                     }
                                 },
                             ],
-                            span: Span::dummy(),
+                            span: span.clone(),
                             return_type: TypeArgument {
                                 type_id: unit_type_id,
                                 initial_type_id: unit_type_id,
-                                span: Span::dummy(),
+                                span: span.clone(),
                                 call_path_tree: None
                             },
                             type_parameters: vec![],
@@ -840,7 +847,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
                         }
                     )
                 ],
-                block_span: Span::dummy(),
+                block_span: span.clone(),
             }
         );
 
@@ -850,7 +857,7 @@ impl<'a, 'b> AutoImplAbiEncodeContext<'a, 'b> {
             self.ctx.by_ref(),
             AstNode {
                 content: AstNodeContent::Declaration(impl_trait),
-                span: Span::dummy(),
+                span: span.clone(),
             },
         )
         .ok()
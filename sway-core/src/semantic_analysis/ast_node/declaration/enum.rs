@@ -1,9 +1,15 @@
 use crate::{
     language::{parsed::*, ty, CallPath},
-    semantic_analysis::{type_check_context::EnforceTypeArguments, *},
+    semantic_analysis::{
+        ast_node::declaration::check_for_unused_type_parameters,
+        type_check_context::EnforceTypeArguments, *,
+    },
     type_system::*,
 };
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    warning::Warning,
+};
 
 impl ty::TyEnumDecl {
     pub fn type_check(
@@ -52,6 +58,22 @@ impl ty::TyEnumDecl {
             attributes,
             visibility,
         };
+
+        // Enums without variants are markers, whose type parameters are only used by their impls.
+        if !decl.variants.is_empty() {
+            check_for_unused_type_parameters(
+                handler,
+                ctx.engines(),
+                &decl,
+                &decl.type_parameters,
+                &decl.attributes,
+                |name| Warning::UnusedTypeParameter {
+                    name,
+                    decl_type: "enum".to_string(),
+                },
+            );
+        }
+
         Ok(decl)
     }
 }
@@ -78,6 +100,7 @@ impl ty::TyEnumVariant {
             name: variant.name.clone(),
             type_argument,
             tag: variant.tag,
+            discriminant: variant.discriminant,
             span: variant.span,
             attributes: variant.attributes,
         })
@@ -13,8 +13,12 @@ use crate::{
         ty::{self, TyCodeBlock, TyFunctionDecl},
         CallPath, Visibility,
     },
-    semantic_analysis::{type_check_context::EnforceTypeArguments, *},
+    semantic_analysis::{
+        ast_node::declaration::check_for_unused_type_parameters,
+        type_check_context::EnforceTypeArguments, *,
+    },
     type_system::*,
+    Engines,
 };
 use sway_types::{style::is_snake_case, Spanned};
 
@@ -207,6 +211,20 @@ impl ty::TyFunctionDecl {
 
         Ok(ty_fn_decl.clone())
     }
+
+    /// Warns about type parameters that are used neither in the signature nor in the body of
+    /// the function. Methods implementing trait methods must declare the type parameters of the
+    /// trait methods, so this is only checked for free functions and inherent methods.
+    pub(crate) fn check_for_unused_type_parameters(&self, handler: &Handler, engines: &Engines) {
+        check_for_unused_type_parameters(
+            handler,
+            engines,
+            self,
+            &self.type_parameters,
+            &self.attributes,
+            |name| Warning::UnusedFunctionTypeParameter { name },
+        );
+    }
 }
 
 /// Unifies the types of the return statements and the return type of the
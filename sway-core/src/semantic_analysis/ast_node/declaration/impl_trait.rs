@@ -519,6 +519,7 @@ impl TyImplTrait {
                             Ok(res) => res,
                             Err(_) => continue,
                         };
+                        new_ty_fn_decl.check_for_unused_type_parameters(handler, engines);
                         decl_engine.replace(*decl_ref.id(), new_ty_fn_decl);
                     }
                     (ImplItem::Constant(_const_decl), TyTraitItem::Constant(_decl_ref)) => {
@@ -615,6 +616,7 @@ fn type_check_trait_implementation(
 
     // Check to see if the type that we are implementing for implements the
     // supertraits of this trait.
+    let marker_traits = ctx.namespace.visible_marker_traits(engines);
     ctx.namespace
         .implemented_traits
         .check_if_trait_constraints_are_satisfied_for_type(
@@ -624,6 +626,7 @@ fn type_check_trait_implementation(
                 .iter()
                 .map(|x| x.into())
                 .collect::<Vec<_>>(),
+            marker_traits,
             block_span,
             engines,
             TryInsertingTraitImplOnFailure::Yes,
@@ -880,47 +883,44 @@ fn type_check_trait_implementation(
     );
     type_mapping.extend(trait_type_mapping);
 
+    // The methods of the trait declaration may call each other, so they're
+    // first copied for this impl block, and the calls between them are then
+    // redirected to the copies.
+    let mut item_refs: ItemMap = BTreeMap::new();
+    let mut trait_item_copy_refs: ItemMap = BTreeMap::new();
+    for item in trait_items.iter() {
+        if let TyImplItem::Fn(decl_ref) = item {
+            let method = (*decl_engine.get_function(decl_ref)).clone();
+            let key = (method.name.clone(), implementing_for);
+            item_refs.insert(key.clone(), item.clone());
+            trait_item_copy_refs.insert(
+                key,
+                TyImplItem::Fn(
+                    decl_engine
+                        .insert(method)
+                        .with_parent(decl_engine, (*decl_ref.id()).into()),
+                ),
+            );
+        }
+    }
+
     interface_item_refs.extend(supertrait_interface_item_refs);
     impld_item_refs.extend(supertrait_impld_item_refs);
     let decl_mapping = DeclMapping::from_interface_and_item_and_impld_decl_refs(
         interface_item_refs,
-        BTreeMap::new(),
-        impld_item_refs,
+        item_refs,
+        trait_item_copy_refs
+            .clone()
+            .into_iter()
+            .chain(impld_item_refs)
+            .collect(),
     );
     for item in trait_items.iter() {
         match item {
             TyImplItem::Fn(decl_ref) => {
-                let mut method = (*decl_engine.get_function(decl_ref)).clone();
-
-                // We need to add impl type parameters to the method's type parameters
-                // so that in-line monomorphization can complete.
-                //
-                // We also need to add impl type parameters to the method's type
-                // parameters so the type constraints are correctly applied to the method.
-                //
-                // NOTE: this is a semi-hack that is used to force monomorphization of
-                // trait methods that contain a generic defined in the parent impl...
-                // without stuffing the generic into the method's type parameters, its
-                // not currently possible to monomorphize on that generic at function
-                // application time.
-                method.type_parameters.append(
-                    &mut impl_type_parameters
-                        .iter()
-                        .cloned()
-                        .map(|mut t| {
-                            t.is_from_parent = true;
-                            t
-                        })
-                        .collect::<Vec<_>>(),
-                );
-
-                method.replace_decls(&decl_mapping, handler, &mut ctx)?;
-                method.subst(&type_mapping, engines);
-                all_items_refs.push(TyImplItem::Fn(
-                    decl_engine
-                        .insert(method)
-                        .with_parent(decl_engine, (*decl_ref.id()).into()),
-                ));
+                let method = decl_engine.get_function(decl_ref);
+                all_items_refs
+                    .push(trait_item_copy_refs[&(method.name.clone(), implementing_for)].clone());
             }
             TyImplItem::Constant(decl_ref) => {
                 let mut const_decl = (*decl_engine.get_constant(decl_ref)).clone();
@@ -936,6 +936,45 @@ fn type_check_trait_implementation(
         }
     }
 
+    // Calling a method copies its body into the caller, so the methods are
+    // transformed after the methods they call.
+    for idx in order_trait_methods_by_calls(engines, trait_items) {
+        if let TyImplItem::Fn(decl_ref) = &trait_items[idx] {
+            let mut method = (*decl_engine.get_function(decl_ref)).clone();
+
+            // We need to add impl type parameters to the method's type parameters
+            // so that in-line monomorphization can complete.
+            //
+            // We also need to add impl type parameters to the method's type
+            // parameters so the type constraints are correctly applied to the method.
+            //
+            // NOTE: this is a semi-hack that is used to force monomorphization of
+            // trait methods that contain a generic defined in the parent impl...
+            // without stuffing the generic into the method's type parameters, its
+            // not currently possible to monomorphize on that generic at function
+            // application time.
+            method.type_parameters.append(
+                &mut impl_type_parameters
+                    .iter()
+                    .cloned()
+                    .map(|mut t| {
+                        t.is_from_parent = true;
+                        t
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+            method.replace_decls(&decl_mapping, handler, &mut ctx)?;
+            method.subst(&type_mapping, engines);
+            let TyImplItem::Fn(copy_ref) =
+                &trait_item_copy_refs[&(method.name.clone(), implementing_for)]
+            else {
+                unreachable!();
+            };
+            decl_engine.replace(*copy_ref.id(), method);
+        }
+    }
+
     handler.scope(|handler| {
         // check that the implementation checklist is complete
         if !method_checklist.is_empty() {
@@ -963,6 +1002,70 @@ fn type_check_trait_implementation(
     })
 }
 
+/// Returns the indices of the methods of a trait declaration, ordered so
+/// that each method comes after the methods of the trait it calls.
+fn order_trait_methods_by_calls(engines: &Engines, trait_items: &[TyImplItem]) -> Vec<usize> {
+    use ty::TyVisitor;
+
+    #[derive(Default)]
+    struct CalledFunctions(HashSet<AssociatedItemDeclId>);
+
+    impl TyVisitor for CalledFunctions {
+        fn visit_expression(&mut self, engines: &Engines, expr: &ty::TyExpression) {
+            if let ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } = &expr.expression {
+                self.0.insert(fn_ref.id().into());
+                self.0
+                    .extend(engines.de().find_all_parents(engines, fn_ref.id()));
+            }
+            ty::walk_expression(self, engines, expr);
+        }
+    }
+
+    let decl_engine = engines.de();
+    let callees = trait_items
+        .iter()
+        .map(|item| match item {
+            TyImplItem::Fn(decl_ref) => {
+                let mut called = CalledFunctions::default();
+                called.visit_function_decl(engines, &decl_engine.get_function(decl_ref));
+                trait_items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, callee)| match callee {
+                        TyImplItem::Fn(callee_ref) => {
+                            callee_ref.id() != decl_ref.id()
+                                && called
+                                    .0
+                                    .contains(&AssociatedItemDeclId::from(callee_ref.id()))
+                        }
+                        _ => false,
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect()
+            }
+            _ => vec![],
+        })
+        .collect::<Vec<Vec<usize>>>();
+
+    fn visit(idx: usize, callees: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+        if visited[idx] {
+            return;
+        }
+        visited[idx] = true;
+        for callee in callees[idx].iter() {
+            visit(*callee, callees, visited, order);
+        }
+        order.push(idx);
+    }
+
+    let mut visited = vec![false; trait_items.len()];
+    let mut order = Vec::with_capacity(trait_items.len());
+    for idx in 0..trait_items.len() {
+        visit(idx, &callees, &mut visited, &mut order);
+    }
+    order
+}
+
 #[allow(clippy::too_many_arguments)]
 fn type_check_impl_method(
     handler: &Handler,
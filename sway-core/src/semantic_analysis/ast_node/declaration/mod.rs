@@ -13,5 +13,7 @@ mod r#trait;
 mod trait_fn;
 mod trait_type;
 mod type_alias;
+mod unused_type_parameters;
 
 pub(crate) use supertrait::*;
+pub(crate) use unused_type_parameters::*;
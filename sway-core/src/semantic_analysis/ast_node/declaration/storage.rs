@@ -1,7 +1,8 @@
 use crate::{
     fuel_prelude::fuel_tx::StorageSlot,
     ir_generation::{
-        const_eval::compile_constant_expression_to_constant, storage::serialize_to_storage_slots,
+        const_eval::compile_constant_expression_to_constant,
+        storage::{serialize_to_storage_slots, FieldStorageSlot},
     },
     language::ty,
     metadata::MetadataManager,
@@ -26,13 +27,14 @@ impl ty::TyStorageDecl {
         context: &mut Context,
         md_mgr: &mut MetadataManager,
         module: Module,
-    ) -> Result<Vec<StorageSlot>, ErrorEmitted> {
+    ) -> Result<Vec<FieldStorageSlot>, ErrorEmitted> {
         handler.scope(|handler| {
             let storage_slots = self
                 .fields
                 .iter()
                 .enumerate()
                 .map(|(i, f)| {
+                    let field = format!("storage.{}", f.name);
                     f.get_initialized_storage_slots(
                         engines,
                         context,
@@ -40,6 +42,17 @@ impl ty::TyStorageDecl {
                         module,
                         &StateIndex::new(i),
                     )
+                    .map(|slots| {
+                        slots
+                            .into_iter()
+                            .zip(0..)
+                            .map(|(slot, offset)| FieldStorageSlot {
+                                slot,
+                                field: field.clone(),
+                                offset,
+                            })
+                            .collect::<Vec<_>>()
+                    })
                 })
                 .filter_map(|s| s.map_err(|e| handler.emit_err(e)).ok())
                 .flatten()
@@ -1,9 +1,15 @@
 use crate::{
     language::{parsed::*, ty, CallPath},
-    semantic_analysis::{type_check_context::EnforceTypeArguments, *},
+    semantic_analysis::{
+        ast_node::declaration::check_for_unused_type_parameters,
+        type_check_context::EnforceTypeArguments, *,
+    },
     type_system::*,
 };
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    warning::Warning,
+};
 
 impl ty::TyStructDecl {
     pub(crate) fn type_check(
@@ -48,6 +54,21 @@ impl ty::TyStructDecl {
             attributes,
         };
 
+        // Structs without fields are markers, whose type parameters are only used by their impls.
+        if !decl.fields.is_empty() {
+            check_for_unused_type_parameters(
+                handler,
+                ctx.engines(),
+                &decl,
+                &decl.type_parameters,
+                &decl.attributes,
+                |name| Warning::UnusedTypeParameter {
+                    name,
+                    decl_type: "struct".to_string(),
+                },
+            );
+        }
+
         Ok(decl)
     }
 }
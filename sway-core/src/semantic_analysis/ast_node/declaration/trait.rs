@@ -196,15 +196,52 @@ impl TyTraitDecl {
             IsExtendingExistingImpl::Yes,
         )?;
 
-        // Type check the items.
+        // Type check the signatures of the items first.
         let mut new_items = vec![];
-        for method in methods.into_iter() {
-            let method =
-                ty::TyFunctionDecl::type_check(handler, ctx.by_ref(), method.clone(), true, false)
-                    .unwrap_or_else(|_| ty::TyFunctionDecl::error(method));
+        for method in methods.iter() {
+            let method = ty::TyFunctionDecl::type_check_signature(
+                handler,
+                ctx.by_ref(),
+                method.clone(),
+                true,
+                false,
+            )
+            .unwrap_or_else(|_| ty::TyFunctionDecl::error(method.clone()));
             new_items.push(ty::TyTraitItem::Fn(decl_engine.insert(method)));
         }
 
+        // insert the items alongside the interface surface to allow them
+        // to call each other through `Self`, regardless of their order
+        if !new_items.is_empty() {
+            ctx.insert_trait_implementation(
+                handler,
+                CallPath {
+                    prefixes: vec![],
+                    suffix: name.clone(),
+                    is_absolute: false,
+                },
+                new_type_parameters.iter().map(|x| x.into()).collect(),
+                self_type,
+                &new_items,
+                &span,
+                None,
+                IsImplSelf::No,
+                IsExtendingExistingImpl::Yes,
+            )?;
+        }
+
+        // Type check the bodies of the items.
+        for (method, new_item) in methods.iter().zip(new_items.iter()) {
+            let ty::TyTraitItem::Fn(decl_ref) = new_item else {
+                unreachable!();
+            };
+            let mut ty_fn_decl = (*decl_engine.get_function(decl_ref.id())).clone();
+            let ty_fn_decl =
+                ty::TyFunctionDecl::type_check_body(handler, ctx.by_ref(), method, &mut ty_fn_decl)
+                    .unwrap_or_else(|_| ty::TyFunctionDecl::error(method.clone()));
+            decl_engine.replace(*decl_ref.id(), ty_fn_decl);
+        }
+
         let typed_trait_decl = ty::TyTraitDecl {
             name: name.clone(),
             type_parameters: new_type_parameters,
@@ -10,6 +10,7 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use crate::{
     semantic_analysis::{AbiMode, TypeCheckContext},
     type_system::*,
+    Engines,
 };
 
 impl ty::TyTraitFn {
@@ -74,6 +75,17 @@ impl ty::TyTraitFn {
         Ok(trait_fn)
     }
 
+    /// Converts the method of an ABI into the value used to select it in contract calls, as
+    /// [ty::TyFunctionDecl::to_fn_selector_value] does for the implementations of the method.
+    pub fn to_fn_selector_value(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+    ) -> Result<[u8; 4], ErrorEmitted> {
+        self.to_dummy_func(AbiMode::NonAbi)
+            .to_fn_selector_value(handler, engines)
+    }
+
     /// This function is used in trait declarations to insert "placeholder"
     /// functions in the methods. This allows the methods to use functions
     /// declared in the interface surface.
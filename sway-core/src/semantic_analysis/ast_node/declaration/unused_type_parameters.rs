@@ -0,0 +1,47 @@
+use sway_error::{
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{constants::ALLOW_UNUSED_TYPE_PARAMETERS_NAME, Ident, Spanned};
+
+use crate::{
+    transform::{AttributeKind, AttributesMap},
+    types::UnconstrainedTypeParameters,
+    Engines, TypeParameter,
+};
+
+/// Emits a warning for each of the `type_parameters` that `decl` never uses, unless the
+/// declaration is annotated with `#[allow(unused_type_parameters)]`.
+///
+/// Type parameters that come from an enclosing `impl` block are not checked here, as they are
+/// checked on the `impl` block itself.
+pub(crate) fn check_for_unused_type_parameters(
+    handler: &Handler,
+    engines: &Engines,
+    decl: &impl UnconstrainedTypeParameters,
+    type_parameters: &[TypeParameter],
+    attributes: &AttributesMap,
+    warning: impl Fn(Ident) -> Warning,
+) {
+    let allowed = attributes
+        .get(&AttributeKind::Allow)
+        .map_or(false, |allows| {
+            allows
+                .iter()
+                .flat_map(|allow| allow.args.iter())
+                .any(|arg| arg.name.as_str() == ALLOW_UNUSED_TYPE_PARAMETERS_NAME)
+        });
+    if allowed {
+        return;
+    }
+    for type_parameter in decl
+        .unconstrained_type_parameters(engines, type_parameters)
+        .into_iter()
+        .filter(|type_parameter| !type_parameter.is_from_parent)
+    {
+        handler.emit_warn(CompileWarning {
+            span: type_parameter.name_ident.span(),
+            warning_content: warning(type_parameter.name_ident.clone()),
+        });
+    }
+}
@@ -40,6 +40,9 @@ impl ty::TyIntrinsicFunctionKind {
             Intrinsic::SizeOfStr => {
                 type_check_size_of_type(handler, ctx, kind, arguments, type_arguments, span)
             }
+            Intrinsic::TypeName | Intrinsic::TypeId => {
+                type_check_type_name_or_id(handler, ctx, kind, arguments, type_arguments, span)
+            }
             Intrinsic::IsReferenceType => {
                 type_check_is_reference_type(handler, ctx, kind, arguments, type_arguments, span)
             }
@@ -240,6 +243,72 @@ fn type_check_size_of_type(
     Ok((intrinsic_function, return_type))
 }
 
+/// Signature: `__type_name<T>() -> str`
+/// Description: Returns the canonical name of the type `T`.
+/// Constraints: None.
+///
+/// Signature: `__type_id<T>() -> u64`
+/// Description: Returns a stable id of the type `T`, derived from its canonical name.
+/// Constraints: None.
+fn type_check_type_name_or_id(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    kind: sway_ast::Intrinsic,
+    arguments: Vec<Expression>,
+    type_arguments: Vec<TypeArgument>,
+    span: Span,
+) -> Result<(ty::TyIntrinsicFunctionKind, TypeId), ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    if !arguments.is_empty() {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumArgs {
+            name: kind.to_string(),
+            expected: 0,
+            span,
+        }));
+    }
+    if type_arguments.len() != 1 {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumTArgs {
+            name: kind.to_string(),
+            expected: 1,
+            span,
+        }));
+    }
+    let targ = type_arguments[0].clone();
+    let initial_type_info = type_engine
+        .to_typeinfo(targ.type_id, &targ.span)
+        .map_err(|e| handler.emit_err(e.into()))
+        .unwrap_or_else(TypeInfo::ErrorRecovery);
+    let initial_type_id = type_engine.insert(engines, initial_type_info, targ.span.source_id());
+    let type_id = ctx
+        .resolve_type(
+            handler,
+            initial_type_id,
+            &targ.span,
+            EnforceTypeArguments::Yes,
+            None,
+        )
+        .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err), None));
+    let intrinsic_function = ty::TyIntrinsicFunctionKind {
+        kind,
+        arguments: vec![],
+        type_arguments: vec![TypeArgument {
+            type_id,
+            initial_type_id,
+            span: targ.span,
+            call_path_tree: targ.call_path_tree,
+        }],
+        span,
+    };
+    let return_type_info = match kind {
+        Intrinsic::TypeName => TypeInfo::StringSlice,
+        _ => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+    };
+    let return_type = type_engine.insert(engines, return_type_info, None);
+    Ok((intrinsic_function, return_type))
+}
+
 /// Signature: `__is_reference_type<T>() -> bool`
 /// Description: Returns `true` if `T` is a _reference type_ and `false` otherwise.
 /// Constraints: None.
@@ -1166,8 +1235,7 @@ fn type_check_revert(
             type_arguments: vec![],
             span,
         },
-        type_engine.insert(engines, TypeInfo::Unknown, None), // TODO: change this to the `Never` type when
-                                                              // available
+        type_engine.insert(engines, TypeInfo::Never, None),
     ))
 }
 
@@ -6,7 +6,7 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::Span;
 
 use crate::decl_engine::DeclEngine;
-use crate::{language::ty, language::Literal, TypeInfo};
+use crate::{language::ty, language::Literal, Engines, TypeInfo};
 
 use super::{patstack::PatStack, range::Range};
 
@@ -114,7 +114,11 @@ pub(crate) enum Pattern {
 
 impl Pattern {
     /// Converts a `Scrutinee` to a `Pattern`.
-    pub(crate) fn from_scrutinee(scrutinee: ty::TyScrutinee) -> Self {
+    ///
+    /// The fields of struct patterns are listed in the order of the struct
+    /// declaration, and the fields omitted by a rest pattern are wildcards,
+    /// so that the sub-patterns of two struct patterns line up one to one.
+    pub(crate) fn from_scrutinee(engines: &Engines, scrutinee: ty::TyScrutinee) -> Self {
         let pat = match scrutinee.variant {
             ty::TyScrutineeVariant::CatchAll => Pattern::Wildcard,
             ty::TyScrutineeVariant::Variable(_) => Pattern::Wildcard,
@@ -125,13 +129,18 @@ impl Pattern {
                 fields,
                 instantiation_call_path: _,
             } => {
+                let struct_decl = engines.de().get_struct(&struct_ref);
+                let mut fields = fields;
                 let mut new_fields = vec![];
-                for field in fields.into_iter() {
-                    let f = match field.scrutinee {
-                        Some(scrutinee) => Pattern::from_scrutinee(scrutinee),
+                for decl_field in struct_decl.fields.iter() {
+                    let position = fields
+                        .iter()
+                        .position(|field| field.field.as_str() == decl_field.name.as_str());
+                    let f = match position.and_then(|position| fields.remove(position).scrutinee) {
+                        Some(scrutinee) => Pattern::from_scrutinee(engines, scrutinee),
                         None => Pattern::Wildcard,
                     };
-                    new_fields.push((field.field.as_str().to_string(), f));
+                    new_fields.push((decl_field.name.as_str().to_string(), f));
                 }
                 Pattern::Struct(StructPattern {
                     struct_name: struct_ref.name().to_string(),
@@ -141,14 +150,14 @@ impl Pattern {
             ty::TyScrutineeVariant::Or(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
                 }
                 Pattern::Or(new_elems)
             }
             ty::TyScrutineeVariant::Tuple(elems) => {
                 let mut new_elems = PatStack::empty();
                 for elem in elems.into_iter() {
-                    new_elems.push(Pattern::from_scrutinee(elem));
+                    new_elems.push(Pattern::from_scrutinee(engines, elem));
                 }
                 Pattern::Tuple(new_elems)
             }
@@ -160,7 +169,7 @@ impl Pattern {
             } => Pattern::Enum(EnumPattern {
                 enum_name: enum_ref.name().to_string(),
                 variant_name: variant.name.to_string(),
-                value: Box::new(Pattern::from_scrutinee(*value)),
+                value: Box::new(Pattern::from_scrutinee(engines, *value)),
             }),
         };
         pat
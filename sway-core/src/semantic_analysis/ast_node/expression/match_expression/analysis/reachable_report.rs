@@ -3,13 +3,25 @@ use crate::language::ty;
 pub(crate) struct ReachableReport {
     pub(crate) reachable: bool,
     pub(crate) scrutinee: ty::TyScrutinee,
+    pub(crate) is_guarded: bool,
 }
 
 impl ReachableReport {
-    pub(super) fn new(reachable: bool, scrutinee: ty::TyScrutinee) -> ReachableReport {
+    pub(super) fn new(
+        reachable: bool,
+        scrutinee: ty::TyScrutinee,
+        is_guarded: bool,
+    ) -> ReachableReport {
         ReachableReport {
             reachable,
             scrutinee,
+            is_guarded,
         }
     }
+
+    /// Returns true if the arm matches all the values, i.e. if its scrutinee is a catch-all and
+    /// it has no guard.
+    pub(crate) fn is_catch_all(&self) -> bool {
+        !self.is_guarded && self.scrutinee.is_catch_all()
+    }
 }
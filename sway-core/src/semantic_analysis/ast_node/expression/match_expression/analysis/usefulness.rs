@@ -200,6 +200,7 @@ pub(crate) fn check_match_expression_usefulness(
     engines: &Engines,
     type_id: TypeId,
     scrutinees: Vec<ty::TyScrutinee>,
+    guarded_arms: &[bool],
     span: Span,
 ) -> Result<(WitnessReport, Vec<ReachableReport>), ErrorEmitted> {
     let mut matrix = Matrix::empty();
@@ -221,15 +222,20 @@ pub(crate) fn check_match_expression_usefulness(
     }
 
     let factory = ConstructorFactory::new(engines, type_id);
-    for scrutinee in scrutinees.into_iter() {
-        let pat = Pattern::from_scrutinee(scrutinee.clone());
+    for (scrutinee, is_guarded) in scrutinees.into_iter().zip(guarded_arms.iter().copied()) {
+        let pat = Pattern::from_scrutinee(engines, scrutinee.clone());
         let v = PatStack::from_pattern(pat);
         let witness_report = is_useful(handler, engines, &factory, &matrix, &v, &span)?;
-        matrix.push(v);
+        // an arm with a guard may not match the values its scrutinee matches, so it doesn't
+        // cover them for the subsequent arms nor for the exhaustiveness
+        if !is_guarded {
+            matrix.push(v);
+        }
         // if an arm has witnesses to its usefulness then it is reachable
         arms_reachability.push(ReachableReport::new(
             witness_report.has_witnesses(),
             scrutinee,
+            is_guarded,
         ));
     }
     let v = PatStack::from_pattern(Pattern::wild_pattern());
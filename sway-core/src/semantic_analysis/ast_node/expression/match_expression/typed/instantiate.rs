@@ -29,7 +29,7 @@ impl Instantiate {
             None,
         );
         let boolean_type = type_engine.insert(engines, TypeInfo::Boolean, None);
-        let revert_type = type_engine.insert(engines, TypeInfo::Unknown, None); // TODO: Change this to the `Never` type once available.
+        let revert_type = type_engine.insert(engines, TypeInfo::Never, None);
 
         Self {
             span,
@@ -51,6 +51,10 @@ impl Instantiate {
         self.u64_type
     }
 
+    pub(super) fn boolean_type(&self) -> TypeId {
+        self.boolean_type
+    }
+
     /// Instantiates a [ty::TyDecl::VariableDecl] for an immutable variable of the form `let <name> = <body>;`.
     pub(super) fn var_decl(&self, name: Ident, body: ty::TyExpression) -> ty::TyDecl {
         let return_type = body.return_type;
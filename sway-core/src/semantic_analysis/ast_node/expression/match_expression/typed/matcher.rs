@@ -485,7 +485,8 @@ fn match_enum(
     let mut nodes = vec![];
 
     // The first requirement is that the enum variant behind the `exp` is
-    // of the kind `variant`. `exp is variant` is expressed as `EnumTag(<exp>) == <variant.tag>`.
+    // of the kind `variant`. `exp is variant` is expressed as
+    // `EnumTag(<exp>) == <variant.discriminant>`.
     let enum_variant_req = (
         ty::TyExpression {
             expression: ty::TyExpressionVariant::EnumTag {
@@ -499,7 +500,7 @@ fn match_enum(
             span: exp.span.clone(),
         },
         ty::TyExpression {
-            expression: ty::TyExpressionVariant::Literal(Literal::U64(variant.tag as u64)),
+            expression: ty::TyExpressionVariant::Literal(Literal::U64(variant.discriminant)),
             return_type: type_engine.insert(
                 ctx.engines,
                 TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
@@ -251,6 +251,36 @@
 //! which variant has match, but would mean always instantiating a tuple that is not needed in a case of non-match.
 //! In this trade-off we went for the option explained above.
 //! Note that we will anyhow optimize match expressions on the IR level.
+//!
+//! ### Guards
+//!
+//! The guard of a match arm becomes a part of the match arm `if` condition. It is evaluated only
+//! if the pattern matches, within a block that declares the match arm variables, so that they can
+//! be used in the guard. If the guard does not hold, the matching continues with the next arm.
+//!
+//! ```ignore
+//! match exp {
+//!     Some(x) if x > 3 => x,
+//!     _ => 0,
+//! }
+//! ```
+//! ```ignore
+//! let __matched_value_1 = exp;
+//! if __matched_value_1 is Option::Some && {
+//!     let x = (__matched_value_1 as Some: u64);
+//!     x > 3
+//! } {
+//!     let x = (__matched_value_1 as Some: u64);
+//!     x
+//! }
+//! else {
+//!     0
+//! }
+//! ```
+//!
+//! As a guard might not hold, an arm with a guard is not considered to cover the values its
+//! pattern matches, neither for the reachability of the subsequent arms nor for the
+//! exhaustiveness of the match expression.
 
 mod instantiate;
 mod matcher;
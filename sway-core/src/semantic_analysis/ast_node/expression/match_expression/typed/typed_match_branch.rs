@@ -31,15 +31,16 @@ impl ty::TyMatchBranch {
     ) -> Result<(ty::TyMatchBranch, ty::TyScrutinee), ErrorEmitted> {
         let MatchBranch {
             scrutinee,
+            guard,
             result,
             span: branch_span,
         } = branch;
 
-        // For the dummy span of all the instantiated code elements that cannot be mapped on
-        // any of the elements from the original code, we will simply take the span of the
-        // whole match arm. We assume that these spans will never be used.
+        // For the span of all the instantiated code elements that cannot be mapped on
+        // any of the elements from the original code, we will simply take a synthetic span
+        // of the whole match arm, so that they point at the arm they were desugared from.
         // This is also the error span in case of internal compiler errors.
-        let instantiate = Instantiate::new(ctx.engines, branch_span.clone());
+        let instantiate = Instantiate::new(ctx.engines, Span::synthetic(&branch_span));
 
         let type_engine = ctx.engines.te();
         let decl_engine = ctx.engines.de();
@@ -103,6 +104,42 @@ impl ty::TyMatchBranch {
             });
         }
 
+        // type check the guard, if any, within the scope of the match arm variables and make it
+        // a part of the branch condition. the guard is evaluated only if the scrutinee matches,
+        // in a block that declares the variables again, so if it doesn't hold the matching
+        // falls through to the subsequent arms
+        let is_guarded = guard.is_some();
+        let condition = match guard {
+            Some(guard) => {
+                let typed_guard = {
+                    let ctx = branch_ctx
+                        .by_ref()
+                        .with_help_text("The guard of a match arm must be a boolean expression.")
+                        .with_type_annotation(instantiate.boolean_type());
+                    ty::TyExpression::type_check(handler, ctx, guard)?
+                };
+                let guard_span = typed_guard.span.clone();
+                let mut guard_block_contents = code_block_contents.clone();
+                guard_block_contents.push(ty::TyAstNode {
+                    content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_guard),
+                    span: guard_span.clone(),
+                });
+                let guard_block = ty::TyExpression {
+                    expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
+                        contents: guard_block_contents,
+                        whole_block_span: Span::synthetic(&guard_span),
+                    }),
+                    return_type: instantiate.boolean_type(),
+                    span: guard_span,
+                };
+                Some(match condition {
+                    Some(condition) => instantiate.lazy_and(condition, guard_block),
+                    None => guard_block,
+                })
+            }
+            None => condition,
+        };
+
         // type check the branch result
         let typed_result = {
             let ctx = branch_ctx.by_ref().with_type_annotation(type_engine.insert(
@@ -152,7 +189,7 @@ impl ty::TyMatchBranch {
         let new_result = ty::TyExpression {
             expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
                 contents: code_block_contents,
-                whole_block_span: sway_types::Span::dummy(),
+                whole_block_span: Span::synthetic(&branch_span),
             }),
             return_type: typed_result.return_type,
             span: typed_result_span,
@@ -161,6 +198,7 @@ impl ty::TyMatchBranch {
         let typed_branch = ty::TyMatchBranch {
             matched_or_variant_index_vars: or_variant_vars,
             condition,
+            is_guarded,
             result: new_result,
             span: branch_span,
         };
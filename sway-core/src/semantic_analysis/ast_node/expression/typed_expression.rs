@@ -18,7 +18,10 @@ pub(crate) use self::{
 };
 
 use crate::{
-    asm_lang::{virtual_ops::VirtualOp, virtual_register::VirtualRegister},
+    asm_lang::{
+        virtual_ops::VirtualOp,
+        virtual_register::{ConstantRegister, VirtualRegister},
+    },
     decl_engine::*,
     language::{
         parsed::*,
@@ -226,15 +229,18 @@ impl ty::TyExpression {
                 r#else.map(|e| *e),
                 span,
             ),
-            ExpressionKind::Match(MatchExpression { value, branches }) => {
-                Self::type_check_match_expression(
-                    handler,
-                    ctx.by_ref().with_help_text(""),
-                    *value,
-                    branches,
-                    span,
-                )
-            }
+            ExpressionKind::Match(MatchExpression {
+                value,
+                branches,
+                source,
+            }) => Self::type_check_match_expression(
+                handler,
+                ctx.by_ref().with_help_text(""),
+                *value,
+                branches,
+                source,
+                span,
+            ),
             ExpressionKind::Asm(asm) => {
                 Self::type_check_asm_expression(handler, ctx.by_ref(), *asm, span)
             }
@@ -360,7 +366,7 @@ impl ty::TyExpression {
             ExpressionKind::Break => {
                 let expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Break,
-                    return_type: type_engine.insert(engines, TypeInfo::Unknown, None),
+                    return_type: type_engine.insert(engines, TypeInfo::Never, None),
                     span,
                 };
                 Ok(expr)
@@ -368,14 +374,23 @@ impl ty::TyExpression {
             ExpressionKind::Continue => {
                 let expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Continue,
-                    return_type: type_engine.insert(engines, TypeInfo::Unknown, None),
+                    return_type: type_engine.insert(engines, TypeInfo::Never, None),
                     span,
                 };
                 Ok(expr)
             }
-            ExpressionKind::Reassignment(ReassignmentExpression { lhs, rhs }) => {
-                Self::type_check_reassignment(handler, ctx.by_ref(), lhs, *rhs, span)
-            }
+            ExpressionKind::Reassignment(ReassignmentExpression {
+                lhs,
+                rhs,
+                assign_op_call,
+            }) => Self::type_check_reassignment(
+                handler,
+                ctx.by_ref(),
+                lhs,
+                *rhs,
+                assign_op_call.map(|call| *call),
+                span,
+            ),
             ExpressionKind::Return(expr) => {
                 let ctx = ctx
                     // we use "unknown" here because return statements do not
@@ -397,8 +412,7 @@ impl ty::TyExpression {
                     .unwrap_or_else(|err| ty::TyExpression::error(err, expr_span, engines));
                 let typed_expr = ty::TyExpression {
                     expression: ty::TyExpressionVariant::Return(Box::new(expr)),
-                    return_type: type_engine.insert(engines, TypeInfo::Unknown, None),
-                    // FIXME: This should be Yes?
+                    return_type: type_engine.insert(engines, TypeInfo::Never, None),
                     span,
                 };
                 Ok(typed_expr)
@@ -674,6 +688,7 @@ impl ty::TyExpression {
         mut ctx: TypeCheckContext,
         value: Expression,
         branches: Vec<MatchBranch>,
+        source: MatchExpressionSource,
         span: Span,
     ) -> Result<ty::TyExpression, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -704,12 +719,29 @@ impl ty::TyExpression {
             span.clone(),
         )?;
 
+        // the `else` block of a `let .. else` is the last arm, and it must not complete normally
+        if source == MatchExpressionSource::LetElse {
+            if let Some(else_branch) = typed_match_expression.branches.last() {
+                if !else_branch.result.diverges(engines.de()) {
+                    handler.emit_err(CompileError::LetElseDoesNotDiverge {
+                        span: else_branch.result.span.clone(),
+                    });
+                }
+            }
+        }
+
         // check to see if the match expression is exhaustive and if all match arms are reachable
+        let guarded_arms = typed_match_expression
+            .branches
+            .iter()
+            .map(|branch| branch.is_guarded)
+            .collect::<Vec<_>>();
         let (witness_report, arms_reachability) = check_match_expression_usefulness(
             handler,
             engines,
             type_id,
             typed_scrutinees.clone(),
+            &guarded_arms,
             span.clone(),
         )?;
 
@@ -762,7 +794,21 @@ impl ty::TyExpression {
             );
 
             // for the last one, give a different warning if it is an unreachable catch-all arm
-            if !last_arm_report.reachable {
+            if !last_arm_report.reachable && source != MatchExpressionSource::Match {
+                // the last arm of a desugared `if let` or `let .. else` is the one taken when
+                // the pattern doesn't match, so it's the pattern that is pointed out as irrefutable
+                let pattern = other_arms_reachability
+                    .last()
+                    .map(|report| report.scrutinee.span.clone())
+                    .unwrap_or_else(|| last_arm_report.scrutinee.span.clone());
+                handler.emit_warn(CompileWarning {
+                    span: pattern.clone(),
+                    warning_content: Warning::IrrefutableLetPattern {
+                        pattern,
+                        match_type: engines.help_out(type_id).to_string(),
+                    },
+                });
+            } else if !last_arm_report.reachable {
                 handler.emit_warn(CompileWarning {
                     span: last_arm_report.scrutinee.span.clone(),
                     warning_content: Warning::MatchExpressionUnreachableArm {
@@ -776,7 +822,7 @@ impl ty::TyExpression {
                         ),
                         unreachable_arm: last_arm_report.scrutinee.span.clone(),
                         is_last_arm: true,
-                        is_catch_all_arm: last_arm_report.scrutinee.is_catch_all(),
+                        is_catch_all_arm: last_arm_report.is_catch_all(),
                     },
                 });
             }
@@ -815,7 +861,7 @@ impl ty::TyExpression {
                 .split_last()?
                 .1
                 .iter()
-                .position(|report| report.scrutinee.is_catch_all())
+                .position(|report| report.is_catch_all())
         }
 
         fn check_interior_non_catch_all_arms_for_reachability(
@@ -859,16 +905,19 @@ impl ty::TyExpression {
         let engines = ctx.engines();
 
         // Various checks that we can catch early to check that the assembly is valid. For now,
-        // this includes two checks:
+        // this includes these checks:
+        // 0. Check that the instructions are valid VM instructions, with the right number of
+        //    operands and immediates that fit, and that all of their registers are declared.
         // 1. Check that no control flow opcodes are used.
-        // 2. Check that initialized registers are not reassigned in the `asm` block.
+        // 2. Check that initialized registers are not reassigned in the `asm` block, unless
+        //    they are declared as clobbered.
+        // 3. Check that uninitialized registers are not read before they are written.
         check_asm_block_validity(handler, &asm, &ctx)?;
 
-        let asm_span = asm
-            .returns
-            .clone()
-            .map(|x| x.1)
-            .unwrap_or_else(|| asm.whole_block_span.clone());
+        let asm_span = match (asm.returns.first(), asm.returns.last()) {
+            (Some((_, first)), Some((_, last))) => Span::join(first.clone(), last.clone()),
+            _ => asm.whole_block_span.clone(),
+        };
         let return_type = ctx
             .resolve_type(
                 handler,
@@ -879,17 +928,59 @@ impl ty::TyExpression {
             )
             .unwrap_or_else(|err| type_engine.insert(engines, TypeInfo::ErrorRecovery(err), None));
 
+        // A block that returns several registers stores them into a tuple, so the elements of
+        // the tuple must fit into registers.
+        if asm.returns.len() > 1 {
+            let is_register_tuple = match &*type_engine.get_unaliased(return_type) {
+                TypeInfo::Tuple(elems) => {
+                    elems.len() == asm.returns.len()
+                        && elems.iter().all(|elem| {
+                            matches!(
+                                &*type_engine.get_unaliased(elem.type_id),
+                                TypeInfo::UnsignedInteger(
+                                    IntegerBits::Eight
+                                        | IntegerBits::Sixteen
+                                        | IntegerBits::ThirtyTwo
+                                        | IntegerBits::SixtyFour
+                                ) | TypeInfo::Boolean
+                                    | TypeInfo::RawUntypedPtr
+                                    | TypeInfo::ErrorRecovery(_)
+                            )
+                        })
+                }
+                TypeInfo::ErrorRecovery(_) => true,
+                _ => false,
+            };
+            if !is_register_tuple {
+                handler.emit_err(CompileError::InvalidAsmTupleOutputType {
+                    ty: engines.help_out(return_type).to_string(),
+                    registers: asm.returns.len(),
+                    span: asm_span.clone(),
+                });
+            }
+        }
+
         // type check the initializers
         let typed_registers = asm
             .registers
             .clone()
             .into_iter()
             .map(
-                |AsmRegisterDeclaration { name, initializer }| ty::TyAsmRegisterDeclaration {
+                |AsmRegisterDeclaration {
+                     name,
+                     initializer,
+                     is_const,
+                 }| ty::TyAsmRegisterDeclaration {
                     name,
                     initializer: initializer.map(|initializer| {
+                        // The values of `const` operands become immediates.
+                        let type_annotation = if is_const {
+                            TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)
+                        } else {
+                            TypeInfo::Unknown
+                        };
                         let ctx = ctx.by_ref().with_help_text("").with_type_annotation(
-                            type_engine.insert(engines, TypeInfo::Unknown, None),
+                            type_engine.insert(engines, type_annotation, None),
                         );
 
                         ty::TyExpression::type_check(handler, ctx, initializer.clone())
@@ -897,6 +988,7 @@ impl ty::TyExpression {
                                 ty::TyExpression::error(err, initializer.span(), engines)
                             })
                     }),
+                    is_const,
                 },
             )
             .collect();
@@ -1799,12 +1891,76 @@ impl ty::TyExpression {
             ty::TyExpression::type_check(handler, ctx, prefix.clone())?
         };
 
-        fn get_array_type(ty: TypeId, type_engine: &TypeEngine) -> Option<TypeInfo> {
-            match &*type_engine.get(ty) {
-                TypeInfo::Array(..) => Some((*type_engine.get(ty)).clone()),
-                TypeInfo::Alias { ty, .. } => get_array_type(ty.type_id, type_engine),
-                _ => None,
-            }
+        // If the return type is a static array and the index is a range, then convert the array
+        // into a slice of all of its elements, to be sliced via the core::ops::Index trait.
+        if let (Some(TypeInfo::Array(elem_type, length)), true) = (
+            get_array_type(prefix_te.return_type, type_engine),
+            is_range(&index),
+        ) {
+            let slice = Expression {
+                kind: ExpressionKind::FunctionApplication(Box::new(
+                    FunctionApplicationExpression {
+                        call_path_binding: TypeBinding {
+                            inner: CallPath {
+                                prefixes: vec![
+                                    Ident::new_with_override("core".into(), span.clone()),
+                                    Ident::new_with_override("slice".into(), span.clone()),
+                                ],
+                                suffix: Ident::new_with_override("from_parts".into(), span.clone()),
+                                is_absolute: true,
+                            },
+                            type_arguments: TypeArgs::Regular(vec![elem_type]),
+                            span: span.clone(),
+                        },
+                        arguments: vec![
+                            Expression {
+                                kind: ExpressionKind::IntrinsicFunction(
+                                    IntrinsicFunctionExpression {
+                                        name: Ident::new_with_override(
+                                            "__addr_of".into(),
+                                            span.clone(),
+                                        ),
+                                        kind_binding: TypeBinding {
+                                            inner: Intrinsic::AddrOf,
+                                            type_arguments: TypeArgs::Regular(vec![]),
+                                            span: span.clone(),
+                                        },
+                                        arguments: vec![prefix],
+                                    },
+                                ),
+                                span: span.clone(),
+                            },
+                            Expression {
+                                kind: ExpressionKind::Literal(Literal::U64(length.val() as u64)),
+                                span: span.clone(),
+                            },
+                        ],
+                    },
+                )),
+                span: span.clone(),
+            };
+            let method_name = TypeBinding {
+                inner: MethodName::FromTrait {
+                    call_path: CallPath {
+                        prefixes: vec![
+                            Ident::new_with_override("core".into(), span.clone()),
+                            Ident::new_with_override("ops".into(), span.clone()),
+                        ],
+                        suffix: Ident::new_with_override("index".into(), span.clone()),
+                        is_absolute: true,
+                    },
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: span.clone(),
+            };
+            return type_check_method_application(
+                handler,
+                ctx,
+                method_name,
+                vec![],
+                vec![slice, index],
+                span,
+            );
         }
 
         // If the return type is a static array then create a `ty::TyExpressionVariant::ArrayIndex`.
@@ -1826,7 +1982,7 @@ impl ty::TyExpression {
                 span,
             })
         } else {
-            // Otherwise convert into a method call 'index(self, index)' via the std::ops::Index trait.
+            // Otherwise convert into a method call 'index(self, index)' via the core::ops::Index trait.
             let method_name = TypeBinding {
                 inner: MethodName::FromTrait {
                     call_path: CallPath {
@@ -1919,6 +2075,7 @@ impl ty::TyExpression {
         ctx: TypeCheckContext,
         lhs: ReassignmentTarget,
         rhs: Expression,
+        assign_op_call: Option<Expression>,
         span: Span,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -1930,6 +2087,48 @@ impl ty::TyExpression {
         // ensure that the lhs is a supported expression kind
         match lhs {
             ReassignmentTarget::VariableExpression(var) => {
+                // If the lhs indexes into anything but a static array then convert into a method
+                // call 'index_assign(self, index, value)' via the core::ops::IndexMut trait.
+                if let ExpressionKind::ArrayIndex(ArrayIndexExpression { prefix, index }) =
+                    &var.kind
+                {
+                    let prefix_te = {
+                        let ctx = ctx.by_ref().with_type_annotation(type_engine.insert(
+                            engines,
+                            TypeInfo::Unknown,
+                            None,
+                        ));
+                        ty::TyExpression::type_check(handler, ctx, (**prefix).clone())?
+                    };
+                    if get_array_type(prefix_te.return_type, type_engine).is_none() {
+                        let method_name = TypeBinding {
+                            inner: MethodName::FromTrait {
+                                call_path: CallPath {
+                                    prefixes: vec![
+                                        Ident::new_with_override("core".into(), span.clone()),
+                                        Ident::new_with_override("ops".into(), span.clone()),
+                                    ],
+                                    suffix: Ident::new_with_override(
+                                        "index_assign".into(),
+                                        span.clone(),
+                                    ),
+                                    is_absolute: true,
+                                },
+                            },
+                            type_arguments: TypeArgs::Regular(vec![]),
+                            span: span.clone(),
+                        };
+                        return type_check_method_application(
+                            handler,
+                            ctx,
+                            method_name,
+                            vec![],
+                            vec![(**prefix).clone(), (**index).clone(), rhs],
+                            span,
+                        );
+                    }
+                }
+
                 let mut expr = var;
                 let mut names_vec = Vec::new();
                 let (base_name, final_return_type) = loop {
@@ -1996,6 +2195,31 @@ impl ty::TyExpression {
                     &base_name,
                     &names_vec,
                 )?;
+
+                // a compound assignment is a call to the assignment operator trait method if the
+                // type of the lhs implements it
+                if let Some(assign_op_call) = assign_op_call {
+                    if let ExpressionKind::MethodApplication(method_application) =
+                        &assign_op_call.kind
+                    {
+                        if let MethodName::FromTrait { call_path } =
+                            &method_application.method_name_binding.inner
+                        {
+                            if ctx
+                                .find_assign_op_method_for_type(ty_of_field, call_path)
+                                .is_some()
+                            {
+                                let ctx = ctx.with_type_annotation(type_engine.insert(
+                                    engines,
+                                    TypeInfo::Tuple(Vec::new()),
+                                    None,
+                                ));
+                                return ty::TyExpression::type_check(handler, ctx, assign_op_call);
+                            }
+                        }
+                    }
+                }
+
                 // type check the reassignment
                 let ctx = ctx.with_type_annotation(ty_of_field).with_help_text("");
                 let rhs_span = rhs.span();
@@ -2176,31 +2400,107 @@ impl ty::TyExpression {
     }
 }
 
+/// Returns whether the index is a range, i.e. `start..end`, which is desugared to
+/// `core::ops::Range { start, end }`.
+fn is_range(index: &Expression) -> bool {
+    match &index.kind {
+        ExpressionKind::Struct(struct_expression) => {
+            let call_path = &struct_expression.call_path_binding.inner;
+            call_path.is_absolute
+                && call_path.suffix.as_str() == "Range"
+                && call_path
+                    .prefixes
+                    .iter()
+                    .map(|prefix| prefix.as_str())
+                    .eq(["core", "ops"])
+        }
+        _ => false,
+    }
+}
+
+fn get_array_type(ty: TypeId, type_engine: &TypeEngine) -> Option<TypeInfo> {
+    match &*type_engine.get(ty) {
+        TypeInfo::Array(..) => Some((*type_engine.get(ty)).clone()),
+        TypeInfo::Alias { ty, .. } => get_array_type(ty.type_id, type_engine),
+        _ => None,
+    }
+}
+
 fn check_asm_block_validity(
     handler: &Handler,
     asm: &AsmExpression,
     ctx: &TypeCheckContext,
 ) -> Result<(), ErrorEmitted> {
-    // Collect all asm block instructions in the form of `VirtualOp`s
+    // The values of `const` operands are only known once the program is compiled, so the
+    // immediates that name them are checked with a placeholder value here.
+    let const_operands = asm
+        .registers
+        .iter()
+        .filter(|reg| reg.is_const)
+        .map(|reg| reg.name.as_str())
+        .collect::<FxHashSet<_>>();
+
+    // Registers that can be used as operands are the declared ones that are not `const`
+    // operands, and the reserved registers of the VM.
+    let declared_registers = asm
+        .registers
+        .iter()
+        .filter(|reg| !reg.is_const)
+        .map(|reg| reg.name.as_str())
+        .collect::<FxHashSet<_>>();
+    let check_register = |reg_name: &Ident| {
+        if !declared_registers.contains(reg_name.as_str())
+            && ConstantRegister::parse_register_name(reg_name.as_str()).is_none()
+        {
+            handler.emit_err(CompileError::UnknownRegister {
+                span: reg_name.span(),
+                initialized_registers: asm
+                    .registers
+                    .iter()
+                    .filter(|reg| !reg.is_const)
+                    .map(|reg| reg.name.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            });
+        }
+    };
+
+    // Collect all asm block instructions in the form of `VirtualOp`s. The instructions are
+    // checked against the instruction set of the VM here, so that every invalid instruction is
+    // reported with its own span, rather than failing when the final assembly is generated.
     let mut opcodes = vec![];
+    let mut error_emitted = None;
     for op in &asm.body {
+        op.op_args.iter().for_each(check_register);
         let registers = op
             .op_args
             .iter()
             .map(|reg_name| VirtualRegister::Virtual(reg_name.to_string()))
             .collect::<Vec<VirtualRegister>>();
+        let immediate = op.immediate.as_ref().map(|imm| {
+            if const_operands.contains(imm.as_str()) {
+                Ident::new_with_override("i0".to_string(), imm.span())
+            } else {
+                imm.clone()
+            }
+        });
 
-        opcodes.push((
-            crate::asm_lang::Op::parse_opcode(
-                handler,
-                &op.op_name,
-                &registers,
-                &op.immediate,
-                op.span.clone(),
-            )?,
-            op.op_name.clone(),
+        match crate::asm_lang::Op::parse_opcode(
+            handler,
+            &op.op_name,
+            &registers,
+            &immediate,
             op.span.clone(),
-        ));
+        ) {
+            Ok(opcode) => opcodes.push((opcode, op.op_name.clone(), op.span.clone())),
+            Err(err) => error_emitted = Some(err),
+        }
+    }
+    asm.returns
+        .iter()
+        .for_each(|(reg, _)| check_register(&reg.name));
+    if let Some(err) = error_emitted {
+        return Err(err);
     }
 
     // Check #1: Disallow control flow instructions
@@ -2228,20 +2528,39 @@ fn check_asm_block_validity(
         handler.emit_err(err);
     }
 
-    // Check #2: Disallow initialized registers from being reassigned in the asm block
+    // Check #2: Disallow initialized registers from being reassigned in the asm block, unless
+    // they are declared as clobbered
     //
-    // 1. Collect all registers that have initializers in the list of arguments
+    // 1. Collect all registers that have initializers in the list of arguments, and are not
+    //    declared as clobbered
+    let clobbered_registers = asm
+        .clobbers
+        .iter()
+        .map(|reg| VirtualRegister::Virtual(reg.to_string()))
+        .collect::<FxHashSet<_>>();
     let initialized_registers = asm
         .registers
         .iter()
         .filter_map(|reg| {
-            if reg.initializer.is_some() {
+            if reg.initializer.is_some() && !reg.is_const {
                 Some(VirtualRegister::Virtual(reg.name.to_string()))
             } else {
                 None
             }
         })
         .collect::<FxHashSet<_>>();
+    for clobber in asm.clobbers.iter() {
+        if !initialized_registers.contains(&VirtualRegister::Virtual(clobber.to_string())) {
+            handler.emit_err(CompileError::InvalidAsmClobber {
+                name: clobber.to_string(),
+                span: clobber.span(),
+            });
+        }
+    }
+    let initialized_registers = initialized_registers
+        .difference(&clobbered_registers)
+        .cloned()
+        .collect::<FxHashSet<_>>();
 
     // 2. From the list of `VirtualOp`s, figure out what registers are assigned
     let assigned_registers: FxHashSet<VirtualRegister> =
@@ -2279,7 +2598,7 @@ fn check_asm_block_validity(
         .registers
         .iter()
         .filter_map(|reg| {
-            if reg.initializer.is_none() {
+            if reg.initializer.is_none() && !reg.is_const {
                 let span = reg.name.span();
 
                 // Emit warning if this register shadows a variable
@@ -2323,7 +2642,7 @@ fn check_asm_block_validity(
         }
     }
 
-    if let Some((reg, _)) = asm.returns.as_ref() {
+    for (reg, _) in asm.returns.iter() {
         let reg = VirtualRegister::Virtual(reg.name.to_string());
         if let Some(span) = uninitialized_registers.remove(&reg) {
             handler.emit_err(CompileError::UninitRegisterInAsmBlockBeingRead { span });
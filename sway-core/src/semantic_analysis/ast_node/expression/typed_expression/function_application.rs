@@ -52,6 +52,10 @@ pub(crate) fn instantiate_function_application(
         false,
     )?;
 
+    // Let the expected type bind the type parameters of the function before the arguments are
+    // type checked, so that they are type checked against the concrete parameter types.
+    ctx.propagate_type_annotation(handler, function_decl.return_type.type_id, &span);
+
     let typed_arguments =
         type_check_arguments(handler, ctx.by_ref(), arguments, &function_decl.parameters)?;
 
@@ -72,7 +76,13 @@ pub(crate) fn instantiate_function_application(
         function_decl.name.as_str(),
         &call_path_binding.span(),
     )?;
-    function_decl.replace_decls(&decl_mapping, handler, &mut ctx)?;
+    let instantiation_name = function_decl.instantiation_name(ctx.engines);
+    ty::record_instantiation(
+        handler,
+        instantiation_name,
+        &call_path_binding.span(),
+        |handler| function_decl.replace_decls(&decl_mapping, handler, &mut ctx),
+    )?;
     let return_type = function_decl.return_type.clone();
     let new_decl_ref = decl_engine
         .insert(function_decl)
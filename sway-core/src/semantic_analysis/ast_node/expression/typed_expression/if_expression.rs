@@ -4,10 +4,7 @@ use sway_error::{
 };
 use sway_types::Span;
 
-use crate::{
-    language::ty, semantic_analysis::TypeCheckContext, type_system::*,
-    types::DeterministicallyAborts,
-};
+use crate::{language::ty, semantic_analysis::TypeCheckContext, type_system::*};
 
 pub(crate) fn instantiate_if_expression(
     handler: &Handler,
@@ -21,10 +18,10 @@ pub(crate) fn instantiate_if_expression(
     let decl_engine = ctx.engines.de();
     let engines = ctx.engines();
 
-    // if the branch aborts, then its return type doesn't matter.
-    let then_deterministically_aborts = then.deterministically_aborts(decl_engine, true);
-    if !then_deterministically_aborts {
-        // if this does not deterministically_abort, check the block return type
+    // if the branch diverges, then its return type doesn't matter.
+    let then_diverges = then.diverges(decl_engine);
+    if !then_diverges {
+        // if this does not diverge, check the block return type
         let ty_to_check = if r#else.is_some() {
             ctx.type_annotation()
         } else {
@@ -40,16 +37,16 @@ pub(crate) fn instantiate_if_expression(
             None,
         );
     }
-    let mut else_deterministically_aborts = false;
+    let mut else_diverges = false;
     let r#else = r#else.map(|r#else| {
-        else_deterministically_aborts = r#else.deterministically_aborts(decl_engine, true);
-        let ty_to_check = if then_deterministically_aborts {
+        else_diverges = r#else.diverges(decl_engine);
+        let ty_to_check = if then_diverges {
             ctx.type_annotation()
         } else {
             then.return_type
         };
-        if !else_deterministically_aborts {
-            // if this does not deterministically_abort, check the block return type
+        if !else_diverges {
+            // if this does not diverge, check the block return type
             type_engine.unify(
                 handler,
                 engines,
@@ -67,7 +64,7 @@ pub(crate) fn instantiate_if_expression(
         type_engine.insert(engines, TypeInfo::Tuple(Vec::new()), span.source_id())
     });
     // if there is a type annotation, then the else branch must exist
-    if !else_deterministically_aborts && !then_deterministically_aborts {
+    if !else_diverges && !then_diverges {
         // delay emitting the errors until we decide if this is a missing else branch or some other set of errors
         let h = Handler::default();
         type_engine.unify(
@@ -98,7 +95,7 @@ pub(crate) fn instantiate_if_expression(
         }
     }
 
-    let return_type = if !then_deterministically_aborts {
+    let return_type = if !then_diverges {
         then.return_type
     } else {
         r#else_ret_ty
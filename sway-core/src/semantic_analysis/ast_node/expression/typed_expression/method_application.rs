@@ -571,6 +571,9 @@ pub(crate) fn monomorphize_method_application(
         )?;
         let mut method = (*decl_engine.get_function(fn_ref)).clone();
 
+        // let the expected type bind the type parameters of the method before the arguments do
+        ctx.propagate_type_annotation(handler, method.return_type.type_id, &call_path.span());
+
         // unify the types of the arguments with the types of the parameters from the function declaration
         *arguments =
             unify_arguments_and_parameters(handler, ctx.by_ref(), arguments, &method.parameters)?;
@@ -622,7 +625,10 @@ pub(crate) fn monomorphize_method_application(
         )?;
 
         if !ctx.defer_monomorphization() {
-            method.replace_decls(&decl_mapping, handler, &mut ctx)?;
+            let instantiation_name = method.instantiation_name(engines);
+            ty::record_instantiation(handler, instantiation_name, &call_path.span(), |handler| {
+                method.replace_decls(&decl_mapping, handler, &mut ctx)
+            })?;
         }
 
         decl_engine.replace(*fn_ref.id(), method);
@@ -0,0 +1,318 @@
+//! Incremental type-checking of the submodules of a program.
+//!
+//! The typed submodules are cached along with the namespace modules they were type-checked into,
+//! keyed by the package they belong to and their path within it. A cached submodule is reused
+//! when its fingerprint is unchanged, i.e. when neither its source, the sources of its own
+//! submodules, those of the modules type-checked before it, nor the packages it depends on have
+//! changed.
+//!
+//! The fingerprint of each type-checked package is recorded, so that the packages depending on it
+//! can extend their fingerprints with it. A package depending on a package whose fingerprint is
+//! unknown is not cached.
+//!
+//! The typed submodules refer to the declarations and types of the engines they were
+//! type-checked with, so the cached submodules are only valid for those engines and are cleared
+//! whenever modules are cleared from the engines.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+use sway_error::{error::CompileError, warning::CompileWarning};
+
+use crate::{
+    language::{parsed::ParseModule, ty},
+    Engines,
+};
+
+use super::namespace;
+
+/// Identifies a submodule of a package.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TypedModuleCacheKey {
+    /// The path to the root module of the package.
+    pub package: Arc<PathBuf>,
+    /// The path of the submodule within the package, e.g. `["foo", "bar"]` for `foo::bar`.
+    pub mod_path: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TypedModuleCacheEntry {
+    /// The fingerprint of the sources the submodule was type-checked from.
+    pub fingerprint: u64,
+    pub submodule: ty::TySubmodule,
+    /// The namespace module the submodule was type-checked into.
+    pub namespace: namespace::Module,
+    /// The errors and warnings of type-checking the submodule.
+    pub handler_data: (Vec<CompileError>, Vec<CompileWarning>),
+}
+
+pub type TypedModuleCacheMap = HashMap<TypedModuleCacheKey, TypedModuleCacheEntry>;
+
+/// The typed submodules of the packages type-checked with the same engines.
+#[derive(Clone, Debug, Default)]
+pub struct TypedModuleCache {
+    entries: Arc<RwLock<TypedModuleCacheMap>>,
+    /// The fingerprints of the type-checked packages, by the path to their root module.
+    package_fingerprints: Arc<RwLock<HashMap<Arc<PathBuf>, u64>>>,
+}
+
+impl TypedModuleCache {
+    pub fn get(
+        &self,
+        key: &TypedModuleCacheKey,
+        fingerprint: u64,
+    ) -> Option<TypedModuleCacheEntry> {
+        let entries = self
+            .entries
+            .read()
+            .expect("Failed to read typed module cache");
+        entries
+            .get(key)
+            .filter(|entry| entry.fingerprint == fingerprint)
+            .cloned()
+    }
+
+    pub fn insert(&self, key: TypedModuleCacheKey, entry: TypedModuleCacheEntry) {
+        let mut entries = self.entries.write().unwrap();
+        entries.insert(key, entry);
+    }
+
+    /// The fingerprint of the package with the given root module, if it was type-checked.
+    pub fn package_fingerprint(&self, package: &PathBuf) -> Option<u64> {
+        let fingerprints = self
+            .package_fingerprints
+            .read()
+            .expect("Failed to read package fingerprints");
+        fingerprints.get(package).copied()
+    }
+
+    pub fn insert_package_fingerprint(&self, package: Arc<PathBuf>, fingerprint: u64) {
+        let mut fingerprints = self.package_fingerprints.write().unwrap();
+        fingerprints.insert(package, fingerprint);
+    }
+
+    /// Drops the cached submodules. The package fingerprints are kept, as they only depend on the
+    /// sources of the packages.
+    pub fn clear(&self) {
+        let mut entries = self.entries.write().unwrap();
+        entries.clear();
+    }
+}
+
+/// The cache of the package being type-checked, along with the position of the module being
+/// type-checked within it.
+#[derive(Clone)]
+pub(crate) struct ModuleCacheScope<'a> {
+    pub(crate) cache: &'a TypedModuleCache,
+    pub(crate) package: Arc<PathBuf>,
+    pub(crate) mod_path: Vec<String>,
+    /// The fingerprint of the module, which the fingerprints of its submodules extend.
+    pub(crate) fingerprint: u64,
+}
+
+impl<'a> ModuleCacheScope<'a> {
+    /// The scope of the root module of a package, whose fingerprint covers the settings the
+    /// package is type-checked with and the fingerprints of the packages it depends on.
+    ///
+    /// The dependencies are the external modules of the `initial_namespace`, which are looked up
+    /// by the source of their span. Returns `None` if the fingerprint of any of them is unknown.
+    pub(crate) fn root(
+        cache: &'a TypedModuleCache,
+        engines: &Engines,
+        package: Arc<PathBuf>,
+        settings: u64,
+        initial_namespace: &namespace::Module,
+    ) -> Option<Self> {
+        let mut hasher = DefaultHasher::new();
+        settings.hash(&mut hasher);
+        for (name, dependency) in initial_namespace.submodules() {
+            let source_id = dependency.span.as_ref().and_then(|span| span.source_id())?;
+            let dependency_package = engines.se().get_path(source_id);
+            name.hash(&mut hasher);
+            cache
+                .package_fingerprint(&dependency_package)?
+                .hash(&mut hasher);
+        }
+        Some(ModuleCacheScope {
+            cache,
+            package,
+            mod_path: vec![],
+            fingerprint: hasher.finish(),
+        })
+    }
+
+    /// Records the fingerprint of the package of this root scope, which covers the sources of
+    /// all of its modules, for the packages depending on it.
+    pub(crate) fn record_package_fingerprint(&self, parsed: &ParseModule) {
+        let mut hasher = self.preceding_hasher();
+        module_tree_hash(parsed).hash(&mut hasher);
+        self.cache
+            .insert_package_fingerprint(self.package.clone(), hasher.finish());
+    }
+
+    /// The scope of a submodule of this module, type-checked after the modules whose
+    /// fingerprints were hashed into `preceding`.
+    pub(crate) fn submodule(
+        &self,
+        name: &str,
+        preceding: &DefaultHasher,
+        parsed: &ParseModule,
+    ) -> Self {
+        let mut hasher = preceding.clone();
+        module_tree_hash(parsed).hash(&mut hasher);
+        ModuleCacheScope {
+            cache: self.cache,
+            package: self.package.clone(),
+            mod_path: self
+                .mod_path
+                .iter()
+                .cloned()
+                .chain(std::iter::once(name.to_string()))
+                .collect(),
+            fingerprint: hasher.finish(),
+        }
+    }
+
+    /// A hasher of the fingerprints of the submodules type-checked so far, starting with the
+    /// fingerprint of this module.
+    pub(crate) fn preceding_hasher(&self) -> DefaultHasher {
+        let mut hasher = DefaultHasher::new();
+        self.fingerprint.hash(&mut hasher);
+        hasher
+    }
+
+    pub(crate) fn key(&self) -> TypedModuleCacheKey {
+        TypedModuleCacheKey {
+            package: self.package.clone(),
+            mod_path: self.mod_path.clone(),
+        }
+    }
+}
+
+/// The hash of the sources of the module and of all of its submodules.
+fn module_tree_hash(parsed: &ParseModule) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    parsed.hash.hash(&mut hasher);
+    for (name, submodule) in &parsed.submodules {
+        name.as_str().hash(&mut hasher);
+        module_tree_hash(&submodule.module).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use sway_error::handler::Handler;
+    use sway_types::{Ident, Span};
+
+    use super::*;
+    use crate::{
+        decl_engine::DeclId,
+        test_utils::{self, TempDir},
+    };
+
+    const DEP: &str = "library;\n\npub fn dep() -> u64 { 0 }\n";
+    const APP: &str = "library;\n\nmod foo;\n\npub fn app() -> u64 { 1 }\n";
+    const FOO: &str = "library;\n\npub fn foo() -> u64 { 2 }\n";
+
+    /// Writes the `dep` and `app` packages, `app` having the submodule `foo`.
+    fn write_packages(name: &str) -> TempDir {
+        TempDir::new(
+            &format!("typed-module-cache-{name}"),
+            &[
+                ("dep/src/lib.sw", DEP),
+                ("app/src/lib.sw", APP),
+                ("app/src/foo.sw", FOO),
+            ],
+        )
+    }
+
+    fn check(
+        engines: &Engines,
+        package_dir: &Path,
+        initial_namespace: namespace::Module,
+    ) -> ty::TyProgram {
+        let config = test_utils::build_config(package_dir, "src/lib.sw").with_incremental(true);
+        let handler = Handler::default();
+        let input = Arc::from(fs::read_to_string(package_dir.join("src/lib.sw")).unwrap());
+        let (_, parsed) = crate::parse(input, &handler, engines, Some(&config)).unwrap();
+        let typed = crate::parsed_to_ast(
+            &handler,
+            engines,
+            &parsed,
+            initial_namespace,
+            Some(&config),
+            "test",
+            None,
+        );
+        test_utils::assert_no_errors(handler);
+        typed.unwrap()
+    }
+
+    /// The namespace `app` is type-checked in, as built by `forc-pkg`.
+    fn app_namespace(engines: &Engines, dir: &Path, dep: &ty::TyProgram) -> namespace::Module {
+        let mut dep_namespace = dep.root.namespace.module().clone();
+        dep_namespace.name = Some(Ident::new_no_span("dep".to_string()));
+        dep_namespace.span = Span::new(
+            Arc::from(DEP),
+            0,
+            0,
+            Some(engines.se().get_source_id(&dir.join("dep/src/lib.sw"))),
+        );
+        let mut namespace = namespace::Module::default();
+        namespace.insert_submodule("dep".to_string(), dep_namespace);
+        namespace
+    }
+
+    fn foo_decl_id(app: &ty::TyProgram) -> DeclId<ty::TyFunctionDecl> {
+        let (_, foo) = &app.root.submodules[0];
+        foo.module
+            .all_nodes
+            .iter()
+            .find_map(|node| match &node.content {
+                ty::TyAstNodeContent::Declaration(ty::TyDecl::FunctionDecl(decl)) => {
+                    Some(decl.decl_id)
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    /// Type-checks `dep` then `app`, returning the declaration of `app`'s `foo`.
+    fn check_packages(engines: &Engines, dir: &Path) -> DeclId<ty::TyFunctionDecl> {
+        let dep = check(engines, &dir.join("dep"), namespace::Module::default());
+        let app = check(engines, &dir.join("app"), app_namespace(engines, dir, &dep));
+        foo_decl_id(&app)
+    }
+
+    #[test]
+    fn unchanged_packages_reuse_typed_submodules() {
+        let dir = write_packages("unchanged");
+        let engines = Engines::default();
+
+        let first = check_packages(&engines, dir.path());
+        let second = check_packages(&engines, dir.path());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn edited_dependency_invalidates_typed_submodules() {
+        let dir = write_packages("edited-dependency");
+        let engines = Engines::default();
+
+        let first = check_packages(&engines, dir.path());
+        fs::write(
+            dir.path().join("dep/src/lib.sw"),
+            format!("{DEP}\npub fn other_dep() -> u64 {{ 3 }}\n"),
+        )
+        .unwrap();
+        let second = check_packages(&engines, dir.path());
+        assert_ne!(first, second);
+    }
+}
@@ -610,7 +610,9 @@ fn effects_of_intrinsic(intr: &sway_ast::Intrinsic) -> HashSet<Effect> {
         Smo => HashSet::from([Effect::OutputMessage]),
         Revert | IsReferenceType | IsStrArray | SizeOfType | SizeOfVal | SizeOfStr
         | AssertIsStrArray | ToStrArray | Eq | Gt | Lt | Gtf | AddrOf | Log | Add | Sub | Mul
-        | Div | And | Or | Xor | Mod | Rsh | Lsh | PtrAdd | PtrSub | Not => HashSet::new(),
+        | Div | And | Or | Xor | Mod | Rsh | Lsh | PtrAdd | PtrSub | Not | TypeName | TypeId => {
+            HashSet::new()
+        }
     }
 }
 
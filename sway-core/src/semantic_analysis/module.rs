@@ -2,9 +2,11 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
     fs,
+    hash::Hash,
 };
 
 use graph_cycles::Cycles;
+use rayon::prelude::*;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
@@ -12,6 +14,7 @@ use sway_error::{
 use sway_types::{BaseIdent, Named};
 
 use crate::{
+    concurrent_slab::InsertionScope,
     decl_engine::DeclEngineGet,
     engine_threading::DebugWithEngines,
     language::{
@@ -19,7 +22,10 @@ use crate::{
         ty::{self, TyAstNodeContent, TyDecl},
         CallPath, ModName,
     },
-    semantic_analysis::*,
+    semantic_analysis::{
+        cache::{ModuleCacheScope, TypedModuleCacheEntry},
+        *,
+    },
     Engines, TypeInfo,
 };
 
@@ -67,6 +73,10 @@ pub struct ModuleDepGraph {
 
 pub type ModuleEvaluationOrder = Vec<ModName>;
 
+/// Batches of submodules in evaluation order. The submodules of a batch only depend on those of
+/// the preceding batches, so they may be type-checked in parallel.
+pub type ModuleEvaluationBatches = Vec<ModuleEvaluationOrder>;
+
 impl ModuleDepGraph {
     pub(crate) fn new() -> Self {
         Self {
@@ -184,6 +194,40 @@ impl ModuleDepGraph {
 
         Ok(sorted)
     }
+
+    /// Groups the submodules of the evaluation `order` into batches, placing each submodule in
+    /// the batch following the last one it depends on.
+    ///
+    /// If not `parallel`, each submodule is placed in a batch of its own instead, so that the
+    /// submodules are type-checked one at a time in the evaluation order.
+    pub(crate) fn compute_batches(
+        &self,
+        order: ModuleEvaluationOrder,
+        parallel: bool,
+    ) -> ModuleEvaluationBatches {
+        if !parallel {
+            return order.into_iter().map(|mod_name| vec![mod_name]).collect();
+        }
+
+        let mut batch_of = HashMap::<ModuleDepGraphNodeId, usize>::new();
+        let mut batches = ModuleEvaluationBatches::new();
+        for mod_name in order {
+            let node_id = self.get_node_id_for_module(&mod_name).unwrap();
+            let batch = self
+                .dep_graph
+                .neighbors(node_id)
+                .filter_map(|dep_node_id| batch_of.get(&dep_node_id))
+                .map(|dep_batch| dep_batch + 1)
+                .max()
+                .unwrap_or(0);
+            batch_of.insert(node_id, batch);
+            if batch == batches.len() {
+                batches.push(vec![]);
+            }
+            batches[batch].push(mod_name);
+        }
+        batches
+    }
 }
 
 impl ty::TyModule {
@@ -216,12 +260,15 @@ impl ty::TyModule {
 
     /// Type-check the given parsed module to produce a typed module.
     ///
-    /// Recursively type-checks submodules first.
-    pub fn type_check(
+    /// Recursively type-checks submodules first, reusing those of the `cache_scope` which haven't
+    /// changed. If `parallel`, the submodules of each batch are type-checked in parallel.
+    pub(crate) fn type_check(
         handler: &Handler,
         mut ctx: TypeCheckContext,
         parsed: &ParseModule,
-        module_eval_order: ModuleEvaluationOrder,
+        module_eval_order: ModuleEvaluationBatches,
+        cache_scope: Option<ModuleCacheScope>,
+        parallel: bool,
     ) -> Result<Self, ErrorEmitted> {
         let ParseModule {
             submodules,
@@ -233,19 +280,36 @@ impl ty::TyModule {
         } = parsed;
 
         // Type-check submodules first in order of evaluation previously computed by the dependency graph.
+        // The fingerprint of each submodule covers those of the submodules checked before it.
+        let mut preceding = cache_scope.as_ref().map(ModuleCacheScope::preceding_hasher);
         let submodules_res = module_eval_order
             .iter()
-            .map(|eval_mod_name| {
-                let (name, submodule) = submodules
+            .map(|batch| {
+                let batch = batch
                     .iter()
-                    .find(|(submod_name, _submodule)| eval_mod_name == submod_name)
-                    .unwrap();
-                Ok((
-                    name.clone(),
-                    ty::TySubmodule::type_check(handler, ctx.by_ref(), name.clone(), submodule)?,
-                ))
+                    .map(|eval_mod_name| {
+                        let (name, submodule) = submodules
+                            .iter()
+                            .find(|(submod_name, _submodule)| eval_mod_name == submod_name)
+                            .unwrap();
+                        let submod_cache_scope = cache_scope.as_ref().zip(preceding.as_mut()).map(
+                            |(cache_scope, preceding)| {
+                                let submod_cache_scope = cache_scope.submodule(
+                                    name.as_str(),
+                                    preceding,
+                                    &submodule.module,
+                                );
+                                submod_cache_scope.fingerprint.hash(preceding);
+                                submod_cache_scope
+                            },
+                        );
+                        (name.clone(), submodule, submod_cache_scope)
+                    })
+                    .collect::<Vec<_>>();
+                Self::type_check_submodules(handler, ctx.by_ref(), batch, parallel)
             })
-            .collect::<Result<Vec<_>, _>>();
+            .collect::<Result<Vec<_>, _>>()
+            .map(|batches| batches.into_iter().flatten().collect::<Vec<_>>());
 
         // TODO: Ordering should be solved across all modules prior to the beginning of type-check.
         let ordered_nodes_res = node_dependencies::order_ast_nodes_by_dependency(
@@ -268,6 +332,93 @@ impl ty::TyModule {
         })
     }
 
+    /// Type-check a batch of submodules which don't depend on each other, in parallel if
+    /// `parallel` and there's more than one of them.
+    ///
+    /// The submodules of the batches nested within a submodule type-checked in parallel are
+    /// type-checked one after another by the thread type-checking it.
+    fn type_check_submodules(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        batch: Vec<(ModName, &ParseSubmodule, Option<ModuleCacheScope>)>,
+        parallel: bool,
+    ) -> Result<Vec<(ModName, ty::TySubmodule)>, ErrorEmitted> {
+        if !parallel || batch.len() < 2 || InsertionScope::current_index().is_some() {
+            return batch
+                .into_iter()
+                .map(|(name, submodule, cache_scope)| {
+                    let typed_submodule = ty::TySubmodule::type_check(
+                        handler,
+                        ctx.by_ref(),
+                        name.clone(),
+                        submodule,
+                        cache_scope,
+                        parallel,
+                    )?;
+                    Ok((name, typed_submodule))
+                })
+                .collect();
+        }
+
+        // Each submodule is type-checked into its own copy of the namespace, with its own
+        // handler and within its own insertion scope of the engines. Their namespace modules and
+        // diagnostics are then merged back in evaluation order, and their types and declarations
+        // are given ids depending on their position in the batch, so that the result doesn't
+        // depend on the scheduling of the threads.
+        let engines = ctx.engines;
+        let namespace: &Namespace = ctx.namespace;
+        let results = batch
+            .into_par_iter()
+            .enumerate()
+            .map(|(index, (name, submodule, cache_scope))| {
+                let mut submod_namespace = namespace.clone();
+                let submod_handler = Handler::default();
+                let (typed_submodule_res, insertion_scope) = InsertionScope::run(index, || {
+                    ty::TySubmodule::type_check(
+                        &submod_handler,
+                        TypeCheckContext::from_module_namespace(&mut submod_namespace, engines),
+                        name.clone(),
+                        submodule,
+                        cache_scope,
+                        parallel,
+                    )
+                });
+                let submod_module = submod_namespace.submodules().get(name.as_str()).cloned();
+                (
+                    name,
+                    typed_submodule_res,
+                    submod_module,
+                    submod_handler.consume(),
+                    insertion_scope,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut typed_submodules = Vec::with_capacity(results.len());
+        let mut first_err = None;
+        for (name, typed_submodule_res, submod_module, (errors, warnings), insertion_scope) in
+            results
+        {
+            insertion_scope.commit();
+            handler.append(Handler::from_parts(errors, warnings));
+            if let Some(submod_module) = submod_module {
+                ctx.namespace
+                    .insert_submodule(name.to_string(), submod_module);
+            }
+            match typed_submodule_res {
+                Ok(typed_submodule) => typed_submodules.push((name, typed_submodule)),
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                }
+            }
+        }
+        engines.te().commit_insertion_scopes(engines);
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(typed_submodules),
+        }
+    }
+
     // Filter and gather impl items
     fn get_all_impls(
         ctx: TypeCheckContext<'_>,
@@ -389,11 +540,66 @@ impl ty::TySubmodule {
         Ok(())
     }
 
-    pub fn type_check(
+    /// Type-check the given parsed submodule, or reuse it from the `cache_scope` if neither it nor
+    /// the modules it may depend on have changed since it was cached.
+    pub(crate) fn type_check(
+        handler: &Handler,
+        parent_ctx: TypeCheckContext,
+        mod_name: ModName,
+        submodule: &ParseSubmodule,
+        cache_scope: Option<ModuleCacheScope>,
+        parallel: bool,
+    ) -> Result<Self, ErrorEmitted> {
+        let Some(cache_scope) = cache_scope else {
+            return Self::type_check_uncached(
+                handler, parent_ctx, mod_name, submodule, None, parallel,
+            );
+        };
+
+        let key = cache_scope.key();
+        if let Some(entry) = cache_scope.cache.get(&key, cache_scope.fingerprint) {
+            let (errors, warnings) = entry.handler_data;
+            handler.append(Handler::from_parts(errors, warnings));
+            parent_ctx
+                .namespace
+                .insert_submodule(mod_name.to_string(), entry.namespace);
+            return Ok(entry.submodule);
+        }
+
+        let submod_handler = Handler::default();
+        let fingerprint = cache_scope.fingerprint;
+        let cache = cache_scope.cache;
+        let submodule_res = Self::type_check_uncached(
+            &submod_handler,
+            parent_ctx,
+            mod_name,
+            submodule,
+            Some(cache_scope),
+            parallel,
+        );
+        let (errors, warnings) = submod_handler.consume();
+        if let (Ok(submodule), true) = (&submodule_res, errors.is_empty()) {
+            cache.insert(
+                key,
+                TypedModuleCacheEntry {
+                    fingerprint,
+                    submodule: submodule.clone(),
+                    namespace: submodule.module.namespace.module().clone(),
+                    handler_data: (errors.clone(), warnings.clone()),
+                },
+            );
+        }
+        handler.append(Handler::from_parts(errors, warnings));
+        submodule_res
+    }
+
+    fn type_check_uncached(
         handler: &Handler,
         parent_ctx: TypeCheckContext,
         mod_name: ModName,
         submodule: &ParseSubmodule,
+        cache_scope: Option<ModuleCacheScope>,
+        parallel: bool,
     ) -> Result<Self, ErrorEmitted> {
         let ParseSubmodule {
             module,
@@ -402,9 +608,16 @@ impl ty::TySubmodule {
         } = submodule;
         let modules_dep_graph = ty::TyModule::analyze(handler, module)?;
         let module_eval_order = modules_dep_graph.compute_order(handler)?;
+        let module_eval_order = modules_dep_graph.compute_batches(module_eval_order, parallel);
         parent_ctx.enter_submodule(mod_name, *visibility, module.span.clone(), |submod_ctx| {
-            let module_res =
-                ty::TyModule::type_check(handler, submod_ctx, module, module_eval_order);
+            let module_res = ty::TyModule::type_check(
+                handler,
+                submod_ctx,
+                module,
+                module_eval_order,
+                cache_scope,
+                parallel,
+            );
             module_res.map(|module| ty::TySubmodule {
                 module,
                 mod_name_span: mod_name_span.clone(),
@@ -412,3 +625,128 @@ impl ty::TySubmodule {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use sway_types::Ident;
+
+    use super::*;
+    use crate::{
+        language::ty::TyModule,
+        test_utils::{self, TempDir},
+        BuildConfig,
+    };
+
+    /// The diagnostics, the declarations with their types, and the bytecode of a compilation.
+    #[derive(Debug, PartialEq)]
+    struct Compilation {
+        diagnostics: Vec<String>,
+        declarations: Vec<String>,
+        bytecode: Vec<u8>,
+    }
+
+    fn build_config(manifest_dir: &Path, root_module: &str) -> BuildConfig {
+        test_utils::build_config(manifest_dir, root_module).with_parallel_type_check(true)
+    }
+
+    fn collect_declarations(engines: &Engines, module: &TyModule, declarations: &mut Vec<String>) {
+        for (_, submodule) in &module.submodules {
+            collect_declarations(engines, &submodule.module, declarations);
+        }
+        for node in &module.all_nodes {
+            if let TyAstNodeContent::Declaration(decl) = &node.content {
+                declarations.push(format!("{decl:?}"));
+                if let TyDecl::FunctionDecl(fn_decl) = decl {
+                    let fn_decl = engines.de().get_function(&fn_decl.decl_id);
+                    let parameters = fn_decl
+                        .parameters
+                        .iter()
+                        .map(|param| param.type_argument.type_id)
+                        .collect::<Vec<_>>();
+                    declarations.push(format!(
+                        "{parameters:?} -> {:?}",
+                        fn_decl.return_type.type_id
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Type-checks `sway-lib-core`, then compiles the script in `script_dir` against it, using a
+    /// pool of `num_threads` threads.
+    fn compile(script_dir: &Path, num_threads: usize) -> Compilation {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            let engines = Engines::default();
+
+            let core_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../sway-lib-core");
+            let core = test_utils::compile_to_ast(
+                &engines,
+                &fs::read_to_string(core_dir.join("src/lib.sw")).unwrap(),
+                namespace::Module::default(),
+                Some(&build_config(&core_dir, "src/lib.sw")),
+            );
+            let mut declarations = vec![];
+            collect_declarations(&engines, &core.root, &mut declarations);
+
+            let mut core_namespace = core.root.namespace.module().clone();
+            core_namespace.name = Some(Ident::new_no_span("core".to_string()));
+            let mut script_namespace = namespace::Module::default();
+            script_namespace.insert_submodule("core".to_string(), core_namespace);
+            script_namespace
+                .star_import_with_reexports(
+                    &Handler::default(),
+                    &["core", "prelude"].map(|s| Ident::new_no_span(s.into())),
+                    &[],
+                    &engines,
+                    true,
+                )
+                .unwrap();
+
+            let (bytecode, warnings) = test_utils::compile_to_bytecode(
+                &engines,
+                &fs::read_to_string(script_dir.join("src/main.sw")).unwrap(),
+                script_namespace,
+                build_config(script_dir, "src/main.sw"),
+            );
+            Compilation {
+                diagnostics: warnings
+                    .iter()
+                    .map(|warning| format!("{warning:?}"))
+                    .collect(),
+                declarations,
+                bytecode,
+            }
+        })
+    }
+
+    #[test]
+    fn parallel_type_check_matches_serial_type_check() {
+        let script_dir = TempDir::new(
+            "parallel-type-check",
+            &[
+                (
+                    "src/main.sw",
+                    "script;\n\nmod a;\nmod b;\nmod c;\n\nfn main() -> u64 {\n    a::a() + b::b() + c::c()\n}\n",
+                ),
+                ("src/a.sw", "library;\n\npub fn a() -> u64 {\n    1\n}\n"),
+                ("src/b.sw", "library;\n\npub fn b() -> u64 {\n    2 * 3\n}\n"),
+                (
+                    "src/c.sw",
+                    "library;\n\npub struct C {\n    x: u64,\n}\n\npub fn c() -> u64 {\n    let c = C { x: 4 };\n    c.x\n}\n",
+                ),
+            ],
+        );
+
+        let serial = compile(script_dir.path(), 1);
+        let parallel = compile(script_dir.path(), 4);
+        assert!(!serial.declarations.is_empty());
+        assert!(!serial.bytecode.is_empty());
+        assert_eq!(serial, parallel);
+    }
+}
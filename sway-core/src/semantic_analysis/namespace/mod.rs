@@ -7,13 +7,14 @@ mod submodule_namespace;
 mod trait_map;
 
 pub use items::Items;
-pub use module::Module;
+pub use module::{ExportedItem, Module};
 pub use namespace::Namespace;
 pub use namespace::TryInsertingTraitImplOnFailure;
 pub use root::Root;
 pub(super) use trait_map::IsExtendingExistingImpl;
 pub(super) use trait_map::IsImplSelf;
 pub(super) use trait_map::TraitMap;
+pub(super) use trait_map::VisibleMarkerTraits;
 
 use sway_types::Ident;
 
@@ -1,5 +1,5 @@
 use crate::{
-    decl_engine::DeclRef,
+    decl_engine::{DeclEngine, DeclRef},
     engine_threading::Engines,
     language::{
         parsed::*,
@@ -62,6 +62,35 @@ pub struct Module {
     pub mod_path: PathBuf,
 }
 
+/// A public item that can be referred to through a module, either because the module declares
+/// it or because the module imports it.
+#[derive(Clone, Debug)]
+pub struct ExportedItem {
+    /// The name of the item within the module, which is the alias of aliased imports.
+    pub name: Ident,
+    pub decl: TyDecl,
+    /// The paths of the modules the item is imported through, starting with the module it can
+    /// be referred to through and ending with the module declaring it. Never empty.
+    pub reexport_chain: Vec<PathBuf>,
+}
+
+impl ExportedItem {
+    /// The path of the module the item can be referred to through.
+    pub fn path(&self) -> &Path {
+        &self.reexport_chain[0]
+    }
+
+    /// The path of the module declaring the item, which is the path it should be imported from.
+    pub fn canonical_path(&self) -> &Path {
+        &self.reexport_chain[self.reexport_chain.len() - 1]
+    }
+
+    /// Returns true if the item is imported into the module rather than declared by it.
+    pub fn is_reexport(&self) -> bool {
+        self.reexport_chain.len() > 1
+    }
+}
+
 impl Default for Module {
     fn default() -> Self {
         Self {
@@ -633,6 +662,110 @@ impl Module {
         Ok(())
     }
 
+    /// Returns the public items declared by this module and all of its submodules, along with
+    /// the items they import. The paths of the items are relative to this module.
+    ///
+    /// Imported items are included as re-exports, since the modules importing them can be glob
+    /// imported along with their imports, as it is done with the preludes. Only the dependencies
+    /// of the root module are visited, as the dependencies of dependencies can not be imported.
+    pub fn exported_items(&self, decl_engine: &DeclEngine) -> Vec<ExportedItem> {
+        let mut items = vec![];
+        self.collect_exported_items(self, decl_engine, &[], &mut items);
+        items
+    }
+
+    fn collect_exported_items(
+        &self,
+        root: &Module,
+        decl_engine: &DeclEngine,
+        path: &Path,
+        items: &mut Vec<ExportedItem>,
+    ) {
+        for (name, decl) in self.symbols.iter() {
+            if decl.visibility(decl_engine).is_public() {
+                items.push(ExportedItem {
+                    name: name.clone(),
+                    decl: decl.clone(),
+                    reexport_chain: vec![path.to_vec()],
+                });
+            }
+        }
+        for (name, (_, _, decl, _)) in self.use_synonyms.iter() {
+            if decl.visibility(decl_engine).is_public() {
+                items.push(ExportedItem {
+                    name: name.clone(),
+                    decl: decl.clone(),
+                    reexport_chain: root.reexport_chain(path, name),
+                });
+            }
+        }
+        for (submodule_name, submodule) in self.submodules.iter() {
+            if !path.is_empty() && submodule.is_external {
+                continue;
+            }
+            let mut submodule_path = path.to_vec();
+            submodule_path.push(Ident::new_no_span(submodule_name.clone()));
+            submodule.collect_exported_items(root, decl_engine, &submodule_path, items);
+        }
+    }
+
+    /// Follows the imports of the item `name` starting from the module at `path`, returning the
+    /// paths of the modules the item is imported through, ending with the module declaring it.
+    fn reexport_chain(&self, path: &Path, name: &Ident) -> Vec<PathBuf> {
+        let mut chain: Vec<PathBuf> = vec![];
+        let mut next = Some((path.to_vec(), name.clone()));
+        while let Some((path, name)) = next.take() {
+            if chain.contains(&path) {
+                break;
+            }
+            if let Some(module) = self.submodule(&path) {
+                if !module.symbols.contains_key(&name) {
+                    if let Some((src, _, _, _)) = module.use_synonyms.get(&name) {
+                        let original_name = module
+                            .use_aliases
+                            .get(name.as_str())
+                            .cloned()
+                            .unwrap_or(name);
+                        next = Some((self.resolve_import_path(&path, src), original_name));
+                    }
+                }
+            }
+            chain.push(path);
+        }
+        chain
+    }
+
+    /// Returns the path, relative to this module, of the module that the module at `path`
+    /// imports from with the path `src`. Import paths are relative to the root module of the
+    /// package they are in, while the dependencies of all packages are found in this module.
+    fn resolve_import_path(&self, path: &Path, src: &Path) -> PathBuf {
+        let mut package_path_len = 0;
+        let mut module = self;
+        for (idx, ident) in path.iter().enumerate() {
+            match module.submodules.get(ident.as_str()) {
+                Some(submodule) => {
+                    module = submodule;
+                    if submodule.is_external {
+                        package_path_len = idx + 1;
+                    }
+                }
+                None => break,
+            }
+        }
+        let package_path = &path[..package_path_len];
+        let is_dependency = |ident: &Ident| {
+            self.submodule(package_path)
+                .and_then(|package| package.submodules.get(ident.as_str()))
+                .map_or(false, |submodule| submodule.is_external)
+        };
+        match src.first() {
+            Some(first) if !package_path.is_empty() && !is_dependency(first) => {
+                [package_path, src].concat()
+            }
+            _ => src.to_vec(),
+        }
+    }
+
     fn check_module_privacy(
         &self,
         handler: &Handler,
@@ -711,3 +844,171 @@ fn module_not_found(path: &[Ident]) -> CompileError {
 fn is_ancestor(src: &Path, dst: &Path) -> bool {
     dst.len() >= src.len() && src.iter().zip(dst).all(|(src, dst)| src == dst)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the struct declarations of a library declaring the public structs `Token` and
+    /// `Item` and the private struct `Secret`.
+    fn struct_decls(engines: &Engines) -> Vec<TyDecl> {
+        crate::test_utils::compile_to_ast(
+            engines,
+            "library; pub struct Token {} pub struct Item {} struct Secret {}",
+            Module::default(),
+            None,
+        )
+        .declarations
+    }
+
+    fn decl(decls: &[TyDecl], name: &str) -> TyDecl {
+        decls
+            .iter()
+            .find(|decl| matches!(decl, TyDecl::StructDecl(decl) if decl.name.as_str() == name))
+            .cloned()
+            .unwrap()
+    }
+
+    fn ident(name: &str) -> Ident {
+        Ident::new_no_span(name.to_string())
+    }
+
+    fn path(path: &str) -> PathBuf {
+        path.split("::").map(ident).collect()
+    }
+
+    fn submodule(name: &str, is_external: bool) -> Module {
+        Module {
+            name: Some(ident(name)),
+            visibility: Visibility::Public,
+            is_external,
+            ..Default::default()
+        }
+    }
+
+    fn import(module: &mut Module, name: &str, src: &str, glob: GlobImport, decl: TyDecl) {
+        module
+            .use_synonyms
+            .insert(ident(name), (path(src), glob, decl, true));
+    }
+
+    /// Returns the names of the exported items along with their re-export chains, sorted.
+    fn exported(module: &Module, engines: &Engines) -> Vec<(String, Vec<String>)> {
+        let mut items = module
+            .exported_items(engines.de())
+            .into_iter()
+            .map(|item| {
+                let chain = item
+                    .reexport_chain
+                    .iter()
+                    .map(|path| {
+                        path.iter()
+                            .map(|ident| ident.as_str())
+                            .collect::<Vec<_>>()
+                            .join("::")
+                    })
+                    .collect();
+                (item.name.to_string(), chain)
+            })
+            .collect::<Vec<_>>();
+        items.sort();
+        items
+    }
+
+    fn item(name: &str, chain: &[&str]) -> (String, Vec<String>) {
+        (
+            name.to_string(),
+            chain.iter().map(|path| path.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn glob_reexports() {
+        let engines = Engines::default();
+        let decls = struct_decls(&engines);
+
+        let mut a = submodule("a", false);
+        for name in ["Token", "Secret"] {
+            a.symbols.insert(ident(name), decl(&decls, name));
+        }
+        let mut b = submodule("b", false);
+        import(&mut b, "Token", "a", GlobImport::Yes, decl(&decls, "Token"));
+        let mut root = Module::default();
+        root.insert_submodule("a".to_string(), a);
+        root.insert_submodule("b".to_string(), b);
+
+        // Private items are neither exported by the module declaring them nor re-exported.
+        assert_eq!(
+            exported(&root, &engines),
+            vec![item("Token", &["a"]), item("Token", &["b", "a"])]
+        );
+
+        let items = root.exported_items(engines.de());
+        let reexport = items.iter().find(|item| item.is_reexport()).unwrap();
+        assert_eq!(reexport.path(), path("b").as_slice());
+        assert_eq!(reexport.canonical_path(), path("a").as_slice());
+    }
+
+    #[test]
+    fn item_reexports_are_chained() {
+        let engines = Engines::default();
+        let decls = struct_decls(&engines);
+        let token = decl(&decls, "Token");
+
+        let mut a = submodule("a", false);
+        a.symbols.insert(ident("Token"), token.clone());
+        let mut b = submodule("b", false);
+        import(&mut b, "Token", "a", GlobImport::No, token.clone());
+        // `use ::b::Token as Coin;` is followed through `b` under the original name.
+        let mut c = submodule("c", false);
+        import(&mut c, "Coin", "b", GlobImport::No, token);
+        c.use_aliases.insert("Coin".to_string(), ident("Token"));
+        let mut root = Module::default();
+        root.insert_submodule("a".to_string(), a);
+        root.insert_submodule("b".to_string(), b);
+        root.insert_submodule("c".to_string(), c);
+
+        assert_eq!(
+            exported(&root, &engines),
+            vec![
+                item("Coin", &["c", "b", "a"]),
+                item("Token", &["a"]),
+                item("Token", &["b", "a"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn dependency_reexports() {
+        let engines = Engines::default();
+        let decls = struct_decls(&engines);
+        let token = decl(&decls, "Token");
+        let item_decl = decl(&decls, "Item");
+
+        let mut core = submodule("core", true);
+        core.symbols.insert(ident("Token"), token.clone());
+        let mut inner = submodule("inner", false);
+        inner.symbols.insert(ident("Item"), item_decl.clone());
+        // The imports of a dependency are relative to its own root module, except for the
+        // imports from its own dependencies, which are found in the root module.
+        let mut dep = submodule("dep", true);
+        import(&mut dep, "Item", "inner", GlobImport::No, item_decl);
+        import(&mut dep, "Token", "core", GlobImport::Yes, token);
+        dep.insert_submodule("inner".to_string(), inner);
+        dep.insert_submodule("core".to_string(), core.clone());
+        let mut root = Module::default();
+        root.insert_submodule("core".to_string(), core);
+        root.insert_submodule("dep".to_string(), dep);
+
+        // The dependencies of `dep` are not visited through it.
+        assert_eq!(
+            exported(&root, &engines),
+            vec![
+                item("Item", &["dep", "dep::inner"]),
+                item("Item", &["dep::inner"]),
+                item("Token", &["core"]),
+                item("Token", &["dep", "core"]),
+            ]
+        );
+    }
+}
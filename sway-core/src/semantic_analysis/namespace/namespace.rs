@@ -1,9 +1,13 @@
 use crate::{
+    decl_engine::DeclEngineGet,
     language::{ty, CallPath, Visibility},
     Engines, Ident, TypeId,
 };
 
-use super::{module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path, PathBuf};
+use super::{
+    module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path, PathBuf,
+    VisibleMarkerTraits,
+};
 
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::span::Span;
@@ -115,6 +119,18 @@ impl Namespace {
             .resolve_call_path(handler, engines, &self.mod_path, call_path, self_type)
     }
 
+    /// Returns the traits of `core::marker` this namespace refers to by their names.
+    pub(crate) fn visible_marker_traits(&self, engines: &Engines) -> VisibleMarkerTraits {
+        VisibleMarkerTraits::new(|name| {
+            match self.resolve_symbol(&Handler::default(), engines, name, None) {
+                Ok(ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. })) => {
+                    Some(engines.de().get_trait(&decl_id).call_path.clone())
+                }
+                _ => None,
+            }
+        })
+    }
+
     /// "Enter" the submodule at the given path by returning a new [SubmoduleNamespace].
     ///
     /// Here we temporarily change `mod_path` to the given `dep_mod_path` and wrap `self` in a
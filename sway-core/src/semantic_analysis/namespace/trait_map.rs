@@ -995,6 +995,7 @@ impl TraitMap {
         handler: &Handler,
         type_id: TypeId,
         constraints: &[TraitConstraint],
+        marker_traits: VisibleMarkerTraits,
         access_span: &Span,
         engines: &Engines,
         try_inserting_trait_impl_on_failure: TryInsertingTraitImplOnFailure,
@@ -1033,6 +1034,8 @@ impl TraitMap {
             })
             .collect();
 
+        // The constraints of marker traits, which are checked by the compiler.
+        let mut marker_constraints = HashMap::<Ident, MarkerTrait>::new();
         let required_traits: BTreeSet<(Ident, TypeId)> = constraints
             .iter()
             .map(|c| {
@@ -1053,6 +1056,11 @@ impl TraitMap {
                     },
                     constraint_trait_name.span().source_id(),
                 );
+                if let Some(marker_trait) =
+                    MarkerTrait::from_trait_name(constraint_trait_name, marker_traits)
+                {
+                    marker_constraints.insert(constraint_trait_name.suffix.clone(), marker_trait);
+                }
                 (c.trait_name.suffix.clone(), constraint_type_id)
             })
             .collect();
@@ -1060,6 +1068,9 @@ impl TraitMap {
         let traits_not_found: BTreeSet<(BaseIdent, TypeId)> = required_traits
             .into_iter()
             .filter(|(required_trait_name, required_trait_type_id)| {
+                if let Some(marker_trait) = marker_constraints.get(required_trait_name) {
+                    return !marker_trait.is_implemented_for(engines, type_id, marker_traits);
+                }
                 !all_impld_traits
                     .iter()
                     .any(|(trait_name, constraint_type_id)| {
@@ -1080,6 +1091,7 @@ impl TraitMap {
                         handler,
                         type_id,
                         constraints,
+                        marker_traits,
                         access_span,
                         engines,
                         TryInsertingTraitImplOnFailure::No,
@@ -1099,6 +1111,7 @@ impl TraitMap {
                         ty: engines.help_out(type_id).to_string(),
                         trait_name: format!("{}{}", trait_name, type_arguments_string),
                         span: access_span.clone(),
+                        instantiations: vec![],
                     });
                 }
             }
@@ -1106,3 +1119,135 @@ impl TraitMap {
         })
     }
 }
+
+/// The traits of `core::marker` that a namespace refers to by their names, i.e. those that are
+/// not shadowed by other traits of the same names.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct VisibleMarkerTraits {
+    fixed_size: bool,
+    encodable: bool,
+}
+
+impl VisibleMarkerTraits {
+    /// Finds the visible marker traits, given a function resolving a trait name to the full call
+    /// path of the trait it refers to.
+    pub(crate) fn new(resolve: impl Fn(&Ident) -> Option<CallPath>) -> Self {
+        let is_visible = |marker_trait: MarkerTrait| {
+            resolve(&Ident::new_no_span(marker_trait.name().to_string()))
+                .is_some_and(|call_path| marker_trait.is_call_path(&call_path))
+        };
+        VisibleMarkerTraits {
+            fixed_size: is_visible(MarkerTrait::FixedSize),
+            encodable: is_visible(MarkerTrait::Encodable),
+        }
+    }
+
+    fn contains(self, marker_trait: MarkerTrait) -> bool {
+        match marker_trait {
+            MarkerTrait::FixedSize => self.fixed_size,
+            MarkerTrait::Encodable => self.encodable,
+        }
+    }
+}
+
+/// The traits of `core::marker`, which the compiler implements for all the types with a given
+/// property instead of looking up their `impl` blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MarkerTrait {
+    FixedSize,
+    Encodable,
+}
+
+impl MarkerTrait {
+    fn name(self) -> &'static str {
+        match self {
+            MarkerTrait::FixedSize => "FixedSize",
+            MarkerTrait::Encodable => "Encodable",
+        }
+    }
+
+    /// Whether `call_path` is the full call path of this trait, `core::marker::<name>`.
+    fn is_call_path(self, call_path: &CallPath) -> bool {
+        call_path.suffix.as_str() == self.name()
+            && call_path
+                .prefixes
+                .iter()
+                .map(Ident::as_str)
+                .eq(["core", "marker"])
+    }
+
+    /// Returns the marker trait `trait_name` refers to, if any. A name without prefixes refers to
+    /// a marker trait only if the marker trait is visible under it.
+    fn from_trait_name(trait_name: &CallPath, visible: VisibleMarkerTraits) -> Option<Self> {
+        [MarkerTrait::FixedSize, MarkerTrait::Encodable]
+            .into_iter()
+            .find(|marker_trait| {
+                if trait_name.prefixes.is_empty() {
+                    trait_name.suffix.as_str() == marker_trait.name()
+                        && visible.contains(*marker_trait)
+                } else {
+                    marker_trait.is_call_path(trait_name)
+                }
+            })
+    }
+
+    fn is_implemented_for(
+        self,
+        engines: &Engines,
+        type_id: TypeId,
+        visible: VisibleMarkerTraits,
+    ) -> bool {
+        let decl_engine = engines.de();
+        match &*engines.te().get(type_id) {
+            // Generic types implement the marker traits they are constrained by.
+            TypeInfo::UnknownGeneric {
+                trait_constraints, ..
+            } => trait_constraints.iter().any(|constraint| {
+                Self::from_trait_name(&constraint.trait_name, visible) == Some(self)
+            }),
+            TypeInfo::Struct(decl_ref) => {
+                decl_engine.get_struct(decl_ref).fields.iter().all(|field| {
+                    self.is_implemented_for(engines, field.type_argument.type_id, visible)
+                })
+            }
+            TypeInfo::Enum(decl_ref) => {
+                decl_engine
+                    .get_enum(decl_ref)
+                    .variants
+                    .iter()
+                    .all(|variant| {
+                        self.is_implemented_for(engines, variant.type_argument.type_id, visible)
+                    })
+            }
+            TypeInfo::Tuple(fields) => fields
+                .iter()
+                .all(|field| self.is_implemented_for(engines, field.type_id, visible)),
+            TypeInfo::Array(elem_type, _) | TypeInfo::Alias { ty: elem_type, .. } => {
+                self.is_implemented_for(engines, elem_type.type_id, visible)
+            }
+            TypeInfo::StringSlice | TypeInfo::RawUntypedSlice => self == MarkerTrait::Encodable,
+            TypeInfo::Slice(elem_type) => {
+                self == MarkerTrait::Encodable
+                    && self.is_implemented_for(engines, elem_type.type_id, visible)
+            }
+            TypeInfo::RawUntypedPtr
+            | TypeInfo::Ptr(_)
+            | TypeInfo::Ref(_)
+            | TypeInfo::ContractCaller { .. }
+            | TypeInfo::Contract
+            | TypeInfo::Storage { .. }
+            | TypeInfo::Unknown
+            | TypeInfo::Placeholder(_)
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Custom { .. }
+            | TypeInfo::TraitType { .. } => false,
+            TypeInfo::StringArray(_)
+            | TypeInfo::UnsignedInteger(_)
+            | TypeInfo::Boolean
+            | TypeInfo::Never
+            | TypeInfo::B256
+            | TypeInfo::Numeric
+            | TypeInfo::ErrorRecovery(_) => true,
+        }
+    }
+}
@@ -450,18 +450,26 @@ impl Dependencies {
 
     fn gather_from_fn_decl(self, engines: &Engines, fn_decl: &FunctionDeclaration) -> Self {
         let FunctionDeclaration {
+            name,
             parameters,
             return_type,
             body,
             type_parameters,
             ..
         } = fn_decl;
-        self.gather_from_iter(parameters.iter(), |deps, param| {
-            deps.gather_from_type_argument(engines, &param.type_argument)
-        })
-        .gather_from_type_argument(engines, return_type)
-        .gather_from_block(engines, body)
-        .gather_from_type_parameters(type_parameters)
+        let mut deps = self
+            .gather_from_iter(parameters.iter(), |deps, param| {
+                deps.gather_from_type_argument(engines, &param.type_argument)
+            })
+            .gather_from_type_argument(engines, return_type)
+            .gather_from_block(engines, body)
+            .gather_from_type_parameters(type_parameters);
+        // Non-generic functions marked `#[tail_call]` may call themselves, as their recursive
+        // calls are turned into loops.
+        if fn_decl.is_tail_call() && type_parameters.is_empty() {
+            deps.deps.remove(&DependentSymbol::Fn(name.clone(), None));
+        }
+        deps
     }
 
     fn gather_from_expr(self, engines: &Engines, expr: &Expression) -> Self {
@@ -622,12 +630,16 @@ impl Dependencies {
 
     fn gather_from_match_branch(self, engines: &Engines, branch: &MatchBranch) -> Self {
         let MatchBranch {
-            scrutinee, result, ..
+            scrutinee,
+            guard,
+            result,
+            ..
         } = branch;
         self.gather_from_iter(
             scrutinee.gather_approximate_typeinfo_dependencies().iter(),
             |deps, type_info| deps.gather_from_typeinfo(engines, type_info),
         )
+        .gather_from_opt_expr(engines, guard.as_ref())
         .gather_from_expr(engines, result)
     }
 
@@ -883,6 +895,7 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::V256 => "uint256",
         },
         TypeInfo::Boolean => "bool",
+        TypeInfo::Never => "never",
         TypeInfo::Custom {
             qualified_call_path: name,
             ..
@@ -0,0 +1,218 @@
+//! Checks the types inferred for untyped numeric literals.
+//!
+//! A numeric literal without a suffix, like `42`, takes the type that its uses infer for it and
+//! defaults to `u64` if nothing does. Once the whole program is type checked, this reports
+//! literals whose value does not fit in the inferred type, which would otherwise be silently
+//! truncated, and warns about hexadecimal and binary literals that are written with the width of
+//! a narrower type, like `0xff`, but defaulted to `u64`.
+
+use crate::{
+    language::{
+        ty::{self, TyDecl, TyTraitItem},
+        Literal,
+    },
+    Engines, TypeId, TypeInfo,
+};
+use sway_error::{
+    error::CompileError,
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{integer_bits::IntegerBits, Span};
+
+pub(crate) fn analyze_program(handler: &Handler, engines: &Engines, prog: &ty::TyProgram) {
+    analyze_module(handler, engines, &prog.root);
+}
+
+fn analyze_module(handler: &Handler, engines: &Engines, module: &ty::TyModule) {
+    for (_, submodule) in module.submodules.iter() {
+        analyze_module(handler, engines, &submodule.module);
+    }
+    for node in module.all_nodes.iter() {
+        analyze_node(handler, engines, node);
+    }
+}
+
+fn analyze_code_block(handler: &Handler, engines: &Engines, code_block: &ty::TyCodeBlock) {
+    for node in code_block.contents.iter() {
+        analyze_node(handler, engines, node);
+    }
+}
+
+fn analyze_node(handler: &Handler, engines: &Engines, node: &ty::TyAstNode) {
+    let decl_engine = engines.de();
+    match &node.content {
+        ty::TyAstNodeContent::Declaration(decl) => match decl {
+            TyDecl::VariableDecl(decl) => analyze_expr(handler, engines, &decl.body),
+            TyDecl::ConstantDecl(decl) => {
+                if let Some(value) = &decl_engine.get_constant(&decl.decl_id).value {
+                    analyze_expr(handler, engines, value);
+                }
+            }
+            TyDecl::FunctionDecl(decl) => {
+                analyze_code_block(
+                    handler,
+                    engines,
+                    &decl_engine.get_function(&decl.decl_id).body,
+                );
+            }
+            TyDecl::ImplTrait(decl) => {
+                for item in decl_engine.get_impl_trait(&decl.decl_id).items.iter() {
+                    match item {
+                        TyTraitItem::Fn(decl_ref) => analyze_code_block(
+                            handler,
+                            engines,
+                            &decl_engine.get_function(decl_ref).body,
+                        ),
+                        TyTraitItem::Constant(decl_ref) => {
+                            if let Some(value) = &decl_engine.get_constant(decl_ref).value {
+                                analyze_expr(handler, engines, value);
+                            }
+                        }
+                        TyTraitItem::Type(_) => {}
+                    }
+                }
+            }
+            _ => {}
+        },
+        ty::TyAstNodeContent::Expression(expr)
+        | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => {
+            analyze_expr(handler, engines, expr)
+        }
+        ty::TyAstNodeContent::SideEffect(_) | ty::TyAstNodeContent::Error(_, _) => {}
+    }
+}
+
+fn analyze_expr(handler: &Handler, engines: &Engines, expr: &ty::TyExpression) {
+    let analyze = |expr: &ty::TyExpression| analyze_expr(handler, engines, expr);
+    match &expr.expression {
+        ty::TyExpressionVariant::Literal(Literal::Numeric(value)) => {
+            check_numeric_literal(handler, engines, *value, expr.return_type, &expr.span)
+        }
+        ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            contract_call_params,
+            ..
+        } => {
+            arguments.iter().for_each(|(_, arg)| analyze(arg));
+            contract_call_params.values().for_each(analyze);
+        }
+        ty::TyExpressionVariant::LazyOperator { lhs, rhs, .. }
+        | ty::TyExpressionVariant::ArrayIndex {
+            prefix: lhs,
+            index: rhs,
+        } => {
+            analyze(lhs);
+            analyze(rhs);
+        }
+        ty::TyExpressionVariant::Tuple { fields } => fields.iter().for_each(analyze),
+        ty::TyExpressionVariant::Array { contents, .. } => contents.iter().for_each(analyze),
+        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+            fields.iter().for_each(|field| analyze(&field.value))
+        }
+        ty::TyExpressionVariant::CodeBlock(code_block) => {
+            analyze_code_block(handler, engines, code_block)
+        }
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => analyze(desugared),
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            analyze(condition);
+            analyze(then);
+            r#else.iter().for_each(|r#else| analyze(r#else));
+        }
+        ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix, .. } => analyze(prefix),
+        ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
+            contents.iter().for_each(|contents| analyze(contents))
+        }
+        ty::TyExpressionVariant::AbiCast { address, .. } => analyze(address),
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => kind.arguments.iter().for_each(analyze),
+        ty::TyExpressionVariant::EnumTag { exp }
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Return(exp)
+        | ty::TyExpressionVariant::Ref(exp)
+        | ty::TyExpressionVariant::Deref(exp) => analyze(exp),
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            analyze(condition);
+            analyze_code_block(handler, engines, body);
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            for lhs_index in reassignment.lhs_indices.iter() {
+                if let ty::ProjectionKind::ArrayIndex { index, .. } = lhs_index {
+                    analyze(index);
+                }
+            }
+            analyze(&reassignment.rhs);
+        }
+        // Registers hold whole words, so literals in their initializers are always `u64`.
+        ty::TyExpressionVariant::AsmExpression { .. }
+        | ty::TyExpressionVariant::Literal(_)
+        | ty::TyExpressionVariant::ConstantExpression { .. }
+        | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::FunctionParameter
+        | ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AbiName(_)
+        | ty::TyExpressionVariant::Break
+        | ty::TyExpressionVariant::Continue => {}
+    }
+}
+
+fn check_numeric_literal(
+    handler: &Handler,
+    engines: &Engines,
+    value: u64,
+    type_id: TypeId,
+    span: &Span,
+) {
+    match &*engines.te().get(type_id) {
+        TypeInfo::UnsignedInteger(bits) if value > max_value(bits) => {
+            handler.emit_err(CompileError::InferredIntegerTooLarge {
+                value,
+                ty: engines.help_out(type_id).to_string(),
+                span: span.clone(),
+            });
+        }
+        TypeInfo::Numeric => {
+            if let Some(written_type) = written_type(span.as_str()) {
+                handler.emit_warn(CompileWarning {
+                    span: span.clone(),
+                    warning_content: Warning::NumericLiteralDefaultedToU64 {
+                        literal: span.as_str().to_string(),
+                        written_type: written_type.to_string(),
+                    },
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn max_value(bits: &IntegerBits) -> u64 {
+    match bits {
+        IntegerBits::Eight => u8::MAX as u64,
+        IntegerBits::Sixteen => u16::MAX as u64,
+        IntegerBits::ThirtyTwo => u32::MAX as u64,
+        IntegerBits::SixtyFour | IntegerBits::V256 => u64::MAX,
+    }
+}
+
+/// Returns the type whose width a hexadecimal or binary literal is written with, if it is
+/// narrower than `u64`. For example, `0xff` and `0b0000_1111` are written as `u8` values.
+fn written_type(literal: &str) -> Option<&'static str> {
+    let (digits, bits_per_digit) = if let Some(digits) = literal.strip_prefix("0x") {
+        (digits, 4)
+    } else if let Some(digits) = literal.strip_prefix("0b") {
+        (digits, 1)
+    } else {
+        return None;
+    };
+    match digits.chars().filter(|c| *c != '_').count() * bits_per_digit {
+        8 => Some("u8"),
+        16 => Some("u16"),
+        32 => Some("u32"),
+        _ => None,
+    }
+}
@@ -1,3 +1,8 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use crate::{
     language::{
         parsed::ParseProgram,
@@ -5,6 +10,7 @@ use crate::{
     },
     metadata::MetadataManager,
     semantic_analysis::{
+        cache::ModuleCacheScope,
         namespace::{self, Namespace},
         TypeCheckContext,
     },
@@ -31,6 +37,22 @@ impl TyProgram {
         package_name: &str,
         build_config: Option<&BuildConfig>,
     ) -> Result<Self, ErrorEmitted> {
+        // Reuse the unchanged submodules of previous compilations, if compiling incrementally.
+        let cache_scope = build_config
+            .filter(|config| config.incremental)
+            .and_then(|config| {
+                let mut settings = DefaultHasher::new();
+                config.include_tests.hash(&mut settings);
+                config.experimental.new_encoding.hash(&mut settings);
+                ModuleCacheScope::root(
+                    engines.qe().typed_module_cache(),
+                    engines,
+                    config.canonical_root_module.clone(),
+                    settings.finish(),
+                    &initial_namespace,
+                )
+            });
+
         let mut namespace = Namespace::init_root(initial_namespace);
         let ctx = TypeCheckContext::from_root(&mut namespace, engines)
             .with_kind(parsed.kind.clone())
@@ -41,8 +63,16 @@ impl TyProgram {
         // Analyze the dependency order for the submodules.
         let modules_dep_graph = ty::TyModule::analyze(handler, root)?;
         let module_eval_order = modules_dep_graph.compute_order(handler)?;
+        let parallel = build_config.map_or(false, |config| config.parallel_type_check);
+        let module_eval_order = modules_dep_graph.compute_batches(module_eval_order, parallel);
+
+        if let Some(cache_scope) = &cache_scope {
+            cache_scope.record_package_fingerprint(root);
+        }
 
-        ty::TyModule::type_check(handler, ctx, root, module_eval_order).and_then(|root| {
+        let typed_root =
+            ty::TyModule::type_check(handler, ctx, root, module_eval_order, cache_scope, parallel);
+        typed_root.and_then(|root| {
             let res = Self::validate_root(handler, engines, &root, kind.clone(), package_name);
             res.map(|(kind, declarations, configurables)| Self {
                 kind,
@@ -85,7 +115,7 @@ impl TyProgram {
                         )?;
                         // Sort the slots to standardize the output. Not strictly required by the
                         // spec.
-                        storage_slots.sort();
+                        storage_slots.sort_by(|a, b| a.slot.cmp(&b.slot));
                         Ok(Self {
                             storage_slots,
                             ..self
@@ -110,7 +110,10 @@ impl<'a> TypeCheckContext<'a> {
         Self::from_module_namespace(root_namespace, engines)
     }
 
-    fn from_module_namespace(namespace: &'a mut Namespace, engines: &'a Engines) -> Self {
+    pub(crate) fn from_module_namespace(
+        namespace: &'a mut Namespace,
+        engines: &'a Engines,
+    ) -> Self {
         Self {
             namespace,
             engines,
@@ -374,6 +377,22 @@ impl<'a> TypeCheckContext<'a> {
         )
     }
 
+    /// Unifies `ty`, the type an expression is going to have, with the type annotation before the
+    /// sub-expressions it depends on are type checked. This lets the expected type bind the type
+    /// parameters of generic calls, so that they do not have to be inferred from the arguments.
+    ///
+    /// Does nothing if the types are not compatible, leaving the mismatch to be reported once
+    /// the type of the expression is known.
+    pub(crate) fn propagate_type_annotation(&self, handler: &Handler, ty: TypeId, span: &Span) {
+        let type_annotation = self.type_annotation();
+        if matches!(&*self.engines.te().get(type_annotation), TypeInfo::Unknown)
+            || !UnifyCheck::coercion(self.engines).check(ty, type_annotation)
+        {
+            return;
+        }
+        self.unify_with_type_annotation(handler, ty, span);
+    }
+
     /// Short-hand around `type_system::unify_`, where the `TypeCheckContext`
     /// provides the type annotation and help text.
     pub(crate) fn unify_with_type_annotation(&self, handler: &Handler, ty: TypeId, span: &Span) {
@@ -1205,6 +1224,43 @@ impl<'a> TypeCheckContext<'a> {
         }
     }
 
+    /// Given a type and the absolute call path of an assignment operator trait method, e.g.
+    /// `core::ops::add_assign`, find that method if the type implements it.
+    ///
+    /// Compound assignments, e.g. `lhs += operand`, are desugared to a call of the method when
+    /// the type of `lhs` implements it, and to `lhs = core::ops::add(lhs, operand)` otherwise. So
+    /// unlike [Self::find_method_for_type], this function doesn't generate any errors.
+    pub(crate) fn find_assign_op_method_for_type(
+        &mut self,
+        type_id: TypeId,
+        call_path: &CallPath,
+    ) -> Option<DeclRefFunction> {
+        let mut method_prefix = call_path.prefixes.clone();
+        if let (Some(root_mod), Some(root_name)) =
+            (method_prefix.first(), self.namespace.root().name.as_ref())
+        {
+            if root_mod.as_str() == root_name.as_str() {
+                method_prefix.remove(0);
+            }
+        }
+
+        let annotation_type = self
+            .engines
+            .te()
+            .insert(self.engines, TypeInfo::Unknown, None);
+        self.find_method_for_type(
+            &Handler::default(),
+            type_id,
+            &method_prefix,
+            &call_path.suffix,
+            annotation_type,
+            &VecDeque::new(),
+            None,
+            TryInsertingTraitImplOnFailure::Yes,
+        )
+        .ok()
+    }
+
     /// Short-hand for performing a [Module::star_import] with `mod_path` as the destination.
     pub(crate) fn star_import(
         &mut self,
@@ -1555,6 +1611,7 @@ impl<'a> TypeCheckContext<'a> {
         constraints: &[TraitConstraint],
     ) -> bool {
         let handler = Handler::default();
+        let marker_traits = self.namespace.visible_marker_traits(self.engines);
 
         self.namespace
             .implemented_traits
@@ -1562,6 +1619,7 @@ impl<'a> TypeCheckContext<'a> {
                 &handler,
                 type_id,
                 constraints,
+                marker_traits,
                 &Span::dummy(),
                 self.engines,
                 crate::namespace::TryInsertingTraitImplOnFailure::Yes,
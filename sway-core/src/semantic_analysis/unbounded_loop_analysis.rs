@@ -0,0 +1,241 @@
+//! Warns about loops in contract methods whose number of iterations is controlled by the caller.
+//!
+//! A loop bounded by an argument of a contract method, or by the length of a storage collection
+//! that anyone can grow, lets a caller make the method run out of gas. This can lock funds or
+//! block other users of the contract, a griefing attack also known as a gas bomb. The analysis
+//! tracks the variables whose values derive from the arguments of the methods of ABI
+//! implementations, and from the lengths of storage collections, and reports the `while` loops
+//! whose condition depends on them. Methods annotated with `#[allow(unbounded_loop)]` are not
+//! analyzed.
+
+use crate::{
+    decl_engine::InterfaceDeclId,
+    language::ty::{self, TyDecl, TyImplItem},
+    transform::AttributeKind,
+    Engines, TypeInfo,
+};
+use std::collections::HashSet;
+use sway_error::{
+    handler::Handler,
+    warning::{CompileWarning, Warning},
+};
+use sway_types::{constants::ALLOW_UNBOUNDED_LOOP_NAME, Ident};
+
+pub(crate) fn analyze_program(handler: &Handler, engines: &Engines, prog: &ty::TyProgram) {
+    // Only contracts have methods that anyone can call.
+    if !matches!(prog.kind, ty::TyProgramKind::Contract { .. }) {
+        return;
+    }
+    let decl_engine = engines.de();
+    for node in prog.root.all_nodes.iter() {
+        let ty::TyAstNodeContent::Declaration(TyDecl::ImplTrait(ty::ImplTrait { decl_id, .. })) =
+            &node.content
+        else {
+            continue;
+        };
+        let impl_trait = decl_engine.get_impl_trait(decl_id);
+        let is_abi_impl = matches!(
+            &*engines.te().get(impl_trait.implementing_for.type_id),
+            TypeInfo::Contract
+        ) && impl_trait
+            .trait_decl_ref
+            .as_ref()
+            .is_some_and(|decl_ref| matches!(decl_ref.id(), InterfaceDeclId::Abi(_)));
+        if !is_abi_impl {
+            continue;
+        }
+        for item in impl_trait.items.iter() {
+            if let TyImplItem::Fn(decl_ref) = item {
+                analyze_method(handler, &decl_engine.get_function(decl_ref));
+            }
+        }
+    }
+}
+
+fn analyze_method(handler: &Handler, fn_decl: &ty::TyFunctionDecl) {
+    let allowed = fn_decl
+        .attributes
+        .get(&AttributeKind::Allow)
+        .into_iter()
+        .flatten()
+        .flat_map(|attribute| attribute.args.iter())
+        .any(|arg| arg.name.as_str() == ALLOW_UNBOUNDED_LOOP_NAME);
+    if allowed {
+        return;
+    }
+    let mut analysis = Analysis {
+        handler,
+        method_name: &fn_decl.name,
+        tainted: fn_decl
+            .parameters
+            .iter()
+            .map(|param| param.name.clone())
+            .filter(|name| name.as_str() != "self")
+            .collect(),
+    };
+    analysis.analyze_code_block(&fn_decl.body);
+}
+
+struct Analysis<'a> {
+    handler: &'a Handler,
+    method_name: &'a Ident,
+    /// The variables whose values derive from the arguments of the method, or from the length
+    /// of a storage collection.
+    tainted: HashSet<Ident>,
+}
+
+impl Analysis<'_> {
+    fn analyze_code_block(&mut self, code_block: &ty::TyCodeBlock) {
+        for node in code_block.contents.iter() {
+            match &node.content {
+                ty::TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => {
+                    self.analyze_expr(&decl.body);
+                    if self.is_tainted(&decl.body) {
+                        self.tainted.insert(decl.name.clone());
+                    }
+                }
+                ty::TyAstNodeContent::Expression(expr)
+                | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => self.analyze_expr(expr),
+                _ => {}
+            }
+        }
+    }
+
+    fn analyze_expr(&mut self, expr: &ty::TyExpression) {
+        match &expr.expression {
+            ty::TyExpressionVariant::CodeBlock(code_block) => self.analyze_code_block(code_block),
+            ty::TyExpressionVariant::WhileLoop { condition, body } => {
+                if self.is_tainted(condition) {
+                    self.handler.emit_warn(CompileWarning {
+                        span: condition.span.clone(),
+                        warning_content: Warning::UnboundedLoop {
+                            method_name: self.method_name.clone(),
+                        },
+                    });
+                }
+                self.analyze_expr(condition);
+                self.analyze_code_block(body);
+            }
+            ty::TyExpressionVariant::Reassignment(reassignment) => {
+                self.analyze_expr(&reassignment.rhs);
+                if reassignment.lhs_indices.is_empty() && self.is_tainted(&reassignment.rhs) {
+                    self.tainted.insert(reassignment.lhs_base_name.clone());
+                }
+            }
+            _ => {
+                for sub_expr in sub_expressions(expr) {
+                    self.analyze_expr(sub_expr);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the value of the expression derives from a tainted variable, or from
+    /// the length of a storage collection.
+    fn is_tainted(&self, expr: &ty::TyExpression) -> bool {
+        match &expr.expression {
+            ty::TyExpressionVariant::VariableExpression { name, .. } => self.tainted.contains(name),
+            ty::TyExpressionVariant::FunctionApplication {
+                call_path,
+                arguments,
+                ..
+            } if call_path.suffix.as_str() == "len"
+                && arguments
+                    .first()
+                    .is_some_and(|(_, arg)| is_storage_access(arg)) =>
+            {
+                true
+            }
+            _ => sub_expressions(expr)
+                .into_iter()
+                .any(|sub_expr| self.is_tainted(sub_expr)),
+        }
+    }
+}
+
+/// Returns `true` if the expression refers to a storage field, like `storage.values`, or to a
+/// storage collection nested in one, like `storage.vecs.get(key)`.
+fn is_storage_access(expr: &ty::TyExpression) -> bool {
+    match &expr.expression {
+        ty::TyExpressionVariant::StorageAccess(_) => true,
+        ty::TyExpressionVariant::FunctionApplication { arguments, .. } => arguments
+            .first()
+            .is_some_and(|(_, arg)| is_storage_access(arg)),
+        _ => false,
+    }
+}
+
+/// Returns the expressions directly nested in the expression, including the expressions of the
+/// statements of its code blocks.
+fn sub_expressions(expr: &ty::TyExpression) -> Vec<&ty::TyExpression> {
+    fn code_block_expressions(code_block: &ty::TyCodeBlock) -> Vec<&ty::TyExpression> {
+        code_block
+            .contents
+            .iter()
+            .filter_map(|node| match &node.content {
+                ty::TyAstNodeContent::Declaration(TyDecl::VariableDecl(decl)) => Some(&decl.body),
+                ty::TyAstNodeContent::Expression(expr)
+                | ty::TyAstNodeContent::ImplicitReturnExpression(expr) => Some(expr),
+                _ => None,
+            })
+            .collect()
+    }
+
+    match &expr.expression {
+        ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            contract_call_params,
+            ..
+        } => arguments
+            .iter()
+            .map(|(_, arg)| arg)
+            .chain(contract_call_params.values())
+            .collect(),
+        ty::TyExpressionVariant::LazyOperator { lhs, rhs, .. }
+        | ty::TyExpressionVariant::ArrayIndex {
+            prefix: lhs,
+            index: rhs,
+        } => vec![&**lhs, &**rhs],
+        ty::TyExpressionVariant::Tuple { fields } => fields.iter().collect(),
+        ty::TyExpressionVariant::Array { contents, .. } => contents.iter().collect(),
+        ty::TyExpressionVariant::StructExpression { fields, .. } => {
+            fields.iter().map(|field| &field.value).collect()
+        }
+        ty::TyExpressionVariant::CodeBlock(code_block) => code_block_expressions(code_block),
+        ty::TyExpressionVariant::MatchExp { desugared, .. } => vec![&**desugared],
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => [condition, then]
+            .into_iter()
+            .chain(r#else)
+            .map(|expr| &**expr)
+            .collect(),
+        ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix, .. } => vec![&**prefix],
+        ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
+            contents.iter().map(|contents| &**contents).collect()
+        }
+        ty::TyExpressionVariant::AbiCast { address, .. } => vec![&**address],
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => kind.arguments.iter().collect(),
+        ty::TyExpressionVariant::EnumTag { exp }
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::Return(exp)
+        | ty::TyExpressionVariant::Ref(exp)
+        | ty::TyExpressionVariant::Deref(exp) => vec![&**exp],
+        ty::TyExpressionVariant::WhileLoop { condition, body } => std::iter::once(&**condition)
+            .chain(code_block_expressions(body))
+            .collect(),
+        ty::TyExpressionVariant::Reassignment(reassignment) => vec![&reassignment.rhs],
+        ty::TyExpressionVariant::AsmExpression { .. }
+        | ty::TyExpressionVariant::Literal(_)
+        | ty::TyExpressionVariant::ConstantExpression { .. }
+        | ty::TyExpressionVariant::VariableExpression { .. }
+        | ty::TyExpressionVariant::FunctionParameter
+        | ty::TyExpressionVariant::StorageAccess(_)
+        | ty::TyExpressionVariant::AbiName(_)
+        | ty::TyExpressionVariant::Break
+        | ty::TyExpressionVariant::Continue => vec![],
+    }
+}
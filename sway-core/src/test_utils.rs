@@ -0,0 +1,103 @@
+use crate::{language::ty, namespace, BuildConfig, BuildTarget, Engines};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use sway_error::{handler::Handler, warning::CompileWarning};
+
+/// A directory of sources in the temporary directory, removed when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Writes the `files`, given by their paths relative to the directory, to a new directory
+    /// named after `name` and the process.
+    pub fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let dir = std::env::temp_dir().join(format!("sway-{name}-{}", std::process::id()));
+        for (path, src) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, src).unwrap();
+        }
+        Self(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Returns the build config of the package in `manifest_dir` whose root module is at
+/// `root_module`, relative to `manifest_dir`.
+pub fn build_config(manifest_dir: &Path, root_module: &str) -> BuildConfig {
+    BuildConfig::root_from_file_name_and_manifest_path(
+        manifest_dir.join(root_module),
+        manifest_dir.to_path_buf(),
+        BuildTarget::default(),
+    )
+}
+
+/// Returns the build config of a program whose source is not written anywhere, as the
+/// `src/main.sw` of a package named after `name` in the temporary directory.
+pub fn source_build_config(name: &str) -> BuildConfig {
+    build_config(
+        &std::env::temp_dir().join(format!("sway-{name}")),
+        "src/main.sw",
+    )
+}
+
+/// Asserts that the compilation reported to `handler` has no errors, and returns its warnings.
+pub fn assert_no_errors(handler: Handler) -> Vec<CompileWarning> {
+    let (errors, warnings) = handler.consume();
+    assert!(errors.is_empty(), "{errors:#?}");
+    warnings
+}
+
+/// Type checks `src` within `initial_namespace`, asserting that it has no errors.
+pub fn compile_to_ast(
+    engines: &Engines,
+    src: &str,
+    initial_namespace: namespace::Module,
+    build_config: Option<&BuildConfig>,
+) -> ty::TyProgram {
+    let handler = Handler::default();
+    let programs = crate::compile_to_ast(
+        &handler,
+        engines,
+        Arc::from(src),
+        initial_namespace,
+        build_config,
+        "test",
+        None,
+    );
+    assert_no_errors(handler);
+    programs.unwrap().typed.unwrap()
+}
+
+/// Compiles `src` within `initial_namespace`, asserting that it has no errors, and returns its
+/// bytecode along with its warnings.
+pub fn compile_to_bytecode(
+    engines: &Engines,
+    src: &str,
+    initial_namespace: namespace::Module,
+    build_config: BuildConfig,
+) -> (Vec<u8>, Vec<CompileWarning>) {
+    let handler = Handler::default();
+    let compiled = crate::compile_to_bytecode(
+        &handler,
+        engines,
+        Arc::from(src),
+        initial_namespace,
+        build_config,
+        &mut crate::source_map::SourceMap::new(),
+        "test",
+    );
+    let warnings = assert_no_errors(handler);
+    (compiled.unwrap().bytecode, warnings)
+}
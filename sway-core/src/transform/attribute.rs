@@ -23,14 +23,17 @@
 use sway_ast::Literal;
 use sway_types::{
     constants::{
-        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, CFG_EXPERIMENTAL_NEW_ENCODING,
-        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, ALLOW_UNBOUNDED_LOOP_NAME,
+        ALLOW_UNUSED_TYPE_PARAMETERS_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        TEST_SHARED_STATE_NAME, TEST_SHOULD_REVERT_NAME,
     },
     Ident, Span, Spanned,
 };
 
 use std::{collections::HashMap, hash::Hash, sync::Arc};
 
+use crate::ExperimentalFeature;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AttributeArg {
     pub name: Ident,
@@ -62,7 +65,12 @@ pub enum AttributeKind {
     Storage,
     Inline,
     Test,
+    BeforeEach,
+    AfterEach,
     Payable,
+    Constructor,
+    WhenNotPaused,
+    TailCall,
     Allow,
     Cfg,
     Deprecated,
@@ -78,7 +86,12 @@ impl AttributeKind {
             AttributeKind::Storage => (0, None),
             AttributeKind::Inline => (0, None),
             AttributeKind::Test => (0, None),
+            AttributeKind::BeforeEach => (0, Some(0)),
+            AttributeKind::AfterEach => (0, Some(0)),
             AttributeKind::Payable => (0, None),
+            AttributeKind::Constructor => (0, Some(0)),
+            AttributeKind::WhenNotPaused => (0, Some(0)),
+            AttributeKind::TailCall => (0, Some(0)),
             AttributeKind::Allow => (1, Some(1)),
             AttributeKind::Cfg => (1, Some(1)),
             AttributeKind::Deprecated => (0, None),
@@ -92,17 +105,29 @@ impl AttributeKind {
             AttributeKind::DocComment => None,
             AttributeKind::Storage => None,
             AttributeKind::Inline => None,
-            AttributeKind::Test => None,
+            AttributeKind::Test => Some(vec![
+                TEST_SHOULD_REVERT_NAME.to_string(),
+                TEST_SHARED_STATE_NAME.to_string(),
+            ]),
+            AttributeKind::BeforeEach => None,
+            AttributeKind::AfterEach => None,
             AttributeKind::Payable => None,
+            AttributeKind::Constructor => None,
+            AttributeKind::WhenNotPaused => None,
+            AttributeKind::TailCall => None,
             AttributeKind::Allow => Some(vec![
                 ALLOW_DEAD_CODE_NAME.to_string(),
                 ALLOW_DEPRECATED_NAME.to_string(),
+                ALLOW_UNUSED_TYPE_PARAMETERS_NAME.to_string(),
+                ALLOW_UNBOUNDED_LOOP_NAME.to_string(),
             ]),
-            AttributeKind::Cfg => Some(vec![
-                CFG_TARGET_ARG_NAME.to_string(),
-                CFG_PROGRAM_TYPE_ARG_NAME.to_string(),
-                CFG_EXPERIMENTAL_NEW_ENCODING.to_string(),
-            ]),
+            AttributeKind::Cfg => Some(
+                [CFG_TARGET_ARG_NAME, CFG_PROGRAM_TYPE_ARG_NAME]
+                    .into_iter()
+                    .chain(ExperimentalFeature::ALL.iter().map(|f| f.cfg_name()))
+                    .map(|arg| arg.to_string())
+                    .collect(),
+            ),
             AttributeKind::Deprecated => None,
         }
     }
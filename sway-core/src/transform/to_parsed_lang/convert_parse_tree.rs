@@ -1,12 +1,12 @@
 use crate::{
     compiler_generated::{
         generate_destructured_struct_var_name, generate_matched_value_var_name,
-        generate_tuple_var_name,
+        generate_tuple_var_name, TEST_RESULT_VAR_NAME,
     },
     language::{parsed::*, *},
     transform::{attribute::*, to_parsed_lang::context::Context},
     type_system::*,
-    BuildTarget, Engines, ExperimentalFlags,
+    BuildTarget, Engines, ExperimentalFeature, ExperimentalFlags,
 };
 
 use itertools::Itertools;
@@ -28,11 +28,13 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
     constants::{
-        ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, CFG_EXPERIMENTAL_NEW_ENCODING,
-        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, DEPRECATED_ATTRIBUTE_NAME,
-        DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
-        PAYABLE_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME,
-        STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME, VALID_ATTRIBUTE_NAMES,
+        AFTER_EACH_ATTRIBUTE_NAME, ALLOW_ATTRIBUTE_NAME, BEFORE_EACH_ATTRIBUTE_NAME,
+        CFG_ATTRIBUTE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        CONSTRUCTOR_ATTRIBUTE_NAME, DEPRECATED_ATTRIBUTE_NAME, DOC_ATTRIBUTE_NAME,
+        DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME, PAYABLE_ATTRIBUTE_NAME,
+        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+        TAIL_CALL_ATTRIBUTE_NAME, TEST_ATTRIBUTE_NAME, VALID_ATTRIBUTE_NAMES,
+        WHEN_NOT_PAUSED_ATTRIBUTE_NAME,
     },
     integer_bits::IntegerBits,
 };
@@ -87,18 +89,163 @@ pub fn module_to_sway_parse_tree(
         }
         root_nodes
     };
+    let root_nodes = insert_test_fixture_calls(handler, engines, root_nodes)?;
     Ok(ParseTree { span, root_nodes })
 }
 
 fn ast_node_is_test_fn(node: &AstNode) -> bool {
     if let AstNodeContent::Declaration(Declaration::FunctionDeclaration(ref decl)) = node.content {
-        if decl.attributes.contains_key(&AttributeKind::Test) {
+        if decl.is_test() || decl.is_test_fixture() {
             return true;
         }
     }
     false
 }
 
+/// Inserts calls to the `#[before_each]` functions of a module at the start of each of its tests,
+/// and calls to its `#[after_each]` functions at their end, in the order the functions are
+/// declared in. The tests inherit the storage access of the functions they call.
+fn insert_test_fixture_calls(
+    handler: &Handler,
+    engines: &Engines,
+    mut root_nodes: Vec<AstNode>,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let mut before_each = vec![];
+    let mut after_each = vec![];
+    let mut error_emitted = None;
+    for node in root_nodes.iter() {
+        let AstNodeContent::Declaration(Declaration::FunctionDeclaration(decl)) = &node.content
+        else {
+            continue;
+        };
+        for (kind, fixtures) in [
+            (AttributeKind::BeforeEach, &mut before_each),
+            (AttributeKind::AfterEach, &mut after_each),
+        ] {
+            let Some(attribute) = decl.attributes.get(&kind).and_then(|attrs| attrs.last()) else {
+                continue;
+            };
+            let returns_unit = matches!(
+                &*engines.te().get(decl.return_type.type_id),
+                TypeInfo::Tuple(fields) if fields.is_empty()
+            );
+            if decl.is_test()
+                || !decl.parameters.is_empty()
+                || !decl.type_parameters.is_empty()
+                || !returns_unit
+            {
+                let error = ConvertParseTreeError::InvalidTestFixture {
+                    attribute: attribute.name.to_string(),
+                    span: decl.name.span(),
+                };
+                error_emitted = Some(handler.emit_err(error.into()));
+                continue;
+            }
+            fixtures.push((decl.name.clone(), decl.purity));
+        }
+    }
+    if let Some(err) = error_emitted {
+        return Err(err);
+    }
+    if before_each.is_empty() && after_each.is_empty() {
+        return Ok(root_nodes);
+    }
+
+    for node in root_nodes.iter_mut() {
+        let AstNodeContent::Declaration(Declaration::FunctionDeclaration(decl)) = &mut node.content
+        else {
+            continue;
+        };
+        if !decl.is_test() {
+            continue;
+        }
+        for (_, purity) in before_each.iter().chain(after_each.iter()) {
+            if *purity != Purity::Pure {
+                decl.purity = promote_purity(decl.purity, *purity);
+            }
+        }
+
+        let span = decl.name.span();
+        let fixture_call = |name: &Ident| AstNode {
+            content: AstNodeContent::Expression(Expression {
+                kind: ExpressionKind::FunctionApplication(Box::new(
+                    FunctionApplicationExpression {
+                        call_path_binding: TypeBinding {
+                            inner: CallPath {
+                                prefixes: vec![],
+                                suffix: name.clone(),
+                                is_absolute: false,
+                            },
+                            type_arguments: TypeArgs::Regular(vec![]),
+                            span: span.clone(),
+                        },
+                        arguments: vec![],
+                    },
+                )),
+                span: span.clone(),
+            }),
+            span: span.clone(),
+        };
+
+        let contents = &mut decl.body.contents;
+        let mut new_contents: Vec<AstNode> = before_each
+            .iter()
+            .map(|(name, _)| fixture_call(name))
+            .collect();
+        // The result of the test is stored, so that it can be returned after the
+        // `#[after_each]` functions run.
+        let returns_implicitly = matches!(
+            contents.last(),
+            Some(AstNode {
+                content: AstNodeContent::ImplicitReturnExpression(_),
+                ..
+            })
+        );
+        let result = match contents.pop() {
+            Some(last) if returns_implicitly && !after_each.is_empty() => {
+                let AstNodeContent::ImplicitReturnExpression(body) = last.content else {
+                    unreachable!("the last node is an implicit return");
+                };
+                let result_name =
+                    Ident::new_with_override(TEST_RESULT_VAR_NAME.to_string(), last.span.clone());
+                let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+                contents.push(AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        VariableDeclaration {
+                            name: result_name.clone(),
+                            type_ascription: TypeArgument {
+                                type_id,
+                                initial_type_id: type_id,
+                                span: last.span.clone(),
+                                call_path_tree: None,
+                            },
+                            body,
+                            is_mutable: false,
+                        },
+                    )),
+                    span: last.span.clone(),
+                });
+                Some(AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(Expression {
+                        kind: ExpressionKind::Variable(result_name),
+                        span: last.span.clone(),
+                    }),
+                    span: last.span,
+                })
+            }
+            last => {
+                contents.extend(last);
+                None
+            }
+        };
+        new_contents.append(contents);
+        new_contents.extend(after_each.iter().map(|(name, _)| fixture_call(name)));
+        new_contents.extend(result);
+        *contents = new_contents;
+    }
+    Ok(root_nodes)
+}
+
 fn item_to_ast_nodes(
     context: &mut Context,
     handler: &Handler,
@@ -420,18 +567,44 @@ fn item_enum_to_enum_declaration(
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
-            Ok(Some(type_field_to_enum_variant(
-                context,
-                handler,
-                engines,
-                type_field.value,
-                attributes,
-                tag,
-            )?))
+            let discriminant_opt = type_field
+                .value
+                .discriminant_opt
+                .as_ref()
+                .map(|(_, discriminant)| literal_to_enum_discriminant(handler, discriminant))
+                .transpose()?;
+            Ok(Some((
+                type_field_to_enum_variant(
+                    context,
+                    handler,
+                    engines,
+                    type_field.value,
+                    attributes,
+                    tag,
+                )?,
+                discriminant_opt,
+            )))
         })
         .filter_map_ok(|field| field)
         .collect::<Result<Vec<_>, _>>()?;
 
+    // Variants without an explicit discriminant continue from the previous one.
+    let mut next_discriminant = Some(0u64);
+    let variants = variants
+        .into_iter()
+        .map(|(mut variant, discriminant_opt)| {
+            match discriminant_opt.or(next_discriminant) {
+                Some(discriminant) => variant.discriminant = discriminant,
+                None => errors.push(ConvertParseTreeError::EnumDiscriminantOverflow {
+                    name: variant.name.clone(),
+                    span: variant.span.clone(),
+                }),
+            }
+            next_discriminant = variant.discriminant.checked_add(1);
+            variant
+        })
+        .collect::<Vec<_>>();
+
     if variants.iter().any(|variant| {
        matches!(&&*engines.te().get(variant.type_argument.type_id), TypeInfo::Custom { qualified_call_path, ..} if qualified_call_path.call_path.suffix == item_enum.name)
     }) {
@@ -449,6 +622,19 @@ fn item_enum_to_enum_declaration(
         }
     });
 
+    // Make sure no two enum variants share a discriminant
+    let mut variants_by_discriminant = HashMap::new();
+    variants.iter().for_each(|v| {
+        if let Some(other) = variants_by_discriminant.insert(v.discriminant, &v.name) {
+            errors.push(ConvertParseTreeError::DuplicateEnumDiscriminant {
+                name: v.name.clone(),
+                other: other.clone(),
+                discriminant: v.discriminant,
+                span: v.span.clone(),
+            });
+        }
+    });
+
     if let Some(emitted) = emit_all(handler, errors) {
         return Err(emitted);
     }
@@ -1070,6 +1256,13 @@ fn type_field_to_struct_field(
     attributes: AttributesMap,
 ) -> Result<StructField, ErrorEmitted> {
     let span = type_field.span();
+    if type_field.discriminant_opt.is_some() {
+        let error = ConvertParseTreeError::StructFieldDiscriminant {
+            name: type_field.name.clone(),
+            span: span.clone(),
+        };
+        return Err(handler.emit_err(error.into()));
+    }
     let struct_field = StructField {
         name: type_field.name,
         attributes,
@@ -1219,11 +1412,34 @@ fn type_field_to_enum_variant(
         attributes,
         type_argument: ty_to_type_argument(context, handler, engines, type_field.ty)?,
         tag,
+        discriminant: tag as u64,
         span,
     };
     Ok(enum_variant)
 }
 
+fn literal_to_enum_discriminant(
+    handler: &Handler,
+    literal: &sway_ast::Literal,
+) -> Result<u64, ErrorEmitted> {
+    match literal {
+        sway_ast::Literal::Int(LitInt {
+            parsed,
+            ty_opt: None | Some((LitIntType::U64, _)),
+            ..
+        }) => u64::try_from(parsed).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        handler.emit_err(
+            ConvertParseTreeError::InvalidEnumDiscriminant {
+                span: literal.span(),
+            }
+            .into(),
+        )
+    })
+}
+
 fn braced_code_block_contents_to_code_block(
     context: &mut Context,
     handler: &Handler,
@@ -1386,6 +1602,11 @@ fn ty_to_type_info(
             let type_argument = ty_to_type_argument(context, handler, engines, *ty)?;
             TypeInfo::Ref(type_argument)
         }
+        Ty::SliceRef { ty, .. } => {
+            let type_argument = ty_to_type_argument(context, handler, engines, *ty.into_inner())?;
+            TypeInfo::Slice(type_argument)
+        }
+        Ty::Never { .. } => TypeInfo::Never,
     };
     Ok(type_info)
 }
@@ -2011,16 +2232,79 @@ fn expr_to_expression(
                     .collect::<Result<_, _>>()?
             };
 
-            match_expr_to_expression(context, handler, engines, *value, branches, span)?
+            match_expr_to_expression(
+                context,
+                handler,
+                engines,
+                *value,
+                branches,
+                MatchExpressionSource::Match,
+                span,
+            )?
         }
         Expr::While {
-            condition, block, ..
-        } => Expression {
-            kind: ExpressionKind::WhileLoop(WhileLoopExpression {
-                condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
-                body: braced_code_block_contents_to_code_block(context, handler, engines, block)?,
-            }),
-            span,
+            while_token,
+            condition,
+            block,
+        } => match condition {
+            IfCondition::Expr(condition) => Expression {
+                kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                    condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
+                    body: braced_code_block_contents_to_code_block(
+                        context, handler, engines, block,
+                    )?,
+                }),
+                span,
+            },
+            // A `while let` loop is desugared into a `while true` loop whose body is an
+            // `if let` expression, breaking out of the loop if the condition doesn't hold.
+            condition => {
+                let block_span = block.span();
+                let body = Expression {
+                    kind: ExpressionKind::CodeBlock(braced_code_block_contents_to_code_block(
+                        context, handler, engines, block,
+                    )?),
+                    span: block_span.clone(),
+                };
+                let break_block = Expression {
+                    kind: ExpressionKind::CodeBlock(CodeBlock {
+                        contents: vec![AstNode {
+                            content: AstNodeContent::Expression(Expression {
+                                kind: ExpressionKind::Break,
+                                span: while_token.span(),
+                            }),
+                            span: while_token.span(),
+                        }],
+                        whole_block_span: while_token.span(),
+                    }),
+                    span: while_token.span(),
+                };
+                let if_expression = if_condition_to_expression(
+                    context,
+                    handler,
+                    engines,
+                    condition,
+                    body,
+                    Some(break_block),
+                    span.clone(),
+                )?;
+                Expression {
+                    kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                        condition: Box::new(Expression {
+                            kind: ExpressionKind::Literal(Literal::Boolean(true)),
+                            span: while_token.span(),
+                        }),
+                        body: CodeBlock {
+                            contents: vec![AstNode {
+                                content: AstNodeContent::Expression(if_expression),
+                                span: block_span.clone(),
+                            }],
+                            whole_block_span: block_span,
+                        },
+                    }),
+                    span,
+                }
+            }
         },
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(context, handler, engines, func, args)?;
@@ -2038,6 +2322,11 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::Range { start, end, .. } => {
+            let start = expr_to_expression(context, handler, engines, *start)?;
+            let end = expr_to_expression(context, handler, engines, *end)?;
+            range_to_struct_expression(start, end, span)
+        }
         Expr::MethodCall {
             target,
             path_seg,
@@ -2315,6 +2604,7 @@ fn expr_to_expression(
                 kind: ExpressionKind::Reassignment(ReassignmentExpression {
                     lhs: assignable_to_reassignment_target(context, handler, engines, assignable)?,
                     rhs: Box::new(expr_to_expression(context, handler, engines, *expr)?),
+                    assign_op_call: None,
                 }),
                 span,
             },
@@ -2325,17 +2615,27 @@ fn expr_to_expression(
                     engines,
                     assignable.clone(),
                 )?;
+                let args = vec![
+                    assignable_to_expression(context, handler, engines, assignable)?,
+                    expr_to_expression(context, handler, engines, *expr)?,
+                ];
+                let assign_op_call = op_variant
+                    .assign_core_name()
+                    .map(|name| op_call(name, op_span.clone(), span.clone(), &args))
+                    .transpose()?
+                    .map(Box::new);
                 let rhs = Box::new(op_call(
                     op_variant.core_name(),
                     op_span,
                     span.clone(),
-                    &vec![
-                        assignable_to_expression(context, handler, engines, assignable)?,
-                        expr_to_expression(context, handler, engines, *expr)?,
-                    ],
+                    &args,
                 )?);
                 Expression {
-                    kind: ExpressionKind::Reassignment(ReassignmentExpression { lhs, rhs }),
+                    kind: ExpressionKind::Reassignment(ReassignmentExpression {
+                        lhs,
+                        rhs,
+                        assign_op_call,
+                    }),
                     span,
                 }
             }
@@ -2352,6 +2652,41 @@ fn expr_to_expression(
     Ok(expression)
 }
 
+/// Desugars the range `start..end` to `core::ops::Range { start, end }`.
+fn range_to_struct_expression(start: Expression, end: Expression, span: Span) -> Expression {
+    let call_path_binding = TypeBinding {
+        inner: CallPath {
+            prefixes: vec![
+                Ident::new_with_override("core".into(), span.clone()),
+                Ident::new_with_override("ops".into(), span.clone()),
+            ],
+            suffix: Ident::new_with_override("Range".into(), span.clone()),
+            is_absolute: true,
+        },
+        type_arguments: TypeArgs::Regular(vec![]),
+        span: span.clone(),
+    };
+    let fields = vec![
+        StructExpressionField {
+            name: Ident::new_with_override("start".into(), start.span()),
+            span: start.span(),
+            value: start,
+        },
+        StructExpressionField {
+            name: Ident::new_with_override("end".into(), end.span()),
+            span: end.span(),
+            value: end,
+        },
+    ];
+    Expression {
+        kind: ExpressionKind::Struct(Box::new(StructExpression {
+            call_path_binding,
+            fields,
+        })),
+        span,
+    }
+}
+
 fn op_call(
     name: &'static str,
     op_span: Span,
@@ -2721,7 +3056,7 @@ fn if_expr_to_expression(
         kind: ExpressionKind::CodeBlock(braced_code_block_contents_to_code_block(
             context, handler, engines, then_block,
         )?),
-        span: then_block_span.clone(),
+        span: then_block_span,
     };
     let else_block = match else_opt {
         None => None,
@@ -2742,6 +3077,24 @@ fn if_expr_to_expression(
             Some(expression)
         }
     };
+    if_condition_to_expression(
+        context, handler, engines, condition, then_block, else_block, span,
+    )
+}
+
+/// Converts an `if` expression with the given condition, desugaring the `let` conditions into
+/// `match` expressions and the chains of conditions into nested `if` expressions, each of them
+/// falling back to the `else_block`.
+fn if_condition_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    condition: IfCondition,
+    then_block: Expression,
+    else_block: Option<Expression>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let then_block_span = then_block.span();
     let expression = match condition {
         IfCondition::Expr(condition) => Expression {
             kind: ExpressionKind::If(IfExpression {
@@ -2756,6 +3109,7 @@ fn if_expr_to_expression(
             let scrutinee_span = scrutinee.span();
             let mut branches = vec![MatchBranch {
                 scrutinee,
+                guard: None,
                 result: then_block.clone(),
                 span: Span::join(scrutinee_span, then_block_span),
             }];
@@ -2766,6 +3120,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         result: else_block,
                         span: else_block_span,
                     }
@@ -2776,6 +3131,7 @@ fn if_expr_to_expression(
                         scrutinee: Scrutinee::CatchAll {
                             span: else_block_span.clone(),
                         },
+                        guard: None,
                         // If there's no else in an `if-let` expression,
                         // then the else is equivalent to an empty block.
                         result: Expression {
@@ -2790,7 +3146,29 @@ fn if_expr_to_expression(
                 }
             });
 
-            match_expr_to_expression(context, handler, engines, *rhs, branches, span)?
+            match_expr_to_expression(
+                context,
+                handler,
+                engines,
+                *rhs,
+                branches,
+                MatchExpressionSource::IfLet,
+                span,
+            )?
+        }
+        IfCondition::And { lhs, rhs, .. } => {
+            let then_block = if_condition_to_expression(
+                context,
+                handler,
+                engines,
+                *rhs,
+                then_block,
+                else_block.clone(),
+                span.clone(),
+            )?;
+            if_condition_to_expression(
+                context, handler, engines, *lhs, then_block, else_block, span,
+            )?
         }
     };
     Ok(expression)
@@ -2802,9 +3180,26 @@ fn match_expr_to_expression(
     engines: &Engines,
     value: Expr,
     branches: Vec<MatchBranch>,
+    source: MatchExpressionSource,
     span: Span,
 ) -> Result<Expression, ErrorEmitted> {
     let value = expr_to_expression(context, handler, engines, value)?;
+    Ok(match_value_to_expression(
+        context, engines, value, None, branches, source, span,
+    ))
+}
+
+/// Creates the block that stores the matched `value` in a variable, optionally ascribed with
+/// `type_ascription`, and matches on that variable.
+fn match_value_to_expression(
+    context: &mut Context,
+    engines: &Engines,
+    value: Expression,
+    type_ascription: Option<TypeArgument>,
+    branches: Vec<MatchBranch>,
+    source: MatchExpressionSource,
+    span: Span,
+) -> Expression {
     let var_decl_span = value.span();
 
     // Generate a deterministic name for the variable matched by the match expression.
@@ -2819,13 +3214,13 @@ fn match_expr_to_expression(
         span: var_decl_span,
     };
 
-    Ok(Expression {
+    Expression {
         kind: ExpressionKind::CodeBlock(CodeBlock {
             contents: vec![
                 AstNode {
                     content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
                         VariableDeclaration {
-                            type_ascription: {
+                            type_ascription: type_ascription.unwrap_or_else(|| {
                                 let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
                                 TypeArgument {
                                     type_id,
@@ -2833,7 +3228,7 @@ fn match_expr_to_expression(
                                     span: var_decl_name.span(),
                                     call_path_tree: None,
                                 }
-                            },
+                            }),
                             name: var_decl_name,
                             is_mutable: false,
                             body: value,
@@ -2846,6 +3241,7 @@ fn match_expr_to_expression(
                         kind: ExpressionKind::Match(MatchExpression {
                             value: Box::new(var_decl_exp),
                             branches,
+                            source,
                         }),
                         span: span.clone(),
                     }),
@@ -2855,7 +3251,7 @@ fn match_expr_to_expression(
             whole_block_span: span.clone(),
         }),
         span,
-    })
+    }
 }
 
 /// Determine if the path is in absolute form, e.g., `::foo::bar`.
@@ -3242,18 +3638,48 @@ fn asm_block_to_asm_expression(
     let asm_block_contents = asm_block.contents.into_inner();
     let (returns, return_type) = match asm_block_contents.final_expr_opt {
         Some(asm_final_expr) => {
-            let asm_register = AsmRegister {
-                name: asm_final_expr.register.as_str().to_owned(),
-            };
-            let returns = Some((asm_register, asm_final_expr.register.span()));
+            let returns = asm_final_expr
+                .registers
+                .registers()
+                .into_iter()
+                .map(|register| {
+                    let asm_register = AsmRegister {
+                        name: register.as_str().to_owned(),
+                    };
+                    (asm_register, register.span())
+                })
+                .collect::<Vec<_>>();
             let return_type = match asm_final_expr.ty_opt {
                 Some((_colon_token, ty)) => ty_to_type_info(context, handler, engines, ty)?,
+                // Registers are words, so a tuple of registers defaults to a tuple of `u64`s.
+                None if returns.len() > 1 => TypeInfo::Tuple(
+                    returns
+                        .iter()
+                        .map(|(_, span)| {
+                            let type_id = engines.te().insert(
+                                engines,
+                                TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+                                span.source_id(),
+                            );
+                            TypeArgument {
+                                type_id,
+                                initial_type_id: type_id,
+                                span: span.clone(),
+                                call_path_tree: None,
+                            }
+                        })
+                        .collect(),
+                ),
                 None => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
             };
             (returns, return_type)
         }
-        None => (None, TypeInfo::Tuple(Vec::new())),
+        None => (vec![], TypeInfo::Tuple(Vec::new())),
     };
+    let clobbers = asm_block
+        .clobbers_opt
+        .map(|clobbers| clobbers.registers.into_inner().into_iter().collect())
+        .unwrap_or_default();
     let registers = {
         asm_block
             .registers
@@ -3280,6 +3706,7 @@ fn asm_block_to_asm_expression(
         registers,
         body,
         returns,
+        clobbers,
         return_type,
         whole_block_span,
     }))
@@ -3294,6 +3721,12 @@ fn match_branch_to_match_branch(
     let span = match_branch.span();
     Ok(MatchBranch {
         scrutinee: pattern_to_scrutinee(context, handler, match_branch.pattern)?,
+        guard: match match_branch.guard_opt {
+            Some((_if_token, guard)) => {
+                Some(expr_to_expression(context, handler, engines, *guard)?)
+            }
+            None => None,
+        },
         result: match match_branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let span = block.span();
@@ -3616,15 +4049,179 @@ fn statement_let_to_ast_nodes(
     }
     let span = statement_let.span();
     let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
-    unfold(
+    let ty_opt = statement_let.ty_opt.map(|(_colon_token, ty)| ty);
+    match statement_let.else_opt {
+        Some((_else_token, else_block)) => let_else_to_ast_nodes(
+            context,
+            handler,
+            engines,
+            statement_let.pattern,
+            ty_opt,
+            initial_expression,
+            else_block,
+            span,
+        ),
+        None => unfold(
+            context,
+            handler,
+            engines,
+            statement_let.pattern,
+            ty_opt,
+            initial_expression,
+            span,
+        ),
+    }
+}
+
+/// Desugars `let pattern = expression else { .. };` into a match expression that evaluates to
+/// the tuple of the variables bound by the pattern, or to the diverging `else` block:
+///
+/// ```ignore
+/// let __tuple_1 = match expression {
+///     pattern => (a, b),
+///     _ => { .. },
+/// };
+/// let a = __tuple_1.0;
+/// let b = __tuple_1.1;
+/// ```
+#[allow(clippy::too_many_arguments)]
+fn let_else_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    else_block: Braces<CodeBlockContents>,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let mut variables = vec![];
+    collect_pattern_variables(&pattern, &mut variables);
+
+    let scrutinee = pattern_to_scrutinee(context, handler, pattern)?;
+    let scrutinee_span = scrutinee.span();
+    let bound_values = Expression {
+        kind: ExpressionKind::Tuple(
+            variables
+                .iter()
+                .map(|(name, _)| Expression {
+                    kind: ExpressionKind::Variable(name.clone()),
+                    span: name.span(),
+                })
+                .collect(),
+        ),
+        span: scrutinee_span.clone(),
+    };
+    let else_block =
+        braced_code_block_contents_to_expression(context, handler, engines, else_block)?;
+    let else_block_span = else_block.span();
+    let branches = vec![
+        MatchBranch {
+            scrutinee,
+            guard: None,
+            result: bound_values,
+            span: scrutinee_span,
+        },
+        MatchBranch {
+            scrutinee: Scrutinee::CatchAll {
+                span: else_block_span.clone(),
+            },
+            guard: None,
+            result: else_block,
+            span: else_block_span,
+        },
+    ];
+    let type_ascription = ty_opt
+        .map(|ty| ty_to_type_argument(context, handler, engines, ty))
+        .transpose()?;
+    let match_expression = match_value_to_expression(
         context,
-        handler,
         engines,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
-    )
+        expression,
+        type_ascription,
+        branches,
+        MatchExpressionSource::LetElse,
+        span.clone(),
+    );
+
+    // Generate a deterministic name for the tuple of the bound values.
+    let tuple_name = generate_tuple_var_name(context.next_destructured_tuple_unique_suffix());
+    let tuple_name = Ident::new_with_override(tuple_name, span.clone());
+
+    let variable_declaration = |name: Ident, body: Expression, is_mutable: bool| {
+        let type_id = engines.te().insert(engines, TypeInfo::Unknown, None);
+        AstNode {
+            content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                VariableDeclaration {
+                    type_ascription: TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: name.span(),
+                        call_path_tree: None,
+                    },
+                    name,
+                    body,
+                    is_mutable,
+                },
+            )),
+            span: span.clone(),
+        }
+    };
+
+    let mut ast_nodes = vec![variable_declaration(
+        tuple_name.clone(),
+        match_expression,
+        false,
+    )];
+    for (index, (name, is_mutable)) in variables.into_iter().enumerate() {
+        let element = Expression {
+            kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                prefix: Box::new(Expression {
+                    kind: ExpressionKind::Variable(tuple_name.clone()),
+                    span: span.clone(),
+                }),
+                index,
+                index_span: name.span(),
+            }),
+            span: name.span(),
+        };
+        ast_nodes.push(variable_declaration(name, element, is_mutable));
+    }
+    Ok(ast_nodes)
+}
+
+/// Collects the variables bound by the `pattern`, along with whether they are mutable.
+/// The alternatives of an or-pattern bind the same variables, so only the first one is visited.
+fn collect_pattern_variables(pattern: &Pattern, variables: &mut Vec<(Ident, bool)>) {
+    match pattern {
+        Pattern::Or { lhs, .. } => collect_pattern_variables(lhs, variables),
+        Pattern::Var { mutable, name, .. } => variables.push((name.clone(), mutable.is_some())),
+        Pattern::AmbiguousSingleIdent(ident) => variables.push((ident.clone(), false)),
+        Pattern::Constructor { args: elems, .. } | Pattern::Tuple(elems) => {
+            for elem in elems.get() {
+                collect_pattern_variables(elem, variables);
+            }
+        }
+        Pattern::Struct { fields, .. } => {
+            for field in fields.get() {
+                match field {
+                    PatternStructField::Field {
+                        pattern_opt: Some((_, pattern)),
+                        ..
+                    } => collect_pattern_variables(pattern, variables),
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt: None,
+                    } => variables.push((field_name.clone(), false)),
+                    PatternStructField::Rest { .. } => {}
+                }
+            }
+        }
+        Pattern::Wildcard { .. }
+        | Pattern::Literal(_)
+        | Pattern::Constant(_)
+        | Pattern::Error(..) => {}
+    }
 }
 
 fn submodule_to_include_statement(dependency: &Submodule) -> IncludeStatement {
@@ -3664,6 +4261,7 @@ fn asm_register_declaration_to_asm_register_declaration(
     Ok(AsmRegisterDeclaration {
         name: asm_register_declaration.register,
         initializer,
+        is_const: asm_register_declaration.const_token_opt.is_some(),
     })
 }
 
@@ -3833,6 +4431,8 @@ fn ty_to_type_parameter(
         Ty::Ptr { .. } => panic!("__ptr types are not allowed in this position"),
         Ty::Slice { .. } => panic!("__slice types are not allowed in this position"),
         Ty::Ref { .. } => panic!("ref types are not allowed in this position"),
+        Ty::SliceRef { .. } => panic!("slice types are not allowed in this position"),
+        Ty::Never { .. } => panic!("never types are not allowed in this position"),
     };
     let custom_type = type_engine.insert(
         engines,
@@ -4246,7 +4846,12 @@ fn item_attrs_to_map(
                 STORAGE_PURITY_ATTRIBUTE_NAME => Some(AttributeKind::Storage),
                 INLINE_ATTRIBUTE_NAME => Some(AttributeKind::Inline),
                 TEST_ATTRIBUTE_NAME => Some(AttributeKind::Test),
+                BEFORE_EACH_ATTRIBUTE_NAME => Some(AttributeKind::BeforeEach),
+                AFTER_EACH_ATTRIBUTE_NAME => Some(AttributeKind::AfterEach),
                 PAYABLE_ATTRIBUTE_NAME => Some(AttributeKind::Payable),
+                CONSTRUCTOR_ATTRIBUTE_NAME => Some(AttributeKind::Constructor),
+                WHEN_NOT_PAUSED_ATTRIBUTE_NAME => Some(AttributeKind::WhenNotPaused),
+                TAIL_CALL_ATTRIBUTE_NAME => Some(AttributeKind::TailCall),
                 ALLOW_ATTRIBUTE_NAME => Some(AttributeKind::Allow),
                 CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
                 DEPRECATED_ATTRIBUTE_NAME => Some(AttributeKind::Deprecated),
@@ -4397,22 +5002,25 @@ pub fn cfg_eval(
                             return Err(handler.emit_err(error.into()));
                         }
                     }
-                    CFG_EXPERIMENTAL_NEW_ENCODING => match &arg.value {
-                        Some(sway_ast::Literal::Bool(v)) => {
-                            let is_true = matches!(v.kind, sway_ast::literal::LitBoolType::True);
-                            return Ok(experimental.new_encoding == is_true);
-                        }
-                        _ => {
-                            let error =
-                                ConvertParseTreeError::ExpectedExperimentalNewEncodingArgValue {
+                    name => {
+                        let Some(feature) = ExperimentalFeature::from_cfg_name(name) else {
+                            // Already checked with `AttributeKind::expected_args_*`
+                            unreachable!("cfg attribute should only have the `target`, the `program_type` or an experimental feature argument");
+                        };
+                        match &arg.value {
+                            Some(sway_ast::Literal::Bool(v)) => {
+                                let is_true =
+                                    matches!(v.kind, sway_ast::literal::LitBoolType::True);
+                                return Ok(experimental.is_enabled(feature) == is_true);
+                            }
+                            _ => {
+                                let error = ConvertParseTreeError::ExpectedExperimentalArgValue {
                                     span: arg.span(),
+                                    name: name.to_string(),
                                 };
-                            return Err(handler.emit_err(error.into()));
+                                return Err(handler.emit_err(error.into()));
+                            }
                         }
-                    },
-                    _ => {
-                        // Already checked with `AttributeKind::expected_args_*`
-                        unreachable!("cfg attribute should only have the `target` or the `program_type` argument");
                     }
                 }
             }
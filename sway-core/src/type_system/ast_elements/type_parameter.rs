@@ -447,6 +447,7 @@ impl TypeParameter {
         let mut item_refs: ItemMap = BTreeMap::new();
         let mut impld_item_refs: ItemMap = BTreeMap::new();
         let engines = ctx.engines();
+        let marker_traits = ctx.namespace.visible_marker_traits(engines);
 
         handler.scope(|handler| {
             for type_param in type_parameters.iter() {
@@ -464,6 +465,7 @@ impl TypeParameter {
                         handler,
                         *type_id,
                         trait_constraints,
+                        marker_traits,
                         access_span,
                         engines,
                         TryInsertingTraitImplOnFailure::Yes,
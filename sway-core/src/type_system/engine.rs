@@ -1,5 +1,5 @@
 use crate::{
-    concurrent_slab::{ConcurrentSlab, ListDisplay},
+    concurrent_slab::{ConcurrentSlab, InsertionScope, ListDisplay},
     decl_engine::*,
     engine_threading::*,
     type_system::priv_prelude::*,
@@ -20,6 +20,10 @@ use super::unify::unifier::UnifyKind;
 pub struct TypeEngine {
     slab: ConcurrentSlab<TypeSourceInfo>,
     id_map: RwLock<HashMap<TypeSourceInfo, TypeId>>,
+    /// The types inserted within each [InsertionScope] of the current batch, by the index of the
+    /// scope. They're only shared with the other scopes once the batch is committed, so that
+    /// the id a type is interned with doesn't depend on which scope inserts it first.
+    scoped_id_maps: RwLock<HashMap<usize, HashMap<TypeSourceInfo, TypeId>>>,
 }
 
 impl Clone for TypeEngine {
@@ -27,6 +31,7 @@ impl Clone for TypeEngine {
         TypeEngine {
             slab: self.slab.clone(),
             id_map: RwLock::new(self.id_map.read().unwrap().clone()),
+            scoped_id_maps: RwLock::new(self.scoped_id_maps.read().unwrap().clone()),
         }
     }
 }
@@ -47,6 +52,9 @@ impl TypeEngine {
             type_info: ty.clone().into(),
             source_id,
         };
+        if let Some(scope_index) = InsertionScope::current_index() {
+            return self.insert_scoped(engines, ty, tsi, scope_index);
+        }
         let mut id_map = self.id_map.write().unwrap();
 
         let hash_builder = id_map.hasher().clone();
@@ -68,6 +76,74 @@ impl TypeEngine {
         }
     }
 
+    /// Inserts a type within the [InsertionScope] with the given index, reusing the types
+    /// inserted before its batch or within the same scope.
+    fn insert_scoped(
+        &self,
+        engines: &Engines,
+        ty: TypeInfo,
+        tsi: TypeSourceInfo,
+        scope_index: usize,
+    ) -> TypeId {
+        {
+            let id_map = self.id_map.read().unwrap();
+            let ty_hash = make_hasher(id_map.hasher(), engines)(&tsi);
+            if let Some((_, type_id)) = id_map
+                .raw_entry()
+                .from_hash(ty_hash, |x| x.eq(&tsi, engines))
+            {
+                return *type_id;
+            }
+        }
+        if ty.can_change(engines.de()) {
+            return TypeId::new(self.slab.insert(tsi));
+        }
+
+        let mut scoped_id_maps = self.scoped_id_maps.write().unwrap();
+        let id_map = scoped_id_maps.entry(scope_index).or_default();
+        let hash_builder = id_map.hasher().clone();
+        let ty_hash = make_hasher(&hash_builder, engines)(&tsi);
+        match id_map
+            .raw_entry_mut()
+            .from_hash(ty_hash, |x| x.eq(&tsi, engines))
+        {
+            RawEntryMut::Occupied(o) => *o.get(),
+            RawEntryMut::Vacant(v) => {
+                let type_id = TypeId::new(self.slab.insert(tsi.clone()));
+                v.insert_with_hasher(ty_hash, tsi, type_id, make_hasher(&hash_builder, engines));
+                type_id
+            }
+        }
+    }
+
+    /// Shares the types inserted within the [InsertionScope]s of a batch once all of its tasks are
+    /// done. A type inserted within several scopes is then reused with the id of the first of
+    /// them, as if the tasks of the batch had run one after another.
+    pub(crate) fn commit_insertion_scopes(&self, engines: &Engines) {
+        let scoped_id_maps = std::mem::take(&mut *self.scoped_id_maps.write().unwrap());
+        let mut scoped_id_maps = scoped_id_maps.into_iter().collect::<Vec<_>>();
+        scoped_id_maps.sort_by_key(|(scope_index, _)| *scope_index);
+
+        let mut id_map = self.id_map.write().unwrap();
+        let hash_builder = id_map.hasher().clone();
+        for (_, scoped_id_map) in scoped_id_maps {
+            for (tsi, type_id) in scoped_id_map {
+                let ty_hash = make_hasher(&hash_builder, engines)(&tsi);
+                if let RawEntryMut::Vacant(v) = id_map
+                    .raw_entry_mut()
+                    .from_hash(ty_hash, |x| x.eq(&tsi, engines))
+                {
+                    v.insert_with_hasher(
+                        ty_hash,
+                        tsi,
+                        type_id,
+                        make_hasher(&hash_builder, engines),
+                    );
+                }
+            }
+        }
+    }
+
     /// Removes all data associated with `module_id` from the type engine.
     pub fn clear_module(&mut self, module_id: &ModuleId) {
         self.slab.retain(|_, tsi| match tsi.source_id {
@@ -87,6 +163,12 @@ impl TypeEngine {
         self.slab.replace(id.index(), new_value);
     }
 
+    /// Returns the position of `id` among the types inserted into the [TypeEngine], which is what
+    /// identifies the type outside of the compiler, see [ConcurrentSlab::dense_index].
+    pub fn dense_index(&self, id: TypeId) -> usize {
+        self.slab.dense_index(id.index())
+    }
+
     /// Performs a lookup of `id` into the [TypeEngine].
     pub fn get(&self, id: TypeId) -> Arc<TypeInfo> {
         self.slab.get(id.index()).type_info.clone()
@@ -279,6 +361,7 @@ impl TypeEngine {
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(..)
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
             | TypeInfo::B256
@@ -334,6 +417,7 @@ impl TypeEngine {
             | TypeInfo::StringArray(..)
             | TypeInfo::UnsignedInteger(..)
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
             | TypeInfo::B256
@@ -385,6 +469,7 @@ fn info_to_source_id(ty: &TypeInfo) -> Option<SourceId> {
         | TypeInfo::UnsignedInteger(_)
         | TypeInfo::Numeric
         | TypeInfo::Boolean
+        | TypeInfo::Never
         | TypeInfo::B256
         | TypeInfo::RawUntypedPtr
         | TypeInfo::RawUntypedSlice
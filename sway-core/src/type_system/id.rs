@@ -1,3 +1,4 @@
+use sha2::{Digest, Sha256};
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
@@ -221,6 +222,7 @@ impl TypeId {
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::B256
             | TypeInfo::Numeric
             | TypeInfo::Contract
@@ -445,6 +447,67 @@ impl TypeId {
         }))
     }
 
+    /// Returns the canonical name of the type, as returned by the `__type_name` intrinsic.
+    ///
+    /// Structs and enums are named by their fully qualified paths along with their type
+    /// arguments, like `std::option::Option<u64>`, and aliases are replaced by the types they
+    /// stand for, so two types have the same name exactly if they are the same type.
+    pub(crate) fn get_type_name(&self, engines: &Engines) -> String {
+        let type_names = |type_ids: &mut dyn Iterator<Item = TypeId>| {
+            type_ids
+                .map(|type_id| type_id.get_type_name(engines))
+                .collect::<Vec<_>>()
+        };
+        let with_type_args = |call_path: &CallPath, type_parameters: &[TypeParameter]| {
+            let type_args = type_names(&mut type_parameters.iter().map(|param| param.type_id));
+            if type_args.is_empty() {
+                call_path.to_string()
+            } else {
+                format!("{}<{}>", call_path, type_args.join(", "))
+            }
+        };
+        match &*engines.te().get(*self) {
+            TypeInfo::Struct(decl_ref) => {
+                let decl = engines.de().get_struct(decl_ref);
+                with_type_args(&decl.call_path, &decl.type_parameters)
+            }
+            TypeInfo::Enum(decl_ref) => {
+                let decl = engines.de().get_enum(decl_ref);
+                with_type_args(&decl.call_path, &decl.type_parameters)
+            }
+            TypeInfo::Alias { ty, .. } => ty.type_id.get_type_name(engines),
+            TypeInfo::Tuple(fields) if fields.len() == 1 => {
+                format!("({},)", fields[0].type_id.get_type_name(engines))
+            }
+            TypeInfo::Tuple(fields) => {
+                let fields = type_names(&mut fields.iter().map(|field| field.type_id));
+                format!("({})", fields.join(", "))
+            }
+            TypeInfo::Array(elem_ty, length) => {
+                format!(
+                    "[{}; {}]",
+                    elem_ty.type_id.get_type_name(engines),
+                    length.val()
+                )
+            }
+            TypeInfo::Ptr(ty) => format!("__ptr[{}]", ty.type_id.get_type_name(engines)),
+            TypeInfo::Slice(ty) => format!("__slice[{}]", ty.type_id.get_type_name(engines)),
+            TypeInfo::Ref(ty) => format!("&{}", ty.type_id.get_type_name(engines)),
+            TypeInfo::Numeric => "u64".to_string(),
+            _ => engines.help_out(self).to_string(),
+        }
+    }
+
+    /// Returns the stable id of the type, as returned by the `__type_id` intrinsic. It is made
+    /// of the first eight bytes of the SHA-256 hash of the canonical name of the type, so it is
+    /// the same across programs and compilations.
+    pub(crate) fn get_stable_type_id(&self, engines: &Engines) -> u64 {
+        let hash = Sha256::digest(self.get_type_name(engines).as_bytes());
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&hash[..8]);
+        u64::from_be_bytes(bytes)
+    }
+
     /// `check_type_parameter_bounds` does two types of checks. Lets use the example below for demonstrating the two checks:
     /// ```ignore
     /// enum MyEnum<T> where T: MyAdd {
@@ -554,6 +617,7 @@ impl TypeId {
                                         type_arguments_string
                                     ),
                                     span: span.clone(),
+                                    instantiations: vec![],
                                 });
                             },
                         );
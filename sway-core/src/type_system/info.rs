@@ -128,6 +128,9 @@ pub enum TypeInfo {
     Enum(DeclRefEnum),
     Struct(DeclRefStruct),
     Boolean,
+    /// The type `!` of the expressions that never produce a value, like `return`, `break`,
+    /// `continue` or a call to `__revert`. It coerces into any other type.
+    Never,
     Tuple(Vec<TypeArgument>),
     /// Represents a type which contains methods to issue a contract call.
     /// The specific contract is identified via the `Ident` within.
@@ -274,7 +277,8 @@ impl HashWithEngines for TypeInfo {
             | TypeInfo::ErrorRecovery(_)
             | TypeInfo::Unknown
             | TypeInfo::RawUntypedPtr
-            | TypeInfo::RawUntypedSlice => {}
+            | TypeInfo::RawUntypedSlice
+            | TypeInfo::Never => {}
         }
     }
 }
@@ -544,10 +548,17 @@ impl DisplayWithEngines for TypeInfo {
             }
             .into(),
             Boolean => "bool".into(),
+            Never => "!".into(),
             Custom {
                 qualified_call_path: call_path,
                 ..
-            } => call_path.call_path.suffix.to_string(),
+            } => {
+                if engines.verbose_types() {
+                    call_path.call_path.to_string()
+                } else {
+                    call_path.call_path.suffix.to_string()
+                }
+            }
             Tuple(fields) => {
                 let field_strs = fields
                     .iter()
@@ -563,7 +574,7 @@ impl DisplayWithEngines for TypeInfo {
                 let decl = engines.de().get_enum(decl_ref);
                 print_inner_types(
                     engines,
-                    decl.call_path.suffix.as_str().to_string(),
+                    display_call_path(engines, &decl.call_path),
                     decl.type_parameters.iter().map(|x| x.type_id),
                 )
             }
@@ -571,7 +582,7 @@ impl DisplayWithEngines for TypeInfo {
                 let decl = engines.de().get_struct(decl_ref);
                 print_inner_types(
                     engines,
-                    decl.call_path.suffix.as_str().to_string(),
+                    display_call_path(engines, &decl.call_path),
                     decl.type_parameters.iter().map(|x| x.type_id),
                 )
             }
@@ -586,7 +597,7 @@ impl DisplayWithEngines for TypeInfo {
                 format!("__ptr[{}]", engines.help_out(ty))
             }
             Slice(ty) => {
-                format!("__slice[{}]", engines.help_out(ty))
+                format!("&[{}]", engines.help_out(ty))
             }
             Alias { name, .. } => name.to_string(),
             TraitType {
@@ -620,6 +631,7 @@ impl DebugWithEngines for TypeInfo {
             }
             .into(),
             Boolean => "bool".into(),
+            Never => "!".into(),
             Custom {
                 qualified_call_path: call_path,
                 type_arguments,
@@ -687,7 +699,7 @@ impl DebugWithEngines for TypeInfo {
                 format!("__ptr[{:?}]", engines.help_out(ty))
             }
             Slice(ty) => {
-                format!("__slice[{:?}]", engines.help_out(ty))
+                format!("&[{:?}]", engines.help_out(ty))
             }
             Alias { name, ty } => {
                 format!("type {} = {:?}", name, engines.help_out(ty))
@@ -737,6 +749,7 @@ impl TypeInfo {
             TypeInfo::StringSlice => 23,
             TypeInfo::TraitType { .. } => 24,
             TypeInfo::Ref { .. } => 25,
+            TypeInfo::Never => 26,
         }
     }
 
@@ -958,6 +971,7 @@ impl TypeInfo {
         let id_uninhabited = |id| type_engine.get(id).is_uninhabited(type_engine, decl_engine);
 
         match self {
+            TypeInfo::Never => true,
             TypeInfo::Enum(decl_ref) => decl_engine
                 .get_enum(decl_ref)
                 .variants
@@ -1127,6 +1141,7 @@ impl TypeInfo {
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(_)
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::Tuple(_)
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::B256
@@ -1167,6 +1182,7 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::Alias { .. } => Ok(()),
             TypeInfo::Unknown
+            | TypeInfo::Never
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
             | TypeInfo::Ptr(..)
@@ -1226,6 +1242,7 @@ impl TypeInfo {
             | TypeInfo::TraitType { .. }
             | TypeInfo::Ref(_) => Ok(()),
             TypeInfo::Unknown
+            | TypeInfo::Never
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
@@ -1327,6 +1344,7 @@ impl TypeInfo {
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(_)
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::B256
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
@@ -1492,13 +1510,32 @@ impl TypeInfo {
     }
 }
 
+/// How deeply generic arguments are nested in displayed types before being elided, unless types
+/// are displayed verbosely.
+const MAX_DISPLAYED_GENERICS_DEPTH: usize = 3;
+
+fn display_call_path(engines: &Engines, call_path: &CallPath) -> String {
+    if engines.verbose_types() {
+        call_path.to_string()
+    } else {
+        call_path.suffix.as_str().to_string()
+    }
+}
+
 fn print_inner_types(
     engines: &Engines,
     name: String,
     inner_types: impl Iterator<Item = TypeId>,
 ) -> String {
     let inner_types = inner_types
-        .map(|x| engines.help_out(x).to_string())
+        .map(|x| {
+            let inner_type = engines.help_out(x).to_string();
+            if engines.verbose_types() {
+                inner_type
+            } else {
+                elide_generic_arguments(&inner_type, MAX_DISPLAYED_GENERICS_DEPTH - 1)
+            }
+        })
         .collect::<Vec<_>>();
     format!(
         "{}{}",
@@ -1511,6 +1548,34 @@ fn print_inner_types(
     )
 }
 
+/// Replaces the generic arguments nested more than `max_depth` levels deep in the displayed type
+/// with `..`, e.g. `Vec<Option<Vec<u64>>>` becomes `Vec<Option<..>>` for a `max_depth` of 2.
+fn elide_generic_arguments(type_str: &str, max_depth: usize) -> String {
+    let mut elided = String::with_capacity(type_str.len());
+    let mut depth = 0;
+    for c in type_str.chars() {
+        match c {
+            '<' => {
+                depth += 1;
+                if depth <= max_depth {
+                    elided.push(c);
+                } else if depth == max_depth + 1 {
+                    elided.push_str("<..");
+                }
+            }
+            '>' => {
+                if depth <= max_depth + 1 {
+                    elided.push(c);
+                }
+                depth = depth.saturating_sub(1);
+            }
+            _ if depth <= max_depth => elided.push(c),
+            _ => {}
+        }
+    }
+    elided
+}
+
 fn print_inner_types_debug(
     engines: &Engines,
     name: String,
@@ -68,6 +68,7 @@ fn generic_enum_resolution() {
     let variant_types = vec![ty::TyEnumVariant {
         name: a_name.clone(),
         tag: 0,
+        discriminant: 0,
         type_argument: TypeArgument {
             type_id: placeholder_type,
             initial_type_id: placeholder_type,
@@ -99,6 +100,7 @@ fn generic_enum_resolution() {
     let variant_types = vec![ty::TyEnumVariant {
         name: a_name,
         tag: 0,
+        discriminant: 0,
         type_argument: TypeArgument {
             type_id: boolean_type,
             initial_type_id: boolean_type,
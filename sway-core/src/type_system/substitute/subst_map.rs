@@ -480,6 +480,7 @@ impl TypeSubstMap {
             | TypeInfo::StringSlice
             | TypeInfo::UnsignedInteger(..)
             | TypeInfo::Boolean
+            | TypeInfo::Never
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::B256
             | TypeInfo::Numeric
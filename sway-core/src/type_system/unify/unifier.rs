@@ -100,6 +100,7 @@ impl<'a> Unifier<'a> {
             // If they have the same `TypeInfo`, then we either compare them for
             // correctness or perform further unification.
             (Boolean, Boolean) => (),
+            (Never, Never) => (),
             (B256, B256) => (),
             (Numeric, Numeric) => (),
             (Contract, Contract) => (),
@@ -137,6 +138,10 @@ impl<'a> Unifier<'a> {
                 )
             }
 
+            // The never type coerces into any type. An unknown expected type is left unknown,
+            // for it to be inferred from the other expressions that are not diverging.
+            (Never, _) => (),
+
             // When we don't know anything about either term, assume that
             // they match and make the one we know nothing about reference the
             // one we may know something about.
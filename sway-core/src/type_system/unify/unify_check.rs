@@ -357,6 +357,9 @@ impl<'a> UnifyCheck<'a> {
                         !OccursCheck::new(self.engines).check(right, left)
                     }
 
+                    // the never type can be coerced into any type
+                    (Never, _) => true,
+
                     // Let empty enums to coerce to any other type. This is useful for Never enum.
                     (Enum(r_decl_ref), _)
                         if self.engines.de().get_enum(r_decl_ref).variants.is_empty() =>
@@ -506,6 +509,7 @@ impl<'a> UnifyCheck<'a> {
                 // these cases are able to be directly compared
                 (TypeInfo::Contract, TypeInfo::Contract) => true,
                 (TypeInfo::Boolean, TypeInfo::Boolean) => true,
+                (TypeInfo::Never, TypeInfo::Never) => true,
                 (TypeInfo::B256, TypeInfo::B256) => true,
                 (TypeInfo::ErrorRecovery(_), TypeInfo::ErrorRecovery(_)) => true,
                 (TypeInfo::StringSlice, TypeInfo::StringSlice) => true,
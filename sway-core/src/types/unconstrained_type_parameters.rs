@@ -1,4 +1,4 @@
-use crate::{Engines, TypeParameter};
+use crate::{Engines, TypeId, TypeParameter};
 
 pub(crate) trait UnconstrainedTypeParameters {
     fn type_parameter_is_unconstrained(
@@ -43,3 +43,17 @@ pub(crate) trait UnconstrainedTypeParameters {
         constrained
     }
 }
+
+/// Returns true if the type with the given [TypeId], or any of the types nested within it, is the
+/// [TypeParameter].
+pub(crate) fn type_uses_type_parameter(
+    engines: &Engines,
+    type_id: TypeId,
+    type_parameter: &TypeParameter,
+) -> bool {
+    let type_engine = engines.te();
+    let type_parameter_info = type_engine.get(type_parameter.type_id);
+    std::iter::once(type_id)
+        .chain(type_id.extract_inner_types(engines))
+        .any(|type_id| type_engine.get(type_id).eq(&type_parameter_info, engines))
+}
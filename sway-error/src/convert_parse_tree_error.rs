@@ -83,6 +83,19 @@ pub enum ConvertParseTreeError {
     RecursiveType { span: Span },
     #[error("enum variant \"{name}\" already declared")]
     DuplicateEnumVariant { name: Ident, span: Span },
+    #[error("enum variant \"{name}\" has the same discriminant {discriminant} as \"{other}\"")]
+    DuplicateEnumDiscriminant {
+        name: Ident,
+        other: Ident,
+        discriminant: u64,
+        span: Span,
+    },
+    #[error("enum discriminants must be integer literals that fit into a u64")]
+    InvalidEnumDiscriminant { span: Span },
+    #[error("the discriminant of enum variant \"{name}\" overflows a u64")]
+    EnumDiscriminantOverflow { name: Ident, span: Span },
+    #[error("struct field \"{name}\" cannot have a discriminant, only enum variants can")]
+    StructFieldDiscriminant { name: Ident, span: Span },
     #[error("storage field \"{name}\" already declared")]
     DuplicateStorageField { name: Ident, span: Span },
     #[error("configurable \"{name}\" already declared")]
@@ -97,6 +110,8 @@ pub enum ConvertParseTreeError {
     SelfParameterNotAllowedForFn { fn_kind: String, span: Span },
     #[error("test functions are only allowed at module level")]
     TestFnOnlyAllowedAtModuleLevel { span: Span },
+    #[error("functions annotated with `#[{attribute}]` must not be tests, must not have parameters or type parameters, and must return `()`")]
+    InvalidTestFixture { attribute: String, span: Span },
     #[error("`impl Self` for contracts is not supported")]
     SelfImplForContract { span: Span },
     #[error("Cannot attach a documentation comment to a dependency.")]
@@ -117,8 +132,8 @@ pub enum ConvertParseTreeError {
     InvalidCfgProgramTypeArgValue { span: Span, value: String },
     #[error("Expected a value for the program_type argument")]
     ExpectedCfgProgramTypeArgValue { span: Span },
-    #[error("Expected \"true\" or \"false\" for experimental_new_encoding")]
-    ExpectedExperimentalNewEncodingArgValue { span: Span },
+    #[error("Expected \"true\" or \"false\" for {name}")]
+    ExpectedExperimentalArgValue { span: Span, name: String },
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -164,6 +179,10 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::GetStorageKeyTooManyArgs { span, .. } => span.clone(),
             ConvertParseTreeError::RecursiveType { span } => span.clone(),
             ConvertParseTreeError::DuplicateEnumVariant { span, .. } => span.clone(),
+            ConvertParseTreeError::DuplicateEnumDiscriminant { span, .. } => span.clone(),
+            ConvertParseTreeError::InvalidEnumDiscriminant { span } => span.clone(),
+            ConvertParseTreeError::EnumDiscriminantOverflow { span, .. } => span.clone(),
+            ConvertParseTreeError::StructFieldDiscriminant { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStorageField { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateConfigurable { span, .. } => span.clone(),
             ConvertParseTreeError::MultipleConfigurableBlocksInModule { span } => span.clone(),
@@ -171,6 +190,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::DuplicateParameterIdentifier { span, .. } => span.clone(),
             ConvertParseTreeError::SelfParameterNotAllowedForFn { span, .. } => span.clone(),
             ConvertParseTreeError::TestFnOnlyAllowedAtModuleLevel { span } => span.clone(),
+            ConvertParseTreeError::InvalidTestFixture { span, .. } => span.clone(),
             ConvertParseTreeError::SelfImplForContract { span, .. } => span.clone(),
             ConvertParseTreeError::CannotDocCommentDependency { span } => span.clone(),
             ConvertParseTreeError::CannotAnnotateDependency { span } => span.clone(),
@@ -181,7 +201,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::ExpectedCfgTargetArgValue { span } => span.clone(),
             ConvertParseTreeError::InvalidCfgProgramTypeArgValue { span, .. } => span.clone(),
             ConvertParseTreeError::ExpectedCfgProgramTypeArgValue { span } => span.clone(),
-            ConvertParseTreeError::ExpectedExperimentalNewEncodingArgValue { span } => span.clone(),
+            ConvertParseTreeError::ExpectedExperimentalArgValue { span, .. } => span.clone(),
         }
     }
 }
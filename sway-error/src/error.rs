@@ -347,6 +347,9 @@ pub enum CompileError {
         ty: String,
         trait_name: String,
         span: Span,
+        /// The generic functions whose instantiation led to the constraint, from the innermost
+        /// to the outermost, along with the spans of the calls that instantiated them.
+        instantiations: Vec<(String, Span)>,
     },
     #[error(
         "Expects trait constraint \"{param}: {trait_name}\" which is missing from type parameter \"{param}\"."
@@ -470,6 +473,8 @@ pub enum CompileError {
     },
     #[error("Function {fn_name} is recursive, which is unsupported at this time.")]
     RecursiveCall { fn_name: Ident, span: Span },
+    #[error("This recursive call cannot be turned into a loop, as required by `#[tail_call]`, as its result is not returned as is, or it passes a reference to a local variable.")]
+    RecursiveCallNotInTailPosition { span: Span },
     #[error(
         "Function {fn_name} is recursive via {call_chain}, which is unsupported at this time."
     )]
@@ -523,6 +528,8 @@ pub enum CompileError {
         missing_patterns: String,
         span: Span,
     },
+    #[error("The `else` block of a `let` statement must diverge, i.e. return, revert, break, or continue.")]
+    LetElseDoesNotDiverge { span: Span },
     #[error("Pattern does not mention {}: {}",
         if missing_fields.len() == 1 { "field" } else { "fields" },
         missing_fields.join(", "))]
@@ -593,6 +600,10 @@ pub enum CompileError {
     ParameterRefMutabilityMismatch { span: Span },
     #[error("Literal value is too large for type {ty}.")]
     IntegerTooLarge { span: Span, ty: String },
+    #[error(
+        "Literal value {value} is too large for type {ty}, which was inferred from how it is used."
+    )]
+    InferredIntegerTooLarge { value: u64, ty: String, span: Span },
     #[error("Literal value underflows type {ty}.")]
     IntegerTooSmall { span: Span, ty: String },
     #[error("Literal value contains digits which are not valid for type {ty}.")]
@@ -645,6 +656,18 @@ pub enum CompileError {
     NonConstantDeclValue { span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
+    #[error("Only the methods of contract ABI implementations can be constructors.")]
+    ConstructorNotAbiMethod { span: Span },
+    #[error("A contract can only have one constructor, but \"{name}\" is also marked with `#[constructor]`.")]
+    MultipleConstructors { name: Ident, span: Span },
+    #[error("Constructor \"{name}\" must be marked with `#[storage(read, write)]`, as it records in storage that it has run.")]
+    ConstructorWithoutStorageAccess { name: Ident, span: Span },
+    #[error(
+        "Only the methods of contract ABI implementations can be marked with `#[when_not_paused]`."
+    )]
+    WhenNotPausedNotAbiMethod { span: Span },
+    #[error("Contract method \"{name}\" must be marked with `#[storage(read)]`, as `#[when_not_paused]` reads in storage whether the contract is paused.")]
+    WhenNotPausedWithoutStorageRead { name: Ident, span: Span },
     #[error("Unsupported argument type to intrinsic \"{name}\".{}", if hint.is_empty() { "".to_string() } else { format!(" Hint: {hint}") })]
     IntrinsicUnsupportedArgType {
         name: String,
@@ -687,9 +710,30 @@ pub enum CompileError {
     RefMutableNotAllowedInMain { param_name: Ident, span: Span },
     #[error(
         "Register \"{name}\" is initialized and later reassigned which is not allowed. \
-            Consider assigning to a different register inside the ASM block."
+            Consider assigning to a different register inside the ASM block. \
+            Alternatively, declare the register as clobbered with `clobbers({name})`."
     )]
     InitializedRegisterReassignment { name: String, span: Span },
+    #[error(
+        "Register \"{name}\" cannot be declared as clobbered. \
+            Only registers that are initialized with a value can be clobbered."
+    )]
+    InvalidAsmClobber { name: String, span: Span },
+    #[error(
+        "The {registers} registers returned by this ASM block must have a tuple type with \
+            {registers} elements that fit into registers, like `u64`, `bool` or `raw_ptr`, \
+            but the type is \"{ty}\"."
+    )]
+    InvalidAsmTupleOutputType {
+        ty: String,
+        registers: usize,
+        span: Span,
+    },
+    #[error(
+        "The value of the const operand \"{name}\" could not be evaluated at compile time. \
+            Const operands must be constant expressions."
+    )]
+    NonConstantAsmOperand { name: String, span: Span },
     #[error("Control flow VM instructions are not allowed in assembly blocks.")]
     DisallowedControlFlowInstruction { name: String, span: Span },
     #[error("Calling private library method {name} is not allowed.")]
@@ -755,6 +799,12 @@ pub enum CompileError {
     UninitRegisterInAsmBlockBeingRead { span: Span },
     #[error("Expression of type \"{expression_type}\" cannot be dereferenced.")]
     ExpressionCannotBeDereferenced { expression_type: String, span: Span },
+    #[error("{message} (lint `{name}` is denied)")]
+    Lint {
+        name: String,
+        message: String,
+        span: Span,
+    },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -864,6 +914,7 @@ impl Spanned for CompileError {
             IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
             ArgumentParameterTypeMismatch { span, .. } => span.clone(),
             RecursiveCall { span, .. } => span.clone(),
+            RecursiveCallNotInTailPosition { span } => span.clone(),
             RecursiveCallChain { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
             RecursiveTypeChain { span, .. } => span.clone(),
@@ -878,6 +929,7 @@ impl Spanned for CompileError {
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
+            LetElseDoesNotDiverge { span } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
             MatchArmVariableNotDefinedInAllAlternatives { variable, .. } => variable.span(),
             MatchArmVariableMismatchedType { variable, .. } => variable.span(),
@@ -900,6 +952,7 @@ impl Spanned for CompileError {
             ImpureInPureContext { span, .. } => span.clone(),
             ParameterRefMutabilityMismatch { span, .. } => span.clone(),
             IntegerTooLarge { span, .. } => span.clone(),
+            InferredIntegerTooLarge { span, .. } => span.clone(),
             IntegerTooSmall { span, .. } => span.clone(),
             IntegerContainsInvalidDigit { span, .. } => span.clone(),
             AbiAsSupertrait { span, .. } => span.clone(),
@@ -920,6 +973,11 @@ impl Spanned for CompileError {
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
+            ConstructorNotAbiMethod { span } => span.clone(),
+            MultipleConstructors { span, .. } => span.clone(),
+            ConstructorWithoutStorageAccess { span, .. } => span.clone(),
+            WhenNotPausedNotAbiMethod { span } => span.clone(),
+            WhenNotPausedWithoutStorageRead { span, .. } => span.clone(),
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
@@ -929,6 +987,9 @@ impl Spanned for CompileError {
             ContractIdValueNotALiteral { span } => span.clone(),
             RefMutableNotAllowedInMain { span, .. } => span.clone(),
             InitializedRegisterReassignment { span, .. } => span.clone(),
+            InvalidAsmClobber { span, .. } => span.clone(),
+            InvalidAsmTupleOutputType { span, .. } => span.clone(),
+            NonConstantAsmOperand { span, .. } => span.clone(),
             DisallowedControlFlowInstruction { span, .. } => span.clone(),
             CallingPrivateLibraryMethod { span, .. } => span.clone(),
             DisallowedIntrinsicInPredicate { span, .. } => span.clone(),
@@ -948,6 +1009,31 @@ impl Spanned for CompileError {
             SelfIsNotValidAsImplementingFor { span } => span.clone(),
             UninitRegisterInAsmBlockBeingRead { span } => span.clone(),
             ExpressionCannotBeDereferenced { span, .. } => span.clone(),
+            Lint { span, .. } => span.clone(),
+        }
+    }
+}
+
+impl CompileError {
+    /// Records that the error occurred while instantiating the generic function `name` at the
+    /// call at `span`. Only errors of unsatisfied trait constraints track their instantiations.
+    pub fn in_instantiation_of(self, name: String, span: Span) -> Self {
+        match self {
+            CompileError::TraitConstraintNotSatisfied {
+                ty,
+                trait_name,
+                span: constraint_span,
+                mut instantiations,
+            } => {
+                instantiations.push((name, span));
+                CompileError::TraitConstraintNotSatisfied {
+                    ty,
+                    trait_name,
+                    span: constraint_span,
+                    instantiations,
+                }
+            }
+            err => err,
         }
     }
 }
@@ -1253,6 +1339,34 @@ impl ToDiagnostic for CompileError {
                 ],
                 help: vec![],
             },
+            TraitConstraintNotSatisfied { ty, trait_name, span, instantiations } if !instantiations.is_empty() => Diagnostic {
+                reason: Some(Reason::new(code(1), "Trait constraint is not satisfied".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Trait \"{trait_name}\" is not implemented for type \"{ty}\".")
+                ),
+                hints: instantiations
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (name, call_span))| Hint::info(
+                        source_engine,
+                        call_span.clone(),
+                        if index == 0 {
+                            format!("The constraint is required by \"{name}\", which is instantiated here.")
+                        } else {
+                            format!("\"{}\" is called from \"{name}\", which is instantiated here.", instantiations[index - 1].0)
+                        }
+                    ))
+                    .collect(),
+                help: {
+                    let mut help = vec!["Instantiation backtrace, starting from the innermost function:".to_string()];
+                    for (name, _) in instantiations.iter() {
+                        help.push(format!("  - {name}"));
+                    }
+                    help
+                },
+            },
            _ => Diagnostic {
                     // TODO: Temporary we use self here to achieve backward compatibility.
                     //       In general, self must not be used and will not be used once we
@@ -42,6 +42,8 @@ pub enum ParseErrorKind {
     UnexpectedTokenAfterAsmReturnType,
     #[error("Malformed asm immediate value.")]
     MalformedAsmImmediate,
+    #[error("Expected a value for this asm const operand, like `const offset: 8`.")]
+    ExpectedAsmConstOperandValue,
     #[error("Expected an identifier.")]
     ExpectedIdent,
     #[error("Expected an pattern.")]
@@ -4,7 +4,13 @@ use core::fmt;
 
 use either::Either;
 
-use sway_types::{Ident, SourceId, Span, Spanned};
+use sway_types::{
+    constants::{
+        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, ALLOW_UNBOUNDED_LOOP_NAME,
+        ALLOW_UNUSED_TYPE_PARAMETERS_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
+    },
+    Ident, SourceId, Span, Spanned,
+};
 
 // TODO: since moving to using Idents instead of strings,
 // the warning_content will usually contain a duplicate of the span.
@@ -88,6 +94,8 @@ pub enum Warning {
     DeadStorageDeclaration,
     DeadStorageDeclarationForFunction {
         unneeded_attrib: String,
+        /// The attributes the function actually needs, empty if it does not access storage.
+        required_attrib: String,
     },
     MatchExpressionUnreachableArm {
         match_value: Span,
@@ -98,6 +106,11 @@ pub enum Warning {
         is_last_arm: bool,
         is_catch_all_arm: bool,
     },
+    /// The pattern of an `if let` expression or a `while let` loop matches all the values.
+    IrrefutableLetPattern {
+        pattern: Span,
+        match_type: String,
+    },
     UnrecognizedAttribute {
         attrib_name: Ident,
     },
@@ -121,6 +134,78 @@ pub enum Warning {
     UsingDeprecated {
         message: String,
     },
+    NumericLiteralDefaultedToU64 {
+        literal: String,
+        written_type: String,
+    },
+    UnusedFunctionTypeParameter {
+        name: Ident,
+    },
+    UnusedTypeParameter {
+        name: Ident,
+        decl_type: String,
+    },
+    Lint {
+        name: String,
+        message: String,
+    },
+    AuditFinding {
+        name: String,
+        message: String,
+    },
+    UnboundedLoop {
+        method_name: Ident,
+    },
+}
+
+impl Warning {
+    /// The name of the lint the warning belongs to, used to allow or deny the warning, e.g.
+    /// `dead_code`. The warnings that can be allowed with an `#[allow]` attribute belong to the
+    /// lint of the same name.
+    pub fn lint_name(&self) -> &str {
+        use Warning::*;
+        match self {
+            NonClassCaseStructName { .. }
+            | NonClassCaseTypeParameter { .. }
+            | NonClassCaseTraitName { .. }
+            | NonClassCaseEnumName { .. }
+            | NonClassCaseEnumVariantName { .. } => "non_camel_case_type",
+            NonSnakeCaseStructFieldName { .. } => "non_snake_case_field",
+            NonSnakeCaseFunctionName { .. } => "non_snake_case_function",
+            NonScreamingSnakeCaseConstName { .. } => "non_screaming_snake_case_const",
+            UnusedReturnValue { .. } => "unused_return_value",
+            SimilarMethodFound { .. } => "similar_method",
+            ShadowsOtherSymbol { .. }
+            | UninitializedAsmRegShadowsVariable { .. }
+            | ShadowingReservedRegister { .. } => "shadowing",
+            OverridingTraitImplementation => "overriding_trait_implementation",
+            DeadDeclaration
+            | DeadEnumDeclaration
+            | DeadFunctionDeclaration
+            | DeadStructDeclaration
+            | DeadTrait
+            | DeadEnumVariant { .. }
+            | DeadMethod
+            | StructFieldNeverRead
+            | DeadStorageDeclaration
+            | DeadStorageDeclarationForFunction { .. } => ALLOW_DEAD_CODE_NAME,
+            UnreachableCode => "unreachable_code",
+            MatchExpressionUnreachableArm { .. } => "unreachable_pattern",
+            IrrefutableLetPattern { .. } => "irrefutable_let_patterns",
+            UnrecognizedAttribute { .. }
+            | AttributeExpectedNumberOfArguments { .. }
+            | UnexpectedAttributeArgumentValue { .. } => "attributes",
+            EffectAfterInteraction { .. } => "effect_after_interaction",
+            ModulePrivacyDisabled => "module_privacy",
+            UsingDeprecated { .. } => ALLOW_DEPRECATED_NAME,
+            NumericLiteralDefaultedToU64 { .. } => "numeric_literal_default",
+            UnusedFunctionTypeParameter { .. } | UnusedTypeParameter { .. } => {
+                ALLOW_UNUSED_TYPE_PARAMETERS_NAME
+            }
+            Lint { name, .. } | AuditFinding { name, .. } => name,
+            UnboundedLoop { .. } => ALLOW_UNBOUNDED_LOOP_NAME,
+        }
+    }
 }
 
 impl fmt::Display for Warning {
@@ -232,12 +317,23 @@ impl fmt::Display for Warning {
                 f,
                 "This storage declaration is never accessed and can be removed."
             ),
-            DeadStorageDeclarationForFunction { unneeded_attrib } => write!(
+            DeadStorageDeclarationForFunction { unneeded_attrib, required_attrib } => write!(
                 f,
                 "This function's storage attributes declaration does not match its \
-                 actual storage access pattern: '{unneeded_attrib}' attribute(s) can be removed."
+                 actual storage access pattern: '{unneeded_attrib}' attribute(s) can be removed. {}",
+                if required_attrib.is_empty() {
+                    "The function does not access storage.".to_string()
+                } else {
+                    format!("The function only requires \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({required_attrib})]\".")
+                }
             ),
             MatchExpressionUnreachableArm { .. } => write!(f, "This match arm is unreachable."),
+            IrrefutableLetPattern { pattern, match_type } => write!(
+                f,
+                "The pattern `{}` matches all the values of type \"{match_type}\", so this \
+                 `let` condition always holds. Consider using a `let` statement instead.",
+                pattern.as_str()
+            ),
             UnrecognizedAttribute {attrib_name} => write!(f, "Unknown attribute: \"{attrib_name}\"."),
             AttributeExpectedNumberOfArguments {attrib_name, received_args, expected_min_len, expected_max_len } => write!(
                 f,
@@ -264,6 +360,33 @@ impl fmt::Display for Warning {
                                             You can enable the new behavior with the --experimental-private-modules flag, which will become the default behavior in a later release.
                                             More details are available in the related RFC: https://github.com/FuelLabs/sway-rfcs/blob/master/rfcs/0008-private-modules.md"),
             UsingDeprecated { message } => write!(f, "{}", message),
+            Lint { name, message } => write!(f, "{message} (lint `{name}`)"),
+            AuditFinding { name, message } => write!(f, "{message} (check `{name}`)"),
+            UnboundedLoop { method_name } => write!(
+                f,
+                "The number of iterations of this loop in contract method \"{method_name}\" is \
+                 controlled by its caller or by the size of a storage collection, so a caller can \
+                 make the method run out of gas. Consider bounding the number of iterations, or \
+                 allowing the loop with #[allow(unbounded_loop)] if it is bounded by other means."
+            ),
+            NumericLiteralDefaultedToU64 { literal, written_type } => write!(
+                f,
+                "Literal \"{literal}\" is written as a {written_type} value, but no type is inferred for it, \
+                 so it defaults to u64. Consider annotating the type it is expected to have."
+            ),
+            UnusedFunctionTypeParameter { name } => write!(
+                f,
+                "Type parameter \"{name}\" is never used, so it can never be inferred at a call \
+                 site. Consider removing it."
+            ),
+            UnusedTypeParameter { name, decl_type } => {
+                let member = if decl_type == "enum" { "variant" } else { "field" };
+                write!(
+                    f,
+                    "Type parameter \"{name}\" is not used by any {member} of this {decl_type}. \
+                     Consider removing it, or using it in a {member} of type \"PhantomData<{name}>\"."
+                )
+            }
         }
     }
 }
@@ -333,6 +333,65 @@ pub fn may_alias(context: &Context, val1: Value, len1: u64, val2: Value, len2: u
     false
 }
 
+/// Whether the memory pointed to by `a` and `b` may overlap.
+///
+/// Unlike [may_alias], this is conservative for pointers whose symbols can't be determined, and
+/// assumes that the arguments of the function, which may point anywhere but into its locals,
+/// may alias each other.
+pub fn pointers_may_alias(context: &Context, a: Value, b: Value) -> bool {
+    match (pointer_roots(context, a), pointer_roots(context, b)) {
+        (Some(a_roots), Some(b_roots)) => {
+            let is_arg = |root: &Symbol| matches!(root, Symbol::Arg(_));
+            !a_roots.is_disjoint(&b_roots)
+                || (a_roots.iter().any(is_arg) && b_roots.iter().any(is_arg))
+        }
+        _otherwise => true,
+    }
+}
+
+/// The local variables and function arguments the pointer may point into, or `None` if it may
+/// point anywhere.
+fn pointer_roots(context: &Context, ptr: Value) -> Option<FxHashSet<Symbol>> {
+    fn pointer_roots_rec(
+        context: &Context,
+        roots: &mut FxHashSet<Symbol>,
+        visited: &mut FxHashSet<Value>,
+        ptr: Value,
+    ) -> bool {
+        if !visited.insert(ptr) {
+            return true;
+        }
+        match &context.values[ptr.0].value {
+            ValueDatum::Instruction(Instruction {
+                op: InstOp::GetLocal(local),
+                ..
+            }) => {
+                roots.insert(Symbol::Local(*local));
+                true
+            }
+            ValueDatum::Instruction(Instruction {
+                op: InstOp::GetElemPtr { base, .. } | InstOp::CastPtr(base, _),
+                ..
+            }) => pointer_roots_rec(context, roots, visited, *base),
+            ValueDatum::Argument(arg)
+                if arg.block == arg.block.get_function(context).get_entry_block(context) =>
+            {
+                roots.insert(Symbol::Arg(*arg));
+                true
+            }
+            ValueDatum::Argument(arg) => arg.block.pred_iter(context).all(|pred| {
+                arg.get_val_coming_from(context, pred)
+                    .is_some_and(|val| pointer_roots_rec(context, roots, visited, val))
+            }),
+            _otherwise => false,
+        }
+    }
+
+    let mut roots = FxHashSet::default();
+    let mut visited = FxHashSet::default();
+    pointer_roots_rec(context, &mut roots, &mut visited, ptr).then_some(roots)
+}
+
 /// Are memory ranges [val1, val1+len1] and [val2, val2+len2] exactly the same?
 /// Conservatively returns false if cannot statically determine.
 pub fn must_alias(context: &Context, val1: Value, len1: u64, val2: Value, len2: u64) -> bool {
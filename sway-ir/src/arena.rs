@@ -0,0 +1,311 @@
+//! The arenas of a [`Context`](crate::Context), which can be forked.
+//!
+//! A fork of an arena reads the entries of the arena it was forked from, and keeps the entries it
+//! inserts, changes and removes to itself until it is joined back.  The forks of an arena can be
+//! used on different threads, each changing a different part of the IR, see
+//! [`Context::fork`](crate::Context::fork).
+
+use generational_arena::Index;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The generation of the indices of the entries inserted by forks, the number of the fork being
+/// added to it.  The generations of [`generational_arena::Arena`] never get that high, so the
+/// indices of the entries inserted by different forks are distinct from each other and from the
+/// indices of the arena they were forked from.
+const FORK_GENERATION: u64 = 1 << 63;
+
+/// The generation of the indices of the entries inserted by several forks, see
+/// [`shared_index`].
+const SHARED_GENERATION: u64 = u64::MAX;
+
+const SHARED_ARENA: &str = "Internal error. An arena is changed while it is forked.";
+
+/// Returns the index at `slot` of the entries inserted by several forks at the same index, with
+/// [`Arena::insert_at`].  It is never given out by [`Arena::insert`].
+pub(crate) fn shared_index(slot: usize) -> Index {
+    Index::from_raw_parts(slot, SHARED_GENERATION)
+}
+
+pub(crate) struct Arena<T> {
+    /// The entries inserted outside of forks.
+    base: Arc<generational_arena::Arena<T>>,
+    /// The entries inserted by the forks joined back into the arena.
+    forked: Arc<BTreeMap<Index, T>>,
+    /// The changes made to the arena, if it is a fork.
+    fork: Option<Fork<T>>,
+}
+
+struct Fork<T> {
+    generation: u64,
+    next_slot: usize,
+    /// The entries inserted or changed by the fork, or `None` if removed by it.
+    changes: BTreeMap<Index, Option<T>>,
+}
+
+/// The changes made by a fork of an [`Arena`], to be joined back into it.
+pub(crate) struct ForkChanges<T>(BTreeMap<Index, Option<T>>);
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self {
+            base: Arc::default(),
+            forked: Arc::default(),
+            fork: None,
+        }
+    }
+}
+
+impl<T: Clone> Arena<T> {
+    /// Forks the arena, as the fork numbered `fork`.  The numbers of all the forks ever made of an
+    /// arena must be distinct, so that the entries they insert are too.
+    pub(crate) fn fork(&self, fork: u64) -> Self {
+        assert!(self.fork.is_none(), "Internal error. A fork is forked.");
+        Self {
+            base: self.base.clone(),
+            forked: self.forked.clone(),
+            fork: Some(Fork {
+                generation: FORK_GENERATION + fork,
+                next_slot: 0,
+                changes: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Returns the changes made by the fork, dropping its view of the arena it was forked from.
+    pub(crate) fn into_changes(self) -> ForkChanges<T> {
+        ForkChanges(self.fork.map(|fork| fork.changes).unwrap_or_default())
+    }
+
+    /// Joins back the changes of forks, once all the forks are dropped.
+    ///
+    /// # Panics
+    ///
+    /// If two of the forks changed the same entry of the arena.
+    pub(crate) fn join(&mut self, forks: impl IntoIterator<Item = ForkChanges<T>>) {
+        let base = Arc::get_mut(&mut self.base).expect(SHARED_ARENA);
+        let forked = Arc::get_mut(&mut self.forked).expect(SHARED_ARENA);
+        let forks = forks.into_iter().collect::<Vec<_>>();
+
+        // The entries inserted by the forks are their own, except for the ones inserted at given
+        // indices, which are the same in all the forks inserting them.
+        let mut changed = BTreeMap::new();
+        for (fork, ForkChanges(changes)) in forks.iter().enumerate() {
+            for idx in changes.keys() {
+                if base.contains(*idx) || forked.contains_key(idx) {
+                    if let Some(other_fork) = changed.insert(*idx, fork) {
+                        panic!(
+                            "Internal error. Forks {other_fork} and {fork} changed the same \
+                             entry {idx:?}."
+                        );
+                    }
+                }
+            }
+        }
+
+        for ForkChanges(changes) in forks {
+            for (idx, entry) in changes {
+                match entry {
+                    Some(entry) if base.contains(idx) => base[idx] = entry,
+                    Some(entry) => {
+                        forked.insert(idx, entry);
+                    }
+                    None if base.contains(idx) => {
+                        base.remove(idx);
+                    }
+                    None => {
+                        forked.remove(&idx);
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn get(&self, idx: Index) -> Option<&T> {
+        match self.fork.as_ref().and_then(|fork| fork.changes.get(&idx)) {
+            Some(entry) => entry.as_ref(),
+            None => self.base.get(idx).or_else(|| self.forked.get(&idx)),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, idx: Index) -> Option<&mut T> {
+        match &mut self.fork {
+            Some(fork) => {
+                if !fork.changes.contains_key(&idx) {
+                    let entry = self.base.get(idx).or_else(|| self.forked.get(&idx))?;
+                    fork.changes.insert(idx, Some(entry.clone()));
+                }
+                fork.changes.get_mut(&idx)?.as_mut()
+            }
+            None => {
+                if self.base.contains(idx) {
+                    Arc::get_mut(&mut self.base)
+                        .expect(SHARED_ARENA)
+                        .get_mut(idx)
+                } else {
+                    Arc::get_mut(&mut self.forked)
+                        .expect(SHARED_ARENA)
+                        .get_mut(&idx)
+                }
+            }
+        }
+    }
+
+    pub(crate) fn contains(&self, idx: Index) -> bool {
+        self.get(idx).is_some()
+    }
+
+    pub(crate) fn insert(&mut self, entry: T) -> Index {
+        match &mut self.fork {
+            Some(fork) => {
+                let idx = Index::from_raw_parts(fork.next_slot, fork.generation);
+                fork.next_slot += 1;
+                fork.changes.insert(idx, Some(entry));
+                idx
+            }
+            None => Arc::get_mut(&mut self.base)
+                .expect(SHARED_ARENA)
+                .insert(entry),
+        }
+    }
+
+    /// Inserts `entry` at `idx`, a [`shared_index`] the other forks inserting it use too.
+    pub(crate) fn insert_at(&mut self, idx: Index, entry: T) {
+        let fork = self
+            .fork
+            .as_mut()
+            .expect("Internal error. Only forks insert at given indices.");
+        fork.changes.insert(idx, Some(entry));
+    }
+
+    pub(crate) fn remove(&mut self, idx: Index) -> Option<T> {
+        match &mut self.fork {
+            Some(fork) => match fork.changes.insert(idx, None) {
+                Some(entry) => entry,
+                None => self
+                    .base
+                    .get(idx)
+                    .or_else(|| self.forked.get(&idx))
+                    .cloned(),
+            },
+            None => {
+                if self.base.contains(idx) {
+                    Arc::get_mut(&mut self.base)
+                        .expect(SHARED_ARENA)
+                        .remove(idx)
+                } else {
+                    Arc::get_mut(&mut self.forked)
+                        .expect(SHARED_ARENA)
+                        .remove(&idx)
+                }
+            }
+        }
+    }
+
+    /// Iterates over the entries, in the order of their indices within the entries inserted
+    /// outside of forks, then within the entries inserted by forks.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Index, &T)> {
+        let changes = self.fork.as_ref().map(|fork| &fork.changes);
+        let unchanged =
+            move |idx: &Index| !changes.is_some_and(|changes| changes.contains_key(idx));
+        self.base
+            .iter()
+            .filter(move |(idx, _)| unchanged(idx))
+            .chain(
+                self.forked
+                    .iter()
+                    .map(|(idx, entry)| (*idx, entry))
+                    .filter(move |(idx, _)| unchanged(idx)),
+            )
+            .chain(changes.into_iter().flat_map(|changes| {
+                changes
+                    .iter()
+                    .filter_map(|(idx, entry)| entry.as_ref().map(|entry| (*idx, entry)))
+            }))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match &self.fork {
+            Some(_) => self.iter().count(),
+            None => self.base.len() + self.forked.len(),
+        }
+    }
+}
+
+impl<T: Clone> std::ops::Index<Index> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Index) -> &T {
+        self.get(idx).expect("No element at index")
+    }
+}
+
+impl<T: Clone> std::ops::IndexMut<Index> for Arena<T> {
+    fn index_mut(&mut self, idx: Index) -> &mut T {
+        self.get_mut(idx).expect("No element at index")
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a Arena<T> {
+    type Item = (Index, &'a T);
+    type IntoIter = Box<dyn Iterator<Item = (Index, &'a T)> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forks_keep_their_changes_until_joined() {
+        let mut arena = Arena::default();
+        let a = arena.insert("a");
+        let b = arena.insert("b");
+
+        let mut first = arena.fork(0);
+        let mut second = arena.fork(1);
+        first[a] = "a1";
+        let c = first.insert("c");
+        second.remove(b);
+        let d = second.insert("d");
+
+        assert_ne!(c, d);
+        assert_eq!(arena[a], "a");
+        assert_eq!(first[a], "a1");
+        assert_eq!(second[a], "a");
+        assert!(!second.contains(b));
+        assert!(!first.contains(d));
+        assert_eq!(
+            first.iter().map(|(_, entry)| *entry).collect::<Vec<_>>(),
+            ["b", "a1", "c"]
+        );
+
+        arena.join([first.into_changes(), second.into_changes()]);
+        assert_eq!(arena[a], "a1");
+        assert!(!arena.contains(b));
+        assert_eq!(arena[c], "c");
+        assert_eq!(arena[d], "d");
+        assert_eq!(arena.len(), 3);
+
+        arena[c] = "c1";
+        assert_eq!(arena.remove(d), Some("d"));
+        assert_eq!(
+            arena.iter().map(|(_, entry)| *entry).collect::<Vec<_>>(),
+            ["a1", "c1"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "changed the same entry")]
+    fn forks_changing_the_same_entry_do_not_join() {
+        let mut arena = Arena::default();
+        let a = arena.insert(0);
+        let mut first = arena.fork(0);
+        let mut second = arena.fork(1);
+        first[a] = 1;
+        second[a] = 2;
+        arena.join([first.into_changes(), second.into_changes()]);
+    }
+}
@@ -28,6 +28,7 @@ use crate::{
 pub struct Block(pub generational_arena::Index);
 
 #[doc(hidden)]
+#[derive(Clone)]
 pub struct BlockContent {
     /// Block label, useful for printing.
     pub label: Label,
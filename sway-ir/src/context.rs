@@ -2,18 +2,24 @@
 //!
 //! [`Context`] contains several
 //! [generational_arena](https://github.com/fitzgen/generational-arena) collections to maintain the
-//! IR ECS.
+//! IR ECS.  They can be forked, so that the functions of a module are transformed in parallel, see
+//! [`Context::fork`].
 //!
 //! It is passed around as a mutable reference to many of the Sway-IR APIs.
 
-use generational_arena::Arena;
-use rustc_hash::FxHashMap;
+use std::{
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use sway_types::SourceEngine;
 
+use crate::arena::{shared_index, Arena};
 use crate::{
     block::BlockContent, function::FunctionContent, local_var::LocalVarContent,
     metadata::Metadatum, module::Kind, module::ModuleContent, module::ModuleIterator,
-    value::ValueContent, Type, TypeContent,
+    profile::ExecutionProfile, value::ValueContent, EliminatedFunctions, Type, TypeContent,
 };
 
 /// The main IR context handle.
@@ -31,15 +37,28 @@ pub struct Context<'eng> {
     pub(crate) types: Arena<TypeContent>,
     pub(crate) type_map: FxHashMap<TypeContent, Type>,
     pub(crate) metadata: Arena<Metadatum>,
+    /// The types interned by the forks of a context, if this is one of them.
+    pub(crate) forked_types: Option<Arc<Mutex<ForkedTypes>>>,
 
     pub program_kind: Kind,
 
     next_unique_sym_tag: u64,
+    /// The number of the next fork of the context.
+    next_fork: u64,
 
     pub experimental: ExperimentalFlags,
+
+    /// The execution profile of the program, guiding the optimizations if present.
+    pub profile: Option<Arc<ExecutionProfile>>,
+
+    /// The functions removed so far for not being reachable from the entry points.
+    pub eliminated_functions: EliminatedFunctions,
+    /// The number of instructions the bytecode may grow by when inlining functions, unlimited if
+    /// `None`.
+    pub inline_budget: Option<u64>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ExperimentalFlags {
     pub new_encoding: bool,
 }
@@ -56,9 +75,14 @@ impl<'eng> Context<'eng> {
             types: Default::default(),
             type_map: Default::default(),
             metadata: Default::default(),
+            forked_types: None,
             next_unique_sym_tag: Default::default(),
+            next_fork: 0,
             program_kind: Kind::Contract,
             experimental,
+            profile: None,
+            eliminated_functions: EliminatedFunctions::default(),
+            inline_budget: None,
         };
         Type::create_basic_types(&mut def);
         def
@@ -86,6 +110,120 @@ impl<'eng> Context<'eng> {
         self.next_unique_sym_tag += 1;
         sym
     }
+
+    /// Forks the context `count` times, for each fork to change a different function.
+    ///
+    /// The forks read the IR of the context and keep their changes to themselves, until they are
+    /// joined back in the same order with [`Context::join`].  They give out their own ids to the
+    /// values, blocks, locals and metadata they insert, so they can be used on different threads.
+    /// The types they create are the same in all of them though, see [`ForkedTypes`].
+    pub(crate) fn fork(&mut self, count: usize) -> Vec<Context<'eng>> {
+        let forked_types = Arc::new(Mutex::new(ForkedTypes::default()));
+        (0..count as u64)
+            .map(|i| {
+                let fork = self.next_fork;
+                self.next_fork += 1;
+                Context {
+                    source_engine: self.source_engine,
+                    modules: self.modules.fork(fork),
+                    functions: self.functions.fork(fork),
+                    blocks: self.blocks.fork(fork),
+                    values: self.values.fork(fork),
+                    local_vars: self.local_vars.fork(fork),
+                    types: self.types.fork(fork),
+                    type_map: self.type_map.clone(),
+                    metadata: self.metadata.fork(fork),
+                    forked_types: Some(forked_types.clone()),
+                    program_kind: self.program_kind,
+                    next_unique_sym_tag: self.next_unique_sym_tag + (i << 32),
+                    next_fork: 0,
+                    experimental: self.experimental.clone(),
+                    profile: self.profile.clone(),
+                    eliminated_functions: self.eliminated_functions,
+                    inline_budget: self.inline_budget,
+                }
+            })
+            .collect()
+    }
+
+    /// Joins back the changes of the `forks` of the context, made with [`Context::fork`].
+    ///
+    /// # Panics
+    ///
+    /// If two of the forks changed the same module, function, block, value, local or metadata.
+    pub(crate) fn join(&mut self, forks: Vec<Context<'eng>>) {
+        let mut modules = Vec::with_capacity(forks.len());
+        let mut functions = Vec::with_capacity(forks.len());
+        let mut blocks = Vec::with_capacity(forks.len());
+        let mut values = Vec::with_capacity(forks.len());
+        let mut local_vars = Vec::with_capacity(forks.len());
+        let mut types = Vec::with_capacity(forks.len());
+        let mut metadata = Vec::with_capacity(forks.len());
+        let mut forked_types = None;
+        let mut next_unique_sym_tag = self.next_unique_sym_tag;
+        for (i, fork) in forks.into_iter().enumerate() {
+            modules.push(fork.modules.into_changes());
+            functions.push(fork.functions.into_changes());
+            blocks.push(fork.blocks.into_changes());
+            values.push(fork.values.into_changes());
+            local_vars.push(fork.local_vars.into_changes());
+            types.push(fork.types.into_changes());
+            metadata.push(fork.metadata.into_changes());
+            forked_types = fork.forked_types;
+            // Only the forks which gave out ids move the ids of the context on.
+            if fork.next_unique_sym_tag > self.next_unique_sym_tag + ((i as u64) << 32) {
+                next_unique_sym_tag = next_unique_sym_tag.max(fork.next_unique_sym_tag);
+            }
+        }
+        self.modules.join(modules);
+        self.functions.join(functions);
+        self.blocks.join(blocks);
+        self.values.join(values);
+        self.local_vars.join(local_vars);
+        self.types.join(types);
+        self.metadata.join(metadata);
+        if let Some(forked_types) = forked_types {
+            let forked_types = forked_types
+                .lock()
+                .expect("Internal error. A fork panicked while creating a type.");
+            self.type_map.extend(
+                forked_types
+                    .types
+                    .iter()
+                    .map(|(content, ty)| (content.clone(), *ty)),
+            );
+        }
+        self.next_unique_sym_tag = next_unique_sym_tag;
+    }
+}
+
+/// The types created by the forks of a context.
+///
+/// A type created by a fork is created by every other fork creating it too, at an index derived
+/// from its content rather than from the order the forks created their types in.  The IR of the
+/// forks joined back is then the same whatever the number of threads they were transformed on.
+#[derive(Default)]
+pub(crate) struct ForkedTypes {
+    types: FxHashMap<TypeContent, Type>,
+    slots: FxHashSet<usize>,
+}
+
+impl ForkedTypes {
+    /// Returns the type with the `content`, creating it if no fork has.
+    pub(crate) fn intern(&mut self, content: &TypeContent) -> Type {
+        if let Some(ty) = self.types.get(content) {
+            return *ty;
+        }
+        let mut hasher = FxHasher::default();
+        content.hash(&mut hasher);
+        let mut slot = hasher.finish() as usize;
+        while !self.slots.insert(slot) {
+            slot = slot.wrapping_add(1);
+        }
+        let ty = Type(shared_index(slot));
+        self.types.insert(content.clone(), ty);
+        ty
+    }
 }
 
 use std::fmt::{Display, Error, Formatter};
@@ -101,3 +239,58 @@ impl From<Context<'_>> for String {
         crate::printer::to_string(&context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn forks_transform_functions_apart() {
+        let source_engine = SourceEngine::default();
+        let mut context = parse(
+            "script {
+                entry fn main() -> u64 {
+                    entry():
+                }
+
+                fn other() -> u64 {
+                    entry():
+                }
+            }",
+            &source_engine,
+            ExperimentalFlags::default(),
+        )
+        .unwrap();
+        let functions = context
+            .module_iter()
+            .flat_map(|module| module.function_iter(&context))
+            .collect::<Vec<_>>();
+
+        let mut forks = context.fork(functions.len());
+        let created = forks
+            .iter_mut()
+            .zip(&functions)
+            .map(|(fork, function)| {
+                let u64_ty = Type::get_uint64(fork);
+                let array_ty = Type::new_array(fork, u64_ty, 7);
+                let block = function.create_block(fork, None);
+                (array_ty, block)
+            })
+            .collect::<Vec<_>>();
+
+        // The forks agree on the types they create, but not on the blocks.
+        assert_eq!(created[0].0, created[1].0);
+        assert_ne!(created[0].1, created[1].1);
+        assert!(!forks[1].blocks.contains(created[0].1 .0));
+        assert_eq!(functions[0].num_blocks(&context), 1);
+
+        context.join(forks);
+        let u64_ty = Type::get_uint64(&context);
+        assert_eq!(Type::new_array(&mut context, u64_ty, 7), created[0].0);
+        for (function, (_, block)) in functions.iter().zip(created) {
+            assert_eq!(block.get_function(&context), *function);
+            assert_eq!(function.block_iter(&context).last(), Some(block));
+        }
+    }
+}
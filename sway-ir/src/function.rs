@@ -30,6 +30,7 @@ use crate::{
 pub struct Function(pub generational_arena::Index);
 
 #[doc(hidden)]
+#[derive(Clone)]
 pub struct FunctionContent {
     pub name: String,
     pub arguments: Vec<(String, Value)>,
@@ -31,15 +31,22 @@ pub enum TypeContent {
 
 impl Type {
     fn get_or_create_unique_type(context: &mut Context, t: TypeContent) -> Type {
-        // Trying to avoiding cloning t unless we're creating a new type.
-        #[allow(clippy::map_entry)]
-        if !context.type_map.contains_key(&t) {
-            let new_type = Type(context.types.insert(t.clone()));
-            context.type_map.insert(t, new_type);
-            new_type
-        } else {
-            context.type_map.get(&t).copied().unwrap()
+        if let Some(ty) = context.type_map.get(&t) {
+            return *ty;
         }
+        let new_type = match &context.forked_types {
+            Some(forked_types) => {
+                let new_type = forked_types
+                    .lock()
+                    .expect("Internal error. A fork panicked while creating a type.")
+                    .intern(&t);
+                context.types.insert_at(new_type.0, t.clone());
+                new_type
+            }
+            None => Type(context.types.insert(t.clone())),
+        };
+        context.type_map.insert(t, new_type);
+        new_type
     }
 
     /// Get Type if it already exists.
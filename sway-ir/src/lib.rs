@@ -39,6 +39,7 @@
 
 pub mod analysis;
 pub use analysis::*;
+mod arena;
 pub mod asm;
 pub use asm::*;
 pub mod block;
@@ -71,6 +72,8 @@ pub mod pretty;
 pub use pretty::*;
 pub mod printer;
 pub use printer::*;
+pub mod profile;
+pub use profile::*;
 pub mod value;
 pub use value::*;
 pub mod verify;
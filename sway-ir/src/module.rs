@@ -16,6 +16,7 @@ use crate::{
 pub struct Module(pub generational_arena::Index);
 
 #[doc(hidden)]
+#[derive(Clone)]
 pub struct ModuleContent {
     pub kind: Kind,
     pub functions: Vec<Function>,
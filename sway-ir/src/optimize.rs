@@ -13,16 +13,22 @@
 //! over blocks or instructions can be invalidated, and starting over is a safer option than trying
 //! to attempt multiple changes at once.
 
+pub mod alloc_demotion;
+pub use alloc_demotion::*;
 pub mod arg_demotion;
 pub use arg_demotion::*;
 pub mod const_demotion;
 pub use const_demotion::*;
 pub mod constants;
 pub use constants::*;
+pub mod cse;
+pub use cse::*;
 pub mod dce;
 pub use dce::*;
 pub mod inline;
 pub use inline::*;
+pub mod licm;
+pub use licm::*;
 pub mod mem2reg;
 pub use mem2reg::*;
 pub mod memcpyopt;
@@ -31,10 +37,18 @@ pub mod misc_demotion;
 pub use misc_demotion::*;
 pub mod ret_demotion;
 pub use ret_demotion::*;
+pub mod sccp;
+pub use sccp::*;
 pub mod simplify_cfg;
 pub use simplify_cfg::*;
 pub mod sroa;
 pub use sroa::*;
+pub mod storage_coalesce;
+pub use storage_coalesce::*;
+pub mod storage_dse;
+pub use storage_dse::*;
+pub mod tail_call;
+pub use tail_call::*;
 pub mod fn_dedup;
 pub use fn_dedup::*;
 
@@ -0,0 +1,349 @@
+//! ## Heap Allocation Demotion
+//!
+//! Heap allocations of a constant size, whose pointers never escape the function, are demoted to
+//! local variables on the stack.  The heap only ever grows, so this saves both the memory and the
+//! gas of allocating it, typically for the buffers of `Vec` and `Bytes` temporaries.
+//!
+//! An allocation is the asm block `alloc` and `alloc_bytes` inline to, i.e. `aloc` followed by
+//! `move` of `hp` into the returned register.  Its pointer, and the pointers derived from it by
+//! pointer arithmetic and casts, escape unless they are only:
+//! - loaded from or stored to, or copied from or to;
+//! - compared;
+//! - stored to local variables which don't escape themselves, and which are only loaded from, or
+//!   copied to other such local variables;
+//! - passed to asm blocks only accessing memory through them, or computing other pointers.
+//!
+//! The allocation mustn't be in a loop, as the same local variable would then be reused by every
+//! iteration.  It's replaced by an asm block clearing the local variable, as `aloc` clears the
+//! memory it allocates, and returning its address.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use sway_types::ident::Ident;
+
+use crate::{
+    asm::{AsmArg, AsmBlock, AsmInstruction},
+    get_loaded_ptr_values, get_memory_offsets, get_symbol, get_symbols, pointee_size,
+    AnalysisResults, BinaryOpKind, Block, ConstantValue, Context, EscapedSymbols, Function, InstOp,
+    Instruction, InstructionInserter, IrError, Pass, PassMutability, ScopedPass, Symbol, Type,
+    Value, ESCAPED_SYMBOLS_NAME,
+};
+
+pub const ALLOCDEMOTION_NAME: &str = "allocdemotion";
+
+pub fn create_alloc_demotion_pass() -> Pass {
+    Pass {
+        name: ALLOCDEMOTION_NAME,
+        descr: "Demotion of non-escaping heap allocations to the stack.",
+        deps: vec![ESCAPED_SYMBOLS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(alloc_demotion)),
+    }
+}
+
+/// The size of the largest allocation demoted, in bytes, to keep stack frames reasonably small.
+const MAX_DEMOTED_ALLOC_SIZE: u64 = 1024;
+
+pub fn alloc_demotion(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let escaped_symbols: &EscapedSymbols = analyses.get_analysis_result(function);
+
+    let mut users: FxHashMap<Value, Vec<Value>> = FxHashMap::default();
+    for (_block, inst) in function.instruction_iter(context) {
+        for operand in inst.get_instruction(context).unwrap().op.get_operands() {
+            users.entry(operand).or_default().push(inst);
+        }
+    }
+
+    let candidates: Vec<(Block, Value, u64)> = function
+        .instruction_iter(context)
+        .filter_map(|(block, inst)| {
+            let size = get_alloc_size(context, inst)?;
+            (size > 0
+                && size <= MAX_DEMOTED_ALLOC_SIZE
+                && !is_in_loop(context, block)
+                && !escapes(context, escaped_symbols, &users, function, inst))
+            .then_some((block, inst, size))
+        })
+        .collect();
+
+    let modified = !candidates.is_empty();
+    for (block, inst, size) in candidates {
+        demote(context, function, block, inst, size);
+    }
+    Ok(modified)
+}
+
+/// The constant size of the allocation, if the instruction is one.
+fn get_alloc_size(context: &Context, inst: Value) -> Option<u64> {
+    let InstOp::AsmBlock(asm, args) = &inst.get_instruction(context)?.op else {
+        return None;
+    };
+    let [aloc, mov] = asm.body.as_slice() else {
+        return None;
+    };
+    let return_name = asm.return_name.as_ref()?;
+    let is_alloc = aloc.op_name.as_str() == "aloc"
+        && aloc.args.len() == 1
+        && mov.op_name.as_str() == "move"
+        && matches!(mov.args.as_slice(), [dst, src] if dst == return_name && src.as_str() == "hp");
+    if !is_alloc {
+        return None;
+    }
+
+    let size = args
+        .iter()
+        .find(|arg| arg.name == aloc.args[0])?
+        .initializer?
+        .get_constant(context)?;
+    match size.value {
+        ConstantValue::Uint(size) => Some(size),
+        _ => None,
+    }
+}
+
+/// Whether the block may be executed more than once, i.e. may be reached from itself.
+fn is_in_loop(context: &Context, block: Block) -> bool {
+    let mut visited = FxHashSet::default();
+    let mut worklist: Vec<Block> = block
+        .successors(context)
+        .into_iter()
+        .map(|succ| succ.block)
+        .collect();
+    while let Some(next) = worklist.pop() {
+        if next == block {
+            return true;
+        }
+        if visited.insert(next) {
+            worklist.extend(next.successors(context).into_iter().map(|succ| succ.block));
+        }
+    }
+    false
+}
+
+/// Whether the pointer returned by the allocation may escape, or outlive the function.
+fn escapes(
+    context: &Context,
+    escaped_symbols: &EscapedSymbols,
+    users: &FxHashMap<Value, Vec<Value>>,
+    function: Function,
+    alloc: Value,
+) -> bool {
+    // The values holding, or derived from, the pointer, and the memory it's stored to.
+    let mut derived = FxHashSet::default();
+    let mut holders = Vec::new();
+    let mut worklist = vec![alloc];
+    derived.insert(alloc);
+
+    loop {
+        while let Some(value) = worklist.pop() {
+            for user in users.get(&value).into_iter().flatten() {
+                let Some(Instruction { op, .. }) = user.get_instruction(context) else {
+                    return true;
+                };
+                let derives = match op {
+                    InstOp::BinaryOp {
+                        op: BinaryOpKind::Add | BinaryOpKind::Sub,
+                        ..
+                    }
+                    | InstOp::IntToPtr(..)
+                    | InstOp::CastPtr(..)
+                    | InstOp::PtrToInt(..) => true,
+                    InstOp::GetElemPtr { base, .. } if *base == value => true,
+                    InstOp::Cmp(..)
+                    | InstOp::Load(_)
+                    | InstOp::MemCopyBytes { .. }
+                    | InstOp::MemCopyVal { .. } => false,
+                    InstOp::Store {
+                        dst_val_ptr,
+                        stored_val,
+                    } if *stored_val == value => {
+                        match get_holder(context, escaped_symbols, *dst_val_ptr, true) {
+                            Some(holder) => {
+                                if !holders.contains(&holder) {
+                                    holders.push(holder);
+                                }
+                                false
+                            }
+                            None => return true,
+                        }
+                    }
+                    InstOp::Store { .. } => false,
+                    InstOp::AsmBlock(asm, args) => {
+                        match asm_derives(asm, args, value) {
+                            // Only registers are returned, so derived values returned must be words.
+                            Some(true) if asm.return_type.is_uint64(context) => true,
+                            Some(false) => false,
+                            _ => return true,
+                        }
+                    }
+                    _ => return true,
+                };
+                if derives && derived.insert(*user) {
+                    worklist.push(*user);
+                }
+            }
+        }
+
+        // The values loaded from the local variables holding the pointer may be the pointer too.
+        let num_holders = holders.len();
+        for (_block, inst) in function.instruction_iter(context) {
+            for ptr in get_loaded_ptr_values(context, inst) {
+                let op = &inst.get_instruction(context).unwrap().op;
+                if !may_hold(context, &holders, ptr, matches!(op, InstOp::Load(_))) {
+                    continue;
+                }
+                match op {
+                    InstOp::Load(_) => {
+                        if derived.insert(inst) {
+                            worklist.push(inst);
+                        }
+                    }
+                    InstOp::MemCopyBytes { dst_val_ptr, .. }
+                    | InstOp::MemCopyVal { dst_val_ptr, .. } => {
+                        match get_holder(context, escaped_symbols, *dst_val_ptr, false) {
+                            Some(holder) => {
+                                if !holders.contains(&holder) {
+                                    holders.push(holder);
+                                }
+                            }
+                            None => return true,
+                        }
+                    }
+                    _ => return true,
+                }
+            }
+        }
+        if worklist.is_empty() && holders.len() == num_holders {
+            return false;
+        }
+    }
+}
+
+/// Memory of a local variable holding the pointer: the range of bytes stored to, if known.
+type Holder = (Symbol, Option<(u64, u64)>);
+
+/// The memory `ptr` points to, if it's in a local variable which doesn't escape and which can
+/// thus hold the pointer.  Only the memory `ptr` points to is held if `is_precise`, otherwise all
+/// of the local variable.
+fn get_holder(
+    context: &Context,
+    escaped_symbols: &EscapedSymbols,
+    ptr: Value,
+    is_precise: bool,
+) -> Option<Holder> {
+    let symbol = get_symbol(context, ptr)
+        .filter(|symbol| matches!(symbol, Symbol::Local(_)) && !escaped_symbols.contains(symbol))?;
+    let range = is_precise
+        .then(|| get_memory_offsets(context, ptr).get(&symbol).copied())
+        .flatten()
+        .map(|offset| (offset, offset + pointee_size(context, ptr)));
+    Some((symbol, range))
+}
+
+/// Whether the memory `ptr` points to may hold the pointer.  Only the memory `ptr` points to is
+/// considered if `is_precise`, otherwise all of the local variables it may point into.
+fn may_hold(context: &Context, holders: &[Holder], ptr: Value, is_precise: bool) -> bool {
+    let symbols = get_symbols(context, ptr);
+    let offsets = get_memory_offsets(context, ptr);
+    holders
+        .iter()
+        .filter(|(symbol, _)| symbols.contains(symbol))
+        .any(|(symbol, range)| match (range, offsets.get(symbol)) {
+            (Some((start, end)), Some(offset)) if is_precise => {
+                *offset < *end && *start < *offset + pointee_size(context, ptr)
+            }
+            _ => true,
+        })
+}
+
+/// Whether the asm block returns a value derived from `value`, or `None` if `value` may escape
+/// through it.
+fn asm_derives(asm: &AsmBlock, args: &[AsmArg], value: Value) -> Option<bool> {
+    let mut derived: FxHashSet<&str> = args
+        .iter()
+        .filter(|arg| arg.initializer == Some(value))
+        .map(|arg| arg.name.as_str())
+        .collect();
+
+    for AsmInstruction {
+        op_name,
+        args: regs,
+        ..
+    } in &asm.body
+    {
+        let regs: Vec<&str> = regs.iter().map(|reg| reg.as_str()).collect();
+        let is_derived = |idx: usize| regs.get(idx).is_some_and(|reg| derived.contains(reg));
+        // The operands which may be pointers, and whether the first one is written to rather than
+        // read from.
+        let (ptr_operands, writes_first): (&[usize], bool) = match op_name.as_str() {
+            "add" | "addi" | "sub" | "subi" | "move" => {
+                if let Some((dst, srcs)) = regs.split_first() {
+                    if srcs.iter().any(|src| derived.contains(src)) {
+                        derived.insert(*dst);
+                    } else {
+                        derived.remove(dst);
+                    }
+                }
+                continue;
+            }
+            "lw" | "lb" => (&[1], true),
+            "sw" | "sb" => (&[0], false),
+            "eq" | "lt" | "gt" => (&[1, 2], true),
+            "meq" => (&[1, 2], true),
+            "mcp" | "mcpi" | "s256" | "k256" => (&[0, 1], false),
+            "mcl" | "mcli" => (&[0], false),
+            _ => (&[], false),
+        };
+        let first_read = usize::from(writes_first);
+        if (first_read..regs.len()).any(|idx| is_derived(idx) && !ptr_operands.contains(&idx)) {
+            return None;
+        }
+        if let Some(dst) = regs.first().filter(|_| writes_first) {
+            derived.remove(dst);
+        }
+    }
+
+    Some(
+        asm.return_name
+            .as_ref()
+            .is_some_and(|name| derived.contains(name.as_str())),
+    )
+}
+
+/// Replaces the allocation by clearing a new local variable and returning its address.
+fn demote(context: &mut Context, function: Function, block: Block, alloc: Value, size: u64) {
+    let u8_ty = Type::get_uint8(context);
+    let local_ty = Type::new_array(context, u8_ty, size);
+    let local = function.new_unique_local_var(context, "__alloc".to_owned(), local_ty, None, true);
+    let get_local = Value::new_instruction(context, block, InstOp::GetLocal(local));
+    let u64_ty = Type::get_uint64(context);
+    let addr = Value::new_instruction(context, block, InstOp::PtrToInt(get_local, u64_ty));
+    InstructionInserter::new(context, block, crate::InsertionPosition::Before(alloc))
+        .insert_slice(&[get_local, addr]);
+
+    let Some(Instruction {
+        op: InstOp::AsmBlock(asm, args),
+        ..
+    }) = alloc.get_instruction_mut(context)
+    else {
+        unreachable!("Allocations are asm blocks.");
+    };
+    let size_reg = asm.body[0].args[0].clone();
+    let addr_reg = asm.return_name.clone().unwrap();
+    let metadata = asm.body[0].metadata;
+
+    args.retain(|arg| arg.name != addr_reg);
+    args.push(AsmArg {
+        name: addr_reg.clone(),
+        initializer: Some(addr),
+    });
+    asm.args_names = args.iter().map(|arg| arg.name.clone()).collect();
+    asm.body = vec![AsmInstruction {
+        op_name: Ident::new_no_span("mcl".to_owned()),
+        args: vec![addr_reg, size_reg],
+        immediate: None,
+        metadata,
+    }];
+}
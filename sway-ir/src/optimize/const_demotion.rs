@@ -21,7 +21,7 @@ pub fn create_const_demotion_pass() -> Pass {
         name: CONSTDEMOTION_NAME,
         descr: "By-value constant demotion to by-reference.",
         deps: Vec::new(),
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(const_demotion)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(const_demotion)),
     }
 }
 
@@ -0,0 +1,234 @@
+//! ## Common Subexpression Elimination
+//!
+//! Instructions computing a value already computed by an instruction which dominates them are
+//! removed, and their uses replaced with the earlier value.  The dominator tree is walked from
+//! the entry block, keeping the values available in the current block and the blocks dominating
+//! it, keyed by their operation and operands.
+//!
+//! Loads are handled separately, and only within a block, as the memory they read may be written
+//! in between.  A load is replaced by an earlier load from, or store to, the same pointer, unless
+//! the memory it points to may have been written since.  Pointers into distinct local variables
+//! never alias, and neither do the arguments of the function and its local variables.
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    pointers_may_alias, AnalysisResults, BinaryOpKind, Block, Context, DomTree, FuelVmInstruction,
+    Function, InstOp, Instruction, IrError, LocalVar, Pass, PassMutability, Predicate, ScopedPass,
+    Type, UnaryOpKind, Value, ValueDatum, DOMINATORS_NAME,
+};
+
+pub const CSE_NAME: &str = "cse";
+
+pub fn create_cse_pass() -> Pass {
+    Pass {
+        name: CSE_NAME,
+        descr: "Common subexpression elimination.",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(cse)),
+    }
+}
+
+/// The operation and operands of a side-effect-free instruction.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Expr {
+    UnaryOp(UnaryOpKind, Value),
+    BinaryOp(BinaryOpKind, Value, Value),
+    BitCast(Value, Type),
+    CastPtr(Value, Type),
+    Cmp(Predicate, Value, Value),
+    GetElemPtr(Value, Type, Vec<Value>),
+    GetLocal(LocalVar),
+    IntToPtr(Value, Type),
+    PtrToInt(Value, Type),
+    Gtf(Value, u64),
+}
+
+pub fn cse(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+
+    let mut numbering = ValueNumbering::default();
+    let mut available = FxHashMap::<Expr, Value>::default();
+
+    // Walk the dominator tree depth first, removing the values of a block from the available
+    // ones once all the blocks it dominates have been visited.
+    enum Visit {
+        Enter(Block),
+        Exit(Vec<Expr>),
+    }
+    let mut worklist = vec![Visit::Enter(function.get_entry_block(context))];
+    while let Some(visit) = worklist.pop() {
+        match visit {
+            Visit::Enter(block) => {
+                let added = numbering.number_block(context, block, &mut available);
+                worklist.push(Visit::Exit(added));
+                worklist.extend(
+                    dom_tree[&block]
+                        .children
+                        .iter()
+                        .map(|child| Visit::Enter(*child)),
+                );
+            }
+            Visit::Exit(added) => {
+                for expr in added {
+                    available.remove(&expr);
+                }
+            }
+        }
+    }
+
+    if numbering.replacements.is_empty() {
+        return Ok(false);
+    }
+    function.replace_values(context, &numbering.replacements, None);
+    for (block, inst) in numbering.removed {
+        block.remove_instruction(context, inst);
+    }
+    Ok(true)
+}
+
+#[derive(Default)]
+struct ValueNumbering {
+    /// The constants seen so far, each equal constant being replaced with the first one.
+    constants: Vec<Value>,
+    /// The redundant values, mapped to the values replacing them.
+    replacements: FxHashMap<Value, Value>,
+    /// The redundant instructions, to be removed.
+    removed: Vec<(Block, Value)>,
+}
+
+impl ValueNumbering {
+    /// Numbers the instructions of the block, returning the expressions it makes available.
+    fn number_block(
+        &mut self,
+        context: &Context,
+        block: Block,
+        available: &mut FxHashMap<Expr, Value>,
+    ) -> Vec<Expr> {
+        let mut added = Vec::new();
+        // The values loaded from, or stored to, each pointer since it was last possibly written.
+        let mut memory = FxHashMap::<Value, Value>::default();
+
+        for inst in block.instruction_iter(context) {
+            let Some(Instruction { op, .. }) = inst.get_instruction(context) else {
+                continue;
+            };
+            match op {
+                InstOp::Load(ptr) => {
+                    let ptr = self.canonical(context, *ptr);
+                    match memory.get(&ptr) {
+                        Some(val) if val.get_type(context) == inst.get_type(context) => {
+                            self.replace(block, inst, *val);
+                        }
+                        _ => {
+                            memory.insert(ptr, inst);
+                        }
+                    }
+                    continue;
+                }
+                InstOp::Store {
+                    dst_val_ptr,
+                    stored_val,
+                } => {
+                    let dst_val_ptr = self.canonical(context, *dst_val_ptr);
+                    let stored_val = self.canonical(context, *stored_val);
+                    memory.retain(|ptr, _| !pointers_may_alias(context, *ptr, dst_val_ptr));
+                    memory.insert(dst_val_ptr, stored_val);
+                    continue;
+                }
+                InstOp::MemCopyBytes { dst_val_ptr, .. }
+                | InstOp::MemCopyVal { dst_val_ptr, .. } => {
+                    let dst_val_ptr = self.canonical(context, *dst_val_ptr);
+                    memory.retain(|ptr, _| !pointers_may_alias(context, *ptr, dst_val_ptr));
+                    continue;
+                }
+                InstOp::AsmBlock(..)
+                | InstOp::Call(..)
+                | InstOp::ContractCall { .. }
+                | InstOp::FuelVm(
+                    FuelVmInstruction::StateLoadQuadWord { .. }
+                    | FuelVmInstruction::WideUnaryOp { .. }
+                    | FuelVmInstruction::WideBinaryOp { .. }
+                    | FuelVmInstruction::WideModularOp { .. },
+                ) => {
+                    memory.clear();
+                    continue;
+                }
+                _otherwise => (),
+            }
+
+            let Some(expr) = self.expr(context, op) else {
+                continue;
+            };
+            match available.get(&expr) {
+                Some(val) => self.replace(block, inst, *val),
+                None => {
+                    available.insert(expr.clone(), inst);
+                    added.push(expr);
+                }
+            }
+        }
+        added
+    }
+
+    fn expr(&mut self, context: &Context, op: &InstOp) -> Option<Expr> {
+        let mut canonical = |val: &Value| self.canonical(context, *val);
+        Some(match op {
+            InstOp::UnaryOp { op, arg } => Expr::UnaryOp(*op, canonical(arg)),
+            InstOp::BinaryOp { op, arg1, arg2 } => {
+                Expr::BinaryOp(*op, canonical(arg1), canonical(arg2))
+            }
+            InstOp::BitCast(val, ty) => Expr::BitCast(canonical(val), *ty),
+            InstOp::CastPtr(val, ty) => Expr::CastPtr(canonical(val), *ty),
+            InstOp::Cmp(pred, lhs, rhs) => Expr::Cmp(*pred, canonical(lhs), canonical(rhs)),
+            InstOp::GetElemPtr {
+                base,
+                elem_ptr_ty,
+                indices,
+            } => Expr::GetElemPtr(
+                canonical(base),
+                *elem_ptr_ty,
+                indices.iter().map(canonical).collect(),
+            ),
+            InstOp::GetLocal(local) => Expr::GetLocal(*local),
+            InstOp::IntToPtr(val, ty) => Expr::IntToPtr(canonical(val), *ty),
+            InstOp::PtrToInt(val, ty) => Expr::PtrToInt(canonical(val), *ty),
+            InstOp::FuelVm(FuelVmInstruction::Gtf { index, tx_field_id }) => {
+                Expr::Gtf(canonical(index), *tx_field_id)
+            }
+            _otherwise => return None,
+        })
+    }
+
+    /// The value replacing `val`, i.e. the first equal constant for a constant.
+    fn canonical(&mut self, context: &Context, val: Value) -> Value {
+        if let Some(replacement) = self.replacements.get(&val) {
+            return *replacement;
+        }
+        let ValueDatum::Constant(constant) = &context.values[val.0].value else {
+            return val;
+        };
+        let equal_constant = self.constants.iter().find(|other| {
+            matches!(
+                &context.values[other.0].value,
+                ValueDatum::Constant(other) if constant.eq(context, other)
+            )
+        });
+        match equal_constant {
+            Some(other) => *other,
+            None => {
+                self.constants.push(val);
+                val
+            }
+        }
+    }
+
+    fn replace(&mut self, block: Block, inst: Value, val: Value) {
+        self.replacements.insert(inst, val);
+        self.removed.push((block, inst));
+    }
+}
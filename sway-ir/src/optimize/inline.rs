@@ -2,7 +2,10 @@
 //!
 //! Function inlining is pretty hairy so these passes must be maintained with care.
 
-use std::{cell::RefCell, collections::HashMap};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
 
 use rustc_hash::FxHashMap;
 
@@ -107,13 +110,43 @@ pub fn inline_in_module(
                 }
                 counts
             });
+    let call_counts = RefCell::new(call_counts);
+
+    // The number of instructions the bytecode may grow by, when inlining is worth it but not free.
+    let budget = Cell::new(context.inline_budget);
+
+    // Inlining a call removes it from the calls to the inlined function, and copies the calls
+    // made by the inlined function.
+    let record_inlined = |ctx: &Context, func: &Function| {
+        let mut call_counts = call_counts.borrow_mut();
+        if let Some(count) = call_counts.get_mut(func) {
+            *count = count.saturating_sub(1);
+        }
+        for (_block, ins) in func.instruction_iter(ctx) {
+            if let Some(Instruction {
+                op: InstOp::Call(callee, _args),
+                ..
+            }) = ins.get_instruction(ctx)
+            {
+                *call_counts.entry(*callee).or_insert(0) += 1;
+            }
+        }
+    };
+
+    let inline_heuristic = |ctx: &Context, func: &Function, call_site: &Value| {
+        // A function is never inlined into itself.
+        if call_site
+            .get_instruction(ctx)
+            .is_some_and(|inst| inst.parent.get_function(ctx) == *func)
+        {
+            return false;
+        }
 
-    let inline_heuristic = |ctx: &Context, func: &Function, _call_site: &Value| {
         let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
         match attributed_inline {
             Some(Inline::Always) => {
-                // TODO: check if inlining of function is possible
-                // return true;
+                record_inlined(ctx, func);
+                return true;
             }
             Some(Inline::Never) => {
                 return false;
@@ -121,27 +154,45 @@ pub fn inline_in_module(
             None => {}
         }
 
-        // If the function is called only once then definitely inline it.
-        if call_counts.get(func).copied().unwrap_or(0) == 1 {
+        // If inlining doesn't grow the bytecode, e.g. if the function is called only once or is
+        // smaller than the call itself, then definitely inline it.
+        let call_count = call_counts.borrow().get(func).copied().unwrap_or(0);
+        let growth = estimate_inlining_growth(ctx, func, call_count);
+        if growth <= 0 {
+            record_inlined(ctx, func);
             return true;
         }
 
-        // If the function is (still) small then also inline it.
-        const MAX_INLINE_INSTRS_COUNT: usize = 4;
-        if func.num_instructions(ctx) <= MAX_INLINE_INSTRS_COUNT {
-            return true;
-        }
+        // Functions measured to be hot are inlined even if they are larger, trading bytecode
+        // size for the gas of the calls.
+        const MAX_HOT_INLINE_SIZE: u64 = 64;
+        let is_hot = ctx
+            .profile
+            .as_ref()
+            .is_some_and(|profile| profile.is_hot(func.get_name(ctx)))
+            && estimate_function_size(ctx, func) <= MAX_HOT_INLINE_SIZE;
 
         // See https://github.com/FuelLabs/sway/pull/4899
-        if func.args_iter(ctx).any(|(_name, arg_val)| {
+        let takes_aggregates = func.args_iter(ctx).any(|(_name, arg_val)| {
             arg_val.get_type(ctx).map_or(false, |ty| {
                 ty.is_ptr(ctx) || !(ty.is_unit(ctx) | ty.is_bool(ctx) | ty.is_uint(ctx))
             })
-        }) {
-            return true;
+        });
+
+        if !is_hot && !takes_aggregates {
+            return false;
         }
 
-        false
+        // Either way, the growth must fit in what is left of the budget.
+        let growth = growth as u64;
+        match budget.get() {
+            Some(remaining) if growth > remaining => false,
+            remaining => {
+                budget.set(remaining.map(|remaining| remaining - growth));
+                record_inlined(ctx, func);
+                true
+            }
+        }
     };
 
     let cg =
@@ -155,6 +206,69 @@ pub fn inline_in_module(
     Ok(modified)
 }
 
+/// The estimated number of FuelVM instructions of a call, excluding those passing the arguments:
+/// the jump to the function and the move of the returned value.
+const CALL_SIZE: u64 = 2;
+
+/// The estimated number of FuelVM instructions a function has besides those of its body: saving
+/// and restoring the registers, allocating and freeing the stack frame, and returning.
+const FUNCTION_OVERHEAD_SIZE: u64 = 7;
+
+/// Estimates the number of FuelVM instructions the body of the function compiles to.
+///
+/// This is a rough model of the code generated for each IR instruction, meant to compare the
+/// size of a function with that of the calls to it rather than to be exact.
+pub fn estimate_function_size(context: &Context, function: &Function) -> u64 {
+    function
+        .instruction_iter(context)
+        .map(|(_block, ins)| estimate_instruction_size(&ins.get_instruction(context).unwrap().op))
+        .sum()
+}
+
+fn estimate_instruction_size(op: &InstOp) -> u64 {
+    match op {
+        InstOp::AsmBlock(asm_block, _args) => asm_block.body.len() as u64,
+        InstOp::BitCast(..)
+        | InstOp::CastPtr(..)
+        | InstOp::IntToPtr(..)
+        | InstOp::Nop
+        | InstOp::PtrToInt(..) => 0,
+        InstOp::Branch(to_block) => 1 + to_block.args.len() as u64,
+        InstOp::Call(_, args) => CALL_SIZE + args.len() as u64,
+        InstOp::ConditionalBranch {
+            true_block,
+            false_block,
+            ..
+        } => 2 + (true_block.args.len() + false_block.args.len()) as u64,
+        InstOp::ContractCall { .. } => 4,
+        InstOp::FuelVm(FuelVmInstruction::Log { .. }) => 2,
+        InstOp::GetElemPtr { indices, .. } => indices.len() as u64,
+        InstOp::UnaryOp { .. }
+        | InstOp::BinaryOp { .. }
+        | InstOp::Cmp(..)
+        | InstOp::FuelVm(_)
+        | InstOp::GetLocal(_)
+        | InstOp::Load(_)
+        | InstOp::MemCopyBytes { .. }
+        | InstOp::MemCopyVal { .. }
+        | InstOp::Ret(..)
+        | InstOp::Store { .. } => 1,
+    }
+}
+
+/// Estimates by how many FuelVM instructions the bytecode grows when inlining a call to the
+/// function, which is called `call_count` times in total.  Inlining the last call to the function
+/// removes the function itself.
+fn estimate_inlining_growth(context: &Context, function: &Function, call_count: u64) -> i64 {
+    let body_size = estimate_function_size(context, function) as i64;
+    let call_size = (CALL_SIZE + function.num_args(context) as u64) as i64;
+    let mut growth = body_size - call_size;
+    if call_count <= 1 {
+        growth -= body_size + FUNCTION_OVERHEAD_SIZE as i64;
+    }
+    growth
+}
+
 pub fn inline_in_main(
     context: &mut Context,
     _: &AnalysisResults,
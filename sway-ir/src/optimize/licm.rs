@@ -0,0 +1,286 @@
+//! ## Loop Invariant Code Motion
+//!
+//! Instructions inside a loop which compute the same value on every iteration are hoisted out of
+//! the loop, into its preheader, so that they are executed only once.
+//!
+//! Loops are found as the natural loops of the back edges of the CFG, i.e. the branches to a
+//! block which dominates them.  Only loops whose header has a single predecessor outside the loop,
+//! branching unconditionally to it, are considered, that predecessor being the preheader.
+//!
+//! An instruction is hoisted if it has no side effects and all its operands are defined outside
+//! the loop, or are hoisted themselves.  Loads and storage reads are hoisted only if the loop does
+//! not write to memory or storage respectively, and the instructions which may revert, e.g. on
+//! an overflow, are hoisted only if they are executed on every iteration anyway.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    AnalysisResults, BinaryOpKind, Block, BranchToWithArgs, Context, DomTree, FuelVmInstruction,
+    Function, InsertionPosition, InstOp, Instruction, InstructionInserter, IrError, Pass,
+    PassMutability, ScopedPass, Value, DOMINATORS_NAME,
+};
+
+pub const LICM_NAME: &str = "licm";
+
+pub fn create_licm_pass() -> Pass {
+    Pass {
+        name: LICM_NAME,
+        descr: "Loop invariant code motion.",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(licm)),
+    }
+}
+
+/// A natural loop, made of the blocks which can reach one of its latches without going through
+/// its header.
+struct Loop {
+    header: Block,
+    /// The blocks of the loop, in the order of the function.
+    blocks: Vec<Block>,
+}
+
+pub fn licm(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    let mut loops = find_loops(context, &function, dom_tree);
+
+    // Inner loops first, so that what is hoisted out of them can then be hoisted out of the outer
+    // loops too.  The sort is stable, keeping the order of the function otherwise.
+    loops.sort_by_key(|lp| lp.blocks.len());
+
+    let mut modified = false;
+    for lp in loops {
+        modified |= hoist_loop_invariants(context, dom_tree, &lp);
+    }
+    Ok(modified)
+}
+
+fn find_loops(context: &Context, function: &Function, dom_tree: &DomTree) -> Vec<Loop> {
+    // Gather the latches of each loop header, i.e. the sources of the back edges.
+    let mut headers = Vec::new();
+    let mut latches = FxHashMap::<Block, Vec<Block>>::default();
+    for block in function.block_iter(context) {
+        if !dom_tree.contains_key(&block) {
+            // Unreachable.
+            continue;
+        }
+        for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+            if dominates(dom_tree, succ, block) {
+                if !latches.contains_key(&succ) {
+                    headers.push(succ);
+                }
+                latches.entry(succ).or_default().push(block);
+            }
+        }
+    }
+
+    headers
+        .into_iter()
+        .map(|header| {
+            let mut blocks = FxHashSet::from_iter([header]);
+            let mut worklist = latches.remove(&header).unwrap();
+            while let Some(block) = worklist.pop() {
+                if blocks.insert(block) {
+                    worklist.extend(
+                        block
+                            .pred_iter(context)
+                            .filter(|pred| dom_tree.contains_key(pred)),
+                    );
+                }
+            }
+            let blocks = function
+                .block_iter(context)
+                .filter(|block| blocks.contains(block))
+                .collect();
+            Loop { header, blocks }
+        })
+        .collect()
+}
+
+/// Whether `a` dominates `b`.
+fn dominates(dom_tree: &DomTree, a: Block, mut b: Block) -> bool {
+    loop {
+        if a == b {
+            return true;
+        }
+        match dom_tree.get(&b).and_then(|node| node.parent) {
+            Some(parent) => b = parent,
+            None => return false,
+        }
+    }
+}
+
+fn hoist_loop_invariants(context: &mut Context, dom_tree: &DomTree, lp: &Loop) -> bool {
+    // Find the preheader.
+    let mut outside_preds = lp
+        .header
+        .pred_iter(context)
+        .filter(|pred| !lp.blocks.contains(pred));
+    let (Some(preheader), None) = (outside_preds.next().copied(), outside_preds.next()) else {
+        return false;
+    };
+    let Some(Instruction {
+        op: InstOp::Branch(_),
+        ..
+    }) = preheader.get_terminator(context)
+    else {
+        return false;
+    };
+
+    // The blocks from which the loop can be exited.
+    let exiting_blocks: Vec<_> = lp
+        .blocks
+        .iter()
+        .filter(|block| {
+            block
+                .successors(context)
+                .iter()
+                .any(|succ| !lp.blocks.contains(&succ.block))
+        })
+        .copied()
+        .collect();
+    let always_executed = |block: Block| {
+        !exiting_blocks.is_empty()
+            && exiting_blocks
+                .iter()
+                .all(|exiting| dominates(dom_tree, block, *exiting))
+    };
+
+    let loop_instructions: Vec<_> = lp
+        .blocks
+        .iter()
+        .flat_map(|block| block.instruction_iter(context))
+        .collect();
+    let writes_memory = loop_instructions
+        .iter()
+        .any(|inst| may_write_memory(&inst.get_instruction(context).unwrap().op));
+    let writes_storage = loop_instructions
+        .iter()
+        .any(|inst| may_write_storage(&inst.get_instruction(context).unwrap().op));
+
+    // The values defined in the loop, i.e. its block args and instructions which aren't hoisted.
+    let mut variant: FxHashSet<Value> = lp
+        .blocks
+        .iter()
+        .flat_map(|block| block.arg_iter(context))
+        .copied()
+        .chain(loop_instructions.iter().copied())
+        .collect();
+
+    let mut hoisted = Vec::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for inst in loop_instructions.iter() {
+            if !variant.contains(inst) {
+                continue;
+            }
+            let Instruction { op, parent } = inst.get_instruction(context).unwrap();
+            let hoistable = match hoistability(op) {
+                Hoistability::Never => false,
+                Hoistability::Always => true,
+                Hoistability::IfAlwaysExecuted => always_executed(*parent),
+                Hoistability::IfNoMemoryWrites => !writes_memory && always_executed(*parent),
+                Hoistability::IfNoStorageWrites => !writes_storage && !writes_memory,
+            };
+            if hoistable
+                && op
+                    .get_operands()
+                    .iter()
+                    .all(|operand| !variant.contains(operand))
+            {
+                variant.remove(inst);
+                hoisted.push(*inst);
+                changed = true;
+            }
+        }
+    }
+
+    // Move the hoisted instructions to the end of the preheader, before its terminator, in the
+    // order they were found to be invariant, which respects their dependencies.
+    let terminator = preheader
+        .get_instruction_at(context, preheader.num_instructions(context) - 1)
+        .unwrap();
+    for inst in hoisted.iter() {
+        let block = inst.get_instruction(context).unwrap().parent;
+        block.remove_instruction(context, *inst);
+        inst.get_instruction_mut(context).unwrap().parent = preheader;
+        InstructionInserter::new(context, preheader, InsertionPosition::Before(terminator))
+            .insert(*inst);
+    }
+
+    !hoisted.is_empty()
+}
+
+enum Hoistability {
+    Never,
+    Always,
+    /// The instruction may revert, so it is hoisted only if it is executed before the loop exits.
+    IfAlwaysExecuted,
+    /// The instruction reads memory, and may revert if the memory is out of bounds.
+    IfNoMemoryWrites,
+    /// The instruction reads storage, at a key read from memory.
+    IfNoStorageWrites,
+}
+
+fn hoistability(op: &InstOp) -> Hoistability {
+    match op {
+        InstOp::BinaryOp {
+            op:
+                BinaryOpKind::Add
+                | BinaryOpKind::Sub
+                | BinaryOpKind::Mul
+                | BinaryOpKind::Div
+                | BinaryOpKind::Mod,
+            ..
+        } => Hoistability::IfAlwaysExecuted,
+        InstOp::BinaryOp { .. }
+        | InstOp::UnaryOp { .. }
+        | InstOp::BitCast(..)
+        | InstOp::CastPtr(..)
+        | InstOp::Cmp(..)
+        | InstOp::GetElemPtr { .. }
+        | InstOp::GetLocal(_)
+        | InstOp::IntToPtr(..)
+        | InstOp::PtrToInt(..)
+        | InstOp::FuelVm(FuelVmInstruction::Gtf { .. }) => Hoistability::Always,
+        InstOp::Load(_) => Hoistability::IfNoMemoryWrites,
+        InstOp::FuelVm(FuelVmInstruction::StateLoadWord(_)) => Hoistability::IfNoStorageWrites,
+        _otherwise => Hoistability::Never,
+    }
+}
+
+fn may_write_memory(op: &InstOp) -> bool {
+    matches!(
+        op,
+        InstOp::AsmBlock(..)
+            | InstOp::Call(..)
+            | InstOp::ContractCall { .. }
+            | InstOp::MemCopyBytes { .. }
+            | InstOp::MemCopyVal { .. }
+            | InstOp::Store { .. }
+            | InstOp::FuelVm(
+                FuelVmInstruction::StateLoadQuadWord { .. }
+                    | FuelVmInstruction::WideUnaryOp { .. }
+                    | FuelVmInstruction::WideBinaryOp { .. }
+                    | FuelVmInstruction::WideModularOp { .. }
+            )
+    )
+}
+
+fn may_write_storage(op: &InstOp) -> bool {
+    matches!(
+        op,
+        InstOp::AsmBlock(..)
+            | InstOp::Call(..)
+            | InstOp::ContractCall { .. }
+            | InstOp::FuelVm(
+                FuelVmInstruction::StateClear { .. }
+                    | FuelVmInstruction::StateStoreQuadWord { .. }
+                    | FuelVmInstruction::StateStoreWord { .. }
+            )
+    )
+}
@@ -20,7 +20,7 @@ pub fn create_mem2reg_pass() -> Pass {
         name: MEM2REG_NAME,
         descr: "Promote local memory to SSA registers.",
         deps: vec![POSTORDER_NAME, DOMINATORS_NAME, DOMFRONTS_NAME],
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(promote_to_registers)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(promote_to_registers)),
     }
 }
 
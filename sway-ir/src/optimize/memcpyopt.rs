@@ -16,7 +16,7 @@ pub fn create_memcpyopt_pass() -> Pass {
         name: MEMCPYOPT_NAME,
         descr: "Memcopy optimization.",
         deps: vec![ESCAPED_SYMBOLS_NAME],
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(mem_copy_opt)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(mem_copy_opt)),
     }
 }
 
@@ -26,7 +26,7 @@ pub fn create_misc_demotion_pass() -> Pass {
         name: MISCDEMOTION_NAME,
         descr: "By-value miscellaneous demotion to by-reference.",
         deps: Vec::new(),
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(misc_demotion)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(misc_demotion)),
     }
 }
 
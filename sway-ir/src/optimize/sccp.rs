@@ -0,0 +1,314 @@
+//! ## Sparse Conditional Constant Propagation
+//!
+//! The values of a function are found to be constant by evaluating the instructions of the
+//! blocks which may be executed, starting from the entry block and following only the branches
+//! which may be taken given the values found so far.  Unlike constant folding, this propagates
+//! constants through block arguments, i.e. phi nodes, whose value is constant when the same
+//! constant is passed by every branch to the block which may be taken.
+//!
+//! The conditional branches on constants are then replaced with unconditional branches, the
+//! values found to be constant are replaced with constants, and the blocks which are never
+//! executed are removed.
+//!
+//! Configurables aren't constant, as their values are only set when the program is deployed.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    fold_binary_op, fold_cmp, fold_unary_op, AnalysisResults, Block, BranchToWithArgs, Constant,
+    ConstantValue, Context, Function, InstOp, Instruction, IrError, Pass, PassMutability,
+    ScopedPass, Value, ValueDatum,
+};
+
+pub const SCCP_NAME: &str = "sccp";
+
+pub fn create_sccp_pass() -> Pass {
+    Pass {
+        name: SCCP_NAME,
+        descr: "Sparse conditional constant propagation.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(sccp)),
+    }
+}
+
+/// What is known of a value.
+#[derive(Clone)]
+enum LatticeValue {
+    /// Nothing yet, as the value hasn't been evaluated.
+    Undefined,
+    Constant(Constant),
+    /// The value isn't constant.
+    Overdefined,
+}
+
+pub fn sccp(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let mut analysis = ConstantAnalysis::new(context, function);
+    analysis.run(function);
+    let ConstantAnalysis {
+        values,
+        executable_blocks,
+        ..
+    } = analysis;
+
+    let mut modified = false;
+
+    // Replace the conditional branches on constants with unconditional branches.
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            continue;
+        }
+        let Some(Instruction {
+            op:
+                InstOp::ConditionalBranch {
+                    cond_value,
+                    true_block,
+                    false_block,
+                },
+            ..
+        }) = block.get_terminator(context)
+        else {
+            continue;
+        };
+        let Some(LatticeValue::Constant(Constant {
+            value: ConstantValue::Bool(cond),
+            ..
+        })) = lattice_value(context, &values, *cond_value)
+        else {
+            continue;
+        };
+        let (taken, not_taken) = if cond {
+            (true_block.clone(), false_block.block)
+        } else {
+            (false_block.clone(), true_block.block)
+        };
+        if not_taken != taken.block {
+            not_taken.remove_pred(context, &block);
+        }
+        let cbr = block
+            .get_instruction_at(context, block.num_instructions(context) - 1)
+            .unwrap();
+        cbr.replace(
+            context,
+            ValueDatum::Instruction(Instruction {
+                op: InstOp::Branch(taken),
+                parent: block,
+            }),
+        );
+        modified = true;
+    }
+
+    // Replace the values found to be constant with constants.
+    let mut replacements = FxHashMap::default();
+    let mut folded = Vec::new();
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            continue;
+        }
+        let block_values = block
+            .arg_iter(context)
+            .copied()
+            .chain(block.instruction_iter(context))
+            .collect::<Vec<_>>();
+        for value in block_values {
+            if let Some(LatticeValue::Constant(constant)) = values.get(&value) {
+                replacements.insert(value, Value::new_constant(context, constant.clone()));
+                if value.get_instruction(context).is_some() {
+                    folded.push((block, value));
+                }
+            }
+        }
+    }
+    if !replacements.is_empty() {
+        function.replace_values(context, &replacements, None);
+        for (block, inst) in folded {
+            block.remove_instruction(context, inst);
+        }
+        modified = true;
+    }
+
+    // Remove the blocks which are never executed.
+    for block in function.block_iter(context) {
+        if !executable_blocks.contains(&block) {
+            for BranchToWithArgs { block: succ, .. } in block.successors(context) {
+                succ.remove_pred(context, &block);
+            }
+            function.remove_block(context, &block)?;
+            modified = true;
+        }
+    }
+
+    Ok(modified)
+}
+
+struct ConstantAnalysis<'a, 'eng> {
+    context: &'a Context<'eng>,
+    /// What is known of the block arguments and instructions evaluated so far.
+    values: FxHashMap<Value, LatticeValue>,
+    /// The blocks found to be executable so far.
+    executable_blocks: FxHashSet<Block>,
+    /// The instructions using each value.
+    users: FxHashMap<Value, Vec<Value>>,
+    /// The blocks found to be executable whose instructions are yet to be evaluated.
+    block_worklist: Vec<Block>,
+    /// The values whose users are to be evaluated again, as more is known of them.
+    value_worklist: Vec<Value>,
+}
+
+impl<'a, 'eng> ConstantAnalysis<'a, 'eng> {
+    fn new(context: &'a Context<'eng>, function: Function) -> Self {
+        let mut users = FxHashMap::<Value, Vec<Value>>::default();
+        for (_block, inst) in function.instruction_iter(context) {
+            for operand in inst.get_instruction(context).unwrap().op.get_operands() {
+                users.entry(operand).or_default().push(inst);
+            }
+        }
+        ConstantAnalysis {
+            context,
+            values: FxHashMap::default(),
+            executable_blocks: FxHashSet::default(),
+            users,
+            block_worklist: Vec::new(),
+            value_worklist: Vec::new(),
+        }
+    }
+
+    fn run(&mut self, function: Function) {
+        let entry_block = function.get_entry_block(self.context);
+        for arg in entry_block.arg_iter(self.context) {
+            self.values.insert(*arg, LatticeValue::Overdefined);
+        }
+        self.executable_blocks.insert(entry_block);
+        self.block_worklist.push(entry_block);
+
+        loop {
+            if let Some(block) = self.block_worklist.pop() {
+                for inst in block.instruction_iter(self.context) {
+                    self.evaluate(inst);
+                }
+            } else if let Some(value) = self.value_worklist.pop() {
+                for user in self.users.get(&value).cloned().unwrap_or_default() {
+                    let parent = user.get_instruction(self.context).unwrap().parent;
+                    if self.executable_blocks.contains(&parent) {
+                        self.evaluate(user);
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn evaluate(&mut self, inst: Value) {
+        let context = self.context;
+        let op = &inst.get_instruction(context).unwrap().op;
+        match op {
+            InstOp::Branch(to_block) => self.take_branch(to_block),
+            InstOp::ConditionalBranch {
+                cond_value,
+                true_block,
+                false_block,
+            } => match self.lattice_value(*cond_value) {
+                LatticeValue::Undefined => (),
+                LatticeValue::Constant(Constant {
+                    value: ConstantValue::Bool(true),
+                    ..
+                }) => self.take_branch(true_block),
+                LatticeValue::Constant(Constant {
+                    value: ConstantValue::Bool(false),
+                    ..
+                }) => self.take_branch(false_block),
+                _otherwise => {
+                    self.take_branch(true_block);
+                    self.take_branch(false_block);
+                }
+            },
+            InstOp::Cmp(pred, val1, val2) => {
+                let folded = self.fold(&[*val1, *val2], |constants| {
+                    fold_cmp(context, pred, &constants[0], &constants[1])
+                        .map(|cond| Constant::new_bool(context, cond))
+                });
+                self.update(inst, folded);
+            }
+            InstOp::BinaryOp { op, arg1, arg2 } => {
+                let folded = self.fold(&[*arg1, *arg2], |constants| {
+                    fold_binary_op(op, &constants[0], &constants[1])
+                });
+                self.update(inst, folded);
+            }
+            InstOp::UnaryOp { op, arg } => {
+                let folded = self.fold(&[*arg], |constants| {
+                    fold_unary_op(context, op, &constants[0])
+                });
+                self.update(inst, folded);
+            }
+            _otherwise => self.update(inst, LatticeValue::Overdefined),
+        }
+    }
+
+    /// Folds an instruction whose operands are all constant, which is not constant if it can't
+    /// be folded.
+    fn fold<F: FnOnce(&[Constant]) -> Option<Constant>>(
+        &self,
+        operands: &[Value],
+        fold: F,
+    ) -> LatticeValue {
+        let mut constants = Vec::with_capacity(operands.len());
+        for operand in operands {
+            match self.lattice_value(*operand) {
+                LatticeValue::Undefined => return LatticeValue::Undefined,
+                LatticeValue::Constant(constant) => constants.push(constant),
+                LatticeValue::Overdefined => return LatticeValue::Overdefined,
+            }
+        }
+        fold(&constants).map_or(LatticeValue::Overdefined, LatticeValue::Constant)
+    }
+
+    /// Marks the block branched to as executable, and passes it the arguments of the branch.
+    fn take_branch(&mut self, to_block: &BranchToWithArgs) {
+        for (idx, arg) in to_block.args.iter().enumerate() {
+            let param = to_block.block.get_arg(self.context, idx).unwrap();
+            let arg = self.lattice_value(*arg);
+            self.update(param, arg);
+        }
+        if self.executable_blocks.insert(to_block.block) {
+            self.block_worklist.push(to_block.block);
+        }
+    }
+
+    /// Merges what is newly known of the value with what was known of it.
+    fn update(&mut self, value: Value, new: LatticeValue) {
+        let old = self.lattice_value(value);
+        let merged = match (&old, new) {
+            (LatticeValue::Overdefined, _) | (_, LatticeValue::Undefined) => return,
+            (LatticeValue::Undefined, new) => new,
+            (LatticeValue::Constant(old), LatticeValue::Constant(new))
+                if old.eq(self.context, &new) =>
+            {
+                return
+            }
+            (LatticeValue::Constant(_), _) => LatticeValue::Overdefined,
+        };
+        self.values.insert(value, merged);
+        self.value_worklist.push(value);
+    }
+
+    fn lattice_value(&self, value: Value) -> LatticeValue {
+        lattice_value(self.context, &self.values, value).unwrap_or(LatticeValue::Undefined)
+    }
+}
+
+fn lattice_value(
+    context: &Context,
+    values: &FxHashMap<Value, LatticeValue>,
+    value: Value,
+) -> Option<LatticeValue> {
+    match &context.values[value.0].value {
+        ValueDatum::Constant(constant) => Some(LatticeValue::Constant(constant.clone())),
+        ValueDatum::Configurable(_) => Some(LatticeValue::Overdefined),
+        ValueDatum::Argument(_) | ValueDatum::Instruction(_) => values.get(&value).cloned(),
+    }
+}
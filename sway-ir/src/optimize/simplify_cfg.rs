@@ -23,7 +23,7 @@ pub fn create_simplify_cfg_pass() -> Pass {
         name: SIMPLIFYCFG_NAME,
         descr: "merge or remove redundant blocks.",
         deps: vec![],
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(simplify_cfg)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(simplify_cfg)),
     }
 }
 
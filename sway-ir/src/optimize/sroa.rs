@@ -15,7 +15,7 @@ pub fn create_sroa_pass() -> Pass {
         name: SROA_NAME,
         descr: "Scalar replacement of aggregates.",
         deps: vec![],
-        runner: ScopedPass::FunctionPass(PassMutability::Transform(sroa)),
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(sroa)),
     }
 }
 
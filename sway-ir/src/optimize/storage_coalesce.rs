@@ -0,0 +1,330 @@
+//! ## Storage Access Coalescing
+//!
+//! Quad-word storage reads, or writes, of adjacent storage slots, into or from adjacent memory,
+//! are combined into a single access of all of the slots, which costs much less gas than
+//! accessing them separately.  This typically happens when the parts of a struct spanning many
+//! slots are read or written one after the other.
+//!
+//! Two accesses within a block are combined when their keys and numbers of slots are constants,
+//! the slots of the second one immediately follow those of the first one, and so does the memory
+//! they read into or write from, within the same local variable.  The values they return, telling
+//! whether the slots were all set, must be unused.  No instruction between them may access
+//! storage, call a function, or access the memory which is read into earlier, or written from
+//! later, once they are combined.
+//!
+//! Reads are combined into the first read, and writes into the second write.
+
+use rustc_hash::FxHashSet;
+use sway_types::u256::U256;
+
+use crate::{
+    get_loaded_ptr_values, get_memory_offsets, get_stored_ptr_values, get_stored_symbols,
+    get_symbol, get_symbols, pointee_size, AnalysisResults, Block, Constant, ConstantValue,
+    Context, EscapedSymbols, FuelVmInstruction, Function, InstOp, Instruction, IrError, Pass,
+    PassMutability, ScopedPass, Symbol, Value, ESCAPED_SYMBOLS_NAME,
+};
+
+pub const STORAGE_COALESCE_NAME: &str = "storagecoalesce";
+
+pub fn create_storage_coalesce_pass() -> Pass {
+    Pass {
+        name: STORAGE_COALESCE_NAME,
+        descr: "Coalescing of adjacent storage slot accesses.",
+        deps: vec![ESCAPED_SYMBOLS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(storage_coalesce)),
+    }
+}
+
+/// A quad-word storage read or write of a constant number of slots, at a constant key, into or
+/// from a local variable.
+struct SlotsAccess {
+    inst: Value,
+    is_write: bool,
+    mem_ptr: Value,
+    key_ptr: Value,
+    /// The local variable holding the key.
+    key_symbol: Symbol,
+    key: U256,
+    number_of_slots: u64,
+    /// The local variable read into or written from, and the offset of `mem_ptr` within it.
+    symbol: Symbol,
+    offset: u64,
+}
+
+pub fn storage_coalesce(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let escaped_symbols: &EscapedSymbols = analyses.get_analysis_result(function);
+
+    // The values used by other instructions.  Combining accesses doesn't change them.
+    let used_values: FxHashSet<Value> = function
+        .instruction_iter(context)
+        .flat_map(|(_block, inst)| inst.get_instruction(context).unwrap().op.get_operands())
+        .collect();
+
+    let mut modified = false;
+    let blocks: Vec<Block> = function.block_iter(context).collect();
+    for block in blocks {
+        while let Some((first, second)) =
+            find_adjacent_accesses(context, escaped_symbols, &used_values, block)
+        {
+            coalesce(context, block, first, second);
+            modified = true;
+        }
+    }
+    Ok(modified)
+}
+
+/// Finds the first pair of accesses of the block which can be combined.
+fn find_adjacent_accesses(
+    context: &Context,
+    escaped_symbols: &EscapedSymbols,
+    used_values: &FxHashSet<Value>,
+    block: Block,
+) -> Option<(SlotsAccess, SlotsAccess)> {
+    let insts: Vec<Value> = block.instruction_iter(context).collect();
+    for (first_idx, first_inst) in insts.iter().enumerate() {
+        let Some(first) = get_slots_access(
+            context,
+            escaped_symbols,
+            used_values,
+            &insts[..first_idx],
+            *first_inst,
+        ) else {
+            continue;
+        };
+
+        for (second_idx, second_inst) in insts.iter().enumerate().skip(first_idx + 1) {
+            if let Some(second) = get_slots_access(
+                context,
+                escaped_symbols,
+                used_values,
+                &insts[..second_idx],
+                *second_inst,
+            ) {
+                if are_adjacent(&first, &second)
+                    && insts[first_idx + 1..second_idx]
+                        .iter()
+                        .all(|inst| !interferes(context, &first, &second, *inst))
+                {
+                    return Some((first, second));
+                }
+            }
+            if is_barrier(context, *second_inst) {
+                break;
+            }
+        }
+    }
+    None
+}
+
+fn get_slots_access(
+    context: &Context,
+    escaped_symbols: &EscapedSymbols,
+    used_values: &FxHashSet<Value>,
+    preceding_insts: &[Value],
+    inst: Value,
+) -> Option<SlotsAccess> {
+    let (is_write, mem_ptr, key_ptr, number_of_slots) = match &inst.get_instruction(context)?.op {
+        InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+            load_val,
+            key,
+            number_of_slots,
+        }) => (false, *load_val, *key, *number_of_slots),
+        InstOp::FuelVm(FuelVmInstruction::StateStoreQuadWord {
+            stored_val,
+            key,
+            number_of_slots,
+        }) => (true, *stored_val, *key, *number_of_slots),
+        _ => return None,
+    };
+    if used_values.contains(&inst) {
+        return None;
+    }
+
+    let ConstantValue::Uint(number_of_slots) = number_of_slots.get_constant(context)?.value else {
+        return None;
+    };
+    let key_symbol = get_local_symbol(context, escaped_symbols, key_ptr)?;
+    let key = get_constant_key(context, preceding_insts, key_symbol)?;
+    let symbol = get_local_symbol(context, escaped_symbols, mem_ptr)?;
+    let offset = *get_memory_offsets(context, mem_ptr).get(&symbol)?;
+
+    Some(SlotsAccess {
+        inst,
+        is_write,
+        mem_ptr,
+        key_ptr,
+        key_symbol,
+        key,
+        number_of_slots,
+        symbol,
+        offset,
+    })
+}
+
+/// The local variable `ptr` points into, if it doesn't escape and thus can't be aliased.
+fn get_local_symbol(
+    context: &Context,
+    escaped_symbols: &EscapedSymbols,
+    ptr: Value,
+) -> Option<Symbol> {
+    get_symbol(context, ptr)
+        .filter(|symbol| matches!(symbol, Symbol::Local(_)) && !escaped_symbols.contains(symbol))
+}
+
+/// The key held by a `b256` local variable, if it was last set to a constant within the block.
+fn get_constant_key(context: &Context, preceding_insts: &[Value], symbol: Symbol) -> Option<U256> {
+    if !symbol
+        .get_type(context)
+        .get_pointee_type(context)?
+        .is_b256(context)
+    {
+        return None;
+    }
+    let last_store = preceding_insts
+        .iter()
+        .rev()
+        .find(|inst| get_stored_symbols(context, **inst).contains(&symbol))?;
+    match &last_store.get_instruction(context)?.op {
+        InstOp::Store {
+            dst_val_ptr,
+            stored_val,
+        } if pointee_size(context, *dst_val_ptr) == 32 => {
+            match &stored_val.get_constant(context)?.value {
+                ConstantValue::B256(key) => Some(key.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn are_adjacent(first: &SlotsAccess, second: &SlotsAccess) -> bool {
+    first.is_write == second.is_write
+        && first.symbol == second.symbol
+        && first.key.checked_add(&U256::from(first.number_of_slots)) == Some(second.key.clone())
+        && first.offset + first.number_of_slots * 32 == second.offset
+}
+
+/// Whether no access may be moved across the instruction, as it accesses storage or may do so.
+fn is_barrier(context: &Context, inst: Value) -> bool {
+    matches!(
+        inst.get_instruction(context).map(|inst| &inst.op),
+        Some(
+            InstOp::Call(..)
+                | InstOp::ContractCall { .. }
+                | InstOp::AsmBlock(..)
+                | InstOp::FuelVm(
+                    FuelVmInstruction::StateClear { .. }
+                        | FuelVmInstruction::StateLoadQuadWord { .. }
+                        | FuelVmInstruction::StateLoadWord(_)
+                        | FuelVmInstruction::StateStoreQuadWord { .. }
+                        | FuelVmInstruction::StateStoreWord { .. }
+                )
+        )
+    )
+}
+
+/// Whether combining the accesses changes the behaviour of the instruction between them.
+fn interferes(context: &Context, first: &SlotsAccess, second: &SlotsAccess, inst: Value) -> bool {
+    if is_barrier(context, inst) {
+        return true;
+    }
+
+    if first.is_write {
+        // The first write is moved to the second one, so the memory it writes from, and its key,
+        // must not be written to in between.
+        may_access(
+            context,
+            inst,
+            get_stored_ptr_values(context, inst),
+            first.symbol,
+            first.offset,
+            first.number_of_slots * 32,
+        ) || get_stored_symbols(context, inst).contains(&first.key_symbol)
+    } else {
+        // The second read is moved to the first one, so the memory it reads into must not be
+        // accessed in between.
+        let ptrs = get_loaded_ptr_values(context, inst)
+            .into_iter()
+            .chain(get_stored_ptr_values(context, inst))
+            .collect();
+        may_access(
+            context,
+            inst,
+            ptrs,
+            second.symbol,
+            second.offset,
+            second.number_of_slots * 32,
+        )
+    }
+}
+
+/// Whether the instruction may access `len` bytes of `symbol` at `offset` through `ptrs`.
+fn may_access(
+    context: &Context,
+    inst: Value,
+    ptrs: Vec<Value>,
+    symbol: Symbol,
+    offset: u64,
+    len: u64,
+) -> bool {
+    let is_load_or_store = matches!(
+        inst.get_instruction(context).map(|inst| &inst.op),
+        Some(InstOp::Load(_) | InstOp::Store { .. })
+    );
+    ptrs.into_iter().any(|ptr| {
+        if !get_symbols(context, ptr).contains(&symbol) {
+            return false;
+        }
+        if !is_load_or_store {
+            return true;
+        }
+        match get_memory_offsets(context, ptr).get(&symbol) {
+            Some(ptr_offset) => {
+                *ptr_offset < offset + len && offset < *ptr_offset + pointee_size(context, ptr)
+            }
+            None => true,
+        }
+    })
+}
+
+fn coalesce(context: &mut Context, block: Block, first: SlotsAccess, second: SlotsAccess) {
+    let number_of_slots =
+        Constant::get_uint(context, 64, first.number_of_slots + second.number_of_slots);
+    if first.is_write {
+        let Some(Instruction {
+            op:
+                InstOp::FuelVm(FuelVmInstruction::StateStoreQuadWord {
+                    stored_val,
+                    key,
+                    number_of_slots: second_number_of_slots,
+                }),
+            ..
+        }) = second.inst.get_instruction_mut(context)
+        else {
+            unreachable!("Storage writes are `state_store_quad_word` instructions.");
+        };
+        *stored_val = first.mem_ptr;
+        *key = first.key_ptr;
+        *second_number_of_slots = number_of_slots;
+        block.remove_instruction(context, first.inst);
+    } else {
+        let Some(Instruction {
+            op:
+                InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+                    number_of_slots: first_number_of_slots,
+                    ..
+                }),
+            ..
+        }) = first.inst.get_instruction_mut(context)
+        else {
+            unreachable!("Storage reads are `state_load_quad_word` instructions.");
+        };
+        *first_number_of_slots = number_of_slots;
+        block.remove_instruction(context, second.inst);
+    }
+}
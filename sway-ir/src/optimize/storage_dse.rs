@@ -0,0 +1,267 @@
+//! ## Dead Storage Write Elimination
+//!
+//! Writes to storage slots which are written again later in the same block, without being read
+//! in between, are removed.  This includes clearing slots, which is a write too.
+//!
+//! The slots are identified by the values of their keys, which are read from memory.  A key is
+//! known to be a given value, e.g. a constant, if that value was stored to the memory it's read
+//! from earlier in the block.  Otherwise the key is identified by the pointer it's read from,
+//! until the memory it points to may have been written.
+//!
+//! Calls, contract calls and ASM blocks may read any slot.  Storage writes return whether the
+//! slots they write were already set, so those whose result is used read the slots as well.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    pointers_may_alias, AnalysisResults, Block, Constant, ConstantValue, Context,
+    FuelVmInstruction, Function, InstOp, Instruction, IrError, Pass, PassMutability, ScopedPass,
+    Value, ValueDatum,
+};
+
+pub const STORAGE_DSE_NAME: &str = "storagedse";
+
+pub fn create_storage_dse_pass() -> Pass {
+    Pass {
+        name: STORAGE_DSE_NAME,
+        descr: "Dead storage write elimination.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(storage_dse)),
+    }
+}
+
+/// The key of a storage slot.
+#[derive(Clone)]
+enum SlotKey {
+    /// The key is a known constant.
+    Constant(Constant),
+    /// The key is the value of a given instruction or argument.
+    Value(Value),
+    /// The key is read from the given pointer, while the memory it points to is in the given
+    /// version.
+    Memory(Value, u64),
+}
+
+/// A range of storage slots, the number of slots being unknown if `None`.
+#[derive(Clone)]
+struct Slots {
+    key: SlotKey,
+    count: Option<u64>,
+}
+
+impl Slots {
+    /// Whether all the slots of `other` are among these slots.
+    fn covers(&self, context: &Context, other: &Slots) -> bool {
+        let same_key = match (&self.key, &other.key) {
+            (SlotKey::Constant(a), SlotKey::Constant(b)) => a.eq(context, b),
+            (SlotKey::Value(a), SlotKey::Value(b)) => a == b,
+            (SlotKey::Memory(a, a_version), SlotKey::Memory(b, b_version)) => {
+                a == b && a_version == b_version
+            }
+            _otherwise => false,
+        };
+        same_key && matches!((self.count, other.count), (Some(a), Some(b)) if a >= b)
+    }
+
+    /// Whether these slots and `other` may have slots in common.
+    fn may_overlap(&self, other: &Slots) -> bool {
+        match (&self.key, self.count, &other.key, other.count) {
+            (
+                SlotKey::Constant(Constant {
+                    value: ConstantValue::B256(a),
+                    ..
+                }),
+                Some(a_count),
+                SlotKey::Constant(Constant {
+                    value: ConstantValue::B256(b),
+                    ..
+                }),
+                Some(b_count),
+            ) => {
+                // The ranges can't overlap if one of them ends before the other starts.  They
+                // may overlap if the end of a range overflows, wrapping around.
+                match (
+                    a.checked_add(&a_count.into()),
+                    b.checked_add(&b_count.into()),
+                ) {
+                    (Some(a_end), Some(b_end)) => a < &b_end && b < &a_end,
+                    _otherwise => true,
+                }
+            }
+            _otherwise => true,
+        }
+    }
+}
+
+pub fn storage_dse(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let used_values: FxHashSet<Value> = function
+        .instruction_iter(context)
+        .flat_map(|(_block, inst)| inst.get_instruction(context).unwrap().op.get_operands())
+        .collect();
+
+    let dead_writes: Vec<(Block, Value)> = function
+        .block_iter(context)
+        .flat_map(|block| {
+            find_dead_writes(context, &used_values, block)
+                .into_iter()
+                .map(move |inst| (block, inst))
+        })
+        .collect();
+
+    for (block, inst) in dead_writes.iter() {
+        block.remove_instruction(context, *inst);
+    }
+    Ok(!dead_writes.is_empty())
+}
+
+fn find_dead_writes(context: &Context, used_values: &FxHashSet<Value>, block: Block) -> Vec<Value> {
+    let mut memory = MemoryModel::default();
+
+    // The writes whose slots haven't been read since, along with their slots.
+    let mut pending_writes: Vec<(Value, Slots)> = Vec::new();
+    let mut dead_writes = Vec::new();
+
+    for inst in block.instruction_iter(context) {
+        let Some(Instruction { op, .. }) = inst.get_instruction(context) else {
+            continue;
+        };
+        match op {
+            InstOp::FuelVm(
+                FuelVmInstruction::StateStoreWord { key, .. }
+                | FuelVmInstruction::StateStoreQuadWord { key, .. }
+                | FuelVmInstruction::StateClear { key, .. },
+            ) => {
+                let number_of_slots = match op {
+                    InstOp::FuelVm(
+                        FuelVmInstruction::StateStoreQuadWord {
+                            number_of_slots, ..
+                        }
+                        | FuelVmInstruction::StateClear {
+                            number_of_slots, ..
+                        },
+                    ) => Some(*number_of_slots),
+                    _otherwise => None,
+                };
+                let slots = memory.slots(context, *key, number_of_slots);
+                if used_values.contains(&inst) {
+                    // Whether the slots were set is used, so they're read.
+                    pending_writes.retain(|(_, pending)| !pending.may_overlap(&slots));
+                } else {
+                    pending_writes.retain(|(pending_inst, pending)| {
+                        let is_dead = slots.covers(context, pending);
+                        if is_dead {
+                            dead_writes.push(*pending_inst);
+                        }
+                        !is_dead
+                    });
+                    pending_writes.push((inst, slots));
+                }
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateLoadWord(key)) => {
+                let slots = memory.slots(context, *key, None);
+                pending_writes.retain(|(_, pending)| !pending.may_overlap(&slots));
+            }
+            InstOp::FuelVm(FuelVmInstruction::StateLoadQuadWord {
+                load_val,
+                key,
+                number_of_slots,
+            }) => {
+                let slots = memory.slots(context, *key, Some(*number_of_slots));
+                pending_writes.retain(|(_, pending)| !pending.may_overlap(&slots));
+                memory.write(context, *load_val, None);
+            }
+            InstOp::AsmBlock(..) | InstOp::Call(..) | InstOp::ContractCall { .. } => {
+                pending_writes.clear();
+                memory.clobber();
+            }
+            InstOp::Store {
+                dst_val_ptr,
+                stored_val,
+            } => memory.write(context, *dst_val_ptr, Some(*stored_val)),
+            InstOp::MemCopyBytes { dst_val_ptr, .. } | InstOp::MemCopyVal { dst_val_ptr, .. } => {
+                memory.write(context, *dst_val_ptr, None)
+            }
+            InstOp::FuelVm(
+                FuelVmInstruction::WideUnaryOp { .. }
+                | FuelVmInstruction::WideBinaryOp { .. }
+                | FuelVmInstruction::WideModularOp { .. },
+            ) => memory.clobber(),
+            _otherwise => (),
+        }
+    }
+
+    dead_writes
+}
+
+/// What is known of the memory the storage keys are read from, within a block.
+#[derive(Default)]
+struct MemoryModel {
+    /// The values stored to each pointer, as long as the memory it points to isn't written again.
+    stored_values: FxHashMap<Value, Value>,
+    /// The version of the memory each pointer read from points to, increased whenever that
+    /// memory may be written.
+    versions: FxHashMap<Value, u64>,
+    next_version: u64,
+}
+
+impl MemoryModel {
+    /// The slots written or read with the key read from `key_ptr`.
+    fn slots(
+        &mut self,
+        context: &Context,
+        key_ptr: Value,
+        number_of_slots: Option<Value>,
+    ) -> Slots {
+        let key = match self.stored_values.get(&key_ptr) {
+            Some(key) => match &context.values[key.0].value {
+                ValueDatum::Constant(constant) => SlotKey::Constant(constant.clone()),
+                _otherwise => SlotKey::Value(*key),
+            },
+            None => {
+                let next_version = &mut self.next_version;
+                let version = *self.versions.entry(key_ptr).or_insert_with(|| {
+                    *next_version += 1;
+                    *next_version
+                });
+                SlotKey::Memory(key_ptr, version)
+            }
+        };
+        let count = match number_of_slots {
+            None => Some(1),
+            Some(number_of_slots) => {
+                number_of_slots
+                    .get_constant(context)
+                    .and_then(|constant| match constant.value {
+                        ConstantValue::Uint(count) => Some(count),
+                        _otherwise => None,
+                    })
+            }
+        };
+        Slots { key, count }
+    }
+
+    /// Records a write to the memory `ptr` points to, of `stored_val` if known.
+    fn write(&mut self, context: &Context, ptr: Value, stored_val: Option<Value>) {
+        self.stored_values
+            .retain(|other, _| !pointers_may_alias(context, *other, ptr));
+        for (other, version) in self.versions.iter_mut() {
+            if pointers_may_alias(context, *other, ptr) {
+                self.next_version += 1;
+                *version = self.next_version;
+            }
+        }
+        if let Some(stored_val) = stored_val {
+            self.stored_values.insert(ptr, stored_val);
+        }
+    }
+
+    /// Records writes to any memory.
+    fn clobber(&mut self) {
+        self.stored_values.clear();
+        self.versions.clear();
+    }
+}
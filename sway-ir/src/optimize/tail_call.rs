@@ -0,0 +1,160 @@
+//! ## Tail Call Elimination
+//!
+//! Calls a function makes to itself in tail position, i.e. whose result is returned unchanged,
+//! are replaced with branches back to the start of the function, turning the recursion into a
+//! loop which uses no call stack.
+//!
+//! The body of the function is moved to a new block following the entry block, taking the
+//! function arguments as block arguments.  The entry block then simply branches to it, and each
+//! tail call branches to it with the call arguments instead.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    AnalysisResults, Block, BranchToWithArgs, Context, Function, InstOp, Instruction, IrError,
+    Pass, PassMutability, ScopedPass, Value,
+};
+
+pub const TAIL_CALL_NAME: &str = "tailcall";
+
+pub fn create_tail_call_pass() -> Pass {
+    Pass {
+        name: TAIL_CALL_NAME,
+        descr: "Self-recursive tail calls to loops.",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::LocalTransform(tail_call_elimination)),
+    }
+}
+
+pub fn tail_call_elimination(
+    context: &mut Context,
+    _: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let tail_calls = find_tail_calls(context, &function);
+    if tail_calls.is_empty() {
+        return Ok(false);
+    }
+
+    // Move the body of the function into a new loop header, taking the function arguments as
+    // block arguments.
+    let (entry_block, header_block) = function.get_entry_block(context).split_at(context, 0);
+    let fn_args: Vec<_> = entry_block.arg_iter(context).copied().collect();
+    let mut replace_map = FxHashMap::default();
+    for fn_arg in fn_args.iter() {
+        let ty = fn_arg.get_type(context).unwrap();
+        let idx = header_block.new_arg(context, ty);
+        replace_map.insert(*fn_arg, header_block.get_arg(context, idx).unwrap());
+    }
+    function.replace_values(context, &replace_map, None);
+    entry_block.append(context).branch(header_block, fn_args);
+
+    // Replace each tail call, along with the terminator returning its result, with a branch back
+    // to the loop header.
+    for (block, call_val) in tail_calls {
+        let Some(Instruction {
+            op: InstOp::Call(_, args),
+            ..
+        }) = call_val.get_instruction(context)
+        else {
+            unreachable!("Tail calls are call instructions.");
+        };
+        let args = args.clone();
+        if let Some(Instruction {
+            op: InstOp::Branch(BranchToWithArgs {
+                block: to_block, ..
+            }),
+            ..
+        }) = block.get_terminator(context)
+        {
+            let to_block = *to_block;
+            to_block.remove_pred(context, &block);
+        }
+        let md_idx = call_val.get_metadata(context);
+        block.remove_last_instruction(context);
+        block.remove_last_instruction(context);
+        block
+            .append(context)
+            .branch(header_block, args)
+            .add_metadatum(context, md_idx);
+    }
+
+    Ok(true)
+}
+
+/// Finds the calls the function makes to itself in tail position, along with their blocks.
+fn find_tail_calls(context: &Context, function: &Function) -> Vec<(Block, Value)> {
+    let fn_args: FxHashSet<_> = function
+        .args_iter(context)
+        .map(|(_name, arg_val)| *arg_val)
+        .collect();
+    function
+        .block_iter(context)
+        .filter_map(|block| {
+            // The call must be immediately followed by the terminator of its block.
+            let num_instructions = block.num_instructions(context);
+            if num_instructions < 2 {
+                return None;
+            }
+            let call_val = block.get_instruction_at(context, num_instructions - 2)?;
+            let Some(Instruction {
+                op: InstOp::Call(callee, args),
+                ..
+            }) = call_val.get_instruction(context)
+            else {
+                return None;
+            };
+            if callee != function {
+                return None;
+            }
+
+            // Pointers to the locals of the function can't be passed on, as the locals are
+            // reused by the next iteration of the loop.  Pointers it was passed can be.
+            if args.iter().any(|arg| {
+                arg.get_type(context).is_some_and(|ty| ty.is_ptr(context)) && !fn_args.contains(arg)
+            }) {
+                return None;
+            }
+
+            let mut visited = FxHashSet::default();
+            returns_value(context, function, block, Some(call_val), &mut visited)
+                .then_some((block, call_val))
+        })
+        .collect()
+}
+
+/// Whether the terminator of `block` returns `value`, either directly or by branching through
+/// blocks which do nothing else.  `value` is `None` if it is not forwarded to `block`, in which
+/// case it can only be returned if the function returns unit.
+fn returns_value(
+    context: &Context,
+    function: &Function,
+    block: Block,
+    value: Option<Value>,
+    visited: &mut FxHashSet<Block>,
+) -> bool {
+    if !visited.insert(block) {
+        return false;
+    }
+    match block.get_terminator(context) {
+        Some(Instruction {
+            op: InstOp::Ret(ret_val, _),
+            ..
+        }) => Some(*ret_val) == value || function.get_return_type(context).is_unit(context),
+        Some(Instruction {
+            op:
+                InstOp::Branch(BranchToWithArgs {
+                    block: to_block,
+                    args,
+                }),
+            ..
+        }) if to_block.num_instructions(context) == 1 => {
+            let to_value = args
+                .iter()
+                .position(|arg| Some(*arg) == value)
+                .and_then(|idx| to_block.get_arg(context, idx));
+            returns_value(context, function, *to_block, to_value, visited)
+        }
+        _otherwise => false,
+    }
+}
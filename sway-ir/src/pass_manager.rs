@@ -1,14 +1,18 @@
 use crate::{
-    create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_fn_dedup_pass, create_func_dce_pass, create_inline_in_main_pass,
-    create_inline_in_module_pass, create_mem2reg_pass, create_memcpyopt_pass,
-    create_misc_demotion_pass, create_module_printer_pass, create_module_verifier_pass,
-    create_postorder_pass, create_ret_demotion_pass, create_simplify_cfg_pass, create_sroa_pass,
-    Context, Function, IrError, Module, CONSTCOMBINE_NAME, DCE_NAME, FNDEDUP_NAME, FUNC_DCE_NAME,
-    INLINE_MODULE_NAME, MEM2REG_NAME, SIMPLIFYCFG_NAME,
+    create_alloc_demotion_pass, create_arg_demotion_pass, create_const_combine_pass,
+    create_const_demotion_pass, create_cse_pass, create_dce_pass, create_dom_fronts_pass,
+    create_dominators_pass, create_escaped_symbols_pass, create_fn_dedup_pass,
+    create_func_dce_pass, create_inline_in_main_pass, create_inline_in_module_pass,
+    create_licm_pass, create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass,
+    create_module_printer_pass, create_module_verifier_pass, create_postorder_pass,
+    create_ret_demotion_pass, create_sccp_pass, create_simplify_cfg_pass, create_sroa_pass,
+    create_storage_coalesce_pass, create_storage_dse_pass, create_tail_call_pass, Context,
+    Function, IrError, Module, ALLOCDEMOTION_NAME, CONSTCOMBINE_NAME, CSE_NAME, DCE_NAME,
+    FNDEDUP_NAME, FUNC_DCE_NAME, INLINE_MODULE_NAME, LICM_NAME, MEM2REG_NAME, SCCP_NAME,
+    SIMPLIFYCFG_NAME, STORAGE_COALESCE_NAME,
 };
 use downcast_rs::{impl_downcast, Downcast};
+use rayon::prelude::*;
 use rustc_hash::FxHashMap;
 use std::{
     any::{type_name, TypeId},
@@ -16,7 +20,10 @@ use std::{
 };
 
 /// Result of an analysis. Specific result must be downcasted to.
-pub trait AnalysisResultT: Downcast {}
+///
+/// Results must be shareable between threads, as the analyses of different functions are run in
+/// parallel.
+pub trait AnalysisResultT: Downcast + Send + Sync {}
 impl_downcast!(AnalysisResultT);
 pub type AnalysisResult = Box<dyn AnalysisResultT>;
 
@@ -41,6 +48,13 @@ pub enum PassMutability<S: PassScope> {
     Analysis(fn(&Context, analyses: &AnalysisResults, S) -> Result<AnalysisResult, IrError>),
     /// A pass over the IR that can possibly modify it.
     Transform(fn(&mut Context, analyses: &AnalysisResults, S) -> Result<bool, IrError>),
+    /// A pass over the IR that can possibly modify it, but only within its scope.
+    ///
+    /// A function pass local to the function it transforms leaves the other functions, their
+    /// callers included, and the module alone.  The functions of a module are then transformed in
+    /// parallel, each on its own fork of the context.  It is the same as a [`Self::Transform`] for
+    /// a module pass.
+    LocalTransform(fn(&mut Context, analyses: &AnalysisResults, S) -> Result<bool, IrError>),
 }
 
 /// A concrete version of [PassScope].
@@ -198,7 +212,8 @@ impl PassManager {
                             self.analyses.add_result(pass_t.name, m, result);
                         }
                     }
-                    PassMutability::Transform(transform) => {
+                    PassMutability::Transform(transform)
+                    | PassMutability::LocalTransform(transform) => {
                         if transform(ir, &self.analyses, m)? {
                             self.analyses.invalidate_all_results_at_scope(m);
                             for f in m.function_iter(ir) {
@@ -208,25 +223,61 @@ impl PassManager {
                         }
                     }
                 },
-                ScopedPass::FunctionPass(fp) => {
-                    for f in m.function_iter(ir) {
-                        match fp {
-                            PassMutability::Analysis(analysis) => {
-                                if !self.analyses.is_analysis_result_available(pass_t.name, f) {
-                                    let result = analysis(ir, &self.analyses, f)?;
-                                    self.analyses.add_result(pass_t.name, f, result);
-                                }
+                ScopedPass::FunctionPass(fp) => match fp {
+                    PassMutability::Analysis(analysis) => {
+                        // Analyses only read the IR, so the functions are analysed in parallel.
+                        // The first error in the order of the functions is reported, whatever
+                        // the number of threads.
+                        let functions = m
+                            .function_iter(ir)
+                            .filter(|f| {
+                                !self.analyses.is_analysis_result_available(pass_t.name, *f)
+                            })
+                            .collect::<Vec<_>>();
+                        let (ir, analyses) = (&*ir, &self.analyses);
+                        let results = functions
+                            .into_par_iter()
+                            .map(|f| analysis(ir, analyses, f).map(|result| (f, result)))
+                            .collect::<Vec<_>>()
+                            .into_iter()
+                            .collect::<Result<Vec<_>, _>>()?;
+                        for (f, result) in results {
+                            self.analyses.add_result(pass_t.name, f, result);
+                        }
+                    }
+                    PassMutability::LocalTransform(transform) => {
+                        // Each function is transformed on its own fork of the context, in
+                        // parallel, and the forks are joined back in the order of the functions.
+                        // As for analyses, the first error in that order is reported.
+                        let functions = m.function_iter(ir).collect::<Vec<_>>();
+                        let mut forks = ir.fork(functions.len());
+                        let analyses = &self.analyses;
+                        let results = forks
+                            .par_iter_mut()
+                            .zip(functions.par_iter())
+                            .map(|(fork, f)| transform(fork, analyses, *f))
+                            .collect::<Vec<_>>();
+                        ir.join(forks);
+                        for (f, result) in functions.into_iter().zip(results) {
+                            if result? {
+                                self.analyses.invalidate_all_results_at_scope(f);
+                                self.analyses.invalidate_all_results_at_scope(m);
+                                modified = true;
                             }
-                            PassMutability::Transform(transform) => {
-                                if transform(ir, &self.analyses, f)? {
-                                    self.analyses.invalidate_all_results_at_scope(f);
-                                    self.analyses.invalidate_all_results_at_scope(m);
-                                    modified = true;
-                                }
+                        }
+                    }
+                    PassMutability::Transform(transform) => {
+                        // Transforms may change the other functions, so they run one function at
+                        // a time.
+                        for f in m.function_iter(ir) {
+                            if transform(ir, &self.analyses, f)? {
+                                self.analyses.invalidate_all_results_at_scope(f);
+                                self.analyses.invalidate_all_results_at_scope(m);
+                                modified = true;
                             }
                         }
                     }
-                }
+                },
             }
         }
         Ok(modified)
@@ -312,14 +363,21 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_inline_in_module_pass());
     pm.register(create_inline_in_main_pass());
     pm.register(create_const_combine_pass());
+    pm.register(create_sccp_pass());
     pm.register(create_simplify_cfg_pass());
+    pm.register(create_cse_pass());
+    pm.register(create_licm_pass());
     pm.register(create_func_dce_pass());
     pm.register(create_dce_pass());
+    pm.register(create_alloc_demotion_pass());
     pm.register(create_arg_demotion_pass());
     pm.register(create_const_demotion_pass());
     pm.register(create_ret_demotion_pass());
     pm.register(create_misc_demotion_pass());
     pm.register(create_memcpyopt_pass());
+    pm.register(create_storage_coalesce_pass());
+    pm.register(create_storage_dse_pass());
+    pm.register(create_tail_call_pass());
 }
 
 pub fn create_o1_pass_group() -> PassGroup {
@@ -331,8 +389,13 @@ pub fn create_o1_pass_group() -> PassGroup {
     o1.append_pass(FNDEDUP_NAME);
     o1.append_pass(CONSTCOMBINE_NAME);
     o1.append_pass(SIMPLIFYCFG_NAME);
+    o1.append_pass(SCCP_NAME);
     o1.append_pass(CONSTCOMBINE_NAME);
     o1.append_pass(SIMPLIFYCFG_NAME);
+    o1.append_pass(ALLOCDEMOTION_NAME);
+    o1.append_pass(CSE_NAME);
+    o1.append_pass(LICM_NAME);
+    o1.append_pass(STORAGE_COALESCE_NAME);
     o1.append_pass(FUNC_DCE_NAME);
     o1.append_pass(DCE_NAME);
 
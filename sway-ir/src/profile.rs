@@ -0,0 +1,64 @@
+//! Execution profiles of programs, measured while running real workloads and used to bias the
+//! optimizations towards the hot paths of the programs.
+
+use std::collections::BTreeMap;
+
+/// The gas spent in each function of a program, keyed by the name of the function.
+///
+/// As functions are keyed by name, all of the functions of the same name, e.g. the
+/// monomorphizations of a generic function, share their measurements.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionProfile {
+    functions: BTreeMap<String, u64>,
+    total_gas: u64,
+}
+
+impl ExecutionProfile {
+    /// The share of the total gas of the profile, in percent, from which a function is hot.
+    const HOT_FUNCTION_PERCENTAGE: u64 = 1;
+
+    pub fn new(functions: BTreeMap<String, u64>) -> Self {
+        let total_gas = functions
+            .values()
+            .fold(0u64, |total, gas| total.saturating_add(*gas));
+        Self {
+            functions,
+            total_gas,
+        }
+    }
+
+    /// The gas spent in the functions of the given name, if any was measured.
+    pub fn gas(&self, function_name: &str) -> Option<u64> {
+        self.functions.get(function_name).copied()
+    }
+
+    /// Whether the functions of the given name account for a significant share of the gas of the
+    /// profile.
+    pub fn is_hot(&self, function_name: &str) -> bool {
+        self.gas(function_name).map_or(false, |gas| {
+            gas > 0
+                && u128::from(gas) * 100
+                    >= u128::from(self.total_gas) * u128::from(Self::HOT_FUNCTION_PERCENTAGE)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hot_functions() {
+        let profile = ExecutionProfile::new(BTreeMap::from([
+            ("transfer".to_string(), 9_000),
+            ("balance_of".to_string(), 990),
+            ("owner".to_string(), 10),
+            ("unused".to_string(), 0),
+        ]));
+        assert!(profile.is_hot("transfer"));
+        assert!(profile.is_hot("balance_of"));
+        assert!(!profile.is_hot("owner"));
+        assert!(!profile.is_hot("unused"));
+        assert!(!profile.is_hot("unknown"));
+    }
+}
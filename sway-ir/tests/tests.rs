@@ -1,11 +1,15 @@
 use std::path::PathBuf;
 
 use sway_ir::{
-    create_arg_demotion_pass, create_const_combine_pass, create_const_demotion_pass,
-    create_dce_pass, create_dom_fronts_pass, create_dominators_pass, create_escaped_symbols_pass,
-    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, optimize as opt, register_known_passes,
-    Context, ExperimentalFlags, PassGroup, PassManager, DCE_NAME, MEM2REG_NAME, SROA_NAME,
+    create_alloc_demotion_pass, create_arg_demotion_pass, create_const_combine_pass,
+    create_const_demotion_pass, create_cse_pass, create_dce_pass, create_dom_fronts_pass,
+    create_dominators_pass, create_escaped_symbols_pass, create_func_dce_pass,
+    create_inline_in_module_pass, create_licm_pass, create_mem2reg_pass, create_memcpyopt_pass,
+    create_misc_demotion_pass, create_o1_pass_group, create_postorder_pass,
+    create_ret_demotion_pass, create_sccp_pass, create_simplify_cfg_pass,
+    create_storage_coalesce_pass, create_storage_dse_pass, create_tail_call_pass, optimize as opt,
+    register_known_passes, Context, ExperimentalFlags, PassGroup, PassManager, DCE_NAME,
+    MEM2REG_NAME, SROA_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -120,6 +124,24 @@ fn inline() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn inline_budget() {
+    run_tests("inline_budget", |first_line, ir: &mut Context| {
+        // The budget is given on the first line, e.g. `// budget 10`.
+        ir.inline_budget = first_line
+            .strip_prefix("// budget ")
+            .and_then(|budget| budget.trim().parse().ok());
+
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_inline_in_module_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 // Clippy suggests using the map iterator below directly instead of collecting from it first, but
 // if we try that then we have borrowing issues with `ir` which is used within the closure.
 #[allow(clippy::needless_collect)]
@@ -165,6 +187,22 @@ fn dce() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[allow(clippy::needless_collect)]
+#[test]
+fn func_dce() {
+    run_tests("func_dce", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_func_dce_pass());
+        pass_group.append_pass(pass);
+        let modified = pass_mgr.run(ir, &pass_group).unwrap();
+        assert_eq!(ir.eliminated_functions.functions, 2);
+        modified
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[allow(clippy::needless_collect)]
 #[test]
 fn mem2reg() {
@@ -238,6 +276,20 @@ fn demote_misc() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn demote_alloc() {
+    run_tests("demote_alloc", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_escaped_symbols_pass());
+        let pass = pass_mgr.register(create_alloc_demotion_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[allow(clippy::needless_collect)]
 #[test]
 fn memcpyopt() {
@@ -269,6 +321,138 @@ fn sroa() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn sccp() {
+    run_tests("sccp", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_sccp_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn cse() {
+    run_tests("cse", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_cse_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn licm() {
+    run_tests("licm", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_licm_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn storage_coalesce() {
+    run_tests("storage_coalesce", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_escaped_symbols_pass());
+        let pass = pass_mgr.register(create_storage_coalesce_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn storage_dse() {
+    run_tests("storage_dse", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_storage_dse_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn tail_call() {
+    run_tests("tail_call", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_tail_call_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn parallel_passes_match_serial_passes() {
+    // Runs the O1 passes over the IR in a pool of the given number of threads, and returns the
+    // optimised IR.  The function passes transforming each function on its own fork of the
+    // context must leave the same IR whatever the number of threads.
+    fn optimize(input: &str, num_threads: usize) -> String {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            let source_engine = SourceEngine::default();
+            let mut ir =
+                sway_ir::parser::parse(input, &source_engine, ExperimentalFlags::default())
+                    .unwrap();
+            let mut pass_mgr = PassManager::default();
+            register_known_passes(&mut pass_mgr);
+            pass_mgr.run(&mut ir, &create_o1_pass_group()).unwrap();
+            sway_ir::printer::to_string(&ir)
+        })
+    }
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    for sub_dir in [
+        "cse",
+        "dce",
+        "inline",
+        "licm",
+        "mem2reg",
+        "sccp",
+        "simplify_cfg",
+        "sroa",
+    ] {
+        let dir: PathBuf = format!("{manifest_dir}/tests/{sub_dir}").into();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read_to_string(&path).unwrap();
+            assert_eq!(
+                optimize(&input, 1),
+                optimize(&input, 4),
+                "{}",
+                path.display()
+            );
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
@@ -1,6 +1,8 @@
 library;
 
+use ::ops::Eq;
 use ::raw_slice::*;
+use ::slice::*;
 
 pub struct Buffer {
     buffer: raw_ptr,
@@ -180,6 +182,25 @@ impl AbiEncode for raw_slice {
     }
 }
 
+impl<T> AbiEncode for &[T]
+where
+    T: AbiEncode,
+{
+    fn abi_encode(self, ref mut buffer: Buffer) {
+        let len = self.len();
+        buffer.push(len);
+
+        let ptr = self.ptr();
+
+        let mut i = 0;
+        while i < len {
+            let item = ptr.add::<T>(i).read::<T>();
+            item.abi_encode(buffer);
+            i += 1;
+        }
+    }
+}
+
 // str arrays
 
 impl AbiEncode for str[0] {
@@ -395,6 +416,305 @@ where
     buffer.as_raw_slice()
 }
 
+pub struct BufferReader {
+    ptr: raw_ptr,
+    len: u64,
+    pos: u64,
+}
+
+impl BufferReader {
+    pub fn from_parts(ptr: raw_ptr, len: u64) -> BufferReader {
+        BufferReader {
+            ptr,
+            len,
+            pos: 0,
+        }
+    }
+
+    pub fn read_bytes(ref mut self, count: u64) -> raw_ptr {
+        if self.pos + count > self.len {
+            __revert(123456790);
+        }
+
+        let ptr = self.ptr.add::<u8>(self.pos);
+        self.pos += count;
+        ptr
+    }
+
+    pub fn read<T>(ref mut self) -> T {
+        self.read_bytes(__size_of::<T>()).read::<T>()
+    }
+}
+
+pub trait AbiDecode {
+    fn abi_decode(ref mut buffer: BufferReader) -> Self;
+}
+
+impl AbiDecode for () {
+    fn abi_decode(ref mut _buffer: BufferReader) -> () {}
+}
+
+impl AbiDecode for b256 {
+    fn abi_decode(ref mut buffer: BufferReader) -> b256 {
+        buffer.read::<b256>()
+    }
+}
+
+impl AbiDecode for bool {
+    fn abi_decode(ref mut buffer: BufferReader) -> bool {
+        match buffer.read::<u8>() {
+            0 => false,
+            1 => true,
+            _ => __revert(0),
+        }
+    }
+}
+
+impl AbiDecode for u256 {
+    fn abi_decode(ref mut buffer: BufferReader) -> u256 {
+        buffer.read::<u256>()
+    }
+}
+
+impl AbiDecode for u64 {
+    fn abi_decode(ref mut buffer: BufferReader) -> u64 {
+        buffer.read::<u64>()
+    }
+}
+
+impl AbiDecode for u32 {
+    fn abi_decode(ref mut buffer: BufferReader) -> u32 {
+        let a = buffer.read::<u8>();
+        let b = buffer.read::<u8>();
+        let c = buffer.read::<u8>();
+        let d = buffer.read::<u8>();
+        asm(a: a, b: b, c: c, d: d, i: 0x8, j: 0x10, k: 0x18, r1) {
+            sll r1 a k;
+            sll b b j;
+            or r1 r1 b;
+            sll c c i;
+            or r1 r1 c;
+            or r1 r1 d;
+            r1: u32
+        }
+    }
+}
+
+impl AbiDecode for u16 {
+    fn abi_decode(ref mut buffer: BufferReader) -> u16 {
+        let a = buffer.read::<u8>();
+        let b = buffer.read::<u8>();
+        asm(a: a, b: b, i: 0x8, r1) {
+            sll r1 a i;
+            or r1 r1 b;
+            r1: u16
+        }
+    }
+}
+
+impl AbiDecode for u8 {
+    fn abi_decode(ref mut buffer: BufferReader) -> u8 {
+        buffer.read::<u8>()
+    }
+}
+
+impl AbiDecode for str {
+    fn abi_decode(ref mut buffer: BufferReader) -> str {
+        let len = buffer.read::<u64>();
+        let ptr = buffer.read_bytes(len);
+        asm(s: (ptr, len)) {
+            s: str
+        }
+    }
+}
+
+impl AbiDecode for raw_slice {
+    fn abi_decode(ref mut buffer: BufferReader) -> raw_slice {
+        let len = buffer.read::<u64>();
+        let ptr = buffer.read_bytes(len);
+        raw_slice::from_parts::<u8>(ptr, len)
+    }
+}
+
+// str arrays
+
+impl AbiDecode for str[0] {
+    fn abi_decode(ref mut _buffer: BufferReader) -> str[0] {
+        __to_str_array("")
+    }
+}
+
+impl AbiDecode for str[1] {
+    fn abi_decode(ref mut buffer: BufferReader) -> str[1] {
+        buffer.read::<str[1]>()
+    }
+}
+
+impl AbiDecode for str[2] {
+    fn abi_decode(ref mut buffer: BufferReader) -> str[2] {
+        buffer.read::<str[2]>()
+    }
+}
+
+impl AbiDecode for str[3] {
+    fn abi_decode(ref mut buffer: BufferReader) -> str[3] {
+        buffer.read::<str[3]>()
+    }
+}
+
+impl AbiDecode for str[4] {
+    fn abi_decode(ref mut buffer: BufferReader) -> str[4] {
+        buffer.read::<str[4]>()
+    }
+}
+
+impl AbiDecode for str[5] {
+    fn abi_decode(ref mut buffer: BufferReader) -> str[5] {
+        buffer.read::<str[5]>()
+    }
+}
+
+// arrays
+
+impl<T> AbiDecode for [T; 1]
+where
+    T: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> [T; 1] {
+        [T::abi_decode(buffer)]
+    }
+}
+
+impl<T> AbiDecode for [T; 2]
+where
+    T: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> [T; 2] {
+        [T::abi_decode(buffer), T::abi_decode(buffer)]
+    }
+}
+
+impl<T> AbiDecode for [T; 3]
+where
+    T: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> [T; 3] {
+        [T::abi_decode(buffer), T::abi_decode(buffer), T::abi_decode(buffer)]
+    }
+}
+
+impl<T> AbiDecode for [T; 4]
+where
+    T: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> [T; 4] {
+        [
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+        ]
+    }
+}
+
+impl<T> AbiDecode for [T; 5]
+where
+    T: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> [T; 5] {
+        [
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+            T::abi_decode(buffer),
+        ]
+    }
+}
+
+// Tuples
+
+impl<A, B> AbiDecode for (A, B)
+where
+    A: AbiDecode,
+    B: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> (A, B) {
+        (A::abi_decode(buffer), B::abi_decode(buffer))
+    }
+}
+
+impl<A, B, C> AbiDecode for (A, B, C)
+where
+    A: AbiDecode,
+    B: AbiDecode,
+    C: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> (A, B, C) {
+        (
+            A::abi_decode(buffer),
+            B::abi_decode(buffer),
+            C::abi_decode(buffer),
+        )
+    }
+}
+
+impl<A, B, C, D> AbiDecode for (A, B, C, D)
+where
+    A: AbiDecode,
+    B: AbiDecode,
+    C: AbiDecode,
+    D: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> (A, B, C, D) {
+        (
+            A::abi_decode(buffer),
+            B::abi_decode(buffer),
+            C::abi_decode(buffer),
+            D::abi_decode(buffer),
+        )
+    }
+}
+
+impl<A, B, C, D, E> AbiDecode for (A, B, C, D, E)
+where
+    A: AbiDecode,
+    B: AbiDecode,
+    C: AbiDecode,
+    D: AbiDecode,
+    E: AbiDecode,
+{
+    fn abi_decode(ref mut buffer: BufferReader) -> (A, B, C, D, E) {
+        (
+            A::abi_decode(buffer),
+            B::abi_decode(buffer),
+            C::abi_decode(buffer),
+            D::abi_decode(buffer),
+            E::abi_decode(buffer),
+        )
+    }
+}
+
+/// Decodes a value of type `T` from the beginning of `data`, as encoded by [encode].
+/// Reverts if `data` is too short to hold a `T`.
+pub fn abi_decode<T>(data: raw_slice) -> T
+where
+    T: AbiDecode,
+{
+    let mut buffer = BufferReader::from_parts(data.ptr(), data.number_of_bytes());
+    T::abi_decode(buffer)
+}
+
+fn assert_decoding<T>(value: T)
+where
+    T: AbiEncode + AbiDecode + Eq,
+{
+    let decoded = abi_decode::<T>(encode(value));
+    if !(decoded == value) {
+        __revert(0);
+    }
+}
+
 fn assert_encoding<T, SLICE>(value: T, expected: SLICE)
 where
     T: AbiEncode,
@@ -508,3 +828,43 @@ fn ok_encode() {
     assert_encoding([255u8; 4], [255u8; 4]);
     assert_encoding([255u8; 5], [255u8; 5]);
 }
+
+#[test]
+fn ok_decode() {
+    // bool
+    assert_decoding(false);
+    assert_decoding(true);
+
+    // numbers
+    assert_decoding(0u8);
+    assert_decoding(255u8);
+    assert_decoding(0u16);
+    assert_decoding(65535u16);
+    assert_decoding(0x1234u16);
+    assert_decoding(0u32);
+    assert_decoding(4294967295u32);
+    assert_decoding(0x12345678u32);
+    assert_decoding(0u64);
+    assert_decoding(18446744073709551615u64);
+    assert_decoding(0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFu256);
+    assert_decoding(0x0000000000000000000000000000000000000000000000000000000000000001);
+
+    // strings
+    use ::str::*;
+    let s: str = abi_decode(encode("Hello"));
+    if s != "Hello" {
+        __revert(0);
+    }
+
+    // tuples
+    let (a, b, c): (u8, u16, u32) = abi_decode(encode((1u8, 2u16, 3u32)));
+    if a != 1u8 || b != 2u16 || c != 3u32 {
+        __revert(0);
+    }
+
+    // arrays
+    let array: [u64; 3] = abi_decode(encode([1u64, 2u64, 3u64]));
+    if array[0] != 1u64 || array[1] != 2u64 || array[2] != 3u64 {
+        __revert(0);
+    }
+}
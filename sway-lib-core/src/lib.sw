@@ -5,8 +5,10 @@ pub mod raw_ptr;
 pub mod raw_slice;
 pub mod r#str;
 pub mod ops;
+pub mod slice;
 pub mod primitive_conversions;
 pub mod never;
 pub mod r#storage;
 pub mod prelude;
 pub mod codec;
+pub mod marker;
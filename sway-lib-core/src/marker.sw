@@ -0,0 +1,80 @@
+library;
+
+//! Marker traits that the compiler implements for all the types with a given property.
+//! They have no methods, and implementing them with `impl` blocks has no effect. Their only
+//! purpose is to be used as trait constraints on type parameters.
+//!
+//! Also defines `PhantomData`, which marks a type parameter of a struct or enum as used.
+
+/// Implemented for all types whose values are stored entirely in place, without any pointers or
+/// slices.
+///
+/// # Additional Information
+///
+/// These are the primitive types, except for `raw_ptr`, `raw_slice` and string slices, string
+/// arrays, and the arrays, tuples, structs and enums made of `FixedSize` types.
+/// Values of these types can be stored in contract storage.
+///
+/// # Examples
+///
+/// ```sway
+/// fn store<T>(key: b256, value: T) where T: FixedSize {
+///     std::storage::storage_api::write(key, 0, value);
+/// }
+/// ```
+pub trait FixedSize {}
+
+/// Implemented for all types whose values can be passed to and returned from contract calls, and
+/// logged.
+///
+/// # Additional Information
+///
+/// These are all the `FixedSize` types, along with `raw_slice`s, string slices, and the arrays,
+/// tuples, structs and enums made of `Encodable` types.
+/// Types that contain pointers or references, such as `raw_ptr`, are not `Encodable`.
+///
+/// # Examples
+///
+/// ```sway
+/// fn log_twice<T>(value: T) where T: Encodable {
+///     __log(value);
+///     __log(value);
+/// }
+/// ```
+pub trait Encodable {}
+
+/// A type with no fields and no size that marks a type parameter as used.
+///
+/// # Additional Information
+///
+/// Type parameters of structs and enums are expected to be used by their fields or variants.
+/// A field of type `PhantomData<T>` uses `T` without storing any value of it, which is useful for
+/// types that only refer to `T`, such as typed handles to data stored elsewhere.
+///
+/// # Examples
+///
+/// ```sway
+/// struct Handle<T> {
+///     id: u64,
+///     marker: PhantomData<T>,
+/// }
+///
+/// fn handle<T>(id: u64) -> Handle<T> {
+///     Handle {
+///         id,
+///         marker: PhantomData::new(),
+///     }
+/// }
+/// ```
+pub struct PhantomData<T> {}
+
+impl<T> PhantomData<T> {
+    /// Creates a new `PhantomData`.
+    ///
+    /// # Returns
+    ///
+    /// * [PhantomData<T>] - The marker for the type `T`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
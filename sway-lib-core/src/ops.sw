@@ -2,6 +2,17 @@ library;
 
 use ::primitives::*;
 
+/// Returns whether overflowing arithmetic reverts, rather than wrapping around.
+///
+/// It wraps around when the `F_WRAPPING` flag of the `$flag` register is set, e.g. in programs
+/// built with `overflow-checks = false`.
+fn panic_on_overflow_enabled() -> bool {
+    let flags = asm() {
+        flag
+    };
+    __eq(__and(flags, 0b10), 0)
+}
+
 /// Trait for the addition of two values.
 pub trait Add {
     /// Add two values of the same type.
@@ -60,7 +71,11 @@ impl Add for u32 {
         let res = __add(self, other);
         if __gt(res, Self::max()) {
             // integer overflow
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             // no overflow
             res
@@ -72,7 +87,11 @@ impl Add for u16 {
     fn add(self, other: Self) -> Self {
         let res = __add(self, other);
         if __gt(res, Self::max()) {
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             res
         }
@@ -83,7 +102,11 @@ impl Add for u8 {
     fn add(self, other: Self) -> Self {
         let res = __add(self, other);
         if __gt(res, Self::max()) {
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             res
         }
@@ -140,23 +163,23 @@ impl Subtract for u64 {
     }
 }
 
-// unlike addition, underflowing subtraction does not need special treatment
-// because VM handles underflow
+// unlike addition, underflowing subtraction reverts in the VM, unless overflowing
+// arithmetic wraps around, in which case the u64 result is truncated to the type
 impl Subtract for u32 {
     fn subtract(self, other: Self) -> Self {
-        __sub(self, other)
+        __and(__sub(self, other), Self::max())
     }
 }
 
 impl Subtract for u16 {
     fn subtract(self, other: Self) -> Self {
-        __sub(self, other)
+        __and(__sub(self, other), Self::max())
     }
 }
 
 impl Subtract for u8 {
     fn subtract(self, other: Self) -> Self {
-        __sub(self, other)
+        __and(__sub(self, other), Self::max())
     }
 }
 
@@ -218,7 +241,11 @@ impl Multiply for u32 {
         let res = __mul(self, other);
         if __gt(res, Self::max()) {
             // integer overflow
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             // no overflow
             res
@@ -230,7 +257,11 @@ impl Multiply for u16 {
     fn multiply(self, other: Self) -> Self {
         let res = __mul(self, other);
         if __gt(res, Self::max()) {
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             res
         }
@@ -241,7 +272,11 @@ impl Multiply for u8 {
     fn multiply(self, other: Self) -> Self {
         let res = __mul(self, other);
         if __gt(res, Self::max()) {
-            __revert(0)
+            if panic_on_overflow_enabled() {
+                __revert(0)
+            } else {
+                __and(res, Self::max())
+            }
         } else {
             res
         }
@@ -388,6 +423,220 @@ impl Mod for u8 {
     }
 }
 
+/// Trait for adding a value to another in place, i.e. the `+=` operator.
+///
+/// Types implementing [Add] can be used with `+=` without implementing this trait, in which case
+/// `a += b` is the same as `a = a + b`.
+pub trait AddAssign {
+    /// Add a value to self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Self] - The value to add to self.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct MyStruct {
+    ///     val: u64,
+    /// }
+    ///
+    /// impl AddAssign for MyStruct {
+    ///     fn add_assign(ref mut self, other: Self) {
+    ///         self.val += other.val;
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut struct1 = MyStruct { val: 1 };
+    ///     struct1 += MyStruct { val: 2 };
+    ///     assert(struct1.val == 3);
+    /// }
+    /// ```
+    fn add_assign(ref mut self, other: Self);
+}
+
+/// Trait for subtracting a value from another in place, i.e. the `-=` operator.
+///
+/// Types implementing [Subtract] can be used with `-=` without implementing this trait, in which
+/// case `a -= b` is the same as `a = a - b`.
+pub trait SubtractAssign {
+    /// Subtract a value from self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Self] - The value to subtract from self.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct MyStruct {
+    ///     val: u64,
+    /// }
+    ///
+    /// impl SubtractAssign for MyStruct {
+    ///     fn subtract_assign(ref mut self, other: Self) {
+    ///         self.val -= other.val;
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut struct1 = MyStruct { val: 3 };
+    ///     struct1 -= MyStruct { val: 1 };
+    ///     assert(struct1.val == 2);
+    /// }
+    /// ```
+    fn subtract_assign(ref mut self, other: Self);
+}
+
+/// Trait for multiplying a value by another in place, i.e. the `*=` operator.
+///
+/// Types implementing [Multiply] can be used with `*=` without implementing this trait, in which
+/// case `a *= b` is the same as `a = a * b`.
+pub trait MultiplyAssign {
+    /// Multiply self by a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Self] - The value to multiply self by.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct MyStruct {
+    ///     val: u64,
+    /// }
+    ///
+    /// impl MultiplyAssign for MyStruct {
+    ///     fn multiply_assign(ref mut self, other: Self) {
+    ///         self.val *= other.val;
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut struct1 = MyStruct { val: 3 };
+    ///     struct1 *= MyStruct { val: 2 };
+    ///     assert(struct1.val == 6);
+    /// }
+    /// ```
+    fn multiply_assign(ref mut self, other: Self);
+}
+
+/// Trait for dividing a value by another in place, i.e. the `/=` operator.
+///
+/// Types implementing [Divide] can be used with `/=` without implementing this trait, in which
+/// case `a /= b` is the same as `a = a / b`.
+pub trait DivideAssign {
+    /// Divide self by a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: [Self] - The value to divide self by.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct MyStruct {
+    ///     val: u64,
+    /// }
+    ///
+    /// impl DivideAssign for MyStruct {
+    ///     fn divide_assign(ref mut self, other: Self) {
+    ///         self.val /= other.val;
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut struct1 = MyStruct { val: 6 };
+    ///     struct1 /= MyStruct { val: 2 };
+    ///     assert(struct1.val == 3);
+    /// }
+    /// ```
+    fn divide_assign(ref mut self, other: Self);
+}
+
+/// A range of indices, i.e. `start..end`, containing the indices from `start` up to, but not
+/// including, `end`.
+///
+/// Indexing into a container with a range, e.g. `array[1..3]`, returns a slice of the elements
+/// of the container within the range.
+pub struct Range {
+    /// The first index of the range.
+    pub start: u64,
+    /// The index right after the last index of the range.
+    pub end: u64,
+}
+
+/// Trait for indexing into a container, i.e. `container[index]`.
+///
+/// `I` is the type of the index and `T` the type of the element it refers to.
+pub trait Index<I, T> {
+    /// Get the element at the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [I] - The index of the element.
+    ///
+    /// # Returns
+    ///
+    /// * [T] - The element at the index.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct Pair {
+    ///     first: u64,
+    ///     second: u64,
+    /// }
+    ///
+    /// impl Index<bool, u64> for Pair {
+    ///     fn index(self, index: bool) -> u64 {
+    ///         if index { self.second } else { self.first }
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let pair = Pair { first: 1, second: 2 };
+    ///     assert(pair[true] == 2);
+    /// }
+    /// ```
+    fn index(self, index: I) -> T;
+}
+
+/// Trait for assigning to an element of a container, i.e. `container[index] = value`.
+///
+/// `I` is the type of the index and `T` the type of the element it refers to.
+pub trait IndexMut<I, T> {
+    /// Set the element at the index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: [I] - The index of the element.
+    /// * `value`: [T] - The new value of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// struct Pair {
+    ///     first: u64,
+    ///     second: u64,
+    /// }
+    ///
+    /// impl IndexMut<bool, u64> for Pair {
+    ///     fn index_assign(ref mut self, index: bool, value: u64) {
+    ///         if index { self.second = value; } else { self.first = value; }
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut pair = Pair { first: 1, second: 2 };
+    ///     pair[true] = 3;
+    ///     assert(pair.second == 3);
+    /// }
+    /// ```
+    fn index_assign(ref mut self, index: I, value: T);
+}
+
 /// Trait to invert a type.
 pub trait Not {
     /// Inverts the value of the type.
@@ -9,6 +9,8 @@ use ::raw_ptr::*;
 use ::raw_slice::*;
 use ::never::*;
 use ::ops::*;
+use ::slice::*;
 use ::storage::*;
 use ::str::*;
 use ::codec::*;
+use ::marker::*;
@@ -0,0 +1,129 @@
+library;
+
+use ::raw_ptr::*;
+use ::ops::*;
+
+/// Returns a slice from a pointer and a length.
+///
+/// # Arguments
+///
+/// * `ptr`: [raw_ptr] - The location in memory of the first element of the slice.
+/// * `len`: [u64] - The number of elements of the slice.
+///
+/// # Returns
+///
+/// * [&[T]] - The newly created slice.
+///
+/// # Examples
+///
+/// ```sway
+/// use core::slice::from_parts;
+///
+/// fn foo() {
+///     let array = [1, 2, 3];
+///     let slice = from_parts::<u64>(__addr_of(array), 3);
+///     assert(slice.len() == 3);
+/// }
+/// ```
+pub fn from_parts<T>(ptr: raw_ptr, len: u64) -> &[T] {
+    asm(parts: (ptr, len)) {
+        parts: &[T]
+    }
+}
+
+/// Returns a pointer and length from a slice.
+fn into_parts<T>(slice: &[T]) -> (raw_ptr, u64) {
+    asm(ptr: slice) {
+        ptr: (raw_ptr, u64)
+    }
+}
+
+impl<T> &[T] {
+    /// Returns the pointer to the first element of the slice.
+    ///
+    /// # Returns
+    ///
+    /// * [raw_ptr] - The location in memory of the first element of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let array = [1, 2, 3];
+    ///     let slice = array[1..3];
+    ///     assert(slice.ptr().read::<u64>() == 2);
+    /// }
+    /// ```
+    pub fn ptr(self) -> raw_ptr {
+        into_parts(self).0
+    }
+
+    /// Returns the number of elements of the slice.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of elements of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let array = [1, 2, 3];
+    ///     let slice = array[1..3];
+    ///     assert(slice.len() == 2);
+    /// }
+    /// ```
+    pub fn len(self) -> u64 {
+        into_parts(self).1
+    }
+
+    /// Returns whether the slice has no elements.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the slice has no elements, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let array = [1, 2, 3];
+    ///     assert(array[1..1].is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Index<u64, T> for &[T] {
+    /// Gets the element at `index`, i.e. `slice[index]`.
+    ///
+    /// # Reverts
+    ///
+    /// * If `index` is greater than or equal to the length of the slice.
+    fn index(self, index: u64) -> T {
+        if index >= self.len() {
+            __revert(0);
+        }
+
+        self.ptr().add::<T>(index).read::<T>()
+    }
+}
+
+impl<T> Index<Range, &[T]> for &[T] {
+    /// Gets the subslice `range.start..range.end`, i.e. `slice[start..end]`, without copying
+    /// its elements.
+    ///
+    /// # Reverts
+    ///
+    /// * If `range.start` is greater than `range.end`.
+    /// * If `range.end` is greater than the length of the slice.
+    fn index(self, range: Range) -> &[T] {
+        if range.start > range.end || range.end > self.len() {
+            __revert(0);
+        }
+
+        from_parts::<T>(self.ptr().add::<T>(range.start), range.end - range.start)
+    }
+}
@@ -10,6 +10,7 @@ library;
 /// `field_id` is a unique identifier for the storage field being referred to, it is different even
 /// for multiple zero sized fields that might live at the same location but
 /// represent different storage constructs.
+#[allow(unused_type_parameters)]
 pub struct StorageKey<T> {
     /// The assigned location in storage.
     slot: b256,
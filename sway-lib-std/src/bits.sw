@@ -0,0 +1,476 @@
+//! Bit manipulation utilities and typed sets of flags packed into a single word.
+library;
+
+use ::assert::assert;
+
+/// Counts the bits set to one.
+pub trait CountOnes {
+    fn count_ones(self) -> u64;
+}
+
+/// Counts the leading bits set to zero, starting from the most significant bit.
+pub trait LeadingZeros {
+    fn leading_zeros(self) -> u64;
+}
+
+/// Rotates the bits, those shifted out at one end coming back in at the other end.
+pub trait Rotate {
+    fn rotate_left(self, n: u64) -> Self;
+    fn rotate_right(self, n: u64) -> Self;
+}
+
+impl CountOnes for u64 {
+    fn count_ones(self) -> u64 {
+        count_ones_u64(self)
+    }
+}
+
+impl CountOnes for u32 {
+    fn count_ones(self) -> u64 {
+        count_ones_u64(self.as_u64())
+    }
+}
+
+impl CountOnes for u16 {
+    fn count_ones(self) -> u64 {
+        count_ones_u64(self.as_u64())
+    }
+}
+
+impl CountOnes for u8 {
+    fn count_ones(self) -> u64 {
+        count_ones_u64(self.as_u64())
+    }
+}
+
+impl LeadingZeros for u64 {
+    fn leading_zeros(self) -> u64 {
+        leading_zeros_u64(self)
+    }
+}
+
+impl LeadingZeros for u32 {
+    fn leading_zeros(self) -> u64 {
+        leading_zeros_u64(self.as_u64()) - 32
+    }
+}
+
+impl LeadingZeros for u16 {
+    fn leading_zeros(self) -> u64 {
+        leading_zeros_u64(self.as_u64()) - 48
+    }
+}
+
+impl LeadingZeros for u8 {
+    fn leading_zeros(self) -> u64 {
+        leading_zeros_u64(self.as_u64()) - 56
+    }
+}
+
+impl Rotate for u64 {
+    fn rotate_left(self, n: u64) -> Self {
+        rotate_left_u64(self, n, 64)
+    }
+
+    fn rotate_right(self, n: u64) -> Self {
+        rotate_left_u64(self, 64 - n % 64, 64)
+    }
+}
+
+impl Rotate for u32 {
+    fn rotate_left(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), n, 32);
+        asm(r1: value) {
+            r1: u32
+        }
+    }
+
+    fn rotate_right(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), 32 - n % 32, 32);
+        asm(r1: value) {
+            r1: u32
+        }
+    }
+}
+
+impl Rotate for u16 {
+    fn rotate_left(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), n, 16);
+        asm(r1: value) {
+            r1: u16
+        }
+    }
+
+    fn rotate_right(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), 16 - n % 16, 16);
+        asm(r1: value) {
+            r1: u16
+        }
+    }
+}
+
+impl Rotate for u8 {
+    fn rotate_left(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), n, 8);
+        asm(r1: value) {
+            r1: u8
+        }
+    }
+
+    fn rotate_right(self, n: u64) -> Self {
+        let value = rotate_left_u64(self.as_u64(), 8 - n % 8, 8);
+        asm(r1: value) {
+            r1: u8
+        }
+    }
+}
+
+// Counts the bits set by adding them up in parallel, within pairs of bits, then within nibbles,
+// then within bytes, and finally across the bytes. None of the additions overflows.
+fn count_ones_u64(value: u64) -> u64 {
+    asm(
+        x: value,
+        m1: 0x5555_5555_5555_5555,
+        m2: 0x3333_3333_3333_3333,
+        m4: 0x0f0f_0f0f_0f0f_0f0f,
+        t,
+    ) {
+        srli t x i1;
+        and t t m1;
+        sub x x t;
+        srli t x i2;
+        and t t m2;
+        and x x m2;
+        add x x t;
+        srli t x i4;
+        add x x t;
+        and x x m4;
+        srli t x i8;
+        add x x t;
+        srli t x i16;
+        add x x t;
+        srli t x i32;
+        add x x t;
+        andi x x i127;
+        x: u64
+    }
+}
+
+// The index of the most significant bit set is the binary logarithm, rounded down.
+fn leading_zeros_u64(value: u64) -> u64 {
+    if value == 0 {
+        return 64;
+    }
+    let base: u64 = 2;
+    let log = asm(r1: value, r2: base, r3) {
+        mlog r3 r1 r2;
+        r3: u64
+    };
+    63 - log
+}
+
+// Rotates the lowest `width` bits of `value` to the left.
+fn rotate_left_u64(value: u64, n: u64, width: u64) -> u64 {
+    let n = n % width;
+    if n == 0 {
+        return value;
+    }
+    let rotated = (value << n) | (value >> (width - n));
+    if width == 64 {
+        rotated
+    } else {
+        rotated & ((1 << width) - 1)
+    }
+}
+
+/// A flag of a `Bitflags` set, e.g. a variant of an enum.
+pub trait Flag {
+    /// The index of the bit representing the flag, which must be less than 64.
+    fn bit(self) -> u64;
+}
+
+/// A set of flags of type `T`, packed into a single word, e.g. to store them in a single storage
+/// slot.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::bits::{Bitflags, Flag};
+///
+/// enum Role {
+///     Minter: (),
+///     Burner: (),
+///     Pauser: (),
+/// }
+///
+/// impl Flag for Role {
+///     fn bit(self) -> u64 {
+///         match self {
+///             Role::Minter => 0,
+///             Role::Burner => 1,
+///             Role::Pauser => 2,
+///         }
+///     }
+/// }
+///
+/// storage {
+///     roles: Bitflags<Role> = Bitflags::new(),
+/// }
+///
+/// #[storage(read, write)]
+/// fn grant_minter() {
+///     let mut roles = storage.roles.read();
+///     roles.insert(Role::Minter);
+///     storage.roles.write(roles);
+/// }
+/// ```
+#[allow(unused_type_parameters)]
+pub struct Bitflags<T> {
+    bits: u64,
+}
+
+impl<T> Bitflags<T> {
+    /// Creates an empty set of flags.
+    ///
+    /// # Returns
+    ///
+    /// * [Bitflags<T>] - The set without any flag.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let roles: Bitflags<Role> = Bitflags::new();
+    ///     assert(roles.is_empty());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Creates a set of flags from the word packing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits`: [u64] - The bits of the flags of the set.
+    ///
+    /// # Returns
+    ///
+    /// * [Bitflags<T>] - The set of flags whose bits are set.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let roles: Bitflags<Role> = Bitflags::from_bits(0b101);
+    ///     assert(roles.contains(Role::Pauser));
+    /// }
+    /// ```
+    pub fn from_bits(bits: u64) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the word packing the flags of the set.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The bits of the flags of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let mut roles: Bitflags<Role> = Bitflags::new();
+    ///     roles.insert(Role::Burner);
+    ///     assert(roles.bits() == 0b10);
+    /// }
+    /// ```
+    pub fn bits(self) -> u64 {
+        self.bits
+    }
+
+    /// Returns whether the set has no flag.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if no flag is set, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let roles: Bitflags<Role> = Bitflags::new();
+    ///     assert(roles.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl<T> Bitflags<T>
+where
+    T: Flag,
+{
+    /// Returns whether the flag is in the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag`: [T] - The flag to look for.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the flag is set, otherwise `false`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the bit of the flag is not less than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let mut roles: Bitflags<Role> = Bitflags::new();
+    ///     roles.insert(Role::Minter);
+    ///     assert(roles.contains(Role::Minter));
+    ///     assert(!roles.contains(Role::Burner));
+    /// }
+    /// ```
+    pub fn contains(self, flag: T) -> bool {
+        self.bits & mask(flag) != 0
+    }
+
+    /// Adds the flag to the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag`: [T] - The flag to set.
+    ///
+    /// # Reverts
+    ///
+    /// * When the bit of the flag is not less than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let mut roles: Bitflags<Role> = Bitflags::new();
+    ///     roles.insert(Role::Minter);
+    ///     assert(roles.contains(Role::Minter));
+    /// }
+    /// ```
+    pub fn insert(ref mut self, flag: T) {
+        self.bits = self.bits | mask(flag);
+    }
+
+    /// Removes the flag from the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag`: [T] - The flag to unset.
+    ///
+    /// # Reverts
+    ///
+    /// * When the bit of the flag is not less than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let mut roles: Bitflags<Role> = Bitflags::new();
+    ///     roles.insert(Role::Minter);
+    ///     roles.remove(Role::Minter);
+    ///     assert(roles.is_empty());
+    /// }
+    /// ```
+    pub fn remove(ref mut self, flag: T) {
+        self.bits = self.bits & !mask(flag);
+    }
+
+    /// Adds the flag to the set if it isn't in it, otherwise removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag`: [T] - The flag to toggle.
+    ///
+    /// # Reverts
+    ///
+    /// * When the bit of the flag is not less than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::bits::Bitflags;
+    ///
+    /// fn foo() {
+    ///     let mut roles: Bitflags<Role> = Bitflags::new();
+    ///     roles.toggle(Role::Minter);
+    ///     assert(roles.contains(Role::Minter));
+    ///     roles.toggle(Role::Minter);
+    ///     assert(!roles.contains(Role::Minter));
+    /// }
+    /// ```
+    pub fn toggle(ref mut self, flag: T) {
+        self.bits = self.bits ^ mask(flag);
+    }
+}
+
+impl<T> core::ops::Eq for Bitflags<T> {
+    fn eq(self, other: Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+fn mask<T>(flag: T) -> u64
+where
+    T: Flag,
+{
+    let bit = flag.bit();
+    assert(bit < 64);
+    1 << bit
+}
+
+#[test]
+fn bits_test_count_ones() {
+    assert(0u64.count_ones() == 0);
+    assert(0b1011u64.count_ones() == 3);
+    assert(u64::max().count_ones() == 64);
+    assert(u32::max().count_ones() == 32);
+    assert(u16::max().count_ones() == 16);
+    assert(0b1000_0001u8.count_ones() == 2);
+}
+
+#[test]
+fn bits_test_leading_zeros() {
+    assert(0u64.leading_zeros() == 64);
+    assert(1u64.leading_zeros() == 63);
+    assert(u64::max().leading_zeros() == 0);
+    assert(1u32.leading_zeros() == 31);
+    assert(0u16.leading_zeros() == 16);
+    assert(0b0001_0000u8.leading_zeros() == 3);
+}
+
+#[test]
+fn bits_test_rotate() {
+    assert(1u64.rotate_right(1) == 0x8000_0000_0000_0000);
+    assert(0x8000_0000_0000_0000u64.rotate_left(1) == 1);
+    assert(0x1234u64.rotate_left(64) == 0x1234);
+    assert(0x8000_0001u32.rotate_left(4) == 0x0000_0018);
+    assert(0x0001u16.rotate_right(4) == 0x1000);
+    assert(0b1000_0001u8.rotate_left(1) == 0b0000_0011);
+    assert(0b1000_0001u8.rotate_right(9) == 0b1100_0000);
+}
@@ -869,6 +869,12 @@ impl AbiEncode for Bytes {
     }
 }
 
+impl AbiDecode for Bytes {
+    fn abi_decode(ref mut buffer: BufferReader) -> Bytes {
+        Bytes::from(raw_slice::abi_decode(buffer))
+    }
+}
+
 // Tests
 //
 fn setup() -> (Bytes, u8, u8, u8) {
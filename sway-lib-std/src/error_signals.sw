@@ -35,3 +35,24 @@ pub const FAILED_ASSERT_SIGNAL = 0xffff_ffff_ffff_0004;
 ///
 /// The value is: 18446744073709486085
 pub const FAILED_ASSERT_NE_SIGNAL = 0xffff_ffff_ffff_0005;
+
+/// A revert with this value signals that it was caused by calling the `#[constructor]` of a contract after it has already run.
+///
+/// # Additional Information
+///
+/// The value is: 18446744073709486086
+pub const CONSTRUCTOR_ALREADY_RUN_SIGNAL = 0xffff_ffff_ffff_0006;
+
+/// A revert with this value signals that it was caused by a failing call to `std::test_harness::assert_emitted`.
+///
+/// # Additional Information
+///
+/// The value is: 18446744073709486087
+pub const FAILED_ASSERT_EMITTED_SIGNAL = 0xffff_ffff_ffff_0007;
+
+/// A revert with this value signals that it was caused by calling a method marked `#[when_not_paused]` while the contract is paused with `std::pausable::pause`.
+///
+/// # Additional Information
+///
+/// The value is: 18446744073709486088
+pub const PAUSED_SIGNAL = 0xffff_ffff_ffff_0008;
@@ -90,3 +90,51 @@ pub fn size_of<T>() -> u64 {
 pub fn size_of_val<T>(val: T) -> u64 {
     __size_of_val::<T>(val)
 }
+
+/// Returns the canonical name of a generic type `T`.
+///
+/// # Additional Information
+///
+/// Structs and enums are named by their fully qualified paths along with their type arguments.
+///
+/// # Returns
+///
+/// * [str] - The canonical name of `T`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::intrinsics::type_name;
+///
+/// fn foo() {
+///     assert(type_name::<u64>() == "u64");
+///     assert(type_name::<Option<u8>>() == "std::option::Option<u8>");
+/// }
+/// ```
+pub fn type_name<T>() -> str {
+    __type_name::<T>()
+}
+
+/// Returns a stable id of a generic type `T`.
+///
+/// # Additional Information
+///
+/// The id is derived from the canonical name of `T`, so it is the same across programs and
+/// compilations.
+///
+/// # Returns
+///
+/// * [u64] - The id of `T`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::intrinsics::type_id;
+///
+/// fn foo() {
+///     assert(type_id::<u64>() != type_id::<u8>());
+/// }
+/// ```
+pub fn type_id<T>() -> u64 {
+    __type_id::<T>()
+}
@@ -14,6 +14,7 @@ pub mod vec;
 pub mod bytes;
 pub mod primitive_conversions;
 pub mod math;
+pub mod bits;
 pub mod flags;
 pub mod u128;
 pub mod u256;
@@ -41,7 +42,10 @@ pub mod asset;
 pub mod message;
 pub mod prelude;
 pub mod low_level_call;
+pub mod multicall;
+pub mod pausable;
 pub mod array_conversions;
 pub mod bytes_conversions;
+pub mod test_harness;
 
 use core::*;
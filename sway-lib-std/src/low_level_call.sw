@@ -6,6 +6,7 @@ use ::asset_id::AssetId;
 use ::bytes::Bytes;
 use ::contract_id::ContractId;
 use ::option::Option;
+use ::result::Result;
 use ::revert::require;
 use ::vec::Vec;
 
@@ -19,6 +20,14 @@ pub struct CallParams {
     gas: u64,
 }
 
+/// The error of a typed low-level call.
+pub enum CallError {
+    /// The function selector is not one word long. Holds its actual length.
+    InvalidSelectorLength: u64,
+    /// The called function returned no data, although its return type is not `()`.
+    NoReturnData: (),
+}
+
 // TODO : Replace with `from` when implemented
 /// Represent a contract ID as a `Bytes`, so it can be concatenated with a payload.
 ///
@@ -154,6 +163,38 @@ fn call_with_raw_payload(payload: Bytes, call_params: CallParams) {
     };
 }
 
+/// Call a target contract with an already-encoded payload and return the data it returned.
+///
+/// # Additional Information
+///
+/// It is recommended to use the `call_and_decode` function directly, unless you know what you are doing.
+///
+/// The returned slice points to the data the called contract returned with `retd`.
+///
+/// # Arguments
+///
+/// * `payload` : [Bytes] - The encoded payload to be called.
+/// * `call_params` : [CallParams] - The call parameters of the function call.
+///
+/// # Returns
+///
+/// * [raw_slice] - The data returned by the called contract.
+fn call_with_raw_payload_and_return_data(payload: Bytes, call_params: CallParams) -> raw_slice {
+    let return_data = (0, 0);
+    asm(
+        r1: payload.buf.ptr,
+        r2: call_params.coins,
+        r3: call_params.asset_id,
+        r4: call_params.gas,
+        data: return_data,
+    ) {
+        call r1 r2 r3 r4;
+        sw data ret i0;
+        sw data retl i1;
+        data: raw_slice
+    }
+}
+
 /// Encode a payload from the function selection and calldata.
 ///
 /// # Additional Information
@@ -198,7 +239,7 @@ fn create_payload(
     */
     require(
         function_selector
-            .len() == 8,
+            .len() == function_selector_length(),
         "function selector must be 8 bytes",
     );
 
@@ -216,6 +257,12 @@ fn create_payload(
     payload
 }
 
+/// The length in bytes of a function selector, which is passed to the called contract as the
+/// first parameter of the payload, a word.
+fn function_selector_length() -> u64 {
+    __size_of::<u64>()
+}
+
 /// Call a target contract with a function selector and calldata, provided as `Bytes`.
 ///
 /// # Arguments
@@ -288,3 +335,152 @@ pub fn call_with_function_selector_vec(
         call_params,
     );
 }
+
+/// Call a target contract with a function selector and calldata, provided as `Bytes`, and return
+/// a copy of the data it returned.
+///
+/// # Arguments
+///
+/// * `target` : [ContractId] - The ContractId of the contract to be called.
+/// * `function_selector` : [Bytes] - The function selector of the function to be called, i.e. the first 8 bytes of `sha256("my_func(u64)")`.
+/// * `calldata` : [Bytes] - The encoded arguments with which to call the function.
+/// * `single_value_type_arg` : [bool] - Whether the function being called takes a single value-type argument.
+/// * `call_params` : [CallParams] - The amount and color of coins to forward, and the gas to forward.
+///
+/// # Returns
+///
+/// * [Bytes] - The data returned by the called function.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::low_level_call::{bytes::Bytes, call_with_function_selector_and_return_data, CallParams};
+///
+/// fn call_contract(target: ContractId, function_selector: Bytes, calldata: Bytes, call_params: CallParams) -> Bytes {
+///     call_with_function_selector_and_return_data(target, function_selector, calldata, false, call_params)
+/// }
+/// ```
+pub fn call_with_function_selector_and_return_data(
+    target: ContractId,
+    function_selector: Bytes,
+    calldata: Bytes,
+    single_value_type_arg: bool,
+    call_params: CallParams,
+) -> Bytes {
+    let payload = create_payload(target, function_selector, calldata, single_value_type_arg);
+    let return_data = call_with_raw_payload_and_return_data(payload, call_params);
+
+    // The returned data lives in the memory of the called contract, which the next call may
+    // overwrite, so it is copied onto the heap.
+    let number_of_bytes = return_data.number_of_bytes();
+    let mut bytes = Bytes::with_capacity(number_of_bytes);
+    bytes.len = number_of_bytes;
+    return_data.ptr().copy_bytes_to(bytes.buf.ptr, number_of_bytes);
+
+    bytes
+}
+
+/// Call a target contract with a function selector and calldata, provided as `Bytes`, and decode
+/// the value it returned.
+///
+/// # Additional Information
+///
+/// The called function must return its value encoded with the new encoding, as with `encode`.
+/// As the return type is generic, libraries can wrap calls to any contract method without
+/// writing a helper per return type.
+///
+/// # Arguments
+///
+/// * `target` : [ContractId] - The ContractId of the contract to be called.
+/// * `function_selector` : [Bytes] - The function selector of the function to be called, i.e. the first 8 bytes of `sha256("my_func(u64)")`.
+/// * `calldata` : [Bytes] - The encoded arguments with which to call the function.
+/// * `single_value_type_arg` : [bool] - Whether the function being called takes a single value-type argument.
+/// * `call_params` : [CallParams] - The amount and color of coins to forward, and the gas to forward.
+///
+/// # Returns
+///
+/// * [T] - The value returned by the called function.
+///
+/// # Reverts
+///
+/// * When the returned data is too short to be decoded as a `T`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::low_level_call::{bytes::Bytes, call_and_decode, CallParams};
+///
+/// fn get_balance(target: ContractId, function_selector: Bytes, calldata: Bytes, call_params: CallParams) -> u64 {
+///     call_and_decode::<u64>(target, function_selector, calldata, false, call_params)
+/// }
+/// ```
+pub fn call_and_decode<T>(
+    target: ContractId,
+    function_selector: Bytes,
+    calldata: Bytes,
+    single_value_type_arg: bool,
+    call_params: CallParams,
+) -> T
+where
+    T: AbiDecode,
+{
+    let payload = create_payload(target, function_selector, calldata, single_value_type_arg);
+    abi_decode::<T>(call_with_raw_payload_and_return_data(payload, call_params))
+}
+
+/// Call a target contract with a function selector and typed arguments, and decode the value it
+/// returned.
+///
+/// # Additional Information
+///
+/// The arguments are encoded with the new encoding, as with `encode`, and passed by reference to
+/// the called function, which must return its value encoded with the new encoding as well.
+/// Libraries can wrap calls to any contract method this way, without any `asm` block.
+///
+/// # Arguments
+///
+/// * `contract_id` : [ContractId] - The ContractId of the contract to be called.
+/// * `selector` : [Bytes] - The function selector of the function to be called, i.e. the first 8 bytes of `sha256("my_func(u64)")`.
+/// * `args` : [TArgs] - The arguments with which to call the function, e.g. a tuple of them.
+/// * `call_params` : [CallParams] - The amount and color of coins to forward, and the gas to forward.
+///
+/// # Returns
+///
+/// * [Result<TRet, CallError>] - The value returned by the called function, or the reason it could not be called or decoded.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::low_level_call::{bytes::Bytes, call, CallError, CallParams};
+///
+/// fn transfer(target: ContractId, selector: Bytes, to: Identity, amount: u64, call_params: CallParams) -> bool {
+///     match call::<(Identity, u64), bool>(target, selector, (to, amount), call_params) {
+///         Ok(succeeded) => succeeded,
+///         Err(_) => false,
+///     }
+/// }
+/// ```
+pub fn call<TArgs, TRet>(
+    contract_id: ContractId,
+    selector: Bytes,
+    args: TArgs,
+    call_params: CallParams,
+) -> Result<TRet, CallError>
+where
+    TArgs: AbiEncode,
+    TRet: AbiDecode,
+{
+    let selector_length = selector.len();
+    if selector_length != function_selector_length() {
+        return Result::Err(CallError::InvalidSelectorLength(selector_length));
+    }
+
+    // The encoded arguments are always passed by reference.
+    let payload = create_payload(contract_id, selector, Bytes::from(encode(args)), false);
+    let return_data = call_with_raw_payload_and_return_data(payload, call_params);
+    if return_data.number_of_bytes() == 0 && __size_of::<TRet>() != 0 {
+        return Result::Err(CallError::NoReturnData);
+    }
+
+    Result::Ok(abi_decode::<TRet>(return_data))
+}
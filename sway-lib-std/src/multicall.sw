@@ -0,0 +1,205 @@
+//! A builder batching calls to contracts, executed in order within a single transaction.
+library;
+
+use ::bytes::Bytes;
+use ::bytes_conversions::u64::*;
+use ::contract_id::ContractId;
+use ::low_level_call::{call_with_function_selector_and_return_data, CallParams};
+use ::registers::context_gas;
+use ::revert::require;
+use ::vec::Vec;
+
+/// A call batched in a `Multicall`.
+pub struct Call {
+    /// The contract to be called.
+    target: ContractId,
+    /// The function selector of the function to be called.
+    function_selector: Bytes,
+    /// The encoded arguments with which to call the function.
+    calldata: Bytes,
+    /// Whether the function being called takes a single value-type argument.
+    single_value_type_arg: bool,
+    /// The amount and color of coins to forward, and the gas to forward.
+    call_params: CallParams,
+}
+
+/// A batch of calls to contracts, executed in the order they were added.
+///
+/// # Additional Information
+///
+/// The helpers generated with `forc multicall` add typed calls to the methods of the ABIs
+/// declared by interface packages, and decode the values they return.
+pub struct Multicall {
+    calls: Vec<Call>,
+    gas: u64,
+}
+
+impl Multicall {
+    /// Constructs a new, empty `Multicall`.
+    ///
+    /// # Returns
+    ///
+    /// * [Multicall] - A new, empty `Multicall`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::multicall::Multicall;
+    ///
+    /// fn foo() {
+    ///     let multicall = Multicall::new();
+    ///     assert(multicall.is_empty());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            calls: Vec::new(),
+            gas: 0,
+        }
+    }
+
+    /// Appends a call to the end of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` : [ContractId] - The ContractId of the contract to be called.
+    /// * `function_selector` : [u64] - The function selector of the function to be called, i.e. the first 4 bytes of `sha256("my_func(u64)")`.
+    /// * `calldata` : [Bytes] - The encoded arguments with which to call the function.
+    /// * `single_value_type_arg` : [bool] - Whether the function being called takes a single value-type argument.
+    /// * `call_params` : [CallParams] - The amount and color of coins to forward, and the gas to forward.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{bytes::Bytes, low_level_call::CallParams, multicall::Multicall};
+    ///
+    /// fn foo(target: ContractId, calldata: Bytes, call_params: CallParams) {
+    ///     let mut multicall = Multicall::new();
+    ///     multicall.add(target, 0x9890aef4, calldata, false, call_params);
+    ///     assert(multicall.len() == 1);
+    /// }
+    /// ```
+    pub fn add(
+        ref mut self,
+        target: ContractId,
+        function_selector: u64,
+        calldata: Bytes,
+        single_value_type_arg: bool,
+        call_params: CallParams,
+    ) {
+        self.gas += call_params.gas;
+        self.calls.push(Call {
+            target,
+            function_selector: function_selector.to_be_bytes(),
+            calldata,
+            single_value_type_arg,
+            call_params,
+        });
+    }
+
+    /// Returns the number of calls in the batch.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of calls in the batch.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::multicall::Multicall;
+    ///
+    /// fn foo() {
+    ///     let multicall = Multicall::new();
+    ///     assert(multicall.len() == 0);
+    /// }
+    /// ```
+    pub fn len(self) -> u64 {
+        self.calls.len()
+    }
+
+    /// Returns whether the batch contains no calls.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the batch contains no calls, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::multicall::Multicall;
+    ///
+    /// fn foo() {
+    ///     let multicall = Multicall::new();
+    ///     assert(multicall.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(self) -> bool {
+        self.calls.len() == 0
+    }
+
+    /// Returns the total gas forwarded to the calls of the batch.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The sum of the gas forwarded to each call.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{bytes::Bytes, low_level_call::CallParams, multicall::Multicall};
+    ///
+    /// fn foo(target: ContractId, calldata: Bytes, call_params: CallParams) {
+    ///     let mut multicall = Multicall::new();
+    ///     multicall.add(target, 0x9890aef4, calldata, false, call_params);
+    ///     assert(multicall.gas() == call_params.gas);
+    /// }
+    /// ```
+    pub fn gas(self) -> u64 {
+        self.gas
+    }
+
+    /// Executes the calls of the batch in the order they were added.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<Bytes>] - The data returned by each call, in the order of the calls.
+    ///
+    /// # Reverts
+    ///
+    /// * When the gas forwarded to the calls exceeds the gas remaining in the current context.
+    /// * When any of the calls reverts.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{bytes::Bytes, low_level_call::CallParams, multicall::Multicall};
+    ///
+    /// fn foo(target: ContractId, calldata: Bytes, call_params: CallParams) {
+    ///     let mut multicall = Multicall::new();
+    ///     multicall.add(target, 0x9890aef4, calldata, false, call_params);
+    ///     let results = multicall.execute();
+    ///     assert(results.len() == 1);
+    /// }
+    /// ```
+    pub fn execute(self) -> Vec<Bytes> {
+        require(
+            self.gas <= context_gas(),
+            "multicall forwards more gas than remains",
+        );
+
+        let mut results = Vec::with_capacity(self.calls.len());
+        let mut i = 0;
+        while i < self.calls.len() {
+            let call = self.calls.get(i).unwrap();
+            results.push(call_with_function_selector_and_return_data(
+                call.target,
+                call.function_selector,
+                call.calldata,
+                call.single_value_type_arg,
+                call.call_params,
+            ));
+            i += 1;
+        }
+        results
+    }
+}
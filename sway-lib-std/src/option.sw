@@ -58,8 +58,16 @@
 //! * `unwrap` reverts.
 //! * `unwrap_or` returns the provided default value.
 //!
+//! * `expect` reverts, logging the provided message.
+//!
 //! `unwrap`   : `Option::unwrap`
 //! `unwrap_or`: `Option::unwrap_or`
+//! `expect`   : `Option::expect`
+//!
+//! # Combining options
+//!
+//! * `and` returns the provided option if `Some`, and `None` otherwise.
+//! * `or` returns the option itself if `Some`, and the provided option otherwise.
 //!
 //! # Transforming contained values
 //!
@@ -72,8 +80,12 @@
 //! `Ok(v)`  : `Result::Ok`
 //! `Some(v)`: `Option::Some`
 //! `ok_or`  : `Option::ok_or`
+//!
+//! Conversely, `Result::ok` and `Result::err` transform `Result` to `Option`.
 library;
 
+use ::error_signals::FAILED_REQUIRE_SIGNAL;
+use ::logging::log;
 use ::result::Result;
 use ::revert::revert;
 
@@ -218,6 +230,119 @@ impl<T> Option<T> {
         }
     }
 
+    /// Returns the contained `Some` value, logging `msg` and reverting if the value is `None`.
+    ///
+    /// # Additional Information
+    ///
+    /// Because this function may revert, its use is generally discouraged.
+    /// Instead, use pattern matching and handle the `None`
+    /// case explicitly, or call `unwrap_or`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg`: [M] - The message logged if the option is `None`.
+    ///
+    /// # Returns
+    ///
+    /// * [T] - The value contained by the option.
+    ///
+    /// # Reverts
+    ///
+    /// * Reverts if the `Option` is the `None` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x = Some(42);
+    ///     assert(x.expect("x is missing") == 42);
+    ///
+    ///     let x: Option<u64> = None;
+    ///     let value = x.expect("x is missing"); // logs "x is missing" and reverts
+    /// }
+    /// ```
+    #[cfg(experimental_new_encoding = false)]
+    pub fn expect<M>(self, msg: M) -> T {
+        match self {
+            Self::Some(inner_value) => inner_value,
+            Self::None => {
+                log(msg);
+                revert(FAILED_REQUIRE_SIGNAL)
+            },
+        }
+    }
+
+    #[cfg(experimental_new_encoding = true)]
+    pub fn expect<M>(self, msg: M) -> T
+    where
+        M: AbiEncode,
+    {
+        match self {
+            Self::Some(inner_value) => inner_value,
+            Self::None => {
+                log(msg);
+                revert(FAILED_REQUIRE_SIGNAL)
+            },
+        }
+    }
+
+    // Combining options
+    //
+    /// Returns `None` if the option is `None`, otherwise returns `optb`.
+    ///
+    /// # Arguments
+    ///
+    /// * `optb`: [Option<U>] - The option returned if this one is `Some`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<U>] - `optb` if the option is `Some`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x: Option<u64> = None;
+    ///     let y: Option<bool> = None;
+    ///     assert(Some(2).and(Some(true)) == Some(true));
+    ///     assert(x.and(Some(true)).is_none());
+    ///     assert(Some(2).and(y).is_none());
+    /// }
+    /// ```
+    pub fn and<U>(self, optb: Option<U>) -> Option<U> {
+        match self {
+            Self::Some(_) => optb,
+            Self::None => Option::None,
+        }
+    }
+
+    /// Returns the option if it contains a value, otherwise returns `optb`.
+    ///
+    /// # Arguments
+    ///
+    /// * `optb`: [Option<T>] - The option returned if this one is `None`.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<T>] - The option if it is `Some`, otherwise `optb`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// fn foo() {
+    ///     let x: Option<u64> = None;
+    ///     assert(Some(2).or(Some(100)) == Some(2));
+    ///     assert(x.or(Some(100)) == Some(100));
+    ///     assert(x.or(None).is_none());
+    /// }
+    /// ```
+    pub fn or(self, optb: Option<T>) -> Option<T> {
+        match self {
+            Self::Some(_) => self,
+            Self::None => optb,
+        }
+    }
+
     // Transforming contained values
     //
     /// Transforms the `Option<T>` into a `Result<T, E>`, mapping `Some(v)` to
@@ -262,3 +387,64 @@ impl<T> Option<T> {
         }
     }
 }
+
+// `Result` can't depend on `Option`, so its conversions to `Option` are implemented here.
+impl<T, E> Result<T, E> {
+    /// Converts the result into an `Option<T>`, discarding the error, if any.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<T>] - `Some(v)` if the result is `Ok(v)`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Ok(42);
+    ///     assert(x.ok() == Some(42));
+    ///
+    ///     let y: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     assert(y.ok().is_none());
+    /// }
+    /// ```
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Self::Ok(v) => Option::Some(v),
+            Self::Err(_) => Option::None,
+        }
+    }
+
+    /// Converts the result into an `Option<E>`, discarding the success value, if any.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<E>] - `Some(e)` if the result is `Err(e)`, otherwise `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Ok(42);
+    ///     assert(x.err().is_none());
+    ///
+    ///     let y: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     assert(y.err().is_some());
+    /// }
+    /// ```
+    pub fn err(self) -> Option<E> {
+        match self {
+            Self::Ok(_) => Option::None,
+            Self::Err(e) => Option::Some(e),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Functionality for pausing a contract, along with the `#[when_not_paused]` attribute.
+//!
+//! Whether the contract is paused, as well as the identity allowed to pause and unpause it, is
+//! kept in storage slots derived from the `storage_` domain, which cannot collide with the slots
+//! of the storage fields of the contract.
+library;
+
+use ::auth::msg_sender;
+use ::identity::Identity;
+use ::option::Option::{self, *};
+use ::result::Result::{self, *};
+use ::revert::require;
+use ::storage::storage_api::{read, write};
+
+/// The storage slot recording whether the contract is paused, i.e. `sha256("storage_paused")`.
+///
+/// # Additional Information
+///
+/// The methods marked `#[when_not_paused]` read this slot, and revert with
+/// `std::error_signals::PAUSED_SIGNAL` when it is set.
+const PAUSED_KEY: b256 = 0x7397a8a6bb40357d41aa5ef94cf86283aa8b3655c9ae48a7d5163533ab5609ae;
+
+/// The storage slot holding the identity allowed to pause the contract, i.e.
+/// `sha256("storage_pauser")`.
+const PAUSER_KEY: b256 = 0x676e4d2d6f0f9b9d988d304e560fad3e34d202b7319e851109a7fd720b6c2ebb;
+
+/// The error type used when pausing or unpausing a contract fails.
+pub enum PausableError {
+    /// The caller is not the pauser of the contract.
+    NotPauser: (),
+    /// The contract is paused.
+    Paused: (),
+}
+
+/// Returns whether the contract is paused.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the contract is paused, otherwise `false`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::pausable::is_paused;
+///
+/// #[storage(read)]
+/// fn foo() {
+///     assert(!is_paused());
+/// }
+/// ```
+#[storage(read)]
+pub fn is_paused() -> bool {
+    __state_load_word(PAUSED_KEY) != 0
+}
+
+/// Returns the identity allowed to pause and unpause the contract, if set.
+///
+/// # Returns
+///
+/// * [Option<Identity>] - The pauser of the contract, or `None` if it has not been set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+#[storage(read)]
+pub fn pauser() -> Option<Identity> {
+    read::<Identity>(PAUSER_KEY, 0)
+}
+
+/// Sets the identity allowed to pause and unpause the contract.
+///
+/// # Additional Information
+///
+/// Until a pauser is set, anyone can set it, so it should be set in the constructor of the
+/// contract. Afterwards, only the current pauser can hand the role over.
+///
+/// # Arguments
+///
+/// * `new_pauser`: [Identity] - The new pauser of the contract.
+///
+/// # Reverts
+///
+/// * When a pauser is set, and the caller is not the pauser.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{auth::msg_sender, pausable::set_pauser};
+///
+/// #[constructor]
+/// #[storage(read, write)]
+/// fn init() {
+///     set_pauser(msg_sender().unwrap());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn set_pauser(new_pauser: Identity) {
+    if pauser().is_some() {
+        require_pauser();
+    }
+    write::<Identity>(PAUSER_KEY, 0, new_pauser);
+}
+
+/// Pauses the contract, so that the methods marked `#[when_not_paused]` revert until it is
+/// unpaused.
+///
+/// # Reverts
+///
+/// * When the caller is not the pauser of the contract.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::pausable::{is_paused, pause};
+///
+/// #[storage(read, write)]
+/// fn foo() {
+///     pause();
+///     assert(is_paused());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn pause() {
+    require_pauser();
+    let _ = __state_store_word(PAUSED_KEY, 1);
+}
+
+/// Unpauses the contract.
+///
+/// # Reverts
+///
+/// * When the caller is not the pauser of the contract.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+#[storage(read, write)]
+pub fn unpause() {
+    require_pauser();
+    let _ = __state_store_word(PAUSED_KEY, 0);
+}
+
+/// Reverts, logging `PausableError::Paused`, if the contract is paused.
+///
+/// # Reverts
+///
+/// * When the contract is paused.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+#[storage(read)]
+pub fn require_not_paused() {
+    require(!is_paused(), PausableError::Paused);
+}
+
+#[storage(read)]
+fn require_pauser() {
+    let is_pauser = match (pauser(), msg_sender()) {
+        (Some(pauser), Ok(sender)) => pauser == sender,
+        _ => false,
+    };
+    require(is_pauser, PausableError::NotPauser);
+}
@@ -50,11 +50,28 @@
 //!
 //! * `unwrap` reverts.
 //! * `unwrap_or` returns the default provided value.
+//! * `expect` reverts, logging the provided message.
 //!
 //! `unwrap`   : `Result::unwrap`
 //! `unwrap_or`: `Result::unwrap_or`
+//! `expect`   : `Result::expect`
+//!
+//! ### Combining results
+//!
+//! * `and` returns the provided result if `Ok`, and the error otherwise.
+//! * `or` returns the result itself if `Ok`, and the provided result otherwise.
+//!
+//! ### Transforming contained values
+//!
+//! * `ok` transforms `Ok(v)` to `Some(v)`, and `Err(e)` to `None`.
+//! * `err` transforms `Err(e)` to `Some(e)`, and `Ok(v)` to `None`.
+//!
+//! `ok` : `Result::ok`
+//! `err`: `Result::err`
 library;
 
+use ::error_signals::FAILED_REQUIRE_SIGNAL;
+use ::logging::log;
 use ::revert::revert;
 
 // ANCHOR: docs_result
@@ -203,8 +220,137 @@ impl<T, E> Result<T, E> {
         }
     }
 
-    // TODO: Implement the following transforms when Option and Result can
-    // import one another:
-    // - `ok(self) -> Option<T>`
-    // - `err(self) -> Option<E>`
+    /// Returns the contained `Ok` value, logging `msg` and reverting if the result is an `Err`.
+    ///
+    /// # Additional Information
+    ///
+    /// Because this function may revert, its use is generally discouraged.
+    /// Instead, prefer to use pattern matching and handle the `Err`
+    /// case explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg`: [M] - The message logged if the result is an `Err`.
+    ///
+    /// # Returns
+    ///
+    /// * [T] - The value contained by the result.
+    ///
+    /// # Reverts
+    ///
+    /// * Reverts if the `Result` is the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Ok(42);
+    ///     assert(x.expect("x is not ok") == 42);
+    ///
+    ///     let y: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     let val = y.expect("y is not ok"); // logs "y is not ok" and reverts
+    /// }
+    /// ```
+    #[cfg(experimental_new_encoding = false)]
+    pub fn expect<M>(self, msg: M) -> T {
+        match self {
+            Self::Ok(inner_value) => inner_value,
+            Self::Err(_) => {
+                log(msg);
+                revert(FAILED_REQUIRE_SIGNAL)
+            },
+        }
+    }
+
+    #[cfg(experimental_new_encoding = true)]
+    pub fn expect<M>(self, msg: M) -> T
+    where
+        M: AbiEncode,
+    {
+        match self {
+            Self::Ok(inner_value) => inner_value,
+            Self::Err(_) => {
+                log(msg);
+                revert(FAILED_REQUIRE_SIGNAL)
+            },
+        }
+    }
+
+    // Combining results
+    //
+    /// Returns `res` if the result is `Ok`, otherwise returns the `Err` value of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `res`: [Result<U, E>] - The result returned if this one is `Ok`.
+    ///
+    /// # Returns
+    ///
+    /// * [Result<U, E>] - `res` if the result is `Ok`, otherwise the error of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Ok(2);
+    ///     let y: Result<bool, Error> = Result::Ok(true);
+    ///     assert(x.and(y).unwrap());
+    ///
+    ///     let x: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     assert(x.and(y).is_err());
+    /// }
+    /// ```
+    pub fn and<U>(self, res: Result<U, E>) -> Result<U, E> {
+        match self {
+            Self::Ok(_) => res,
+            Self::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns the result if it is `Ok`, otherwise returns `res`.
+    ///
+    /// # Arguments
+    ///
+    /// * `res`: [Result<T, F>] - The result returned if this one is an `Err`.
+    ///
+    /// # Returns
+    ///
+    /// * [Result<T, F>] - The `Ok` value of the result if it is `Ok`, otherwise `res`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// enum Error {
+    ///     NotFound,
+    ///     Invalid,
+    /// }
+    ///
+    /// fn foo() {
+    ///     let x: Result<u64, Error> = Result::Err(Error::NotFound);
+    ///     let y: Result<u64, Error> = Result::Ok(100);
+    ///     assert(x.or(y).unwrap() == 100);
+    ///
+    ///     let x: Result<u64, Error> = Result::Ok(2);
+    ///     assert(x.or(y).unwrap() == 2);
+    /// }
+    /// ```
+    pub fn or<F>(self, res: Result<T, F>) -> Result<T, F> {
+        match self {
+            Self::Ok(v) => Result::Ok(v),
+            Self::Err(_) => res,
+        }
+    }
+
+    // The closure based transforms, `map`, `map_err`, `and_then`, `or_else` and `unwrap_or_else`,
+    // are to be implemented once closures are supported.
 }
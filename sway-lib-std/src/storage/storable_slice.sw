@@ -133,6 +133,164 @@ pub fn clear_slice(key: b256) -> bool {
     __state_clear(sha256(key), number_of_slots)
 }
 
+/// Append a raw_slice to the one stored at some key.
+///
+/// # Additional Information
+///
+/// Only the length and the last, partially filled, storage slot of the stored slice are read, so
+/// the cost doesn't depend on the length of the stored slice.
+///
+/// # Arguments
+///
+/// * `key`: [b256] - The storage slot at which the slice is stored.
+/// * `slice`: [raw_slice] - The raw_slice to be appended.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{alloc::alloc_bytes, storage::{append_slice, write_slice, read_slice}, constants::ZERO_B256};
+///
+/// fn foo() {
+///     let slice = asm(ptr: (alloc_bytes(1), 1)) { ptr: raw_slice };
+///     write_slice(ZERO_B256, slice);
+///     append_slice(ZERO_B256, slice);
+///     assert(read_slice(ZERO_B256).unwrap().number_of_bytes() == 2);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn append_slice(key: b256, slice: raw_slice) {
+    let number_of_bytes = slice.number_of_bytes();
+    if number_of_bytes == 0 {
+        return;
+    }
+
+    // The appended bytes start within the slot following the last full one.
+    let len = read::<u64>(key, 0).unwrap_or(0);
+    let first_slot = len >> 5;
+    let offset_in_slot = len % 32;
+    let number_of_slots = (offset_in_slot + number_of_bytes + 31) >> 5;
+    let slot = (sha256(key).as_u256() + first_slot.as_u256()).as_b256();
+
+    // Keep the bytes already stored in the first slot.
+    let ptr = alloc_bytes(number_of_slots * 32);
+    if offset_in_slot != 0 {
+        let _ = __state_load_quad(slot, ptr, 1);
+    }
+    slice
+        .ptr()
+        .copy_bytes_to(ptr.add_uint_offset(offset_in_slot), number_of_bytes);
+    let _ = __state_store_quad(slot, ptr, number_of_slots);
+
+    write(key, 0, len + number_of_bytes);
+}
+
+/// Shorten the raw_slice stored at some key, keeping its first `len` bytes.
+///
+/// # Additional Information
+///
+/// The storage slots which are no longer used are cleared. If `len` is not less than the length
+/// of the stored slice, nothing happens.
+///
+/// # Arguments
+///
+/// * `key`: [b256] - The storage slot at which the slice is stored.
+/// * `len`: [u64] - The length to shorten the stored slice to.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+/// * Clears: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{alloc::alloc_bytes, storage::{truncate_slice, write_slice, read_slice}, constants::ZERO_B256};
+///
+/// fn foo() {
+///     let slice = asm(ptr: (alloc_bytes(64), 64)) { ptr: raw_slice };
+///     write_slice(ZERO_B256, slice);
+///     truncate_slice(ZERO_B256, 8);
+///     assert(read_slice(ZERO_B256).unwrap().number_of_bytes() == 8);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn truncate_slice(key: b256, len: u64) {
+    let stored_len = read::<u64>(key, 0).unwrap_or(0);
+    if len >= stored_len {
+        return;
+    }
+
+    let number_of_slots = (len + 31) >> 5;
+    let stored_number_of_slots = (stored_len + 31) >> 5;
+    if number_of_slots < stored_number_of_slots {
+        let slot = (sha256(key).as_u256() + number_of_slots.as_u256()).as_b256();
+        let _ = __state_clear(slot, stored_number_of_slots - number_of_slots);
+    }
+
+    write(key, 0, len);
+}
+
+/// Load `len` bytes of the raw_slice stored at some key, starting at `offset`.
+///
+/// # Additional Information
+///
+/// Only the storage slots holding the requested bytes are read.
+///
+/// # Arguments
+///
+/// * `key`: [b256] - The storage slot at which the slice is stored.
+/// * `offset`: [u64] - The index of the first byte to load.
+/// * `len`: [u64] - The number of bytes to load.
+///
+/// # Returns
+///
+/// * [Option<raw_slice>] - `Some(slice)` if the bytes are within the stored slice. Otherwise, `None`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{alloc::alloc_bytes, storage::{read_slice_range, write_slice}, constants::ZERO_B256};
+///
+/// fn foo() {
+///     let slice = asm(ptr: (alloc_bytes(64), 64)) { ptr: raw_slice };
+///     write_slice(ZERO_B256, slice);
+///     assert(read_slice_range(ZERO_B256, 40, 16).unwrap().number_of_bytes() == 16);
+///     assert(read_slice_range(ZERO_B256, 60, 16).is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn read_slice_range(key: b256, offset: u64, len: u64) -> Option<raw_slice> {
+    let stored_len = read::<u64>(key, 0).unwrap_or(0);
+    if offset > stored_len || len > stored_len - offset {
+        return None;
+    }
+    if len == 0 {
+        return Some(asm(ptr: (alloc_bytes(0), 0)) {
+            ptr: raw_slice
+        });
+    }
+
+    // Load the slots from the one holding the first byte to the one holding the last byte.
+    let first_slot = offset >> 5;
+    let number_of_slots = ((offset + len + 31) >> 5) - first_slot;
+    let slot = (sha256(key).as_u256() + first_slot.as_u256()).as_b256();
+    let ptr = alloc_bytes(number_of_slots * 32);
+    let _ = __state_load_quad(slot, ptr, number_of_slots);
+    Some(asm(ptr: (ptr.add_uint_offset(offset % 32), len)) {
+        ptr: raw_slice
+    })
+}
+
 /// A general way to persistently store heap types.
 pub trait StorableSlice<T> {
     #[storage(read, write)]
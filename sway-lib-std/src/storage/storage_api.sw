@@ -2,6 +2,7 @@ library;
 
 use ::alloc::alloc;
 use ::option::Option::{self, *};
+use ::vec::Vec;
 
 /// Stores a stack value in storage. Will not work for heap values.
 ///
@@ -146,6 +147,115 @@ pub fn clear<T>(slot: b256, offset: u64) -> bool {
     __state_clear(offset_slot, number_of_slots)
 }
 
+/// Stores a sequence of values in consecutive storage slots, with a single storage write.
+///
+/// # Additional Information
+///
+/// The values are packed one after the other starting at the beginning of `slot`, each of them
+/// taking `__size_of::<T>()` bytes, which is much cheaper than writing them separately when they
+/// span many slots. The rest of the last slot is preserved.
+///
+/// # Arguments
+///
+/// * `slot`: [b256] - The storage slot at which the first value will be stored.
+/// * `values`: [Vec<T>] - The values to be stored.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{storage::storage_api::{read_slots, write_slots}, constants::ZERO_B256};
+///
+/// fn foo() {
+///     let mut values = Vec::new();
+///     values.push(1_u64);
+///     values.push(2_u64);
+///     values.push(3_u64);
+///     write_slots(ZERO_B256, values);
+///     let stored_values = read_slots::<u64>(ZERO_B256, 3).unwrap();
+///     assert(stored_values.get(2).unwrap() == 3);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn write_slots<T>(slot: b256, values: Vec<T>) {
+    let number_of_bytes = values.len() * __size_of::<T>();
+    if number_of_bytes == 0 {
+        return;
+    }
+
+    let number_of_slots = (number_of_bytes + 31) >> 5;
+    let padded_values = alloc::<u64>(number_of_slots * 4);
+
+    // Only the last slot may be partially overwritten, so it's the only one which needs to be read.
+    let last_slot = (slot.as_u256() + (number_of_slots - 1).as_u256()).as_b256();
+    let last_padded_value = padded_values.add::<u64>((number_of_slots - 1) * 4);
+    let _ = __state_load_quad(last_slot, last_padded_value, 1);
+
+    values.ptr().copy_bytes_to(padded_values, number_of_bytes);
+    let _ = __state_store_quad(slot, padded_values, number_of_slots);
+}
+
+/// Reads a sequence of values of type `T` from consecutive storage slots, with a single storage
+/// read.
+///
+/// # Additional Information
+///
+/// The values are expected to be packed as they are by `write_slots`.
+///
+/// # Arguments
+///
+/// * `slot`: [b256] - The storage slot from which the first value is read.
+/// * `count`: [u64] - The number of values to read.
+///
+/// # Returns
+///
+/// * [Option<Vec<T>>] - `Some(values)` if all the storage slots read were set. Otherwise, `None`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{storage::storage_api::{read_slots, write_slots}, constants::ZERO_B256};
+///
+/// fn foo() {
+///     let mut values = Vec::new();
+///     values.push(1_u64);
+///     values.push(2_u64);
+///     write_slots(ZERO_B256, values);
+///     let stored_values = read_slots::<u64>(ZERO_B256, 2).unwrap();
+///     assert(stored_values.len() == 2);
+/// }
+/// ```
+#[storage(read)]
+pub fn read_slots<T>(slot: b256, count: u64) -> Option<Vec<T>> {
+    let mut values = Vec::with_capacity(count);
+    let number_of_bytes = count * __size_of::<T>();
+    if number_of_bytes == 0 {
+        return Some(values);
+    }
+
+    // Read all the slots at once into a buffer whose size is a multiple of 32 bytes.
+    let number_of_slots = (number_of_bytes + 31) >> 5;
+    let padded_values = alloc::<u64>(number_of_slots * 4);
+    if !__state_load_quad(slot, padded_values, number_of_slots) {
+        return None;
+    }
+
+    let mut i = 0;
+    while i < count {
+        values.push(padded_values.add_uint_offset(i * __size_of::<T>()).read::<T>());
+        i += 1;
+    }
+    Some(values)
+}
+
 /// Given a slot, offset, and type this function determines where something should be stored.
 ///
 /// # Arguments
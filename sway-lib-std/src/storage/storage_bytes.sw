@@ -157,3 +157,128 @@ impl StorableSlice<Bytes> for StorageKey<StorageBytes> {
         read::<u64>(self.field_id, 0).unwrap_or(0)
     }
 }
+
+impl StorageKey<StorageBytes> {
+    /// Appends bytes to the collection of tightly packed bytes in storage, without loading it.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: [Bytes] - The bytes which will be appended.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_bytes::StorageBytes, bytes::Bytes};
+    ///
+    /// storage {
+    ///     stored_bytes: StorageBytes = StorageBytes {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5_u8);
+    ///     bytes.push(7_u8);
+    ///
+    ///     storage.stored_bytes.write_slice(bytes);
+    ///     storage.stored_bytes.append(bytes);
+    ///     assert(storage.stored_bytes.len() == 4);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn append(self, bytes: Bytes) {
+        append_slice(self.field_id, bytes.as_raw_slice());
+    }
+
+    /// Shortens the collection of tightly packed bytes in storage, keeping its first `len` bytes.
+    ///
+    /// # Additional Information
+    ///
+    /// If `len` is not less than the number of bytes stored, nothing happens.
+    ///
+    /// # Arguments
+    ///
+    /// * `len`: [u64] - The number of bytes to keep.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    /// * Clears: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_bytes::StorageBytes, bytes::Bytes};
+    ///
+    /// storage {
+    ///     stored_bytes: StorageBytes = StorageBytes {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5_u8);
+    ///     bytes.push(7_u8);
+    ///     bytes.push(9_u8);
+    ///
+    ///     storage.stored_bytes.write_slice(bytes);
+    ///     storage.stored_bytes.truncate(1);
+    ///     assert(storage.stored_bytes.len() == 1);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn truncate(self, len: u64) {
+        truncate_slice(self.field_id, len);
+    }
+
+    /// Constructs a `Bytes` type from `len` of the bytes in storage, starting at `offset`, without
+    /// loading the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: [u64] - The index of the first byte to load.
+    /// * `len`: [u64] - The number of bytes to load.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Bytes>] - The bytes if they are all stored, otherwise `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_bytes::StorageBytes, bytes::Bytes};
+    ///
+    /// storage {
+    ///     stored_bytes: StorageBytes = StorageBytes {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut bytes = Bytes::new();
+    ///     bytes.push(5_u8);
+    ///     bytes.push(7_u8);
+    ///     bytes.push(9_u8);
+    ///
+    ///     storage.stored_bytes.write_slice(bytes);
+    ///     let retrieved_bytes = storage.stored_bytes.read_range(1, 2).unwrap();
+    ///     assert(retrieved_bytes.get(0).unwrap() == 7_u8);
+    ///     assert(storage.stored_bytes.read_range(2, 2).is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn read_range(self, offset: u64, len: u64) -> Option<Bytes> {
+        match read_slice_range(self.field_id, offset, len) {
+            Some(slice) => {
+                Some(Bytes::from(slice))
+            },
+            None => None,
+        }
+    }
+}
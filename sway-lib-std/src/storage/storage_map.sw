@@ -196,3 +196,28 @@ where
         }
     }
 }
+
+impl<K, V> core::ops::Index<K, StorageKey<V>> for StorageKey<StorageMap<K, V>>
+where
+    K: Hash,
+{
+    /// Retrieves the `StorageKey` that describes the raw location in storage of the value
+    /// stored at `key`, i.e. `storage.map[key]`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// storage {
+    ///     map: StorageMap<u64, bool> = StorageMap {}
+    /// }
+    ///
+    /// #[storage(read, write)]
+    /// fn foo() {
+    ///     storage.map.insert(5, true);
+    ///     assert(storage.map[5].read());
+    /// }
+    /// ```
+    fn index(self, key: K) -> StorageKey<V> {
+        self.get(key)
+    }
+}
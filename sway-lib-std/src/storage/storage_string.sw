@@ -162,3 +162,113 @@ impl StorableSlice<String> for StorageKey<StorageString> {
         read::<u64>(self.slot, 0).unwrap_or(0)
     }
 }
+
+impl StorageKey<StorageString> {
+    /// Appends a `String` to the one in storage, without loading it.
+    ///
+    /// # Arguments
+    ///
+    /// * `string`: [String] - The string which will be appended.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_string::StorageString, string::String};
+    ///
+    /// storage {
+    ///     stored_string: StorageString = StorageString {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.stored_string.write_slice(String::from_ascii_str("Fuel"));
+    ///     storage.stored_string.append(String::from_ascii_str("VM"));
+    ///     assert(storage.stored_string.len() == 6);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn append(self, string: String) {
+        append_slice(self.slot, string.as_raw_slice());
+    }
+
+    /// Shortens the `String` in storage, keeping its first `len` bytes.
+    ///
+    /// # Additional Information
+    ///
+    /// If `len` is not less than the length of the stored `String`, nothing happens.
+    ///
+    /// # Arguments
+    ///
+    /// * `len`: [u64] - The number of bytes to keep.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    /// * Clears: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_string::StorageString, string::String};
+    ///
+    /// storage {
+    ///     stored_string: StorageString = StorageString {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.stored_string.write_slice(String::from_ascii_str("FuelVM"));
+    ///     storage.stored_string.truncate(4);
+    ///     assert(storage.stored_string.read_slice().unwrap() == String::from_ascii_str("Fuel"));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn truncate(self, len: u64) {
+        truncate_slice(self.slot, len);
+    }
+
+    /// Constructs a `String` type from `len` of the bytes of the `String` in storage, starting at
+    /// `offset`, without loading the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: [u64] - The index of the first byte to load.
+    /// * `len`: [u64] - The number of bytes to load.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<String>] - The `String` if its bytes are all stored, otherwise `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{storage::storage_string::StorageString, string::String};
+    ///
+    /// storage {
+    ///     stored_string: StorageString = StorageString {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.stored_string.write_slice(String::from_ascii_str("FuelVM"));
+    ///     let retrieved_string = storage.stored_string.read_range(4, 2).unwrap();
+    ///     assert(retrieved_string == String::from_ascii_str("VM"));
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn read_range(self, offset: u64, len: u64) -> Option<String> {
+        match read_slice_range(self.slot, offset, len) {
+            Some(slice) => {
+                Some(String::from(slice))
+            },
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,503 @@
+//! A harness for tests run with `forc test`, to create funded wallets, manipulate blocks,
+//! inspect receipts and events, set up the storage of contracts and impersonate callers.
+//!
+//! The chain manipulated by this module is the chain `forc test` executes the tests on, as seen
+//! by `std::block` and by the contracts the tests call. A test runs within a single transaction,
+//! which executes within a single block, so the blocks produced by `advance_blocks` are seen by
+//! the transactions executed after the test, i.e. by the `#[test(shared_state)]` tests declared
+//! after it. The block producer set with `set_block_producer` is seen right away.
+//!
+//! The wallets created with `new_wallet` are funded with coin inputs of the test transaction, so
+//! their coins can be forwarded to the contracts the test calls. The storage slots of contracts
+//! and the caller impersonated with `prank` are seen by the contracts the test calls as well.
+//!
+//! The functions of this module are only available when running tests with `forc test`, and
+//! panic everywhere else.
+library;
+
+use ::address::Address;
+use ::asset_id::AssetId;
+use ::constants::ZERO_B256;
+use ::contract_id::ContractId;
+use ::error_signals::FAILED_ASSERT_EMITTED_SIGNAL;
+use ::inputs::{
+    GTF_INPUT_COIN_OWNER,
+    Input,
+    input_amount,
+    input_asset_id,
+    input_coin_owner,
+    input_count,
+    input_type,
+};
+use ::logging::log;
+use ::option::Option::{self, *};
+use ::revert::revert;
+
+// The numbers of the operations of the harness, see `forc_test::ecal`.
+const NEW_WALLET: u64 = 1;
+const WALLET_TRANSFER: u64 = 2;
+const ADVANCE_BLOCKS: u64 = 3;
+const BLOCK_HEIGHT: u64 = 4;
+const BLOCK_TIMESTAMP: u64 = 5;
+const SET_BLOCK_PRODUCER: u64 = 6;
+const BLOCK_PRODUCER: u64 = 7;
+const RECEIPTS_LEN: u64 = 8;
+const RECEIPT_KIND: u64 = 9;
+const STORE_SLOT: u64 = 10;
+const LOAD_SLOT: u64 = 11;
+const PRANK: u64 = 12;
+const STOP_PRANK: u64 = 13;
+const FIND_LOG: u64 = 14;
+
+/// The kinds of receipts the VM emits.
+pub enum ReceiptKind {
+    Call: (),
+    Return: (),
+    ReturnData: (),
+    Panic: (),
+    Revert: (),
+    Log: (),
+    LogData: (),
+    Transfer: (),
+    TransferOut: (),
+    ScriptResult: (),
+    MessageOut: (),
+    Mint: (),
+    Burn: (),
+}
+
+impl core::ops::Eq for ReceiptKind {
+    fn eq(self, other: Self) -> bool {
+        __eq(receipt_kind_number(self), receipt_kind_number(other))
+    }
+}
+
+fn receipt_kind_number(kind: ReceiptKind) -> u64 {
+    match kind {
+        ReceiptKind::Call => 0,
+        ReceiptKind::Return => 1,
+        ReceiptKind::ReturnData => 2,
+        ReceiptKind::Panic => 3,
+        ReceiptKind::Revert => 4,
+        ReceiptKind::Log => 5,
+        ReceiptKind::LogData => 6,
+        ReceiptKind::Transfer => 7,
+        ReceiptKind::TransferOut => 8,
+        ReceiptKind::ScriptResult => 9,
+        ReceiptKind::MessageOut => 10,
+        ReceiptKind::Mint => 11,
+        ReceiptKind::Burn => 12,
+    }
+}
+
+/// Creates a new wallet holding `amount` coins of `asset_id`.
+///
+/// # Additional Information
+///
+/// The coins of the wallet are a coin input of the test transaction, owned by the wallet.
+/// `forc test` adds that input and executes the test again once it knows the wallets the test
+/// creates. A wallet only holds coins of the asset it is created with.
+///
+/// # Arguments
+///
+/// * `asset_id`: [AssetId] - The asset the wallet is funded with.
+/// * `amount`: [u64] - The number of coins the wallet is funded with.
+///
+/// # Returns
+///
+/// * [Address] - The address of the new wallet.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{constants::BASE_ASSET_ID, test_harness::{new_wallet, wallet_balance}};
+///
+/// #[test]
+/// fn test_wallet() {
+///     let wallet = new_wallet(BASE_ASSET_ID, 100);
+///     assert(wallet_balance(wallet, BASE_ASSET_ID) == 100);
+/// }
+/// ```
+pub fn new_wallet(asset_id: AssetId, amount: u64) -> Address {
+    let mut address = ZERO_B256;
+    asm(
+        op: NEW_WALLET,
+        asset_id: asset_id,
+        amount: amount,
+        address: __addr_of(address),
+    ) clobbers(op) {
+        ecal op asset_id amount address;
+    };
+    Address::from(address)
+}
+
+/// Returns the balance of `asset_id` of a wallet created with `new_wallet`.
+///
+/// # Arguments
+///
+/// * `wallet`: [Address] - The address of the wallet.
+/// * `asset_id`: [AssetId] - The asset to return the balance of.
+///
+/// # Returns
+///
+/// * [u64] - The number of coins of `asset_id` the wallet holds.
+pub fn wallet_balance(wallet: Address, asset_id: AssetId) -> u64 {
+    match wallet_input(wallet, asset_id) {
+        Some(input) => input_amount(input).unwrap(),
+        None => 0,
+    }
+}
+
+/// Transfers `amount` coins of `asset_id` between two wallets created with `new_wallet`.
+///
+/// # Additional Information
+///
+/// The coins are moved between the coin inputs of both wallets, which must have been created
+/// with `asset_id`.
+///
+/// # Arguments
+///
+/// * `from`: [Address] - The wallet the coins are taken from.
+/// * `to`: [Address] - The wallet the coins are given to.
+/// * `asset_id`: [AssetId] - The asset to transfer.
+/// * `amount`: [u64] - The number of coins to transfer.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `from` held enough coins to make the transfer, `false` otherwise.
+pub fn wallet_transfer(from: Address, to: Address, asset_id: AssetId, amount: u64) -> bool {
+    let (from, to) = match (wallet_input(from, asset_id), wallet_input(to, asset_id)) {
+        (Some(from), Some(to)) => (from, to),
+        _ => return false,
+    };
+    if input_amount(from).unwrap() < amount {
+        return false;
+    }
+    let from = __gtf::<raw_ptr>(from, GTF_INPUT_COIN_OWNER);
+    let to = __gtf::<raw_ptr>(to, GTF_INPUT_COIN_OWNER);
+    asm(op: WALLET_TRANSFER, from: from, to: to, amount: amount) clobbers(op) {
+        ecal op from to amount;
+    };
+    true
+}
+
+/// Returns the index of the coin input of `asset_id` owned by `wallet`.
+fn wallet_input(wallet: Address, asset_id: AssetId) -> Option<u64> {
+    let inputs = input_count();
+    let mut i = 0u8;
+    while i < inputs {
+        let index = i.as_u64();
+        if let Input::Coin = input_type(index) {
+            if input_coin_owner(index).unwrap() == wallet
+                && input_asset_id(index).unwrap() == asset_id
+            {
+                return Some(index);
+            }
+        }
+        i += 1u8;
+    }
+    None
+}
+
+/// Advances the chain by `blocks` blocks, each produced `block_time` seconds after the previous
+/// one.
+///
+/// # Additional Information
+///
+/// The test keeps executing in the block it started in, so `std::block::height` and
+/// `std::block::timestamp` return the new block in the `#[test(shared_state)]` tests declared
+/// after this one. `block_height` and `block_timestamp` return it right away.
+///
+/// # Arguments
+///
+/// * `blocks`: [u64] - The number of blocks to advance by.
+/// * `block_time`: [u64] - The number of seconds between two blocks.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test_harness::{advance_blocks, block_height};
+///
+/// #[test]
+/// fn test_blocks() {
+///     let height = block_height();
+///     advance_blocks(10, 1);
+///     assert(block_height() == height + 10);
+/// }
+/// ```
+pub fn advance_blocks(blocks: u64, block_time: u64) {
+    asm(op: ADVANCE_BLOCKS, blocks: blocks, block_time: block_time) clobbers(op) {
+        ecal op blocks block_time zero;
+    };
+}
+
+/// Returns the height of the latest block of the chain.
+///
+/// # Returns
+///
+/// * [u32] - The height of the latest block.
+pub fn block_height() -> u32 {
+    asm(op: BLOCK_HEIGHT) clobbers(op) {
+        ecal op zero zero zero;
+        op: u32
+    }
+}
+
+/// Returns the TAI64 timestamp of the latest block of the chain.
+///
+/// # Returns
+///
+/// * [u64] - The timestamp of the latest block.
+pub fn block_timestamp() -> u64 {
+    asm(op: BLOCK_TIMESTAMP) clobbers(op) {
+        ecal op zero zero zero;
+        op: u64
+    }
+}
+
+/// Sets the producer of the current block, as returned by the `cb` instruction.
+///
+/// # Arguments
+///
+/// * `producer`: [ContractId] - The new block producer.
+pub fn set_block_producer(producer: ContractId) {
+    asm(op: SET_BLOCK_PRODUCER, producer: producer) clobbers(op) {
+        ecal op producer zero zero;
+    };
+}
+
+/// Returns the producer of the current block.
+///
+/// # Returns
+///
+/// * [ContractId] - The current block producer.
+pub fn block_producer() -> ContractId {
+    let mut producer = ZERO_B256;
+    asm(op: BLOCK_PRODUCER, producer: __addr_of(producer)) clobbers(op) {
+        ecal op producer zero zero;
+    };
+    ContractId::from(producer)
+}
+
+/// Returns the number of receipts emitted by the test so far.
+///
+/// # Returns
+///
+/// * [u64] - The number of receipts.
+pub fn receipts_len() -> u64 {
+    asm(op: RECEIPTS_LEN) clobbers(op) {
+        ecal op zero zero zero;
+        op: u64
+    }
+}
+
+/// Returns the kind of the receipt at `index`, in the order the receipts were emitted.
+///
+/// # Arguments
+///
+/// * `index`: [u64] - The index of the receipt.
+///
+/// # Returns
+///
+/// * [ReceiptKind] - The kind of the receipt.
+///
+/// # Reverts
+///
+/// * When `index` is not less than `receipts_len()`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test_harness::{receipt_kind, receipts_len, ReceiptKind};
+///
+/// #[test]
+/// fn test_log() {
+///     log(42);
+///     assert(receipt_kind(receipts_len() - 1) == ReceiptKind::Log);
+/// }
+/// ```
+pub fn receipt_kind(index: u64) -> ReceiptKind {
+    let kind = asm(op: RECEIPT_KIND, index: index) clobbers(op) {
+        ecal op index zero zero;
+        op: u64
+    };
+    match kind {
+        0 => ReceiptKind::Call,
+        1 => ReceiptKind::Return,
+        2 => ReceiptKind::ReturnData,
+        3 => ReceiptKind::Panic,
+        4 => ReceiptKind::Revert,
+        5 => ReceiptKind::Log,
+        6 => ReceiptKind::LogData,
+        7 => ReceiptKind::Transfer,
+        8 => ReceiptKind::TransferOut,
+        9 => ReceiptKind::ScriptResult,
+        10 => ReceiptKind::MessageOut,
+        11 => ReceiptKind::Mint,
+        _ => ReceiptKind::Burn,
+    }
+}
+
+/// Stores `value` in the storage slot `key` of the contract `contract`.
+///
+/// # Arguments
+///
+/// * `contract`: [ContractId] - The contract whose storage is written.
+/// * `key`: [b256] - The key of the storage slot.
+/// * `value`: [b256] - The value to store.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test_harness::{load_slot, store_slot};
+///
+/// #[test]
+/// fn test_storage() {
+///     let key = 0x0000000000000000000000000000000000000000000000000000000000000001;
+///     let value = 0x0000000000000000000000000000000000000000000000000000000000000002;
+///     store_slot(CONTRACT_ID, key, value);
+///     assert(load_slot(CONTRACT_ID, key).unwrap() == value);
+/// }
+/// ```
+pub fn store_slot(contract: ContractId, key: b256, value: b256) {
+    let slot = (contract, key, value);
+    asm(op: STORE_SLOT, slot: slot) clobbers(op) {
+        ecal op slot zero zero;
+    };
+}
+
+/// Loads the storage slot `key` of the contract `contract`.
+///
+/// # Arguments
+///
+/// * `contract`: [ContractId] - The contract whose storage is read.
+/// * `key`: [b256] - The key of the storage slot.
+///
+/// # Returns
+///
+/// * [Option<b256>] - The value of the slot, or `None` if the slot is not set.
+pub fn load_slot(contract: ContractId, key: b256) -> Option<b256> {
+    let slot = (contract, key);
+    let mut value = ZERO_B256;
+    let is_set = asm(op: LOAD_SLOT, slot: slot, value: __addr_of(value)) clobbers(op) {
+        ecal op slot value zero;
+        op: bool
+    };
+    if is_set { Some(value) } else { None }
+}
+
+/// Impersonates `caller` in the calls the test makes to contracts, until `stop_prank` is called.
+///
+/// The owner of the coin inputs of the test transaction is set to `caller`, so that
+/// `std::auth::msg_sender` returns `Identity::Address(caller)` within the called contracts. The
+/// asset and the amount seen by the called contracts are set with the `CallParams` of the calls.
+/// Until `stop_prank` is called, the coins of the wallets created with `new_wallet` are owned by
+/// `caller`, so `wallet_balance` and `wallet_transfer` do not find them.
+///
+/// # Arguments
+///
+/// * `caller`: [Address] - The address to impersonate.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test_harness::{prank, stop_prank};
+///
+/// abi Owned {
+///     fn owner_only();
+/// }
+///
+/// #[test]
+/// fn test_prank() {
+///     let owner = Address::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+///     prank(owner);
+///     abi(Owned, CONTRACT_ID).owner_only();
+///     stop_prank();
+/// }
+/// ```
+pub fn prank(caller: Address) {
+    let inputs = input_count();
+    let mut i = 0u8;
+    while i < inputs {
+        if let Input::Coin = input_type(i.as_u64()) {
+            let owner = __gtf::<raw_ptr>(i.as_u64(), GTF_INPUT_COIN_OWNER);
+            asm(op: PRANK, owner: owner, caller: caller) clobbers(op) {
+                ecal op owner caller zero;
+            };
+        }
+        i += 1u8;
+    }
+}
+
+/// Stops impersonating the caller set with `prank`.
+pub fn stop_prank() {
+    let inputs = input_count();
+    let mut i = 0u8;
+    while i < inputs {
+        if let Input::Coin = input_type(i.as_u64()) {
+            let owner = __gtf::<raw_ptr>(i.as_u64(), GTF_INPUT_COIN_OWNER);
+            asm(op: STOP_PRANK, owner: owner) clobbers(op) {
+                ecal op owner zero zero;
+            };
+        }
+        i += 1u8;
+    }
+}
+
+/// Returns whether `event` was logged by the test, or by the contracts it called, so far.
+///
+/// # Additional Information
+///
+/// `event` is logged in turn, and compared to the values logged before with the same type.
+///
+/// # Arguments
+///
+/// * `event`: [T] - The expected event.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `event` was logged, `false` otherwise.
+pub fn is_emitted<T>(event: T) -> bool {
+    log(event);
+    let index = receipts_len() - 1;
+    asm(op: FIND_LOG, index: index) clobbers(op) {
+        ecal op index zero zero;
+        op: bool
+    }
+}
+
+/// Asserts that `event` was logged by the test, or by the contracts it called, so far.
+///
+/// # Additional Information
+///
+/// On failure, `forc test` prints the expected event along with the events of the same type that
+/// were logged, decoded using the ABI of the tested package.
+///
+/// # Arguments
+///
+/// * `event`: [T] - The expected event.
+///
+/// # Reverts
+///
+/// * When `event` was not logged, with `FAILED_ASSERT_EMITTED_SIGNAL`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test_harness::assert_emitted;
+///
+/// struct Transfer {
+///     amount: u64,
+/// }
+///
+/// #[test]
+/// fn test_transfer_event() {
+///     log(Transfer { amount: 10 });
+///     assert_emitted(Transfer { amount: 10 });
+/// }
+/// ```
+pub fn assert_emitted<T>(event: T) {
+    if !is_emitted(event) {
+        revert(FAILED_ASSERT_EMITTED_SIGNAL);
+    }
+}
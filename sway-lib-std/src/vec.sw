@@ -5,7 +5,9 @@ use ::alloc::{alloc, realloc};
 use ::assert::assert;
 use ::option::Option::{self, *};
 use ::convert::From;
+use ::result::Result;
 
+#[allow(unused_type_parameters)]
 struct RawVec<T> {
     ptr: raw_ptr,
     cap: u64,
@@ -587,6 +589,440 @@ impl<T> Vec<T> {
 
         index_ptr.write::<T>(value);
     }
+
+    /// Returns a slice of all the elements of the vector, without copying them.
+    ///
+    /// # Additional Information
+    ///
+    /// The slice refers to the elements of the vector when it is created, and is invalidated by
+    /// any operation that reallocates the vector, e.g. pushing elements beyond its capacity.
+    ///
+    /// # Returns
+    ///
+    /// * [&[T]] - The slice of the elements of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///
+    ///     let slice = vec.as_slice();
+    ///     assert(slice.len() == 2);
+    ///     assert(slice[1] == 10);
+    /// }
+    /// ```
+    pub fn as_slice(self) -> &[T] {
+        core::slice::from_parts::<T>(self.buf.ptr(), self.len)
+    }
+
+    /// Retains only the elements which satisfy `predicate`, preserving their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: [P] - The predicate the retained elements satisfy.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::{Predicate, Vec};
+    ///
+    /// struct IsEven {}
+    ///
+    /// impl Predicate<u64> for IsEven {
+    ///     fn evaluate(self, element: u64) -> bool {
+    ///         element % 2 == 0
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(1);
+    ///     vec.push(2);
+    ///     vec.push(4);
+    ///     vec.retain(IsEven {});
+    ///     assert(vec.len() == 2);
+    ///     assert(vec.get(0).unwrap() == 2);
+    /// }
+    /// ```
+    pub fn retain<P>(ref mut self, predicate: P)
+    where
+        P: Predicate<T>,
+    {
+        let buf_start = self.buf.ptr();
+
+        // Move the retained elements down over the removed ones.
+        let mut retained = 0;
+        let mut i = 0;
+        while i < self.len {
+            let ptr = buf_start.add::<T>(i);
+            if predicate.evaluate(ptr.read::<T>()) {
+                if retained != i {
+                    ptr.copy_to::<T>(buf_start.add::<T>(retained), 1);
+                }
+                retained += 1;
+            }
+            i += 1;
+        }
+
+        self.len = retained;
+    }
+
+    /// Sorts the vector in ascending order of the keys `key_fn` computes for its elements.
+    ///
+    /// # Additional Information
+    ///
+    /// The sort is not stable, i.e. the order of elements with equal keys is not preserved.
+    /// Each key is computed only once, and the sort itself doesn't allocate beyond the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_fn`: [F] - Computes the key of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::{SortKey, Vec};
+    ///
+    /// struct Order {
+    ///     price: u64,
+    ///     amount: u64,
+    /// }
+    ///
+    /// struct ByPrice {}
+    ///
+    /// impl SortKey<Order, u64> for ByPrice {
+    ///     fn sort_key(self, element: Order) -> u64 {
+    ///         element.price
+    ///     }
+    /// }
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(Order { price: 20, amount: 1 });
+    ///     vec.push(Order { price: 10, amount: 2 });
+    ///     vec.sort_by_key::<ByPrice, u64>(ByPrice {});
+    ///     assert(vec.get(0).unwrap().price == 10);
+    /// }
+    /// ```
+    pub fn sort_by_key<F, K>(ref mut self, key_fn: F)
+    where
+        F: SortKey<T, K>,
+        K: Ord,
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let buf_start = self.buf.ptr();
+        let keys = alloc::<K>(self.len);
+        let mut i = 0;
+        while i < self.len {
+            keys.add::<K>(i).write::<K>(key_fn.sort_key(buf_start.add::<T>(i).read::<T>()));
+            i += 1;
+        }
+
+        heap_sort::<T, K>(buf_start, keys, self.len);
+    }
+}
+
+impl<T> Vec<T>
+where
+    T: Ord + Eq,
+{
+    /// Sorts the vector in ascending order.
+    ///
+    /// # Additional Information
+    ///
+    /// The sort is a heapsort, which doesn't allocate, and is not stable, i.e. the order of
+    /// equal elements is not preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(15);
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///     vec.sort();
+    ///     assert(vec.get(0).unwrap() == 5);
+    ///     assert(vec.get(1).unwrap() == 10);
+    ///     assert(vec.get(2).unwrap() == 15);
+    /// }
+    /// ```
+    pub fn sort(ref mut self) {
+        let buf_start = self.buf.ptr();
+        heap_sort::<T, T>(buf_start, buf_start, self.len);
+    }
+
+    /// Searches the sorted vector for `value`.
+    ///
+    /// # Additional Information
+    ///
+    /// If the vector is not sorted in ascending order, the result is unspecified. If `value` occurs
+    /// more than once, the index of any of its occurrences may be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [T] - The value to search for.
+    ///
+    /// # Returns
+    ///
+    /// * [Result<u64, u64>] - `Ok` with the index of `value` if it is found, otherwise `Err` with
+    /// the index at which it could be inserted while keeping the vector sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///     vec.push(15);
+    ///     assert(vec.binary_search(10).unwrap() == 1);
+    ///     match vec.binary_search(12) {
+    ///         Ok(_) => revert(0),
+    ///         Err(index) => assert(index == 2),
+    ///     }
+    /// }
+    /// ```
+    pub fn binary_search(self, value: T) -> Result<u64, u64> {
+        let buf_start = self.buf.ptr();
+
+        // The value is within `[low, high)`, if anywhere.
+        let mut low = 0;
+        let mut high = self.len;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let element = buf_start.add::<T>(mid).read::<T>();
+            if element < value {
+                low = mid + 1;
+            } else if element > value {
+                high = mid;
+            } else {
+                return Result::Ok(mid);
+            }
+        }
+
+        Result::Err(low)
+    }
+}
+
+impl<T> Vec<T>
+where
+    T: Eq,
+{
+    /// Removes consecutive repeated elements, keeping the first of them.
+    ///
+    /// # Additional Information
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///     vec.push(5);
+    ///     vec.dedup();
+    ///     assert(vec.len() == 3);
+    ///     assert(vec.get(1).unwrap() == 10);
+    /// }
+    /// ```
+    pub fn dedup(ref mut self) {
+        if self.len < 2 {
+            return;
+        }
+
+        let buf_start = self.buf.ptr();
+
+        // Move each element which differs from the last retained one after it.
+        let mut retained = 1;
+        let mut i = 1;
+        while i < self.len {
+            let ptr = buf_start.add::<T>(i);
+            if ptr.read::<T>() != buf_start.add::<T>(retained - 1).read::<T>() {
+                if retained != i {
+                    ptr.copy_to::<T>(buf_start.add::<T>(retained), 1);
+                }
+                retained += 1;
+            }
+            i += 1;
+        }
+
+        self.len = retained;
+    }
+}
+
+/// A predicate on the elements of a `Vec<T>`, e.g. selecting those kept by `Vec::retain`.
+pub trait Predicate<T> {
+    /// Returns whether `element` satisfies the predicate.
+    fn evaluate(self, element: T) -> bool;
+}
+
+/// Computes the keys of the elements of a `Vec<T>` to sort them by with `Vec::sort_by_key`.
+pub trait SortKey<T, K> {
+    /// Returns the key of `element`.
+    fn sort_key(self, element: T) -> K;
+}
+
+/// Sorts `len` keys with a heapsort, applying the same swaps to the `len` elements, unless the
+/// elements are the keys themselves.
+fn heap_sort<T, K>(elements: raw_ptr, keys: raw_ptr, len: u64)
+where
+    K: Ord,
+{
+    if len < 2 {
+        return;
+    }
+
+    // Arrange the keys into a max-heap.
+    let mut start = len / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down::<T, K>(elements, keys, start, len);
+    }
+
+    // Repeatedly move the greatest key left in the heap right after it.
+    let mut end = len - 1;
+    while end > 0 {
+        swap_elements::<T, K>(elements, keys, 0, end);
+        sift_down::<T, K>(elements, keys, 0, end);
+        end -= 1;
+    }
+}
+
+/// Moves the key at `root` down the max-heap made of the first `len` keys, until it is not less
+/// than its children.
+fn sift_down<T, K>(elements: raw_ptr, keys: raw_ptr, root: u64, len: u64)
+where
+    K: Ord,
+{
+    let mut root = root;
+    while true {
+        let mut child = 2 * root + 1;
+        if child >= len {
+            break;
+        }
+        if child + 1 < len
+            && keys.add::<K>(child).read::<K>() < keys.add::<K>(child + 1).read::<K>()
+        {
+            child += 1;
+        }
+        if !(keys.add::<K>(root).read::<K>() < keys.add::<K>(child).read::<K>()) {
+            break;
+        }
+        swap_elements::<T, K>(elements, keys, root, child);
+        root = child;
+    }
+}
+
+fn swap_elements<T, K>(elements: raw_ptr, keys: raw_ptr, i: u64, j: u64) {
+    swap::<K>(keys, i, j);
+    if elements != keys {
+        swap::<T>(elements, i, j);
+    }
+}
+
+fn swap<T>(buf_start: raw_ptr, i: u64, j: u64) {
+    let i_ptr = buf_start.add::<T>(i);
+    let j_ptr = buf_start.add::<T>(j);
+    let i_val = i_ptr.read::<T>();
+    j_ptr.copy_to::<T>(i_ptr, 1);
+    j_ptr.write::<T>(i_val);
+}
+
+impl<T> core::ops::Index<u64, T> for Vec<T> {
+    /// Gets the element at `index`, i.e. `vec[index]`.
+    ///
+    /// # Reverts
+    ///
+    /// * If `index` is greater than or equal to the length of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     assert(vec[0] == 5);
+    /// }
+    /// ```
+    fn index(self, index: u64) -> T {
+        assert(index < self.len);
+
+        self.buf.ptr().add::<T>(index).read::<T>()
+    }
+}
+
+impl<T> core::ops::Index<core::ops::Range, &[T]> for Vec<T> {
+    /// Gets the slice of the elements within `range`, i.e. `vec[start..end]`, without copying
+    /// them.
+    ///
+    /// # Reverts
+    ///
+    /// * If `range.start` is greater than `range.end`.
+    /// * If `range.end` is greater than the length of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec.push(10);
+    ///     vec.push(15);
+    ///
+    ///     let slice = vec[1..3];
+    ///     assert(slice.len() == 2);
+    ///     assert(slice[0] == 10);
+    /// }
+    /// ```
+    fn index(self, range: core::ops::Range) -> &[T] {
+        self.as_slice()[range]
+    }
+}
+
+impl<T> core::ops::IndexMut<u64, T> for Vec<T> {
+    /// Sets the element at `index`, i.e. `vec[index] = value`.
+    ///
+    /// # Reverts
+    ///
+    /// * If `index` is greater than or equal to the length of vector.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// fn foo() {
+    ///     let mut vec = Vec::new();
+    ///     vec.push(5);
+    ///     vec[0] = 10;
+    ///     assert(vec.get(0).unwrap() == 10);
+    /// }
+    /// ```
+    fn index_assign(ref mut self, index: u64, value: T) {
+        self.set(index, value);
+    }
 }
 
 impl<T> AsRawSlice for Vec<T> {
@@ -640,3 +1076,29 @@ fn test_vec_with_len_1() {
     let _ = ve.remove(0);
     assert(ve.len == 0);
 }
+
+#[test()]
+fn test_vec_sort() {
+    let mut ve: Vec<u64> = Vec::new();
+    ve.push(5);
+    ve.push(1);
+    ve.push(4);
+    ve.push(1);
+    ve.push(3);
+    ve.sort();
+    assert(ve.get(0).unwrap() == 1);
+    assert(ve.get(1).unwrap() == 1);
+    assert(ve.get(2).unwrap() == 3);
+    assert(ve.get(3).unwrap() == 4);
+    assert(ve.get(4).unwrap() == 5);
+
+    assert(ve.binary_search(4).unwrap() == 3);
+    match ve.binary_search(2) {
+        Result::Ok(_) => assert(false),
+        Result::Err(index) => assert(index == 2),
+    }
+
+    ve.dedup();
+    assert(ve.len == 4);
+    assert(ve.get(1).unwrap() == 3);
+}
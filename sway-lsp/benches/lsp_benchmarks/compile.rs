@@ -2,7 +2,7 @@ use criterion::{black_box, criterion_group, Criterion};
 use lsp_types::Url;
 use std::sync::Arc;
 use sway_core::Engines;
-use sway_lsp::core::session;
+use sway_lsp::core::{dependency_cache::DependencyCache, session};
 
 const NUM_DID_CHANGE_ITERATIONS: usize = 10;
 
@@ -11,14 +11,26 @@ fn benchmarks(c: &mut Criterion) {
     let uri = Url::from_file_path(super::benchmark_dir().join("src/main.sw")).unwrap();
     c.bench_function("compile", |b| {
         b.iter(|| {
-            let engines = Engines::default();
-            let _ = black_box(session::compile(&uri, &engines, None).unwrap());
+            let mut engines = Engines::default();
+            let _ = black_box(session::compile(&uri, &mut engines, None, None).unwrap());
+        })
+    });
+
+    c.bench_function("compile_with_cached_dependencies", |b| {
+        let dependency_cache = DependencyCache::default();
+        let mut engines = Engines::default();
+        let _ = session::compile(&uri, &mut engines, None, Some(&dependency_cache)).unwrap();
+        b.iter(|| {
+            let mut engines = Engines::default();
+            let _ = black_box(
+                session::compile(&uri, &mut engines, None, Some(&dependency_cache)).unwrap(),
+            );
         })
     });
 
     c.bench_function("traverse", |b| {
-        let engines = Engines::default();
-        let results = black_box(session::compile(&uri, &engines, None).unwrap());
+        let mut engines = Engines::default();
+        let results = black_box(session::compile(&uri, &mut engines, None, None).unwrap());
         let session = Arc::new(session::Session::new());
         b.iter(|| {
             let _ =
@@ -27,10 +39,10 @@ fn benchmarks(c: &mut Criterion) {
     });
 
     c.bench_function("did_change_with_caching", |b| {
-        let engines = Engines::default();
+        let mut engines = Engines::default();
         b.iter(|| {
             for _ in 0..NUM_DID_CHANGE_ITERATIONS {
-                let _ = black_box(session::compile(&uri, &engines, None).unwrap());
+                let _ = black_box(session::compile(&uri, &mut engines, None, None).unwrap());
             }
         })
     });
@@ -12,7 +12,9 @@ pub async fn compile_test_project() -> (Url, Arc<Session>) {
     let uri = Url::from_file_path(benchmark_dir().join("src/main.sw")).unwrap();
     session.handle_open_file(&uri).await;
     // Compile the project
-    session::parse_project(&uri, &session.engines.read(), None, session.clone()).unwrap();
+    let mut engines = session.engines.read().clone();
+    session::parse_project(&uri, &mut engines, None, session.clone(), None).unwrap();
+    *session.engines.write() = engines;
     (uri, session)
 }
 
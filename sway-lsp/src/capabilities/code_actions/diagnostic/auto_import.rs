@@ -3,11 +3,14 @@ use crate::{
         code_actions::{CodeActionContext, CODE_ACTION_IMPORT_TITLE},
         diagnostic::DiagnosticData,
     },
-    core::token::{get_range_from_span, AstToken, SymbolKind, TypedAstToken},
+    core::{
+        session::Session,
+        token::{get_range_from_span, AstToken, SymbolKind, TypedAstToken},
+    },
 };
 use lsp_types::{
     CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit,
-    WorkspaceEdit,
+    Url, WorkspaceEdit,
 };
 use serde_json::Value;
 use std::{
@@ -17,9 +20,7 @@ use std::{
 };
 use sway_core::language::{
     parsed::ImportType,
-    ty::{
-        TyConstantDecl, TyDecl, TyFunctionDecl, TyIncludeStatement, TyTypeAliasDecl, TyUseStatement,
-    },
+    ty::{TyDecl, TyIncludeStatement, TyUseStatement},
     CallPath,
 };
 use sway_types::{Ident, Spanned};
@@ -84,57 +85,87 @@ pub(crate) fn import_code_action(
     None
 }
 
+/// Orders the import code actions, which come first in `actions`, by the number of errors the
+/// project would have once the import is made. The first one is marked as preferred if it leaves
+/// fewer errors than the others.
+///
+/// The imports are only type checked when there are several to choose from, with
+/// [Session::speculative_errors]. They are left in their order if any of them can't be.
+pub(crate) fn rank_import_code_actions(
+    session: &Session,
+    temp_uri: &Url,
+    actions: &mut Vec<CodeActionOrCommand>,
+) {
+    let imports = actions
+        .iter()
+        .take_while(|action| {
+            matches!(action, CodeActionOrCommand::CodeAction(action)
+                if action.title.starts_with(CODE_ACTION_IMPORT_TITLE))
+        })
+        .count();
+    if imports < 2 {
+        return;
+    }
+
+    let errors = actions[..imports]
+        .iter()
+        .map(|action| {
+            let CodeActionOrCommand::CodeAction(action) = action else {
+                return None;
+            };
+            let edits = action.edit.as_ref()?.changes.as_ref()?.values().next()?;
+            session
+                .speculative_errors(temp_uri, edits)
+                .ok()
+                .map(|errors| errors.len())
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(errors) = errors else {
+        return;
+    };
+
+    let mut ranked = actions.drain(..imports).zip(errors).collect::<Vec<_>>();
+    ranked.sort_by_key(|(_, errors)| *errors);
+    if ranked[0].1 < ranked[1].1 {
+        if let CodeActionOrCommand::CodeAction(action) = &mut ranked[0].0 {
+            action.is_preferred = Some(true);
+        }
+    }
+    actions.splice(0..0, ranked.into_iter().map(|(action, _)| action));
+}
+
 /// Returns an [Iterator] of [CallPath]s that match the given symbol name. The [CallPath]s are sorted
 /// alphabetically.
 pub(crate) fn get_call_paths_for_name<'s>(
     ctx: &'s CodeActionContext,
     symbol_name: &'s String,
 ) -> Option<impl 's + Iterator<Item = CallPath>> {
-    let namespace = ctx.namespace.to_owned()?;
-    let mut call_paths = ctx
-        .tokens
-        .tokens_for_name(symbol_name)
-        .filter_map(move |item| {
-            // If the typed token is a declaration, then we can import it.
-            match item.value().typed.as_ref() {
-                Some(TypedAstToken::TypedDeclaration(ty_decl)) => {
-                    return match ty_decl {
-                        TyDecl::StructDecl(decl) => {
-                            let struct_decl = ctx.engines.de().get_struct(&decl.decl_id);
-                            let call_path = struct_decl.call_path.to_import_path(&namespace);
-                            Some(call_path)
-                        }
-                        TyDecl::EnumDecl(decl) => {
-                            let enum_decl = ctx.engines.de().get_enum(&decl.decl_id);
-                            let call_path = enum_decl.call_path.to_import_path(&namespace);
-                            Some(call_path)
-                        }
-                        TyDecl::TraitDecl(decl) => {
-                            let trait_decl = ctx.engines.de().get_trait(&decl.decl_id);
-                            let call_path = trait_decl.call_path.to_import_path(&namespace);
-                            Some(call_path)
-                        }
-                        _ => None,
-                    };
-                }
-                Some(TypedAstToken::TypedFunctionDeclaration(TyFunctionDecl {
-                    call_path, ..
-                }))
-                | Some(TypedAstToken::TypedConstantDeclaration(TyConstantDecl {
-                    call_path, ..
-                }))
-                | Some(TypedAstToken::TypedTypeAliasDeclaration(TyTypeAliasDecl {
-                    call_path,
-                    ..
-                })) => {
-                    let call_path = call_path.to_import_path(&namespace);
-                    Some(call_path)
-                }
-                _ => None,
-            }
+    let namespace = ctx.namespace.as_ref()?;
+    let mut call_paths = namespace
+        .root()
+        .exported_items(ctx.engines.de())
+        .into_iter()
+        // Items should be imported from the module declaring them.
+        .filter(|item| item.name.as_str() == symbol_name && !item.is_reexport())
+        .filter(|item| {
+            matches!(
+                item.decl,
+                TyDecl::StructDecl(_)
+                    | TyDecl::EnumDecl(_)
+                    | TyDecl::TraitDecl(_)
+                    | TyDecl::FunctionDecl(_)
+                    | TyDecl::ConstantDecl(_)
+                    | TyDecl::TypeAliasDecl(_)
+            )
+        })
+        .map(|item| CallPath {
+            prefixes: item.canonical_path().to_vec(),
+            suffix: item.name,
+            is_absolute: false,
         })
         .collect::<Vec<_>>();
     call_paths.sort();
+    call_paths.dedup();
     Some(call_paths.into_iter())
 }
 
@@ -4,6 +4,8 @@ mod qualify;
 use crate::capabilities::{code_actions::CodeActionContext, diagnostic::DiagnosticData};
 use lsp_types::CodeActionOrCommand;
 
+pub(crate) use self::auto_import::rank_import_code_actions;
+
 use self::auto_import::import_code_action;
 use self::qualify::qualify_code_action;
 
@@ -54,8 +54,9 @@ pub fn code_actions(
         .token_at_position(temp_uri, range.start)?;
     let token = t.value();
 
+    let engines = session.engines.read();
     let ctx = CodeActionContext {
-        engines: &session.engines.read(),
+        engines: &engines,
         tokens: session.token_map(),
         token,
         uri,
@@ -94,7 +95,12 @@ pub fn code_actions(
         })
         .unwrap_or_default();
 
-    let actions_by_diagnostic = diagnostic::code_actions(&ctx).unwrap_or_default();
+    let mut actions_by_diagnostic = diagnostic::code_actions(&ctx).unwrap_or_default();
+
+    // Ranking the imports type checks them with the engines of the session, which can only be
+    // done once the context is no longer reading them.
+    drop(engines);
+    diagnostic::rank_import_code_actions(&session, temp_uri, &mut actions_by_diagnostic);
 
     Some([actions_by_type, actions_by_diagnostic].concat())
 }
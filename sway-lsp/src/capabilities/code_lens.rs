@@ -1,8 +1,16 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
 
-use lsp_types::{CodeLens, Url};
+use lsp_types::{CodeLens, Command, Location, Url};
+use serde_json::json;
+use sway_core::{
+    call_graph::{CallGraph, StorageAccessKind},
+    language::ty,
+};
 
-use crate::core::session::Session;
+use crate::{
+    core::{session::Session, token::get_range_from_span},
+    utils::document::get_url_from_span,
+};
 
 pub fn code_lens(session: &Arc<Session>, url: &Url) -> Vec<CodeLens> {
     let url_path = PathBuf::from(url.path());
@@ -21,7 +29,85 @@ pub fn code_lens(session: &Arc<Session>, url: &Url) -> Vec<CodeLens> {
                 .collect()
         })
         .unwrap_or_default();
+    result.extend(storage_access_lenses(session, url));
     // Sort the results
     result.sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
     result
 }
+
+/// Summarizes the storage fields read and written by each contract method declared in the file,
+/// including through the functions it calls. Clicking on the summary shows the accesses.
+fn storage_access_lenses(session: &Session, url: &Url) -> Vec<CodeLens> {
+    let compiled_program = session.compiled_program.read();
+    let Some(program) = compiled_program.typed.as_ref() else {
+        return vec![];
+    };
+    if !matches!(program.kind, ty::TyProgramKind::Contract { .. }) {
+        return vec![];
+    }
+    let Some(workspace_url) = session.sync.to_workspace_url(url.clone()) else {
+        return vec![];
+    };
+
+    let engines = session.engines.read();
+    let call_graph = CallGraph::new(&engines, program);
+    call_graph
+        .functions
+        .iter()
+        .enumerate()
+        .filter(|(_, function)| {
+            function.is_entry
+                && get_url_from_span(engines.se(), &function.span)
+                    .ok()
+                    .as_ref()
+                    == Some(url)
+        })
+        .filter_map(|(index, function)| {
+            let accesses = call_graph.storage_accesses(index);
+            let fields = |kind: StorageAccessKind| {
+                accesses
+                    .iter()
+                    .filter(|access| access.kind == kind)
+                    .map(|access| access.field.as_str())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let summary: Vec<_> = [
+                ("reads", fields(StorageAccessKind::Read)),
+                ("writes", fields(StorageAccessKind::Write)),
+            ]
+            .into_iter()
+            .filter(|(_, fields)| !fields.is_empty())
+            .map(|(kind, fields)| format!("{kind} {fields}"))
+            .collect();
+            if summary.is_empty() {
+                return None;
+            }
+
+            let locations: Vec<_> = accesses
+                .iter()
+                .filter_map(|access| {
+                    let url = get_url_from_span(engines.se(), &access.span).ok()?;
+                    let url = session.sync.to_workspace_url(url)?;
+                    Some(Location::new(url, get_range_from_span(&access.span)))
+                })
+                .collect();
+            let range = get_range_from_span(&function.span);
+            Some(CodeLens {
+                range,
+                command: Some(Command {
+                    title: format!("Storage {}", summary.join("; ")),
+                    command: "sway.peekLocations".to_string(),
+                    arguments: Some(vec![json!({
+                        "uri": workspace_url,
+                        "position": range.start,
+                        "locations": locations,
+                    })]),
+                }),
+                data: None,
+            })
+        })
+        .collect()
+}
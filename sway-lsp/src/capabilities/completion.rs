@@ -1,13 +1,65 @@
 use crate::core::token::TokenIdent;
 use lsp_types::{
-    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionTextEdit, Position,
-    Range, TextEdit,
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionTextEdit,
+    InsertTextFormat, Position, Range, TextEdit,
 };
 use sway_core::{
     language::ty::{TyAstNodeContent, TyDecl, TyFunctionDecl},
     namespace::Items,
     Engines, TypeId, TypeInfo,
 };
+use sway_types::{
+    constants::{
+        ALLOW_ATTRIBUTE_NAME, ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME,
+        ALLOW_UNBOUNDED_LOOP_NAME, ALLOW_UNUSED_TYPE_PARAMETERS_NAME, CFG_ATTRIBUTE_NAME,
+        CFG_EXPERIMENTAL_NEW_ENCODING, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        CONSTRUCTOR_ATTRIBUTE_NAME, DEPRECATED_ATTRIBUTE_NAME, INLINE_ALWAYS_NAME,
+        INLINE_ATTRIBUTE_NAME, INLINE_NEVER_NAME, PAYABLE_ATTRIBUTE_NAME,
+        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+        TAIL_CALL_ATTRIBUTE_NAME, TEST_ATTRIBUTE_NAME, TEST_SHARED_STATE_NAME,
+        TEST_SHOULD_REVERT_NAME, WHEN_NOT_PAUSED_ATTRIBUTE_NAME,
+    },
+    style::to_snake_case,
+};
+
+/// Primitive types that can be used both in storage fields and configurables.
+const PRIMITIVE_TYPES: &[&str] = &["bool", "u8", "u16", "u32", "u64", "u256", "b256"];
+
+/// Storage types from the standard library that are only valid inside of `storage { }`.
+const STORAGE_TYPES: &[(&str, &str)] = &[
+    ("StorageMap", "StorageMap<${1:K}, ${2:V}>"),
+    ("StorageVec", "StorageVec<${1:V}>"),
+    ("StorageBytes", "StorageBytes"),
+    ("StorageString", "StorageString"),
+];
+
+/// Attributes that can be written by users, along with a snippet for their arguments.
+const ATTRIBUTES: &[(&str, &str)] = &[
+    (STORAGE_PURITY_ATTRIBUTE_NAME, "storage(${1:read})"),
+    (TEST_ATTRIBUTE_NAME, "test"),
+    (PAYABLE_ATTRIBUTE_NAME, "payable"),
+    (CONSTRUCTOR_ATTRIBUTE_NAME, "constructor"),
+    (WHEN_NOT_PAUSED_ATTRIBUTE_NAME, "when_not_paused"),
+    (TAIL_CALL_ATTRIBUTE_NAME, "tail_call"),
+    (INLINE_ATTRIBUTE_NAME, "inline(${1:always})"),
+    (ALLOW_ATTRIBUTE_NAME, "allow(${1:dead_code})"),
+    (CFG_ATTRIBUTE_NAME, "cfg(${1:target} = ${2:\"fuel\"})"),
+    (DEPRECATED_ATTRIBUTE_NAME, "deprecated"),
+];
+
+/// The syntactic position of the cursor for completions that do not depend on
+/// the typed AST of a function body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CompletionContext {
+    /// Inside of `#[` before an attribute name has been completed.
+    AttributeName,
+    /// Inside of the argument list of an attribute, e.g. `#[storage(`.
+    AttributeArg(String),
+    /// In the type position of a field inside of a `storage { }` block.
+    StorageFieldType,
+    /// In the type position of a field inside of a `configurable { }` block.
+    ConfigurableFieldType,
+}
 
 pub(crate) fn to_completion_items(
     namespace: &Items,
@@ -204,3 +256,296 @@ fn type_id_of_local_ident(ident_name: &str, fn_decl: &TyFunctionDecl) -> Option<
             })
         })
 }
+
+/// Builds completion items for the [CompletionContext] of the cursor, if any.
+///
+/// `text_before_cursor` is the source text from the start of the file up to the cursor.
+pub(crate) fn to_contextual_completion_items(
+    namespace: Option<&Items>,
+    text_before_cursor: &str,
+) -> Option<Vec<CompletionItem>> {
+    let items =
+        match completion_context(text_before_cursor)? {
+            CompletionContext::AttributeName => ATTRIBUTES
+                .iter()
+                .map(|(name, snippet)| snippet_item(name, snippet, CompletionItemKind::PROPERTY))
+                .collect(),
+            CompletionContext::AttributeArg(attribute) => attribute_args(&attribute)
+                .iter()
+                .map(|arg| CompletionItem {
+                    kind: Some(CompletionItemKind::ENUM_MEMBER),
+                    label: arg.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            CompletionContext::StorageFieldType => {
+                let mut items = type_items(namespace);
+                items.extend(STORAGE_TYPES.iter().map(|(name, snippet)| {
+                    snippet_item(name, snippet, CompletionItemKind::STRUCT)
+                }));
+                items
+            }
+            CompletionContext::ConfigurableFieldType => type_items(namespace),
+        };
+    Some(items)
+}
+
+/// Returns the [CompletionContext] at the end of `text_before_cursor`.
+pub(crate) fn completion_context(text_before_cursor: &str) -> Option<CompletionContext> {
+    let line = text_before_cursor
+        .rsplit('\n')
+        .next()
+        .unwrap_or(text_before_cursor);
+
+    // Attributes are always written on a single line, so the current line is enough context.
+    if let Some(attr_start) = line.rfind("#[") {
+        let attr = &line[attr_start + 2..];
+        if attr.contains(']') {
+            return None;
+        }
+        return match attr.rfind('(') {
+            Some(paren) if !attr[paren..].contains(')') => Some(CompletionContext::AttributeArg(
+                attr[..paren].trim().to_string(),
+            )),
+            Some(_) => None,
+            None => Some(CompletionContext::AttributeName),
+        };
+    }
+
+    // The type position of a field is after the `:` and before the `=` of the initializer.
+    let field = line.rsplit([',', '{']).next().unwrap_or(line);
+    if !field.contains(':') || field.contains('=') {
+        return None;
+    }
+    match enclosing_block_keyword(text_before_cursor)? {
+        "storage" => Some(CompletionContext::StorageFieldType),
+        "configurable" => Some(CompletionContext::ConfigurableFieldType),
+        _ => None,
+    }
+}
+
+/// Returns the last identifier of the header of the innermost unclosed block in the text.
+fn enclosing_block_keyword(text: &str) -> Option<&str> {
+    enclosing_block_header(text)?
+        .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+}
+
+/// Returns the text preceding the innermost unclosed `{` in the text, up to the end of the
+/// previous statement or block, e.g. `impl Foo for Bar` or `let a = match b`.
+fn enclosing_block_header(text: &str) -> Option<&str> {
+    let mut open_braces = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '/' if chars.peek().map(|(_, c)| *c) == Some('/') => {
+                // Skip line comments, which may contain unbalanced braces.
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let mut escaped = false;
+                for (_, c) in chars.by_ref() {
+                    match c {
+                        '"' if !escaped => break,
+                        '\\' => escaped = !escaped,
+                        _ => escaped = false,
+                    }
+                }
+            }
+            '{' => open_braces.push(i),
+            '}' => {
+                open_braces.pop();
+            }
+            _ => {}
+        }
+    }
+    let header = text[..*open_braces.last()?]
+        .rsplit([';', '{', '}'])
+        .next()?
+        .trim();
+    Some(header)
+}
+
+/// Returns the text of the expression being matched on if the cursor is at the start of an arm
+/// of the innermost `match` expression.
+pub(crate) fn match_value_at_cursor(text_before_cursor: &str) -> Option<&str> {
+    let arm = text_before_cursor.rsplit([',', '{', '}']).next()?.trim();
+    if !arm
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    {
+        return None;
+    }
+    let (before, value) = enclosing_block_header(text_before_cursor)?.rsplit_once("match ")?;
+    if before.ends_with(|c: char| c.is_alphanumeric() || c == '_') || value.trim().is_empty() {
+        return None;
+    }
+    Some(value.trim())
+}
+
+/// Builds a completion item that expands to an arm for every variant of the enum being matched on.
+pub(crate) fn to_match_arm_completion_items(
+    namespace: &Items,
+    engines: &Engines,
+    match_value: &str,
+    fn_decl: &TyFunctionDecl,
+) -> Option<Vec<CompletionItem>> {
+    let type_id = type_id_of_raw_ident(engines, namespace, match_value, fn_decl)?;
+    let type_info = engines.te().get(type_id);
+    let TypeInfo::Enum(decl_ref) = &*type_info else {
+        return None;
+    };
+    let enum_decl = engines.de().get_enum(decl_ref.id());
+    let enum_name = enum_decl.call_path.suffix.as_str();
+    let mut tab_stop = 0;
+    let arms = enum_decl
+        .variants
+        .iter()
+        .map(|variant| {
+            let pattern = if engines.te().get(variant.type_argument.type_id).is_unit() {
+                format!("{enum_name}::{}", variant.name)
+            } else {
+                tab_stop += 1;
+                format!(
+                    "{enum_name}::{}(${{{tab_stop}:{}}})",
+                    variant.name,
+                    to_snake_case(variant.name.as_str())
+                )
+            };
+            tab_stop += 1;
+            format!("{pattern} => {{${tab_stop}}},")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    Some(vec![CompletionItem {
+        kind: Some(CompletionItemKind::SNIPPET),
+        label: format!("{enum_name}::… (all variants)"),
+        filter_text: Some(enum_name.to_string()),
+        label_details: Some(CompletionItemLabelDetails {
+            description: Some(engines.help_out(type_id).to_string()),
+            detail: None,
+        }),
+        insert_text: Some(arms),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    }])
+}
+
+/// Returns the known argument names for the given attribute.
+fn attribute_args(attribute: &str) -> &'static [&'static str] {
+    match attribute {
+        STORAGE_PURITY_ATTRIBUTE_NAME => &[STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME],
+        INLINE_ATTRIBUTE_NAME => &[INLINE_ALWAYS_NAME, INLINE_NEVER_NAME],
+        ALLOW_ATTRIBUTE_NAME => &[
+            ALLOW_DEAD_CODE_NAME,
+            ALLOW_DEPRECATED_NAME,
+            ALLOW_UNUSED_TYPE_PARAMETERS_NAME,
+            ALLOW_UNBOUNDED_LOOP_NAME,
+        ],
+        CFG_ATTRIBUTE_NAME => &[
+            CFG_TARGET_ARG_NAME,
+            CFG_PROGRAM_TYPE_ARG_NAME,
+            CFG_EXPERIMENTAL_NEW_ENCODING,
+        ],
+        TEST_ATTRIBUTE_NAME => &[TEST_SHOULD_REVERT_NAME, TEST_SHARED_STATE_NAME],
+        _ => &[],
+    }
+}
+
+/// Returns completion items for the primitive types and the structs and enums declared
+/// in the given namespace.
+fn type_items(namespace: Option<&Items>) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = PRIMITIVE_TYPES
+        .iter()
+        .map(|name| CompletionItem {
+            kind: Some(CompletionItemKind::KEYWORD),
+            label: name.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    for (name, decl) in namespace
+        .iter()
+        .flat_map(|namespace| namespace.symbols().iter())
+    {
+        let kind = match decl {
+            TyDecl::StructDecl(_) => CompletionItemKind::STRUCT,
+            TyDecl::EnumDecl(_) => CompletionItemKind::ENUM,
+            _ => continue,
+        };
+        items.push(CompletionItem {
+            kind: Some(kind),
+            label: name.as_str().to_string(),
+            ..Default::default()
+        });
+    }
+    items
+}
+
+/// Builds a [CompletionItem] that inserts the given snippet.
+fn snippet_item(label: &str, snippet: &str, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        kind: Some(kind),
+        label: label.to_string(),
+        insert_text: Some(snippet.to_string()),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completion_context_attributes() {
+        assert_eq!(
+            completion_context("#["),
+            Some(CompletionContext::AttributeName)
+        );
+        assert_eq!(
+            completion_context("contract;\n\n#[storage(read, "),
+            Some(CompletionContext::AttributeArg("storage".to_string()))
+        );
+        assert_eq!(completion_context("#[storage(read)"), None);
+        assert_eq!(completion_context("#[test]\nfn foo() {"), None);
+    }
+
+    #[test]
+    fn completion_context_blocks() {
+        assert_eq!(
+            completion_context("storage {\n    counter: "),
+            Some(CompletionContext::StorageFieldType)
+        );
+        assert_eq!(
+            completion_context("configurable {\n    A: u64 = 1,\n    B: "),
+            Some(CompletionContext::ConfigurableFieldType)
+        );
+        assert_eq!(completion_context("storage {\n    counter: u64 = "), None);
+        assert_eq!(
+            completion_context("storage {\n    a: u64 = 1,\n}\n\nfn foo(a: "),
+            None
+        );
+        assert_eq!(
+            completion_context("// {\nstorage {\n    s: str = \"}\",\n    b: "),
+            Some(CompletionContext::StorageFieldType)
+        );
+    }
+
+    #[test]
+    fn match_value_at_cursor_finds_scrutinee() {
+        assert_eq!(
+            match_value_at_cursor("fn foo() {\n    let a = match self.color {\n        "),
+            Some("self.color")
+        );
+        assert_eq!(
+            match_value_at_cursor("match c {\n    Color::Red => 1,\n    Col"),
+            Some("c")
+        );
+        assert_eq!(match_value_at_cursor("match c {\n    Color::Red => "), None);
+        assert_eq!(match_value_at_cursor("let rematch = foo {\n    "), None);
+    }
+}
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, Position, Range};
+use crate::core::token::get_range_from_line_cols;
+use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag};
 use serde::{Deserialize, Serialize};
 use sway_error::warning::CompileWarning;
-use sway_error::{error::CompileError, warning::Warning};
-use sway_types::{LineCol, SourceEngine, Spanned};
+use sway_error::{error::CompileError, handler::Handler, warning::Warning};
+use sway_parse::{ParsedFile, SourceEdit};
+use sway_types::{SourceEngine, SourceId, Spanned};
 
 pub(crate) type DiagnosticMap = HashMap<PathBuf, Diagnostics>;
 
@@ -15,11 +18,11 @@ pub struct Diagnostics {
     pub errors: Vec<Diagnostic>,
 }
 
-fn get_error_diagnostic(error: &CompileError) -> Diagnostic {
+fn get_error_diagnostic(error: &CompileError, source_engine: &SourceEngine) -> Diagnostic {
     let data = serde_json::to_value(DiagnosticData::try_from(error.clone()).ok()).ok();
 
     Diagnostic {
-        range: get_range(error.span().line_col()),
+        range: get_range_from_line_cols(source_engine.line_col_utf16(&error.span())),
         severity: Some(DiagnosticSeverity::ERROR),
         message: format!("{error}"),
         data,
@@ -27,9 +30,9 @@ fn get_error_diagnostic(error: &CompileError) -> Diagnostic {
     }
 }
 
-fn get_warning_diagnostic(warning: &CompileWarning) -> Diagnostic {
+fn get_warning_diagnostic(warning: &CompileWarning, source_engine: &SourceEngine) -> Diagnostic {
     Diagnostic {
-        range: get_range(warning.span().line_col()),
+        range: get_range_from_line_cols(source_engine.line_col_utf16(&warning.span())),
         severity: Some(DiagnosticSeverity::WARNING),
         message: warning.to_friendly_warning_string(),
         tags: get_warning_diagnostic_tags(&warning.warning_content),
@@ -44,7 +47,7 @@ pub fn get_diagnostics(
 ) -> DiagnosticMap {
     let mut diagnostics = DiagnosticMap::new();
     for warning in warnings {
-        let diagnostic = get_warning_diagnostic(warning);
+        let diagnostic = get_warning_diagnostic(warning, source_engine);
         if let Some(source_id) = warning.span().source_id() {
             let path = source_engine.get_path(source_id);
             diagnostics
@@ -55,7 +58,7 @@ pub fn get_diagnostics(
         }
     }
     for error in errors {
-        let diagnostic = get_error_diagnostic(error);
+        let diagnostic = get_error_diagnostic(error, source_engine);
         if let Some(source_id) = error.span().source_id() {
             let path = source_engine.get_path(source_id);
             diagnostics.entry(path).or_default().errors.push(diagnostic);
@@ -65,11 +68,92 @@ pub fn get_diagnostics(
     diagnostics
 }
 
-fn get_range((start, end): (LineCol, LineCol)) -> Range {
-    let pos = |lc: LineCol| Position::new(lc.line as u32 - 1, lc.col as u32 - 1);
-    let start = pos(start);
-    let end = pos(end);
-    Range { start, end }
+/// Parses the source of a single file, without compiling its project, and returns the diagnostics
+/// of lexing and parsing it.
+///
+/// The syntax of the experimental features the package of the file opts into is accepted.
+pub fn get_parse_diagnostics(
+    path: &PathBuf,
+    src: Arc<str>,
+    source_engine: &SourceEngine,
+) -> Diagnostics {
+    let handler = Handler::default();
+    let source_id = source_engine.get_source_id(path);
+    let experimental = package_experimental_flags(path);
+    let _ = sway_parse::parse_file(&handler, src, Some(source_id), experimental);
+    let (errors, warnings) = handler.consume();
+    get_diagnostics(&warnings, &errors, source_engine)
+        .remove(path)
+        .unwrap_or_default()
+}
+
+/// Parses the source of a single file again after the `edits` made to it since it was parsed into
+/// `previous`, and returns the diagnostics of parsing it along with its parse, to parse it again
+/// after the next edits.
+///
+/// Only the code touched by the edits is lexed and parsed again. The file is parsed from scratch
+/// when it has not been parsed before or has not been edited since.
+pub fn get_reparse_diagnostics(
+    path: &PathBuf,
+    src: Arc<str>,
+    previous: Option<ParsedFile>,
+    edits: &[SourceEdit],
+    source_engine: &SourceEngine,
+) -> (Diagnostics, Option<ParsedFile>) {
+    let source_id = source_engine.get_source_id(path);
+    let experimental = package_experimental_flags(path);
+    let reparsed = previous
+        .filter(|_| !edits.is_empty())
+        .and_then(|previous| reparse(previous, edits, source_id, experimental))
+        .filter(|(parsed, _)| parsed.src == src);
+    let (parsed, handler) = match reparsed {
+        Some((parsed, handler)) => (Some(parsed), handler),
+        None => {
+            let handler = Handler::default();
+            let parsed =
+                sway_parse::parse_file_incremental(&handler, src, Some(source_id), experimental)
+                    .ok();
+            (parsed, handler)
+        }
+    };
+    let (errors, warnings) = handler.consume();
+    let diagnostics = get_diagnostics(&warnings, &errors, source_engine)
+        .remove(path)
+        .unwrap_or_default();
+    (diagnostics, parsed)
+}
+
+/// Parses the file again after each of the `edits`, returning its last parse along with the
+/// handler of the diagnostics of that parse.
+fn reparse(
+    previous: ParsedFile,
+    edits: &[SourceEdit],
+    source_id: SourceId,
+    experimental: sway_parse::ExperimentalFlags,
+) -> Option<(ParsedFile, Handler)> {
+    let mut parsed = (previous, Handler::default());
+    for edit in edits {
+        let handler = Handler::default();
+        let reparsed =
+            sway_parse::reparse_file(&handler, &parsed.0, edit, Some(source_id), experimental)
+                .ok()?;
+        parsed = (reparsed, handler);
+    }
+    Some(parsed)
+}
+
+/// Returns the experimental features the package containing the file at `path` opts into.
+fn package_experimental_flags(path: &Path) -> sway_parse::ExperimentalFlags {
+    let mut experimental = sway_core::ExperimentalFlags::default();
+    if let Ok(manifest) = forc_pkg::PackageManifestFile::from_dir(path) {
+        for feature in &manifest.project.experimental {
+            experimental.enable(*feature);
+        }
+    }
+    sway_parse::ExperimentalFlags {
+        new_encoding: experimental.new_encoding,
+        test_only: experimental.test_only,
+    }
 }
 
 fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>> {
@@ -123,3 +207,37 @@ impl TryFrom<CompileError> for DiagnosticData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_with_the_experimental_features_of_the_package() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = r#"
+            [project]
+            name = "test"
+            license = "Apache-2.0"
+            entry = "main.sw"
+            implicit-std = false
+            experimental = ["test_only"]
+        "#;
+        std::fs::write(dir.path().join("Forc.toml"), manifest).unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        let path = dir.path().join("src/main.sw");
+        std::fs::write(&path, "script;\n\nfn main() {}\n").unwrap();
+
+        assert_eq!(
+            package_experimental_flags(&path),
+            sway_parse::ExperimentalFlags {
+                test_only: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            package_experimental_flags(&std::env::temp_dir().join("no_package/src/main.sw")),
+            sway_parse::ExperimentalFlags::default()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    core::{session::Session, token::get_range_from_span},
+    utils::syntax::{syntax_spans, SyntaxKind},
+};
+use lsp_types::{FoldingRange, FoldingRangeKind, Range, Url};
+use std::sync::Arc;
+use sway_ast::token::{CommentKind, CommentedTokenStream, CommentedTokenTree, CommentedTree};
+use sway_error::handler::Handler;
+use sway_types::{Span, Spanned};
+
+/// Returns the folding ranges for functions, impl blocks, storage blocks and other items,
+/// code blocks, `match` expressions and comments spanning multiple lines.
+pub fn folding_ranges(session: &Session, url: &Url) -> Option<Vec<FoldingRange>> {
+    let document = session.get_text_document(url).ok()?;
+    let src: Arc<str> = Arc::from(document.get_text());
+    let handler = Handler::default();
+
+    let mut ranges: Vec<FoldingRange> = vec![];
+    if let Ok(module) = sway_parse::parse_file(&handler, src.clone(), None, Default::default()) {
+        ranges.extend(
+            syntax_spans(&module.value)
+                .iter()
+                .filter(|syntax_span| {
+                    matches!(
+                        syntax_span.kind,
+                        SyntaxKind::Item | SyntaxKind::Block | SyntaxKind::Match
+                    )
+                })
+                .filter_map(|syntax_span| {
+                    folding_range(get_range_from_span(&syntax_span.span), None)
+                }),
+        );
+    }
+    if let Ok(token_stream) = sway_parse::lex_commented(&handler, &src, 0, src.len(), &None) {
+        let mut comments = vec![];
+        collect_comment_spans(&token_stream, &mut comments);
+        ranges.extend(
+            merge_adjacent_lines(comments)
+                .into_iter()
+                .filter_map(|range| folding_range(range, Some(FoldingRangeKind::Comment))),
+        );
+    }
+
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges.dedup_by(|a, b| a.start_line == b.start_line && a.end_line == b.end_line);
+    Some(ranges)
+}
+
+/// Builds a [FoldingRange] from the [Range] if it spans more than one line.
+fn folding_range(range: Range, kind: Option<FoldingRangeKind>) -> Option<FoldingRange> {
+    if range.end.line <= range.start.line {
+        return None;
+    }
+    Some(FoldingRange {
+        start_line: range.start.line,
+        end_line: range.end.line,
+        kind,
+        ..Default::default()
+    })
+}
+
+/// Collects the spans of all comments and doc comments that are not trailing or inlined.
+fn collect_comment_spans(token_stream: &CommentedTokenStream, spans: &mut Vec<Span>) {
+    for token_tree in token_stream.token_trees() {
+        match token_tree {
+            CommentedTokenTree::Comment(comment) => {
+                if !matches!(
+                    comment.comment_kind,
+                    CommentKind::Trailing | CommentKind::Inlined
+                ) {
+                    spans.push(comment.span());
+                }
+            }
+            CommentedTokenTree::Tree(CommentedTree::DocComment(doc_comment)) => {
+                spans.push(doc_comment.span());
+            }
+            CommentedTokenTree::Tree(CommentedTree::Group(group)) => {
+                collect_comment_spans(&group.token_stream, spans);
+            }
+            CommentedTokenTree::Tree(_) => {}
+        }
+    }
+}
+
+/// Merges the ranges of comments on consecutive lines, so that a block of line comments
+/// is folded as a whole.
+fn merge_adjacent_lines(spans: Vec<Span>) -> Vec<Range> {
+    let mut ranges: Vec<Range> = vec![];
+    for range in spans.iter().map(get_range_from_span) {
+        match ranges.last_mut() {
+            Some(last) if range.start.line == last.end.line + 1 => last.end = range.end,
+            _ => ranges.push(range),
+        }
+    }
+    ranges
+}
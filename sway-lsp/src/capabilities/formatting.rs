@@ -1,5 +1,6 @@
 use crate::error::LanguageServerError;
 use lsp_types::{Position, Range, TextEdit};
+use ropey::Rope;
 use std::sync::Arc;
 use swayfmt::Formatter;
 
@@ -21,3 +22,96 @@ pub fn get_page_text_edit(
         new_text: formatted_code,
     })
 }
+
+/// Returns the edits formatting the items of the document that intersect the [Range].
+pub fn get_range_text_edits(
+    text: Arc<str>,
+    range: &Range,
+    formatter: &mut Formatter,
+) -> Result<Vec<TextEdit>, LanguageServerError> {
+    let rope = Rope::from_str(&text);
+    let byte_range = position_to_byte(&rope, range.start)..position_to_byte(&rope, range.end);
+    let formatted_ranges = formatter
+        .format_range(text, byte_range, None)
+        .map_err(LanguageServerError::FormatError)?;
+
+    Ok(formatted_ranges
+        .into_iter()
+        .map(|formatted_range| TextEdit {
+            range: Range::new(
+                byte_to_position(&rope, formatted_range.range.start),
+                byte_to_position(&rope, formatted_range.range.end),
+            ),
+            new_text: formatted_range.formatted_code,
+        })
+        .collect())
+}
+
+/// Returns the edits correcting the formatting of the code around a character typed at the
+/// [Position], such as the closing brace of a block, the end of a statement or the arrow of a
+/// match arm.
+///
+/// The item enclosing the position is formatted with swayfmt, and only the part of it that
+/// changed is replaced, so that the code away from the edit keeps its layout.
+pub fn get_on_type_text_edits(
+    text: Arc<str>,
+    position: Position,
+    ch: &str,
+    formatter: &mut Formatter,
+) -> Result<Vec<TextEdit>, LanguageServerError> {
+    let rope = Rope::from_str(&text);
+    let offset = position_to_byte(&rope, position);
+    // The arrow of a match arm is triggered by its last character.
+    let typed = if ch == ">" { "=>" } else { ch };
+    if !text[..offset].ends_with(typed) {
+        return Ok(vec![]);
+    }
+    let formatted_ranges = formatter
+        .format_range(text.clone(), offset..offset, None)
+        .map_err(LanguageServerError::FormatError)?;
+
+    Ok(formatted_ranges
+        .into_iter()
+        .map(|formatted_range| {
+            let unformatted = &text[formatted_range.range.clone()];
+            let formatted = formatted_range.formatted_code.as_str();
+            let prefix: usize = unformatted
+                .chars()
+                .zip(formatted.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            let suffix: usize = unformatted[prefix..]
+                .chars()
+                .rev()
+                .zip(formatted[prefix..].chars().rev())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            let start = formatted_range.range.start + prefix;
+            let end = formatted_range.range.end - suffix;
+            TextEdit {
+                range: Range::new(byte_to_position(&rope, start), byte_to_position(&rope, end)),
+                new_text: formatted[prefix..formatted.len() - suffix].to_string(),
+            }
+        })
+        .collect())
+}
+
+fn position_to_byte(rope: &Rope, position: Position) -> usize {
+    if position.line as usize >= rope.len_lines() {
+        return rope.len_bytes();
+    }
+    let line_utf16_cu_index = rope.char_to_utf16_cu(rope.line_to_char(position.line as usize));
+    let utf16_cu_index =
+        (line_utf16_cu_index + position.character as usize).min(rope.len_utf16_cu());
+    rope.char_to_byte(rope.utf16_cu_to_char(utf16_cu_index))
+}
+
+fn byte_to_position(rope: &Rope, byte_index: usize) -> Position {
+    let char_index = rope.byte_to_char(byte_index);
+    let line_index = rope.char_to_line(char_index);
+    let character =
+        rope.char_to_utf16_cu(char_index) - rope.char_to_utf16_cu(rope.line_to_char(line_index));
+    Position::new(line_index as u32, character as u32)
+}
@@ -11,8 +11,9 @@ use crate::{
 };
 use std::sync::Arc;
 use sway_core::{
+    ir_generation::layout::{self, TypeLayout},
     language::{ty, Visibility},
-    Engines, TypeId,
+    Engines, TypeId, TypeInfo,
 };
 
 use lsp_types::{self, Position, Url};
@@ -51,6 +52,14 @@ pub fn hover_data(
         });
     }
 
+    // Calls of methods and generic functions are described by the function they resolved to.
+    let call_signature = match &token.typed {
+        Some(TypedAstToken::TypedExpression(expr)) => {
+            format_fn_application_hover(&session.engines.read(), expr)
+        }
+        _ => None,
+    };
+
     let contents = match &token.declared_token_ident(&session.engines.read()) {
         Some(decl_ident) => {
             let t = session.token_map().try_get(decl_ident).try_unwrap()?;
@@ -60,11 +69,18 @@ pub fn hover_data(
                 &session.engines.read(),
                 decl_token,
                 &decl_ident.name,
+                call_signature,
             )
         }
         // The `TypeInfo` of the token does not contain an `Ident`. In this case,
         // we use the `Ident` of the token itself.
-        None => hover_format(session.clone(), &session.engines.read(), token, &ident.name),
+        None => hover_format(
+            session.clone(),
+            &session.engines.read(),
+            token,
+            &ident.name,
+            call_signature,
+        ),
     };
 
     Some(lsp_types::Hover {
@@ -86,6 +102,74 @@ fn extract_fn_signature(span: &Span) -> String {
     value.split('{').take(1).map(|v| v.trim()).collect()
 }
 
+/// Formats the signature of the function a call resolved to. Methods are qualified with the
+/// trait they implement and the type parameters are replaced with the types they were
+/// instantiated with, e.g. `fn <S as MyTrait>::method(self) -> u64`.
+///
+/// Returns `None` for calls of plain functions, which are described by their declaration.
+fn format_fn_application_hover(engines: &Engines, expr: &ty::TyExpression) -> Option<String> {
+    let ty::TyExpressionVariant::FunctionApplication {
+        fn_ref,
+        call_path_typeid,
+        ..
+    } = &expr.expression
+    else {
+        return None;
+    };
+    let func = engines.de().get_function(fn_ref);
+    let (path, parent_type_params) = match &func.implementing_type {
+        Some(ty::TyDecl::ImplTrait(ty::ImplTrait { decl_id, .. })) => {
+            let impl_trait = engines.de().get_impl_trait(decl_id);
+            let self_type =
+                engines.help_out(call_path_typeid.unwrap_or(impl_trait.implementing_for.type_id));
+            let path = match impl_trait.trait_decl_ref {
+                Some(_) => format!("<{self_type} as {}>::", impl_trait.trait_name.suffix),
+                None => format!("{self_type}::"),
+            };
+            let parent_type_params = impl_trait
+                .impl_type_parameters
+                .iter()
+                .map(|param| param.name_ident.clone())
+                .collect::<Vec<_>>();
+            (path, parent_type_params)
+        }
+        _ if func.type_parameters.is_empty() => return None,
+        _ => (String::new(), vec![]),
+    };
+    // The type parameters of the impl block are already part of the implementing type.
+    let type_args = func
+        .type_parameters
+        .iter()
+        .filter(|param| !parent_type_params.contains(&param.name_ident))
+        .map(|param| engines.help_out(param.type_id).to_string())
+        .collect::<Vec<_>>();
+    let type_args = match type_args.is_empty() {
+        true => String::new(),
+        false => format!("<{}>", type_args.join(", ")),
+    };
+    let params = func
+        .parameters
+        .iter()
+        .map(|param| match param.is_self() {
+            true => "self".to_string(),
+            false => format!(
+                "{}: {}",
+                param.name,
+                engines.help_out(param.type_argument.type_id)
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match engines.te().get(func.return_type.type_id).is_unit() {
+        true => String::new(),
+        false => format!(" -> {}", engines.help_out(func.return_type.type_id)),
+    };
+    Some(format!(
+        "fn {path}{}{type_args}({params}){return_type}",
+        func.name
+    ))
+}
+
 fn format_doc_attributes(token: &Token) -> String {
     let mut doc_comment = String::new();
     if let Some(attributes) = doc_comment_attributes(token) {
@@ -114,6 +198,25 @@ fn format_variable_hover(is_mutable: bool, type_name: &str, token_name: &str) ->
     format!("let{mutability} {token_name}: {type_name}")
 }
 
+/// Formats the size and storage footprint of a type, followed by the offsets of its named fields.
+fn format_layout(layout: &TypeLayout, field_names: &[&str]) -> String {
+    let slots = match layout.storage_slots {
+        1 => "slot",
+        _ => "slots",
+    };
+    let mut text = format!(
+        "Size: {} bytes, {} storage {slots}",
+        layout.size_in_bytes, layout.storage_slots
+    );
+    if !layout.field_offsets.is_empty() {
+        text.push('\n');
+    }
+    for (name, offset) in field_names.iter().zip(&layout.field_offsets) {
+        text.push_str(&format!("\n- `{name}`: offset {offset}"));
+    }
+    text
+}
+
 fn markup_content(markup: Markup) -> lsp_types::MarkupContent {
     let kind = lsp_types::MarkupKind::Markdown;
     let value = markdown::format_docs(markup.as_str());
@@ -125,6 +228,7 @@ fn hover_format(
     engines: &Engines,
     token: &Token,
     ident_name: &str,
+    call_signature: Option<String>,
 ) -> lsp_types::HoverContents {
     let decl_engine = engines.de();
     let doc_comment = format_doc_attributes(token);
@@ -137,6 +241,9 @@ fn hover_format(
     // Used to collect all the information we need to generate links for the hover component.
     let mut hover_link_contents = HoverLinkContents::new(session, engines);
 
+    // The memory and storage layout of the type, if it can be computed.
+    let mut type_layout = None;
+
     let sway_block = token
         .typed
         .as_ref()
@@ -146,6 +253,18 @@ fn hover_format(
                     let type_name =
                         format!("{}", engines.help_out(var_decl.type_ascription.type_id));
                     hover_link_contents.add_related_types(&var_decl.type_ascription.type_id);
+                    if matches!(
+                        *engines.te().get(var_decl.type_ascription.type_id),
+                        TypeInfo::Array(..)
+                    ) {
+                        type_layout = layout::type_layout(
+                            engines,
+                            var_decl.type_ascription.type_id,
+                            &var_decl.type_ascription.span,
+                        )
+                        .ok()
+                        .map(|layout| format_layout(&layout, &[]));
+                    }
                     Some(format_variable_hover(
                         var_decl.mutability.is_mutable(),
                         &type_name,
@@ -155,6 +274,14 @@ fn hover_format(
                 ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
                     let struct_decl = decl_engine.get_struct(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
+                    let field_names = struct_decl
+                        .fields
+                        .iter()
+                        .map(|field| field.name.as_str())
+                        .collect::<Vec<_>>();
+                    type_layout = layout::struct_layout(engines, &struct_decl)
+                        .ok()
+                        .map(|layout| format_layout(&layout, &field_names));
                     Some(format_visibility_hover(
                         struct_decl.visibility,
                         decl.friendly_type_name(),
@@ -173,6 +300,14 @@ fn hover_format(
                 ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
                     let enum_decl = decl_engine.get_enum(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
+                    let variant_names = enum_decl
+                        .variants
+                        .iter()
+                        .map(|variant| variant.name.as_str())
+                        .collect::<Vec<_>>();
+                    type_layout = layout::enum_layout(engines, &enum_decl)
+                        .ok()
+                        .map(|layout| format_layout(&layout, &variant_names));
                     Some(format_visibility_hover(
                         enum_decl.visibility,
                         decl.friendly_type_name(),
@@ -187,7 +322,7 @@ fn hover_format(
             },
             TypedAstToken::TypedFunctionDeclaration(func) => {
                 hover_link_contents.add_related_types(&func.return_type.type_id);
-                Some(extract_fn_signature(&func.span()))
+                Some(call_signature.unwrap_or_else(|| extract_fn_signature(&func.span())))
             }
             TypedAstToken::TypedFunctionParameter(param) => {
                 hover_link_contents.add_related_types(&param.type_argument.type_id);
@@ -201,6 +336,27 @@ fn hover_format(
                     &field.type_argument.span(),
                     &field.type_argument.type_id,
                 );
+                type_layout = layout::type_layout(
+                    engines,
+                    field.type_argument.type_id,
+                    &field.type_argument.span,
+                )
+                .ok()
+                .map(|layout| format_layout(&layout, &[]));
+                Some(format_name_with_type(
+                    field.name.as_str(),
+                    &field.type_argument.type_id,
+                ))
+            }
+            TypedAstToken::TypedStorageField(field) => {
+                hover_link_contents.add_related_types(&field.type_argument.type_id);
+                type_layout = layout::type_layout(
+                    engines,
+                    field.type_argument.type_id,
+                    &field.type_argument.span,
+                )
+                .ok()
+                .map(|layout| format_layout(&layout, &[]));
                 Some(format_name_with_type(
                     field.name.as_str(),
                     &field.type_argument.type_id,
@@ -218,6 +374,7 @@ fn hover_format(
     let content = Markup::new()
         .maybe_add_sway_block(sway_block)
         .text(&doc_comment)
+        .maybe_add_text(type_layout)
         .maybe_add_links(
             engines.se(),
             hover_link_contents.related_types,
@@ -1,5 +1,5 @@
 use crate::{
-    config::InlayHintsConfig,
+    config::{GasHints, InlayHintsConfig},
     core::{
         session::Session,
         token::{get_range_from_span, TypedAstToken},
@@ -7,13 +7,14 @@ use crate::{
 };
 use lsp_types::{self, Range, Url};
 use std::sync::Arc;
-use sway_core::{language::ty::TyDecl, type_system::TypeInfo};
+use sway_core::{gas::GasEstimator, language::ty::TyDecl, type_system::TypeInfo};
 use sway_types::Spanned;
 
 // Future PR's will add more kinds
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InlayKind {
     TypeHint,
+    GasHint,
 }
 
 #[derive(Debug)]
@@ -29,16 +30,33 @@ pub fn inlay_hints(
     range: &Range,
     config: &InlayHintsConfig,
 ) -> Option<Vec<lsp_types::InlayHint>> {
+    if !config.type_hints && config.gas_hints == GasHints::Off {
+        return None;
+    }
+
+    let mut hints = vec![];
+    if config.type_hints {
+        hints.extend(type_hints(&session, uri, range, config));
+    }
+    if config.gas_hints != GasHints::Off {
+        hints.extend(gas_hints(&session, uri, range, config));
+    }
+
+    Some(hints)
+}
+
+fn type_hints(
+    session: &Session,
+    uri: &Url,
+    range: &Range,
+    config: &InlayHintsConfig,
+) -> Vec<lsp_types::InlayHint> {
     // 1. Loop through all our tokens and filter out all tokens that aren't TypedVariableDeclaration tokens
     // 2. Also filter out all tokens that have a span that fall outside of the provided range
     // 3. Filter out all variable tokens that have a type_ascription
     // 4. Look up the type id for the remaining tokens
     // 5. Convert the type into a string
-    if !config.type_hints {
-        return None;
-    }
-
-    let hints: Vec<lsp_types::InlayHint> = session
+    session
         .token_map()
         .tokens_for_file(uri)
         .filter_map(|item| {
@@ -74,30 +92,94 @@ pub fn inlay_hints(
             let inlay_hint = InlayHint { range, kind, label };
             self::inlay_hint(config.render_colons, inlay_hint)
         })
+        .collect()
+}
+
+/// Estimates the gas of the statements or of the bodies of the functions within the range.
+fn gas_hints(
+    session: &Session,
+    uri: &Url,
+    range: &Range,
+    config: &InlayHintsConfig,
+) -> Vec<lsp_types::InlayHint> {
+    let functions: Vec<_> = session
+        .token_map()
+        .tokens_for_file(uri)
+        .filter_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(func))
+                if item.key().range == get_range_from_span(&func.name.span()) =>
+            {
+                Some(func.clone())
+            }
+            _ => None,
+        })
         .collect();
 
-    Some(hints)
+    let engines = session.engines.read();
+    let mut estimator = GasEstimator::new(&engines);
+    let mut hints = vec![];
+    for func in functions {
+        let fn_range = get_range_from_span(&func.span());
+        if fn_range.end < range.start || fn_range.start > range.end {
+            continue;
+        }
+        match config.gas_hints {
+            GasHints::Functions => hints.push(InlayHint {
+                range: get_range_from_span(&func.name.span()),
+                kind: InlayKind::GasHint,
+                label: estimator.code_block(&func.body).to_string(),
+            }),
+            GasHints::Statements => hints.extend(func.body.contents.iter().filter_map(|node| {
+                let node_range = get_range_from_span(&node.span);
+                if node_range.start < range.start || node_range.end > range.end {
+                    return None;
+                }
+                match estimator.ast_node(node) {
+                    0 => None,
+                    gas => Some(InlayHint {
+                        range: node_range,
+                        kind: InlayKind::GasHint,
+                        label: gas.to_string(),
+                    }),
+                }
+            })),
+            GasHints::Off => {}
+        }
+    }
+    hints
+        .into_iter()
+        .map(|inlay_hint| self::inlay_hint(config.render_colons, inlay_hint))
+        .collect()
 }
 
 fn inlay_hint(render_colons: bool, inlay_hint: InlayHint) -> lsp_types::InlayHint {
     lsp_types::InlayHint {
         position: match inlay_hint.kind {
             // after annotated thing
-            InlayKind::TypeHint => inlay_hint.range.end,
+            InlayKind::TypeHint | InlayKind::GasHint => inlay_hint.range.end,
         },
         label: lsp_types::InlayHintLabel::String(match inlay_hint.kind {
             InlayKind::TypeHint if render_colons => format!(": {}", inlay_hint.label),
+            InlayKind::GasHint => format!("~{} gas", inlay_hint.label),
             _ => inlay_hint.label,
         }),
         kind: match inlay_hint.kind {
             InlayKind::TypeHint => Some(lsp_types::InlayHintKind::TYPE),
+            InlayKind::GasHint => None,
+        },
+        tooltip: match inlay_hint.kind {
+            InlayKind::TypeHint => None,
+            InlayKind::GasHint => Some(lsp_types::InlayHintTooltip::String(
+                "Estimated by a static gas model. Loops are counted as a single iteration."
+                    .to_string(),
+            )),
         },
-        tooltip: None,
         padding_left: Some(match inlay_hint.kind {
             InlayKind::TypeHint => !render_colons,
+            InlayKind::GasHint => true,
         }),
         padding_right: Some(match inlay_hint.kind {
-            InlayKind::TypeHint => false,
+            InlayKind::TypeHint | InlayKind::GasHint => false,
         }),
         text_edits: None,
         data: None,
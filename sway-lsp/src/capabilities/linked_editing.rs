@@ -0,0 +1,38 @@
+use crate::core::{session::Session, token::SymbolKind};
+use lsp_types::{LinkedEditingRanges, Position, Url};
+use std::sync::Arc;
+
+/// The identifiers that may be typed into a linked editing range.
+const IDENT_PATTERN: &str = "[a-zA-Z_][a-zA-Z0-9_]*";
+
+/// Returns the ranges of all of the occurrences of the generic parameter or local binding at the
+/// given position, so that editing one of them edits all of the others.
+///
+/// Only symbols that cannot be referred to from outside of their scope are linked, as renaming
+/// anything else may require edits in other files.
+pub fn linked_editing_ranges(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<LinkedEditingRanges> {
+    let kind = session
+        .token_map()
+        .token_at_position(url, position)?
+        .value()
+        .kind
+        .clone();
+    if !matches!(
+        kind,
+        SymbolKind::TypeParameter | SymbolKind::Variable | SymbolKind::ValueParam
+    ) {
+        return None;
+    }
+    let ranges = session.token_ranges(url, position)?;
+    if ranges.is_empty() {
+        return None;
+    }
+    Some(LinkedEditingRanges {
+        ranges,
+        word_pattern: Some(IDENT_PATTERN.to_string()),
+    })
+}
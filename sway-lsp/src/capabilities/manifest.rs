@@ -0,0 +1,356 @@
+//! Language features for `Forc.toml` manifests: document links, hovers and goto definition for
+//! dependencies, and a code action for updating a git dependency to its latest tag.
+
+use crate::utils::markup::Markup;
+use forc_pkg::{manifest::PackageManifestFile, source::git};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, DocumentLink, GotoDefinitionResponse, Hover,
+    HoverContents, Location, MarkupContent, MarkupKind, Position, Range, TextEdit, Url,
+    WorkspaceEdit,
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_utils::constants::MANIFEST_FILE_NAME;
+
+/// Tables of a manifest whose entries are dependencies.
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "contract-dependencies"];
+
+/// A dependency declared in a manifest, along with the ranges of its name and string fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ManifestDependency {
+    pub name: String,
+    pub name_range: Range,
+    pub fields: Vec<ManifestField>,
+}
+
+/// A `key = "value"` field of a dependency. The range covers the value without the quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ManifestField {
+    pub key: String,
+    pub value: String,
+    pub range: Range,
+}
+
+impl ManifestDependency {
+    fn field(&self, key: &str) -> Option<&ManifestField> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    fn contains(&self, position: Position) -> bool {
+        contains(&self.name_range, position)
+            || self
+                .fields
+                .iter()
+                .any(|field| contains(&field.range, position))
+    }
+}
+
+/// Returns true if the [Url] points to a `Forc.toml` manifest.
+pub fn is_manifest(url: &Url) -> bool {
+    url.path().ends_with(MANIFEST_FILE_NAME)
+}
+
+/// Returns links to the manifests of path dependencies and to the repositories of git dependencies.
+pub fn document_links(manifest_path: &Path, text: &str) -> Vec<DocumentLink> {
+    parse_dependencies(text)
+        .iter()
+        .flat_map(|dependency| dependency.fields.iter())
+        .filter_map(|field| {
+            let (target, tooltip) = match field.key.as_str() {
+                "path" => (
+                    path_dependency_manifest(manifest_path, &field.value)
+                        .and_then(|path| Url::from_file_path(path).ok())?,
+                    "Open the dependency's manifest",
+                ),
+                "git" => (Url::parse(&field.value).ok()?, "Open the repository"),
+                _ => return None,
+            };
+            Some(DocumentLink {
+                range: field.range,
+                target: Some(target),
+                tooltip: Some(tooltip.to_string()),
+                data: None,
+            })
+        })
+        .collect()
+}
+
+/// Describes the source of the dependency at the given [Position].
+pub fn hover(manifest_path: &Path, text: &str, position: Position) -> Option<Hover> {
+    let dependency = parse_dependencies(text)
+        .into_iter()
+        .find(|dependency| dependency.contains(position))?;
+    let mut markup = Markup::new();
+    if let Some(path) = dependency.field("path") {
+        let manifest = path_dependency_manifest(manifest_path, &path.value)
+            .and_then(|path| PackageManifestFile::from_file(path).ok());
+        markup = markup.text(&format!("Path dependency `{}`", dependency.name));
+        markup = match manifest {
+            Some(manifest) => markup.text(&format!(
+                "Package `{}` at `{}`",
+                manifest.project.name,
+                manifest.dir().display()
+            )),
+            None => markup.text(&format!("No manifest found at `{}`", path.value)),
+        };
+    } else if let Some(repo) = dependency.field("git") {
+        let reference = ["tag", "branch", "rev"]
+            .iter()
+            .find_map(|key| {
+                dependency
+                    .field(key)
+                    .map(|field| format!("{} `{}`", key, field.value))
+            })
+            .unwrap_or_else(|| "the default branch".to_string());
+        markup = markup
+            .text(&format!("Git dependency `{}`", dependency.name))
+            .text(&format!("Fetched from <{}> at {}", repo.value, reference));
+    } else if let Some(version) = dependency.field("version") {
+        markup = markup.text(&format!(
+            "Registry dependency `{}` with version requirement `{}`",
+            dependency.name, version.value
+        ));
+    } else {
+        return None;
+    }
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: markup.into(),
+        }),
+        range: Some(dependency.name_range),
+    })
+}
+
+/// Returns the location of the manifest of the path dependency at the given [Position].
+pub fn goto_definition(
+    manifest_path: &Path,
+    text: &str,
+    position: Position,
+) -> Option<GotoDefinitionResponse> {
+    let dependency = parse_dependencies(text)
+        .into_iter()
+        .find(|dependency| dependency.contains(position))?;
+    let path = path_dependency_manifest(manifest_path, &dependency.field("path")?.value)?;
+    Url::from_file_path(path)
+        .ok()
+        .map(|url| GotoDefinitionResponse::Scalar(Location::new(url, Range::default())))
+}
+
+/// Returns a code action that updates the tag of the git dependency within the [Range] to the
+/// latest semantic version tag of its repository.
+///
+/// This lists the tags of the remote repository, so it should not be run on the async runtime.
+pub fn code_actions(uri: &Url, text: &str, range: &Range) -> Option<Vec<CodeActionOrCommand>> {
+    let dependency = parse_dependencies(text)
+        .into_iter()
+        .find(|dependency| dependency.contains(range.start))?;
+    let tag = dependency.field("tag")?;
+    let repo = git::Url::from_str(&dependency.field("git")?.value).ok()?;
+    let latest_tag = match git::latest_semver_tag(&repo) {
+        Ok(latest_tag) => latest_tag?,
+        Err(err) => {
+            tracing::warn!("{}", err.to_string());
+            return None;
+        }
+    };
+    if latest_tag == tag.value {
+        return None;
+    }
+    let changes = HashMap::from([(
+        uri.clone(),
+        vec![TextEdit {
+            range: tag.range,
+            new_text: latest_tag.clone(),
+        }],
+    )]);
+    Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
+        title: format!("Update `{}` to `{}`", dependency.name, latest_tag),
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })])
+}
+
+/// Returns the path to the manifest of a path dependency, if it exists.
+fn path_dependency_manifest(manifest_path: &Path, dependency_path: &str) -> Option<PathBuf> {
+    let path = manifest_path
+        .parent()?
+        .join(dependency_path)
+        .join(MANIFEST_FILE_NAME);
+    path.canonicalize().ok()
+}
+
+/// Collects the dependencies declared in the manifest's dependency tables, either inline
+/// (`std = { git = "...", tag = "v0.1.0" }`) or as their own table (`[dependencies.std]`).
+pub(crate) fn parse_dependencies(text: &str) -> Vec<ManifestDependency> {
+    enum Section {
+        Dependencies,
+        Dependency,
+        Other,
+    }
+
+    let mut dependencies: Vec<ManifestDependency> = vec![];
+    let mut section = Section::Other;
+    for (line_index, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            let header = trimmed.trim_matches(|c| c == '[' || c == ']').trim();
+            section = if DEPENDENCY_TABLES.contains(&header) || header.starts_with("patch.") {
+                Section::Dependencies
+            } else if let Some((table, name)) = header.split_once('.') {
+                if !DEPENDENCY_TABLES.contains(&table) {
+                    Section::Other
+                } else {
+                    let start = line.find(name).unwrap_or_default();
+                    dependencies.push(ManifestDependency {
+                        name: name.to_string(),
+                        name_range: line_range(line, line_index, start, start + name.len()),
+                        fields: vec![],
+                    });
+                    Section::Dependency
+                }
+            } else {
+                Section::Other
+            };
+            continue;
+        }
+        match section {
+            Section::Dependencies => {
+                let Some(eq_index) = line.find('=') else {
+                    continue;
+                };
+                let name = line[..eq_index].trim();
+                if name.is_empty() || name.starts_with('#') {
+                    continue;
+                }
+                let start = line.find(name).unwrap_or_default();
+                // A dependency specified with just a string is a version requirement.
+                let fields = match string_value(line, line_index, eq_index + 1) {
+                    Some((value, range, _)) => vec![ManifestField {
+                        key: "version".to_string(),
+                        value,
+                        range,
+                    }],
+                    None => string_fields(line, line_index, eq_index + 1),
+                };
+                dependencies.push(ManifestDependency {
+                    name: name.to_string(),
+                    name_range: line_range(line, line_index, start, start + name.len()),
+                    fields,
+                });
+            }
+            Section::Dependency => {
+                if let Some(dependency) = dependencies.last_mut() {
+                    dependency.fields.extend(string_fields(line, line_index, 0));
+                }
+            }
+            Section::Other => {}
+        }
+    }
+    dependencies
+}
+
+/// Collects the `key = "value"` fields of the line starting from the byte offset `from`.
+fn string_fields(line: &str, line_index: usize, from: usize) -> Vec<ManifestField> {
+    let mut fields = vec![];
+    let mut rest = from;
+    while let Some(eq_offset) = line[rest..].find('=') {
+        let eq_index = rest + eq_offset;
+        let key = line[rest..eq_index]
+            .trim()
+            .trim_start_matches(|c| c == '{' || c == ',')
+            .trim();
+        match string_value(line, line_index, eq_index + 1) {
+            Some((value, range, end)) if !key.is_empty() => {
+                fields.push(ManifestField {
+                    key: key.to_string(),
+                    value,
+                    range,
+                });
+                // Continue after the closing quote of the value.
+                rest = end + 1;
+            }
+            _ => rest = eq_index + 1,
+        }
+    }
+    fields
+}
+
+/// Returns the quoted string directly following the byte offset `from`, its range, and the byte
+/// offset of its closing quote.
+fn string_value(line: &str, line_index: usize, from: usize) -> Option<(String, Range, usize)> {
+    let value = line[from..].trim_start().strip_prefix('"')?;
+    let start = line.len() - value.len();
+    let end = start + value.find('"')?;
+    Some((
+        line[start..end].to_string(),
+        line_range(line, line_index, start, end),
+        end,
+    ))
+}
+
+/// Converts the byte offsets of a line into a [Range] of UTF-16 code units.
+fn line_range(line: &str, line_index: usize, start: usize, end: usize) -> Range {
+    let character = |byte_index: usize| line[..byte_index].encode_utf16().count() as u32;
+    Range::new(
+        Position::new(line_index as u32, character(start)),
+        Position::new(line_index as u32, character(end)),
+    )
+}
+
+fn contains(range: &Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dependencies_in_all_forms() {
+        let manifest = r#"[project]
+name = "app"
+
+[dependencies]
+std = { git = "https://github.com/fuellang/sway", tag = "v0.49.1" }
+lib = { path = "../lib" }
+other = "0.1.0"
+
+[dependencies.token]
+git = "https://github.com/fuellang/token"
+branch = "master"
+"#;
+        let dependencies = parse_dependencies(manifest);
+        let names = dependencies
+            .iter()
+            .map(|dependency| dependency.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["std", "lib", "other", "token"]);
+
+        let std = &dependencies[0];
+        assert_eq!(
+            std.field("git").unwrap().value,
+            "https://github.com/fuellang/sway"
+        );
+        let tag = std.field("tag").unwrap();
+        assert_eq!(tag.value, "v0.49.1");
+        assert_eq!(
+            tag.range,
+            Range::new(Position::new(4, 57), Position::new(4, 64))
+        );
+        assert_eq!(dependencies[1].field("path").unwrap().value, "../lib");
+        assert_eq!(dependencies[2].field("version").unwrap().value, "0.1.0");
+        assert_eq!(dependencies[3].field("branch").unwrap().value, "master");
+        assert_eq!(
+            dependencies[3].name_range,
+            Range::new(Position::new(8, 14), Position::new(8, 19))
+        );
+    }
+}
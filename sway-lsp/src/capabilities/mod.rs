@@ -3,13 +3,17 @@ pub mod code_lens;
 pub mod completion;
 pub mod diagnostic;
 pub mod document_symbol;
+pub mod folding_range;
 pub mod formatting;
 pub mod highlight;
 pub mod hover;
 pub mod inlay_hints;
+pub mod linked_editing;
+pub mod manifest;
 pub mod on_enter;
 pub mod rename;
 pub mod runnable;
+pub mod selection_range;
 pub mod semantic_tokens;
 
 pub(crate) use code_actions::code_actions;
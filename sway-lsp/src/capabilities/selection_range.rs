@@ -0,0 +1,58 @@
+use crate::{
+    core::{session::Session, token::get_range_from_span},
+    utils::syntax::syntax_spans,
+};
+use lsp_types::{Position, Range, SelectionRange, Url};
+use std::sync::Arc;
+use sway_error::handler::Handler;
+
+/// Returns a [SelectionRange] for each of the positions, where each selection range is the
+/// innermost syntax node at the position and its parents are the enclosing nodes.
+pub fn selection_ranges(
+    session: &Session,
+    url: &Url,
+    positions: &[Position],
+) -> Option<Vec<SelectionRange>> {
+    let document = session.get_text_document(url).ok()?;
+    let src: Arc<str> = Arc::from(document.get_text());
+    let module = sway_parse::parse_file(&Handler::default(), src, None, Default::default()).ok()?;
+    let ranges = syntax_spans(&module.value)
+        .iter()
+        .map(|syntax_span| get_range_from_span(&syntax_span.span))
+        .collect::<Vec<_>>();
+
+    let selection_ranges = positions
+        .iter()
+        .map(|position| {
+            // Parents always precede their children, so the enclosing ranges are ordered
+            // from the outermost to the innermost.
+            let mut enclosing = ranges
+                .iter()
+                .filter(|range| contains(range, position))
+                .copied()
+                .collect::<Vec<_>>();
+            if let Some(token) = session.token_map().token_at_position(url, *position) {
+                enclosing.push(token.key().range);
+            }
+            enclosing.dedup();
+            enclosing
+                .into_iter()
+                .fold(None, |parent: Option<SelectionRange>, range| {
+                    Some(SelectionRange {
+                        range,
+                        parent: parent.map(Box::new),
+                    })
+                })
+                .unwrap_or(SelectionRange {
+                    range: Range::new(*position, *position),
+                    parent: None,
+                })
+        })
+        .collect();
+    Some(selection_ranges)
+}
+
+fn contains(range: &Range, position: &Position) -> bool {
+    (range.start.line, range.start.character) <= (position.line, position.character)
+        && (position.line, position.character) <= (range.end.line, range.end.character)
+}
@@ -42,6 +42,13 @@ impl Default for DebugConfig {
 pub struct DiagnosticConfig {
     pub show_warnings: bool,
     pub show_errors: bool,
+    /// Whether to display the fully-qualified names of types in diagnostics and hovers, rather
+    /// than their abbreviated forms.
+    #[serde(default)]
+    pub verbose_types: bool,
+    /// When to type-check the project to update its diagnostics.
+    #[serde(default)]
+    pub check_on: CheckOn,
 }
 
 impl Default for DiagnosticConfig {
@@ -49,10 +56,24 @@ impl Default for DiagnosticConfig {
         Self {
             show_warnings: true,
             show_errors: true,
+            verbose_types: false,
+            check_on: CheckOn::default(),
         }
     }
 }
 
+/// When the project is type-checked, which may be slow for large projects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckOn {
+    /// Type-check the project on every change.
+    #[default]
+    Type,
+    /// Type-check the project when a file is saved. On every change, only the changed file is
+    /// parsed, and its syntax errors reported.
+    Save,
+}
+
 // Options for confguring server logging.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -101,6 +122,21 @@ pub struct InlayHintsConfig {
     pub type_hints: bool,
     /// Maximum length for inlay hints. Set to null to have an unlimited length.
     pub max_length: Option<usize>,
+    /// Whether to show the estimated gas of statements or functions. Experimental.
+    #[serde(default)]
+    pub gas_hints: GasHints,
+}
+
+/// Where to render the estimated gas computed by the static gas model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GasHints {
+    #[default]
+    Off,
+    /// Show the estimated gas at the end of every statement of a function body.
+    Statements,
+    /// Show the estimated gas of a function body after the function's name.
+    Functions,
 }
 
 impl Default for InlayHintsConfig {
@@ -109,6 +145,7 @@ impl Default for InlayHintsConfig {
             render_colons: true,
             type_hints: true,
             max_length: Some(25),
+            gas_hints: GasHints::default(),
         }
     }
 }
@@ -0,0 +1,141 @@
+//! Dependencies type checked for one project, reused when compiling other projects which depend
+//! on the same packages, like `std`.
+
+use dashmap::DashMap;
+use forc_pkg::{self as pkg, BuildPlan, BuildProfile};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::Path,
+    sync::Arc,
+};
+use sway_core::{query_engine::QueryEngine, BuildTarget, Engines, ExperimentalFlags, Programs};
+use sway_error::handler::Handler;
+use sway_utils::helpers::get_sway_files;
+
+/// Identifies the dependencies of a [BuildPlan] by the hash of their sources, along with the
+/// compiler configuration they are type checked with.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DependencyCacheKey {
+    build_target: BuildTarget,
+    include_tests: bool,
+    dependencies: Vec<(String, u64, ExperimentalFlags)>,
+}
+
+impl DependencyCacheKey {
+    /// The dependencies are all of the packages of the plan but the last one in the compilation
+    /// order, which is the project being compiled.
+    pub fn new(plan: &BuildPlan, build_target: BuildTarget, include_tests: bool) -> Self {
+        let order = plan.compilation_order();
+        let dependencies = order[..order.len().saturating_sub(1)]
+            .iter()
+            .map(|&node| {
+                let pkg = &plan.graph()[node];
+                let manifest = &plan.manifest_map()[&pkg.id()];
+                let experimental = pkg::experimental_flags(plan, node, &BuildProfile::debug());
+                (
+                    pkg.name.clone(),
+                    sources_hash(manifest.path(), manifest.dir()),
+                    experimental,
+                )
+            })
+            .collect();
+        Self {
+            build_target,
+            include_tests,
+            dependencies,
+        }
+    }
+}
+
+/// The programs of type checked dependencies, along with a snapshot of the engines they were
+/// type checked with.
+#[derive(Debug)]
+pub struct CachedDependencies {
+    engines: Engines,
+    programs: Vec<Programs>,
+}
+
+impl CachedDependencies {
+    /// The programs of the dependencies, in compilation order.
+    pub fn programs(&self) -> &[Programs] {
+        &self.programs
+    }
+}
+
+/// Dependencies type checked by any session, shared by all of them.
+#[derive(Debug, Default)]
+pub struct DependencyCache(DashMap<DependencyCacheKey, Arc<CachedDependencies>>);
+
+impl DependencyCache {
+    /// Returns the cached dependencies identified by `key`, replacing `engines` with the engines
+    /// they were type checked with, so that the ids within them are valid.
+    pub fn seed(
+        &self,
+        key: &DependencyCacheKey,
+        engines: &mut Engines,
+    ) -> Option<Arc<CachedDependencies>> {
+        let cached = self.0.get(key)?.value().clone();
+        let verbose_types = engines.verbose_types();
+        *engines = fork(&cached.engines);
+        engines.set_verbose_types(verbose_types);
+        Some(cached)
+    }
+
+    /// Caches the dependencies identified by `key` from the results of checking their plan with
+    /// `engines`, if all of them type checked.
+    pub fn insert(
+        &self,
+        key: DependencyCacheKey,
+        engines: &Engines,
+        results: &[(Option<Programs>, Handler)],
+    ) {
+        let num_dependencies = key.dependencies.len();
+        if results.len() <= num_dependencies {
+            return;
+        }
+        let programs = results[..num_dependencies]
+            .iter()
+            .map(|(programs, _)| programs.clone().filter(|programs| programs.typed.is_ok()))
+            .collect::<Option<Vec<_>>>();
+        if let Some(programs) = programs {
+            let engines = fork(engines);
+            self.0
+                .insert(key, Arc::new(CachedDependencies { engines, programs }));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Clones the type, declaration and source engines.
+///
+/// The caches of the query engine are keyed by path and shared between clones, so they are not
+/// carried over to engines which go on to diverge from each other.
+fn fork(engines: &Engines) -> Engines {
+    Engines::new(
+        engines.te().clone(),
+        engines.de().clone(),
+        QueryEngine::default(),
+        engines.se().clone(),
+    )
+}
+
+/// Hashes the paths and contents of the manifest and the Sway files of a package.
+fn sources_hash(manifest_path: &Path, dir: &Path) -> u64 {
+    let mut files = get_sway_files(dir.to_path_buf());
+    files.push(manifest_path.to_path_buf());
+    files.sort();
+    let mut hasher = DefaultHasher::new();
+    for file in files {
+        file.hash(&mut hasher);
+        std::fs::read(&file).unwrap_or_default().hash(&mut hasher);
+    }
+    hasher.finish()
+}
@@ -5,6 +5,7 @@ use crate::{
 };
 use lsp_types::{Position, Range, TextDocumentContentChangeEvent, Url};
 use ropey::Rope;
+use sway_parse::SourceEdit;
 use tokio::fs::File;
 
 #[derive(Debug, Clone)]
@@ -38,16 +39,31 @@ impl TextDocument {
         self.content.line(line).to_string()
     }
 
-    pub fn apply_change(&mut self, change: &TextDocumentContentChangeEvent) {
+    /// Applies the change to the document, returning it as an edit of the bytes of its text.
+    pub fn apply_change(&mut self, change: &TextDocumentContentChangeEvent) -> SourceEdit {
         let edit = self.build_edit(change);
+        let source_edit = SourceEdit {
+            range: self.content.char_to_byte(edit.start_index)
+                ..self.content.char_to_byte(edit.end_index),
+            new_text: edit.change_text.to_string(),
+        };
 
         self.content.remove(edit.start_index..edit.end_index);
         self.content.insert(edit.start_index, edit.change_text);
+        source_edit
     }
 
     pub fn get_text(&self) -> String {
         self.content.to_string()
     }
+
+    /// Returns the text of the document from the start of the file up to the given [Position].
+    pub fn get_text_before(&self, position: Position) -> String {
+        let end_index = self
+            .position_to_index(position)
+            .min(self.content.len_chars());
+        self.content.slice(..end_index).to_string()
+    }
 }
 
 // private methods
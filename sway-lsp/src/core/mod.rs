@@ -1,3 +1,4 @@
+pub mod dependency_cache;
 pub mod document;
 pub mod session;
 pub(crate) mod sync;
@@ -1,20 +1,22 @@
 use crate::{
     capabilities::{
         self,
-        diagnostic::DiagnosticMap,
-        formatting::get_page_text_edit,
+        diagnostic::{DiagnosticMap, Diagnostics},
+        formatting::{get_on_type_text_edits, get_page_text_edit, get_range_text_edits},
         runnable::{Runnable, RunnableMainFn, RunnableTestFn},
     },
     core::{
+        dependency_cache::{DependencyCache, DependencyCacheKey},
         document::TextDocument,
         sync::SyncWorkspace,
         token::{self, TypedAstToken},
         token_map::{TokenMap, TokenMapExt},
     },
-    error::{DocumentError, LanguageServerError},
+    error::{DirectoryError, DocumentError, LanguageServerError},
     traverse::{
         dependency, lexed_tree, parsed_tree::ParsedTree, typed_tree::TypedTree, ParseContext,
     },
+    utils::document::get_url_from_path,
 };
 use dashmap::DashMap;
 use forc_pkg as pkg;
@@ -26,6 +28,7 @@ use parking_lot::RwLock;
 use pkg::{manifest::ManifestFile, BuildPlan};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
+    fs,
     path::PathBuf,
     sync::{atomic::AtomicBool, Arc},
 };
@@ -40,6 +43,7 @@ use sway_core::{
     BuildTarget, Engines, Namespace, Programs,
 };
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
+use sway_parse::{ParsedFile, SourceEdit};
 use sway_types::{SourceEngine, SourceId, Spanned};
 use sway_utils::{helpers::get_sway_files, PerformanceData};
 use tokio::{fs::File, io::AsyncWriteExt};
@@ -70,6 +74,9 @@ pub struct Session {
     // Cached diagnostic results that require a lock to access. Readers will wait for writers to complete.
     pub diagnostics: Arc<RwLock<DiagnosticMap>>,
     pub metrics: DashMap<SourceId, PerformanceData>,
+    /// The last parse of each document along with the edits made to it since, to parse it again
+    /// incrementally.
+    parsed_documents: DashMap<String, (ParsedFile, Vec<SourceEdit>)>,
 }
 
 impl Default for Session {
@@ -89,6 +96,7 @@ impl Session {
             engines: <_>::default(),
             sync: SyncWorkspace::new(),
             diagnostics: Arc::new(RwLock::new(DiagnosticMap::new())),
+            parsed_documents: DashMap::new(),
         }
     }
 
@@ -171,9 +179,41 @@ impl Session {
         position: Position,
         trigger_char: &str,
     ) -> Option<Vec<CompletionItem>> {
+        if let Ok(document) = self.get_text_document(uri) {
+            let text_before_cursor = document.get_text_before(position);
+            let namespace = self.namespace();
+            let contextual_items = capabilities::completion::to_contextual_completion_items(
+                namespace.as_deref().map(|module| &**module),
+                &text_before_cursor,
+            );
+            if contextual_items.is_some() {
+                return contextual_items;
+            }
+            if let Some(match_value) =
+                capabilities::completion::match_value_at_cursor(&text_before_cursor)
+            {
+                let engines = self.engines.read();
+                let fn_tokens =
+                    self.token_map
+                        .tokens_at_position(engines.se(), uri, position, Some(true));
+                if let Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) = fn_tokens
+                    .first()
+                    .and_then(|token| token.value().typed.clone())
+                {
+                    return capabilities::completion::to_match_arm_completion_items(
+                        &namespace?,
+                        &engines,
+                        match_value,
+                        &fn_decl,
+                    );
+                }
+            }
+        }
         let shifted_position = Position {
             line: position.line,
-            character: position.character - trigger_char.len() as u32 - 1,
+            character: position
+                .character
+                .checked_sub(trigger_char.len() as u32 + 1)?,
         };
         let t = self.token_map.token_at_position(uri, shifted_position)?;
         let ident_to_complete = t.key();
@@ -223,6 +263,46 @@ impl Session {
             .map(|page_text_edit| vec![page_text_edit])
     }
 
+    /// Formats the items of the document that intersect the [Range].
+    pub fn format_range(
+        &self,
+        url: &Url,
+        range: &Range,
+    ) -> Result<Vec<TextEdit>, LanguageServerError> {
+        let document = self
+            .documents
+            .try_get(url.path())
+            .try_unwrap()
+            .ok_or_else(|| DocumentError::DocumentNotFound {
+                path: url.path().to_string(),
+            })?;
+
+        get_range_text_edits(Arc::from(document.get_text()), range, &mut <_>::default())
+    }
+
+    /// Corrects the formatting of the code around the character `ch` typed at the [Position].
+    pub fn format_on_type(
+        &self,
+        url: &Url,
+        position: Position,
+        ch: &str,
+    ) -> Result<Vec<TextEdit>, LanguageServerError> {
+        let document = self
+            .documents
+            .try_get(url.path())
+            .try_unwrap()
+            .ok_or_else(|| DocumentError::DocumentNotFound {
+                path: url.path().to_string(),
+            })?;
+
+        get_on_type_text_edits(
+            Arc::from(document.get_text()),
+            position,
+            ch,
+            &mut <_>::default(),
+        )
+    }
+
     pub async fn handle_open_file(&self, uri: &Url) {
         if !self.documents.contains_key(uri.path()) {
             if let Ok(text_document) = TextDocument::build_from_path(uri.path()).await {
@@ -277,19 +357,102 @@ impl Session {
         url: &Url,
         changes: Vec<TextDocumentContentChangeEvent>,
     ) -> Option<String> {
-        self.documents
-            .try_get_mut(url.path())
-            .try_unwrap()
-            .map(|mut document| {
-                changes.iter().for_each(|change| {
-                    document.apply_change(change);
-                });
-                document.get_text()
-            })
+        let (edits, text) =
+            self.documents
+                .try_get_mut(url.path())
+                .try_unwrap()
+                .map(|mut document| {
+                    let edits: Vec<_> = changes
+                        .iter()
+                        .map(|change| document.apply_change(change))
+                        .collect();
+                    (edits, document.get_text())
+                })?;
+        if let Some(mut parsed) = self.parsed_documents.get_mut(url.path()) {
+            parsed.1.extend(edits);
+        }
+        Some(text)
+    }
+
+    /// Get the diagnostics of parsing the document at the given [Url], without compiling the
+    /// project.
+    ///
+    /// Only the code touched by the edits made to the document since it was last parsed is parsed
+    /// again.
+    pub fn parse_diagnostics(&self, url: &Url) -> Result<Diagnostics, DocumentError> {
+        let document = self.get_text_document(url)?;
+        let (previous, edits) = match self.parsed_documents.remove(url.path()) {
+            Some((_, (parsed, edits))) => (Some(parsed), edits),
+            None => (None, vec![]),
+        };
+        let (diagnostics, parsed) = capabilities::diagnostic::get_reparse_diagnostics(
+            &PathBuf::from(url.path()),
+            Arc::from(document.get_text()),
+            previous,
+            &edits,
+            self.engines.read().se(),
+        );
+        if let Some(parsed) = parsed {
+            self.parsed_documents
+                .insert(url.path().to_string(), (parsed, vec![]));
+        }
+        Ok(diagnostics)
+    }
+
+    /// Type check the project as if the edits were made to the document at the given [Url], and
+    /// return the errors it would have.
+    ///
+    /// The edited project is a copy of the temporary workspace, so the documents of the session are
+    /// left as they are. The engines of the session are rolled back once it is type checked,
+    /// discarding everything the speculative type check inserted into them.
+    pub fn speculative_errors(
+        &self,
+        url: &Url,
+        edits: &[TextEdit],
+    ) -> Result<Vec<CompileError>, LanguageServerError> {
+        let mut document = self.get_text_document(url)?;
+        // The edits refer to the document before any of them is made, so they are made from the
+        // last one to the first.
+        let mut edits = edits.to_vec();
+        edits.sort_by_key(|edit| edit.range.start);
+        for edit in edits.into_iter().rev() {
+            document.apply_change(&TextDocumentContentChangeEvent {
+                range: Some(edit.range),
+                range_length: None,
+                text: edit.new_text,
+            });
+        }
+
+        let temp_dir = self.sync.temp_dir()?;
+        let copy = self.sync.copy_temp_dir()?;
+        let path = copy.path().join(
+            PathBuf::from(url.path())
+                .strip_prefix(&temp_dir)
+                .map_err(DirectoryError::StripPrefixError)?,
+        );
+        fs::write(&path, document.get_text()).map_err(|err| DocumentError::UnableToWriteFile {
+            path: path.to_string_lossy().to_string(),
+            err: err.to_string(),
+        })?;
+
+        let mut engines = self.engines.write();
+        let snapshot = engines.snapshot();
+        let results = compile(
+            &get_url_from_path(&copy.path().to_path_buf())?,
+            &mut engines,
+            None,
+            None,
+        );
+        engines.rollback(snapshot);
+        Ok(results?
+            .pop()
+            .map(|(_, handler)| handler.consume().0)
+            .unwrap_or_default())
     }
 
     /// Remove the text document from the session.
     pub fn remove_document(&self, url: &Url) -> Result<TextDocument, DocumentError> {
+        self.parsed_documents.remove(url.path());
         self.documents
             .remove(url.path())
             .ok_or_else(|| DocumentError::DocumentNotFound {
@@ -344,22 +507,62 @@ pub(crate) fn build_plan(uri: &Url) -> Result<BuildPlan, LanguageServerError> {
         .map_err(LanguageServerError::BuildPlanFailed)
 }
 
+/// Compiles the project at `uri`.
+///
+/// If a [DependencyCache] is given, the dependencies of the project are reused from it when they
+/// are cached, in which case `engines` are replaced with those the dependencies were type checked
+/// with. They are cached otherwise.
 pub fn compile(
     uri: &Url,
-    engines: &Engines,
+    engines: &mut Engines,
     retrigger_compilation: Option<Arc<AtomicBool>>,
+    dependency_cache: Option<&DependencyCache>,
 ) -> Result<Vec<(Option<Programs>, Handler)>, LanguageServerError> {
     let build_plan = build_plan(uri)?;
+    let build_target = BuildTarget::default();
     let tests_enabled = true;
-    pkg::check(
+    let cache_key =
+        dependency_cache.map(|_| DependencyCacheKey::new(&build_plan, build_target, tests_enabled));
+    let cached_dependencies = dependency_cache
+        .zip(cache_key.as_ref())
+        .and_then(|(cache, key)| cache.seed(key, engines));
+    let results = pkg::check_with_dependencies(
         &build_plan,
-        BuildTarget::default(),
+        build_target,
         true,
         tests_enabled,
         engines,
         retrigger_compilation,
+        cached_dependencies
+            .as_ref()
+            .map(|cached| cached.programs())
+            .unwrap_or_default(),
     )
-    .map_err(LanguageServerError::FailedToCompile)
+    .map_err(LanguageServerError::FailedToCompile)?;
+    if let (Some(cache), Some(key), None) = (dependency_cache, cache_key, &cached_dependencies) {
+        cache.insert(key, engines, &results);
+    }
+    Ok(lint(&build_plan, results))
+}
+
+/// Lints the program of the package being edited, if its manifest has a `[lint]` section.
+fn lint(
+    build_plan: &BuildPlan,
+    mut results: Vec<(Option<Programs>, Handler)>,
+) -> Vec<(Option<Programs>, Handler)> {
+    // The results only hold the package being edited if all of its dependencies compiled.
+    let order = build_plan.compilation_order();
+    if results.len() != order.len() {
+        return results;
+    }
+    let pkg_id = build_plan.graph()[order[order.len() - 1]].id();
+    let Some(config) = &build_plan.manifest_map()[&pkg_id].lint else {
+        return results;
+    };
+    if let Some((Some(programs), handler)) = results.last_mut() {
+        *handler = sway_core::lint::lint(std::mem::take(handler), &programs.lexed, config);
+    }
+    results
 }
 
 type CompileResults = (Vec<CompileError>, Vec<CompileWarning>);
@@ -442,11 +645,12 @@ pub fn traverse(
 /// Parses the project and returns true if the compiler diagnostics are new and should be published.
 pub fn parse_project(
     uri: &Url,
-    engines: &Engines,
+    engines: &mut Engines,
     retrigger_compilation: Option<Arc<AtomicBool>>,
     session: Arc<Session>,
+    dependency_cache: Option<&DependencyCache>,
 ) -> Result<(), LanguageServerError> {
-    let results = compile(uri, engines, retrigger_compilation)?;
+    let results = compile(uri, engines, retrigger_compilation, dependency_cache)?;
     if results.last().is_none() {
         return Err(LanguageServerError::ProgramsIsNone);
     }
@@ -576,9 +780,9 @@ mod tests {
     fn parse_project_returns_manifest_file_not_found() {
         let dir = get_absolute_path("sway-lsp/tests/fixtures");
         let uri = get_url(&dir);
-        let engines = Engines::default();
+        let mut engines = Engines::default();
         let session = Arc::new(Session::new());
-        let result = parse_project(&uri, &engines, None, session)
+        let result = parse_project(&uri, &mut engines, None, session, None)
             .expect_err("expected ManifestFileNotFound");
         assert!(matches!(
             result,
@@ -588,4 +792,24 @@ mod tests {
             if test_dir == dir
         ));
     }
+
+    #[test]
+    fn compile_reuses_cached_dependencies() {
+        let uri = get_url(&get_absolute_path("sway-lsp/tests/fixtures/benchmark"));
+        let dependency_cache = DependencyCache::default();
+        let mut engines = Engines::default();
+        let results = compile(&uri, &mut engines, None, Some(&dependency_cache)).unwrap();
+        assert_eq!(dependency_cache.len(), 1);
+
+        // Compiling the project again with fresh engines type checks it against the cached
+        // dependencies rather than adding them to the cache again.
+        let mut engines = Engines::default();
+        let cached_results = compile(&uri, &mut engines, None, Some(&dependency_cache)).unwrap();
+        assert_eq!(dependency_cache.len(), 1);
+        assert_eq!(results.len(), cached_results.len());
+        let (programs, _) = cached_results.last().unwrap();
+        assert!(programs
+            .as_ref()
+            .is_some_and(|programs| programs.typed.is_ok()));
+    }
 }
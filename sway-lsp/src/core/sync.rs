@@ -17,7 +17,7 @@ use std::{
     time::Duration,
 };
 use sway_types::{SourceEngine, Span};
-use tempfile::Builder;
+use tempfile::{Builder, TempDir};
 use tokio::task::JoinHandle;
 
 #[derive(Debug, Eq, PartialEq, Hash)]
@@ -115,6 +115,18 @@ impl SyncWorkspace {
         Ok(())
     }
 
+    /// Copy the temporary workspace into a new temporary directory, which is removed when the
+    /// returned [TempDir] is dropped.
+    pub(crate) fn copy_temp_dir(&self) -> Result<TempDir, DirectoryError> {
+        let copy = Builder::new()
+            .prefix(SyncWorkspace::LSP_TEMP_PREFIX)
+            .tempdir()
+            .map_err(|_| DirectoryError::TempDirFailed)?;
+        copy_dir_contents(self.temp_dir()?, copy.path())
+            .map_err(|_| DirectoryError::CopyContentsFailed)?;
+        Ok(copy)
+    }
+
     /// Check if the current path is part of the users workspace.
     /// Returns false if the path is from a dependancy
     pub(crate) fn is_path_in_temp_workspace(&self, uri: &Url) -> bool {
@@ -16,7 +16,7 @@ use sway_core::{
     type_system::{TypeId, TypeInfo, TypeParameter},
     Engines, TraitConstraint, TypeArgument, TypeEngine,
 };
-use sway_types::{Ident, SourceEngine, Span, Spanned};
+use sway_types::{Ident, LineCol, SourceEngine, Span, Spanned};
 
 /// The `AstToken` holds the types produced by the [sway_core::language::parsed::ParseProgram].
 /// These tokens have not been type-checked.
@@ -198,13 +198,16 @@ pub struct TokenIdent {
 
 impl TokenIdent {
     pub fn new(ident: &Ident, se: &SourceEngine) -> Self {
+        // Identifiers of code generated by the compiler point at the item that triggered the
+        // generation, so they are not tied to a file to not shadow the tokens of that item.
         let path = ident
             .span()
             .source_id()
+            .filter(|_| !ident.span().is_synthetic())
             .map(|source_id| se.get_path(source_id));
         Self {
             name: ident.span().str(),
-            range: get_range_from_span(&ident.span()),
+            range: get_range_from_line_cols(se.line_col_utf16(&ident.span())),
             path,
             is_raw_ident: ident.is_raw_ident(),
         }
@@ -271,7 +274,7 @@ pub fn type_info_to_symbol_kind(
     }
 
     match type_info {
-        TypeInfo::UnsignedInteger(..) | TypeInfo::Boolean | TypeInfo::B256 => {
+        TypeInfo::UnsignedInteger(..) | TypeInfo::Boolean | TypeInfo::Never | TypeInfo::B256 => {
             SymbolKind::BuiltinType
         }
         TypeInfo::Numeric | TypeInfo::StringArray(..) => SymbolKind::NumericLiteral,
@@ -288,11 +291,18 @@ pub fn type_info_to_symbol_kind(
 }
 
 /// Given a [Span], convert into a [Range] and return.
+///
+/// This scans the source up to the span. Prefer [SourceEngine::line_col_utf16] where the
+/// engine is available, as it uses the cached line table of the source.
 pub fn get_range_from_span(span: &Span) -> Range {
-    let start = span.start_pos().line_col();
-    let end = span.end_pos().line_col();
+    get_range_from_line_cols(span.line_col_utf16())
+}
+
+/// Given the start and end [LineCol] of a span, convert into a [Range] and return.
+pub fn get_range_from_line_cols((start, end): (LineCol, LineCol)) -> Range {
+    let pos = |lc: LineCol| Position::new(lc.line as u32 - 1, lc.col as u32 - 1);
     Range {
-        start: Position::new(start.0 as u32 - 1, start.1 as u32 - 1),
-        end: Position::new(end.0 as u32 - 1, end.1 as u32 - 1),
+        start: pos(start),
+        end: pos(end),
     }
 }
@@ -2,6 +2,7 @@
 //! Protocol. This module specifically handles notification messages sent by the Client.
 
 use crate::{
+    config::CheckOn,
     core::{document, session::Session},
     error::LanguageServerError,
     server_state::{CompilationContext, ServerState, TaskMessage},
@@ -83,12 +84,20 @@ pub async fn handle_did_change_text_document(
     session
         .write_changes_to_file(&uri, params.content_changes)
         .await?;
-    send_new_compilation_request(
-        state,
-        session.clone(),
-        &uri,
-        Some(params.text_document.version),
-    );
+    let check_on = state.config.read().diagnostic.check_on;
+    match check_on {
+        CheckOn::Type => send_new_compilation_request(
+            state,
+            session.clone(),
+            &uri,
+            Some(params.text_document.version),
+        ),
+        CheckOn::Save => {
+            state
+                .publish_parse_diagnostics(uri, params.text_document.uri, session)
+                .await
+        }
+    }
     Ok(())
 }
 
@@ -6,10 +6,11 @@ use crate::{
 };
 use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions, TracingWriterMode};
 use lsp_types::{
-    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentSymbolResponse,
+    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
     InitializeResult, InlayHint, InlayHintParams, PrepareRenameResponse, RenameParams,
-    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
-    SemanticTokensResult, TextDocumentIdentifier, Url, WorkspaceEdit,
+    SelectionRange, SelectionRangeParams, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, TextDocumentIdentifier, Url, WorkspaceEdit,
 };
 use std::{
     fs::File,
@@ -73,6 +74,13 @@ pub async fn handle_goto_definition(
     state: &ServerState,
     params: lsp_types::GotoDefinitionParams,
 ) -> Result<Option<lsp_types::GotoDefinitionResponse>> {
+    let uri = &params.text_document_position_params.text_document.uri;
+    if capabilities::manifest::is_manifest(uri) {
+        let position = params.text_document_position_params.position;
+        return Ok(read_manifest(uri).and_then(|(path, text)| {
+            capabilities::manifest::goto_definition(&path, &text, position)
+        }));
+    }
     match state
         .sessions
         .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
@@ -118,6 +126,12 @@ pub async fn handle_hover(
     state: &ServerState,
     params: lsp_types::HoverParams,
 ) -> Result<Option<lsp_types::Hover>> {
+    let uri = &params.text_document_position_params.text_document.uri;
+    if capabilities::manifest::is_manifest(uri) {
+        let position = params.text_document_position_params.position;
+        return Ok(read_manifest(uri)
+            .and_then(|(path, text)| capabilities::manifest::hover(&path, &text, position)));
+    }
     match state
         .sessions
         .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
@@ -214,6 +228,29 @@ pub async fn handle_document_highlight(
     }
 }
 
+pub async fn handle_linked_editing_range(
+    state: &ServerState,
+    params: lsp_types::LinkedEditingRangeParams,
+) -> Result<Option<lsp_types::LinkedEditingRanges>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            Ok(capabilities::linked_editing::linked_editing_ranges(
+                session, &uri, position,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
 pub async fn handle_formatting(
     state: &ServerState,
     params: DocumentFormattingParams,
@@ -230,10 +267,55 @@ pub async fn handle_formatting(
         })
 }
 
+pub async fn handle_range_formatting(
+    state: &ServerState,
+    params: DocumentRangeFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    let _ = state.wait_for_parsing().await;
+    state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+        .and_then(|(uri, session)| session.format_range(&uri, &params.range).map(Some))
+        .or_else(|err| {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        })
+}
+
+pub async fn handle_on_type_formatting(
+    state: &ServerState,
+    params: DocumentOnTypeFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    let _ = state.wait_for_parsing().await;
+    let position = params.text_document_position.position;
+    state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document_position.text_document.uri)
+        .await
+        .and_then(|(uri, session)| session.format_on_type(&uri, position, &params.ch).map(Some))
+        .or_else(|err| {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        })
+}
+
 pub async fn handle_code_action(
     state: &ServerState,
     params: lsp_types::CodeActionParams,
 ) -> Result<Option<lsp_types::CodeActionResponse>> {
+    if capabilities::manifest::is_manifest(&params.text_document.uri) {
+        let Some((_, text)) = read_manifest(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        // Looking up the latest tag of a git dependency requires a network request.
+        return Ok(tokio::task::spawn_blocking(move || {
+            capabilities::manifest::code_actions(&params.text_document.uri, &text, &params.range)
+        })
+        .await
+        .ok()
+        .flatten());
+    }
     match state
         .sessions
         .uri_and_session_from_workspace(&params.text_document.uri)
@@ -271,6 +353,17 @@ pub async fn handle_code_lens(
     }
 }
 
+pub async fn handle_document_link(
+    _state: &ServerState,
+    params: DocumentLinkParams,
+) -> Result<Option<Vec<DocumentLink>>> {
+    let uri = &params.text_document.uri;
+    if !capabilities::manifest::is_manifest(uri) {
+        return Ok(None);
+    }
+    Ok(read_manifest(uri).map(|(path, text)| capabilities::manifest::document_links(&path, &text)))
+}
+
 pub async fn handle_semantic_tokens_range(
     state: &ServerState,
     params: SemanticTokensRangeParams,
@@ -313,7 +406,47 @@ pub async fn handle_semantic_tokens_full(
     }
 }
 
-pub(crate) async fn handle_inlay_hints(
+pub async fn handle_folding_range(
+    state: &ServerState,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::folding_range::folding_ranges(&session, &uri)),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_selection_range(
+    state: &ServerState,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .sessions
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => Ok(capabilities::selection_range::selection_ranges(
+            &session,
+            &uri,
+            &params.positions,
+        )),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
+pub async fn handle_inlay_hints(
     state: &ServerState,
     params: InlayHintParams,
 ) -> Result<Option<Vec<InlayHint>>> {
@@ -473,6 +606,18 @@ pub async fn handle_on_enter(
     }
 }
 
+/// Reads the `Forc.toml` manifest at the given [Url] from disk, returning its path and contents.
+fn read_manifest(uri: &Url) -> Option<(PathBuf, String)> {
+    let path = uri.to_file_path().ok()?;
+    match std::fs::read_to_string(&path) {
+        Ok(text) => Some((path, text)),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            None
+        }
+    }
+}
+
 /// Returns a [String] of the GraphViz DOT representation of a graph.
 pub fn handle_visualize(
     _state: &ServerState,
@@ -1,8 +1,12 @@
 //! The context or environment in which the language server functions.
 
 use crate::{
+    capabilities::diagnostic::Diagnostics,
     config::{Config, Warnings},
-    core::session::{self, Session},
+    core::{
+        dependency_cache::DependencyCache,
+        session::{self, Session},
+    },
     error::{DirectoryError, DocumentError, LanguageServerError},
     utils::debug,
     utils::keyword_docs::KeywordDocs,
@@ -29,6 +33,7 @@ pub struct ServerState {
     pub(crate) config: Arc<RwLock<Config>>,
     pub(crate) keyword_docs: Arc<KeywordDocs>,
     pub(crate) sessions: Arc<Sessions>,
+    pub(crate) dependency_cache: Arc<DependencyCache>,
     pub(crate) retrigger_compilation: Arc<AtomicBool>,
     pub is_compiling: Arc<AtomicBool>,
     pub(crate) cb_tx: Sender<TaskMessage>,
@@ -45,6 +50,7 @@ impl Default for ServerState {
             config: Arc::new(RwLock::new(Default::default())),
             keyword_docs: Arc::new(KeywordDocs::new()),
             sessions: Arc::new(Sessions(DashMap::new())),
+            dependency_cache: Arc::new(DependencyCache::default()),
             retrigger_compilation: Arc::new(AtomicBool::new(false)),
             is_compiling: Arc::new(AtomicBool::new(false)),
             cb_tx,
@@ -103,6 +109,8 @@ impl ServerState {
         let finished_compilation = self.finished_compilation.clone();
         let rx = self.cb_rx.clone();
         let last_compilation_state = self.last_compilation_state.clone();
+        let config = self.config.clone();
+        let dependency_cache = self.dependency_cache.clone();
         std::thread::spawn(move || {
             while let Ok(msg) = rx.recv() {
                 match msg {
@@ -110,6 +118,7 @@ impl ServerState {
                         let uri = ctx.uri.as_ref().unwrap().clone();
                         let session = ctx.session.as_ref().unwrap().clone();
                         let mut engines_clone = session.engines.read().clone();
+                        engines_clone.set_verbose_types(config.read().diagnostic.verbose_types);
 
                         if let Some(version) = ctx.version {
                             // Garbage collection is fairly expsensive so we only clear on every 10th keystroke.
@@ -125,13 +134,18 @@ impl ServerState {
                             }
                         }
 
+                        // Only the first compilation of a session reuses the dependencies type checked by
+                        // other sessions, later ones reuse the programs cached by the engines of the session.
+                        let is_first_compilation = session.compiled_program.read().typed.is_none();
+
                         // Set the is_compiling flag to true so that the wait_for_parsing function knows that we are compiling
                         is_compiling.store(true, Ordering::SeqCst);
                         match session::parse_project(
                             &uri,
-                            &engines_clone,
+                            &mut engines_clone,
                             Some(retrigger_compilation.clone()),
                             session.clone(),
+                            is_first_compilation.then_some(&*dependency_cache),
                         ) {
                             Ok(_) => {
                                 mem::swap(&mut *session.engines.write(), &mut engines_clone);
@@ -218,35 +232,59 @@ impl ServerState {
         }
     }
 
-    async fn diagnostics(&self, uri: &Url, session: Arc<Session>) -> Vec<Diagnostic> {
-        let mut diagnostics_to_publish = vec![];
+    /// Publishes the diagnostics of parsing the file, without compiling the project.
+    pub(crate) async fn publish_parse_diagnostics(
+        &self,
+        uri: Url,
+        workspace_uri: Url,
+        session: Arc<Session>,
+    ) {
+        let Ok(diagnostics) = session.parse_diagnostics(&uri) else {
+            return;
+        };
+        let diagnostics = self.filter_diagnostics(&diagnostics);
+        if let Some(client) = self.client.as_ref() {
+            client
+                .publish_diagnostics(workspace_uri, diagnostics, None)
+                .await;
+        }
+    }
+
+    /// Returns the warnings and errors the user chose to display.
+    fn filter_diagnostics(&self, diagnostics: &Diagnostics) -> Vec<Diagnostic> {
         let config = &self.config.read();
+        let mut diagnostics_to_publish = vec![];
+        if config.diagnostic.show_warnings {
+            diagnostics_to_publish.extend(diagnostics.warnings.clone());
+        }
+        if config.diagnostic.show_errors {
+            diagnostics_to_publish.extend(diagnostics.errors.clone());
+        }
+        diagnostics_to_publish
+    }
+
+    async fn diagnostics(&self, uri: &Url, session: Arc<Session>) -> Vec<Diagnostic> {
         let tokens = session.token_map().tokens_for_file(uri);
-        match config.debug.show_collected_tokens_as_warnings {
+        let show_collected_tokens_as_warnings = self
+            .config
+            .read()
+            .debug
+            .show_collected_tokens_as_warnings
+            .clone();
+        match show_collected_tokens_as_warnings {
             // If collected_tokens_as_warnings is Parsed or Typed,
             // take over the normal error and warning display behavior
             // and instead show the either the parsed or typed tokens as warnings.
             // This is useful for debugging the lsp parser.
-            Warnings::Parsed => {
-                diagnostics_to_publish = debug::generate_warnings_for_parsed_tokens(tokens)
-            }
-            Warnings::Typed => {
-                diagnostics_to_publish = debug::generate_warnings_for_typed_tokens(tokens)
-            }
-            Warnings::Default => {
-                if let Some(diagnostics) =
-                    session.diagnostics.read().get(&PathBuf::from(uri.path()))
-                {
-                    if config.diagnostic.show_warnings {
-                        diagnostics_to_publish.extend(diagnostics.warnings.clone());
-                    }
-                    if config.diagnostic.show_errors {
-                        diagnostics_to_publish.extend(diagnostics.errors.clone());
-                    }
-                }
-            }
+            Warnings::Parsed => debug::generate_warnings_for_parsed_tokens(tokens),
+            Warnings::Typed => debug::generate_warnings_for_typed_tokens(tokens),
+            Warnings::Default => session
+                .diagnostics
+                .read()
+                .get(&PathBuf::from(uri.path()))
+                .map(|diagnostics| self.filter_diagnostics(diagnostics))
+                .unwrap_or_default(),
         }
-        diagnostics_to_publish
     }
 }
 
@@ -159,6 +159,10 @@ impl Parse for Expr {
                 value.parse(ctx);
                 branches.get().iter().par_bridge().for_each(|branch| {
                     branch.pattern.parse(ctx);
+                    if let Some((if_token, guard)) = &branch.guard_opt {
+                        insert_keyword(ctx, if_token.span());
+                        guard.parse(ctx);
+                    }
                     branch.kind.parse(ctx);
                 });
             }
@@ -182,6 +186,10 @@ impl Parse for Expr {
                 target.parse(ctx);
                 arg.get().parse(ctx);
             }
+            Expr::Range { start, end, .. } => {
+                start.parse(ctx);
+                end.parse(ctx);
+            }
             Expr::MethodCall {
                 target,
                 contract_args_opt,
@@ -602,6 +610,10 @@ impl Parse for StatementLet {
             ty.parse(ctx);
         }
         self.expr.parse(ctx);
+        if let Some((else_token, block)) = &self.else_opt {
+            insert_keyword(ctx, else_token.span());
+            block.get().parse(ctx);
+        }
     }
 }
 
@@ -657,6 +669,10 @@ impl Parse for IfCondition {
                 lhs.parse(ctx);
                 rhs.parse(ctx);
             }
+            IfCondition::And { lhs, rhs, .. } => {
+                lhs.parse(ctx);
+                rhs.parse(ctx);
+            }
         }
     }
 }
@@ -269,6 +269,9 @@ impl Parse for Expression {
                 value.parse(ctx);
                 branches.par_iter().for_each(|branch| {
                     branch.scrutinee.parse(ctx);
+                    if let Some(guard) = &branch.guard {
+                        guard.parse(ctx);
+                    }
                     branch.result.parse(ctx);
                 });
             }
@@ -0,0 +1,343 @@
+//! Collects the spans of the nodes of a lexed [Module] for requests that only depend on
+//! the structure of the source, such as folding ranges and selection ranges.
+
+use sway_ast::{
+    attribute::Annotated, expr::LoopControlFlow, CodeBlockContents, Expr, ExprArrayDescriptor,
+    ExprTupleDescriptor, IfCondition, IfExpr, Item, ItemFn, ItemImplItem, ItemKind, ItemTraitItem,
+    MatchBranchKind, Module, Statement,
+};
+use sway_types::{Span, Spanned};
+
+/// The kind of syntax node a [SyntaxSpan] was collected from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SyntaxKind {
+    /// A top-level or nested item, such as a function, impl block or storage block.
+    Item,
+    /// A field of a struct, enum, storage or configurable block.
+    Field,
+    /// A braced code block.
+    Block,
+    /// A `match` expression.
+    Match,
+    /// An arm of a `match` expression.
+    MatchArm,
+    Statement,
+    Expr,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SyntaxSpan {
+    pub kind: SyntaxKind,
+    pub span: Span,
+}
+
+/// Returns the spans of all of the items, statements and expressions of the module,
+/// with parents always preceding their children.
+pub(crate) fn syntax_spans(module: &Module) -> Vec<SyntaxSpan> {
+    let mut collector = Collector::default();
+    for item in &module.items {
+        collector.item(item);
+    }
+    collector.spans
+}
+
+#[derive(Default)]
+struct Collector {
+    spans: Vec<SyntaxSpan>,
+}
+
+impl Collector {
+    fn push(&mut self, kind: SyntaxKind, span: Span) {
+        self.spans.push(SyntaxSpan { kind, span });
+    }
+
+    fn item(&mut self, item: &Item) {
+        self.push(SyntaxKind::Item, item.value.span());
+        match &item.value {
+            ItemKind::Fn(item_fn) => self.item_fn(item_fn),
+            ItemKind::Struct(item_struct) => {
+                for field in &item_struct.fields.inner {
+                    self.push(SyntaxKind::Field, field.value.span());
+                }
+            }
+            ItemKind::Enum(item_enum) => {
+                for field in &item_enum.fields.inner {
+                    self.push(SyntaxKind::Field, field.value.span());
+                }
+            }
+            ItemKind::Trait(item_trait) => {
+                self.trait_items(&item_trait.trait_items.inner);
+                if let Some(defs) = &item_trait.trait_defs_opt {
+                    for item_fn in &defs.inner {
+                        self.push(SyntaxKind::Item, item_fn.value.span());
+                        self.item_fn(&item_fn.value);
+                    }
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                self.trait_items(&item_abi.abi_items.inner);
+                if let Some(defs) = &item_abi.abi_defs_opt {
+                    for item_fn in &defs.inner {
+                        self.push(SyntaxKind::Item, item_fn.value.span());
+                        self.item_fn(&item_fn.value);
+                    }
+                }
+            }
+            ItemKind::Impl(item_impl) => {
+                for impl_item in &item_impl.contents.inner {
+                    match &impl_item.value {
+                        ItemImplItem::Fn(item_fn) => {
+                            self.push(SyntaxKind::Item, item_fn.span());
+                            self.item_fn(item_fn);
+                        }
+                        ItemImplItem::Const(item_const) => {
+                            self.push(SyntaxKind::Item, item_const.span());
+                            if let Some(expr) = &item_const.expr_opt {
+                                self.expr(expr);
+                            }
+                        }
+                        ItemImplItem::Type(trait_type) => {
+                            self.push(SyntaxKind::Item, trait_type.span());
+                        }
+                    }
+                }
+            }
+            ItemKind::Const(item_const) => {
+                if let Some(expr) = &item_const.expr_opt {
+                    self.expr(expr);
+                }
+            }
+            ItemKind::Storage(item_storage) => {
+                for field in &item_storage.fields.inner {
+                    self.push(SyntaxKind::Field, field.value.span());
+                    self.expr(&field.value.initializer);
+                }
+            }
+            ItemKind::Configurable(item_configurable) => {
+                for field in &item_configurable.fields.inner {
+                    self.push(SyntaxKind::Field, field.value.span());
+                    self.expr(&field.value.initializer);
+                }
+            }
+            ItemKind::Submodule(_)
+            | ItemKind::Use(_)
+            | ItemKind::TypeAlias(_)
+            | ItemKind::Error(_, _) => {}
+        }
+    }
+
+    fn trait_items(&mut self, items: &[Annotated<ItemTraitItem>]) {
+        for item in items {
+            let span = match &item.value {
+                ItemTraitItem::Fn(fn_signature, _) => fn_signature.span(),
+                ItemTraitItem::Const(item_const, _) => item_const.span(),
+                ItemTraitItem::Type(trait_type, _) => trait_type.span(),
+                ItemTraitItem::Error(spans, _) => Span::join_all(spans.iter().cloned()),
+            };
+            self.push(SyntaxKind::Item, span);
+        }
+    }
+
+    fn item_fn(&mut self, item_fn: &ItemFn) {
+        self.push(SyntaxKind::Block, item_fn.body.span());
+        self.code_block(item_fn.body.get());
+    }
+
+    fn code_block(&mut self, block: &CodeBlockContents) {
+        for statement in &block.statements {
+            self.push(SyntaxKind::Statement, statement.span());
+            match statement {
+                Statement::Let(statement_let) => {
+                    self.expr(&statement_let.expr);
+                    if let Some((_, block)) = &statement_let.else_opt {
+                        self.push(SyntaxKind::Block, block.span());
+                        self.code_block(block.get());
+                    }
+                }
+                Statement::Item(item) => self.item(item),
+                Statement::Expr { expr, .. } => self.expr(expr),
+                Statement::Error(_, _) => {}
+            }
+        }
+        if let Some(expr) = &block.final_expr_opt {
+            self.expr(expr);
+        }
+    }
+
+    fn if_condition(&mut self, condition: &IfCondition) {
+        match condition {
+            IfCondition::Expr(expr) => self.expr(expr),
+            IfCondition::Let { rhs, .. } => self.expr(rhs),
+            IfCondition::And { lhs, rhs, .. } => {
+                self.if_condition(lhs);
+                self.if_condition(rhs);
+            }
+        }
+    }
+
+    fn if_expr(&mut self, if_expr: &IfExpr) {
+        self.if_condition(&if_expr.condition);
+        self.push(SyntaxKind::Block, if_expr.then_block.span());
+        self.code_block(if_expr.then_block.get());
+        match &if_expr.else_opt {
+            Some((_, LoopControlFlow::Break(block))) => {
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            Some((_, LoopControlFlow::Continue(if_expr))) => {
+                self.push(SyntaxKind::Expr, if_expr.span());
+                self.if_expr(if_expr);
+            }
+            None => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        let kind = match expr {
+            Expr::Match { .. } => SyntaxKind::Match,
+            Expr::Block(_) => SyntaxKind::Block,
+            _ => SyntaxKind::Expr,
+        };
+        self.push(kind, expr.span());
+        match expr {
+            Expr::Match {
+                value, branches, ..
+            } => {
+                self.expr(value);
+                for branch in branches.get() {
+                    self.push(SyntaxKind::MatchArm, branch.span());
+                    if let Some((_, guard)) = &branch.guard_opt {
+                        self.expr(guard);
+                    }
+                    match &branch.kind {
+                        MatchBranchKind::Block { block, .. } => {
+                            self.push(SyntaxKind::Block, block.span());
+                            self.code_block(block.get());
+                        }
+                        MatchBranchKind::Expr { expr, .. } => self.expr(expr),
+                    }
+                }
+            }
+            Expr::Block(block) => self.code_block(block.get()),
+            Expr::AbiCast { args, .. } => self.expr(&args.get().address),
+            Expr::Struct { fields, .. } => {
+                for field in fields.get() {
+                    if let Some((_, expr)) = &field.expr_opt {
+                        self.expr(expr);
+                    }
+                }
+            }
+            Expr::Tuple(tuple) => {
+                if let ExprTupleDescriptor::Cons { head, tail, .. } = tuple.get() {
+                    self.expr(head);
+                    for expr in tail {
+                        self.expr(expr);
+                    }
+                }
+            }
+            Expr::Parens(expr) => self.expr(expr.get()),
+            Expr::Array(array) => match array.get() {
+                ExprArrayDescriptor::Sequence(exprs) => {
+                    for expr in exprs {
+                        self.expr(expr);
+                    }
+                }
+                ExprArrayDescriptor::Repeat { value, length, .. } => {
+                    self.expr(value);
+                    self.expr(length);
+                }
+            },
+            Expr::Asm(asm) => {
+                for register in asm.registers.get() {
+                    if let Some((_, initializer)) = &register.value_opt {
+                        self.expr(initializer);
+                    }
+                }
+            }
+            Expr::Return { expr_opt, .. } => {
+                if let Some(expr) = expr_opt {
+                    self.expr(expr);
+                }
+            }
+            Expr::If(if_expr) => self.if_expr(if_expr),
+            Expr::While {
+                condition, block, ..
+            } => {
+                self.if_condition(condition);
+                self.push(SyntaxKind::Block, block.span());
+                self.code_block(block.get());
+            }
+            Expr::FuncApp { func, args } => {
+                self.expr(func);
+                for arg in args.get() {
+                    self.expr(arg);
+                }
+            }
+            Expr::Index { target, arg } => {
+                self.expr(target);
+                self.expr(arg.get());
+            }
+            Expr::Range { start, end, .. } => {
+                self.expr(start);
+                self.expr(end);
+            }
+            Expr::MethodCall {
+                target,
+                contract_args_opt,
+                args,
+                ..
+            } => {
+                self.expr(target);
+                if let Some(contract_args) = contract_args_opt {
+                    for field in contract_args.get() {
+                        if let Some((_, expr)) = &field.expr_opt {
+                            self.expr(expr);
+                        }
+                    }
+                }
+                for arg in args.get() {
+                    self.expr(arg);
+                }
+            }
+            Expr::FieldProjection { target, .. } | Expr::TupleFieldProjection { target, .. } => {
+                self.expr(target)
+            }
+            Expr::Ref { expr, .. } | Expr::Deref { expr, .. } | Expr::Not { expr, .. } => {
+                self.expr(expr)
+            }
+            Expr::Mul { lhs, rhs, .. }
+            | Expr::Div { lhs, rhs, .. }
+            | Expr::Pow { lhs, rhs, .. }
+            | Expr::Modulo { lhs, rhs, .. }
+            | Expr::Add { lhs, rhs, .. }
+            | Expr::Sub { lhs, rhs, .. }
+            | Expr::Shl { lhs, rhs, .. }
+            | Expr::Shr { lhs, rhs, .. }
+            | Expr::BitAnd { lhs, rhs, .. }
+            | Expr::BitXor { lhs, rhs, .. }
+            | Expr::BitOr { lhs, rhs, .. }
+            | Expr::Equal { lhs, rhs, .. }
+            | Expr::NotEqual { lhs, rhs, .. }
+            | Expr::LessThan { lhs, rhs, .. }
+            | Expr::GreaterThan { lhs, rhs, .. }
+            | Expr::LessThanEq { lhs, rhs, .. }
+            | Expr::GreaterThanEq { lhs, rhs, .. }
+            | Expr::LogicalAnd { lhs, rhs, .. }
+            | Expr::LogicalOr { lhs, rhs, .. } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expr::Reassignment {
+                assignable, expr, ..
+            } => {
+                self.push(SyntaxKind::Expr, assignable.span());
+                self.expr(expr);
+            }
+            Expr::Error(_, _)
+            | Expr::Path(_)
+            | Expr::Literal(_)
+            | Expr::Break { .. }
+            | Expr::Continue { .. } => {}
+        }
+    }
+}
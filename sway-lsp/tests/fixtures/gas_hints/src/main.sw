@@ -0,0 +1,14 @@
+script;
+
+fn double(x: u64) -> u64 {
+    asm(r1: x, r2) {
+        add r2 r1 r1;
+        r2: u64
+    }
+}
+
+fn main() -> u64 {
+    let a = 1;
+    let b = double(a);
+    b
+}
@@ -26,3 +26,17 @@ pub fn g(amount: u64, to: Identity) {
 }
 
 fn fun<T>(t: T){}
+
+trait Describe {
+    fn describe(self) -> u32;
+}
+
+impl Describe for A {
+    fn describe(self) -> u32 {
+        self.b
+    }
+}
+
+fn h(s: A) -> u32 {
+    <A as Describe>::describe(s)
+}
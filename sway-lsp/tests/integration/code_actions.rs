@@ -875,7 +875,19 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
     );
     let call_path1 = "deep_mod::deeper_mod::A";
     let call_path2 = "test_mod::A";
+    // Only `test_mod::A` has the `fun` being called, so importing it is preferred.
+    let mut preferred_import = create_code_action(
+        uri.clone(),
+        format!("Import `{}`", call_path2),
+        create_changes_for_import(uri, 5, 23, "test_mod::{A, test_fun}", ""),
+        None,
+        Some(CodeActionKind::QUICKFIX),
+    );
+    if let CodeActionOrCommand::CodeAction(action) = &mut preferred_import {
+        action.is_preferred = Some(true);
+    }
     let expected = vec![
+        preferred_import,
         create_code_action(
             uri.clone(),
             format!("Import `{}`", call_path1),
@@ -883,13 +895,6 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
             None,
             Some(CodeActionKind::QUICKFIX),
         ),
-        create_code_action(
-            uri.clone(),
-            format!("Import `{}`", call_path2),
-            create_changes_for_import(uri, 5, 23, "test_mod::{A, test_fun}", ""),
-            None,
-            Some(CodeActionKind::QUICKFIX),
-        ),
         create_code_action(
             uri.clone(),
             format!("Qualify as `{}`", call_path1),
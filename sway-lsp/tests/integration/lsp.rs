@@ -235,6 +235,104 @@ pub(crate) async fn format_request(server: &ServerState, uri: &Url) {
     assert!(!response.unwrap().is_empty());
 }
 
+pub(crate) async fn range_format_request(server: &ServerState, uri: &Url) {
+    let params = DocumentRangeFormattingParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        range: Range::new(Position::new(13, 4), Position::new(13, 8)),
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let response = request::handle_range_formatting(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    // Only the storage declaration containing the range is formatted.
+    assert_eq!(response.len(), 1);
+    assert_eq!(
+        response[0].range,
+        Range::new(Position::new(12, 0), Position::new(14, 1))
+    );
+    assert!(response[0].new_text.contains("x: 0"));
+}
+
+pub(crate) async fn on_type_format_request(server: &ServerState, uri: &Url) {
+    let params = DocumentOnTypeFormattingParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position::new(13, 59),
+        },
+        ch: "}".to_string(),
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+    };
+    let response = request::handle_on_type_formatting(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    // Only the part of the storage declaration that changed is replaced.
+    assert_eq!(response.len(), 1);
+    assert_eq!(
+        response[0].range,
+        Range::new(Position::new(13, 28), Position::new(13, 54))
+    );
+    assert_eq!(response[0].new_text, " 0, y: false, z: Type2 { x: ");
+}
+
+pub(crate) async fn folding_range_request(server: &ServerState, uri: &Url) {
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let response = request::handle_folding_range(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    let lines = response
+        .iter()
+        .map(|range| (range.start_line, range.end_line))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![(2, 6), (8, 10), (12, 14), (16, 19), (21, 28), (23, 27)]
+    );
+}
+
+pub(crate) async fn selection_range_request(server: &ServerState, uri: &Url) {
+    let params = SelectionRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        positions: vec![Position {
+            line: 24,
+            character: 17,
+        }],
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let response = request::handle_selection_range(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut ranges = vec![];
+    let mut selection_range = response.first();
+    while let Some(range) = selection_range {
+        ranges.push(range.range);
+        selection_range = range.parent.as_deref();
+    }
+    // The innermost range is the `var1` field, the outermost is the impl block.
+    assert_eq!(ranges.first().unwrap().start, Position::new(24, 16));
+    assert_eq!(ranges.last().unwrap().start, Position::new(21, 0));
+    assert!(ranges
+        .windows(2)
+        .all(|pair| pair[1].start <= pair[0].start && pair[0].end <= pair[1].end));
+}
+
 pub(crate) async fn highlight_request(server: &ServerState, uri: &Url) {
     let params = DocumentHighlightParams {
         text_document_position_params: TextDocumentPositionParams {
@@ -281,6 +379,30 @@ pub(crate) async fn highlight_request(server: &ServerState, uri: &Url) {
     assert_eq!(expected, response.unwrap());
 }
 
+pub(crate) async fn linked_editing_range_request(server: &ServerState, uri: &Url) {
+    let params = LinkedEditingRangeParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 25,
+                character: 32,
+            },
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let response = request::handle_linked_editing_range(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    let expected = vec![
+        Range::new(Position::new(20, 8), Position::new(20, 17)),
+        Range::new(Position::new(25, 29), Position::new(25, 38)),
+        Range::new(Position::new(56, 46), Position::new(56, 55)),
+    ];
+    assert_eq!(response.ranges, expected);
+    assert!(response.word_pattern.is_some());
+}
+
 pub(crate) async fn code_lens_empty_request(server: &ServerState, uri: &Url) {
     let params = CodeLensParams {
         text_document: TextDocumentIdentifier { uri: uri.clone() },
@@ -361,6 +483,103 @@ pub(crate) async fn code_lens_request(server: &ServerState, uri: &Url) {
     assert_eq!(expected, response.unwrap());
 }
 
+pub(crate) async fn code_lens_storage_request(server: &ServerState, uri: &Url) {
+    let params = CodeLensParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let response = request::handle_code_lens(server, params).await.unwrap();
+    let location = |line, end| Location {
+        uri: uri.clone(),
+        range: Range {
+            start: Position { line, character: 8 },
+            end: Position {
+                line,
+                character: end,
+            },
+        },
+    };
+    let range = Range {
+        start: Position {
+            line: 23,
+            character: 7,
+        },
+        end: Position {
+            line: 23,
+            character: 22,
+        },
+    };
+    let expected = vec![CodeLens {
+        range,
+        command: Some(Command {
+            title: "Storage writes var1.x, var1.y, var1.z.x".to_string(),
+            command: "sway.peekLocations".to_string(),
+            arguments: Some(vec![json!({
+                "uri": uri,
+                "position": range.start,
+                "locations": [location(24, 32), location(25, 34), location(26, 36)],
+            })]),
+        }),
+        data: None,
+    }];
+    assert_eq!(expected, response.unwrap());
+}
+
+/// Requests the inlay hints of the whole file with only the gas hints, rendered at `gas_hints`,
+/// enabled. Returns the positions and labels of the hints, sorted by position.
+async fn gas_hints(server: &ServerState, uri: &Url, gas_hints: &str) -> Vec<(Position, String)> {
+    let params = InitializeParams {
+        initialization_options: Some(json!({
+            "inlayHints": {
+                "renderColons": true,
+                "typeHints": false,
+                "maxLength": 25,
+                "gasHints": gas_hints,
+            }
+        })),
+        ..Default::default()
+    };
+    let _ = request::handle_initialize(server, params);
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        range: Range::new(Position::new(0, 0), Position::new(u32::MAX, 0)),
+        work_done_progress_params: Default::default(),
+    };
+    let mut hints = request::handle_inlay_hints(server, params)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_iter()
+        .map(|hint| match hint.label {
+            InlayHintLabel::String(label) => (hint.position, label),
+            InlayHintLabel::LabelParts(_) => panic!("expected a plain label"),
+        })
+        .collect::<Vec<_>>();
+    hints.sort_by_key(|(position, _)| *position);
+    hints
+}
+
+pub(crate) async fn gas_statement_hints_request(server: &ServerState, uri: &Url) {
+    let hints = gas_hints(server, uri, "statements").await;
+    let expected = vec![
+        (Position::new(6, 5), "~2 gas".to_string()),
+        (Position::new(10, 14), "~2 gas".to_string()),
+        (Position::new(11, 22), "~14 gas".to_string()),
+        (Position::new(12, 5), "~1 gas".to_string()),
+    ];
+    assert_eq!(hints, expected);
+}
+
+pub(crate) async fn gas_function_hints_request(server: &ServerState, uri: &Url) {
+    let hints = gas_hints(server, uri, "functions").await;
+    let expected = vec![
+        (Position::new(2, 9), "~2 gas".to_string()),
+        (Position::new(9, 7), "~17 gas".to_string()),
+    ];
+    assert_eq!(hints, expected);
+}
+
 pub(crate) async fn completion_request(server: &ServerState, uri: &Url) {
     let params = CompletionParams {
         text_document_position: TextDocumentPositionParams {
@@ -96,6 +96,84 @@ async fn did_change() {
     shutdown_and_exit(&mut service).await;
 }
 
+#[test]
+fn parse_diagnostics() {
+    let source_engine = sway_types::SourceEngine::default();
+    let path = e2e_test_dir().join("src/main.sw");
+    let diagnostics = sway_lsp::capabilities::diagnostic::get_parse_diagnostics(
+        &path,
+        "script;\n\nfn main() {\n    let x = 1\n}\n".into(),
+        &source_engine,
+    );
+    assert!(!diagnostics.errors.is_empty());
+    let diagnostics = sway_lsp::capabilities::diagnostic::get_parse_diagnostics(
+        &path,
+        "script;\n\nfn main() {\n    let x = 1;\n}\n".into(),
+        &source_engine,
+    );
+    assert!(diagnostics.errors.is_empty());
+}
+
+#[test]
+fn reparse_diagnostics() {
+    use sway_lsp::capabilities::diagnostic::{get_parse_diagnostics, get_reparse_diagnostics};
+    use sway_parse::SourceEdit;
+
+    let source_engine = sway_types::SourceEngine::default();
+    let path = e2e_test_dir().join("src/main.sw");
+    let src = "script;\n\nfn main() {\n    let x = 1;\n}\n\nfn foo() -> u64 {\n    2\n}\n";
+    let (diagnostics, parsed) =
+        get_reparse_diagnostics(&path, src.into(), None, &[], &source_engine);
+    assert!(diagnostics.errors.is_empty());
+    assert!(parsed.is_some());
+
+    // Removing the semicolon of the `let` statement, then changing the body of `foo`, which the
+    // first edit moved back by a byte.
+    let semicolon = src.find("1;").unwrap() + 1;
+    let two = src.rfind('2').unwrap() - 1;
+    let edits = [
+        SourceEdit {
+            range: semicolon..semicolon + 1,
+            new_text: String::new(),
+        },
+        SourceEdit {
+            range: two..two + 1,
+            new_text: "3".to_string(),
+        },
+    ];
+    let edited = edits
+        .iter()
+        .fold(src.to_string(), |src, edit| edit.apply(&src));
+    let (diagnostics, parsed) = get_reparse_diagnostics(
+        &path,
+        edited.as_str().into(),
+        parsed,
+        &edits,
+        &source_engine,
+    );
+    assert!(!diagnostics.errors.is_empty());
+    assert_eq!(
+        diagnostics.errors,
+        get_parse_diagnostics(&path, edited.as_str().into(), &source_engine).errors
+    );
+
+    // Restoring the semicolon.
+    let edit = SourceEdit {
+        range: semicolon..semicolon,
+        new_text: ";".to_string(),
+    };
+    let restored = edit.apply(&edited);
+    let (diagnostics, parsed) = get_reparse_diagnostics(
+        &path,
+        restored.as_str().into(),
+        parsed,
+        &[edit],
+        &source_engine,
+    );
+    assert!(diagnostics.errors.is_empty());
+    assert_eq!(&*parsed.unwrap().src, restored);
+}
+
 #[tokio::test]
 async fn did_cache_test() {
     let (mut service, _) = LspService::build(ServerState::new)
@@ -1415,6 +1493,29 @@ async fn hover_docs_for_functions() {
     let _ = server.shutdown_server().await;
 }
 
+#[tokio::test]
+async fn hover_docs_for_method_calls() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("tokens/turbofish/src/main.sw"),
+    )
+    .await;
+
+    let mut hover = HoverDocumentation {
+        req_uri: &uri,
+        req_line: 16,
+        req_char: 8,
+        documentation: vec!["```sway\nfn A::method<Option<u32>>(self, a: Option<u32>)\n```"],
+    };
+    lsp::hover_request(&server, &hover).await;
+    hover.req_line = 40;
+    hover.req_char = 24;
+    hover.documentation = vec!["```sway\nfn <A as Describe>::describe(self) -> u32\n```"];
+    lsp::hover_request(&server, &hover).await;
+    let _ = server.shutdown_server().await;
+}
+
 #[tokio::test]
 async fn hover_docs_for_structs() {
     let server = ServerState::default();
@@ -1479,6 +1580,38 @@ async fn hover_docs_for_enums() {
     let _ = server.shutdown_server().await;
 }
 
+#[tokio::test]
+async fn hover_layout_for_structs_and_enums() {
+    let server = ServerState::default();
+    let uri = open(
+        &server,
+        test_fixtures_dir().join("tokens/enums/src/main.sw"),
+    )
+    .await;
+
+    let mut hover = HoverDocumentation {
+        req_uri: &uri,
+        req_line: 3,
+        req_char: 9,
+        documentation: vec!["Size: 8 bytes, 1 storage slot", "- `foo`: offset 0"],
+    };
+    lsp::hover_request(&server, &hover).await;
+    hover.req_line = 8;
+    hover.req_char = 7;
+    hover.documentation = vec!["Size: 8 bytes, 1 storage slot"];
+    lsp::hover_request(&server, &hover).await;
+    hover.req_line = 15;
+    hover.req_char = 11;
+    hover.documentation = vec![
+        "Size: 24 bytes, 1 storage slot",
+        "- `First`: offset 16",
+        "- `Third`: offset 8",
+        "- `Fourth`: offset 23",
+    ];
+    lsp::hover_request(&server, &hover).await;
+    let _ = server.shutdown_server().await;
+}
+
 #[tokio::test]
 async fn hover_docs_for_abis() {
     let server = ServerState::default();
@@ -1755,16 +1888,41 @@ lsp_capability_test!(
     lsp::document_symbol_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    folding_range,
+    lsp::folding_range_request,
+    test_fixtures_dir().join("tokens/storage/src/main.sw")
+);
+lsp_capability_test!(
+    selection_range,
+    lsp::selection_range_request,
+    test_fixtures_dir().join("tokens/storage/src/main.sw")
+);
 lsp_capability_test!(
     format,
     lsp::format_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    range_format,
+    lsp::range_format_request,
+    test_fixtures_dir().join("tokens/storage/src/main.sw")
+);
+lsp_capability_test!(
+    on_type_format,
+    lsp::on_type_format_request,
+    test_fixtures_dir().join("tokens/storage/src/main.sw")
+);
 lsp_capability_test!(
     highlight,
     lsp::highlight_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    linked_editing_range,
+    lsp::linked_editing_range_request,
+    test_fixtures_dir().join("tokens/variables/src/main.sw")
+);
 lsp_capability_test!(
     code_action_abi,
     code_actions::code_action_abi_request,
@@ -1835,6 +1993,21 @@ lsp_capability_test!(
     lsp::code_lens_empty_request,
     runnables_test_dir().join("src/other.sw")
 );
+lsp_capability_test!(
+    code_lens_storage,
+    lsp::code_lens_storage_request,
+    test_fixtures_dir().join("tokens/storage/src/main.sw")
+);
+lsp_capability_test!(
+    gas_statement_hints,
+    lsp::gas_statement_hints_request,
+    test_fixtures_dir().join("gas_hints/src/main.sw")
+);
+lsp_capability_test!(
+    gas_function_hints,
+    lsp::gas_function_hints_request,
+    test_fixtures_dir().join("gas_hints/src/main.sw")
+);
 lsp_capability_test!(
     completion,
     lsp::completion_request,
@@ -3,14 +3,16 @@ use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed
 
 use sway_ast::attribute::{Annotated, Attribute, AttributeArg, AttributeDecl, AttributeHashKind};
 use sway_ast::brackets::{Parens, SquareBrackets};
-use sway_ast::keywords::{EqToken, HashBangToken, HashToken, StorageToken, Token};
+use sway_ast::keywords::{
+    DoubleColonToken, EqToken, HashBangToken, HashToken, StorageToken, Token,
+};
 use sway_ast::literal::LitBool;
 use sway_ast::punctuated::Punctuated;
 use sway_ast::token::{DocComment, DocStyle};
 use sway_ast::Literal;
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
-use sway_types::{Ident, Spanned};
+use sway_types::{Ident, Span, Spanned};
 
 impl Peek for DocComment {
     fn peek(peeker: Peeker<'_>) -> Option<DocComment> {
@@ -154,7 +156,18 @@ impl Parse for Attribute {
         let name = if let Some(storage) = parser.take::<StorageToken>() {
             Ident::from(storage)
         } else {
-            parser.parse()?
+            let name: Ident = parser.parse()?;
+            // Attributes of external tools are prefixed with the tool's name, e.g. `swayfmt::skip`.
+            match parser.take::<DoubleColonToken>() {
+                Some(_) => {
+                    let tool_attribute: Ident = parser.parse()?;
+                    Ident::new_with_override(
+                        format!("{}::{}", name.as_str(), tool_attribute.as_str()),
+                        Span::join(name.span(), tool_attribute.span()),
+                    )
+                }
+                None => name,
+            }
         };
         let args = Parens::try_parse(parser)?;
         Ok(Attribute { name, args })
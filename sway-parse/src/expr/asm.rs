@@ -1,12 +1,16 @@
+use crate::brackets::ParseBracket;
 use crate::expr::op_code::parse_instruction;
 use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use core::str::FromStr;
 use num_bigint::BigUint;
 
+use sway_ast::brackets::Parens;
 use sway_ast::expr::asm::{
-    AsmBlock, AsmBlockContents, AsmFinalExpr, AsmImmediate, AsmRegisterDeclaration,
+    AsmBlock, AsmBlockContents, AsmClobbers, AsmFinalExpr, AsmFinalRegisters, AsmImmediate,
+    AsmRegisterDeclaration,
 };
+use sway_ast::keywords::ColonToken;
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{Ident, Spanned};
 
@@ -14,10 +18,23 @@ impl Parse for AsmBlock {
     fn parse(parser: &mut Parser) -> ParseResult<AsmBlock> {
         let asm_token = parser.parse()?;
         let registers = parser.parse()?;
+        // `clobbers` is not a keyword, so that it can still be used as a register name.
+        let clobbers_opt = match parser.peek::<Ident>() {
+            Some(ident) if ident.as_str() == "clobbers" => {
+                let clobbers_token = parser.parse()?;
+                let registers = parser.parse()?;
+                Some(AsmClobbers {
+                    clobbers_token,
+                    registers,
+                })
+            }
+            _ => None,
+        };
         let contents = parser.parse()?;
         Ok(AsmBlock {
             asm_token,
             registers,
+            clobbers_opt,
             contents,
         })
     }
@@ -25,15 +42,20 @@ impl Parse for AsmBlock {
 
 impl Parse for AsmRegisterDeclaration {
     fn parse(parser: &mut Parser) -> ParseResult<AsmRegisterDeclaration> {
+        let const_token_opt = parser.take();
         let register = parser.parse()?;
         let value_opt = match parser.take() {
             Some(colon_token) => {
                 let value = parser.parse()?;
                 Some((colon_token, value))
             }
+            None if const_token_opt.is_some() => {
+                return Err(parser.emit_error(ParseErrorKind::ExpectedAsmConstOperandValue));
+            }
             None => None,
         };
         Ok(AsmRegisterDeclaration {
+            const_token_opt,
             register,
             value_opt,
         })
@@ -50,6 +72,12 @@ impl ParseToEnd for AsmBlockContents {
                 break (None, consumed);
             }
 
+            // A tuple of registers can only be the final expression.
+            if let Some(registers) = Parens::try_parse(&mut parser)? {
+                let registers = AsmFinalRegisters::Tuple(registers);
+                break parse_asm_final_expr(&mut parser, registers)?;
+            }
+
             // Parse the opcode directly instead of calling `parser.parse()` to avoid checking for
             // illegal identifiers such as keywords. opcode names should not be subject to those
             // checks because some opcodes, such as `mod`, are also Sway keywords.
@@ -58,28 +86,9 @@ impl ParseToEnd for AsmBlockContents {
                 None => return Err(parser.emit_error(ParseErrorKind::ExpectedIdent)),
             };
 
-            if let Some(consumed) = parser.check_empty() {
-                let final_expr = AsmFinalExpr {
-                    register: ident,
-                    ty_opt: None,
-                };
-                break (Some(final_expr), consumed);
-            }
-            if let Some(colon_token) = parser.take() {
-                let ty = parser.parse()?;
-                let consumed = match parser.check_empty() {
-                    Some(consumed) => consumed,
-                    None => {
-                        return Err(
-                            parser.emit_error(ParseErrorKind::UnexpectedTokenAfterAsmReturnType)
-                        );
-                    }
-                };
-                let final_expr = AsmFinalExpr {
-                    register: ident,
-                    ty_opt: Some((colon_token, ty)),
-                };
-                break (Some(final_expr), consumed);
+            if parser.check_empty().is_some() || parser.peek::<ColonToken>().is_some() {
+                let registers = AsmFinalRegisters::Register(ident);
+                break parse_asm_final_expr(&mut parser, registers)?;
             }
             let instruction = parse_instruction(ident, &mut parser)?;
             let semicolon_token = parser.parse()?;
@@ -93,16 +102,35 @@ impl ParseToEnd for AsmBlockContents {
     }
 }
 
+/// Parses the optional type of the final expression of an asm block, which must be followed by
+/// the end of the block.
+fn parse_asm_final_expr<'a>(
+    parser: &mut Parser<'a, '_>,
+    registers: AsmFinalRegisters,
+) -> ParseResult<(Option<AsmFinalExpr>, ParserConsumed<'a>)> {
+    let ty_opt = match parser.take() {
+        Some(colon_token) => Some((colon_token, parser.parse()?)),
+        None => None,
+    };
+    match parser.check_empty() {
+        Some(consumed) => Ok((Some(AsmFinalExpr { registers, ty_opt }), consumed)),
+        None => Err(parser.emit_error(ParseErrorKind::UnexpectedTokenAfterAsmReturnType)),
+    }
+}
+
 impl Parse for AsmImmediate {
     fn parse(parser: &mut Parser) -> ParseResult<AsmImmediate> {
         let ident = parser.parse::<Ident>()?;
-        let digits = ident
-            .as_str()
-            .strip_prefix('i')
-            .ok_or_else(|| parser.emit_error(ParseErrorKind::MalformedAsmImmediate))?;
-        let parsed = BigUint::from_str(digits)
-            .ok()
-            .ok_or_else(|| parser.emit_error(ParseErrorKind::MalformedAsmImmediate))?;
+        // Immediates are written as `i` followed by their value. Any other name refers to a
+        // `const` operand of the asm block.
+        let parsed = match ident.as_str().strip_prefix('i') {
+            Some(digits) if digits.starts_with(|c: char| c.is_ascii_digit()) => Some(
+                BigUint::from_str(digits)
+                    .ok()
+                    .ok_or_else(|| parser.emit_error(ParseErrorKind::MalformedAsmImmediate))?,
+            ),
+            _ => None,
+        };
         Ok(AsmImmediate {
             span: ident.span(),
             parsed,
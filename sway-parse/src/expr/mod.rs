@@ -68,20 +68,44 @@ impl Parse for IfExpr {
 
 impl Parse for IfCondition {
     fn parse(parser: &mut Parser) -> ParseResult<IfCondition> {
-        if let Some(let_token) = parser.take() {
-            let lhs = parser.parse()?;
-            let eq_token = parser.parse()?;
-            let rhs = Box::new(parse_condition(parser)?);
-            Ok(IfCondition::Let {
-                let_token,
-                lhs,
-                eq_token,
-                rhs,
-            })
-        } else {
+        if parser.peek::<LetToken>().is_none() {
             let expr = Box::new(parse_condition(parser)?);
-            Ok(IfCondition::Expr(expr))
+            return Ok(IfCondition::Expr(expr));
+        }
+
+        // A `let` condition, possibly followed by a chain of conditions. The operands of the
+        // chain are parsed above `&&`, so that `&&` continues the chain.
+        let mut condition = parse_let_chain_operand(parser)?;
+        while let Some(double_ampersand_token) = parser.take() {
+            let rhs = Box::new(parse_let_chain_operand(parser)?);
+            condition = IfCondition::And {
+                lhs: Box::new(condition),
+                double_ampersand_token,
+                rhs,
+            };
         }
+        Ok(condition)
+    }
+}
+
+fn parse_let_chain_operand(parser: &mut Parser) -> ParseResult<IfCondition> {
+    let ctx = ParseExprCtx {
+        parsing_conditional: true,
+        at_start_of_statement: false,
+    };
+    if let Some(let_token) = parser.take() {
+        let lhs = parser.parse()?;
+        let eq_token = parser.parse()?;
+        let rhs = Box::new(parse_comparison(parser, ctx)?);
+        Ok(IfCondition::Let {
+            let_token,
+            lhs,
+            eq_token,
+            rhs,
+        })
+    } else {
+        let expr = Box::new(parse_comparison(parser, ctx)?);
+        Ok(IfCondition::Expr(expr))
     }
 }
 
@@ -113,6 +137,11 @@ impl Parse for StatementLet {
         let eq_token: EqToken = parser.try_parse(true)?;
         let expr = parser.try_parse(true)?;
 
+        let else_opt = match parser.take() {
+            Some(else_token) => Some((else_token, parser.parse()?)),
+            None => None,
+        };
+
         // Recover on missing semicolon.
         let semicolon_token = parser.try_parse(true)?;
 
@@ -122,6 +151,7 @@ impl Parse for StatementLet {
             ty_opt,
             eq_token,
             expr,
+            else_opt,
             semicolon_token,
         })
     }
@@ -525,10 +555,21 @@ fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
 fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     let mut expr = parse_func_app(parser, ctx)?;
     loop {
-        if let Some(arg) = SquareBrackets::try_parse_all_inner(parser, |mut parser| {
-            parser.emit_error(ParseErrorKind::UnexpectedTokenAfterArrayIndex)
-        })? {
+        if let Some((mut inner_parser, span)) = parser.enter_delimited(Delimiter::Bracket) {
+            let mut arg: Box<Expr> = inner_parser.parse()?;
+            if let Some(dot_dot_token) = inner_parser.take() {
+                let end = inner_parser.parse()?;
+                arg = Box::new(Expr::Range {
+                    start: arg,
+                    dot_dot_token,
+                    end,
+                });
+            }
+            if !inner_parser.is_empty() {
+                return Err(inner_parser.emit_error(ParseErrorKind::UnexpectedTokenAfterArrayIndex));
+            }
             let target = Box::new(expr);
+            let arg = SquareBrackets::new(arg, span);
             expr = Expr::Index { target, arg };
             continue;
         }
@@ -712,7 +753,7 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         });
     }
     if let Some(while_token) = parser.take() {
-        let condition = Box::new(parse_condition(parser)?);
+        let condition = parser.parse()?;
         let block = parser.parse()?;
         return Ok(Expr::While {
             while_token,
@@ -809,10 +850,15 @@ impl ParseToEnd for ExprArrayDescriptor {
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
         let pattern = parser.parse()?;
+        let guard_opt = match parser.take() {
+            Some(if_token) => Some((if_token, Box::new(parser.parse()?))),
+            None => None,
+        };
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
             pattern,
+            guard_opt,
             fat_right_arrow_token,
             kind,
         })
@@ -832,3 +878,109 @@ impl Parse for MatchBranchKind {
         Ok(MatchBranchKind::Expr { expr, comma_token })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse;
+    use assert_matches::*;
+    use sway_ast::Pattern;
+
+    #[test]
+    fn parse_if_let_chain() {
+        let expr = parse::<Expr>(
+            r#"
+            if let Some(x) = a && x > 0 && let Ok(y) = b { }
+            "#,
+        );
+        assert_matches!(
+            expr,
+            Expr::If(IfExpr {
+                condition: IfCondition::And { lhs, rhs, .. },
+                ..
+            }) if matches!(*rhs, IfCondition::Let { .. })
+                && matches!(*lhs, IfCondition::And { .. })
+        );
+    }
+
+    #[test]
+    fn parse_if_let_with_lazy_operator_in_expression_condition() {
+        let expr = parse::<Expr>(
+            r#"
+            if a && b { }
+            "#,
+        );
+        assert_matches!(
+            expr,
+            Expr::If(IfExpr {
+                condition: IfCondition::Expr(condition),
+                ..
+            }) if matches!(*condition, Expr::LogicalAnd { .. })
+        );
+    }
+
+    #[test]
+    fn parse_while_let() {
+        let expr = parse::<Expr>(
+            r#"
+            while let Some(x) = iter.next() { }
+            "#,
+        );
+        assert_matches!(
+            expr,
+            Expr::While {
+                condition: IfCondition::Let { .. },
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn parse_match_branch_guard() {
+        let branch = parse::<MatchBranch>(
+            r#"
+            Some(x) if x > 3 => x,
+            "#,
+        );
+        assert_matches!(
+            branch,
+            MatchBranch {
+                guard_opt: Some((_, guard)),
+                ..
+            } if matches!(*guard, Expr::GreaterThan { .. })
+        );
+    }
+
+    #[test]
+    fn parse_let_else() {
+        let statement = parse::<StatementLet>(
+            r#"
+            let Some(x) = a else { return; };
+            "#,
+        );
+        assert_matches!(
+            statement,
+            StatementLet {
+                pattern: Pattern::Constructor { .. },
+                else_opt: Some(_),
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn parse_index_range() {
+        let expr = parse::<Expr>(
+            r#"
+            a[1..b.len]
+            "#,
+        );
+        assert_matches!(
+            expr,
+            Expr::Index { arg, .. } if matches!(
+                *arg.into_inner(),
+                Expr::Range { ref end, .. } if matches!(**end, Expr::FieldProjection { .. })
+            )
+        );
+    }
+}
@@ -113,6 +113,7 @@ define_op_codes!(
     (Flag, FlagOpcode, "flag", (value)),
     (Gm, GmOpcode, "gm", (ret, op)),
     (Gtf, GtfOpcode, "gtf", (ret, index, tx_field_id)),
+    (Ecal, EcalOpcode, "ecal", (reg_a, reg_b, reg_c, reg_d)),
     /* Non-VM Instructions */
     (Blob, BlobOpcode, "blob", (size)),
 );
@@ -0,0 +1,635 @@
+//! Parsing a source file again after an edit, reusing the token trees of its previous version.
+//!
+//! Only the token trees touched by the edit are lexed again, within the innermost delimited group
+//! containing the edit, so that editing the body of a function only re-lexes the statements
+//! around the edit. The token trees away from the edit are reused, with their spans moved to the
+//! edited source. The module is then parsed again from the spliced token trees.
+//!
+//! The items of the module are parsed again the same way: only the items touched by the edit,
+//! along with the items next to them, are parsed again from the spliced token trees. The other
+//! items are reused, with their spans moved to the edited source.
+//!
+//! Whenever the edit may change how the code away from it is lexed, like when it opens a string
+//! or a block comment that is not closed within the re-lexed region, the whole file is lexed
+//! again, so the result is always the same as lexing the edited source from scratch. Likewise,
+//! the whole module is parsed again when the edit touches its header, or when either version of
+//! the file has parse errors, whose recovery may depend on the code away from the edit.
+
+use crate::{token::lex_commented, ExperimentalFlags, Parser};
+use std::{ops::Range, sync::Arc};
+use sway_ast::{
+    attribute::Annotated,
+    relocate::{Relocate, Relocator},
+    token::{CommentedGroup, CommentedTokenStream, CommentedTokenTree, GenericTokenTree},
+    Item, Module,
+};
+use sway_error::handler::{ErrorEmitted, Handler};
+use sway_types::{SourceId, Span, Spanned};
+
+/// A lexed and parsed source file, kept to parse it again incrementally after an edit.
+#[derive(Debug, Clone)]
+pub struct ParsedFile {
+    pub src: Arc<str>,
+    /// The token trees of the file, including its comments.
+    pub token_stream: CommentedTokenStream,
+    pub module: Annotated<Module>,
+    /// Whether lexing the file reported errors, in which case its token trees are not reused,
+    /// as the recovery from the errors may depend on the code away from the edit.
+    has_lex_errors: bool,
+    /// Whether parsing the file reported errors, in which case its items are not reused.
+    has_parse_errors: bool,
+}
+
+/// An edit replacing a range of a source file with new text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceEdit {
+    /// The byte range of the replaced text in the source before the edit.
+    pub range: Range<usize>,
+    pub new_text: String,
+}
+
+impl SourceEdit {
+    /// Returns the source resulting from applying the edit to `src`.
+    ///
+    /// Panics if the range of the edit is not within `src`, or does not start and end at
+    /// character boundaries.
+    pub fn apply(&self, src: &str) -> String {
+        let mut edited = String::with_capacity(src.len() + self.new_text.len());
+        edited.push_str(&src[..self.range.start]);
+        edited.push_str(&self.new_text);
+        edited.push_str(&src[self.range.end..]);
+        edited
+    }
+
+    /// The difference in length between the new text and the replaced text.
+    fn delta(&self) -> isize {
+        self.new_text.len() as isize - self.range.len() as isize
+    }
+}
+
+/// Lexes and parses the source file, keeping its token trees to parse it again incrementally
+/// with [reparse_file].
+pub fn parse_file_incremental(
+    handler: &Handler,
+    src: Arc<str>,
+    source_id: Option<SourceId>,
+    experimental: ExperimentalFlags,
+) -> Result<ParsedFile, ErrorEmitted> {
+    let lex_handler = Handler::default();
+    let token_stream = lex_commented(&lex_handler, &src, 0, src.len(), &source_id);
+    let has_lex_errors = lex_handler.has_errors();
+    handler.append(lex_handler);
+    parse_token_stream(handler, src, token_stream?, has_lex_errors, experimental)
+}
+
+/// Parses the source of the `previous` file after the `edit`, lexing and parsing only the code
+/// touched by the edit again.
+///
+/// Panics if the range of the edit is not within the previous source, or does not start and end
+/// at character boundaries.
+pub fn reparse_file(
+    handler: &Handler,
+    previous: &ParsedFile,
+    edit: &SourceEdit,
+    source_id: Option<SourceId>,
+    experimental: ExperimentalFlags,
+) -> Result<ParsedFile, ErrorEmitted> {
+    let src: Arc<str> = Arc::from(edit.apply(&previous.src));
+    // Inner doc comments are only lexed as such at the start of the file.
+    if previous.has_lex_errors || edit.new_text.contains("//!") {
+        return parse_file_incremental(handler, src, source_id, experimental);
+    }
+    let splicer = Splicer {
+        previous_src: &previous.src,
+        src: &src,
+        source_id: &source_id,
+        edit,
+    };
+    let Some(token_trees) = splicer.splice(&previous.token_stream.token_trees) else {
+        return parse_file_incremental(handler, src, source_id, experimental);
+    };
+    let full_span =
+        Span::new(src.clone(), 0, src.len(), source_id).expect("the source contains its full span");
+    let token_stream = CommentedTokenStream {
+        token_trees,
+        full_span,
+    };
+    if previous.has_parse_errors {
+        return parse_token_stream(handler, src, token_stream, false, experimental);
+    }
+    let module = ItemReparser {
+        previous,
+        src: &src,
+        source_id: &source_id,
+        edit,
+    }
+    .reparse(handler, &token_stream, experimental);
+    match module {
+        Some(module) => Ok(ParsedFile {
+            src,
+            token_stream,
+            module,
+            has_lex_errors: false,
+            has_parse_errors: false,
+        }),
+        None => parse_token_stream(handler, src, token_stream, false, experimental),
+    }
+}
+
+fn parse_token_stream(
+    handler: &Handler,
+    src: Arc<str>,
+    token_stream: CommentedTokenStream,
+    has_lex_errors: bool,
+    experimental: ExperimentalFlags,
+) -> Result<ParsedFile, ErrorEmitted> {
+    let ts = token_stream.clone().strip_comments();
+    let parse_handler = Handler::default();
+    let parsed = Parser::new(&parse_handler, &ts)
+        .with_experimental(experimental)
+        .parse_to_end();
+    let has_parse_errors = parse_handler.has_errors();
+    handler.append(parse_handler);
+    let (module, _) = parsed?;
+    Ok(ParsedFile {
+        src,
+        token_stream,
+        module,
+        has_lex_errors,
+        has_parse_errors,
+    })
+}
+
+/// Returns a copy of the tree parsed from the previous source with its spans moved by the
+/// `relocator`.
+fn relocated<T: Relocate + Clone>(tree: &T, relocator: &Relocator) -> T {
+    let mut tree = tree.clone();
+    tree.relocate(relocator);
+    tree
+}
+
+/// The byte range of an item in its source, including its attributes.
+fn item_range(item: &Item) -> Range<usize> {
+    let span = item.value.span();
+    let start = item
+        .attribute_list
+        .first()
+        .map_or(span.start(), |attribute| attribute.span().start());
+    start..span.end()
+}
+
+/// Parses again the items of a module touched by an edit, reusing its other items.
+struct ItemReparser<'a> {
+    previous: &'a ParsedFile,
+    /// The edited source.
+    src: &'a Arc<str>,
+    source_id: &'a Option<SourceId>,
+    edit: &'a SourceEdit,
+}
+
+impl ItemReparser<'_> {
+    /// Returns the module of the edited source, given its token trees, or `None` if the edit
+    /// may change how the code away from it is parsed.
+    fn reparse(
+        &self,
+        handler: &Handler,
+        token_stream: &CommentedTokenStream,
+        experimental: ExperimentalFlags,
+    ) -> Option<Annotated<Module>> {
+        let Range { start: a, end: b } = self.edit.range;
+        let delta = self.edit.delta();
+        let module = &self.previous.module;
+        // The inner doc comments and the kind of the module are only parsed at its start.
+        let header_end = module.value.semicolon_token.span().end();
+        if a <= header_end {
+            return None;
+        }
+
+        // The items next to the touched ones are parsed again too, as the parse of an item may
+        // look at the code following it, and an edit between items may add attributes to the
+        // item after it.
+        let items = &module.value.items;
+        let first = items
+            .partition_point(|item| item_range(item).end < a)
+            .saturating_sub(1);
+        let last = (items.partition_point(|item| item_range(item).start <= b) + 1).min(items.len());
+        let start = match first {
+            0 => header_end,
+            _ => item_range(&items[first]).start,
+        };
+        let end = match items.get(last) {
+            Some(item) => (item_range(item).start as isize + delta) as usize,
+            None => self.src.len(),
+        };
+
+        let mut token_trees = vec![];
+        for tree in &token_stream.token_trees {
+            let span = tree.span();
+            if span.end() <= start || end <= span.start() {
+                continue;
+            }
+            if span.start() < start || end < span.end() {
+                return None;
+            }
+            token_trees.push(tree.clone());
+        }
+        let ts = CommentedTokenStream {
+            token_trees,
+            full_span: Span::new(self.src.clone(), start, end, *self.source_id)?,
+        }
+        .strip_comments();
+        let parse_handler = Handler::default();
+        let (reparsed_items, _) = Parser::new(&parse_handler, &ts)
+            .with_experimental(experimental)
+            .parse_to_end::<Vec<Item>>()
+            .ok()?;
+        if parse_handler.has_errors() {
+            return None;
+        }
+        handler.append(parse_handler);
+
+        let before = Relocator {
+            from: self.previous.src.clone(),
+            to: self.src.clone(),
+            delta: 0,
+        };
+        let after = Relocator {
+            delta,
+            ..before.clone()
+        };
+        Some(Annotated {
+            attribute_list: relocated(&module.attribute_list, &before),
+            value: Module {
+                kind: relocated(&module.value.kind, &before),
+                semicolon_token: relocated(&module.value.semicolon_token, &before),
+                items: items[..first]
+                    .iter()
+                    .map(|item| relocated(item, &before))
+                    .chain(reparsed_items)
+                    .chain(items[last..].iter().map(|item| relocated(item, &after)))
+                    .collect(),
+            },
+        })
+    }
+}
+
+/// Splices the token trees of an edited source into the token trees of its previous version.
+struct Splicer<'a> {
+    previous_src: &'a Arc<str>,
+    /// The edited source.
+    src: &'a Arc<str>,
+    source_id: &'a Option<SourceId>,
+    edit: &'a SourceEdit,
+}
+
+impl Splicer<'_> {
+    /// Returns the token trees of the edited source at the level of `trees`, or `None` if the
+    /// code touched by the edit cannot be lexed on its own.
+    fn splice(&self, trees: &[CommentedTokenTree]) -> Option<Vec<CommentedTokenTree>> {
+        let Range { start: a, end: b } = self.edit.range;
+        let delta = self.edit.delta();
+        // The trees ending or starting at the edit are touched by it, as an insertion next to an
+        // identifier extends it.
+        let touched = trees
+            .iter()
+            .enumerate()
+            .filter(|(_, tree)| tree.span().start() <= b && a <= tree.span().end())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        // An edit within the delimiters of a single group only changes the trees within it.
+        if let [index] = touched[..] {
+            if let CommentedTokenTree::Tree(GenericTokenTree::Group(group)) = &trees[index] {
+                let inner_span = &group.token_stream.full_span;
+                if inner_span.start() <= a && b <= inner_span.end() {
+                    let token_trees = self.splice(&group.token_stream.token_trees)?;
+                    let group = CommentedGroup {
+                        delimiter: group.delimiter,
+                        token_stream: CommentedTokenStream {
+                            token_trees,
+                            full_span: self.relocate_span_end(inner_span, delta),
+                        },
+                        span: self.relocate_span_end(&group.span, delta),
+                    };
+                    return Some(
+                        trees[..index]
+                            .iter()
+                            .map(|tree| self.relocate(tree, 0))
+                            .chain(std::iter::once(CommentedTokenTree::Tree(group.into())))
+                            .chain(
+                                trees[index + 1..]
+                                    .iter()
+                                    .map(|tree| self.relocate(tree, delta)),
+                            )
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        // Otherwise the touched trees are lexed again, along with the trees adjacent to them,
+        // so that the lexed region is surrounded by whitespace or delimiters and the spacing of
+        // its punctuation does not depend on the code around it.
+        let (mut first, mut last) = match (touched.first(), touched.last()) {
+            (Some(first), Some(last)) => (*first, *last + 1),
+            _ => {
+                let first = trees.partition_point(|tree| tree.span().end() < a);
+                (first, first)
+            }
+        };
+        let mut start = trees[first..last]
+            .first()
+            .map_or(a, |tree| tree.span().start().min(a));
+        let mut end = trees[first..last]
+            .last()
+            .map_or(b, |tree| tree.span().end().max(b));
+        while first > 0 && trees[first - 1].span().end() == start {
+            first -= 1;
+            start = trees[first].span().start();
+        }
+        // Whether a comment is trailing depends on the code before it.
+        while last < trees.len()
+            && (trees[last].span().start() == end
+                || matches!(trees[last], CommentedTokenTree::Comment(_)))
+        {
+            end = trees[last].span().end();
+            last += 1;
+        }
+
+        let lex_handler = Handler::default();
+        let end_after_edit = (end as isize + delta) as usize;
+        let lexed = lex_commented(
+            &lex_handler,
+            self.src,
+            start,
+            end_after_edit,
+            self.source_id,
+        )
+        .ok()?;
+        if lex_handler.has_errors() {
+            return None;
+        }
+        // A line comment ending the region may continue past it after the edit removed a newline.
+        if let Some(tree) = lexed.token_trees.last() {
+            let tree_span = tree.span();
+            if tree_span.end() == end_after_edit
+                && tree_span.as_str().starts_with("//")
+                && !self.src[end_after_edit..].is_empty()
+                && !self.src[end_after_edit..].starts_with('\n')
+            {
+                return None;
+            }
+        }
+        Some(
+            trees[..first]
+                .iter()
+                .map(|tree| self.relocate(tree, 0))
+                .chain(lexed.token_trees)
+                .chain(trees[last..].iter().map(|tree| self.relocate(tree, delta)))
+                .collect(),
+        )
+    }
+
+    /// Moves the span of a tree of the previous source by `delta` bytes into the edited source.
+    fn relocate(&self, tree: &CommentedTokenTree, delta: isize) -> CommentedTokenTree {
+        let relocator = Relocator {
+            from: self.previous_src.clone(),
+            to: self.src.clone(),
+            delta,
+        };
+        relocated(tree, &relocator)
+    }
+
+    /// Moves the end of a span enclosing the edit by `delta` bytes into the edited source.
+    fn relocate_span_end(&self, span: &Span, delta: isize) -> Span {
+        let end = (span.end() as isize + delta) as usize;
+        Span::new(
+            self.src.clone(),
+            span.start(),
+            end,
+            span.source_id().cloned(),
+        )
+        .expect("the relocated span is within the edited source")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the debug representation of a parse without the addresses of the sources its
+    /// spans point into, after checking that they all point into `src`.
+    fn debug_without_src(value: &impl std::fmt::Debug, src: &Arc<str>) -> String {
+        const SRC_FIELD: &str = "src (ptr): ";
+        let src_ptr = format!("{:?}", src.as_ptr());
+        let dummy_ptr = format!("{:?}", Span::dummy().src().as_ptr());
+        let debug = format!("{value:?}");
+        let mut rest = debug.as_str();
+        let mut stripped = String::new();
+        while let Some(index) = rest.find(SRC_FIELD) {
+            stripped.push_str(&rest[..index]);
+            rest = &rest[index + SRC_FIELD.len()..];
+            let ptr_end = rest.find(',').unwrap();
+            let ptr = &rest[..ptr_end];
+            assert!(
+                ptr == src_ptr || ptr == dummy_ptr,
+                "a span points into another source"
+            );
+            rest = &rest[ptr_end..];
+        }
+        stripped.push_str(rest);
+        stripped
+    }
+
+    /// Asserts that reparsing the source after each edit gives the same token trees, module and
+    /// errors as parsing the edited source from scratch.
+    fn assert_reparse(src: &str, edits: &[SourceEdit]) {
+        let handler = Handler::default();
+        let mut parsed =
+            parse_file_incremental(&handler, Arc::from(src), None, Default::default()).unwrap();
+        assert!(!handler.has_errors());
+        for edit in edits {
+            let handler = Handler::default();
+            let reparsed = reparse_file(&handler, &parsed, edit, None, Default::default());
+            let expected_handler = Handler::default();
+            let src: Arc<str> = Arc::from(edit.apply(&parsed.src));
+            let expected =
+                parse_file_incremental(&expected_handler, src.clone(), None, Default::default());
+            let (errors, _) = handler.consume();
+            let (expected_errors, _) = expected_handler.consume();
+            assert_eq!(errors.len(), expected_errors.len());
+            for (error, expected_error) in errors.iter().zip(&expected_errors) {
+                assert_eq!(error.to_string(), expected_error.to_string());
+                assert_eq!(error.span(), expected_error.span());
+            }
+            let (Ok(reparsed), Ok(expected)) = (reparsed, expected) else {
+                panic!("the reparsed and parsed sources must both have a module");
+            };
+            assert_eq!(reparsed.src, src);
+            assert_eq!(reparsed.token_stream, expected.token_stream);
+            assert_eq!(
+                debug_without_src(&reparsed.module, &reparsed.src),
+                debug_without_src(&expected.module, &expected.src)
+            );
+            parsed = reparsed;
+        }
+    }
+
+    fn edit(range: Range<usize>, new_text: &str) -> SourceEdit {
+        SourceEdit {
+            range,
+            new_text: new_text.to_string(),
+        }
+    }
+
+    const SRC: &str = r#"library;
+
+fn foo(a: u64) -> u64 {
+    let b = a + 1; // one more
+    b * 2
+}
+
+fn bar() -> bool {
+    foo(1) == 4
+}
+"#;
+
+    #[test]
+    fn reparse_within_function_body() {
+        let offset = SRC.find("a + 1").unwrap();
+        assert_reparse(
+            SRC,
+            &[
+                // Extend an identifier.
+                edit(offset + 4..offset + 5, "10"),
+                // Insert a statement.
+                edit(offset + 6..offset + 6, ";\n    let c = b"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_between_items() {
+        let offset = SRC.find("fn bar").unwrap();
+        assert_reparse(
+            SRC,
+            &[
+                edit(offset..offset, "const C: u64 = 1_u64;\n\n"),
+                edit(offset + 23..offset + 25, "pub fn"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_comments() {
+        let offset = SRC.find("; // one more").unwrap();
+        assert_reparse(
+            SRC,
+            &[
+                // The trailing comment becomes a newlined comment.
+                edit(offset + 1..offset + 1, "\n   "),
+                edit(offset..offset, " /* inlined */"),
+                // The line comment continues on the next line after removing the newline.
+                edit(offset + 31..offset + 32, ""),
+            ],
+        );
+    }
+
+    const ITEMS_SRC: &str = r#"contract;
+
+use std::hash::*;
+
+/// Doc comment of `S`.
+struct S {
+    x: u64,
+}
+
+#[storage(read)]
+fn foo(s: S) -> u64 {
+    s.x
+}
+
+fn bar() -> bool {
+    foo(S { x: 1 }) == 4
+}
+
+impl S {
+    fn baz(self) -> u64 {
+        self.x * 2
+    }
+}
+"#;
+
+    #[test]
+    fn reparse_items() {
+        let offset = ITEMS_SRC.find("s.x").unwrap();
+        let impl_offset = ITEMS_SRC.find("self.x * 2").unwrap();
+        let foo_offset = ITEMS_SRC.find("#[storage(read)]").unwrap();
+        assert_reparse(
+            ITEMS_SRC,
+            &[
+                // An edit within a function body in the middle of the module.
+                edit(offset + 2..offset + 3, "x + 1"),
+                // An edit in the last item, moved by the first edit: `* 2` becomes `+ 1`.
+                edit(impl_offset + 4 + 7..impl_offset + 4 + 10, "+ 1"),
+                // New items between items.
+                edit(foo_offset..foo_offset, "\nconst C: u64 = 3;\n"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_attributes() {
+        let offset = ITEMS_SRC.find("fn bar").unwrap();
+        let doc_offset = ITEMS_SRC.find("/// Doc comment").unwrap();
+        assert_reparse(
+            ITEMS_SRC,
+            &[
+                // An attribute separated from its item by whitespace.
+                edit(offset - 1..offset - 1, "#[inline(never)]\n\n"),
+                // The removal of a doc comment.
+                edit(doc_offset..doc_offset + 23, ""),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_module_header() {
+        assert_reparse(
+            ITEMS_SRC,
+            &[
+                edit(0..8, "library"),
+                edit(0..0, "//! Inner doc comment.\n"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_parse_errors() {
+        let offset = ITEMS_SRC.find("s.x").unwrap();
+        let bar_offset = ITEMS_SRC.find("fn bar").unwrap();
+        assert_reparse(
+            ITEMS_SRC,
+            &[
+                // An unfinished statement.
+                edit(offset..offset, "let y = "),
+                edit(offset..offset + 8, ""),
+                // An unfinished item.
+                edit(bar_offset..bar_offset, "fn qux() -> "),
+                edit(bar_offset..bar_offset + 12, ""),
+            ],
+        );
+    }
+
+    #[test]
+    fn reparse_delimiters() {
+        let offset = SRC.find("foo(1)").unwrap();
+        assert_reparse(
+            SRC,
+            &[
+                edit(offset + 4..offset + 5, "(1, 2)"),
+                edit(offset..offset + 3, "r#foo"),
+            ],
+        );
+    }
+}
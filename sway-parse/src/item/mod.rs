@@ -1,9 +1,9 @@
 use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use sway_ast::keywords::{
-    AbiToken, ClassToken, ColonToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken,
-    ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
+    AbiToken, ClassToken, ColonToken, ConfigurableToken, ConstToken, EnumToken, EqToken, FnToken,
+    ImplToken, ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken,
+    StorageToken, StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
 use sway_ast::{
     FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
@@ -102,6 +102,10 @@ impl Parse for TypeField {
                 Err(parser.emit_error(ParseErrorKind::MissingColonInEnumTypeField))
             }?,
             ty: parser.parse()?,
+            discriminant_opt: match parser.take::<EqToken>() {
+                Some(eq_token) => Some((eq_token, parser.parse()?)),
+                None => None,
+            },
         })
     }
 }
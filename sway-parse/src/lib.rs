@@ -2,6 +2,7 @@ mod attribute;
 mod brackets;
 mod expr;
 mod generics;
+mod incremental;
 mod item;
 mod keywords;
 mod literal;
@@ -21,10 +22,11 @@ mod where_clause;
 
 use crate::priv_prelude::*;
 pub use crate::{
+    incremental::{parse_file_incremental, reparse_file, ParsedFile, SourceEdit},
     keywords::RESERVED_KEYWORDS,
     parse::Parse,
     parser::Parser,
-    token::{lex, lex_commented, parse_int_suffix},
+    token::{lex, lex_commented, lex_lossless, parse_int_suffix},
 };
 
 use sway_ast::{
@@ -37,13 +39,26 @@ use sway_types::SourceId;
 
 use std::sync::Arc;
 
+/// Experimental features that may change the syntax accepted by the parser. These mirror the
+/// experimental flags of the compiler, so that every tool parsing Sway code, such as the
+/// formatter, accepts the same syntax as the compiler does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExperimentalFlags {
+    pub new_encoding: bool,
+    /// Gates no syntax, and only exists to test how the experimental flags are passed around.
+    pub test_only: bool,
+}
+
 pub fn parse_file(
     handler: &Handler,
     src: Arc<str>,
     source_id: Option<SourceId>,
+    experimental: ExperimentalFlags,
 ) -> Result<Annotated<Module>, ErrorEmitted> {
     let ts = lex(handler, &src, 0, src.len(), source_id)?;
-    let (m, _) = Parser::new(handler, &ts).parse_to_end()?;
+    let (m, _) = Parser::new(handler, &ts)
+        .with_experimental(experimental)
+        .parse_to_end()?;
     Ok(m)
 }
 
@@ -71,7 +86,12 @@ mod tests {
     #[test]
     fn parse_invalid() {
         // just make sure these do not panic
-        let _res = parse_file(&Handler::default(), Arc::from("script; fn main(256߄"), None);
+        let _res = parse_file(
+            &Handler::default(),
+            Arc::from("script; fn main(256߄"),
+            None,
+            ExperimentalFlags::default(),
+        );
         let _res = parse_file(
             &Handler::default(),
             Arc::from(
@@ -85,6 +105,7 @@ mod tests {
             cug",
             ),
             None,
+            ExperimentalFlags::default(),
         );
         let _res = parse_file(
             &Handler::default(),
@@ -100,11 +121,37 @@ mod tests {
             ///\u{7eb}",
             ),
             None,
+            ExperimentalFlags::default(),
         );
         let _res = parse_file(
             &Handler::default(),
             Arc::from("script; \"\u{7eb}\u{7eb}"),
             None,
+            ExperimentalFlags::default(),
+        );
+    }
+
+    #[test]
+    fn experimental_flags_reach_nested_parsers() {
+        let handler = Handler::default();
+        let src: Arc<str> = Arc::from("{ (0) }");
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        let experimental = ExperimentalFlags {
+            test_only: true,
+            ..Default::default()
+        };
+
+        let mut parser = Parser::new(&handler, &token_stream).with_experimental(experimental);
+        let (mut braces, _) = parser
+            .enter_delimited(sway_types::ast::Delimiter::Brace)
+            .unwrap();
+        let (parens, _) = braces
+            .enter_delimited(sway_types::ast::Delimiter::Parenthesis)
+            .unwrap();
+        assert_eq!(parens.experimental(), experimental);
+        assert_eq!(
+            Parser::new(&handler, &token_stream).experimental(),
+            ExperimentalFlags::default()
         );
     }
 }
@@ -1,4 +1,4 @@
-use crate::{Parse, ParseToEnd, Peek};
+use crate::{ExperimentalFlags, Parse, ParseToEnd, Peek};
 use core::marker::PhantomData;
 use std::cell::RefCell;
 use sway_ast::keywords::Keyword;
@@ -19,6 +19,7 @@ pub struct Parser<'a, 'e> {
     token_trees: &'a [TokenTree],
     full_span: Span,
     handler: &'e Handler,
+    experimental: ExperimentalFlags,
 }
 
 impl<'a, 'e> Parser<'a, 'e> {
@@ -27,9 +28,23 @@ impl<'a, 'e> Parser<'a, 'e> {
             token_trees: token_stream.token_trees(),
             full_span: token_stream.span(),
             handler,
+            experimental: ExperimentalFlags::default(),
         }
     }
 
+    /// Enables the syntax of the given experimental features.
+    pub fn with_experimental(self, experimental: ExperimentalFlags) -> Self {
+        Self {
+            experimental,
+            ..self
+        }
+    }
+
+    /// The experimental features whose syntax is accepted by this parser.
+    pub fn experimental(&self) -> ExperimentalFlags {
+        self.experimental
+    }
+
     pub fn emit_error(&mut self, kind: ParseErrorKind) -> ErrorEmitted {
         let span = match self.token_trees {
             [token_tree, ..] => token_tree.span(),
@@ -96,6 +111,7 @@ impl<'a, 'e> Parser<'a, 'e> {
             token_trees: self.token_trees,
             full_span: self.full_span.clone(),
             handler: &handler,
+            experimental: self.experimental,
         };
 
         match parsing_function(&mut fork) {
@@ -155,6 +171,7 @@ impl<'a, 'e> Parser<'a, 'e> {
             token_trees: self.token_trees,
             full_span: self.full_span.clone(),
             handler: &handler,
+            experimental: self.experimental,
         };
 
         match fork.parse() {
@@ -188,6 +205,7 @@ impl<'a, 'e> Parser<'a, 'e> {
             token_trees: self.token_trees,
             full_span: self.full_span.clone(),
             handler: &handler,
+            experimental: self.experimental,
         };
         let r = match T::parse(&mut fork) {
             Ok(result) => {
@@ -244,6 +262,7 @@ impl<'a, 'e> Parser<'a, 'e> {
                     token_trees: token_stream.token_trees(),
                     full_span: token_stream.span(),
                     handler: self.handler,
+                    experimental: self.experimental,
                 };
                 Some((parser, span.clone()))
             }
@@ -469,6 +488,7 @@ impl<'original, 'a, 'e> ParseRecoveryStrategies<'original, 'a, 'e> {
             token_trees: self.fork_token_trees,
             full_span: self.fork_full_span.clone(),
             handler: &self.handler,
+            experimental: self.original.borrow().experimental,
         };
         f(&mut p);
         self.finish(p)
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use sway_ast::literal::{LitChar, LitInt, LitIntType, LitString, Literal};
 use sway_ast::token::{
     Comment, CommentKind, CommentedGroup, CommentedTokenStream, CommentedTokenTree, DocComment,
-    DocStyle, GenericTokenTree, Punct, Spacing, TokenStream,
+    DocStyle, GenericTokenTree, LosslessTokenStream, Punct, Spacing, TokenStream,
 };
 use sway_error::error::CompileError;
 use sway_error::handler::{ErrorEmitted, Handler};
@@ -105,6 +105,15 @@ pub fn lex(
     lex_commented(handler, src, start, end, &source_id).map(|stream| stream.strip_comments())
 }
 
+/// Lexes the source into a [LosslessTokenStream], retaining all of its whitespace and comments.
+pub fn lex_lossless(
+    handler: &Handler,
+    src: &Arc<str>,
+    source_id: Option<SourceId>,
+) -> Result<LosslessTokenStream> {
+    lex_commented(handler, src, 0, src.len(), &source_id).map(LosslessTokenStream::from)
+}
+
 pub fn lex_commented(
     handler: &Handler,
     src: &Arc<str>,
@@ -847,7 +856,7 @@ mod tests {
         literal::{LitChar, Literal},
         token::{
             Comment, CommentKind, CommentedTokenTree, CommentedTree, DocComment, DocStyle,
-            TokenTree,
+            LosslessTokenTree, TokenTree,
         },
     };
     use sway_error::{
@@ -1095,4 +1104,48 @@ mod tests {
         );
         assert_eq!(tts.next(), None);
     }
+
+    #[test]
+    fn lex_lossless_round_trip() {
+        let input = r#"
+        //! Module docs.
+        contract;
+
+        /* A block
+           comment. */
+        fn r#fn(a: u64) -> u64 { // Trailing comment.
+            let b = 0x1_u64; /* Inlined */ let c = "str" ;
+            a+b
+        }
+        "#;
+        let handler = Handler::default();
+        let stream = lex_lossless(&handler, &Arc::from(input), None).unwrap();
+        assert!(handler.consume().0.is_empty());
+        assert_eq!(stream.to_source(), input);
+    }
+
+    #[test]
+    fn lex_lossless_edit() {
+        let input = "fn foo() {\n    foo(  1 ) // Recursion.\n}\n";
+        let handler = Handler::default();
+        let mut stream = lex_lossless(&handler, &Arc::from(input), None).unwrap();
+        let new_name: Arc<str> = Arc::from("bar");
+        let rename = |tree: &mut LosslessTokenTree| {
+            if let GenericTokenTree::Ident(ident) = &tree.tree {
+                if ident.as_str() == "foo" {
+                    let span = Span::new(new_name.clone(), 0, new_name.len(), None).unwrap();
+                    tree.tree = Ident::new(span).into();
+                }
+            }
+        };
+        rename(&mut stream.token_trees[1]);
+        let GenericTokenTree::Group(body) = &mut stream.token_trees[3].tree else {
+            panic!("expected the body of the function");
+        };
+        rename(&mut body.token_stream.token_trees[0]);
+        assert_eq!(
+            stream.to_source(),
+            "fn bar() {\n    bar(  1 ) // Recursion.\n}\n"
+        );
+    }
 }
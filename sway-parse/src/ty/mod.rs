@@ -61,12 +61,44 @@ impl Parse for Ty {
             return Ok(Ty::Slice { slice_token, ty });
         }
         if let Some(ampersand_token) = parser.take() {
+            // `&[T]` is a slice, whereas `&[T; N]` is a reference to an array.
+            if let Some((mut inner_parser, span)) = parser.enter_delimited(Delimiter::Bracket) {
+                let ty = inner_parser.parse()?;
+                if let Some(semicolon_token) = inner_parser.take() {
+                    let length = inner_parser.parse()?;
+                    if !inner_parser.is_empty() {
+                        return Err(inner_parser
+                            .emit_error(ParseErrorKind::UnexpectedTokenAfterArrayTypeLength));
+                    }
+                    let descriptor = TyArrayDescriptor {
+                        ty,
+                        semicolon_token,
+                        length,
+                    };
+                    return Ok(Ty::Ref {
+                        ampersand_token,
+                        ty: Box::new(Ty::Array(SquareBrackets::new(descriptor, span))),
+                    });
+                }
+                if !inner_parser.is_empty() {
+                    return Err(
+                        inner_parser.emit_error(ParseErrorKind::UnexpectedTokenAfterSliceType)
+                    );
+                }
+                return Ok(Ty::SliceRef {
+                    ampersand_token,
+                    ty: SquareBrackets::new(ty, span),
+                });
+            }
             let ty = Box::new(parser.parse()?);
             return Ok(Ty::Ref {
                 ampersand_token,
                 ty,
             });
         }
+        if let Some(bang_token) = parser.take() {
+            return Ok(Ty::Never { bang_token });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
@@ -135,4 +167,34 @@ mod tests {
         );
         assert_matches!(item, Ty::Ref { .. });
     }
+
+    #[test]
+    fn parse_slice_ref() {
+        let item = parse::<Ty>(
+            r#"
+            &[u64]
+            "#,
+        );
+        assert_matches!(item, Ty::SliceRef { .. });
+    }
+
+    #[test]
+    fn parse_ref_to_array() {
+        let item = parse::<Ty>(
+            r#"
+            &[u64; 3]
+            "#,
+        );
+        assert_matches!(item, Ty::Ref { ty, .. } if matches!(*ty, Ty::Array(_)));
+    }
+
+    #[test]
+    fn parse_never() {
+        let item = parse::<Ty>(
+            r#"
+            !
+            "#,
+        );
+        assert_matches!(item, Ty::Never { .. });
+    }
 }
@@ -36,34 +36,61 @@ pub const DOC_COMMENT_ATTRIBUTE_NAME: &str = "doc-comment";
 
 /// The attribute used for Sway in-language unit tests.
 pub const TEST_ATTRIBUTE_NAME: &str = "test";
+pub const TEST_SHOULD_REVERT_NAME: &str = "should_revert";
+pub const TEST_SHARED_STATE_NAME: &str = "shared_state";
+
+/// The attributes of the functions that run before and after each unit test of a module.
+pub const BEFORE_EACH_ATTRIBUTE_NAME: &str = "before_each";
+pub const AFTER_EACH_ATTRIBUTE_NAME: &str = "after_each";
 
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
+/// The attribute of the contract method run once, when the contract is deployed.
+pub const CONSTRUCTOR_ATTRIBUTE_NAME: &str = "constructor";
+
+/// The attribute of the contract methods that revert while the contract is paused.
+pub const WHEN_NOT_PAUSED_ATTRIBUTE_NAME: &str = "when_not_paused";
+
+/// The attribute of the self-recursive functions whose recursive calls must be turned into loops.
+pub const TAIL_CALL_ATTRIBUTE_NAME: &str = "tail_call";
+
 /// The valid attribute strings related to allow.
 pub const ALLOW_ATTRIBUTE_NAME: &str = "allow";
 pub const ALLOW_DEAD_CODE_NAME: &str = "dead_code";
 pub const ALLOW_DEPRECATED_NAME: &str = "deprecated";
+pub const ALLOW_UNUSED_TYPE_PARAMETERS_NAME: &str = "unused_type_parameters";
+pub const ALLOW_UNBOUNDED_LOOP_NAME: &str = "unbounded_loop";
 
 /// The valid attribute strings related to conditional compilation.
 pub const CFG_ATTRIBUTE_NAME: &str = "cfg";
 pub const CFG_TARGET_ARG_NAME: &str = "target";
 pub const CFG_PROGRAM_TYPE_ARG_NAME: &str = "program_type";
 pub const CFG_EXPERIMENTAL_NEW_ENCODING: &str = "experimental_new_encoding";
+pub const CFG_EXPERIMENTAL_TEST_ONLY: &str = "experimental_test_only";
 
 pub const DEPRECATED_ATTRIBUTE_NAME: &str = "deprecated";
 
+/// The attribute used to exclude an item from formatting by `swayfmt`.
+pub const FMT_SKIP_ATTRIBUTE_NAME: &str = "swayfmt::skip";
+
 /// The list of valid attributes.
 pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     STORAGE_PURITY_ATTRIBUTE_NAME,
     DOC_ATTRIBUTE_NAME,
     DOC_COMMENT_ATTRIBUTE_NAME,
     TEST_ATTRIBUTE_NAME,
+    BEFORE_EACH_ATTRIBUTE_NAME,
+    AFTER_EACH_ATTRIBUTE_NAME,
     INLINE_ATTRIBUTE_NAME,
     PAYABLE_ATTRIBUTE_NAME,
+    CONSTRUCTOR_ATTRIBUTE_NAME,
+    WHEN_NOT_PAUSED_ATTRIBUTE_NAME,
+    TAIL_CALL_ATTRIBUTE_NAME,
     ALLOW_ATTRIBUTE_NAME,
     CFG_ATTRIBUTE_NAME,
     DEPRECATED_ATTRIBUTE_NAME,
+    FMT_SKIP_ATTRIBUTE_NAME,
 ];
 
 pub const CORE: &str = "core";
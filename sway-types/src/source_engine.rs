@@ -1,8 +1,8 @@
-use crate::{ModuleId, SourceId};
+use crate::{LineCol, LineTable, ModuleId, SourceId, Span};
 use std::{
     collections::{BTreeSet, HashMap},
     path::PathBuf,
-    sync::RwLock,
+    sync::{Arc, RwLock},
 };
 
 /// The Source Engine manages a relationship between file paths and their corresponding
@@ -11,6 +11,8 @@ use std::{
 /// system is to enable clients that need to reference a file path to do so using an
 /// integer-based ID. This numeric representation can be stored more efficiently as
 /// a key in a hashmap.
+/// The Source Engine also caches a [LineTable] for every source, so that the lines and
+/// columns of spans can be found without scanning the source text.
 /// The Source Engine is designed to be thread-safe. Its internal structures are
 /// secured by the RwLock mechanism. This allows its functions to be invoked using
 /// a straightforward non-mutable reference, ensuring safe concurrent access.
@@ -22,6 +24,7 @@ pub struct SourceEngine {
     next_module_id: RwLock<u16>,
     path_to_module_map: RwLock<HashMap<PathBuf, ModuleId>>,
     module_to_sources_map: RwLock<HashMap<ModuleId, BTreeSet<SourceId>>>,
+    line_tables: RwLock<HashMap<SourceId, (Arc<str>, Arc<LineTable>)>>,
 }
 
 impl Clone for SourceEngine {
@@ -33,6 +36,7 @@ impl Clone for SourceEngine {
             next_module_id: RwLock::new(*self.next_module_id.read().unwrap()),
             path_to_module_map: RwLock::new(self.path_to_module_map.read().unwrap().clone()),
             module_to_sources_map: RwLock::new(self.module_to_sources_map.read().unwrap().clone()),
+            line_tables: RwLock::new(self.line_tables.read().unwrap().clone()),
         }
     }
 }
@@ -49,6 +53,13 @@ impl SourceEngine {
             }
         }
 
+        // Hold the lock on the source map while allocating the ID, so that modules type-checked
+        // in parallel can't allocate different IDs for the same path.
+        let mut source_map = self.path_to_source_map.write().unwrap();
+        if let Some(source_id) = source_map.get(path) {
+            return *source_id;
+        }
+
         let manifest_path = sway_utils::find_parent_manifest_dir(path).unwrap_or(path.clone());
         let module_id = {
             let mut module_map = self.path_to_module_map.write().unwrap();
@@ -59,17 +70,18 @@ impl SourceEngine {
             })
         };
 
-        let source_id = SourceId::new(module_id.id, *self.next_source_id.read().unwrap());
-        {
+        let source_id = {
             let mut next_id = self.next_source_id.write().unwrap();
+            let source_id = SourceId::new(module_id.id, *next_id);
             *next_id += 1;
 
-            let mut source_map = self.path_to_source_map.write().unwrap();
             source_map.insert(path.clone(), source_id);
 
             let mut path_map = self.source_to_path_map.write().unwrap();
             path_map.insert(source_id, path.clone());
-        }
+            source_id
+        };
+        drop(source_map);
 
         let mut module_map = self.module_to_sources_map.write().unwrap();
         module_map.entry(module_id).or_default().insert(source_id);
@@ -100,4 +112,42 @@ impl SourceEngine {
             .map(|file_name| file_name.to_string_lossy())
             .map(|file_name| file_name.to_string())
     }
+
+    /// This function provides the [LineTable] of the source the span points into. The table
+    /// is computed once per source ID and recomputed only if the source text changes.
+    pub fn line_table(&self, span: &Span) -> Arc<LineTable> {
+        let Some(source_id) = span.source_id() else {
+            return Arc::new(LineTable::new(span.src()));
+        };
+        if let Some((src, line_table)) = self.line_tables.read().unwrap().get(source_id) {
+            if Arc::ptr_eq(src, span.src()) {
+                return line_table.clone();
+            }
+        }
+        let line_table = Arc::new(LineTable::new(span.src()));
+        self.line_tables
+            .write()
+            .unwrap()
+            .insert(*source_id, (span.src().clone(), line_table.clone()));
+        line_table
+    }
+
+    /// This function provides the line and column start and end of a span.
+    pub fn line_col(&self, span: &Span) -> (LineCol, LineCol) {
+        let line_table = self.line_table(span);
+        (
+            line_table.line_col(span.start()),
+            line_table.line_col(span.end()),
+        )
+    }
+
+    /// This function provides the line and column start and end of a span, with the columns
+    /// counted in UTF-16 code units, as expected by the language server protocol.
+    pub fn line_col_utf16(&self, span: &Span) -> (LineCol, LineCol) {
+        let line_table = self.line_table(span);
+        (
+            line_table.line_col_utf16(span.src(), span.start()),
+            line_table.line_col_utf16(span.src(), span.end()),
+        )
+    }
 }
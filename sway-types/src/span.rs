@@ -40,6 +40,74 @@ impl<'a> Position<'a> {
         let col = self.pos - last_newline_pos + 1;
         (line, col)
     }
+
+    /// Returns the line and the column of the position, with the column counted in UTF-16 code
+    /// units, as expected by the language server protocol.
+    pub fn line_col_utf16(&self) -> (usize, usize) {
+        let (line, col) = self.line_col();
+        let line_start = self.pos + 1 - col;
+        let col = self.input[line_start..self.pos].encode_utf16().count() + 1;
+        (line, col)
+    }
+}
+
+/// The byte offsets of the starts of all of the lines of a source.
+///
+/// Computing the line and column of a position with a [Position] scans the whole source up to
+/// the position, which gets slow on very large files. A [LineTable] is computed once per source
+/// and finds the line of a position with a binary search instead.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LineTable {
+    line_starts: Vec<usize>,
+}
+
+impl LineTable {
+    pub fn new(src: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                src.bytes()
+                    .enumerate()
+                    .filter(|(_, byte)| *byte == b'\n')
+                    .map(|(pos, _)| pos + 1),
+            )
+            .collect();
+        Self { line_starts }
+    }
+
+    /// Returns the number of lines of the source.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the byte offset of the start of the line, counting lines from 1.
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        line.checked_sub(1)
+            .and_then(|index| self.line_starts.get(index))
+            .copied()
+    }
+
+    /// Returns the line and the column of the byte position, both starting from 1.
+    pub fn line_col(&self, pos: usize) -> LineCol {
+        let index = self.line_index(pos);
+        LineCol {
+            line: index + 1,
+            col: pos - self.line_starts[index] + 1,
+        }
+    }
+
+    /// Returns the line and the column of the byte position in `src`, both starting from 1, with
+    /// the column counted in UTF-16 code units.
+    pub fn line_col_utf16(&self, src: &str, pos: usize) -> LineCol {
+        let index = self.line_index(pos);
+        LineCol {
+            line: index + 1,
+            col: src[self.line_starts[index]..pos].encode_utf16().count() + 1,
+        }
+    }
+
+    fn line_index(&self, pos: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= pos) - 1
+    }
 }
 
 /// Represents a span of the source code in a specific file.
@@ -53,6 +121,9 @@ pub struct Span {
     end: usize,
     // A reference counted pointer to the file from which this span originated.
     source_id: Option<SourceId>,
+    // Whether the span belongs to code generated by the compiler, in which case it points at
+    // the item that triggered the generation.
+    is_synthetic: bool,
 }
 
 impl Hash for Span {
@@ -60,12 +131,16 @@ impl Hash for Span {
         self.start.hash(state);
         self.end.hash(state);
         self.source_id.hash(state);
+        self.is_synthetic.hash(state);
     }
 }
 
 impl PartialEq for Span {
     fn eq(&self, other: &Self) -> bool {
-        self.start == other.start && self.end == other.end && self.source_id == other.source_id
+        self.start == other.start
+            && self.end == other.end
+            && self.source_id == other.source_id
+            && self.is_synthetic == other.is_synthetic
     }
 }
 
@@ -104,9 +179,27 @@ impl Span {
             start,
             end,
             source_id: source,
+            is_synthetic: false,
         })
     }
 
+    /// Creates a span for code generated by the compiler on behalf of the item at `origin`,
+    /// like the methods of automatically implemented traits or desugared `match` expressions.
+    ///
+    /// The span covers `origin`, so that diagnostics in the generated code point at the item
+    /// responsible for it, but it is marked as synthetic and never equals a span of user code.
+    pub fn synthetic(origin: &Span) -> Span {
+        Span {
+            is_synthetic: true,
+            ..origin.clone()
+        }
+    }
+
+    /// Returns true if the span was created with [Span::synthetic] for generated code.
+    pub fn is_synthetic(&self) -> bool {
+        self.is_synthetic
+    }
+
     pub fn from_string(source: String) -> Span {
         let len = source.len();
         Span::new(Arc::from(source), 0, len, None).unwrap()
@@ -162,6 +255,7 @@ impl Span {
             start: self.start + start_delta,
             end: self.end - end_delta,
             source_id: self.source_id,
+            is_synthetic: self.is_synthetic,
         }
     }
 
@@ -179,6 +273,7 @@ impl Span {
             source_id: self.source_id,
             start: self.end,
             end: self.end + char.len_utf8(),
+            is_synthetic: self.is_synthetic,
         })
     }
 
@@ -195,6 +290,7 @@ impl Span {
             start: cmp::min(s1.start, s2.start),
             end: cmp::max(s1.end, s2.end),
             source_id: s1.source_id,
+            is_synthetic: s1.is_synthetic || s2.is_synthetic,
         }
     }
 
@@ -212,6 +308,14 @@ impl Span {
             self.end_pos().line_col().into(),
         )
     }
+
+    /// Returns the line and column start and end, with the columns counted in UTF-16 code units.
+    pub fn line_col_utf16(&self) -> (LineCol, LineCol) {
+        (
+            self.start_pos().line_col_utf16().into(),
+            self.end_pos().line_col_utf16().into(),
+        )
+    }
 }
 
 impl fmt::Debug for Span {
@@ -222,6 +326,7 @@ impl fmt::Debug for Span {
             .field("source_id", &self.source_id)
             .field("start", &self.start)
             .field("end", &self.end)
+            .field("is_synthetic", &self.is_synthetic)
             .field("as_str()", &self.as_str())
             .finish()
     }
@@ -235,7 +340,7 @@ pub trait Spanned {
     fn span(&self) -> Span;
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct LineCol {
     pub line: usize,
     pub col: usize,
@@ -249,3 +354,44 @@ impl From<(usize, usize)> for LineCol {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn line_table_matches_position() {
+        let src = "fn main() {\n    let x = \"é𝄞\";\n\n}\n";
+        let table = LineTable::new(src);
+        assert_eq!(table.line_count(), 5);
+        for pos in (0..=src.len()).filter(|pos| src.is_char_boundary(*pos)) {
+            let position = Position::new(src, pos).unwrap();
+            assert_eq!(table.line_col(pos), position.line_col().into());
+            assert_eq!(
+                table.line_col_utf16(src, pos),
+                position.line_col_utf16().into()
+            );
+        }
+    }
+
+    #[test]
+    fn line_col_utf16_counts_code_units() {
+        let src = "let s = \"é𝄞\";";
+        let table = LineTable::new(src);
+        let end = src.len();
+        assert_eq!(table.line_col(end), LineCol { line: 1, col: 18 });
+        assert_eq!(table.line_col_utf16(src, end), LineCol { line: 1, col: 15 });
+        assert_eq!(table.line_start(2), None);
+    }
+
+    #[test]
+    fn synthetic_span_points_at_origin() {
+        let origin = Span::from_string("struct S {}".to_string());
+        let synthetic = Span::synthetic(&origin);
+        assert!(synthetic.is_synthetic());
+        assert!(!origin.is_synthetic());
+        assert_eq!(synthetic.as_str(), origin.as_str());
+        assert_ne!(synthetic, origin);
+        assert!(Span::join(origin, synthetic).is_synthetic());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    constants::{FMT_OFF_COMMENT, FMT_ON_COMMENT},
     formatter::FormattedCode,
     parse::parse_snippet,
     utils::map::{
@@ -21,18 +22,49 @@ pub struct CommentsContext {
     /// Original unformatted code that the formatter tries to format.
     /// The Formatter requires this to preserve newlines between comments.
     unformatted_code: UnformattedCode,
+    /// Ranges of the unformatted code between `// fmt: off` and `// fmt: on` comments.
+    fmt_off_regions: Vec<Range<usize>>,
 }
 
 impl CommentsContext {
     pub fn new(map: CommentMap, unformatted_code: UnformattedCode) -> Self {
+        let fmt_off_regions = fmt_off_regions(&map, unformatted_code.len());
         Self {
             map,
             unformatted_code,
+            fmt_off_regions,
         }
     }
     pub fn unformatted_code(&self) -> &str {
         &self.unformatted_code
     }
+    /// Checks if the span is within a region where formatting was turned off.
+    pub fn is_fmt_off(&self, span: &Span) -> bool {
+        self.fmt_off_regions
+            .iter()
+            .any(|region| ByteSpan::from(span.clone()).contained_within(region))
+    }
+}
+
+/// Collects the regions between `// fmt: off` and `// fmt: on` comments. A region that is not
+/// turned back on extends to the end of the code.
+fn fmt_off_regions(map: &CommentMap, code_len: usize) -> Vec<Range<usize>> {
+    let mut regions = vec![];
+    let mut region_start = None;
+    for comment in map.values() {
+        match (comment.span().as_str().trim(), region_start) {
+            (FMT_OFF_COMMENT, None) => region_start = Some(comment.span().end()),
+            (FMT_ON_COMMENT, Some(start)) => {
+                regions.push(start..comment.span().start());
+                region_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = region_start {
+        regions.push(start..code_len);
+    }
+    regions
 }
 
 #[inline]
@@ -33,6 +33,10 @@ pub struct Expressions {
     pub fn_args_layout: ItemsLayout,
     /// Put single-expression functions on a single line.
     pub fn_single_line: bool,
+
+    // ASM BLOCKS
+    /// Pad the op codes of the instructions of an `asm` block so that their operands line up.
+    pub asm_operand_alignment: bool,
 }
 
 impl Default for Expressions {
@@ -49,6 +53,7 @@ impl Default for Expressions {
             force_multiline_blocks: false,
             fn_args_layout: Default::default(),
             fn_single_line: false,
+            asm_operand_alignment: false,
         }
     }
 }
@@ -82,6 +87,9 @@ impl Expressions {
                 .unwrap_or(default.force_multiline_blocks),
             fn_args_layout: opts.fn_args_layout.unwrap_or(default.fn_args_layout),
             fn_single_line: opts.fn_single_line.unwrap_or(default.fn_single_line),
+            asm_operand_alignment: opts
+                .asm_operand_alignment
+                .unwrap_or(default.asm_operand_alignment),
         }
     }
 }
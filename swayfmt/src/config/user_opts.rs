@@ -61,6 +61,7 @@ pub struct ExpressionsOptions {
     pub force_multiline_blocks: Option<bool>,
     pub fn_args_layout: Option<ItemsLayout>,
     pub fn_single_line: Option<bool>,
+    pub asm_operand_alignment: Option<bool>,
 }
 /// See parent struct [Heuristics].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
@@ -1,6 +1,11 @@
 /// `swayfmt` file name.
 pub const SWAY_FORMAT_FILE_NAME: &str = "swayfmt.toml";
 
+/// Comment that turns formatting off until the next [FMT_ON_COMMENT].
+pub const FMT_OFF_COMMENT: &str = "// fmt: off";
+/// Comment that turns formatting back on after a [FMT_OFF_COMMENT].
+pub const FMT_ON_COMMENT: &str = "// fmt: on";
+
 //FUNDAMENTALS
 
 /// Default max width of each line.
@@ -20,6 +20,8 @@ pub enum FormatterError {
     HashBangAttributeError,
     #[error("Error while formatting file with syntax errors")]
     SyntaxError,
+    #[error("Error while mapping the formatted items back to the given range")]
+    RangeFormattingError,
 }
 
 #[derive(Debug, Error)]
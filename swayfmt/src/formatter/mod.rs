@@ -1,15 +1,15 @@
 use self::shape::Shape;
 use crate::comments::{write_comments, CommentsContext};
 use crate::parse::parse_file;
-use crate::utils::map::comments::CommentMap;
+use crate::utils::map::{byte_span::ByteSpan, comments::CommentMap};
 use crate::utils::map::{newline::handle_newlines, newline_style::apply_newline_style};
 pub use crate::{
     config::manifest::Config,
     error::{ConfigError, FormatterError},
 };
-use std::{borrow::Cow, fmt::Write, path::Path, sync::Arc};
-use sway_core::BuildConfig;
-use sway_types::{SourceEngine, Spanned};
+use std::{borrow::Cow, fmt::Write, ops::Range, path::Path, sync::Arc};
+use sway_core::{BuildConfig, ExperimentalFlags};
+use sway_types::{SourceEngine, Span, Spanned};
 
 pub(crate) mod shape;
 
@@ -19,10 +19,21 @@ pub struct Formatter {
     pub shape: Shape,
     pub config: Config,
     pub comments_context: CommentsContext,
+    /// The experimental features enabled for the compiler, which may change the accepted syntax.
+    pub experimental: ExperimentalFlags,
+    /// Spans of the unformatted code that were written without formatting them.
+    pub(crate) verbatim_spans: Vec<ByteSpan>,
 }
 
 pub type FormattedCode = String;
 
+/// The formatted code replacing a byte range of the unformatted code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedRange {
+    pub range: Range<usize>,
+    pub formatted_code: FormattedCode,
+}
+
 pub trait Format {
     fn format(
         &self,
@@ -45,6 +56,14 @@ impl Formatter {
         })
     }
 
+    /// Formats the code with the syntax of the given experimental features enabled.
+    pub fn with_experimental(self, experimental: ExperimentalFlags) -> Self {
+        Self {
+            experimental,
+            ..self
+        }
+    }
+
     /// Adds a block to the indentation level of the current [`Shape`].
     pub fn indent(&mut self) {
         self.shape.block_indent(&self.config);
@@ -102,8 +121,14 @@ impl Formatter {
         let mut raw_formatted_code = String::with_capacity(src.len());
 
         self.with_comments_context(src)?;
+        self.verbatim_spans.clear();
 
-        let annotated_module = parse_file(&self.source_engine, Arc::from(src), path.clone())?;
+        let annotated_module = parse_file(
+            &self.source_engine,
+            Arc::from(src),
+            path.clone(),
+            self.experimental,
+        )?;
         annotated_module.format(&mut raw_formatted_code, self)?;
 
         let mut formatted_code = String::from(&raw_formatted_code);
@@ -137,6 +162,70 @@ impl Formatter {
 
         Ok(formatted_code)
     }
+
+    /// Formats only the top-level items of `src` that intersect the byte `range`, along with
+    /// everything within them. Returns the replacements of the unformatted items, ordered by
+    /// their position in `src`, leaving the code around them untouched.
+    pub fn format_range(
+        &mut self,
+        src: Arc<str>,
+        range: Range<usize>,
+        build_config: Option<&BuildConfig>,
+    ) -> Result<Vec<FormattedRange>, FormatterError> {
+        let path = build_config.map(|build_config| build_config.canonical_root_module());
+        let unformatted_module = parse_file(
+            &self.source_engine,
+            src.clone(),
+            path.clone(),
+            self.experimental,
+        )?
+        .value;
+        let formatted_src: Arc<str> = Arc::from(self.format(src, build_config)?);
+        let formatted_module =
+            parse_file(&self.source_engine, formatted_src, path, self.experimental)?.value;
+        if unformatted_module.items.len() != formatted_module.items.len() {
+            return Err(FormatterError::RangeFormattingError);
+        }
+
+        let formatted_ranges = unformatted_module
+            .items
+            .iter()
+            .zip(formatted_module.items.iter())
+            .map(|(unformatted_item, formatted_item)| {
+                (unformatted_item.span(), formatted_item.span())
+            })
+            // The bounds are inclusive, so that an empty range, such as a cursor position, at
+            // the boundary of an item still formats it.
+            .filter(|(unformatted_span, _)| {
+                unformatted_span.start() <= range.end && range.start <= unformatted_span.end()
+            })
+            .filter(|(unformatted_span, formatted_span)| {
+                unformatted_span.as_str() != formatted_span.as_str()
+            })
+            .map(|(unformatted_span, formatted_span)| FormattedRange {
+                range: unformatted_span.start()..unformatted_span.end(),
+                formatted_code: formatted_span.as_str().to_string(),
+            })
+            .collect();
+        Ok(formatted_ranges)
+    }
+
+    /// Writes the span of the unformatted code as it is, along with the comments within it.
+    pub(crate) fn write_verbatim(
+        &mut self,
+        formatted_code: &mut FormattedCode,
+        span: &Span,
+    ) -> Result<(), FormatterError> {
+        self.write_indent_into_buffer(formatted_code)?;
+        write!(formatted_code, "{}", span.as_str())?;
+        let range = span.start()..span.end();
+        self.comments_context
+            .map
+            .retain(|bs, _| !bs.contained_within(&range));
+        self.verbatim_spans.push(ByteSpan::from(span.clone()));
+        Ok(())
+    }
+
     pub(crate) fn with_shape<F, O>(&mut self, new_shape: Shape, f: F) -> O
     where
         F: FnOnce(&mut Self) -> O,
@@ -102,6 +102,10 @@ impl Format for ItemEnum {
                                 type_field.colon_token.span().as_str(),
                             )?;
                             type_field.ty.format(formatted_code, formatter)?;
+                            if let Some((eq_token, discriminant)) = &type_field.discriminant_opt {
+                                write!(formatted_code, " {} ", eq_token.span().as_str())?;
+                                discriminant.format(formatted_code, formatter)?;
+                            }
                             writeln!(formatted_code, "{}", comma_token.span().as_str())?;
                         }
                         if let Some(final_value) = &fields.final_value_opt {
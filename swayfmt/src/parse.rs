@@ -2,6 +2,7 @@ use crate::{error::ParseFileError, Formatter, FormatterError};
 use std::path::PathBuf;
 use std::sync::Arc;
 use sway_ast::{attribute::Annotated, token::CommentedTokenStream, Module};
+use sway_core::ExperimentalFlags;
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::SourceEngine;
 
@@ -20,9 +21,14 @@ pub fn parse_file(
     source_engine: &SourceEngine,
     src: Arc<str>,
     path: Option<Arc<PathBuf>>,
+    experimental: ExperimentalFlags,
 ) -> Result<Annotated<Module>, ParseFileError> {
     let source_id = path.map(|p| source_engine.get_source_id(p.as_ref()));
-    with_handler(|h| sway_parse::parse_file(h, src, source_id))
+    let experimental = sway_parse::ExperimentalFlags {
+        new_encoding: experimental.new_encoding,
+        test_only: experimental.test_only,
+    };
+    with_handler(|h| sway_parse::parse_file(h, src, source_id, experimental))
 }
 
 pub fn lex(input: &Arc<str>) -> Result<CommentedTokenStream, ParseFileError> {
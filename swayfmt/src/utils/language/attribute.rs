@@ -11,8 +11,8 @@ use std::fmt::Write;
 use sway_ast::attribute::{Annotated, Attribute, AttributeArg, AttributeDecl, AttributeHashKind};
 use sway_types::{
     ast::{Delimiter, PunctKind},
-    constants::DOC_COMMENT_ATTRIBUTE_NAME,
-    Spanned,
+    constants::{DOC_COMMENT_ATTRIBUTE_NAME, FMT_SKIP_ATTRIBUTE_NAME},
+    Span, Spanned,
 };
 
 impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
@@ -21,6 +21,21 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        if is_skipped(self, formatter) {
+            let span = match self.attribute_list.first() {
+                Some(attr) => Span::join(attr.span(), self.value.span()),
+                None => self.value.span(),
+            };
+            formatter.write_verbatim(formatted_code, &span)?;
+            // The spans of some items, such as constants, do not include their semicolon.
+            if formatter.comments_context.unformatted_code()[span.end()..]
+                .trim_start()
+                .starts_with(';')
+            {
+                write!(formatted_code, ";")?;
+            }
+            return Ok(());
+        }
         // format each `Attribute`
         let mut start = None;
         for attr in &self.attribute_list {
@@ -52,6 +67,19 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
     }
 }
 
+/// Checks if the annotated value is excluded from formatting, either with the
+/// `#[swayfmt::skip]` attribute or by being within a `// fmt: off` region.
+fn is_skipped<T: Spanned>(annotated: &Annotated<T>, formatter: &Formatter) -> bool {
+    annotated
+        .attribute_list
+        .iter()
+        .flat_map(|attr_decl| attr_decl.attribute.get())
+        .any(|attr| attr.name.as_str() == FMT_SKIP_ATTRIBUTE_NAME)
+        || formatter
+            .comments_context
+            .is_fmt_off(&annotated.value.span())
+}
+
 impl Format for AttributeArg {
     fn format(
         &self,
@@ -127,7 +155,7 @@ impl Format for AttributeDecl {
                 formatter.shape.with_default_code_line(),
                 |formatter| -> Result<(), FormatterError> {
                     // name e.g. `storage`
-                    write!(formatted_code, "{}", attr.name.span().as_str())?;
+                    write!(formatted_code, "{}", attr.name.as_str())?;
                     if let Some(args) = &attr.args {
                         // `(`
                         Self::open_parenthesis(formatted_code, formatter)?;
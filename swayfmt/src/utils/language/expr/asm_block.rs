@@ -8,10 +8,14 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    expr::asm::{AsmBlock, AsmBlockContents, AsmFinalExpr, AsmRegisterDeclaration},
-    Instruction,
+    expr::asm::{
+        AsmBlock, AsmBlockContents, AsmClobbers, AsmFinalExpr, AsmFinalRegisters,
+        AsmRegisterDeclaration,
+    },
+    keywords::CommaToken,
+    Instruction, Punctuated,
 };
-use sway_types::{ast::Delimiter, Spanned};
+use sway_types::{ast::Delimiter, Ident, Spanned};
 
 impl Format for AsmBlock {
     fn format(
@@ -96,6 +100,10 @@ fn format_asm_block(
                 write!(formatted_code, "{}", inline_arguments)?;
                 AsmBlock::close_parenthesis(formatted_code, formatter)?;
             }
+            if let Some(clobbers) = &asm_block.clobbers_opt {
+                write!(formatted_code, " ")?;
+                clobbers.format(formatted_code, formatter)?;
+            }
 
             formatter
                 .shape
@@ -173,6 +181,9 @@ impl Format for AsmRegisterDeclaration {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        if let Some(const_token) = &self.const_token_opt {
+            write!(formatted_code, "{} ", const_token.span().as_str())?;
+        }
         self.register.format(formatted_code, formatter)?;
         if let Some((colon_token, expr)) = &self.value_opt {
             write!(formatted_code, "{} ", colon_token.span().as_str())?;
@@ -183,21 +194,70 @@ impl Format for AsmRegisterDeclaration {
     }
 }
 
+impl Format for AsmClobbers {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        write!(formatted_code, "{}", self.clobbers_token.as_str())?;
+        AsmBlock::open_parenthesis(formatted_code, formatter)?;
+        write_registers(self.registers.get(), formatted_code)?;
+        AsmBlock::close_parenthesis(formatted_code, formatter)?;
+
+        Ok(())
+    }
+}
+
+/// Writes a comma separated list of registers, which always fits on a single line.
+fn write_registers(
+    registers: &Punctuated<Ident, CommaToken>,
+    formatted_code: &mut FormattedCode,
+) -> Result<(), FormatterError> {
+    let registers = registers
+        .into_iter()
+        .map(|register| register.as_str())
+        .collect::<Vec<_>>();
+    write!(formatted_code, "{}", registers.join(", "))?;
+    Ok(())
+}
+
 impl Format for Instruction {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
         _formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        write!(formatted_code, "{}", &self.op_code_ident().as_str())?;
-        for arg in self.register_arg_idents() {
-            write!(formatted_code, " {}", arg.as_str())?
-        }
-        for imm in self.immediate_idents() {
-            write!(formatted_code, " {}", imm.as_str())?
-        }
-        Ok(())
+        write_instruction(self, formatted_code, 0)
+    }
+}
+
+/// Writes the instruction, padding its op code to `op_code_width` if it has any operands.
+fn write_instruction(
+    instruction: &Instruction,
+    formatted_code: &mut FormattedCode,
+    op_code_width: usize,
+) -> Result<(), FormatterError> {
+    let op_code = instruction.op_code_ident();
+    let register_args = instruction.register_arg_idents();
+    let immediates = instruction.immediate_idents();
+    if register_args.is_empty() && immediates.is_empty() {
+        write!(formatted_code, "{}", op_code.as_str())?;
+        return Ok(());
+    }
+    write!(
+        formatted_code,
+        "{:width$}",
+        op_code.as_str(),
+        width = op_code_width
+    )?;
+    for arg in register_args {
+        write!(formatted_code, " {}", arg.as_str())?
     }
+    for imm in immediates {
+        write!(formatted_code, " {}", imm.as_str())?
+    }
+    Ok(())
 }
 
 impl Format for AsmBlockContents {
@@ -206,9 +266,19 @@ impl Format for AsmBlockContents {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        // Align the operands of the instructions by padding their op codes to the longest one.
+        let op_code_width = match formatter.config.expressions.asm_operand_alignment {
+            true => self
+                .instructions
+                .iter()
+                .map(|(instruction, _)| instruction.op_code_ident().as_str().len())
+                .max()
+                .unwrap_or_default(),
+            false => 0,
+        };
         for (instruction, semicolon_token) in self.instructions.iter() {
             write!(formatted_code, "{}", formatter.indent_to_str()?)?;
-            instruction.format(formatted_code, formatter)?;
+            write_instruction(instruction, formatted_code, op_code_width)?;
             writeln!(formatted_code, "{}", semicolon_token.span().as_str())?
         }
         if let Some(final_expr) = &self.final_expr_opt {
@@ -231,7 +301,14 @@ impl Format for AsmFinalExpr {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        self.register.format(formatted_code, formatter)?;
+        match &self.registers {
+            AsmFinalRegisters::Register(register) => register.format(formatted_code, formatter)?,
+            AsmFinalRegisters::Tuple(registers) => {
+                AsmBlock::open_parenthesis(formatted_code, formatter)?;
+                write_registers(registers.get(), formatted_code)?;
+                AsmBlock::close_parenthesis(formatted_code, formatter)?;
+            }
+        }
         if let Some((colon_token, ty)) = &self.ty_opt {
             write!(formatted_code, "{} ", colon_token.span().as_str())?;
             ty.format(formatted_code, formatter)?;
@@ -245,6 +322,10 @@ impl LeafSpans for AsmBlock {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = vec![ByteSpan::from(self.asm_token.span())];
         collected_spans.append(&mut self.registers.leaf_spans());
+        if let Some(clobbers) = &self.clobbers_opt {
+            collected_spans.push(ByteSpan::from(clobbers.clobbers_token.span()));
+            collected_spans.append(&mut clobbers.registers.leaf_spans());
+        }
         collected_spans.append(&mut self.contents.leaf_spans());
         collected_spans
     }
@@ -252,7 +333,11 @@ impl LeafSpans for AsmBlock {
 
 impl LeafSpans for AsmRegisterDeclaration {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
-        let mut collected_spans = vec![ByteSpan::from(self.register.span())];
+        let mut collected_spans = vec![];
+        if let Some(const_token) = &self.const_token_opt {
+            collected_spans.push(ByteSpan::from(const_token.span()));
+        }
+        collected_spans.push(ByteSpan::from(self.register.span()));
         if let Some(value) = &self.value_opt {
             collected_spans.append(&mut value.leaf_spans());
         }
@@ -282,7 +367,10 @@ impl LeafSpans for Instruction {
 
 impl LeafSpans for AsmFinalExpr {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
-        let mut collected_spans = vec![ByteSpan::from(self.register.span())];
+        let mut collected_spans = match &self.registers {
+            AsmFinalRegisters::Register(register) => vec![ByteSpan::from(register.span())],
+            AsmFinalRegisters::Tuple(registers) => registers.leaf_spans(),
+        };
         if let Some(ty) = &self.ty_opt {
             collected_spans.append(&mut ty.leaf_spans());
         }
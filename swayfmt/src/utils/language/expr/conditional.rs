@@ -273,6 +273,19 @@ impl Format for IfCondition {
                 write!(formatted_code, " {} ", eq_token.span().as_str())?;
                 rhs.format(formatted_code, formatter)?;
             }
+            Self::And {
+                lhs,
+                double_ampersand_token,
+                rhs,
+            } => {
+                lhs.format(formatted_code, formatter)?;
+                write!(
+                    formatted_code,
+                    " {} ",
+                    double_ampersand_token.span().as_str()
+                )?;
+                rhs.format(formatted_code, formatter)?;
+            }
         }
 
         Ok(())
@@ -286,6 +299,10 @@ impl Format for MatchBranch {
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         self.pattern.format(formatted_code, formatter)?;
+        if let Some((if_token, guard)) = &self.guard_opt {
+            write!(formatted_code, " {} ", if_token.span().as_str())?;
+            guard.format(formatted_code, formatter)?;
+        }
         write!(
             formatted_code,
             " {} ",
@@ -413,6 +430,16 @@ impl LeafSpans for IfCondition {
                 collected_spans.append(&mut rhs.leaf_spans());
                 collected_spans
             }
+            IfCondition::And {
+                lhs,
+                double_ampersand_token,
+                rhs,
+            } => {
+                let mut collected_spans = lhs.leaf_spans();
+                collected_spans.push(ByteSpan::from(double_ampersand_token.span()));
+                collected_spans.append(&mut rhs.leaf_spans());
+                collected_spans
+            }
         }
     }
 }
@@ -421,6 +448,10 @@ impl LeafSpans for MatchBranch {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = Vec::new();
         collected_spans.append(&mut self.pattern.leaf_spans());
+        if let Some((if_token, guard)) = &self.guard_opt {
+            collected_spans.push(ByteSpan::from(if_token.span()));
+            collected_spans.append(&mut guard.leaf_spans());
+        }
         collected_spans.push(ByteSpan::from(self.fat_right_arrow_token.span()));
         collected_spans.append(&mut self.kind.leaf_spans());
         collected_spans
@@ -295,6 +295,15 @@ impl Format for Expr {
                 arg.get().format(formatted_code, formatter)?;
                 Self::close_square_bracket(formatted_code, formatter)?;
             }
+            Self::Range {
+                start,
+                dot_dot_token,
+                end,
+            } => {
+                start.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", dot_dot_token.span().as_str())?;
+                end.format(formatted_code, formatter)?;
+            }
             Self::MethodCall {
                 target,
                 dot_token,
@@ -1026,6 +1035,16 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut arg.leaf_spans());
             collected_spans
         }
+        Expr::Range {
+            start,
+            dot_dot_token,
+            end,
+        } => {
+            let mut collected_spans = start.leaf_spans();
+            collected_spans.push(ByteSpan::from(dot_dot_token.span()));
+            collected_spans.append(&mut end.leaf_spans());
+            collected_spans
+        }
         Expr::MethodCall {
             target,
             dot_token,
@@ -28,6 +28,14 @@ fmt_test_expr!(  basic_func_app          "foo()",
             ) "
 );
 
+fmt_test_expr!(  method_call_type_args    "foo.bar::<u64, Vec<u8>>(baz)",
+            intermediate_whitespace "foo . bar :: < u64 , Vec < u8 > > ( baz )"
+);
+
+fmt_test_expr!(  qualified_trait_call    "<S as MyTrait>::method(x)",
+            intermediate_whitespace " < S  as  MyTrait > :: method ( x ) "
+);
+
 fmt_test_expr!(  nested_args_func_app    "foo(a_struct { hello: \"hi\" }, a_var, foo.bar.baz.quux)",
             intermediate_whitespace "foo(a_struct {
                     hello  :  \"hi\"
@@ -119,6 +127,11 @@ fmt_test_expr!(  small_if_let "if let Result::Ok(x) = x { 100 } else { 1 }",
             intermediate_whitespace "if    let    Result   ::   Ok( x ) =    x {     100 }   else  {    1 }"
 );
 
+fmt_test_expr!(  small_if_let_chain "if let Some(x) = x && x > 0 { 100 } else { 1 }",
+            intermediate_whitespace "if    let    Some( x ) =    x   &&   x>0 {     100 }   else  {    1 }"
+);
+
+
 fmt_test_expr!(  match_nested_conditional
 "match foo {
     Foo::foo => {
@@ -169,6 +182,24 @@ fmt_test_expr!(  match_branch_kind
 quux();\n    }\n\n\n}"
 );
 
+fmt_test_expr!(  match_branch_guard
+"match foo {
+    Some(x) if x > 3 => {
+        bar();
+    }
+    _ => {
+        baz();
+    }
+}",
+            intermediate_whitespace
+"match foo {
+    Some( x )   if   x>3   =>   {
+        bar()  ;
+    }
+    _ =>  {  baz(); }
+}"
+);
+
 fmt_test_expr!(  match_branch_kind_tuple_long
 "match (foo, bar) {
     (
@@ -256,6 +287,26 @@ intermediate_whitespace
 let i = 42;
 }");
 
+fmt_test_expr!(while_let_loop
+"while let Some(x) = it.next() {
+    let i = x;
+}",
+intermediate_whitespace
+"while let   Some( x )=it.next(){
+let i = x;
+}");
+
+fmt_test_expr!(let_else_in_loop
+"while i == true {
+    let Some(x) = foo else {
+        break;
+    };
+}",
+intermediate_whitespace
+"while i==true{
+let Some( x ) =foo  else  {   break ; } ;
+}");
+
 fmt_test_expr!(scoped_block
 "{
     let i = 42;
@@ -264,3 +315,8 @@ intermediate_whitespace
 "{
 let i = 42;
 }");
+
+fmt_test_expr!(index_range
+"foo[1..bar.len]",
+intermediate_whitespace
+"foo [ 1 .. bar . len ]");
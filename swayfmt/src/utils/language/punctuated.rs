@@ -206,6 +206,10 @@ impl Format for TypeField {
             self.colon_token.span().as_str(),
         )?;
         self.ty.format(formatted_code, formatter)?;
+        if let Some((eq_token, discriminant)) = &self.discriminant_opt {
+            write!(formatted_code, " {} ", eq_token.span().as_str())?;
+            discriminant.format(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -49,6 +49,14 @@ impl Format for Ty {
                 ampersand_token,
                 ty,
             } => format_ref(formatted_code, ampersand_token.clone(), ty.clone()),
+            Self::SliceRef {
+                ampersand_token,
+                ty,
+            } => format_slice_ref(formatted_code, ampersand_token.clone(), ty.clone()),
+            Self::Never { bang_token } => {
+                write!(formatted_code, "{}", bang_token.span().as_str())?;
+                Ok(())
+            }
         }
     }
 }
@@ -133,6 +141,20 @@ fn format_ref(
     Ok(())
 }
 
+fn format_slice_ref(
+    formatted_code: &mut FormattedCode,
+    ampersand_token: AmpersandToken,
+    ty: SquareBrackets<Box<Ty>>,
+) -> Result<(), FormatterError> {
+    write!(
+        formatted_code,
+        "{}[{}]",
+        ampersand_token.span().as_str(),
+        ty.into_inner().span().as_str()
+    )?;
+    Ok(())
+}
+
 impl Format for TyTupleDescriptor {
     fn format(
         &self,
@@ -198,6 +220,15 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut ty.leaf_spans());
                 collected_spans
             }
+            Ty::SliceRef {
+                ampersand_token,
+                ty,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(ampersand_token.span())];
+                collected_spans.append(&mut ty.leaf_spans());
+                collected_spans
+            }
+            Ty::Never { bang_token } => vec![ByteSpan::from(bang_token.span())],
         }
     }
 }
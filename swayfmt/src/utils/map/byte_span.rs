@@ -180,6 +180,10 @@ impl LeafSpans for TypeField {
         let mut collected_spans = vec![ByteSpan::from(self.name.span())];
         collected_spans.push(ByteSpan::from(self.colon_token.span()));
         collected_spans.append(&mut self.ty.leaf_spans());
+        if let Some((eq_token, discriminant)) = &self.discriminant_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut discriminant.leaf_spans());
+        }
         collected_spans
     }
 }
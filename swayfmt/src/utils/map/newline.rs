@@ -119,7 +119,8 @@ pub fn handle_newlines(
     // formatting the code a second time will still produce the same result.
     let newline_map = newline_map_from_src(&unformatted_input)?;
     // After the formatting existing items should be the same (type of the item) but their spans will be changed since we applied formatting to them.
-    let formatted_module = parse_file(source_engine, formatted_input, path)?.value;
+    let formatted_module =
+        parse_file(source_engine, formatted_input, path, formatter.experimental)?.value;
     // Actually find & insert the newline sequences
     add_newlines(
         newline_map,
@@ -128,6 +129,7 @@ pub fn handle_newlines(
         formatted_code,
         unformatted_input,
         newline_threshold,
+        &formatter.verbatim_spans,
     )?;
     Ok(())
 }
@@ -153,6 +155,9 @@ fn calculate_offset(base: usize, offset: i64) -> usize {
 /// This requires both the unformatted_code itself and the parsed version of it, because
 /// unformatted_code is used for context lookups and unformatted_module is required for actual
 /// traversal.
+///
+/// Code that was written without formatting it, described by `verbatim_spans`, already contains
+/// its newlines and is left untouched.
 fn add_newlines(
     newline_map: NewlineMap,
     unformatted_module: &Module,
@@ -160,6 +165,7 @@ fn add_newlines(
     formatted_code: &mut FormattedCode,
     unformatted_code: Arc<str>,
     newline_threshold: usize,
+    verbatim_spans: &[ByteSpan],
 ) -> Result<(), FormatterError> {
     let mut unformatted_newline_spans = unformatted_module.leaf_spans();
     let mut formatted_newline_spans = formatted_module.leaf_spans();
@@ -188,7 +194,11 @@ fn add_newlines(
         .skip(1)
         .zip(formatted_newline_spans.iter().skip(1))
     {
-        if previous_unformatted_newline_span.end < unformatted_newline_span.start {
+        let is_verbatim = verbatim_spans.iter().any(|verbatim_span| {
+            verbatim_span.start < previous_unformatted_newline_span.end
+                && unformatted_newline_span.start < verbatim_span.end
+        });
+        if previous_unformatted_newline_span.end < unformatted_newline_span.start && !is_verbatim {
             // At its core, the spaces between leaf spans are nothing more than just whitespace characters,
             // and sometimes comments, since they are not considered valid AST nodes. We are interested in
             // these spaces (with comments, if any)
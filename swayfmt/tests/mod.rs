@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use sway_core::ExperimentalFlags;
 use swayfmt::{config::user_def::FieldAlignment, Formatter};
 use test_macros::assert_eq_pretty;
 
@@ -121,6 +122,26 @@ enum Color {
     );
 }
 #[test]
+fn enum_with_discriminants() {
+    check(
+        r#"contract;
+
+enum Status {
+    Active: ()=1, Paused: ()   =  5,
+            Stopped: ()
+}
+        "#,
+        r#"contract;
+
+enum Status {
+    Active: () = 1,
+    Paused: () = 5,
+    Stopped: (),
+}
+"#,
+    );
+}
+#[test]
 fn enum_with_variant_alignment() {
     // Creating a config with enum_variant_align_threshold that exceeds longest variant length
     let mut formatter = Formatter::default();
@@ -1437,6 +1458,30 @@ fn foo() {
     );
 }
 
+#[test]
+fn asm_block_with_clobbers_const_operands_and_tuple_output() {
+    check(
+        r#"library;
+
+fn foo() {
+    asm(r1: a,r2 ,const  offset : 8)   clobbers( r1 ) {
+        addi r1 r1 offset;
+        (r1,r2) : (u64,u64)
+    }
+}
+"#,
+        r#"library;
+
+fn foo() {
+    asm(r1: a, r2, const offset: 8) clobbers(r1) {
+        addi r1 r1 offset;
+        (r1, r2): (u64, u64)
+    }
+}
+"#,
+    );
+}
+
 #[test]
 fn empty_blocks() {
     check(
@@ -2840,3 +2885,169 @@ pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
 "#,
     );
 }
+
+#[test]
+fn skip_attribute() {
+    check(
+        r#"library;
+
+#[swayfmt::skip]
+fn   keep(  a: u64 ) -> u64 {
+    a+1
+}
+
+fn   format(  a: u64 ) -> u64 {
+    a+1
+}
+"#,
+        r#"library;
+
+#[swayfmt::skip]
+fn   keep(  a: u64 ) -> u64 {
+    a+1
+}
+
+fn format(a: u64) -> u64 {
+    a + 1
+}
+"#,
+    );
+}
+
+#[test]
+fn fmt_off_region() {
+    check(
+        r#"library;
+
+// fmt: off
+const   MATRIX: [u64; 4] = [
+    1, 0,
+    0, 1,
+];
+// fmt: on
+
+const   X: u64 = 1;
+"#,
+        r#"library;
+
+// fmt: off
+const   MATRIX: [u64; 4] = [
+    1, 0,
+    0, 1,
+];
+// fmt: on
+
+const X: u64 = 1;
+"#,
+    );
+}
+
+#[test]
+fn asm_block_operand_alignment() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.asm_operand_alignment = true;
+    check_with_formatter(
+        r#"library;
+
+fn foo() -> u64 {
+    asm(r1: 5, r2) {
+        add r2 r1 r1; // double
+        addi   r2 r2 i1;
+        r2: u64
+    }
+}
+"#,
+        r#"library;
+
+fn foo() -> u64 {
+    asm(r1: 5, r2) {
+        add  r2 r1 r1; // double
+        addi r2 r2 i1;
+        r2: u64
+    }
+}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn experimental_new_encoding() {
+    let mut formatter = Formatter::default().with_experimental(ExperimentalFlags {
+        new_encoding: true,
+        ..Default::default()
+    });
+    check_with_formatter(
+        r#"library;
+
+#[cfg(experimental_new_encoding=true)]
+impl AbiEncode for Foo {
+    fn abi_encode(self, ref mut buffer: Buffer) {
+        self.bar.abi_encode(buffer);
+    }
+}
+
+#[cfg(experimental_new_encoding = false)]
+fn foo() {}
+"#,
+        r#"library;
+
+#[cfg(experimental_new_encoding = true)]
+impl AbiEncode for Foo {
+    fn abi_encode(self, ref mut buffer: Buffer) {
+        self.bar.abi_encode(buffer);
+    }
+}
+
+#[cfg(experimental_new_encoding = false)]
+fn foo() {}
+"#,
+        &mut formatter,
+    );
+}
+
+#[test]
+fn let_else_statement() {
+    check(
+        r#"script;
+
+fn main() {
+    let Some( x ) =foo  else  {   return ; } ;
+}
+"#,
+        r#"script;
+
+fn main() {
+    let Some(x) = foo else {
+        return;
+    };
+}
+"#,
+    );
+}
+
+#[test]
+fn format_range() {
+    let unformatted = r#"library;
+
+fn foo()->u64{1}
+
+fn bar()->u64{2}
+"#;
+    let mut formatter = Formatter::default();
+    let start = unformatted.find("bar").unwrap();
+    let formatted_ranges = formatter
+        .format_range(Arc::from(unformatted), start..start, None)
+        .unwrap();
+    assert_eq!(formatted_ranges.len(), 1);
+    assert_eq!(
+        &unformatted[formatted_ranges[0].range.clone()],
+        "fn bar()->u64{2}"
+    );
+    assert_eq_pretty!(
+        formatted_ranges[0].formatted_code,
+        r#"fn bar() -> u64 {
+    2
+}"#
+    );
+}
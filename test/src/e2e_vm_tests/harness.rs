@@ -20,6 +20,7 @@ use std::{fs, io::Read, path::PathBuf, str::FromStr};
 use sway_core::{asm_generation::ProgramABI, BuildTarget};
 
 use super::RunConfig;
+use crate::SnapshotMode;
 
 pub const NODE_URL: &str = "http://127.0.0.1:4000";
 pub const SECRET_KEY: &str = "de97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
@@ -269,6 +270,7 @@ pub(crate) async fn compile_to_bytes(file_name: &str, run_config: &RunConfig) ->
         },
         experimental: ExperimentalFlags {
             new_encoding: run_config.experimental.new_encoding,
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -417,3 +419,58 @@ fn emit_json_storage_slots(file_name: &str, built_package: &BuiltPackage) -> Res
     res?;
     Ok(())
 }
+
+/// Checks the snapshot of the ABI JSON, storage slots and bytecode hash of the packages built for
+/// a test against the `snapshot.json` file of the test, or writes the file when blessing.
+pub(crate) fn test_snapshot(
+    file_name: &str,
+    built_packages: &[BuiltPackage],
+    mode: SnapshotMode,
+) -> Result<()> {
+    let snapshot = built_packages
+        .iter()
+        .map(|built_package| {
+            let json_abi = match &built_package.program_abi {
+                ProgramABI::Fuel(abi) => serde_json::json!(abi),
+                ProgramABI::Evm(abi) => serde_json::json!(abi),
+                ProgramABI::MidenVM(_) => serde_json::Value::Null,
+            };
+            let bytecode_hash = Hasher::hash(&built_package.bytecode.bytes);
+            let package_snapshot = serde_json::json!({
+                "abi": json_abi,
+                "storage_slots": built_package.storage_slots,
+                "bytecode_hash": format!("0x{}", hex::encode(bytecode_hash)),
+            });
+            (built_package.descriptor.name.clone(), package_snapshot)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let snapshot_contents = serde_json::to_string_pretty(&snapshot)? + "\n";
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let snapshot_path = format!(
+        "{}/src/e2e_vm_tests/test_programs/{}/{}",
+        manifest_dir, file_name, "snapshot.json"
+    );
+    match mode {
+        SnapshotMode::Bless => {
+            tracing::info!("Blessing snapshot of {} ...", file_name.bold());
+            fs::write(snapshot_path, snapshot_contents)?;
+        }
+        SnapshotMode::Check => {
+            let Ok(expected_contents) = fs::read_to_string(snapshot_path) else {
+                bail!(
+                    "Snapshot file does not exist for this test. Run with `--bless` to create it."
+                );
+            };
+            if expected_contents != snapshot_contents {
+                println!("Mismatched snapshot.");
+                println!(
+                    "{}",
+                    prettydiff::diff_lines(&expected_contents, &snapshot_contents)
+                );
+                bail!("Mismatched snapshot. Run with `--bless` to update it.");
+            }
+        }
+    }
+    Ok(())
+}
@@ -329,6 +329,14 @@ impl TestContext {
                         output.push_str(&out);
                         result?;
                     }
+                    if let Some(mode) = context.run_config.snapshot {
+                        let (result, out) = run_and_capture_output(|| async {
+                            harness::test_snapshot(&name, std::slice::from_ref(&compiled), mode)
+                        })
+                        .await;
+                        output.push_str(&out);
+                        result?;
+                    }
                     Ok(())
                 }
             }
@@ -384,6 +392,19 @@ impl TestContext {
                         output.push_str(&out);
                     }
                 }
+
+                if let Some(mode) = context.run_config.snapshot {
+                    let built_pkgs = compiled_pkgs
+                        .into_iter()
+                        .map(|(_, built_pkg)| built_pkg)
+                        .collect::<Vec<_>>();
+                    let (result, out) = run_and_capture_output(|| async {
+                        harness::test_snapshot(&name, &built_pkgs, mode)
+                    })
+                    .await;
+                    output.push_str(&out);
+                    result?;
+                }
                 Ok(())
             }
 
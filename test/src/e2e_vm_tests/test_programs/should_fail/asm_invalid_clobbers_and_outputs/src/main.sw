@@ -0,0 +1,22 @@
+script;
+
+struct Pair {
+    a: u64,
+    b: u64,
+}
+
+fn main() -> u64 {
+    let _ = asm(r1: 0, r2) clobbers(r2) {
+        move r2 r1;
+        r2
+    };
+    let _ = asm(r1: 0, r2) {
+        move r2 r1;
+        (r1, r2): (u64, u64, u64)
+    };
+    let _ = asm(r1: 0, r2) {
+        move r2 r1;
+        (r1, r2): Pair
+    };
+    0
+}
@@ -0,0 +1,11 @@
+script;
+
+fn main() -> u64 {
+    asm(r1: 0, r2, const offset: 4) {
+        addi r2 r1 i5000;
+        muli r2 r1 i4096;
+        add r2 r1 r3;
+        add r2 r1 offset;
+        r4
+    }
+}
@@ -6,6 +6,6 @@ fn three_generics<A, B, C>(a: A, b: B, c: C) -> B {
 }
 
 fn main() {
-  // this should fail, since three_generics will return a value of type `str` 
+  // this should fail, since the annotation makes `B` a `u32`, which "foo" is not
   let _g: u32 = three_generics(true, "foo", 10);
 }
@@ -0,0 +1,22 @@
+contract;
+
+abi MyContract {
+    #[constructor]
+    #[storage(read, write)]
+    fn init();
+
+    #[constructor]
+    fn init_again();
+}
+
+impl MyContract for Contract {
+    #[constructor]
+    #[storage(read, write)]
+    fn init() {}
+
+    #[constructor]
+    fn init_again() {}
+}
+
+#[constructor]
+fn not_a_method() {}
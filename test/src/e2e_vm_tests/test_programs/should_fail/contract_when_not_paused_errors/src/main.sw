@@ -0,0 +1,22 @@
+contract;
+
+abi MyContract {
+    #[when_not_paused]
+    #[storage(read)]
+    fn deposit();
+
+    #[when_not_paused]
+    fn transfer();
+}
+
+impl MyContract for Contract {
+    #[when_not_paused]
+    #[storage(read)]
+    fn deposit() {}
+
+    #[when_not_paused]
+    fn transfer() {}
+}
+
+#[when_not_paused]
+fn not_a_method() {}
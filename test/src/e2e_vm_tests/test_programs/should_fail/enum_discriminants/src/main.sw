@@ -0,0 +1,24 @@
+script;
+
+enum Duplicate {
+    A: () = 1,
+    B: (),
+    C: () = 2,
+}
+
+enum Invalid {
+    A: () = true,
+}
+
+enum Overflow {
+    A: () = 18446744073709551615,
+    B: (),
+}
+
+struct Point {
+    x: u64 = 1,
+}
+
+fn main() -> u64 {
+    0
+}
@@ -0,0 +1,22 @@
+script;
+
+struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new(value: T) -> Self {
+        Wrapper { value }
+    }
+}
+
+fn wrap<T>(value: T) -> Wrapper<T> {
+    Wrapper { value }
+}
+
+fn main() {
+    // The expected type binds `T` before the arguments are type checked, so the mismatches are
+    // reported at the arguments.
+    let _a: Wrapper<u8> = wrap(true);
+    let _b: Wrapper<u8> = Wrapper::new(true);
+}
@@ -0,0 +1,22 @@
+library;
+
+#[before_each]
+fn setup_with_argument(x: u64) {}
+
+#[after_each]
+fn teardown_with_result() -> u64 {
+    0
+}
+
+#[before_each]
+fn generic_setup<T>() {}
+
+#[test]
+#[before_each]
+fn test_and_setup() {}
+
+#[before_each]
+fn setup() {}
+
+#[test]
+fn test_foo() {}
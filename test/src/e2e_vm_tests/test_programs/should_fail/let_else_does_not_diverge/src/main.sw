@@ -0,0 +1,9 @@
+script;
+
+fn main() {
+    let value = Some(42);
+
+    let Some(_x) = value else {
+        let _y = 0;
+    };
+}
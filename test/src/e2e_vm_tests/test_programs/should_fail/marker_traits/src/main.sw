@@ -0,0 +1,44 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Buffer {
+    ptr: raw_ptr,
+    len: u64,
+}
+
+struct Name {
+    value: str,
+}
+
+fn size_of_fixed<T>(_value: T) -> u64 where T: FixedSize {
+    __size_of::<T>()
+}
+
+fn size_of_encodable<T>(_value: T) -> u64 where T: Encodable {
+    __size_of::<T>()
+}
+
+// Constrained generics can be passed on to other constrained generics.
+fn size_of_pair<T>(value: T) -> u64 where T: FixedSize {
+    size_of_fixed((value, value))
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2 };
+    let buffer = Buffer { ptr: asm() { zero: raw_ptr }, len: 0 };
+    let name = Name { value: "name" };
+
+    let _point = size_of_fixed(point);
+    let _points = size_of_fixed([point; 2]);
+    let _pair = size_of_pair(true);
+    let _encoded_point = size_of_encodable(point);
+    let _encoded_name = size_of_encodable(name);
+
+    let _buffer = size_of_fixed(buffer);
+    let _name = size_of_fixed(name);
+    let _encoded_buffer = size_of_encodable(buffer);
+}
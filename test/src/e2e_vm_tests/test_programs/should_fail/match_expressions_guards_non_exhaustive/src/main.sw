@@ -0,0 +1,11 @@
+script;
+
+fn main() {
+    let value = Some(42);
+
+    // A guarded arm doesn't cover the values matched by its pattern.
+    let _x = match value {
+        Some(x) if x > 10 => x,
+        None => 0,
+    };
+}
@@ -56,3 +56,35 @@ pub fn crazy_point_test() {
         CrazyPoint { p1: Point { x: 0, y: 1 }, p2 } => { 42 },
     };
 }
+
+struct Flags {
+    a: bool,
+    b: bool
+}
+
+pub fn reordered_fields_test() {
+    let f = Flags {
+        a: true,
+        b: false,
+    };
+    // should fail
+    let foo = match f {
+        Flags { a: true, b: _ } => { 1 },
+        Flags { b: false, a: _ } => { 2 },
+    };
+    let p = Point {
+        x: 3,
+        y: 4,
+    };
+    // should fail
+    let foo = match p {
+        Point { y: 0, .. } => { 1 },
+        Point { x: 0, y: _ } => { 2 },
+    };
+    // should succeed
+    let foo = match p {
+        Point { y: 0, .. } => { 1 },
+        Point { y: _, x: 0 } => { 2 },
+        Point { x, .. } => { x },
+    };
+}
@@ -15,6 +15,7 @@ fn main() -> u64 {
     simple_tuples_test();
     point_test();
     crazy_point_test();
+    reordered_fields_test();
     variable_not_found_test();
     nested_match_tests();
     enum_match_exp_bugfix_test();
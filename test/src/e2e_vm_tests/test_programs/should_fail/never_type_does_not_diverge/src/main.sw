@@ -0,0 +1,9 @@
+script;
+
+fn does_not_diverge() -> ! {
+    let _x = 1;
+}
+
+fn main() {
+    does_not_diverge();
+}
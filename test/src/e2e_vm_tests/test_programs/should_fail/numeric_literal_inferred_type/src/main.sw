@@ -0,0 +1,20 @@
+script;
+
+fn consume<T>(_value: T) {}
+
+fn main() {
+    consume(0xff);
+    consume(0x00ff);
+
+    let a = 300;
+    let b: u8 = a;
+    consume(b);
+
+    let c = 70000;
+    let d: u16 = c;
+    consume(d);
+
+    let e = 255;
+    let f: u8 = e;
+    consume(f);
+}
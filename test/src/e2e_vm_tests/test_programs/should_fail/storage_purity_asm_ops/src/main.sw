@@ -0,0 +1,26 @@
+contract;
+
+abi MyContract {
+    #[storage(read)]
+    fn clear_slot(key: b256);
+
+    #[storage(write)]
+    fn read_slot(key: b256) -> u64;
+}
+
+impl MyContract for Contract {
+    #[storage(read)]
+    fn clear_slot(key: b256) {
+        asm(key: key, is_cleared, count: 1) {
+            scwq key is_cleared count;
+        };
+    }
+
+    #[storage(write)]
+    fn read_slot(key: b256) -> u64 {
+        asm(key: key, is_set, value) {
+            srw value is_set key;
+            value: u64
+        }
+    }
+}
@@ -0,0 +1,33 @@
+script;
+
+struct MyStruct<T> {
+    val: T
+}
+
+trait MyTrait {
+    fn foo(self, other: Self) -> bool;
+} {
+    fn bar(self, other: Self) -> bool {
+        self.foo(other)
+    }
+}
+
+impl<T> MyTrait for MyStruct<T> where T: MyTrait {
+    fn foo(self, other: Self) -> bool {
+        self.val.foo(other.val)
+    }
+}
+
+fn inner<T>(a: T, b: T) -> bool where T: MyTrait {
+    a.bar(b)
+}
+
+fn outer<T>(a: T, b: T) -> bool where T: MyTrait {
+    inner(a, b)
+}
+
+fn main() -> bool {
+    // `MyStruct<u64>` implements `MyTrait` only if `u64` does, which is
+    // found out while instantiating `inner` within `outer`.
+    outer(MyStruct { val: 5 }, MyStruct { val: 9 })
+}
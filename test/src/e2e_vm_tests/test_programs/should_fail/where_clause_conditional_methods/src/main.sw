@@ -0,0 +1,46 @@
+script;
+
+trait MyEq {
+    fn my_eq(self, other: Self) -> bool;
+}
+
+impl MyEq for u64 {
+    fn my_eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+impl<T> Pair<T> {
+    fn first(self) -> T {
+        self.a
+    }
+
+    // Only available when `T: MyEq`.
+    fn is_same(self) -> bool where T: MyEq {
+        self.a.my_eq(self.b)
+    }
+
+    // Only available when `T: MyEq`.
+    fn same(v: T) -> Self where T: MyEq {
+        Pair { a: v, b: v }
+    }
+}
+
+fn main() -> bool {
+    let numbers = Pair { a: 1u64, b: 1u64 };
+    let _numbers_same = numbers.is_same();
+    let _numbers = Pair::<u64>::same(1u64);
+
+    let flags = Pair { a: true, b: false };
+    // The unconditional methods are available for all `T`.
+    let _first = flags.first();
+    let _flags_same = flags.is_same();
+    let _flags = Pair::<bool>::same(true);
+
+    true
+}
@@ -0,0 +1,20 @@
+contract;
+
+use token_interface::{admin::Admin, Supply, Token};
+
+impl Token for Contract {
+    fn supply() -> Supply {
+        Supply {
+            minted: 0,
+            burned: 0,
+        }
+    }
+
+    fn mint(amount: u64) {}
+}
+
+impl Admin for Contract {
+    fn owner() -> u64 {
+        0
+    }
+}
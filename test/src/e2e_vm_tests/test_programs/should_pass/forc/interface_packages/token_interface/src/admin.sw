@@ -0,0 +1,5 @@
+library;
+
+abi Admin {
+    fn owner() -> u64;
+}
@@ -0,0 +1,13 @@
+library;
+
+pub mod admin;
+
+pub struct Supply {
+    minted: u64,
+    burned: u64,
+}
+
+abi Token {
+    fn supply() -> Supply;
+    fn mint(amount: u64);
+}
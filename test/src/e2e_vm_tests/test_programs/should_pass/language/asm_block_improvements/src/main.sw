@@ -0,0 +1,45 @@
+script;
+
+const OFFSET: u64 = 8;
+
+fn add_and_compare(a: u64, b: u64) -> (u64, bool) {
+    asm(r1: a, r2: b, r3, r4) {
+        add r3 r1 r2;
+        eq r4 r1 r2;
+        (r3, r4): (u64, bool)
+    }
+}
+
+fn add_offset(a: u64) -> u64 {
+    asm(r1: a, r2, const offset: OFFSET) {
+        addi r2 r1 offset;
+        r2
+    }
+}
+
+fn main() -> u64 {
+    let (sum, equal) = add_and_compare(3, 4);
+    if sum != 7 || equal {
+        return 0;
+    }
+    let (sum, equal) = add_and_compare(5, 5);
+    if sum != 10 || !equal {
+        return 0;
+    }
+
+    if add_offset(2) != 10 {
+        return 0;
+    }
+
+    // The initializer of a clobbered register keeps its value after the block.
+    let x = 5;
+    let doubled = asm(r1: x) clobbers(r1) {
+        add r1 r1 r1;
+        r1
+    };
+    if doubled != 10 || x != 5 {
+        return 0;
+    }
+
+    1
+}
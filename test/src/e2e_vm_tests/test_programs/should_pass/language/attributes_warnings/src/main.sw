@@ -9,6 +9,9 @@ fn f2() {}
 #[allow(dead_code, dead_code)]
 fn f3() {}
 
+#[test(shared)]
+fn f4() {}
+
 fn main() {
     f1();
     f2();
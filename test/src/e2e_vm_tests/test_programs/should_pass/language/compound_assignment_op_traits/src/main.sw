@@ -0,0 +1,76 @@
+script;
+
+use core::ops::*;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Add for Point {
+    fn add(self, other: Self) -> Self {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Subtract for Point {
+    fn subtract(self, other: Self) -> Self {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+struct Counter {
+    count: u64,
+    updates: u64,
+}
+
+impl AddAssign for Counter {
+    fn add_assign(ref mut self, other: Self) {
+        self.count += other.count;
+        self.updates += 1;
+    }
+}
+
+impl MultiplyAssign for Counter {
+    fn multiply_assign(ref mut self, other: Self) {
+        self.count *= other.count;
+        self.updates += 1;
+    }
+}
+
+struct Wrapper {
+    counter: Counter,
+}
+
+fn main() -> bool {
+    // `+=` and `-=` fall back to `Add` and `Subtract`
+    let mut point = Point { x: 1, y: 2 };
+    point += Point { x: 3, y: 4 };
+    assert(point.x == 4 && point.y == 6);
+    point -= Point { x: 1, y: 1 };
+    assert(point.x == 3 && point.y == 5);
+
+    // `+=` and `*=` call `add_assign` and `multiply_assign`
+    let mut counter = Counter { count: 1, updates: 0 };
+    counter += Counter { count: 2, updates: 0 };
+    counter *= Counter { count: 4, updates: 0 };
+    assert(counter.count == 12 && counter.updates == 2);
+
+    let mut wrapper = Wrapper { counter };
+    wrapper.counter += Counter { count: 3, updates: 0 };
+    assert(wrapper.counter.count == 15 && wrapper.counter.updates == 3);
+
+    // primitives keep using the binary operators
+    let mut value = 5;
+    value += 2;
+    value *= 3;
+    assert(value == 21);
+
+    true
+}
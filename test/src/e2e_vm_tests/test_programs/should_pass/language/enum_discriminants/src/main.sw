@@ -0,0 +1,34 @@
+script;
+
+enum Status {
+    Active: () = 1,
+    Paused: () = 5,
+    Stopped: (),
+    Value: u64 = 10,
+}
+
+fn tag(status: Status) -> u64 {
+    asm(ptr: status, tag) {
+        lw tag ptr i0;
+        tag: u64
+    }
+}
+
+fn main() -> u64 {
+    let value = match Status::Value(30) {
+        Status::Value(x) => x,
+        _ => 0,
+    };
+    let stopped = match Status::Stopped {
+        Status::Active => 0,
+        Status::Paused => 0,
+        Status::Stopped => tag(Status::Stopped),
+        Status::Value(_) => 0,
+    };
+
+    // 1 + 5 + 6 + 10 + 30
+    __add(
+        __add(__add(tag(Status::Active), tag(Status::Paused)), stopped),
+        __add(tag(Status::Value(30)), value),
+    )
+}
@@ -0,0 +1,23 @@
+script;
+
+struct Empty {}
+
+// The values of `Nothing` take no space, so nothing is stored for them.
+enum Value {
+    Nothing: Empty,
+    Number: u64,
+    Unit: (),
+}
+
+fn value_of(value: Value) -> u64 {
+    match value {
+        Value::Nothing(_) => 1,
+        Value::Number(n) => n,
+        Value::Unit => 3,
+    }
+}
+
+fn main() -> u64 {
+    let values = [Value::Nothing(Empty {}), Value::Number(38), Value::Unit];
+    value_of(values[0]) + value_of(values[1]) + value_of(values[2])
+}
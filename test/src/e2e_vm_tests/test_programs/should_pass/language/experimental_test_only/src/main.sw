@@ -0,0 +1,16 @@
+script;
+
+// The package opts into the feature, so only the items gated on it being enabled are compiled.
+#[cfg(experimental_test_only = true)]
+fn value() -> u64 {
+    1
+}
+
+#[cfg(experimental_test_only = false)]
+fn value() -> u64 {
+    0
+}
+
+fn main() -> u64 {
+    value()
+}
@@ -61,6 +61,39 @@ fn test_try_from() {
     assert(x.unwrap() == 42);
 }
 
+impl<T> SomeStruct<T> {
+    fn new(a: T) -> Self {
+        SomeStruct { a }
+    }
+
+    fn with(self, a: T) -> Self {
+        SomeStruct { a }
+    }
+
+    fn get(self) -> T {
+        self.a
+    }
+}
+
+fn wrap<T>(a: T) -> SomeStruct<T> {
+    SomeStruct { a }
+}
+
+fn expected_type_test() {
+    // The expected type binds the type parameters before the arguments are type checked.
+    let a: SomeStruct<u8> = wrap(255);
+    assert(a.a == 255u8);
+
+    let b: SomeStruct<u16> = SomeStruct::new(1).with(65535);
+    assert(b.a == 65535u16);
+
+    let c: Option<u32> = Some(wrap(7).get());
+    assert(c.unwrap() == 7u32);
+
+    let d: SomeStruct<Vec<u8>> = wrap(Vec::new());
+    assert(d.get().len() == 0);
+}
+
 fn main() {
     let _ = sell_product();
     simple_vec_test();
@@ -68,6 +101,7 @@ fn main() {
     simple_option_generics_test();
     test_assert_eq_u64();
     test_try_from();
+    expected_type_test();
 
     // Suppress DCA warnings.
     CustomType { name: "" }.use_me();
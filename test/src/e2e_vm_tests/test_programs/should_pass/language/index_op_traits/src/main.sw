@@ -0,0 +1,48 @@
+script;
+
+struct Pair {
+    first: u64,
+    second: u64,
+}
+
+impl Index<bool, u64> for Pair {
+    fn index(self, index: bool) -> u64 {
+        if index { self.second } else { self.first }
+    }
+}
+
+impl IndexMut<bool, u64> for Pair {
+    fn index_assign(ref mut self, index: bool, value: u64) {
+        if index {
+            self.second = value;
+        } else {
+            self.first = value;
+        }
+    }
+}
+
+fn main() -> bool {
+    let mut pair = Pair {
+        first: 1,
+        second: 2,
+    };
+    assert(pair[false] == 1);
+    assert(pair[true] == 2);
+    pair[false] = 3;
+    pair[true] += 5;
+    assert(pair.first == 3 && pair.second == 7);
+
+    let mut vec = Vec::new();
+    vec.push(10);
+    vec.push(20);
+    vec[1] = vec[0] + vec[1];
+    vec[0] *= 2;
+    assert(vec[0] == 20 && vec[1] == 30);
+
+    // static arrays are still indexed directly
+    let mut array = [1, 2, 3];
+    array[2] = array[0] + array[1];
+    assert(array[2] == 3);
+
+    true
+}
@@ -0,0 +1,70 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Circle: u64,
+    Rect: Point,
+}
+
+fn unwrap_or_zero(value: Option<u64>) -> u64 {
+    let Some(x) = value else {
+        return 0;
+    };
+    x
+}
+
+fn rect_area(shape: Shape) -> u64 {
+    let Shape::Rect(Point { x, y: height }) = shape else {
+        return 0;
+    };
+    x * height
+}
+
+fn increment(value: Option<u64>) -> u64 {
+    let Some(mut x) = value else {
+        return 0;
+    };
+    x += 1;
+    x
+}
+
+fn sum_until_none(values: Vec<Option<u64>>) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let Some(value) = values.get(i).unwrap() else {
+            break;
+        };
+        i += 1;
+        let (a, 1) = (value, value % 2) else {
+            continue;
+        };
+        sum += a;
+    }
+    sum
+}
+
+fn main() -> bool {
+    assert_eq(unwrap_or_zero(Some(42)), 42);
+    assert_eq(unwrap_or_zero(None), 0);
+
+    assert_eq(rect_area(Shape::Rect(Point { x: 2, y: 3 })), 6);
+    assert_eq(rect_area(Shape::Circle(5)), 0);
+
+    assert_eq(increment(Some(1)), 2);
+    assert_eq(increment(None), 0);
+
+    let mut values = Vec::new();
+    values.push(Some(1));
+    values.push(Some(2));
+    values.push(Some(3));
+    values.push(None);
+    values.push(Some(5));
+    assert_eq(sum_until_none(values), 4);
+
+    true
+}
@@ -0,0 +1,43 @@
+script;
+
+// Traits named like the marker traits of `core::marker`, which shadow them. Their constraints are
+// satisfied by their `impl` blocks, not by the compiler.
+trait FixedSize {
+    fn fixed_size(self) -> u64;
+}
+
+trait Encodable {
+    fn encoded_size(self) -> u64;
+}
+
+// Not `core::marker::FixedSize`, as it holds a pointer.
+struct Buffer {
+    ptr: raw_ptr,
+    len: u64,
+}
+
+impl FixedSize for Buffer {
+    fn fixed_size(self) -> u64 {
+        let _ptr = self.ptr;
+        8 + self.len
+    }
+}
+
+impl Encodable for Buffer {
+    fn encoded_size(self) -> u64 {
+        self.len
+    }
+}
+
+fn fixed_size<T>(value: T) -> u64 where T: FixedSize {
+    value.fixed_size()
+}
+
+fn encoded_size<T>(value: T) -> u64 where T: Encodable {
+    value.encoded_size()
+}
+
+fn main() -> u64 {
+    let buffer = Buffer { ptr: asm() { zero: raw_ptr }, len: 2 };
+    fixed_size(buffer) + encoded_size(buffer)
+}
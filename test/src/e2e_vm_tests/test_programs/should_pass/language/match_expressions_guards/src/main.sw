@@ -0,0 +1,50 @@
+script;
+
+enum Shape {
+    Circle: u64,
+    Rect: (u64, u64),
+}
+
+fn classify(value: Option<u64>) -> u64 {
+    match value {
+        Some(x) if x > 100 => 3,
+        Some(x) if x > 10 => 2,
+        Some(_) => 1,
+        None => 0,
+    }
+}
+
+// The guards see the variables of their alternative of an or-pattern.
+fn side(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(s) | Shape::Rect((s, _)) if s % 2 == 0 => s / 2,
+        Shape::Rect((a, b)) if a == b => a,
+        _ => 0,
+    }
+}
+
+// A guard on a catch-all arm doesn't make the subsequent arms unreachable.
+fn with_guarded_catch_all(value: u64, limit: u64) -> u64 {
+    match value {
+        x if x < limit => x,
+        _ => limit,
+    }
+}
+
+fn main() -> bool {
+    assert_eq(classify(Some(1000)), 3);
+    assert_eq(classify(Some(11)), 2);
+    assert_eq(classify(Some(10)), 1);
+    assert_eq(classify(None), 0);
+
+    assert_eq(side(Shape::Circle(8)), 4);
+    assert_eq(side(Shape::Rect((6, 1))), 3);
+    assert_eq(side(Shape::Rect((3, 3))), 3);
+    assert_eq(side(Shape::Rect((3, 5))), 0);
+    assert_eq(side(Shape::Circle(3)), 0);
+
+    assert_eq(with_guarded_catch_all(3, 5), 3);
+    assert_eq(with_guarded_catch_all(7, 5), 5);
+
+    true
+}
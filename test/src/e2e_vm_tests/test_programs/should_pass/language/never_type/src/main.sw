@@ -0,0 +1,37 @@
+script;
+
+fn fail(code: u64) -> ! {
+    revert(code)
+}
+
+fn unwrap_or_fail(value: Option<u64>) -> u64 {
+    match value {
+        Some(value) => value,
+        None => fail(1),
+    }
+}
+
+fn sum_until_zero(values: [u64; 4]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < 4 {
+        let value = if values[i] == 0 { break } else { values[i] };
+        sum += value;
+        i += 1;
+    }
+    sum
+}
+
+fn early_return(value: bool) -> u64 {
+    let x: u64 = if value { return 42; } else { 1 };
+    x
+}
+
+fn main() -> bool {
+    assert(unwrap_or_fail(Some(5)) == 5);
+    assert(sum_until_zero([1, 2, 0, 4]) == 3);
+    assert(early_return(true) == 42);
+    assert(early_return(false) == 1);
+
+    true
+}
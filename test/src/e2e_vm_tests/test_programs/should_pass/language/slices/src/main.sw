@@ -0,0 +1,34 @@
+script;
+
+fn sum(values: &[u64]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < values.len() {
+        sum += values[i];
+        i += 1;
+    }
+    sum
+}
+
+fn main() -> bool {
+    let array = [1, 2, 3, 4, 5];
+    let slice: &[u64] = array[1..4];
+    assert(slice.len() == 3);
+    assert(slice[0] == 2 && slice[2] == 4);
+    assert(sum(slice) == 9);
+
+    // slicing a slice doesn't copy its elements
+    let subslice = slice[1..3];
+    assert(subslice.ptr() == slice.ptr().add::<u64>(1));
+    assert(sum(subslice) == 7);
+    assert(slice[2..2].is_empty());
+
+    let mut vec = Vec::new();
+    vec.push(10);
+    vec.push(20);
+    vec.push(30);
+    assert(sum(vec[0..2]) == 30);
+    assert(sum(vec.as_slice()) == 60);
+
+    true
+}
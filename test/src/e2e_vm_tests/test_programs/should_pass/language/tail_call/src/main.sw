@@ -0,0 +1,32 @@
+script;
+
+// Deep enough to overflow the call stack if the recursion isn't turned into a loop.
+const DEPTH: u64 = 100_000;
+
+#[tail_call]
+fn sum(n: u64, acc: u64) -> u64 {
+    if n == 0 {
+        acc
+    } else {
+        sum(n - 1, acc + n)
+    }
+}
+
+#[tail_call]
+fn is_even(n: u64) -> bool {
+    if n == 0 {
+        return true;
+    }
+    if n == 1 {
+        return false;
+    }
+    return is_even(n - 2);
+}
+
+fn main() -> bool {
+    assert(sum(DEPTH, 0) == DEPTH * (DEPTH + 1) / 2);
+    assert(is_even(DEPTH));
+    assert(!is_even(DEPTH + 1));
+
+    true
+}
@@ -0,0 +1,63 @@
+script;
+
+trait Shape {
+    fn sides(self) -> u64;
+    fn side_len(self) -> u64;
+} {
+    // Calls a method declared after it.
+    fn describe(self) -> u64 {
+        self.perimeter() * 100 + Self::sides_of(self)
+    }
+
+    fn perimeter(self) -> u64 {
+        self.sides() * self.side_len()
+    }
+
+    fn sides_of(shape: Self) -> u64 {
+        shape.sides()
+    }
+
+    fn is_larger_than(self, other: Self) -> bool {
+        self.perimeter() > other.perimeter()
+    }
+}
+
+struct Triangle {
+    len: u64,
+}
+
+struct Square {
+    len: u64,
+}
+
+impl Shape for Triangle {
+    fn sides(self) -> u64 {
+        3
+    }
+
+    fn side_len(self) -> u64 {
+        self.len
+    }
+}
+
+impl Shape for Square {
+    fn sides(self) -> u64 {
+        4
+    }
+
+    fn side_len(self) -> u64 {
+        self.len
+    }
+}
+
+fn main() -> bool {
+    let triangle = Triangle { len: 2 };
+    let square = Square { len: 2 };
+
+    assert(triangle.describe() == 603);
+    assert(square.describe() == 804);
+    assert(Square { len: 3 }.is_larger_than(square));
+    assert(!Triangle { len: 1 }.is_larger_than(triangle));
+
+    true
+}
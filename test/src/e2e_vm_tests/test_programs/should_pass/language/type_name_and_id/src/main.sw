@@ -0,0 +1,45 @@
+script;
+
+use std::intrinsics::{type_id, type_name};
+
+struct Foo {}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+enum Bar {
+    A: (),
+}
+
+type Alias = Wrapper<u64>;
+
+fn name_of_val<T>(_val: T) -> str {
+    __type_name::<T>()
+}
+
+fn main() -> bool {
+    let _ = Foo {};
+    let _ = Bar::A;
+
+    assert(__type_name::<u64>() == "u64");
+    assert(__type_name::<bool>() == "bool");
+    assert(__type_name::<b256>() == "b256");
+    assert(__type_name::<str[3]>() == "str[3]");
+    assert(__type_name::<(u8, bool)>() == "(u8, bool)");
+    assert(__type_name::<[u16; 2]>() == "[u16; 2]");
+    assert(__type_name::<Foo>() == "type_name_and_id::Foo");
+    assert(__type_name::<Bar>() == "type_name_and_id::Bar");
+    assert(__type_name::<Wrapper<Foo>>() == "type_name_and_id::Wrapper<type_name_and_id::Foo>");
+    assert(__type_name::<Alias>() == "type_name_and_id::Wrapper<u64>");
+    assert(type_name::<Option<u8>>() == "std::option::Option<u8>");
+    assert(name_of_val(Wrapper { value: true }) == "type_name_and_id::Wrapper<bool>");
+
+    assert(__type_id::<u64>() == __type_id::<u64>());
+    assert(__type_id::<Alias>() == __type_id::<Wrapper<u64>>());
+    assert(__type_id::<u64>() != __type_id::<u8>());
+    assert(type_id::<Wrapper<u8>>() != type_id::<Wrapper<u16>>());
+    assert(type_id::<Foo>() != type_id::<Bar>());
+
+    true
+}
@@ -0,0 +1,58 @@
+script;
+
+use core::marker::PhantomData;
+
+struct Unused<T> {
+    value: u64,
+}
+
+enum UnusedInVariants<T> {
+    A: u64,
+    B: (),
+}
+
+struct Handle<T> {
+    id: u64,
+    #[allow(dead_code)]
+    marker: PhantomData<T>,
+}
+
+struct Marker<T> {}
+
+#[allow(unused_type_parameters)]
+struct Allowed<T> {
+    value: u64,
+}
+
+fn never_used<T>() -> u64 {
+    42
+}
+
+fn used_in_body<T>() -> u64 {
+    __size_of::<T>()
+}
+
+fn handle<T>(id: u64) -> Handle<T> {
+    Handle {
+        id,
+        marker: PhantomData::new(),
+    }
+}
+
+impl<T> Handle<T> {
+    fn id<U>(self) -> u64 {
+        self.id
+    }
+}
+
+fn main() -> u64 {
+    let unused: Unused<u8> = Unused { value: 1 };
+    let variants: UnusedInVariants<u8> = UnusedInVariants::B;
+    let _marker: Marker<u8> = Marker {};
+    let allowed: Allowed<u8> = Allowed { value: 2 };
+    let variant_value = match variants {
+        UnusedInVariants::A(value) => value,
+        UnusedInVariants::B => 0,
+    };
+    unused.value + variant_value + allowed.value + never_used::<u8>() + used_in_body::<u16>() + handle::<bool>(3).id::<u32>()
+}
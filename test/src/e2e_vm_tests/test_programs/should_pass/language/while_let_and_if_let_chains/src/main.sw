@@ -0,0 +1,71 @@
+script;
+
+enum Node {
+    Leaf: u64,
+    Branch: (u64, u64),
+}
+
+fn pop(ref mut stack: Vec<u64>) -> Option<u64> {
+    stack.pop()
+}
+
+fn sum_while_let() -> u64 {
+    let mut stack = Vec::new();
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+
+    let mut sum = 0;
+    while let Some(value) = pop(stack) {
+        sum += value;
+    }
+    sum
+}
+
+fn sum_while_let_chain() -> u64 {
+    let mut stack = Vec::new();
+    stack.push(1);
+    stack.push(20);
+    stack.push(3);
+
+    // Stops at the first value not below 10, leaving `1` on the stack.
+    let mut sum = 0;
+    while let Some(value) = pop(stack) && value < 10 {
+        sum += value;
+    }
+    sum + stack.len() * 100
+}
+
+fn leaf_sum(a: Node, b: Option<Node>) -> u64 {
+    if let Node::Leaf(x) = a && let Some(Node::Leaf(y)) = b && x < y {
+        x + y
+    } else if let Node::Branch((x, y)) = a && true {
+        x * y
+    } else {
+        0
+    }
+}
+
+fn first_value(node: Node) -> u64 {
+    if let Node::Leaf(x) | Node::Branch((x, _)) = node && x > 0 {
+        x
+    } else {
+        0
+    }
+}
+
+fn main() -> bool {
+    assert_eq(sum_while_let(), 6);
+    assert_eq(sum_while_let_chain(), 103);
+
+    assert_eq(leaf_sum(Node::Leaf(1), Some(Node::Leaf(2))), 3);
+    assert_eq(leaf_sum(Node::Leaf(2), Some(Node::Leaf(1))), 0);
+    assert_eq(leaf_sum(Node::Leaf(1), None), 0);
+    assert_eq(leaf_sum(Node::Branch((3, 4)), None), 12);
+
+    assert_eq(first_value(Node::Leaf(5)), 5);
+    assert_eq(first_value(Node::Branch((7, 1))), 7);
+    assert_eq(first_value(Node::Branch((0, 1))), 0);
+
+    true
+}
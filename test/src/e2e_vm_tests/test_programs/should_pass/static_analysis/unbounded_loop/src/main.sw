@@ -0,0 +1,64 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+abi TestAbi {
+    fn sum_up_to(n: u64) -> u64;
+    #[storage(read)]
+    fn sum_balances() -> u64;
+    #[storage(read)]
+    fn sum_first_balances() -> u64;
+    #[storage(read)]
+    fn sum_balances_allowed() -> u64;
+}
+
+storage {
+    balances: StorageVec<u64> = StorageVec {},
+}
+
+impl TestAbi for Contract {
+    fn sum_up_to(n: u64) -> u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while i < n {
+            sum += i;
+            i += 1;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_balances() -> u64 {
+        let len = storage.balances.len();
+        let mut sum = 0;
+        let mut i = 0;
+        while i < len {
+            sum += storage.balances.get(i).unwrap().read();
+            i += 1;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_first_balances() -> u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while i < 10 {
+            sum += storage.balances.get(i).unwrap().read();
+            i += 1;
+        }
+        sum
+    }
+
+    #[allow(unbounded_loop)]
+    #[storage(read)]
+    fn sum_balances_allowed() -> u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while i < storage.balances.len() {
+            sum += storage.balances.get(i).unwrap().read();
+            i += 1;
+        }
+        sum
+    }
+}
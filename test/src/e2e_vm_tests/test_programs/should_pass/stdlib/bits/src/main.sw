@@ -0,0 +1,48 @@
+script;
+
+use std::bits::*;
+
+enum Role {
+    Minter: (),
+    Burner: (),
+    Pauser: (),
+}
+
+impl Flag for Role {
+    fn bit(self) -> u64 {
+        match self {
+            Role::Minter => 0,
+            Role::Burner => 1,
+            Role::Pauser => 63,
+        }
+    }
+}
+
+fn main() -> bool {
+    assert(0xf0f0u64.count_ones() == 8);
+    assert(0xffu8.count_ones() == 8);
+    assert(0x00ffu16.leading_zeros() == 8);
+    assert(0x0000_8000u32.leading_zeros() == 16);
+    assert(0x1234_5678u32.rotate_left(8) == 0x3456_7812);
+    assert(0x1234_5678u32.rotate_right(8) == 0x7812_3456);
+
+    let mut roles: Bitflags<Role> = Bitflags::new();
+    assert(roles.is_empty());
+
+    roles.insert(Role::Minter);
+    roles.insert(Role::Pauser);
+    assert(roles.contains(Role::Minter));
+    assert(!roles.contains(Role::Burner));
+    assert(roles.contains(Role::Pauser));
+    assert(roles.bits() == 0x8000_0000_0000_0001);
+
+    roles.remove(Role::Minter);
+    roles.toggle(Role::Burner);
+    assert(roles == Bitflags::from_bits(0x8000_0000_0000_0002));
+
+    roles.toggle(Role::Burner);
+    roles.remove(Role::Pauser);
+    assert(roles.is_empty());
+
+    true
+}
@@ -0,0 +1,41 @@
+script;
+
+use std::{
+    bytes::Bytes,
+    constants::BASE_ASSET_ID,
+    low_level_call::{call, CallError, CallParams},
+};
+
+fn selector_length_error(selector: Bytes) -> Option<u64> {
+    let target = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+    let call_params = CallParams {
+        coins: 0,
+        asset_id: BASE_ASSET_ID,
+        gas: 1_000,
+    };
+    match call::<u64, u64>(target, selector, 42, call_params) {
+        Result::Err(CallError::InvalidSelectorLength(length)) => Option::Some(length),
+        _ => Option::None,
+    }
+}
+
+// Selectors which are not one word long are rejected before making any call.
+fn main() -> bool {
+    assert(selector_length_error(Bytes::new()) == Option::Some(0));
+
+    let mut selector = Bytes::new();
+    selector.push(0x98);
+    selector.push(0x90);
+    selector.push(0xae);
+    selector.push(0xf4);
+    assert(selector_length_error(selector) == Option::Some(4));
+
+    let mut i = 0;
+    while i < 5 {
+        selector.push(0);
+        i += 1;
+    }
+    assert(selector_length_error(selector) == Option::Some(9));
+
+    true
+}
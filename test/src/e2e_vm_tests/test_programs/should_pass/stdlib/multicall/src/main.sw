@@ -0,0 +1,33 @@
+script;
+
+use std::{bytes::Bytes, constants::BASE_ASSET_ID, low_level_call::CallParams, multicall::Multicall};
+
+fn call_params(gas: u64) -> CallParams {
+    CallParams {
+        coins: 0,
+        asset_id: BASE_ASSET_ID,
+        gas,
+    }
+}
+
+fn main() -> bool {
+    let target = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+
+    let mut multicall = Multicall::new();
+    assert(multicall.is_empty());
+    assert(multicall.len() == 0);
+    assert(multicall.gas() == 0);
+
+    // Executing an empty batch makes no call.
+    let results = multicall.execute();
+    assert(results.len() == 0);
+
+    // The gas of the calls adds up.
+    multicall.add(target, 0x9890aef4, Bytes::new(), false, call_params(1_000));
+    multicall.add(target, 0x2f3a1b2c, Bytes::from(encode(42u64)), true, call_params(2_000));
+    assert(!multicall.is_empty());
+    assert(multicall.len() == 2);
+    assert(multicall.gas() == 3_000);
+
+    true
+}
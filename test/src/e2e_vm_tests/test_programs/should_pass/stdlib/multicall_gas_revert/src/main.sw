@@ -0,0 +1,18 @@
+script;
+
+use std::{bytes::Bytes, constants::BASE_ASSET_ID, low_level_call::CallParams, multicall::Multicall};
+
+fn main() {
+    let target = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+    let call_params = CallParams {
+        coins: 0,
+        asset_id: BASE_ASSET_ID,
+        gas: u64::max(),
+    };
+
+    let mut multicall = Multicall::new();
+    multicall.add(target, 0x9890aef4, Bytes::new(), false, call_params);
+
+    // The batch forwards more gas than remains, so it reverts before making any call.
+    let _ = multicall.execute();
+}
@@ -0,0 +1,57 @@
+script;
+
+enum Error {
+    NotFound: (),
+    Invalid: (),
+}
+
+fn test_option() {
+    let some: Option<u64> = Some(42);
+    let none: Option<u64> = None;
+
+    assert(some.expect("some is missing") == 42);
+
+    assert(some.and(Some(true)) == Some(true));
+    assert(none.and(Some(true)).is_none());
+    let no_bool: Option<bool> = None;
+    assert(some.and(no_bool).is_none());
+
+    assert(some.or(Some(100)) == Some(42));
+    assert(none.or(Some(100)) == Some(100));
+    assert(none.or(None).is_none());
+}
+
+fn test_result() {
+    let ok: Result<u64, Error> = Ok(42);
+    let err: Result<u64, Error> = Err(Error::NotFound);
+
+    assert(ok.expect("ok is not ok") == 42);
+
+    assert(ok.ok() == Some(42));
+    assert(err.ok().is_none());
+    assert(ok.err().is_none());
+    match err.err() {
+        Some(Error::NotFound) => (),
+        _ => revert(0),
+    }
+
+    let other: Result<bool, Error> = Ok(true);
+    assert(ok.and(other).unwrap());
+    assert(err.and(other).is_err());
+
+    let fallback: Result<u64, Error> = Ok(100);
+    assert(ok.or(fallback).unwrap() == 42);
+    assert(err.or(fallback).unwrap() == 100);
+    let invalid: Result<u64, Error> = Err(Error::Invalid);
+    match err.or(invalid) {
+        Err(Error::Invalid) => (),
+        _ => revert(0),
+    }
+}
+
+fn main() -> bool {
+    test_option();
+    test_result();
+
+    true
+}
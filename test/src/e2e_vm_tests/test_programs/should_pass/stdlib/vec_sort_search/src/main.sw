@@ -0,0 +1,112 @@
+script;
+
+use std::vec::{Predicate, SortKey};
+
+struct Order {
+    price: u64,
+    amount: u64,
+}
+
+struct ByPrice {}
+
+impl SortKey<Order, u64> for ByPrice {
+    fn sort_key(self, element: Order) -> u64 {
+        element.price
+    }
+}
+
+struct AmountAtLeast {
+    min: u64,
+}
+
+impl Predicate<Order> for AmountAtLeast {
+    fn evaluate(self, element: Order) -> bool {
+        element.amount >= self.min
+    }
+}
+
+fn test_sort() {
+    let mut vec: Vec<u64> = Vec::new();
+    vec.sort();
+    assert(vec.is_empty());
+
+    let mut i = 0;
+    while i < 20 {
+        // A permutation of 0..20.
+        vec.push((i * 7) % 20);
+        i += 1;
+    }
+    vec.sort();
+    i = 0;
+    while i < 20 {
+        assert(vec.get(i).unwrap() == i);
+        i += 1;
+    }
+}
+
+fn test_binary_search() {
+    let mut vec: Vec<u64> = Vec::new();
+    match vec.binary_search(1) {
+        Ok(_) => revert(0),
+        Err(index) => assert(index == 0),
+    }
+
+    vec.push(10);
+    vec.push(20);
+    vec.push(30);
+    assert(vec.binary_search(10).unwrap() == 0);
+    assert(vec.binary_search(30).unwrap() == 2);
+    match vec.binary_search(25) {
+        Ok(_) => revert(0),
+        Err(index) => assert(index == 2),
+    }
+    match vec.binary_search(35) {
+        Ok(_) => revert(0),
+        Err(index) => assert(index == 3),
+    }
+}
+
+fn test_dedup() {
+    let mut vec: Vec<u64> = Vec::new();
+    vec.push(1);
+    vec.push(1);
+    vec.push(2);
+    vec.push(2);
+    vec.push(2);
+    vec.push(1);
+    vec.dedup();
+    assert(vec.len() == 3);
+    assert(vec.get(0).unwrap() == 1);
+    assert(vec.get(1).unwrap() == 2);
+    assert(vec.get(2).unwrap() == 1);
+}
+
+fn test_sort_by_key_and_retain() {
+    let mut orders: Vec<Order> = Vec::new();
+    orders.push(Order { price: 30, amount: 1 });
+    orders.push(Order { price: 10, amount: 5 });
+    orders.push(Order { price: 20, amount: 3 });
+    orders.push(Order { price: 40, amount: 7 });
+
+    orders.sort_by_key::<ByPrice, u64>(ByPrice {});
+    assert(orders.get(0).unwrap().price == 10);
+    assert(orders.get(1).unwrap().price == 20);
+    assert(orders.get(2).unwrap().price == 30);
+    assert(orders.get(3).unwrap().price == 40);
+    assert(orders.get(3).unwrap().amount == 7);
+
+    orders.retain(AmountAtLeast { min: 3 });
+    assert(orders.len() == 3);
+    assert(orders.get(0).unwrap().price == 10);
+    assert(orders.get(1).unwrap().price == 20);
+    assert(orders.get(2).unwrap().price == 40);
+}
+
+fn main() -> bool {
+    test_sort();
+    test_binary_search();
+    test_dedup();
+    test_sort_by_key_and_retain();
+
+    true
+}
@@ -0,0 +1,104 @@
+contract;
+
+use std::storage::storage_api::{read_slots, write_slots};
+
+// 40 bytes, so each value spans two slots and the last slot written is only partially filled.
+struct Wide {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+    e: u64,
+}
+
+impl Eq for Wide {
+    fn eq(self, other: Self) -> bool {
+        self.a == other.a
+            && self.b == other.b
+            && self.c == other.c
+            && self.d == other.d
+            && self.e == other.e
+    }
+}
+
+fn wide(n: u64) -> Wide {
+    Wide {
+        a: n,
+        b: n + 1,
+        c: n + 2,
+        d: n + 3,
+        e: n + 4,
+    }
+}
+
+abi StorageSlots {
+    #[storage(read, write)]
+    fn write_wide(slot: b256, count: u64, first: u64);
+
+    #[storage(read)]
+    fn read_wide(slot: b256, count: u64, index: u64) -> Option<Wide>;
+}
+
+impl StorageSlots for Contract {
+    #[storage(read, write)]
+    fn write_wide(slot: b256, count: u64, first: u64) {
+        let mut values = Vec::new();
+        let mut i = 0;
+        while i < count {
+            values.push(wide(first + i * 10));
+            i += 1;
+        }
+        write_slots(slot, values);
+    }
+
+    #[storage(read)]
+    fn read_wide(slot: b256, count: u64, index: u64) -> Option<Wide> {
+        match read_slots::<Wide>(slot, count) {
+            Some(values) => {
+                assert(values.len() == count);
+                values.get(index)
+            },
+            None => None,
+        }
+    }
+}
+
+const SLOT_A: b256 = 0x000000000000000000000000000000000000000000000000000000000000000a;
+const SLOT_B: b256 = 0x000000000000000000000000000000000000000000000000000000000000000b;
+const SLOT_C: b256 = 0x000000000000000000000000000000000000000000000000000000000000000c;
+
+#[test]
+fn values_spanning_several_slots() {
+    let caller = abi(StorageSlots, CONTRACT_ID);
+    // 120 bytes, the last of the four slots being filled with 24 of them.
+    caller.write_wide(SLOT_A, 3, 100);
+
+    assert(caller.read_wide(SLOT_A, 3, 0).unwrap() == wide(100));
+    assert(caller.read_wide(SLOT_A, 3, 1).unwrap() == wide(110));
+    assert(caller.read_wide(SLOT_A, 3, 2).unwrap() == wide(120));
+    assert(caller.read_wide(SLOT_A, 1, 0).unwrap() == wide(100));
+}
+
+#[test]
+fn overwriting_with_fewer_values() {
+    let caller = abi(StorageSlots, CONTRACT_ID);
+    caller.write_wide(SLOT_B, 3, 100);
+    caller.write_wide(SLOT_B, 1, 200);
+
+    // The rest of the last slot written, holding the start of the second value, is preserved,
+    // along with the slots after it.
+    assert(caller.read_wide(SLOT_B, 3, 0).unwrap() == wide(200));
+    assert(caller.read_wide(SLOT_B, 3, 1).unwrap() == wide(110));
+    assert(caller.read_wide(SLOT_B, 3, 2).unwrap() == wide(120));
+}
+
+#[test]
+fn reading_unset_slots() {
+    let caller = abi(StorageSlots, CONTRACT_ID);
+    assert(caller.read_wide(SLOT_C, 1, 0).is_none());
+
+    // Two values need three slots, the third of which is unset.
+    caller.write_wide(SLOT_C, 1, 100);
+    assert(caller.read_wide(SLOT_C, 1, 0).unwrap() == wide(100));
+    assert(caller.read_wide(SLOT_C, 2, 0).is_none());
+}
@@ -0,0 +1,67 @@
+contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment() -> u64;
+
+    #[storage(read)]
+    fn count() -> u64;
+}
+
+storage {
+    count: u64 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() -> u64 {
+        let count = storage.count.read() + 1;
+        storage.count.write(count);
+        count
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.read()
+    }
+}
+
+#[before_each]
+fn increment_once() {
+    let counter = abi(Counter, CONTRACT_ID);
+    let _ = counter.increment();
+}
+
+#[before_each]
+fn increment_twice() {
+    let counter = abi(Counter, CONTRACT_ID);
+    let _ = counter.increment();
+}
+
+#[after_each]
+fn check_count() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.count() >= 2);
+}
+
+// Every test starts with freshly deployed storage, so the count only includes the increments of
+// the `#[before_each]` functions.
+#[test]
+fn test_count_after_setup() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.count() == 2);
+}
+
+#[test]
+fn test_increment_after_setup() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 3)
+}
+
+#[test]
+fn test_count_is_isolated() {
+    let counter = abi(Counter, CONTRACT_ID);
+    let _ = counter.increment();
+    let _ = counter.increment();
+    assert(counter.count() == 4);
+}
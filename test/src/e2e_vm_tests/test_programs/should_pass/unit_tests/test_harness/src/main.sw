@@ -0,0 +1,82 @@
+library;
+
+use std::{constants::BASE_ASSET_ID, inputs::input_coin_owner, test_harness::*};
+
+#[test]
+fn test_wallets() {
+    let alice = new_wallet(BASE_ASSET_ID, 100);
+    let bob = new_wallet(BASE_ASSET_ID, 0);
+    assert(alice != bob);
+    assert(wallet_balance(alice, BASE_ASSET_ID) == 100);
+
+    assert(wallet_transfer(alice, bob, BASE_ASSET_ID, 40));
+    assert(wallet_balance(alice, BASE_ASSET_ID) == 60);
+    assert(wallet_balance(bob, BASE_ASSET_ID) == 40);
+
+    assert(!wallet_transfer(bob, alice, BASE_ASSET_ID, 41));
+    assert(wallet_balance(bob, BASE_ASSET_ID) == 40);
+}
+
+#[test]
+fn test_blocks() {
+    let height = block_height();
+    let timestamp = block_timestamp();
+    advance_blocks(10, 2);
+    assert(block_height() == height + 10);
+    assert(block_timestamp() == timestamp + 20);
+}
+
+#[test]
+fn test_block_producer() {
+    let producer = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+    set_block_producer(producer);
+    assert(block_producer() == producer);
+}
+
+#[test]
+fn test_receipts() {
+    let len = receipts_len();
+    log(42);
+    assert(receipts_len() == len + 1);
+    assert(receipt_kind(len) == ReceiptKind::Log);
+}
+
+#[test]
+fn test_storage_slots() {
+    let contract = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000002);
+    let key = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    let value = 0x000000000000000000000000000000000000000000000000000000000000002a;
+    assert(load_slot(contract, key).is_none());
+    store_slot(contract, key, value);
+    assert(load_slot(contract, key).unwrap() == value);
+}
+
+#[test]
+fn test_prank() {
+    let owner = input_coin_owner(0).unwrap();
+    let alice = Address::from(0x0000000000000000000000000000000000000000000000000000000000000003);
+    prank(alice);
+    assert(input_coin_owner(0).unwrap() == alice);
+    stop_prank();
+    assert(input_coin_owner(0).unwrap() == owner);
+}
+
+struct Transfer {
+    amount: u64,
+}
+
+#[test]
+fn test_events() {
+    log(Transfer { amount: 10 });
+    log(7);
+    assert_emitted(Transfer { amount: 10 });
+    assert_emitted(7);
+    assert(!is_emitted(Transfer { amount: 5 }));
+    assert(!is_emitted(8));
+}
+
+#[test(should_revert = "18446744073709486087")]
+fn test_missing_event() {
+    log(Transfer { amount: 10 });
+    assert_emitted(Transfer { amount: 5 });
+}
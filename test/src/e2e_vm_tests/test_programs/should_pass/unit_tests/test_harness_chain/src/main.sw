@@ -0,0 +1,123 @@
+contract;
+
+use std::{
+    block::{height, timestamp},
+    constants::{BASE_ASSET_ID, ZERO_B256},
+    context::{msg_amount, this_balance},
+    test_harness::*,
+};
+
+abi Chain {
+    fn block() -> (u32, u64);
+
+    fn producer() -> ContractId;
+
+    #[payable]
+    fn deposit() -> u64;
+
+    fn balance() -> u64;
+
+    #[storage(read, write)]
+    fn record_block();
+
+    #[storage(read)]
+    fn recorded_block() -> (u32, u64);
+}
+
+storage {
+    height: u32 = 0,
+    timestamp: u64 = 0,
+}
+
+impl Chain for Contract {
+    fn block() -> (u32, u64) {
+        (height(), timestamp())
+    }
+
+    fn producer() -> ContractId {
+        let mut producer = ZERO_B256;
+        asm(producer: __addr_of(producer)) {
+            cb producer;
+        };
+        ContractId::from(producer)
+    }
+
+    #[payable]
+    fn deposit() -> u64 {
+        msg_amount()
+    }
+
+    fn balance() -> u64 {
+        this_balance(BASE_ASSET_ID)
+    }
+
+    #[storage(read, write)]
+    fn record_block() {
+        storage.height.write(height());
+        storage.timestamp.write(timestamp());
+    }
+
+    #[storage(read)]
+    fn recorded_block() -> (u32, u64) {
+        (storage.height.read(), storage.timestamp.read())
+    }
+}
+
+#[test]
+fn test_block_producer() {
+    let producer = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+    set_block_producer(producer);
+    assert(abi(Chain, CONTRACT_ID).producer() == producer);
+}
+
+// The contract is paid with the coins of the wallet, which is the only owner of the base asset
+// among the inputs of the test transaction.
+#[test]
+fn test_wallet_coins() {
+    let alice = new_wallet(BASE_ASSET_ID, 100);
+    let chain = abi(Chain, CONTRACT_ID);
+    prank(alice);
+    let paid = chain.deposit {
+        coins: 40,
+        asset_id: BASE_ASSET_ID.into(),
+    }();
+    stop_prank();
+    assert(paid == 40);
+    assert(chain.balance() == 40);
+}
+
+#[test(should_revert)]
+fn test_no_wallet_coins() {
+    abi(Chain, CONTRACT_ID).deposit {
+        coins: 40,
+        asset_id: BASE_ASSET_ID.into(),
+    }();
+}
+
+// The test keeps executing in the block it started in, and the blocks it produces are seen by the
+// `shared_state` tests that follow it.
+#[test(shared_state)]
+fn test_advance_blocks() {
+    let chain = abi(Chain, CONTRACT_ID);
+    chain.record_block();
+    let (height, timestamp) = chain.block();
+    assert(height == block_height());
+    assert(timestamp == block_timestamp());
+
+    advance_blocks(10, 2);
+    assert(block_height() == height + 10);
+    assert(block_timestamp() == timestamp + 20);
+    let (current_height, current_timestamp) = chain.block();
+    assert(current_height == height);
+    assert(current_timestamp == timestamp);
+}
+
+#[test(shared_state)]
+fn test_advanced_blocks() {
+    let chain = abi(Chain, CONTRACT_ID);
+    let (height, timestamp) = chain.recorded_block();
+    let (current_height, current_timestamp) = chain.block();
+    assert(current_height == height + 10);
+    assert(current_timestamp == timestamp + 20);
+    assert(std::block::height() == current_height);
+}
@@ -0,0 +1,66 @@
+contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment() -> u64;
+
+    #[storage(read)]
+    fn count() -> u64;
+}
+
+storage {
+    count: u64 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() -> u64 {
+        let count = storage.count.read() + 1;
+        storage.count.write(count);
+        count
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.read()
+    }
+}
+
+// Every test starts with freshly deployed storage, however many of them increment the count.
+#[test]
+fn test_isolated_first() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 1);
+}
+
+#[test]
+fn test_isolated_second() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 1);
+}
+
+// The `shared_state` tests run in the order they're declared in, each seeing the storage left by
+// the previous one, but not the storage written by the other tests.
+#[test(shared_state)]
+fn test_shared_first() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 1);
+}
+
+#[test(shared_state)]
+fn test_shared_second() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment() == 2);
+}
+
+#[test]
+fn test_isolated_third() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.count() == 0);
+}
+
+#[test(shared_state)]
+fn test_shared_third() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.count() == 2);
+}
@@ -256,6 +256,7 @@ pub(super) async fn run(
                 let include_tests = true;
                 let mut ir = compile_program(typed_program, include_tests, &engines, sway_core::ExperimentalFlags {
                     new_encoding: experimental.new_encoding,
+                    ..Default::default()
                 })
                     .unwrap_or_else(|e| {
                         use sway_types::span::Spanned;
@@ -518,6 +519,8 @@ fn compile_core(build_target: BuildTarget, engines: &Engines) -> namespace::Modu
         disable_tests: false,
         locked: false,
         ipfs_node: None,
+        verbose_types: false,
+        storage_slots: false,
     };
 
     let res = match forc::test::forc_check::check(check_cmd, engines) {
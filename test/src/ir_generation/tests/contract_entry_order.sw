@@ -0,0 +1,54 @@
+// target-fuelvm
+
+contract;
+
+abi Second {
+    fn zebra() -> u64;
+    fn apple() -> u64;
+}
+
+abi First {
+    fn mango() -> u64;
+}
+
+impl Second for Contract {
+    fn zebra() -> u64 {
+        1
+    }
+
+    fn apple() -> u64 {
+        2
+    }
+}
+
+impl First for Contract {
+    fn mango() -> u64 {
+        3
+    }
+}
+
+// The entries are ordered by name, regardless of the order of their declarations.
+
+// ::check-ir::
+
+// check: contract {
+// check: fn apple<
+// check: fn mango<
+// check: fn zebra<
+
+// ::check-asm::
+
+// regex: REG=\$r\d+
+// regex: ID=[_[:alpha:]][_0-9[:alpha:]]*
+// regex: IMM=i\d+
+
+// check: lw   $(sel_reg=$REG) $$fp i73
+
+// check: load $(apple_sel_reg=$REG) $(apple_sel_data=$ID)
+// check: eq   $(eq_reg=$REG) $sel_reg $apple_sel_reg
+// check: load $(mango_sel_reg=$REG) $(mango_sel_data=$ID)
+// check: eq   $(eq_reg=$REG) $sel_reg $mango_sel_reg
+// check: load $(zebra_sel_reg=$REG) $(zebra_sel_data=$ID)
+// check: eq   $(eq_reg=$REG) $sel_reg $zebra_sel_reg
+
+// check: rvrt $$$$tmp
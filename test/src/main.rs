@@ -54,6 +54,15 @@ struct Cli {
     /// Experimental flag for new encoding
     #[arg(long)]
     experimental_new_encoding: bool,
+
+    /// Check the ABI JSON, storage slots and bytecode hash of compiled tests against their
+    /// snapshots
+    #[arg(long)]
+    snapshot: bool,
+
+    /// Write the snapshots of compiled tests instead of checking them
+    #[arg(long)]
+    bless: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +82,16 @@ pub struct RunConfig {
     pub verbose: bool,
     pub release: bool,
     pub experimental: ExperimentalFlags,
+    pub snapshot: Option<SnapshotMode>,
+}
+
+/// How the snapshots of compiled tests are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotMode {
+    /// Fail tests whose snapshots are missing or do not match.
+    Check,
+    /// Write the snapshots of tests.
+    Bless,
 }
 
 #[tokio::main]
@@ -103,6 +122,14 @@ async fn main() -> Result<()> {
         build_target,
         experimental: sway_core::ExperimentalFlags {
             new_encoding: cli.experimental_new_encoding,
+            ..Default::default()
+        },
+        snapshot: if cli.bless {
+            Some(SnapshotMode::Bless)
+        } else if cli.snapshot {
+            Some(SnapshotMode::Check)
+        } else {
+            None
         },
     };
 
@@ -19,8 +19,10 @@ mod hashing;
 mod logging;
 mod low_level_call;
 mod low_level_call_bytes;
+mod low_level_call_typed;
 mod messages;
 mod methods;
+mod multicall;
 mod option_field_order;
 mod option_in_abi;
 mod parsing_logs;
@@ -0,0 +1,15 @@
+script;
+
+use std::{bytes::Bytes, constants::BASE_ASSET_ID, low_level_call::{call, CallParams}};
+
+// Sets the `b256` value of the target contract and reads it back with typed low-level calls.
+fn main(target: ContractId, set_selector: Bytes, get_selector: Bytes, value: b256) -> b256 {
+    let call_params = CallParams {
+        coins: 0,
+        asset_id: BASE_ASSET_ID,
+        gas: 1_000_000,
+    };
+
+    call::<b256, ()>(target, set_selector, value, call_params).unwrap();
+    call::<(), b256>(target, get_selector, (), call_params).unwrap()
+}
@@ -0,0 +1,103 @@
+use fuel_vm::fuel_tx::{
+    output::contract::Contract as OutputContract, Bytes32, ContractId, Output, TxPointer, UtxoId,
+};
+use fuels::{
+    accounts::wallet::WalletUnlocked,
+    prelude::*,
+    types::{input::Input, Bits256},
+};
+
+macro_rules! fn_selector {
+    ( $fn_name: ident ( $($fn_arg: ty),* )  ) => {
+        u64::from_be_bytes(
+            ::fuels::core::codec::resolve_fn_selector(stringify!($fn_name), &[$( <$fn_arg as ::fuels::core::traits::Parameterize>::param_type() ),*])
+        )
+    }
+}
+
+abigen!(
+    Contract(
+        name = "TestContract",
+        abi =
+            "test_artifacts/low_level_callee_contract/out/release/low_level_callee_contract-abi.json"
+    ),
+    Script(
+        name = "TestScript",
+        abi = "test_projects/multicall/out/release/multicall-abi.json"
+    )
+);
+
+async fn get_contract_instance() -> (TestContract<WalletUnlocked>, ContractId, WalletUnlocked) {
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(
+            Some(1),             /* Single wallet */
+            Some(1),             /* Single coin (UTXO) */
+            Some(1_000_000_000), /* Amount per coin */
+        ),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let wallet = wallets.pop().unwrap();
+
+    let id = Contract::load_from(
+        "test_artifacts/low_level_callee_contract/out/release/low_level_callee_contract.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(&wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    let instance = TestContract::new(id.clone(), wallet.clone());
+
+    (instance, id.into(), wallet)
+}
+
+#[tokio::test]
+async fn executes_calls_in_order_and_returns_their_data() {
+    let (instance, id, wallet) = get_contract_instance().await;
+    let script_instance =
+        TestScript::new(wallet, "test_projects/multicall/out/release/multicall.bin");
+
+    let contract_input = Input::Contract {
+        utxo_id: UtxoId::new(Bytes32::zeroed(), 0),
+        balance_root: Bytes32::zeroed(),
+        state_root: Bytes32::zeroed(),
+        tx_pointer: TxPointer::default(),
+        contract_id: id,
+    };
+    let contract_output = Output::Contract(OutputContract {
+        input_index: 0u8,
+        balance_root: Bytes32::zeroed(),
+        state_root: Bytes32::zeroed(),
+    });
+
+    let value = Bits256([7u8; 32]);
+    let result = script_instance
+        .main(
+            id,
+            fn_selector!(set_b256_value(Bits256)),
+            fn_selector!(get_b256_value()),
+            value,
+        )
+        .with_inputs(vec![contract_input])
+        .with_outputs(vec![contract_output])
+        .with_tx_policies(TxPolicies::default())
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The second call read the value written by the first one.
+    assert_eq!(result, value);
+    let stored = instance
+        .methods()
+        .get_b256_value()
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(stored, value);
+}
@@ -0,0 +1,22 @@
+script;
+
+use std::{bytes::Bytes, constants::BASE_ASSET_ID, low_level_call::CallParams, multicall::Multicall};
+
+// Sets the `b256` value of the target contract and reads it back within a single batch.
+fn main(target: ContractId, set_selector: u64, get_selector: u64, value: b256) -> b256 {
+    let call_params = CallParams {
+        coins: 0,
+        asset_id: BASE_ASSET_ID,
+        gas: 1_000_000,
+    };
+
+    let mut multicall = Multicall::new();
+    multicall.add(target, set_selector, Bytes::from(encode(value)), false, call_params);
+    multicall.add(target, get_selector, Bytes::new(), false, call_params);
+    assert(multicall.gas() == 2_000_000);
+
+    let results = multicall.execute();
+    assert(results.len() == 2);
+    assert(results.get(0).unwrap().len() == 0);
+    abi_decode::<b256>(results.get(1).unwrap().as_raw_slice())
+}
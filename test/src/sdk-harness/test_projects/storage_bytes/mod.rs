@@ -245,3 +245,131 @@ async fn clears_bytes() {
 
     assert_eq!(instance.methods().len().call().await.unwrap().value, 0);
 }
+
+#[tokio::test]
+async fn appends_bytes() {
+    let instance = setup().await;
+
+    let input: Vec<u8> = (1..=40).collect();
+    let appended: Vec<u8> = (41..=100).collect();
+
+    instance
+        .methods()
+        .store_bytes(input.clone())
+        .call()
+        .await
+        .unwrap();
+
+    instance
+        .methods()
+        .append_bytes(appended.clone())
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(instance.methods().len().call().await.unwrap().value, 100);
+
+    instance
+        .methods()
+        .assert_stored_bytes([input, appended].concat())
+        .call()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn appends_to_empty_bytes() {
+    let instance = setup().await;
+
+    let input = vec![1u8, 2u8, 3u8];
+
+    instance
+        .methods()
+        .append_bytes(input.clone())
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        instance.methods().len().call().await.unwrap().value,
+        input.len() as u64
+    );
+
+    instance
+        .methods()
+        .assert_stored_bytes(input)
+        .call()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn truncates_bytes() {
+    let instance = setup().await;
+
+    let input: Vec<u8> = (1..=100).collect();
+
+    instance
+        .methods()
+        .store_bytes(input.clone())
+        .call()
+        .await
+        .unwrap();
+
+    instance.methods().truncate_bytes(33).call().await.unwrap();
+
+    assert_eq!(instance.methods().len().call().await.unwrap().value, 33);
+
+    instance
+        .methods()
+        .assert_stored_bytes(input[..33].to_vec())
+        .call()
+        .await
+        .unwrap();
+
+    // Truncating to a greater length doesn't change the bytes.
+    instance.methods().truncate_bytes(50).call().await.unwrap();
+
+    assert_eq!(instance.methods().len().call().await.unwrap().value, 33);
+}
+
+#[tokio::test]
+async fn reads_range_of_bytes() {
+    let instance = setup().await;
+
+    let input: Vec<u8> = (1..=100).collect();
+
+    instance
+        .methods()
+        .store_bytes(input.clone())
+        .call()
+        .await
+        .unwrap();
+
+    instance
+        .methods()
+        .assert_stored_range(30, input[30..70].to_vec())
+        .call()
+        .await
+        .unwrap();
+
+    assert!(
+        instance
+            .methods()
+            .is_range_stored(90, 10)
+            .call()
+            .await
+            .unwrap()
+            .value
+    );
+
+    assert!(
+        !instance
+            .methods()
+            .is_range_stored(90, 11)
+            .call()
+            .await
+            .unwrap()
+            .value
+    );
+}
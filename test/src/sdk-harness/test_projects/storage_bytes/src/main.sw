@@ -16,6 +16,14 @@ abi StorageBytesTest {
     fn clear_stored_bytes() -> bool;
     #[storage(read)]
     fn len() -> u64;
+    #[storage(read, write)]
+    fn append_bytes(vec: Vec<u8>);
+    #[storage(read, write)]
+    fn truncate_bytes(len: u64);
+    #[storage(read)]
+    fn assert_stored_range(offset: u64, vec: Vec<u8>);
+    #[storage(read)]
+    fn is_range_stored(offset: u64, len: u64) -> bool;
 }
 
 impl StorageBytesTest for Contract {
@@ -51,4 +59,30 @@ impl StorageBytesTest for Contract {
     fn len() -> u64 {
         storage.bytes.len()
     }
+
+    #[storage(read, write)]
+    fn append_bytes(vec: Vec<u8>) {
+        let bytes = Bytes::from(vec);
+
+        storage.bytes.append(bytes);
+    }
+
+    #[storage(read, write)]
+    fn truncate_bytes(len: u64) {
+        storage.bytes.truncate(len);
+    }
+
+    #[storage(read)]
+    fn assert_stored_range(offset: u64, vec: Vec<u8>) {
+        let bytes = Bytes::from(vec);
+        let stored_bytes = storage.bytes.read_range(offset, bytes.len()).unwrap();
+
+        assert(bytes.len() == stored_bytes.len());
+        assert(bytes == stored_bytes);
+    }
+
+    #[storage(read)]
+    fn is_range_stored(offset: u64, len: u64) -> bool {
+        storage.bytes.read_range(offset, len).is_some()
+    }
 }